@@ -0,0 +1,108 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use ambient_core::hierarchy::children;
+use ambient_ecs::query;
+use ambient_model::model_from_url;
+use ambient_model_import::{gltf, model_crate::ModelCrate};
+use ambient_prefab::PrefabFromUrl;
+use ambient_std::{
+    asset_cache::{AssetCache, AsyncAssetKeyExt},
+    asset_url::AbsAssetUrl,
+};
+use anyhow::Context;
+use tokio::runtime::Runtime;
+
+/// Exports a built model or prefab as glTF. `asset_path` is either a model crate in the build output, or a model or prefab
+/// file within one; a model crate is exported through its prefab, if it has one.
+///
+/// Prefabs are loaded the way they are spawned, so that the prefabs they reference, as those composed by the prefabs
+/// pipeline, are exported along with them.
+pub fn export(
+    runtime: &Runtime,
+    assets: &AssetCache,
+    asset_path: &Path,
+    out_path: &Path,
+    skip_missing_textures: bool,
+) -> anyhow::Result<()> {
+    let (crate_path, store, id) = if asset_path.is_dir() {
+        let store = if asset_path.join("prefabs").join("main.json").exists() { "prefabs" } else { "models" };
+        (asset_path, store, ModelCrate::MAIN)
+    } else {
+        let store_path = asset_path.parent().context("The asset path has no parent directory")?;
+        let crate_path = store_path.parent().context("The asset path is not inside a model crate")?;
+        let store = store_path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+        let id = asset_path.file_stem().and_then(|stem| stem.to_str()).context("Invalid asset path")?;
+        (crate_path, store, id)
+    };
+    let export = match store {
+        "prefabs" => {
+            let prefab_path = std::fs::canonicalize(crate_path.join("prefabs").join(format!("{id}.json")))
+                .with_context(|| format!("Failed to find the prefab {asset_path:?}"))?;
+            export_prefab(runtime, assets, &prefab_path, skip_missing_textures)?
+        }
+        "models" => {
+            let model_crate =
+                ModelCrate::read_from_fs(crate_path).with_context(|| format!("Failed to read the model crate at {crate_path:?}"))?;
+            gltf::export_model(&model_crate, id, skip_missing_textures)?
+        }
+        _ => anyhow::bail!(
+            "{asset_path:?} is not a model or a prefab. Expected a model crate, or a file in its \
+            `models` or `prefabs` directory"
+        ),
+    };
+    let data = if out_path.extension().map_or(false, |extension| extension == "gltf") { export.to_gltf() } else { export.to_glb() };
+    std::fs::write(out_path, data).with_context(|| format!("Failed to write {out_path:?}"))?;
+    log::info!("Exported {asset_path:?} to {out_path:?}");
+    Ok(())
+}
+
+fn export_prefab(
+    runtime: &Runtime,
+    assets: &AssetCache,
+    prefab_path: &Path,
+    skip_missing_textures: bool,
+) -> anyhow::Result<gltf::GltfExport> {
+    let url = AbsAssetUrl::from_file_path(prefab_path);
+    let prefab =
+        runtime.block_on(PrefabFromUrl(url.into()).get(assets)).with_context(|| format!("Failed to load the prefab {prefab_path:?}"))?;
+
+    // The models of the prefab and of the prefabs it references can be in any model crate of the build, so each crate is
+    // read once up front
+    let mut model_crates = HashMap::new();
+    for (_, url) in query(model_from_url()).iter(&prefab, None) {
+        let (crate_path, _) = model_file(url)?;
+        if !model_crates.contains_key(&crate_path) {
+            let model_crate =
+                ModelCrate::read_from_fs(&crate_path).with_context(|| format!("Failed to read the model crate at {crate_path:?}"))?;
+            model_crates.insert(crate_path, model_crate);
+        }
+    }
+
+    let model_crates = &model_crates;
+    let roots = prefab.resource_opt(children()).cloned().unwrap_or_default();
+    gltf::export_world(
+        &prefab,
+        &roots,
+        move |url| {
+            let (crate_path, model_id) = model_file(url)?;
+            let model_crate = &model_crates[&crate_path];
+            let model = model_crate.models.content.get(&model_id).with_context(|| format!("The model {url} does not exist"))?;
+            Ok((model_crate, model))
+        },
+        skip_missing_textures,
+    )
+}
+
+/// The model crate and the id of the model that a resolved `model_from_url` points to, which is `<crate>/models/<id>.json`.
+fn model_file(url: &str) -> anyhow::Result<(PathBuf, String)> {
+    let path = AbsAssetUrl::parse(url)?
+        .to_file_path()?
+        .with_context(|| format!("Only local models can be exported, but the prefab uses {url}"))?;
+    let crate_path =
+        path.parent().and_then(|models| models.parent()).with_context(|| format!("The model {url} is not in a model crate"))?;
+    let model_id = path.file_stem().and_then(|stem| stem.to_str()).with_context(|| format!("Invalid model url {url}"))?;
+    Ok((crate_path.to_path_buf(), model_id.to_string()))
+}
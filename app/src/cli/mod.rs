@@ -2,6 +2,7 @@ use std::path::PathBuf;
 
 use clap::{Args, Parser};
 
+pub mod export;
 pub mod new_project;
 
 #[derive(Parser, Clone)]
@@ -43,6 +44,16 @@ pub enum Cli {
         /// Relative to the project path
         asset_path: PathBuf,
     },
+    /// Export a built model or prefab as glTF, i.e. `ambient export build/assets/models/robot.fbx robot.glb`
+    Export {
+        /// A model crate in the build output, or a model or prefab file within one
+        asset_path: PathBuf,
+        /// The file to write. Written as binary glTF, unless the extension is `.gltf`
+        out_path: PathBuf,
+        /// Leave out the textures that can't be exported, such as compressed textures, instead of failing
+        #[arg(long)]
+        skip_missing_textures: bool,
+    },
     /// Join a multiplayer session
     Join {
         #[command(flatten)]
@@ -81,6 +92,14 @@ pub struct ProjectCli {
     /// Build all the assets with full optimization; this will make debugging more difficult
     #[arg(short, long)]
     pub release: bool,
+
+    /// Rebuild all assets, even if they are unchanged since the last build
+    #[arg(long)]
+    pub force: bool,
+
+    /// While running, watch the project's assets, sources and manifest, and rebuild and reload whatever changes
+    #[arg(long)]
+    pub watch: bool,
 }
 #[derive(Args, Clone)]
 pub struct HostCli {
@@ -89,6 +108,12 @@ pub struct HostCli {
     /// Defaults to localhost
     #[arg(long)]
     pub public_host: Option<String>,
+
+    /// The directory where the server keeps the data it persists, such as the projects' storage
+    ///
+    /// Defaults to `server_data` within the project
+    #[arg(long)]
+    pub data_dir: Option<PathBuf>,
 }
 
 impl Cli {
@@ -100,6 +125,7 @@ impl Cli {
             Cli::Build { .. } => None,
             Cli::Serve { .. } => None,
             Cli::View { .. } => None,
+            Cli::Export { .. } => None,
             Cli::Join { run_args, .. } => Some(run_args),
             #[cfg(not(feature = "production"))]
             Cli::UpdateInterfaceComponents => None,
@@ -113,6 +139,7 @@ impl Cli {
             Cli::Build { project_args, .. } => Some(project_args),
             Cli::Serve { project_args, .. } => Some(project_args),
             Cli::View { project_args, .. } => Some(project_args),
+            Cli::Export { .. } => None,
             Cli::Join { .. } => None,
             #[cfg(not(feature = "production"))]
             Cli::UpdateInterfaceComponents => None,
@@ -126,6 +153,7 @@ impl Cli {
             Cli::Build { .. } => None,
             Cli::Serve { host_args, .. } => Some(host_args),
             Cli::View { .. } => None,
+            Cli::Export { .. } => None,
             Cli::Join { .. } => None,
             #[cfg(not(feature = "production"))]
             Cli::UpdateInterfaceComponents => None,
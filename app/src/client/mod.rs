@@ -1,6 +1,7 @@
 use std::{net::SocketAddr, path::PathBuf, process::exit, sync::Arc, time::Duration};
 
 use ambient_app::{window_title, AppBuilder};
+use ambient_audio::{AudioMixer, AudioStream};
 use ambient_cameras::UICamera;
 use ambient_core::{camera::active_camera, runtime};
 use ambient_debugger::Debugger;
@@ -26,12 +27,17 @@ pub async fn run(assets: AssetCache, server_addr: SocketAddr, run: &RunCli, proj
 
     let is_debug = std::env::var("AMBIENT_DEBUGGER").is_ok() || run.debugger;
 
+    // The stream plays the mixer for as long as it's alive, so it's kept until the app exits
+    let audio_stream =
+        if run.headless { None } else { AudioStream::new().map_err(|err| log::error!("Failed to initialize audio: {err}")).ok() };
+    let audio_mixer = audio_stream.as_ref().map(|stream| stream.mixer().clone());
+
     AppBuilder::new()
         .ui_renderer(true)
         .with_asset_cache(assets)
         .headless(headless)
         .run(move |app, _runtime| {
-            MainApp { server_addr, user_id, show_debug: is_debug, screenshot_test: run.screenshot_test, project_path }
+            MainApp { server_addr, user_id, show_debug: is_debug, screenshot_test: run.screenshot_test, project_path, audio_mixer }
                 .el()
                 .spawn_interactive(&mut app.world);
         })
@@ -46,6 +52,7 @@ fn MainApp(
     user_id: String,
     show_debug: bool,
     screenshot_test: Option<f32>,
+    audio_mixer: Option<AudioMixer>,
 ) -> Element {
     let resolution = use_window_physical_resolution(hooks);
 
@@ -65,6 +72,9 @@ fn MainApp(
             on_disconnect: cb(move || {}),
             init_world: cb(UseOnce::new(Box::new(move |world, render_target| {
                 wasm::initialize(world).unwrap();
+                if let Some(mixer) = audio_mixer {
+                    ambient_world_audio::systems::setup_audio(world, mixer).unwrap();
+                }
 
                 world.add_resource(ambient_network::events::event_registry(), Arc::new(ServerEventRegistry::new()));
                 if let Some(seconds) = screenshot_test {
@@ -145,6 +155,7 @@ fn systems() -> SystemGroup {
             Box::new(ambient_sky::systems()),
             Box::new(ambient_water::systems()),
             Box::new(ambient_physics::client_systems()),
+            Box::new(ambient_world_audio::systems::client_systems()),
             Box::new(wasm::systems()),
         ],
     )
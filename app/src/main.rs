@@ -127,6 +127,11 @@ fn main() -> anyhow::Result<()> {
         return Ok(());
     }
 
+    // If export: write the asset out as glTF, immediately exit
+    if let Cli::Export { asset_path, out_path, skip_missing_textures } = &cli {
+        return cli::export::export(&runtime, &assets, asset_path, out_path, *skip_missing_textures);
+    }
+
     // If UIC: write components to disk, immediately exit
     #[cfg(not(feature = "production"))]
     if let Cli::UpdateInterfaceComponents = cli {
@@ -164,7 +169,8 @@ fn main() -> anyhow::Result<()> {
             project_path.clone(),
             manifest,
             cli.project().map(|p| p.release).unwrap_or(false),
-        ));
+            cli.project().map(|p| p.force).unwrap_or(false),
+        ))?;
         log::info!("Done building {}", project_name);
     }
 
@@ -5,7 +5,7 @@ use std::{
     sync::Arc,
 };
 
-use ambient_core::{app_start_time, asset_cache, dtime, no_sync, project_name, time};
+use ambient_core::{app_start_time, asset_cache, dtime, no_sync, project_name, reloaded_assets, time};
 use ambient_ecs::{
     dont_store, world_events, ComponentDesc, ComponentRegistry, Entity, Networked, SystemGroup, World, WorldEventsSystem,
     WorldStreamCompEvent,
@@ -33,6 +33,7 @@ use tower_http::{cors::CorsLayer, services::ServeDir};
 use crate::{cli::Cli, shared};
 
 pub mod wasm;
+pub mod watch;
 
 pub fn start(
     runtime: &tokio::runtime::Runtime,
@@ -60,8 +61,12 @@ pub fn start(
 
     start_http_interface(runtime, &project_path);
 
+    let rebuilt = cli.project().filter(|p| p.watch).map(|p| watch::start(runtime, assets.clone(), project_path.clone(), p.release));
+
     ComponentRegistry::get_mut().add_external(manifest.all_defined_components(false).unwrap());
 
+    let data_dir = cli.host().and_then(|h| h.data_dir.clone()).unwrap_or_else(|| project_path.join("server_data"));
+
     let manifest = manifest.clone();
     runtime.spawn(async move {
         let mut server_world = World::new_with_config("server", true);
@@ -73,11 +78,11 @@ pub fn start(
         let name = manifest.project.name.clone().unwrap_or_else(|| "Ambient".into());
         server_world.add_components(server_world.resource_entity(), Entity::new().with(project_name(), name)).unwrap();
 
-        Entity::new().with(synced_resources(), ()).with(dont_store(), ()).spawn(&mut server_world);
+        Entity::new().with(synced_resources(), ()).with(dont_store(), ()).with_default(reloaded_assets()).spawn(&mut server_world);
         // Note: this should not be reset every time the server is created. Remove this when it becomes possible to load/save worlds.
         Entity::new().with(persistent_resources(), ()).spawn(&mut server_world);
 
-        wasm::initialize(&mut server_world, project_path.clone(), &manifest).unwrap();
+        wasm::initialize(&mut server_world, project_path.clone(), &data_dir, &manifest).unwrap();
 
         if let Cli::View { asset_path, .. } = cli.clone() {
             let asset_path = AbsAssetUrl::from_file_path(project_path.join("build").join(asset_path).join("prefabs/main.json"));
@@ -87,16 +92,23 @@ pub fn start(
         }
         log::info!("Starting server");
         server
-            .run(server_world, Arc::new(systems), Arc::new(on_forking_systems), Arc::new(on_shutdown_systems), Arc::new(is_sync_component))
+            .run(
+                server_world,
+                Arc::new(move |world| systems(world, rebuilt.clone())),
+                Arc::new(on_forking_systems),
+                Arc::new(on_shutdown_systems),
+                Arc::new(is_sync_component),
+            )
             .await;
     });
     port
 }
 
-fn systems(_world: &mut World) -> SystemGroup {
+fn systems(_world: &mut World, rebuilt: Option<watch::RebuiltFiles>) -> SystemGroup {
     SystemGroup::new(
         "server",
         vec![
+            Box::new(watch::systems(rebuilt)),
             ambient_physics::run_simulation_system(),
             // Can happen *during* the physics step
             Box::new(ambient_core::async_ecs::async_ecs_systems()),
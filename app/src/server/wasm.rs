@@ -1,21 +1,32 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use ambient_core::asset_cache;
-use ambient_ecs::{EntityId, SystemGroup, World};
+use ambient_ecs::{ComponentRegistry, EntityId, SystemGroup, World};
 use ambient_project::Identifier;
 use ambient_std::{
     asset_cache::SyncAssetKeyExt,
+    asset_catalog::AssetCatalog,
     asset_url::{AssetUrl, ServerBaseUrlKey},
 };
+use ambient_wasm::server::{asset_catalog, module_bytecode_path, module_capabilities, module_storage, ModuleCapabilities, Storage};
 pub use ambient_wasm::server::{on_forking_systems, on_shutdown_systems};
-use ambient_wasm::shared::{client_bytecode_from_url, get_module_name, module_bytecode, spawn_module, MessageType, ModuleBytecode};
+use ambient_wasm::shared::{
+    client_bytecode_from_url, get_module_name, module_bytecode, module_load_order, spawn_module, MessageType, ModuleBytecode,
+};
 use anyhow::Context;
+use parking_lot::Mutex;
 
 pub fn systems() -> SystemGroup {
     ambient_wasm::server::systems()
 }
 
-pub fn initialize(world: &mut World, project_path: PathBuf, manifest: &ambient_project::Manifest) -> anyhow::Result<()> {
+/// `data_dir` is where the server keeps the data it persists for the projects it runs, such as their storage.
+/// Modules have no access to it.
+pub fn initialize(world: &mut World, project_path: PathBuf, data_dir: &Path, manifest: &ambient_project::Manifest) -> anyhow::Result<()> {
     let messenger = Arc::new(|world: &World, id: EntityId, type_: MessageType, message: &str| {
         let name = get_module_name(world, id);
         let (prefix, level) = match type_ {
@@ -31,41 +42,91 @@ pub fn initialize(world: &mut World, project_path: PathBuf, manifest: &ambient_p
     ambient_wasm::server::initialize(world, messenger)?;
 
     let build_dir = project_path.join("build");
-    for target in ["client", "server"] {
-        let wasm_component_paths: Vec<PathBuf> = std::fs::read_dir(build_dir.join(target))
-            .ok()
-            .map(|rd| rd.filter_map(Result::ok).map(|p| p.path()).filter(|p| p.extension().unwrap_or_default() == "wasm").collect())
-            .unwrap_or_default();
-
-        let is_sole_module = wasm_component_paths.len() == 1;
-        for path in wasm_component_paths {
-            let filename_identifier =
-                Identifier::new(&*path.file_stem().context("no file stem for {path:?}")?.to_string_lossy()).map_err(anyhow::Error::msg)?;
-
-            let name = if is_sole_module {
-                manifest.project.id.clone()
+    world.add_resource(asset_catalog(), AssetCatalog::load(&build_dir)?);
+
+    let capabilities = ModuleCapabilities::new(&manifest.capabilities, &project_path);
+    let current_dir = std::env::current_dir()?;
+    let storage_dir = ambient_std::path::normalize(&current_dir.join(data_dir).join("storage"));
+    // Modules could otherwise tamper with the storage through their I/O capabilities
+    for root in [&capabilities.assets_path, &capabilities.data_path].into_iter().flatten() {
+        let root = ambient_std::path::normalize(&current_dir.join(root));
+        anyhow::ensure!(!storage_dir.starts_with(&root), "The server data directory {storage_dir:?} must not be within {root:?}");
+    }
+    let mut loader = ModuleLoader { build_dir: build_dir.clone(), storage_dir, loaded_projects: HashSet::new(), next_load_order: 0 };
+    loader.spawn_project_modules(world, &build_dir, manifest, &capabilities)
+}
+
+/// Spawns the modules of a project and its dependencies, ordered so that dependencies are loaded first.
+struct ModuleLoader {
+    build_dir: PathBuf,
+    /// Each project loaded gets its own persistent storage within this directory
+    storage_dir: PathBuf,
+    loaded_projects: HashSet<Identifier>,
+    next_load_order: u32,
+}
+impl ModuleLoader {
+    fn spawn_project_modules(
+        &mut self,
+        world: &mut World,
+        project_build_dir: &Path,
+        manifest: &ambient_project::Manifest,
+        capabilities: &ModuleCapabilities,
+    ) -> anyhow::Result<()> {
+        if !self.loaded_projects.insert(manifest.project.id.clone()) {
+            return Ok(());
+        }
+
+        for dependency_id in manifest.dependencies.keys() {
+            let dependency_build_dir = project_build_dir.join("dependencies").join(dependency_id.as_ref());
+            let dependency_manifest = ambient_project::Manifest::parse(
+                &std::fs::read_to_string(dependency_build_dir.join("ambient.toml"))
+                    .with_context(|| format!("Dependency `{dependency_id}` has not been built"))?,
+            )?;
+            ComponentRegistry::get_mut().add_external(dependency_manifest.all_defined_components(false).map_err(anyhow::Error::msg)?);
+
+            // Dependencies do not inherit the capabilities granted to this project.
+            self.spawn_project_modules(world, &dependency_build_dir, &dependency_manifest, &ModuleCapabilities::default())?;
+        }
+
+        let storage = Arc::new(Mutex::new(Storage::open(&self.storage_dir, manifest.project.id.as_ref())?));
+        for (module_id, module) in manifest.modules_in_load_order()? {
+            let name = if module_id == manifest.project.id {
+                module_id.clone()
             } else {
-                Identifier::new(format!("{}_{}", manifest.project.id, filename_identifier)).map_err(anyhow::Error::msg)?
+                Identifier::new(format!("{}_{}", manifest.project.id, module_id)).map_err(anyhow::Error::msg)?
             };
 
             let description = manifest.project.description.clone().unwrap_or_default();
-            let description = if is_sole_module { description } else { format!("{description} ({filename_identifier})") };
+            let description = if module_id == manifest.project.id { description } else { format!("{description} ({module_id})") };
 
-            let id = spawn_module(world, &name, description, true)?;
+            for target in ["client", "server"] {
+                let path = project_build_dir.join(target).join(format!("{module_id}.wasm"));
+                if !path.exists() {
+                    continue;
+                }
 
-            if target == "client" {
-                let relative_path = path.strip_prefix(&build_dir)?;
+                let id = spawn_module(world, &name, description.clone(), module.enabled)?;
+                world.add_component(id, module_load_order(), self.next_load_order)?;
+                self.next_load_order += 1;
 
-                let base_url = ServerBaseUrlKey.get(world.resource(asset_cache()));
-                let bytecode_url = AssetUrl::parse(&relative_path.to_string_lossy())?.resolve(&base_url)?.to_string();
+                if target == "client" {
+                    let relative_path = path.strip_prefix(&self.build_dir)?;
 
-                world.add_component(id, client_bytecode_from_url(), bytecode_url)?;
-            } else {
-                let bytecode = std::fs::read(path)?;
-                world.add_component(id, module_bytecode(), ModuleBytecode(bytecode))?;
+                    let base_url = ServerBaseUrlKey.get(world.resource(asset_cache()));
+                    let bytecode_url = AssetUrl::parse(&relative_path.to_string_lossy())?.resolve(&base_url)?.to_string();
+
+                    world.add_component(id, client_bytecode_from_url(), bytecode_url)?;
+                } else {
+                    world.add_component(id, module_capabilities(), capabilities.clone())?;
+                    world.add_component(id, module_storage(), storage.clone())?;
+
+                    let bytecode = std::fs::read(&path)?;
+                    world.add_component(id, module_bytecode(), ModuleBytecode(bytecode))?;
+                    world.add_component(id, module_bytecode_path(), path)?;
+                }
             }
         }
-    }
 
-    Ok(())
+        Ok(())
+    }
 }
@@ -0,0 +1,151 @@
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+use ambient_core::reloaded_assets;
+use ambient_ecs::{query, FnSystem, SystemGroup};
+use ambient_network::synced_resources;
+use ambient_physics::physx::PhysicsKey;
+use ambient_std::{
+    asset_cache::{AssetCache, SyncAssetKeyExt},
+    asset_catalog::AssetCatalog,
+};
+use ambient_wasm::server::asset_catalog;
+use notify::{EventKind, RecursiveMode, Watcher};
+use parking_lot::Mutex;
+
+/// How long to wait for more changes after one is seen before rebuilding
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+#[derive(Clone)]
+pub struct RebuiltFiles {
+    build_path: PathBuf,
+    /// Files written by the watcher that the server has not picked up yet, relative to the build directory
+    files: Arc<Mutex<Vec<String>>>,
+}
+
+/// Starts watching the project at `project_path`, rebuilding it whenever its assets, Rust sources or manifest change.
+pub fn start(runtime: &tokio::runtime::Runtime, assets: AssetCache, project_path: PathBuf, optimize: bool) -> RebuiltFiles {
+    let rebuilt = RebuiltFiles { build_path: project_path.join("build"), files: Default::default() };
+    let handle = runtime.handle().clone();
+    std::thread::spawn({
+        let files = rebuilt.files.clone();
+        // The build is not `Send`, so it cannot be spawned onto the runtime.
+        move || handle.block_on(watch(assets, project_path, optimize, files))
+    });
+    rebuilt
+}
+
+/// Passes the files rebuilt by the watcher, if there is one, on to the rest of the server and to the clients
+/// through [reloaded_assets].
+pub fn systems(rebuilt: Option<RebuiltFiles>) -> SystemGroup {
+    SystemGroup::new(
+        "watch",
+        vec![Box::new(FnSystem::new(move |world, _| {
+            let Some(rebuilt) = &rebuilt else { return };
+            let files = std::mem::take(&mut *rebuilt.files.lock());
+            if files.is_empty() {
+                return;
+            }
+
+            if files.iter().any(|file| file == AssetCatalog::FILENAME) {
+                match AssetCatalog::load(&rebuilt.build_path) {
+                    Ok(catalog) => world.set(world.resource_entity(), asset_catalog(), catalog).unwrap(),
+                    Err(err) => log::error!("Failed to reload the asset catalog: {err:?}"),
+                }
+            }
+            for id in query(()).incl(synced_resources()).collect_ids(world, None) {
+                world.set(id, reloaded_assets(), files.clone()).unwrap();
+            }
+        }))],
+    )
+}
+
+async fn watch(assets: AssetCache, project_path: PathBuf, optimize: bool, rebuilt: Arc<Mutex<Vec<String>>>) {
+    log::info!("Watching {project_path:?} for changes");
+
+    // The watcher reports absolute paths
+    let project_path = project_path.canonicalize().unwrap_or(project_path);
+    let (sender, mut events) = tokio::sync::mpsc::unbounded_channel();
+    let mut watcher = match notify::recommended_watcher(move |event| {
+        sender.send(event).ok();
+    }) {
+        Ok(watcher) => watcher,
+        Err(err) => {
+            log::error!("Failed to create the file watcher: {err:?}");
+            return;
+        }
+    };
+    if let Err(err) = watcher.watch(&project_path, RecursiveMode::Recursive) {
+        log::error!("Failed to watch {project_path:?}: {err:?}");
+        return;
+    }
+
+    let assets_path = project_path.join("assets");
+    while let Some(event) = events.recv().await {
+        let mut changed = BTreeSet::new();
+        add_changed_paths(&mut changed, event);
+        // Editors and the build itself tend to touch several files at once, which should result in a single rebuild
+        tokio::time::sleep(DEBOUNCE).await;
+        while let Ok(event) = events.try_recv() {
+            add_changed_paths(&mut changed, event);
+        }
+
+        // Everything else in the project, such as the build directory, is ignored
+        let roots = watched_roots(&project_path);
+        let changed = changed.into_iter().filter(|path| roots.iter().any(|root| path.starts_with(root))).collect::<Vec<_>>();
+        if changed.is_empty() {
+            continue;
+        }
+
+        let manifest = match read_manifest(&project_path) {
+            Ok(manifest) => manifest,
+            Err(err) => {
+                log::error!("Failed to read the project manifest: {err:?}");
+                continue;
+            }
+        };
+
+        let rust = changed.iter().any(|path| !path.starts_with(&assets_path));
+        log::info!("Rebuilding after changes to {changed:?}");
+        match ambient_build::rebuild(PhysicsKey.get(&assets), &project_path, &manifest, optimize, rust).await {
+            Ok(files) => {
+                log::info!("Rebuilt {} files", files.len());
+                rebuilt.lock().extend(files);
+            }
+            Err(err) => log::error!("Failed to rebuild the project: {err:?}"),
+        }
+    }
+}
+
+fn add_changed_paths(changed: &mut BTreeSet<PathBuf>, event: notify::Result<notify::Event>) {
+    match event {
+        Ok(event) if !matches!(event.kind, EventKind::Access(_)) => changed.extend(event.paths),
+        Ok(_) => {}
+        Err(err) => log::warn!("Failed to watch for changes: {err:?}"),
+    }
+}
+
+fn read_manifest(project_path: &Path) -> anyhow::Result<ambient_project::Manifest> {
+    Ok(ambient_project::Manifest::parse(&std::fs::read_to_string(project_path.join("ambient.toml"))?)?)
+}
+
+/// Returns the files and directories that can affect the build: the assets, the manifest, and the sources and
+/// `Cargo.toml` of each module.
+fn watched_roots(project_path: &Path) -> Vec<PathBuf> {
+    let mut roots = vec![project_path.join("assets"), project_path.join("ambient.toml")];
+    let module_paths = read_manifest(project_path)
+        .ok()
+        .and_then(|manifest| manifest.modules_in_load_order().ok())
+        .map(|modules| modules.into_iter().map(|(_, module)| module.path.map(|path| project_path.join(path))).collect())
+        .unwrap_or_else(|| vec![None]);
+    for module_path in module_paths {
+        let module_path = module_path.unwrap_or_else(|| project_path.to_owned());
+        roots.push(module_path.join("src"));
+        roots.push(module_path.join("Cargo.toml"));
+    }
+    roots
+}
@@ -5,6 +5,7 @@ pub(crate) fn init() -> anyhow::Result<()> {
     ambient_network::init_all_components();
     ambient_physics::init_all_components();
     ambient_wasm::shared::init_components();
+    ambient_wasm::server::init_components();
     ambient_decals::init_components();
     ambient_world_audio::init_components();
     ambient_primitives::init_components();
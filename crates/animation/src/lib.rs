@@ -1,6 +1,11 @@
-use ambient_core::{asset_cache, hierarchy::children, time};
+use ambient_core::{
+    asset_cache,
+    hierarchy::children,
+    time,
+    transform::{rotation, translation},
+};
 use ambient_ecs::{components, query, Debuggable, EntityId, MakeDefault, Networked, Store, SystemGroup};
-use ambient_model::{animation_binder, model, model_from_url, ModelFromUrl};
+use ambient_model::{animation_binder, animation_pose_override, model, model_from_url, ModelFromUrl};
 use ambient_std::{
     asset_cache::{AssetCache, AsyncAssetKeyExt},
     asset_url::{AnimationAssetType, ModelAssetType, TypedAssetUrl},
@@ -279,6 +284,23 @@ pub fn animation_systems() -> SystemGroup {
                     world.add_component(id, animation_errors(), err).unwrap();
                 }
             }),
+            query((animation_pose_override(), animation_binder())).to_system(|q, world, qs, _| {
+                for (_, (pose_override, binder)) in q.collect_cloned(world, qs) {
+                    let weight = pose_override.weight.clamp(0., 1.);
+                    if weight == 0. {
+                        continue;
+                    }
+                    for (bind_id, (pos, rot)) in pose_override.pose.iter() {
+                        let Some(&entity) = binder.get(bind_id) else { continue };
+                        if let Ok(value) = world.get_mut(entity, translation()) {
+                            *value = value.lerp(*pos, weight);
+                        }
+                        if let Ok(value) = world.get_mut(entity, rotation()) {
+                            *value = value.slerp(*rot, weight);
+                        }
+                    }
+                }
+            }),
         ],
     )
 }
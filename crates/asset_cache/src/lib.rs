@@ -238,6 +238,12 @@ impl AssetCache {
         cache.insert(key.clone(), SyncAssetLoc { _key: key, content: Arc::new(Mutex::new(Some(Arc::new(asset) as Arc<dyn AssetHolder>))) });
     }
 
+    /// Drops every async asset whose key matches `predicate`, so that the next request for it loads it again.
+    /// Assets that have already been handed out are unaffected.
+    pub fn invalidate(&self, predicate: impl Fn(&str) -> bool) {
+        self.async_cache.lock().retain(|key, _| !predicate(key));
+    }
+
     fn clean_up_dropped(&self) {
         let mut async_ = self.async_cache.lock();
         for (key, asset) in &mut *async_ {
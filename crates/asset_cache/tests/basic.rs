@@ -186,3 +186,20 @@ async fn load_background() {
 
     assert!(Arc::ptr_eq(&a, &b));
 }
+
+#[tokio::test]
+async fn invalidate() {
+    let assets = AssetCache::new(runtime::Handle::current());
+
+    let foo = TestAssetKey { name: "foo".into() }.get(&assets).await;
+    let bar = TestAssetKey { name: "bar".into() }.get(&assets).await;
+
+    assets.invalidate(|key| key.contains("foo"));
+
+    assert!(TestAssetKey { name: "foo".into() }.is_loaded(&assets).is_none());
+    assert!(Arc::ptr_eq(&bar, &TestAssetKey { name: "bar".into() }.is_loaded(&assets).unwrap()));
+
+    let foo_reloaded = TestAssetKey { name: "foo".into() }.get(&assets).await;
+    assert_eq!(foo, foo_reloaded);
+    assert!(!Arc::ptr_eq(&foo, &foo_reloaded));
+}
@@ -13,6 +13,42 @@ pub struct BltCoeffs {
     b2: f32,
 }
 
+impl BltCoeffs {
+    /// Coefficients which leave the signal untouched
+    pub const IDENTITY: Self = Self {
+        a1: 0.0,
+        a2: 0.0,
+        b0: 1.0,
+        b1: 0.0,
+        b2: 0.0,
+    };
+}
+
+/// The previous inputs and outputs of a filter
+#[derive(Debug, Clone, Default)]
+pub(crate) struct BltState {
+    x1: Vec2,
+    x2: Vec2,
+    y1: Vec2,
+    y2: Vec2,
+}
+
+impl BltState {
+    /// Filters the next sample with the coefficients `c`
+    pub(crate) fn process(&mut self, c: &BltCoeffs, sample: Vec2) -> Vec2 {
+        let y = c.b0 * sample + c.b1 * self.x1 + c.b2 * self.x2 - c.a1 * self.y1 - c.a2 * self.y2;
+
+        // Slide
+        self.x2 = self.x1;
+        self.x1 = sample;
+
+        self.y2 = self.y1;
+        self.y1 = y;
+
+        y
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Hpf {
     pub freq: f32,
@@ -125,10 +161,7 @@ where
     c: BltCoeffs,
     filter: Vh,
     prev_filter: H,
-    x1: Vec2,
-    x2: Vec2,
-    y1: Vec2,
-    y2: Vec2,
+    state: BltState,
 }
 
 impl<S, H, Vh> BilinearTransform<S, H, Vh>
@@ -145,10 +178,7 @@ where
         Self {
             source,
             c,
-            x1: Vec2::ZERO,
-            x2: Vec2::ZERO,
-            y1: Vec2::ZERO,
-            y2: Vec2::ZERO,
+            state: BltState::default(),
             prev_filter: f,
             filter,
         }
@@ -171,18 +201,7 @@ where
 
         let sample = self.source.next_sample()?;
 
-        let y = self.c.b0 * sample + self.c.b1 * self.x1 + self.c.b2 * self.x2
-            - self.c.a1 * self.y1
-            - self.c.a2 * self.y2;
-
-        // Slide
-        self.x2 = self.x1;
-        self.x1 = sample;
-
-        self.y2 = self.y1;
-        self.y1 = y;
-
-        Some(y)
+        Some(self.state.process(&self.c, sample))
     }
 
     fn sample_rate(&self) -> SampleRate {
@@ -0,0 +1,184 @@
+use std::{sync::Arc, time::Duration};
+
+use parking_lot::Mutex;
+
+use crate::{
+    blt::{BltCoeffs, BltState, Lpf, TransferFunction},
+    to_sample_index, Frame, SampleRate, Source,
+};
+
+/// The parameters of a playing sound, which can be changed while it plays
+#[derive(Debug, Clone, PartialEq)]
+pub struct SoundParams {
+    /// Multiplies the volume of the sound
+    pub gain: f32,
+    /// Multiplies the playback speed of the sound, which also raises or lowers its pitch
+    pub pitch: f32,
+    /// The cutoff frequency of a low-pass filter, in Hz. The sound is not filtered if None
+    pub cutoff: Option<f32>,
+    /// Once set, the sound fades out over this duration and stops
+    pub stop: Option<Duration>,
+}
+
+impl Default for SoundParams {
+    fn default() -> Self {
+        Self {
+            gain: 1.0,
+            pitch: 1.0,
+            cutoff: None,
+            stop: None,
+        }
+    }
+}
+
+/// Plays a source with shared [SoundParams], which can be changed while it plays
+#[derive(Debug)]
+pub struct Controlled<S> {
+    source: S,
+    params: Arc<Mutex<SoundParams>>,
+    /// The two source samples the output is interpolated between, and the position between them
+    window: Option<[Frame; 2]>,
+    t: f32,
+    /// Whether the source has no more samples after the window
+    source_ended: bool,
+    finished: bool,
+    cutoff: Option<f32>,
+    coeffs: BltCoeffs,
+    filter: BltState,
+    /// The remaining and total number of samples of the fade out
+    fade: Option<(u64, u64)>,
+}
+
+/// The bandwidth of the low-pass filter, in octaves
+const CUTOFF_BANDWIDTH: f32 = 1.0;
+
+impl<S> Controlled<S>
+where
+    S: Source,
+{
+    pub fn new(source: S, params: Arc<Mutex<SoundParams>>) -> Self {
+        Self {
+            source,
+            params,
+            window: None,
+            t: 0.0,
+            source_ended: false,
+            finished: false,
+            cutoff: None,
+            coeffs: BltCoeffs::IDENTITY,
+            filter: BltState::default(),
+            fade: None,
+        }
+    }
+
+    /// Slides the window one sample forward, or returns None if the source has no more samples
+    fn advance(&mut self, window: [Frame; 2]) -> Option<[Frame; 2]> {
+        if self.source_ended {
+            return None;
+        }
+        let next = self.source.next_sample().unwrap_or_else(|| {
+            self.source_ended = true;
+            Frame::ZERO
+        });
+        Some([window[1], next])
+    }
+}
+
+impl<S> Source for Controlled<S>
+where
+    S: Source,
+{
+    fn next_sample(&mut self) -> Option<Frame> {
+        if self.finished {
+            return None;
+        }
+        let params = self.params.lock().clone();
+
+        let window = match self.window {
+            Some(window) => window,
+            None => {
+                let first = self.source.next_sample()?;
+                let second = self.source.next_sample().unwrap_or_else(|| {
+                    self.source_ended = true;
+                    Frame::ZERO
+                });
+                [first, second]
+            }
+        };
+
+        if self.cutoff != params.cutoff {
+            self.cutoff = params.cutoff;
+            self.coeffs = match params.cutoff {
+                Some(freq) => Lpf {
+                    freq,
+                    bandwidth: CUTOFF_BANDWIDTH,
+                }
+                .get_coeffs(self.sample_rate()),
+                None => BltCoeffs::IDENTITY,
+            };
+        }
+
+        if let (Some(stop), None) = (params.stop, self.fade) {
+            let len = to_sample_index(self.sample_rate(), stop).max(1);
+            self.fade = Some((len, len));
+        }
+
+        let mut sample = window[0].lerp(window[1], self.t) * params.gain;
+        sample = self.filter.process(&self.coeffs, sample);
+        if let Some((remaining, len)) = &mut self.fade {
+            sample *= *remaining as f32 / *len as f32;
+            *remaining -= 1;
+            self.finished = *remaining == 0;
+        }
+
+        // Step through the source at the speed of the pitch
+        self.t += params.pitch.max(0.0);
+        let mut window = Some(window);
+        while self.t >= 1.0 {
+            self.t -= 1.0;
+            window = window.and_then(|window| self.advance(window));
+        }
+        self.finished |= window.is_none();
+        self.window = window;
+
+        Some(sample)
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        self.source.sample_rate()
+    }
+
+    fn sample_count(&self) -> Option<u64> {
+        // Depends on the pitch, which can change at any time
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use glam::vec2;
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::BufferedSource;
+
+    #[test]
+    fn pitch_and_stop() {
+        let params = Arc::new(Mutex::new(SoundParams {
+            pitch: 0.5,
+            ..Default::default()
+        }));
+        let mut source = Controlled::new(
+            BufferedSource::new([0.0, 0.0, 2.0, 2.0, 4.0, 4.0], 2, 2),
+            params.clone(),
+        );
+
+        assert_eq!(source.next_sample(), Some(vec2(0.0, 0.0)));
+        assert_eq!(source.next_sample(), Some(vec2(1.0, 1.0)));
+        assert_eq!(source.next_sample(), Some(vec2(2.0, 2.0)));
+
+        params.lock().stop = Some(Duration::from_secs(1));
+        let samples = std::iter::from_fn(|| source.next_sample()).collect_vec();
+        pretty_assertions::assert_eq!(samples, [vec2(3.0, 3.0), vec2(2.0, 2.0)]);
+    }
+}
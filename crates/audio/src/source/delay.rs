@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use crate::{to_sample_index, Frame, SampleRate, Source};
+
+/// Plays silence for a duration before the source starts
+#[derive(Debug, Clone)]
+pub struct Delay<S> {
+    source: S,
+    remaining: SampleRate,
+}
+
+impl<S> Delay<S>
+where
+    S: Source,
+{
+    pub fn new(source: S, dur: Duration) -> Self {
+        let remaining = to_sample_index(source.sample_rate(), dur);
+        Self { source, remaining }
+    }
+}
+
+impl<S> Source for Delay<S>
+where
+    S: Source,
+{
+    #[inline]
+    fn next_sample(&mut self) -> Option<Frame> {
+        if self.remaining > 0 {
+            self.remaining -= 1;
+            Some(Frame::ZERO)
+        } else {
+            self.source.next_sample()
+        }
+    }
+
+    #[inline]
+    fn sample_rate(&self) -> SampleRate {
+        self.source.sample_rate()
+    }
+
+    #[inline]
+    fn sample_count(&self) -> Option<u64> {
+        Some(self.source.sample_count()? + self.remaining)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use glam::vec2;
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::BufferedSource;
+
+    #[test]
+    fn delay() {
+        let source = Delay::new(
+            BufferedSource::new([1.0, 1.0, 2.0, 2.0], 2, 2),
+            Duration::from_secs(1),
+        );
+        assert_eq!(source.sample_count(), Some(4));
+
+        let samples = source.samples_iter().collect_vec();
+
+        pretty_assertions::assert_eq!(
+            samples,
+            [
+                vec2(0.0, 0.0),
+                vec2(0.0, 0.0),
+                vec2(1.0, 1.0),
+                vec2(2.0, 2.0),
+            ]
+        )
+    }
+}
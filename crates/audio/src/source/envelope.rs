@@ -0,0 +1,112 @@
+use std::time::Duration;
+
+use crate::{to_sample_index, Frame, SampleRate, Source};
+
+/// Multiplies a source by a gain which is linearly interpolated between points in time.
+///
+/// The gain is held at the first point before it, and at the last point after it.
+#[derive(Debug, Clone)]
+pub struct Envelope<S> {
+    source: S,
+    /// The sample index and gain of each point, in order
+    points: Vec<(SampleRate, f32)>,
+    cursor: SampleRate,
+    /// The index of the first point after the cursor
+    next: usize,
+}
+
+impl<S> Envelope<S>
+where
+    S: Source,
+{
+    /// Creates an envelope from `(time, gain)` points, which are sorted by time
+    pub fn new(source: S, points: impl IntoIterator<Item = (Duration, f32)>) -> Self {
+        let sample_rate = source.sample_rate();
+        let mut points = points
+            .into_iter()
+            .map(|(time, gain)| (to_sample_index(sample_rate, time), gain))
+            .collect::<Vec<_>>();
+        points.sort_by_key(|&(index, _)| index);
+
+        Self {
+            source,
+            points,
+            cursor: 0,
+            next: 0,
+        }
+    }
+
+    fn gain(&mut self) -> f32 {
+        while self.next < self.points.len() && self.points[self.next].0 <= self.cursor {
+            self.next += 1;
+        }
+
+        let prev = self.next.checked_sub(1).map(|i| self.points[i]);
+        match (prev, self.points.get(self.next)) {
+            (Some((start, from)), Some(&(end, to))) => {
+                let t = (self.cursor - start) as f32 / (end - start) as f32;
+                from + t * (to - from)
+            }
+            (Some((_, gain)), None) | (None, Some(&(_, gain))) => gain,
+            (None, None) => 1.0,
+        }
+    }
+}
+
+impl<S> Source for Envelope<S>
+where
+    S: Source,
+{
+    #[inline]
+    fn next_sample(&mut self) -> Option<Frame> {
+        let sample = self.source.next_sample()?;
+        let gain = self.gain();
+        self.cursor += 1;
+        Some(sample * gain)
+    }
+
+    #[inline]
+    fn sample_rate(&self) -> SampleRate {
+        self.source.sample_rate()
+    }
+
+    #[inline]
+    fn sample_count(&self) -> Option<u64> {
+        self.source.sample_count()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use glam::vec2;
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::BufferedSource;
+
+    #[test]
+    fn envelope() {
+        let source = Envelope::new(
+            BufferedSource::new([1.0; 12], 2, 2),
+            [
+                (Duration::from_millis(500), 0.0),
+                (Duration::from_millis(1500), 1.0),
+                (Duration::from_millis(2500), 0.5),
+            ],
+        );
+
+        let samples = source.samples_iter().collect_vec();
+
+        pretty_assertions::assert_eq!(
+            samples,
+            [
+                vec2(0.0, 0.0),
+                vec2(0.0, 0.0),
+                vec2(0.5, 0.5),
+                vec2(1.0, 1.0),
+                vec2(0.75, 0.75),
+                vec2(0.5, 0.5),
+            ]
+        )
+    }
+}
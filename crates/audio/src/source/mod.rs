@@ -1,7 +1,10 @@
 mod buffered;
 mod chain;
+mod controlled;
 mod crossfade;
+mod delay;
 pub(crate) mod dynamic_delay;
+mod envelope;
 pub mod gain;
 pub mod history;
 mod mix;
@@ -13,6 +16,7 @@ mod sample_bufferer;
 mod sample_rate;
 mod slice;
 mod spatial;
+mod speed;
 pub mod streaming_source;
 mod uniform;
 use std::{
@@ -21,8 +25,11 @@ use std::{
 
 pub use buffered::*;
 pub use chain::*;
+pub use controlled::*;
 use circular_queue::CircularQueue;
 pub use crossfade::*;
+pub use delay::*;
+pub use envelope::*;
 pub use gain::*;
 pub use mix::*;
 use parking_lot::Mutex;
@@ -31,6 +38,7 @@ pub use repeat::*;
 pub use sample_rate::*;
 pub use slice::*;
 pub use spatial::*;
+pub use speed::*;
 pub use uniform::*;
 
 use self::{history::History, mix::Mix, oscilloscope::Oscilloscope, pad_to::PadTo};
@@ -100,6 +108,20 @@ pub trait Source: Send {
         PadTo::new(self, dur)
     }
 
+    fn controlled(self, params: Arc<Mutex<SoundParams>>) -> Controlled<Self>
+    where
+        Self: Sized,
+    {
+        Controlled::new(self, params)
+    }
+
+    fn delay(self, dur: Duration) -> Delay<Self>
+    where
+        Self: Sized,
+    {
+        Delay::new(self, dur)
+    }
+
     fn crossfade<S>(self, other: S, dur: Duration) -> Crossfade<Self, S>
     where
         Self: Sized,
@@ -153,6 +175,20 @@ pub trait Source: Send {
         Gain::new(self, gain)
     }
 
+    fn envelope(self, points: impl IntoIterator<Item = (Duration, f32)>) -> Envelope<Self>
+    where
+        Self: Sized,
+    {
+        Envelope::new(self, points)
+    }
+
+    fn speed(self, factor: f32) -> Speed<Self>
+    where
+        Self: Sized,
+    {
+        Speed::new(self, factor)
+    }
+
     fn spatial<L, P>(self, hrtf_lib: &HrtfLib, listener: L, params: P) -> Spatial<Self, L, P>
     where
         Self: Sized,
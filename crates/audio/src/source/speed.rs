@@ -0,0 +1,44 @@
+use crate::{Frame, SampleRate, Source};
+
+/// Plays a source faster or slower, which also raises or lowers its pitch.
+///
+/// The samples are left untouched; the source reports a scaled sample rate instead, so that it is
+/// resampled when mixed with other sources.
+#[derive(Debug, Clone)]
+pub struct Speed<S> {
+    source: S,
+    sample_rate: SampleRate,
+}
+
+impl<S> Speed<S>
+where
+    S: Source,
+{
+    pub fn new(source: S, factor: f32) -> Self {
+        let sample_rate = ((source.sample_rate() as f32 * factor).round() as SampleRate).max(1);
+        Self {
+            source,
+            sample_rate,
+        }
+    }
+}
+
+impl<S> Source for Speed<S>
+where
+    S: Source,
+{
+    #[inline]
+    fn next_sample(&mut self) -> Option<Frame> {
+        self.source.next_sample()
+    }
+
+    #[inline]
+    fn sample_rate(&self) -> SampleRate {
+        self.sample_rate
+    }
+
+    #[inline]
+    fn sample_count(&self) -> Option<u64> {
+        self.source.sample_count()
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    collections::HashSet,
     path::{Path, PathBuf},
     sync::Arc,
 };
@@ -6,10 +7,12 @@ use std::{
 use ambient_asset_cache::{AssetCache, SyncAssetKeyExt};
 use ambient_physics::physx::{Physics, PhysicsKey};
 use ambient_project::Manifest as ProjectManifest;
-use ambient_std::asset_url::AbsAssetUrl;
+use ambient_std::{asset_catalog::AssetCatalog, asset_url::AbsAssetUrl};
+use anyhow::Context;
+use async_recursion::async_recursion;
 use futures::FutureExt;
 use itertools::Itertools;
-use pipelines::{FileCollection, ProcessCtx, ProcessCtxKey};
+use pipelines::{build_manifest::BuildManifest, FileCollection, ProcessCtx, ProcessCtxKey};
 use walkdir::WalkDir;
 
 pub mod pipelines;
@@ -21,24 +24,145 @@ pub mod pipelines;
 /// src/**  This is where you store Rust source files
 /// build  This is the output directory, and is created when building
 /// ambient.toml  This is a metadata file to describe the project
-pub async fn build(physics: Physics, _assets: &AssetCache, path: PathBuf, manifest: &ProjectManifest, optimize: bool) {
+///
+/// Any projects listed in the manifest's `[dependencies]` are built first, and their outputs copied to
+/// `build/dependencies/<id>`. Each dependency is built once, even if several projects depend on it;
+/// dependency cycles are reported as errors.
+///
+/// Asset pipelines whose configuration and inputs have not changed since the last build are skipped,
+/// unless `force` is set.
+pub async fn build(
+    physics: Physics,
+    assets: &AssetCache,
+    path: PathBuf,
+    manifest: &ProjectManifest,
+    optimize: bool,
+    force: bool,
+) -> anyhow::Result<()> {
+    build_project(physics, assets, path, manifest, optimize, force, &mut DependencyState::default()).await
+}
+
+/// The projects of a build, used to build every dependency once and to detect cycles
+#[derive(Default)]
+struct DependencyState {
+    /// The projects whose build has started but not finished, in the order they were started
+    in_progress: Vec<PathBuf>,
+    /// The projects that have been built
+    built: HashSet<PathBuf>,
+}
+
+#[async_recursion(?Send)]
+async fn build_project(
+    physics: Physics,
+    assets: &AssetCache,
+    path: PathBuf,
+    manifest: &ProjectManifest,
+    optimize: bool,
+    force: bool,
+    state: &mut DependencyState,
+) -> anyhow::Result<()> {
     log::info!(
         "Building project `{}` ({})",
         manifest.project.id,
         manifest.project.name.as_deref().unwrap_or_else(|| manifest.project.id.as_ref())
     );
 
-    ambient_ecs::ComponentRegistry::get_mut().add_external(manifest.all_defined_components(false).unwrap());
+    ambient_ecs::ComponentRegistry::get_mut().add_external(manifest.all_defined_components(false)?);
 
     let build_path = path.join("build");
     let assets_path = path.join("assets");
 
-    std::fs::create_dir_all(&build_path).unwrap();
-    build_assets(physics, &assets_path, &build_path).await;
-    build_rust_if_available(&path, manifest, &build_path, optimize).await.unwrap();
+    std::fs::create_dir_all(&build_path).with_context(|| format!("Failed to create {build_path:?}"))?;
+
+    let normalized_path = ambient_std::path::normalize(&path);
+    state.in_progress.push(normalized_path.clone());
+    let dependencies = build_dependencies(physics.clone(), assets, &path, manifest, &build_path, optimize, force, state).await;
+    state.in_progress.pop();
+    dependencies.with_context(|| format!("Failed to build the dependencies of `{}`", manifest.project.id))?;
+
+    build_assets(physics, &assets_path, &build_path, force).await;
+    build_rust_if_available(&path, manifest, &build_path, optimize).await?;
+    state.built.insert(normalized_path);
+    Ok(())
 }
 
-async fn build_assets(physics: Physics, assets_path: &Path, build_path: &Path) {
+/// Incrementally rebuilds the assets of an already-built project, and its Rust modules if `rust` is set.
+/// Dependencies are not rebuilt.
+///
+/// Returns the paths, relative to the build directory, of every file that was written or removed.
+pub async fn rebuild(physics: Physics, path: &Path, manifest: &ProjectManifest, optimize: bool, rust: bool) -> anyhow::Result<Vec<String>> {
+    let build_path = path.join("build");
+
+    let mut changed = build_assets(physics, &path.join("assets"), &build_path, false).await;
+    if rust {
+        changed.extend(build_rust_if_available(path, manifest, &build_path, optimize).await?);
+    }
+    Ok(changed)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn build_dependencies(
+    physics: Physics,
+    assets: &AssetCache,
+    project_path: &Path,
+    manifest: &ProjectManifest,
+    build_path: &Path,
+    optimize: bool,
+    force: bool,
+    state: &mut DependencyState,
+) -> anyhow::Result<()> {
+    for (id, dependency) in &manifest.dependencies {
+        let dependency_path = ambient_std::path::normalize(&project_path.join(&dependency.path));
+        if let Some(start) = state.in_progress.iter().position(|path| *path == dependency_path) {
+            anyhow::bail!(
+                "Dependency cycle detected: {} -> {dependency_path:?}",
+                state.in_progress[start..].iter().map(|path| format!("{path:?}")).join(" -> ")
+            );
+        }
+
+        let dependency_manifest_path = dependency_path.join("ambient.toml");
+        let dependency_manifest = ProjectManifest::parse(
+            &tokio::fs::read_to_string(&dependency_manifest_path)
+                .await
+                .with_context(|| format!("No project manifest was found for dependency `{id}` at {dependency_manifest_path:?}"))?,
+        )?;
+        anyhow::ensure!(
+            dependency_manifest.project.id == *id,
+            "The dependency `{id}` refers to the project `{}` at {dependency_path:?}",
+            dependency_manifest.project.id
+        );
+
+        if !state.built.contains(&dependency_path) {
+            build_project(physics.clone(), assets, dependency_path.clone(), &dependency_manifest, optimize, force, state)
+                .await
+                .with_context(|| format!("Failed to build the dependency `{id}`"))?;
+        }
+
+        let output_path = build_path.join("dependencies").join(id.as_ref());
+        if output_path.exists() {
+            std::fs::remove_dir_all(&output_path)?;
+        }
+        for dir in ["client", "server", "dependencies"] {
+            copy_dir(&dependency_path.join("build").join(dir), &output_path.join(dir))?;
+        }
+        std::fs::create_dir_all(&output_path)?;
+        std::fs::copy(&dependency_manifest_path, output_path.join("ambient.toml"))?;
+    }
+
+    Ok(())
+}
+
+fn copy_dir(from: &Path, to: &Path) -> anyhow::Result<()> {
+    for entry in WalkDir::new(from).into_iter().filter_map(|e| e.ok()).filter(|e| e.file_type().is_file()) {
+        let target = to.join(entry.path().strip_prefix(from)?);
+        std::fs::create_dir_all(target.parent().unwrap())?;
+        std::fs::copy(entry.path(), target)?;
+    }
+    Ok(())
+}
+
+/// Returns the outputs that were written or removed, relative to `build_path`.
+async fn build_assets(physics: Physics, assets_path: &Path, build_path: &Path, force: bool) -> Vec<String> {
     let files = WalkDir::new(assets_path)
         .into_iter()
         .filter_map(|e| e.ok())
@@ -74,40 +198,90 @@ async fn build_assets(physics: Physics, assets_path: &Path, build_path: &Path) {
             log::error!("{:?}", err);
             async {}.boxed()
         }),
+        on_read: Arc::new(|_| {}),
     };
     ProcessCtxKey.insert(&ctx.assets, ctx.clone());
-    pipelines::process_pipelines(&ctx).await;
-}
 
-async fn build_rust_if_available(project_path: &Path, manifest: &ProjectManifest, build_path: &Path, optimize: bool) -> anyhow::Result<()> {
-    let cargo_toml_path = project_path.join("Cargo.toml");
-    if !cargo_toml_path.exists() {
-        return Ok(());
+    let previous_build = if force { BuildManifest::default() } else { BuildManifest::load(build_path) };
+    let (_, build_manifest) = pipelines::process_pipelines(&ctx, &previous_build).await;
+
+    let mut changed = vec![];
+    for output in previous_build.stale_outputs(&build_manifest) {
+        let path = build_path.join("assets").join(output);
+        if path.exists() {
+            log::info!("Removing stale output {path:?}");
+            std::fs::remove_file(path).ok();
+        }
+        changed.push(format!("assets/{output}"));
     }
+    changed.extend(previous_build.rebuilt_outputs(&build_manifest).map(|output| format!("assets/{output}")));
 
-    let toml = cargo_toml::Manifest::from_str(&tokio::fs::read_to_string(&cargo_toml_path).await?)?;
-    match toml.package {
-        Some(package) if package.name == manifest.project.id.as_ref() => {}
-        Some(package) => {
-            anyhow::bail!(
-                "The name of the package in the Cargo.toml ({}) does not match the project's ID ({})",
-                package.name,
-                manifest.project.id
-            );
+    let catalog = build_manifest.catalog();
+    if AssetCatalog::load(build_path).ok().as_ref() != Some(&catalog) {
+        match catalog.save(build_path) {
+            Ok(()) => changed.push(AssetCatalog::FILENAME.to_string()),
+            Err(err) => log::warn!("Failed to write the asset catalog: {err:?}"),
+        }
+    }
+
+    if let Err(err) = build_manifest.save(build_path) {
+        log::warn!("Failed to save the build manifest: {err:?}");
+    }
+    changed
+}
+
+/// Returns the modules that were written, relative to `build_path`.
+async fn build_rust_if_available(
+    project_path: &Path,
+    manifest: &ProjectManifest,
+    build_path: &Path,
+    optimize: bool,
+) -> anyhow::Result<Vec<String>> {
+    // The collision layers are turned into constants for the modules, so report invalid ones before building them
+    manifest.physics.collision_layer_masks().context("Invalid collision layers in ambient.toml")?;
+
+    let mut modules = vec![];
+    for (module_id, module) in manifest.modules_in_load_order()? {
+        let module_path = match &module.path {
+            Some(path) => project_path.join(path),
+            None => project_path.to_owned(),
+        };
+        let cargo_toml_path = module_path.join("Cargo.toml");
+        if !cargo_toml_path.exists() {
+            continue;
         }
-        None => anyhow::bail!("No [package] present in Cargo.toml for project {}", manifest.project.id.as_ref()),
+
+        let toml = cargo_toml::Manifest::from_str(&tokio::fs::read_to_string(&cargo_toml_path).await?)?;
+        match toml.package {
+            Some(package) if package.name == module_id.as_ref() => {}
+            Some(package) => {
+                anyhow::bail!(
+                    "The name of the package in {cargo_toml_path:?} ({}) does not match the module's ID ({module_id})",
+                    package.name,
+                );
+            }
+            None => anyhow::bail!("No [package] present in {cargo_toml_path:?} for module {module_id}"),
+        }
+        modules.push((module_id, module_path));
+    }
+    if modules.is_empty() {
+        return Ok(vec![]);
     }
 
     let rustc = ambient_rustc::Rust::get_system_installation().await?;
 
-    for feature in &manifest.build.rust.feature_multibuild {
-        let Some(wasm_bytecode) = rustc.build(project_path, manifest.project.id.as_ref(), optimize, &[feature])? else { continue; };
-        let component_bytecode = ambient_wasm::shared::build::componentize(&wasm_bytecode)?;
+    let mut written = vec![];
+    for (module_id, module_path) in modules {
+        for feature in &manifest.build.rust.feature_multibuild {
+            let Some(wasm_bytecode) = rustc.build(&module_path, module_id.as_ref(), optimize, &[feature])? else { continue; };
+            let component_bytecode = ambient_wasm::shared::build::componentize(&wasm_bytecode)?;
 
-        let output_path = build_path.join(feature);
-        std::fs::create_dir_all(&output_path)?;
-        tokio::fs::write(output_path.join(format!("{}.wasm", manifest.project.id)), component_bytecode).await?;
+            let output_path = build_path.join(feature);
+            std::fs::create_dir_all(&output_path)?;
+            tokio::fs::write(output_path.join(format!("{module_id}.wasm")), component_bytecode).await?;
+            written.push(format!("{feature}/{module_id}.wasm"));
+        }
     }
 
-    Ok(())
+    Ok(written)
 }
@@ -0,0 +1,283 @@
+use std::{
+    io::Cursor,
+    num::{NonZeroU32, NonZeroU8},
+};
+
+use anyhow::Context;
+use symphonia::core::{
+    audio::SampleBuffer, codecs::DecoderOptions, errors::Error as SymphoniaError, formats::FormatOptions, io::MediaSourceStream,
+    meta::MetadataOptions, probe::Hint,
+};
+use vorbis_rs::VorbisEncoderBuilder;
+
+/// The number of zero crossings of the sinc on each side of a sample that [Audio::resample] takes into account
+const RESAMPLE_ZERO_CROSSINGS: f64 = 16.0;
+
+/// Decoded audio, with one buffer of samples per channel.
+#[derive(Debug, Clone)]
+pub struct Audio {
+    pub sample_rate: u32,
+    pub channels: Vec<Vec<f32>>,
+}
+impl Audio {
+    /// Decodes any of the supported formats (WAV, MP3, FLAC and Ogg Vorbis). `extension` is used as a hint for the format.
+    pub fn decode(data: Vec<u8>, extension: &str) -> anyhow::Result<Self> {
+        let mut hint = Hint::new();
+        hint.with_extension(extension);
+        let probed = symphonia::default::get_probe()
+            .format(
+                &hint,
+                MediaSourceStream::new(Box::new(Cursor::new(data)), Default::default()),
+                &FormatOptions::default(),
+                &MetadataOptions::default(),
+            )
+            .context("Unsupported audio format")?;
+        let mut format = probed.format;
+
+        let track = format.default_track().context("The file has no audio track")?;
+        let track_id = track.id;
+        let sample_rate = track.codec_params.sample_rate.context("The audio track has no sample rate")?;
+        let channel_count = track.codec_params.channels.context("The audio track has no channel layout")?.count();
+        let mut decoder = symphonia::default::get_codecs().make(&track.codec_params, &DecoderOptions::default())?;
+
+        let mut channels = vec![Vec::new(); channel_count];
+        loop {
+            let packet = match format.next_packet() {
+                Ok(packet) => packet,
+                Err(SymphoniaError::IoError(err)) if err.kind() == std::io::ErrorKind::UnexpectedEof => break,
+                Err(err) => return Err(err.into()),
+            };
+            if packet.track_id() != track_id {
+                continue;
+            }
+
+            let decoded = match decoder.decode(&packet) {
+                Ok(decoded) => decoded,
+                // Corrupt packets are skipped rather than failing the whole file
+                Err(SymphoniaError::DecodeError(err)) => {
+                    log::warn!("Skipping undecodable audio packet: {err}");
+                    continue;
+                }
+                Err(err) => return Err(err.into()),
+            };
+            let frames = decoded.frames();
+            let mut buffer = SampleBuffer::<f32>::new(decoded.capacity() as u64, *decoded.spec());
+            buffer.copy_planar_ref(decoded);
+            for (channel, samples) in channels.iter_mut().zip(buffer.samples().chunks(frames.max(1))) {
+                channel.extend_from_slice(samples);
+            }
+        }
+
+        Ok(Self { sample_rate, channels })
+    }
+
+    pub fn frames(&self) -> usize {
+        self.channels.first().map(|c| c.len()).unwrap_or_default()
+    }
+
+    /// Mixes every channel into a single one.
+    pub fn downmix_to_mono(&mut self) {
+        if self.channels.len() <= 1 {
+            return;
+        }
+        let scale = 1.0 / self.channels.len() as f32;
+        let mono = (0..self.frames()).map(|i| self.channels.iter().map(|c| c[i]).sum::<f32>() * scale).collect();
+        self.channels = vec![mono];
+    }
+
+    /// Converts to `sample_rate` with windowed sinc interpolation. When downsampling, the frequencies that the new sample
+    /// rate cannot represent are filtered out, so that they don't alias.
+    pub fn resample(&mut self, sample_rate: u32) {
+        if sample_rate == self.sample_rate || self.frames() == 0 {
+            return;
+        }
+        let ratio = self.sample_rate as f64 / sample_rate as f64;
+        // The cutoff frequency of the low-pass filter, relative to the source's Nyquist frequency
+        let cutoff = (1.0 / ratio).min(1.0);
+        let half_width = RESAMPLE_ZERO_CROSSINGS / cutoff;
+        let frames = ((self.frames() as f64) / ratio).round() as usize;
+        for channel in &mut self.channels {
+            *channel = (0..frames)
+                .map(|i| {
+                    let position = i as f64 * ratio;
+                    let first = (position - half_width).ceil().max(0.0) as usize;
+                    let last = ((position + half_width).floor() as usize).min(channel.len() - 1);
+                    (first..=last)
+                        .map(|j| {
+                            let x = j as f64 - position;
+                            channel[j] as f64 * cutoff * sinc(cutoff * x) * blackman(x / half_width)
+                        })
+                        .sum::<f64>() as f32
+                })
+                .collect();
+        }
+        self.sample_rate = sample_rate;
+    }
+
+    /// Scales the audio so that its RMS level is `target_db` decibels relative to full scale, without letting any
+    /// sample clip.
+    pub fn normalize_loudness(&mut self, target_db: f32) {
+        let count = self.channels.iter().map(|c| c.len()).sum::<usize>();
+        if count == 0 {
+            return;
+        }
+        let rms = (self.channels.iter().flatten().map(|s| s * s).sum::<f32>() / count as f32).sqrt();
+        let peak = self.channels.iter().flatten().fold(0.0f32, |peak, s| peak.max(s.abs()));
+        if rms <= f32::EPSILON {
+            return;
+        }
+
+        let gain = (db_to_amplitude(target_db) / rms).min(1.0 / peak);
+        for sample in self.channels.iter_mut().flatten() {
+            *sample *= gain;
+        }
+    }
+
+    /// Removes the leading and trailing frames in which every channel is quieter than `threshold_db` decibels relative
+    /// to full scale.
+    pub fn trim_silence(&mut self, threshold_db: f32) {
+        let threshold = db_to_amplitude(threshold_db);
+        let is_audible = |i: usize| self.channels.iter().any(|c| c[i].abs() > threshold);
+
+        let frames = self.frames();
+        let Some(start) = (0..frames).find(|&i| is_audible(i)) else {
+            for channel in &mut self.channels {
+                channel.clear();
+            }
+            return;
+        };
+        let end = (0..frames).rev().find(|&i| is_audible(i)).unwrap_or(start) + 1;
+        for channel in &mut self.channels {
+            channel.truncate(end);
+            channel.drain(..start);
+        }
+    }
+
+    /// Encodes the audio as Ogg Vorbis.
+    pub fn encode_vorbis(&self) -> anyhow::Result<Vec<u8>> {
+        let sample_rate = NonZeroU32::new(self.sample_rate).context("The sample rate must not be zero")?;
+        let channels = NonZeroU8::new(u8::try_from(self.channels.len()).context("Too many audio channels")?)
+            .context("The audio must have at least one channel")?;
+
+        let mut output = Vec::new();
+        let mut encoder = VorbisEncoderBuilder::new(sample_rate, channels, &mut output)?.build()?;
+        // Encode in blocks to keep the encoder's internal buffers small
+        const BLOCK_SIZE: usize = 4096;
+        for start in (0..self.frames()).step_by(BLOCK_SIZE) {
+            let end = (start + BLOCK_SIZE).min(self.frames());
+            let block: Vec<&[f32]> = self.channels.iter().map(|c| &c[start..end]).collect();
+            encoder.encode_audio_block(&block)?;
+        }
+        encoder.finish()?;
+        Ok(output)
+    }
+}
+
+fn db_to_amplitude(db: f32) -> f32 {
+    10f32.powf(db / 20.0)
+}
+
+fn sinc(x: f64) -> f64 {
+    if x.abs() < 1e-9 {
+        1.0
+    } else {
+        let x = std::f64::consts::PI * x;
+        x.sin() / x
+    }
+}
+
+/// The Blackman window, for `x` in `-1..=1`
+fn blackman(x: f64) -> f64 {
+    let x = std::f64::consts::PI * x;
+    0.42 + 0.5 * x.cos() + 0.08 * (2.0 * x).cos()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sine(frequency: f32, sample_rate: u32, frames: usize) -> Vec<f32> {
+        (0..frames).map(|i| (std::f32::consts::TAU * frequency * i as f32 / sample_rate as f32).sin()).collect()
+    }
+
+    fn rms(samples: &[f32]) -> f32 {
+        (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+    }
+
+    fn max_difference(a: &[f32], b: &[f32]) -> f32 {
+        a.iter().zip(b).map(|(a, b)| (a - b).abs()).fold(0.0, f32::max)
+    }
+
+    #[test]
+    fn downmix_to_mono() {
+        let mut audio = Audio { sample_rate: 44100, channels: vec![vec![1.0, 0.5, 0.0], vec![0.0, 0.5, -1.0]] };
+        audio.downmix_to_mono();
+        assert_eq!(audio.channels, vec![vec![0.5, 0.5, -0.5]]);
+    }
+
+    #[test]
+    fn resample_keeps_audible_frequencies() {
+        let mut audio = Audio { sample_rate: 48000, channels: vec![sine(440.0, 48000, 48000)] };
+        audio.resample(22050);
+        assert_eq!(audio.sample_rate, 22050);
+        assert_eq!(audio.frames(), 22050);
+        // The edges are attenuated as there are no samples beyond them
+        assert!(max_difference(&audio.channels[0][100..21950], &sine(440.0, 22050, 22050)[100..21950]) < 0.01);
+
+        let mut audio = Audio { sample_rate: 22050, channels: vec![sine(1000.0, 22050, 22050)] };
+        audio.resample(44100);
+        assert_eq!(audio.frames(), 44100);
+        assert!(max_difference(&audio.channels[0][100..44000], &sine(1000.0, 44100, 44100)[100..44000]) < 0.01);
+    }
+
+    #[test]
+    fn resample_filters_frequencies_above_nyquist() {
+        // 15 kHz can't be represented at 16 kHz, and would alias to 1 kHz if it wasn't filtered out
+        let mut audio = Audio { sample_rate: 48000, channels: vec![sine(15000.0, 48000, 48000)] };
+        audio.resample(16000);
+        assert_eq!(audio.frames(), 16000);
+        assert!(rms(&audio.channels[0][100..15900]) < 0.01);
+    }
+
+    #[test]
+    fn normalize_loudness() {
+        let mut audio = Audio { sample_rate: 44100, channels: vec![sine(440.0, 44100, 44100).into_iter().map(|s| s * 0.1).collect()] };
+        audio.normalize_loudness(-20.0);
+        assert!((rms(&audio.channels[0]) - db_to_amplitude(-20.0)).abs() < 1e-4);
+
+        // Reaching 0 dB RMS would clip the sine, so it is only brought up to full scale
+        audio.normalize_loudness(0.0);
+        let peak = audio.channels[0].iter().fold(0.0f32, |peak, s| peak.max(s.abs()));
+        assert!((peak - 1.0).abs() < 1e-4);
+
+        // Silence is left alone
+        let mut audio = Audio { sample_rate: 44100, channels: vec![vec![0.0; 16]] };
+        audio.normalize_loudness(-20.0);
+        assert_eq!(audio.channels, vec![vec![0.0; 16]]);
+    }
+
+    #[test]
+    fn trim_silence() {
+        let mut audio = Audio { sample_rate: 44100, channels: vec![vec![0.0, 0.001, 0.5, 0.0, 0.2, 0.0, 0.0], vec![0.0; 7]] };
+        audio.trim_silence(-40.0);
+        assert_eq!(audio.channels, vec![vec![0.5, 0.0, 0.2], vec![0.0; 3]]);
+
+        // A frame is kept if any of its channels is audible
+        let mut audio = Audio { sample_rate: 44100, channels: vec![vec![0.0, 0.0, 0.5], vec![0.0, 0.5, 0.0]] };
+        audio.trim_silence(-40.0);
+        assert_eq!(audio.channels, vec![vec![0.0, 0.5], vec![0.5, 0.0]]);
+
+        let mut audio = Audio { sample_rate: 44100, channels: vec![vec![0.0, 0.001], vec![0.0, 0.0]] };
+        audio.trim_silence(-40.0);
+        assert_eq!(audio.frames(), 0);
+    }
+
+    #[test]
+    fn encode_and_decode_vorbis() {
+        let audio = Audio { sample_rate: 44100, channels: vec![sine(440.0, 44100, 44100), sine(880.0, 44100, 44100)] };
+        let decoded = Audio::decode(audio.encode_vorbis().unwrap(), "ogg").unwrap();
+        assert_eq!(decoded.sample_rate, 44100);
+        assert_eq!(decoded.channels.len(), 2);
+        assert!(decoded.frames().abs_diff(audio.frames()) < 4096);
+    }
+}
@@ -1,117 +1,175 @@
-use std::process::Stdio;
-
-use ambient_std::asset_url::AssetType;
+use ambient_std::asset_url::{AbsAssetUrl, AssetType, AssetUrl};
 use ambient_world_audio::AudioNode;
 use anyhow::Context;
-use futures::FutureExt;
-use tokio::io::{AsyncRead, AsyncReadExt};
+use serde::{Deserialize, Serialize};
 use tracing::{info_span, Instrument};
 
+use self::convert::Audio;
+
 use super::{
     context::PipelineCtx,
     out_asset::{asset_id_from_url, OutAsset, OutAssetContent, OutAssetPreview},
 };
 
+mod convert;
+
 pub const SOUND_GRAPH_EXTENSION: &str = "sgr";
 
-pub async fn pipeline(ctx: &PipelineCtx) -> Vec<OutAsset> {
-    ctx.process_files(
-        |file| matches!(file.extension().as_deref(), Some("ogg") | Some("wav") | Some("mp3")),
-        |ctx, file| async move {
-            let contents = file.download_bytes(ctx.assets()).await?;
+/// The file extensions of the audio formats that can be imported.
+pub const SUPPORTED_EXTENSIONS: &[&str] = &["ogg", "wav", "mp3", "flac"];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AudioPipeline {
+    /// Resample the audio to this sample rate, in Hz. The source's sample rate is kept if not specified.
+    pub sample_rate: Option<u32>,
+    /// Mix all channels down to a single channel.
+    pub downmix_to_mono: bool,
+    /// Scale the audio so that its average (RMS) level is this many decibels relative to full scale, i.e. `-20`.
+    /// The audio is never amplified to the point of clipping.
+    pub normalize_loudness: Option<f32>,
+    /// Remove the silence at the start and end of the audio, treating anything quieter than this many decibels
+    /// relative to full scale as silent, i.e. `-60`.
+    pub trim_silence: Option<f32>,
+}
+impl AudioPipeline {
+    fn is_passthrough(&self) -> bool {
+        self.sample_rate.is_none() && !self.downmix_to_mono && self.normalize_loudness.is_none() && self.trim_silence.is_none()
+    }
 
-            let filename = file.path().file_name().unwrap().to_string();
+    /// Decodes `contents`, applies the processing options and encodes the result as Ogg Vorbis.
+    fn convert(&self, contents: Vec<u8>, extension: &str) -> anyhow::Result<Vec<u8>> {
+        let mut audio = Audio::decode(contents, extension)?;
+        if self.downmix_to_mono {
+            audio.downmix_to_mono();
+        }
+        if let Some(sample_rate) = self.sample_rate {
+            audio.resample(sample_rate);
+        }
+        if let Some(threshold) = self.trim_silence {
+            audio.trim_silence(threshold);
+        }
+        if let Some(target) = self.normalize_loudness {
+            audio.normalize_loudness(target);
+        }
+        audio.encode_vorbis()
+    }
+}
 
-            let rel_path = ctx.in_root().relative_path(file.path());
+pub async fn pipeline(ctx: &PipelineCtx, config: AudioPipeline) -> Vec<OutAsset> {
+    let mut assets = audio_files(ctx, config).await;
+    assets.extend(sound_graphs(ctx).instrument(info_span!("sound_graph_pipeline")).await);
+    assets
+}
 
-            let content_url = match file.extension().as_deref() {
-                Some("ogg") => ctx.write_file(&rel_path, contents).await,
-                ext @ Some("wav" | "mp3") => {
-                    tracing::info!("Processing {ext:?} file");
-                    // Make sure to take the contents, to avoid having both the input and output in
-                    // memory at once
-                    let contents = ffmpeg_convert(std::io::Cursor::new(contents)).await?;
+/// Imports the audio files, and generates a sound graph playing each of them.
+async fn audio_files(ctx: &PipelineCtx, config: AudioPipeline) -> Vec<OutAsset> {
+    ctx.process_files(
+        |file| file.extension().map_or(false, |ext| SUPPORTED_EXTENSIONS.contains(&ext.as_str())),
+        move |ctx, file| {
+            let config = config.clone();
+            async move {
+                let contents = file.download_bytes(ctx.assets()).await?;
+
+                let filename = file.path().file_name().unwrap().to_string();
+
+                let rel_path = ctx.in_root().relative_path(file.path());
+
+                let extension = file.extension().unwrap_or_default();
+                let content_url = if extension == "ogg" && config.is_passthrough() {
+                    ctx.write_file(&rel_path, contents).await
+                } else {
+                    tracing::info!("Converting {extension:?} file");
+                    let contents = tokio::task::block_in_place(|| config.convert(contents, &extension))
+                        .with_context(|| format!("Failed to convert {file}"))?;
                     ctx.write_file(rel_path.with_extension("ogg"), contents).await
-                }
-                other => anyhow::bail!("Audio filetype {:?} is not yet supported", other.unwrap_or_default()),
-            };
-
-            let root_node = AudioNode::Vorbis { url: content_url.to_string() };
-            let graph_url = ctx.write_file(&rel_path.with_extension("SOUND_GRAPH_EXTENSION"), save_audio_graph(root_node).unwrap()).await;
-
-            Ok(vec![
-                OutAsset {
-                    id: asset_id_from_url(&file),
-                    type_: AssetType::VorbisTrack,
-                    hidden: false,
-                    name: filename.clone(),
-                    tags: Vec::new(),
-                    categories: Default::default(),
-                    preview: OutAssetPreview::None,
-                    content: OutAssetContent::Content(content_url),
-                    source: Some(file.clone()),
-                },
-                OutAsset {
-                    id: asset_id_from_url(&file.push("graph").unwrap()),
-                    type_: AssetType::SoundGraph,
-                    hidden: false,
-                    name: filename,
-                    tags: Vec::new(),
-                    categories: Default::default(),
-                    preview: OutAssetPreview::None,
-                    content: OutAssetContent::Content(graph_url),
-                    source: None,
-                },
-            ])
+                };
+
+                let root_node = AudioNode::Vorbis { url: content_url.to_string() };
+                let graph_url = ctx.write_file(&rel_path.with_extension(SOUND_GRAPH_EXTENSION), save_audio_graph(root_node).unwrap()).await;
+
+                Ok(vec![
+                    OutAsset {
+                        id: asset_id_from_url(&file),
+                        type_: AssetType::VorbisTrack,
+                        hidden: false,
+                        name: filename.clone(),
+                        tags: Vec::new(),
+                        categories: Default::default(),
+                        preview: OutAssetPreview::None,
+                        content: OutAssetContent::Content(content_url),
+                        source: Some(file.clone()),
+                    },
+                    OutAsset {
+                        id: asset_id_from_url(&file.push("graph").unwrap()),
+                        type_: AssetType::SoundGraph,
+                        hidden: false,
+                        name: filename,
+                        tags: Vec::new(),
+                        categories: Default::default(),
+                        preview: OutAssetPreview::None,
+                        content: OutAssetContent::Content(graph_url),
+                        source: None,
+                    },
+                ])
+            }
         },
     )
     .instrument(info_span!("audio_pipeline"))
     .await
 }
 
-fn save_audio_graph(root: AudioNode) -> anyhow::Result<Vec<u8>> {
-    Ok(serde_json::to_string_pretty(&root).context("Invalid sound graph")?.into_bytes())
+/// Validates the sound graphs authored as JSON, and points their relative urls at the audio files this pipeline outputs.
+async fn sound_graphs(ctx: &PipelineCtx) -> Vec<OutAsset> {
+    ctx.process_files(
+        |file| file.extension_is(SOUND_GRAPH_EXTENSION),
+        |ctx, file| async move {
+            let contents = file.download_bytes(ctx.assets()).await?;
+            let mut graph: AudioNode = serde_json::from_slice(&contents).with_context(|| format!("Invalid sound graph {file}"))?;
+            graph.validate().with_context(|| format!("Invalid sound graph {file}"))?;
+            resolve_urls(&ctx, &file, &mut graph)?;
+
+            let rel_path = ctx.in_root().relative_path(file.path());
+            let graph_url = ctx.write_file(&rel_path, save_audio_graph(graph)?).await;
+
+            Ok(vec![OutAsset {
+                id: asset_id_from_url(&file),
+                type_: AssetType::SoundGraph,
+                hidden: false,
+                name: file.path().file_name().unwrap().to_string(),
+                tags: Vec::new(),
+                categories: Default::default(),
+                preview: OutAssetPreview::None,
+                content: OutAssetContent::Content(graph_url),
+                source: Some(file.clone()),
+            }])
+        },
+    )
+    .await
 }
 
-#[tracing::instrument(level = "info", skip(input))]
-async fn ffmpeg_convert<A>(input: A) -> anyhow::Result<Vec<u8>>
-where
-    A: 'static + Send + AsyncRead,
-{
-    let mut child = tokio::process::Command::new("ffmpeg")
-        .args(["-i", "pipe:", "-f", "ogg", "pipe:1"])
-        .stdin(Stdio::piped())
-        .stdout(Stdio::piped())
-        .spawn()
-        .context("Failed to execute ffmpeg")?;
-
-    tracing::info!("Writing to stdin");
-
-    let mut stdin = child.stdin.take().expect("no stdin");
-    let mut stdout = child.stdout.take().expect("no stdout");
-
-    let input = tokio::task::spawn(async move {
-        tokio::pin!(input);
-        tokio::io::copy(&mut input, &mut stdin).await.context("Failed to write to stdin")
-    })
-    .map(|v| v.unwrap());
-
-    let output = async move {
-        let mut output = Vec::new();
-        stdout.read_to_end(&mut output).await.unwrap();
-        Ok(output)
-    };
-
-    let status = async { child.wait().await.context("Failed to wait for ffmpeg") };
-
-    tracing::info!("Waiting for ffmpeg to complete");
-    let (_, output, status) = tokio::try_join!(input, output, status)?;
-
-    if !status.success() {
-        anyhow::bail!("FFMPEG conversion failed")
+/// Replaces the relative `Vorbis` urls of `node`, which are relative to the graph `file`, with the urls of the
+/// Ogg Vorbis files this pipeline produces for them. Absolute urls are kept as they are.
+fn resolve_urls(ctx: &PipelineCtx, file: &AbsAssetUrl, node: &mut AudioNode) -> anyhow::Result<()> {
+    if let AudioNode::Vorbis { url } = node {
+        if let AssetUrl::Relative(path) = AssetUrl::parse(&*url)? {
+            let source = file.resolve(path.as_str())?;
+            ctx.get_downloadable_url(&source).with_context(|| format!("Sound graph {file} refers to a missing file"))?;
+            let rel_path = ctx.in_root().relative_path(source.path());
+            anyhow::ensure!(
+                !rel_path.as_str().starts_with(".."),
+                "Sound graph {file} refers to {source}, which is outside of the pipeline"
+            );
+            anyhow::ensure!(
+                source.extension().map_or(false, |ext| SUPPORTED_EXTENSIONS.contains(&ext.as_str())),
+                "Sound graph {file} refers to {source}, which is not a supported audio file"
+            );
+            *url = ctx.out_root().push(rel_path.with_extension("ogg"))?.to_string();
+        }
     }
+    node.inputs_mut().into_iter().try_for_each(|input| resolve_urls(ctx, file, input))
+}
 
-    tracing::info!("Converted to vorbis of {} kb", output.len() as f32 / 1000.0);
-
-    Ok(output)
+fn save_audio_graph(root: AudioNode) -> anyhow::Result<Vec<u8>> {
+    Ok(serde_json::to_string_pretty(&root).context("Invalid sound graph")?.into_bytes())
 }
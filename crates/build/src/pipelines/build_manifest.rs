@@ -0,0 +1,249 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    path::Path,
+};
+
+use ambient_std::{
+    asset_cache::AssetCache,
+    asset_catalog::{AssetCatalog, CatalogAsset},
+    asset_url::AbsAssetUrl,
+};
+use data_encoding::HEXLOWER;
+use futures::StreamExt;
+use ring::digest::{digest, Context as DigestContext, SHA256};
+use serde::{Deserialize, Serialize};
+
+use super::{FileCollection, Pipeline};
+
+/// Records what each pipeline consumed and produced in a build, so that the next build can skip
+/// pipelines whose configuration and inputs have not changed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BuildManifest {
+    /// Keyed by [super::context::PipelineCtx::pipeline_path].
+    pub pipelines: BTreeMap<String, PipelineRecord>,
+}
+impl BuildManifest {
+    pub const FILENAME: &'static str = "build_manifest.json";
+
+    /// Loads the manifest from `build_path`. A missing or unreadable manifest is treated as empty,
+    /// which results in a full build.
+    pub fn load(build_path: &Path) -> Self {
+        std::fs::read(build_path.join(Self::FILENAME)).ok().and_then(|data| serde_json::from_slice(&data).ok()).unwrap_or_default()
+    }
+
+    pub fn save(&self, build_path: &Path) -> anyhow::Result<()> {
+        std::fs::write(build_path.join(Self::FILENAME), serde_json::to_vec_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Returns the catalog of every asset produced by this build, ordered by id.
+    pub fn catalog(&self) -> AssetCatalog {
+        let mut assets: Vec<_> = self.pipelines.values().flat_map(|p| p.assets.iter()).cloned().collect();
+        assets.sort_by(|a, b| a.id.cmp(&b.id));
+        AssetCatalog { assets }
+    }
+
+    /// Returns the outputs of `self` that are no longer produced by `next`.
+    pub fn stale_outputs<'a>(&'a self, next: &BuildManifest) -> impl Iterator<Item = &'a String> {
+        let next_outputs: HashSet<_> = next.pipelines.values().flat_map(|p| p.outputs.iter()).cloned().collect();
+        self.pipelines.values().flat_map(|p| p.outputs.iter()).filter(move |output| !next_outputs.contains(*output))
+    }
+
+    /// Returns the outputs of `next` written by pipelines that were rerun since `self` was built.
+    pub fn rebuilt_outputs<'a>(&'a self, next: &'a BuildManifest) -> impl Iterator<Item = &'a String> {
+        next.pipelines.iter().filter(move |(key, record)| self.pipelines.get(*key) != Some(record)).flat_map(|(_, p)| p.outputs.iter())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PipelineRecord {
+    /// Hash of the pipeline's configuration and the version of Ambient that ran it
+    pub config_hash: String,
+    /// Hashes of every file in the pipeline's directory, and of the files outside of it that it read,
+    /// keyed by path relative to the input root
+    pub inputs: BTreeMap<String, String>,
+    /// Files written by the pipeline, relative to the output root
+    pub outputs: Vec<String>,
+    /// The catalog entries of the assets produced by the pipeline, kept so that they are not lost when it is skipped
+    #[serde(default)]
+    pub assets: Vec<CatalogAsset>,
+    /// Whether the pipeline reported errors. The outputs of a failed run are still recorded so that they can be cleaned up,
+    /// but the pipeline is rerun by the next build.
+    #[serde(default)]
+    pub failed: bool,
+}
+impl PipelineRecord {
+    /// Whether the pipeline can be skipped: it succeeded with the same configuration and inputs, and all of its outputs,
+    /// as checked by `output_exists`, are still there.
+    pub fn is_up_to_date(&self, config_hash: &str, inputs: &BTreeMap<String, String>, output_exists: impl Fn(&str) -> bool) -> bool {
+        !self.failed && self.config_hash == config_hash && &self.inputs == inputs && self.outputs.iter().all(|output| output_exists(output))
+    }
+}
+
+/// Adds the current hashes of `paths`, relative to the input root, to `inputs`. Paths whose files are gone are left out,
+/// so that the inputs no longer match those of a record that read them.
+pub fn add_inputs<'a>(
+    inputs: &mut BTreeMap<String, String>,
+    paths: impl IntoIterator<Item = &'a String>,
+    hashes: &HashMap<String, String>,
+) {
+    for path in paths {
+        if let Some(hash) = hashes.get(path) {
+            inputs.entry(path.clone()).or_insert_with(|| hash.clone());
+        }
+    }
+}
+
+/// Hashes the configuration of `pipeline`, and the bytecode of its `processor` for custom pipelines.
+pub fn hash_pipeline_config(pipeline: &Pipeline, processor: Option<&[u8]>) -> String {
+    let mut context = DigestContext::new(&SHA256);
+    context.update(env!("CARGO_PKG_VERSION").as_bytes());
+    context.update(&serde_json::to_vec(pipeline).unwrap());
+    if let Some(processor) = processor {
+        context.update(processor);
+    }
+    HEXLOWER.encode(context.finish().as_ref())
+}
+
+/// Hashes the contents of every file in `files`. Files that cannot be read are left out.
+pub async fn hash_files(assets: &AssetCache, files: &FileCollection) -> HashMap<AbsAssetUrl, String> {
+    futures::stream::iter(files.0.iter().cloned())
+        .map(|file| async move {
+            let data = match file.to_file_path() {
+                Ok(Some(path)) => tokio::fs::read(path).await.ok()?,
+                _ => file.download_bytes(assets).await.ok()?,
+            };
+            Some((file, HEXLOWER.encode(digest(&SHA256, &data).as_ref())))
+        })
+        .buffer_unordered(32)
+        .filter_map(|x| async move { x })
+        .collect()
+        .await
+}
+
+#[cfg(test)]
+mod test {
+    use ambient_std::asset_catalog::AssetType;
+
+    use super::*;
+
+    fn record(outputs: &[&str]) -> PipelineRecord {
+        PipelineRecord {
+            config_hash: "config".to_string(),
+            inputs: BTreeMap::from([("a.glb".to_string(), "hash".to_string())]),
+            outputs: outputs.iter().map(|o| o.to_string()).collect(),
+            assets: vec![],
+            failed: false,
+        }
+    }
+
+    fn catalog_asset(id: &str) -> CatalogAsset {
+        CatalogAsset {
+            id: id.to_string(),
+            type_: AssetType::Prefab,
+            name: id.to_string(),
+            hidden: false,
+            tags: vec![],
+            categories: vec![],
+            content: Some(format!("assets/{id}/prefabs/main.json")),
+            collection: vec![],
+            source: None,
+            preview: None,
+        }
+    }
+
+    #[test]
+    fn is_up_to_date() {
+        let record = record(&["a/model.json"]);
+        let inputs = record.inputs.clone();
+        assert!(record.is_up_to_date("config", &inputs, |_| true));
+        assert!(!record.is_up_to_date("other config", &inputs, |_| true));
+        assert!(!record.is_up_to_date("config", &BTreeMap::from([("a.glb".to_string(), "changed".to_string())]), |_| true));
+        assert!(!record.is_up_to_date("config", &BTreeMap::new(), |_| true));
+        assert!(!record.is_up_to_date("config", &inputs, |output| output != "a/model.json"));
+        assert!(!PipelineRecord { failed: true, ..record }.is_up_to_date("config", &inputs, |_| true));
+    }
+
+    #[test]
+    fn files_read_outside_of_the_pipeline_are_inputs() {
+        let hashes = HashMap::from([
+            ("a.glb".to_string(), "hash".to_string()),
+            ("../shared/wood.png".to_string(), "wood".to_string()),
+            ("../shared/unread.png".to_string(), "unread".to_string()),
+        ]);
+        let mut record = record(&[]);
+        add_inputs(&mut record.inputs, &["../shared/wood.png".to_string()], &hashes);
+        assert_eq!(record.inputs.len(), 2);
+
+        let inputs_with = |hashes: &HashMap<String, String>| {
+            let mut inputs = BTreeMap::from([("a.glb".to_string(), "hash".to_string())]);
+            add_inputs(&mut inputs, record.inputs.keys(), hashes);
+            inputs
+        };
+        assert!(record.is_up_to_date("config", &inputs_with(&hashes), |_| true));
+        let changed = HashMap::from([("a.glb".to_string(), "hash".to_string()), ("../shared/wood.png".to_string(), "oak".to_string())]);
+        assert!(!record.is_up_to_date("config", &inputs_with(&changed), |_| true));
+        let removed = HashMap::from([("a.glb".to_string(), "hash".to_string())]);
+        assert!(!record.is_up_to_date("config", &inputs_with(&removed), |_| true));
+    }
+
+    #[test]
+    fn stale_and_rebuilt_outputs() {
+        let previous = BuildManifest {
+            pipelines: BTreeMap::from([
+                ("models/pipeline.json/0".to_string(), record(&["a.json", "b.json"])),
+                ("removed/pipeline.json/0".to_string(), record(&["c.json"])),
+                ("unchanged/pipeline.json/0".to_string(), record(&["d.json"])),
+            ]),
+        };
+        let next = BuildManifest {
+            pipelines: BTreeMap::from([
+                ("models/pipeline.json/0".to_string(), PipelineRecord { config_hash: "new".to_string(), ..record(&["a.json", "e.json"]) }),
+                ("unchanged/pipeline.json/0".to_string(), record(&["d.json"])),
+            ]),
+        };
+
+        let mut stale: Vec<_> = previous.stale_outputs(&next).cloned().collect();
+        stale.sort();
+        assert_eq!(stale, vec!["b.json", "c.json"]);
+        let rebuilt: Vec<_> = previous.rebuilt_outputs(&next).cloned().collect();
+        assert_eq!(rebuilt, vec!["a.json", "e.json"]);
+    }
+
+    #[test]
+    fn catalog_is_sorted_by_id() {
+        let manifest = BuildManifest {
+            pipelines: BTreeMap::from([
+                ("a/pipeline.json/0".to_string(), PipelineRecord { assets: vec![catalog_asset("z"), catalog_asset("m")], ..record(&[]) }),
+                ("b/pipeline.json/0".to_string(), PipelineRecord { assets: vec![catalog_asset("a")], ..record(&[]) }),
+            ]),
+        };
+        let ids: Vec<_> = manifest.catalog().assets.into_iter().map(|a| a.id).collect();
+        assert_eq!(ids, vec!["a", "m", "z"]);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let build_path = std::env::temp_dir().join(format!("ambient_build_manifest_{}", std::process::id()));
+        std::fs::create_dir_all(&build_path).unwrap();
+        assert!(BuildManifest::load(&build_path).pipelines.is_empty());
+
+        let manifest = BuildManifest { pipelines: BTreeMap::from([("a/pipeline.json/0".to_string(), record(&["a.json"]))]) };
+        manifest.save(&build_path).unwrap();
+        assert_eq!(BuildManifest::load(&build_path).pipelines, manifest.pipelines);
+
+        // A corrupt manifest results in a full build
+        std::fs::write(build_path.join(BuildManifest::FILENAME), "{").unwrap();
+        assert!(BuildManifest::load(&build_path).pipelines.is_empty());
+        std::fs::remove_dir_all(&build_path).ok();
+    }
+
+    #[test]
+    fn processor_is_part_of_the_config_hash() {
+        let pipeline: Pipeline = serde_json::from_str(r#"{ "pipeline": { "type": "Custom", "processor": "../processors/csv" } }"#).unwrap();
+        let hash = hash_pipeline_config(&pipeline, Some(b"processor"));
+        assert_eq!(hash, hash_pipeline_config(&pipeline, Some(b"processor")));
+        assert_ne!(hash, hash_pipeline_config(&pipeline, Some(b"changed processor")));
+        assert_ne!(hash, hash_pipeline_config(&pipeline, None));
+    }
+}
@@ -66,16 +66,12 @@ impl PipelineCtx {
         (self.process_ctx.on_error)(err).await;
         Vec::new()
     }
-    pub async fn process_files<F: Future<Output = anyhow::Result<Vec<OutAsset>>> + Send>(
-        &self,
-        filter: impl Fn(&AbsAssetUrl) -> bool,
-        process_file: impl Fn(PipelineCtx, AbsAssetUrl) -> F + Sync + Send + 'static,
-    ) -> Vec<OutAsset> {
+    /// The files of this pipeline that match its `sources` and `filter`.
+    pub fn filtered_files(&self, filter: impl Fn(&AbsAssetUrl) -> bool) -> Vec<AbsAssetUrl> {
         let sources_filter =
             self.pipeline.sources.iter().map(|p| glob::Pattern::new(p)).collect::<Result<Vec<_>, glob::PatternError>>().unwrap();
         let opt_filter = self.process_ctx.input_file_filter.as_ref().and_then(|x| glob::Pattern::new(x).ok());
-        let files = self
-            .files
+        self.files
             .0
             .iter()
             .filter(move |file| {
@@ -97,7 +93,14 @@ impl PipelineCtx {
             })
             .filter(|f| filter(f))
             .cloned()
-            .collect_vec();
+            .collect_vec()
+    }
+    pub async fn process_files<F: Future<Output = anyhow::Result<Vec<OutAsset>>> + Send>(
+        &self,
+        filter: impl Fn(&AbsAssetUrl) -> bool,
+        process_file: impl Fn(PipelineCtx, AbsAssetUrl) -> F + Sync + Send + 'static,
+    ) -> Vec<OutAsset> {
+        let files = self.filtered_files(filter);
         let n_files = files.len();
         let process_file = Arc::new(process_file);
         let semaphore = PipelineFileSemaphore.get(&self.process_ctx.assets);
@@ -141,8 +144,11 @@ impl PipelineCtx {
         .flatten()
         .collect()
     }
+    /// Finds the input file at `url`, which can be outside of this pipeline's directory, and records that the pipeline reads it
     pub fn get_downloadable_url(&self, url: &AbsAssetUrl) -> anyhow::Result<&AbsAssetUrl> {
-        self.process_ctx.files.0.iter().find(|x| x.path() == url.path()).with_context(|| format!("No such file: {url}"))
+        let file = self.process_ctx.files.0.iter().find(|x| x.path() == url.path()).with_context(|| format!("No such file: {url}"))?;
+        (self.process_ctx.on_read)(file);
+        Ok(file)
     }
 }
 
@@ -0,0 +1,159 @@
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
+
+use ambient_asset_cache::{AssetCache, AsyncAssetKey, AsyncAssetKeyExt};
+use ambient_std::{
+    asset_url::{AbsAssetUrl, AssetType},
+    download_asset::AssetResult,
+};
+use ambient_wasm::shared::pipeline::{pipeline_host, run_processor, ProcessorLimits};
+use anyhow::Context;
+use async_trait::async_trait;
+use relative_path::RelativePathBuf;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    context::PipelineCtx,
+    out_asset::{asset_id_from_url, OutAsset, OutAssetContent, OutAssetPreview},
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomPipeline {
+    /// The path of the Rust package of the processor, relative to this pipeline, i.e. `../processors/csv`.
+    /// It is built for WASM and must declare its processing function with `ambient_pipeline_api::processor!`.
+    pub processor: String,
+    /// Passed to the processor as a JSON string.
+    #[serde(default)]
+    pub config: serde_json::Value,
+}
+
+pub async fn pipeline(ctx: &PipelineCtx, config: CustomPipeline) -> Vec<OutAsset> {
+    ctx.process_single(move |ctx| async move {
+        let processor = build_processor(&ctx, &config).await?;
+        let host = ProcessorHost {
+            files: ctx.filtered_files(|_| true),
+            written: HashMap::new(),
+            assets: Vec::new(),
+            runtime: tokio::runtime::Handle::current(),
+            ctx,
+        };
+        let name = &processor.name;
+        let host = tokio::task::block_in_place(|| {
+            run_processor(name, &processor.bytecode, host, &config.config.to_string(), ProcessorLimits::default())
+        })
+        .with_context(|| format!("The processor {name} failed"))?;
+        Ok(host.assets)
+    })
+    .await
+}
+
+/// A processor, built for WASM.
+#[derive(Debug)]
+pub struct BuiltProcessor {
+    /// The name of the processor's package.
+    pub name: String,
+    /// The bytecode of the processor's component.
+    pub bytecode: Vec<u8>,
+}
+
+/// Builds the processor of `config`. The processor is only built once per build, so this can be called again for the
+/// same pipeline.
+pub async fn build_processor(ctx: &PipelineCtx, config: &CustomPipeline) -> anyhow::Result<Arc<BuiltProcessor>> {
+    let path = ctx.in_root().push(&config.processor)?.to_file_path()?.context("Processors can only be built from local files")?;
+    Ok(ProcessorFromPath(path).get(ctx.assets()).await?)
+}
+
+/// Builds the processor package at the path. Returns its name and component bytecode.
+#[derive(Debug, Clone)]
+struct ProcessorFromPath(PathBuf);
+#[async_trait]
+impl AsyncAssetKey<AssetResult<Arc<BuiltProcessor>>> for ProcessorFromPath {
+    async fn load(self, _assets: AssetCache) -> AssetResult<Arc<BuiltProcessor>> {
+        let path = self.0;
+        let cargo_toml_path = path.join("Cargo.toml");
+        let toml = cargo_toml::Manifest::from_str(
+            &tokio::fs::read_to_string(&cargo_toml_path).await.with_context(|| format!("No processor package was found at {path:?}"))?,
+        )
+        .context("Invalid processor package")?;
+        let package = toml.package.with_context(|| format!("No [package] present in {cargo_toml_path:?}"))?;
+
+        let rustc = ambient_rustc::Rust::get_system_installation().await?;
+        let wasm_bytecode = tokio::task::block_in_place(|| rustc.build(&path, &package.name, false, &[]))?
+            .with_context(|| format!("The processor {} did not produce a WASM binary", package.name))?;
+        let bytecode = ambient_wasm::shared::build::componentize(&wasm_bytecode)?;
+        Ok(Arc::new(BuiltProcessor { name: package.name, bytecode }))
+    }
+}
+
+/// Gives a processor access to the input files of its pipeline, and collects what it writes.
+struct ProcessorHost {
+    ctx: PipelineCtx,
+    runtime: tokio::runtime::Handle,
+    files: Vec<AbsAssetUrl>,
+    written: HashMap<RelativePathBuf, AbsAssetUrl>,
+    assets: Vec<OutAsset>,
+}
+impl ProcessorHost {
+    fn input_file(&self, path: &str) -> Result<AbsAssetUrl, String> {
+        let url = self.ctx.in_root().push(path).map_err(|err| err.to_string())?;
+        if self.files.contains(&url) {
+            Ok(url)
+        } else {
+            Err(format!("`{path}` is not an input of this pipeline"))
+        }
+    }
+}
+impl pipeline_host::Host for ProcessorHost {
+    fn files(&mut self) -> anyhow::Result<Vec<String>> {
+        Ok(self.files.iter().map(|file| self.ctx.in_root().relative_path(file.path()).to_string()).collect())
+    }
+
+    fn read_file(&mut self, path: String) -> anyhow::Result<Result<Vec<u8>, String>> {
+        let url = match self.input_file(&path) {
+            Ok(url) => url,
+            Err(err) => return Ok(Err(err)),
+        };
+        Ok(self.runtime.block_on(url.download_bytes(self.ctx.assets())).map_err(|err| format!("{err:#}")))
+    }
+
+    fn write_file(&mut self, path: String, contents: Vec<u8>) -> anyhow::Result<Result<(), String>> {
+        let path = RelativePathBuf::from(path).normalize();
+        if path.as_str().starts_with("..") {
+            return Ok(Err(format!("`{path}` is outside of the output folder of this pipeline")));
+        }
+        let url = self.runtime.block_on(self.ctx.write_file(path.as_str(), contents));
+        self.written.insert(path, url);
+        Ok(Ok(()))
+    }
+
+    fn add_asset(
+        &mut self,
+        path: String,
+        asset_type: String,
+        name: String,
+        tags: Vec<String>,
+        source: Option<String>,
+    ) -> anyhow::Result<Result<(), String>> {
+        let Some(content) = self.written.get(&RelativePathBuf::from(&path).normalize()).cloned() else {
+            return Ok(Err(format!("`{path}` has not been written by this processor")));
+        };
+        let Some(type_) = AssetType::from_snake_case(&asset_type) else {
+            return Ok(Err(format!("Unknown asset type `{asset_type}`")));
+        };
+        let source = match source.map(|source| self.input_file(&source)).transpose() {
+            Ok(source) => source,
+            Err(err) => return Ok(Err(err)),
+        };
+        self.assets.push(OutAsset {
+            id: asset_id_from_url(&content),
+            type_,
+            hidden: false,
+            name,
+            tags,
+            categories: Default::default(),
+            preview: OutAssetPreview::None,
+            content: OutAssetContent::Content(content),
+            source,
+        });
+        Ok(Ok(()))
+    }
+}
@@ -12,6 +12,7 @@ use ambient_renderer::materials::pbr_material::PbrMaterialDesc;
 use ambient_std::{
     asset_url::{AbsAssetUrl, AssetType, AssetUrl},
     download_asset::AssetResult,
+    ktx2::Ktx2,
 };
 use anyhow::Context;
 use async_trait::async_trait;
@@ -24,6 +25,7 @@ use serde::{Deserialize, Serialize};
 use super::{
     context::PipelineCtx,
     out_asset::{asset_id_from_url, OutAsset, OutAssetContent, OutAssetPreview},
+    textures::{self, encode_texture, TextureCompression, TextureRole},
     ProcessCtxKey,
 };
 use crate::pipelines::download_image;
@@ -47,17 +49,21 @@ pub struct MaterialsPipeline {
     /// Whether or not decal prefabs should be created for each of these materials.
     #[serde(default)]
     pub output_decals: bool,
+    /// If specified, the textures of these materials will be compressed, and have their mipmaps generated, at build time.
+    pub compress_textures: Option<TextureCompression>,
 }
 
 pub async fn pipeline(ctx: &PipelineCtx, config: MaterialsPipeline) -> Vec<OutAsset> {
     let materials = match *config.importer.clone() {
         MaterialsImporter::Single(mat) => {
+            let compression = config.compress_textures.clone();
             ctx.process_single(move |ctx| async move {
                 let name = mat.name.as_ref().or(mat.source.as_ref()).unwrap().to_string();
 
                 let mat_out_url = ctx.out_root().join(ctx.pipeline_path())?.as_directory();
-                let material = mat.to_mat(&ctx, &ctx.in_root(), &mat_out_url).await?;
-                let base_color_url = material.base_color.clone().unwrap().resolve(&mat_out_url).unwrap();
+                let material = mat.to_mat(&ctx, &ctx.in_root(), &mat_out_url, compression.as_ref()).await?;
+                // The preview is made from the source image, as the output may be a compressed texture
+                let base_color_url = ctx.get_downloadable_url(&mat.base_color.clone().unwrap().resolve(&ctx.in_root())?)?.clone();
                 let base_color = ImageFromUrl { url: base_color_url }.get(ctx.assets()).await?;
                 let mat_url = ctx.write_file(ctx.pipeline_path().join("mat.json"), serde_json::to_vec(&material).unwrap()).await;
                 Ok(vec![OutAsset {
@@ -151,28 +157,55 @@ pub struct PipelinePbrMaterial {
     pub specular_exponent: Option<f32>,
 }
 impl PipelinePbrMaterial {
-    pub async fn to_mat(&self, ctx: &PipelineCtx, source_root: &AbsAssetUrl, out_root: &AbsAssetUrl) -> anyhow::Result<PbrMaterialDesc> {
-        let pipe_image = |path: &Option<AssetUrl>| -> BoxFuture<'_, anyhow::Result<Option<AssetUrl>>> {
+    /// Processes the textures of this material, and writes them to the output.
+    ///
+    /// If `compression` is specified, the textures are written as compressed KTX2 files, and the opacity map is merged
+    /// into the alpha channel of the base color.
+    pub async fn to_mat(
+        &self,
+        ctx: &PipelineCtx,
+        source_root: &AbsAssetUrl,
+        out_root: &AbsAssetUrl,
+        compression: Option<&TextureCompression>,
+    ) -> anyhow::Result<PbrMaterialDesc> {
+        let pipe_image = |path: &Option<AssetUrl>, role: Option<TextureRole>| -> BoxFuture<'_, anyhow::Result<Option<AssetUrl>>> {
             let source_root = source_root.clone();
             let path = path.clone();
             let ctx = ctx.clone();
+            let compression = role.zip(compression.cloned());
             async move {
                 if let Some(path) = path {
-                    Ok(Some(AssetUrl::from(PipeImage::resolve(&ctx, path.resolve(&source_root).unwrap()).get(ctx.assets()).await?)))
+                    let image = PipeImage::resolve(&ctx, path.resolve(&source_root).unwrap()).compress(compression);
+                    Ok(Some(AssetUrl::from(image.get(ctx.assets()).await?)))
                 } else {
                     Ok(None)
                 }
             }
             .boxed()
         };
+        let merge_opacity = compression.is_some() && self.base_color.is_some() && self.opacity.is_some();
         Ok(PbrMaterialDesc {
             name: self.name.clone(),
             source: self.source.clone(),
-            base_color: pipe_image(&self.base_color).await?,
-            opacity: pipe_image(&self.opacity).await?,
-            normalmap: pipe_image(&self.normalmap).await?,
+            base_color: if merge_opacity {
+                let opacity = ctx.get_downloadable_url(&self.opacity.as_ref().unwrap().resolve(source_root)?)?.clone();
+                Some(
+                    PipeImage::resolve(ctx, self.base_color.as_ref().unwrap().resolve(source_root)?)
+                        .second_source(opacity)
+                        .transform("opacity", |image, opacity| textures::merge_opacity(image, opacity.unwrap()))
+                        .compress(compression.map(|compression| (TextureRole::Color, compression.clone())))
+                        .get(ctx.assets())
+                        .await?
+                        .into(),
+                )
+            } else {
+                pipe_image(&self.base_color, Some(TextureRole::Color)).await?
+            },
+            // Opacity maps are only ever loaded as images, as they're combined with the base color at runtime
+            opacity: if merge_opacity { None } else { pipe_image(&self.opacity, None).await? },
+            normalmap: pipe_image(&self.normalmap, Some(TextureRole::Normal)).await?,
             metallic_roughness: if let Some(url) = &self.metallic_roughness {
-                Some(PipeImage::resolve(ctx, url.resolve(source_root).unwrap()).get(ctx.assets()).await?.into())
+                pipe_image(&Some(url.clone()), Some(TextureRole::MetallicRoughness)).await?
             } else if let Some(specular) = &self.specular {
                 let specular_exponent = self.specular_exponent.unwrap_or(1.);
                 Some(
@@ -186,6 +219,7 @@ impl PipelinePbrMaterial {
                                 p[3] = 255;
                             }
                         })
+                        .compress(compression.map(|compression| (TextureRole::MetallicRoughness, compression.clone())))
                         .get(ctx.assets())
                         .await?
                         .into(),
@@ -245,13 +279,19 @@ pub struct PipeImage {
     second_source: Option<AbsAssetUrl>,
     transform: Option<Box<dyn ImageTransformer>>,
     cap_texture_sizes: Option<ModelTextureSize>,
+    compress: Option<(TextureRole, TextureCompression)>,
 }
 impl PipeImage {
     pub fn resolve(ctx: &PipelineCtx, source: AbsAssetUrl) -> Self {
         Self::new(ctx.get_downloadable_url(&source).unwrap().clone())
     }
     pub fn new(source: AbsAssetUrl) -> Self {
-        PipeImage { source, second_source: None, transform: None, cap_texture_sizes: None }
+        PipeImage { source, second_source: None, transform: None, cap_texture_sizes: None, compress: None }
+    }
+    /// An image that is passed to the transform along with the source image.
+    pub fn second_source(mut self, second_source: AbsAssetUrl) -> Self {
+        self.second_source = Some(second_source);
+        self
     }
     pub fn transform<F: Fn(&mut RgbaImage, Option<&RgbaImage>) + Sync + Send + 'static>(
         mut self,
@@ -265,6 +305,11 @@ impl PipeImage {
         self.cap_texture_sizes = cap_texture_sizes;
         self
     }
+    /// Writes the image as a compressed KTX2 texture, with mipmaps, instead of as a PNG.
+    pub fn compress(mut self, compress: Option<(TextureRole, TextureCompression)>) -> Self {
+        self.compress = compress;
+        self
+    }
 }
 #[async_trait]
 impl AsyncAssetKey<AssetResult<Arc<AbsAssetUrl>>> for PipeImage {
@@ -275,7 +320,10 @@ impl AsyncAssetKey<AssetResult<Arc<AbsAssetUrl>>> for PipeImage {
             .await
             .with_context(|| format!("Failed to download image {}", self.source))?)
         .clone();
-        let mut extension = "png".to_string();
+        let mut extension = match &self.compress {
+            Some((role, _)) => format!("{}.{}", role.name(), Ktx2::EXTENSION),
+            None => "png".to_string(),
+        };
         let second_image = if let Some(second_source) = &self.second_source {
             Some(
                 ImageFromUrl { url: second_source.clone() }
@@ -287,18 +335,23 @@ impl AsyncAssetKey<AssetResult<Arc<AbsAssetUrl>>> for PipeImage {
             None
         };
         let path = ctx.in_root.relative_path(self.source.path());
-        let mut data = Cursor::new(Vec::new());
-        tokio::task::block_in_place(|| {
+        let data = tokio::task::block_in_place(|| {
             if let Some(transform) = &self.transform {
                 transform.transform(&mut image, second_image.as_deref());
-                extension = format!("{}.png", transform.name());
+                extension = format!("{}.{extension}", transform.name());
             }
             if let Some(size) = self.cap_texture_sizes {
                 cap_texture_size(&mut image, size.size());
             }
-            image.write_to(&mut data, ImageOutputFormat::Png).unwrap();
+            if let Some((role, compression)) = &self.compress {
+                encode_texture(&image, *role, compression)
+            } else {
+                let mut data = Cursor::new(Vec::new());
+                image.write_to(&mut data, ImageOutputFormat::Png).unwrap();
+                data.into_inner()
+            }
         });
-        Ok(Arc::new((ctx.write_file)(path.with_extension(extension).to_string(), data.into_inner()).await))
+        Ok(Arc::new((ctx.write_file)(path.with_extension(extension).to_string(), data).await))
     }
 }
 
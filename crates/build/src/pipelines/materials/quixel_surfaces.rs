@@ -11,51 +11,62 @@ use futures::future::join_all;
 use itertools::Itertools;
 
 use super::{
-    super::{models::quixel::QuixelId, OutAsset, OutAssetContent, OutAssetPreview, PipelineCtx},
+    super::{models::quixel::QuixelId, textures::compress_model_textures, OutAsset, OutAssetContent, OutAssetPreview, PipelineCtx},
     MaterialsPipeline,
 };
 use crate::pipelines::out_asset::asset_id_from_url;
 
-pub async fn pipeline(ctx: &PipelineCtx, _config: MaterialsPipeline) -> Vec<OutAsset> {
+pub async fn pipeline(ctx: &PipelineCtx, config: MaterialsPipeline) -> Vec<OutAsset> {
     ctx.process_files(
         |file| {
             file.extension() == Some("json".to_string())
                 && file.path().to_string().contains(&format!("_{}_", file.path().file_stem().unwrap()))
         },
-        move |ctx, file| async move {
-            let mut res = Vec::new();
-            let quixel_id = QuixelId::from_full(file.last_dir_name().unwrap()).unwrap();
-            let quixel_json: serde_json::Value = file.download_json(ctx.assets()).await.unwrap();
-            let in_root_url = file.join(".").unwrap();
-            let surface = QuixelSurfaceDef::from_quixel_json(&ctx, &quixel_id, &quixel_json, &in_root_url);
-            let mut asset_crate = ModelCrate::new();
-            surface.write_to_asset_crate(ctx.assets(), &mut asset_crate).await;
+        move |ctx, file| {
+            let compression = config.compress_textures.clone();
+            async move {
+                let mut res = Vec::new();
+                let quixel_id = QuixelId::from_full(file.last_dir_name().unwrap()).unwrap();
+                let quixel_json: serde_json::Value = file.download_json(ctx.assets()).await.unwrap();
+                let in_root_url = file.join(".").unwrap();
+                let surface = QuixelSurfaceDef::from_quixel_json(&ctx, &quixel_id, &quixel_json, &in_root_url);
+                let mut asset_crate = ModelCrate::new();
+                surface.write_to_asset_crate(ctx.assets(), &mut asset_crate).await;
 
-            let tags =
-                quixel_json["tags"].as_array().unwrap().iter().map(|x| x.as_str().unwrap().to_string().to_case(Case::Title)).collect_vec();
-            let pack_name = quixel_json["semanticTags"]["name"].as_str().unwrap().to_string();
+                let tags = quixel_json["tags"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|x| x.as_str().unwrap().to_string().to_case(Case::Title))
+                    .collect_vec();
+                let pack_name = quixel_json["semanticTags"]["name"].as_str().unwrap().to_string();
 
-            let model_crate_url = ctx.write_model_crate(&asset_crate, &ctx.in_root().relative_path(file.path())).await;
-
-            res.push(OutAsset {
-                id: asset_id_from_url(&file),
-                type_: AssetType::Material,
-                hidden: false,
-                name: pack_name.clone(),
-                tags,
-                categories: Default::default(),
-                preview: asset_crate
+                let preview = asset_crate
                     .images
                     .content
                     .get("base_color")
                     .or(asset_crate.images.content.get("opacity"))
                     .or(asset_crate.images.content.get("normal"))
                     .map(|image| OutAssetPreview::Image { image: Arc::new(image.clone()) })
-                    .unwrap_or(OutAssetPreview::None),
-                content: OutAssetContent::Content(model_crate_url.material(ModelCrate::MAIN).abs().unwrap()),
-                source: Some(file.clone()),
-            });
-            Ok(res)
+                    .unwrap_or(OutAssetPreview::None);
+                if let Some(compression) = &compression {
+                    tokio::task::block_in_place(|| compress_model_textures(&mut asset_crate, compression));
+                }
+                let model_crate_url = ctx.write_model_crate(&asset_crate, &ctx.in_root().relative_path(file.path())).await;
+
+                res.push(OutAsset {
+                    id: asset_id_from_url(&file),
+                    type_: AssetType::Material,
+                    hidden: false,
+                    name: pack_name.clone(),
+                    tags,
+                    categories: Default::default(),
+                    preview,
+                    content: OutAssetContent::Content(model_crate_url.material(ModelCrate::MAIN).abs().unwrap()),
+                    source: Some(file.clone()),
+                });
+                Ok(res)
+            }
         },
     )
     .await
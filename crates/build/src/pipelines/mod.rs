@@ -1,21 +1,33 @@
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 use ambient_asset_cache::SyncAssetKey;
 use ambient_std::{asset_cache::AssetCache, asset_url::AbsAssetUrl};
 use anyhow::Context;
+use build_manifest::{BuildManifest, PipelineRecord};
 use context::PipelineCtx;
 use futures::{future::BoxFuture, StreamExt};
 use image::ImageFormat;
+use itertools::Itertools;
 use out_asset::{OutAsset, OutAssetContent, OutAssetPreview};
 use serde::{Deserialize, Serialize};
 
 use self::{materials::MaterialsPipeline, models::ModelsPipeline};
 
 pub mod audio;
+pub mod build_manifest;
 pub mod context;
+pub mod custom;
 pub mod materials;
 pub mod models;
 pub mod out_asset;
+pub mod prefabs;
+pub mod textures;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
@@ -28,7 +40,13 @@ pub enum PipelineConfig {
     Materials(MaterialsPipeline),
     /// The audio asset pipeline.
     /// Will import supported audio file formats and produce Ogg Vorbis files to be used by the runtime.
-    Audio,
+    Audio(audio::AudioPipeline),
+    /// The prefabs asset pipeline.
+    /// Will compose prefabs from `.prefab.toml` and `.prefab.json` files, which describe a hierarchy of entities and their components.
+    Prefabs(prefabs::PrefabsPipeline),
+    /// A custom asset pipeline.
+    /// Will build a processor from a Rust package in the project, and run it with the files of this pipeline.
+    Custom(custom::CustomPipeline),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,7 +71,9 @@ impl Pipeline {
         let mut assets = match &self.pipeline {
             PipelineConfig::Models(config) => models::pipeline(&ctx, config.clone()).await,
             PipelineConfig::Materials(config) => materials::pipeline(&ctx, config.clone()).await,
-            PipelineConfig::Audio => audio::pipeline(&ctx).await,
+            PipelineConfig::Audio(config) => audio::pipeline(&ctx, config.clone()).await,
+            PipelineConfig::Prefabs(config) => prefabs::pipeline(&ctx, config.clone()).await,
+            PipelineConfig::Custom(config) => custom::pipeline(&ctx, config.clone()).await,
         };
         for asset in &mut assets {
             asset.tags.extend(self.tags.clone());
@@ -67,7 +87,11 @@ impl Pipeline {
     }
 }
 
-pub async fn process_pipelines(ctx: &ProcessCtx) -> Vec<OutAsset> {
+/// Runs every pipeline found in `ctx.files`, skipping those whose configuration and inputs are unchanged
+/// since `previous_build`. Pass an empty [BuildManifest] to run all of them.
+///
+/// Returns the assets produced by the pipelines that ran, and the manifest describing this build.
+pub async fn process_pipelines(ctx: &ProcessCtx, previous_build: &BuildManifest) -> (Vec<OutAsset>, BuildManifest) {
     log::info!("Processing pipeline with out_root={}", ctx.out_root);
 
     #[derive(Debug, Clone, Deserialize)]
@@ -85,7 +109,11 @@ pub async fn process_pipelines(ctx: &ProcessCtx) -> Vec<OutAsset> {
         }
     }
 
-    futures::stream::iter(ctx.files.0.iter())
+    let file_hashes = Arc::new(build_manifest::hash_files(&ctx.assets, &ctx.files).await);
+    let hashes_by_path: Arc<HashMap<_, _>> =
+        Arc::new(file_hashes.iter().map(|(file, hash)| (ctx.in_root.relative_path(file.path()).to_string(), hash.clone())).collect());
+
+    let results = futures::stream::iter(ctx.files.0.iter())
         .filter_map(|file| async move {
             let pipelines: PipelineOneOrMany = if file.0.path().ends_with("pipeline.json") {
                 file.download_json(&ctx.assets).await.unwrap()
@@ -103,20 +131,115 @@ pub async fn process_pipelines(ctx: &ProcessCtx) -> Vec<OutAsset> {
         })
         .map(|(pipeline_file, pipeline)| {
             let root = pipeline_file.join(".").unwrap();
+            let files = ctx.files.sub_directory(root.path().as_str());
+
+            let inputs: BTreeMap<_, _> = files
+                .0
+                .iter()
+                .filter_map(|file| Some((ctx.in_root.relative_path(file.path()).to_string(), file_hashes.get(file)?.clone())))
+                .collect();
+
+            // Record everything the pipeline writes, so that its outputs can be cleaned up once they are stale
+            let outputs = Arc::new(parking_lot::Mutex::new(Vec::new()));
+            // Record the files the pipeline reads, as they can be outside of its directory
+            let reads = Arc::new(parking_lot::Mutex::new(HashSet::new()));
+            // A pipeline that reported errors is not up to date, even if its inputs don't change
+            let failed = Arc::new(AtomicBool::new(false));
+            let mut process_ctx = ctx.clone();
+            process_ctx.write_file = Arc::new({
+                let write_file = ctx.write_file.clone();
+                let outputs = outputs.clone();
+                move |path: String, contents: Vec<u8>| {
+                    outputs.lock().push(path.clone());
+                    write_file(path, contents)
+                }
+            });
+            process_ctx.on_read = Arc::new({
+                let on_read = ctx.on_read.clone();
+                let reads = reads.clone();
+                move |url: &AbsAssetUrl| {
+                    reads.lock().insert(url.clone());
+                    on_read(url)
+                }
+            });
+            process_ctx.on_error = Arc::new({
+                let on_error = ctx.on_error.clone();
+                let failed = failed.clone();
+                move |err| {
+                    failed.store(true, Ordering::SeqCst);
+                    on_error(err)
+                }
+            });
+
             let ctx = PipelineCtx {
-                files: ctx.files.sub_directory(root.path().as_str()),
-                process_ctx: ctx.clone(),
+                files,
+                process_ctx,
                 pipeline: Arc::new(pipeline.clone()),
                 pipeline_file,
                 root_path: ctx.in_root.relative_path(root.path()),
             };
-            tokio::spawn(async move { pipeline.process(ctx).await })
+            let key = ctx.pipeline_path().to_string();
+            let previous = previous_build.pipelines.get(&key).cloned();
+            let in_root = ctx.process_ctx.in_root.clone();
+            let hashes_by_path = hashes_by_path.clone();
+            tokio::spawn(async move {
+                // The processor of a custom pipeline can change without its configuration or inputs changing, so it is
+                // hashed with the configuration. If it fails to build, the pipeline runs to report why
+                let config_hash = match &pipeline.pipeline {
+                    PipelineConfig::Custom(config) => match custom::build_processor(&ctx, config).await {
+                        Ok(processor) => Some(build_manifest::hash_pipeline_config(&pipeline, Some(&processor.bytecode))),
+                        Err(_) => None,
+                    },
+                    _ => Some(build_manifest::hash_pipeline_config(&pipeline, None)),
+                };
+                let previous = previous.filter(|record| {
+                    // The files the previous run read outside of the pipeline's directory are inputs too
+                    let mut inputs = inputs.clone();
+                    build_manifest::add_inputs(&mut inputs, record.inputs.keys(), &hashes_by_path);
+                    config_hash.as_ref().map_or(false, |config_hash| {
+                        record.is_up_to_date(config_hash, &inputs, |output| output_exists(&ctx.process_ctx, output))
+                    })
+                });
+                if let Some(previous) = previous {
+                    (ctx.process_ctx.on_status)(format!("[{key}] Up to date, skipping")).await;
+                    return (key, previous, Vec::new());
+                }
+
+                let process_ctx = ctx.process_ctx.clone();
+                let assets = pipeline.process(ctx).await;
+                let catalog = futures::future::join_all(assets.iter().map(|asset| asset.to_catalog_asset(&process_ctx))).await;
+                let mut outputs = std::mem::take(&mut *outputs.lock());
+                outputs.sort();
+                outputs.dedup();
+                let failed = failed.load(Ordering::SeqCst);
+                let config_hash = config_hash.unwrap_or_default();
+                let mut inputs = inputs;
+                let reads = reads.lock().iter().map(|url| in_root.relative_path(url.path()).to_string()).collect_vec();
+                build_manifest::add_inputs(&mut inputs, &reads, &hashes_by_path);
+                (key, PipelineRecord { config_hash, inputs, outputs, assets: catalog, failed }, assets)
+            })
         })
         .buffered(30)
         .map(|x| x.unwrap())
-        .flat_map(|out_assets| futures::stream::iter(out_assets.into_iter()))
         .collect::<Vec<_>>()
-        .await
+        .await;
+
+    let mut manifest = BuildManifest::default();
+    let mut out_assets = Vec::new();
+    for (key, record, assets) in results {
+        manifest.pipelines.insert(key, record);
+        out_assets.extend(assets);
+    }
+    (out_assets, manifest)
+}
+
+/// Whether `output`, relative to the output root of `ctx`, exists. Outputs that aren't written to files are assumed to.
+fn output_exists(ctx: &ProcessCtx, output: &str) -> bool {
+    match ctx.out_root.push(output).map(|url| url.to_file_path()) {
+        Ok(Ok(Some(path))) => path.exists(),
+        Ok(Ok(None)) => true,
+        _ => false,
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -134,6 +257,8 @@ pub struct ProcessCtx {
     pub write_file: Arc<dyn Fn(String, Vec<u8>) -> BoxFuture<'static, AbsAssetUrl> + Sync + Send>,
     pub on_status: Arc<dyn Fn(String) -> BoxFuture<'static, ()> + Sync + Send>,
     pub on_error: Arc<dyn Fn(anyhow::Error) -> BoxFuture<'static, ()> + Sync + Send>,
+    /// Called with every file a pipeline looks up with [PipelineCtx::get_downloadable_url], which can be outside of its directory
+    pub on_read: Arc<dyn Fn(&AbsAssetUrl) + Sync + Send>,
 }
 #[derive(Clone)]
 pub struct FileCollection(pub Arc<Vec<AbsAssetUrl>>);
@@ -2,7 +2,9 @@ use std::{path::PathBuf, sync::Arc};
 
 use ambient_core::hierarchy::children;
 use ambient_ecs::Entity;
-use ambient_model_import::{model_crate::ModelCrate, MaterialFilter, ModelTextureSize, ModelTransform, TextureResolver};
+use ambient_model_import::{
+    mesh_lod::LodLevel, model_crate::ModelCrate, MaterialFilter, ModelTextureSize, ModelTransform, TextureResolver,
+};
 use ambient_physics::collider::{collider_type, ColliderType};
 use ambient_std::asset_url::AssetType;
 use futures::FutureExt;
@@ -14,6 +16,7 @@ use super::{
     download_image,
     materials::PipelinePbrMaterial,
     out_asset::{asset_id_from_url, OutAsset, OutAssetContent, OutAssetPreview},
+    textures::{compress_model_textures, TextureCompression},
 };
 
 pub mod quixel;
@@ -50,6 +53,14 @@ fn true_value() -> bool {
     true
 }
 
+fn default_max_hulls() -> u32 {
+    16
+}
+
+fn default_max_vertices_per_hull() -> u16 {
+    64
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModelsPipeline {
     /// The importer to use to process models.
@@ -93,6 +104,18 @@ pub struct ModelsPipeline {
     /// These will be applied in sequence.
     #[serde(default)]
     transforms: Vec<ModelTransform>,
+    /// If specified, simplified versions of the meshes will be generated and rendered instead of the originals
+    /// as the model gets smaller on screen.
+    generate_lods: Option<GenerateLods>,
+    /// Reorder the triangles of the meshes so that they render faster, by making better use of the GPU's vertex cache
+    /// and reducing overdraw. The meshes look exactly the same.
+    #[serde(default)]
+    optimize_meshes: bool,
+    /// If specified, the textures of this model will be compressed, and have their mipmaps generated, at build time.
+    compress_textures: Option<TextureCompression>,
+    /// If specified, a ragdoll with a capsule per bone will be generated from the skeleton of this model, so that its
+    /// skeleton can be driven by physics by setting `ragdoll_active`.
+    generate_ragdoll: Option<GenerateRagdoll>,
 }
 impl ModelsPipeline {
     pub async fn apply(
@@ -105,22 +128,57 @@ impl ModelsPipeline {
             transform.apply(model_crate);
         }
         for mat in &self.material_overrides {
-            let material =
-                mat.material.to_mat(ctx, &ctx.in_root(), &ctx.out_root().push(out_model_path.as_ref().join("materials"))?).await?;
+            let material = mat
+                .material
+                .to_mat(
+                    ctx,
+                    &ctx.in_root(),
+                    &ctx.out_root().push(out_model_path.as_ref().join("materials"))?,
+                    self.compress_textures.as_ref(),
+                )
+                .await?;
             model_crate.override_material(&mat.filter, material);
         }
         if let Some(max_size) = self.cap_texture_sizes {
             model_crate.cap_texture_sizes(max_size.size());
         }
+        if let Some(lods) = &self.generate_lods {
+            model_crate.generate_mesh_lods(&lods.levels, lods.lod_cutoffs.clone())?;
+        }
+        if self.optimize_meshes {
+            model_crate.optimize_meshes();
+        }
         model_crate.finalize_model();
+        if let Some(compression) = &self.compress_textures {
+            tokio::task::block_in_place(|| compress_model_textures(model_crate, compression));
+        }
         match self.collider {
             Collider::None => {}
             Collider::FromModel { flip_normals, reverse_indices } => {
                 model_crate.create_collider_from_model(&ctx.process_ctx.assets, flip_normals, reverse_indices).unwrap();
             }
+            Collider::ConvexDecomposition { flip_normals, reverse_indices, max_hulls, max_vertices_per_hull } => {
+                model_crate.create_convex_decomposition_collider_from_model(
+                    &ctx.process_ctx.assets,
+                    flip_normals,
+                    reverse_indices,
+                    max_hulls,
+                    max_vertices_per_hull,
+                )?;
+            }
+            Collider::SimplifiedBoxes => model_crate.create_box_collider_from_model(&ctx.process_ctx.assets),
+            Collider::Capsule => model_crate.create_capsule_collider_from_model(&ctx.process_ctx.assets),
             Collider::Character { radius, height } => model_crate.create_character_collider(radius, height),
         }
         model_crate.add_component_to_prefab(collider_type(), self.collider_type);
+        if let Some(ragdoll) = &self.generate_ragdoll {
+            model_crate.create_ragdoll_from_model(
+                ragdoll.radius_scale,
+                ragdoll.min_bone_length,
+                ragdoll.swing_limit.to_radians(),
+                ragdoll.twist_limit.to_radians(),
+            );
+        }
         let world = model_crate.prefab_world_mut();
         let obj = world.resource(children())[0];
         world.add_components(obj, self.prefab_components.clone()).unwrap();
@@ -128,6 +186,51 @@ impl ModelsPipeline {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerateLods {
+    /// The levels of detail to generate, from the most to the least detailed. The original mesh is always used as the
+    /// first level, before these. Defaults to half, a quarter and an eighth of the original triangles.
+    #[serde(default = "LodLevel::defaults")]
+    levels: Vec<LodLevel>,
+    /// The size on screen, as a fraction of the screen, below which each level stops being used, starting with the
+    /// original mesh, so there must be one more cutoff than there are `levels`. The model is not rendered below the last
+    /// cutoff. Defaults to cutoffs spaced evenly down to 4% of the screen.
+    lod_cutoffs: Option<Vec<f32>>,
+}
+
+fn default_ragdoll_radius_scale() -> f32 {
+    0.25
+}
+
+fn default_ragdoll_min_bone_length() -> f32 {
+    0.05
+}
+
+fn default_ragdoll_swing_limit() -> f32 {
+    45.
+}
+
+fn default_ragdoll_twist_limit() -> f32 {
+    30.
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerateRagdoll {
+    /// The radius of the capsule of each bone, as a fraction of the length of the bone. Defaults to 0.25.
+    #[serde(default = "default_ragdoll_radius_scale")]
+    radius_scale: f32,
+    /// Bones shorter than this, in meters, are left out of the ragdoll together with their children, and keep following
+    /// the animation. Defaults to 0.05.
+    #[serde(default = "default_ragdoll_min_bone_length")]
+    min_bone_length: f32,
+    /// How far each bone can swing away from its parent, in degrees. Defaults to 45.
+    #[serde(default = "default_ragdoll_swing_limit")]
+    swing_limit: f32,
+    /// How far each bone can twist around its own axis, in degrees. Defaults to 30.
+    #[serde(default = "default_ragdoll_twist_limit")]
+    twist_limit: f32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MaterialOverride {
     /// The filter for this override (i.e. what it should apply to).
@@ -166,6 +269,26 @@ pub enum Collider {
         #[serde(default = "true_value")]
         reverse_indices: bool,
     },
+    /// Split the model into convex hulls that approximate its shape, so that it can be used by dynamic objects.
+    /// Static objects still use the model's triangles.
+    ConvexDecomposition {
+        /// Whether or not the normals should be flipped.
+        #[serde(default)]
+        flip_normals: bool,
+        /// Whether or not the indices should be reversed for each triangle. On by default.
+        #[serde(default = "true_value")]
+        reverse_indices: bool,
+        /// The maximum number of convex hulls to split each mesh into. Defaults to 16.
+        #[serde(default = "default_max_hulls")]
+        max_hulls: u32,
+        /// The maximum number of vertices in each convex hull, from 4 to 255. Defaults to 64.
+        #[serde(default = "default_max_vertices_per_hull")]
+        max_vertices_per_hull: u16,
+    },
+    /// Fit a box around each mesh of the model.
+    SimplifiedBoxes,
+    /// Fit a single capsule around the whole model, lying along the longest side of its bounding box.
+    Capsule,
     /// Use a spherical character collider.
     Character {
         /// The radius of the collider.
@@ -1,6 +1,13 @@
-use std::{collections::HashSet, sync::Arc};
+use std::{collections::HashSet, io::Cursor, sync::Arc};
 
-use ambient_std::asset_url::{AbsAssetUrl, AssetType};
+use ambient_std::{
+    asset_catalog::CatalogAsset,
+    asset_url::{AbsAssetUrl, AssetType},
+};
+use image::ImageOutputFormat;
+use itertools::Itertools;
+
+use super::ProcessCtx;
 
 #[derive(Debug, Clone)]
 pub enum OutAssetContent {
@@ -37,6 +44,57 @@ pub struct OutAsset {
     pub content: OutAssetContent,
     pub source: Option<AbsAssetUrl>,
 }
+impl OutAsset {
+    /// Describes this asset for the build's [ambient_std::asset_catalog::AssetCatalog], writing its preview image
+    /// (if it has one) to the output.
+    pub async fn to_catalog_asset(&self, ctx: &ProcessCtx) -> CatalogAsset {
+        let preview = match &self.preview {
+            OutAssetPreview::Image { image } => {
+                let mut data = Cursor::new(Vec::new());
+                match tokio::task::block_in_place(|| image.write_to(&mut data, ImageOutputFormat::Png)) {
+                    Ok(()) => {
+                        Some(build_relative_path(ctx, &(ctx.write_file)(format!("previews/{}.png", self.id), data.into_inner()).await))
+                    }
+                    Err(err) => {
+                        log::warn!("Failed to encode the preview of {}: {err}", self.id);
+                        None
+                    }
+                }
+            }
+            OutAssetPreview::None | OutAssetPreview::FromModel { .. } => None,
+        };
+        let (content, collection) = match &self.content {
+            OutAssetContent::Content(url) => (Some(build_relative_path(ctx, url)), vec![]),
+            OutAssetContent::Collection(ids) => (None, ids.clone()),
+        };
+        let source = self.source.as_ref().map(|source| {
+            if source.0.as_str().starts_with(ctx.in_root.0.as_str()) {
+                ctx.in_root.relative_path(source.path()).to_string()
+            } else {
+                source.to_string()
+            }
+        });
+
+        CatalogAsset {
+            id: self.id.clone(),
+            type_: self.type_,
+            name: self.name.clone(),
+            hidden: self.hidden,
+            tags: self.tags.clone(),
+            categories: self.categories.iter().flat_map(|level| level.iter().sorted()).cloned().collect(),
+            content,
+            collection,
+            source,
+            preview,
+        }
+    }
+}
+
+/// Returns the path of `url`, which must be in the output, relative to the build directory.
+fn build_relative_path(ctx: &ProcessCtx, url: &AbsAssetUrl) -> String {
+    format!("assets/{}", ctx.out_root.relative_path(url.path()))
+}
+
 pub fn asset_id_from_url(url: &AbsAssetUrl) -> String {
     slugify::slugify(&format!("{}{}", url.0.host_str().unwrap_or(""), url.0.path()), "", "_", None)
 }
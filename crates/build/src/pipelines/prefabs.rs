@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+
+use ambient_core::hierarchy::{add_child, children, parent};
+use ambient_ecs::{with_component_registry, Entity, EntityId, World};
+use ambient_model_import::model_crate::ModelCrate;
+use ambient_prefab::prefab_from_url;
+use ambient_std::asset_url::{AbsAssetUrl, AssetType, AssetUrl};
+use anyhow::Context;
+use async_recursion::async_recursion;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    context::PipelineCtx,
+    out_asset::{asset_id_from_url, OutAsset, OutAssetContent, OutAssetPreview},
+};
+
+/// The file extensions of the prefab files that are composed by this pipeline.
+pub const PREFAB_EXTENSIONS: &[&str] = &["prefab.toml", "prefab.json"];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PrefabsPipeline {}
+
+/// An entity of a prefab file, with its children.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PrefabNode {
+    /// A model or prefab to base this entity on, relative to this file, i.e. `../models/door.fbx`.
+    /// The components of this entity override those of the referenced prefab.
+    #[serde(default)]
+    prefab: Option<String>,
+    /// The components of this entity, by their path, i.e. `core::transform::translation`.
+    /// Components defined in `ambient.toml` are prefixed with the project's id.
+    #[serde(default)]
+    components: BTreeMap<String, serde_json::Value>,
+    #[serde(default)]
+    children: Vec<PrefabNode>,
+}
+
+fn is_prefab_file(url: &AbsAssetUrl) -> bool {
+    PREFAB_EXTENSIONS.iter().any(|ext| url.path().as_str().ends_with(&format!(".{ext}")))
+}
+
+pub async fn pipeline(ctx: &PipelineCtx, _config: PrefabsPipeline) -> Vec<OutAsset> {
+    ctx.process_files(is_prefab_file, move |ctx, file| async move {
+        let node = load_node(&ctx, &file).await?;
+        check_references(&ctx, &file, &node, &mut vec![file.clone()]).await?;
+
+        let mut world = World::new("prefab_asset");
+        let root = spawn_node(&mut world, &node)?;
+        world.add_resource(children(), vec![root]);
+
+        let mut model_crate = ModelCrate::new();
+        model_crate.prefabs.insert(ModelCrate::MAIN, world);
+        let out_prefab_path = ctx.in_root().relative_path(file.path());
+        let model_crate_url = ctx.write_model_crate(&model_crate, &out_prefab_path).await;
+
+        Ok(vec![OutAsset {
+            id: asset_id_from_url(&file),
+            type_: AssetType::Prefab,
+            hidden: false,
+            name: file.path().file_name().unwrap().to_string(),
+
+            tags: Default::default(),
+            categories: Default::default(),
+            preview: OutAssetPreview::None,
+            content: OutAssetContent::Content(model_crate_url.prefab().abs().unwrap()),
+            source: Some(file.clone()),
+        }])
+    })
+    .await
+}
+
+async fn load_node(ctx: &PipelineCtx, file: &AbsAssetUrl) -> anyhow::Result<PrefabNode> {
+    let node = if file.path().as_str().ends_with(".toml") {
+        file.download_toml(ctx.assets()).await
+    } else {
+        file.download_json(ctx.assets()).await
+    };
+    node.with_context(|| format!("Failed to load prefab {file}"))
+}
+
+/// Checks that every prefab referenced by `node` exists, and that no prefab file ends up referencing itself, which could
+/// never finish loading. `stack` is the chain of prefab files that led to `node`.
+#[async_recursion]
+async fn check_references(ctx: &PipelineCtx, file: &AbsAssetUrl, node: &PrefabNode, stack: &mut Vec<AbsAssetUrl>) -> anyhow::Result<()> {
+    if let Some(prefab) = &node.prefab {
+        // Absolute urls point outside of this project, and can't be checked
+        if let AssetUrl::Relative(_) = AssetUrl::parse(prefab).with_context(|| format!("Invalid prefab reference `{prefab}`"))? {
+            let target = file.resolve(prefab).with_context(|| format!("Invalid prefab reference `{prefab}`"))?;
+            anyhow::ensure!(ctx.get_downloadable_url(&target).is_ok(), "The prefab `{prefab}` referenced by {file} does not exist");
+            if is_prefab_file(&target) {
+                if stack.contains(&target) {
+                    let chain = stack.iter().chain(std::iter::once(&target)).map(|url| url.to_string()).collect::<Vec<_>>();
+                    anyhow::bail!("Prefab references itself: {}", chain.join(" -> "));
+                }
+                let target_node = load_node(ctx, &target).await?;
+                stack.push(target.clone());
+                check_references(ctx, &target, &target_node, stack).await?;
+                stack.pop();
+            }
+        }
+    }
+    for child in &node.children {
+        check_references(ctx, file, child, stack).await?;
+    }
+    Ok(())
+}
+
+fn spawn_node(world: &mut World, node: &PrefabNode) -> anyhow::Result<EntityId> {
+    let mut entity = parse_components(&node.components)?;
+    if let Some(prefab) = &node.prefab {
+        // References are relative to the prefab file, which is built to `<file>/prefabs/main.json`
+        let url = match AssetUrl::parse(prefab)? {
+            AssetUrl::Absolute(url) => url.to_string(),
+            AssetUrl::Relative(path) => format!("../../{path}"),
+        };
+        entity.set(prefab_from_url(), url);
+    }
+    let id = entity.spawn(world);
+    for (i, child) in node.children.iter().enumerate() {
+        let child_id = spawn_node(world, child).with_context(|| format!("In child {i}"))?;
+        world.add_component(child_id, parent(), id)?;
+        add_child(world, id, child_id)?;
+    }
+    Ok(id)
+}
+
+/// Deserializes `components` with the component registry, so that unknown components and invalid values are reported at
+/// build time rather than when the prefab is spawned.
+fn parse_components(components: &BTreeMap<String, serde_json::Value>) -> anyhow::Result<Entity> {
+    let mut entity = Entity::new();
+    for (path, value) in components {
+        anyhow::ensure!(
+            with_component_registry(|r| r.get_by_path(path)).is_some(),
+            "No such component: `{path}`. Components defined in ambient.toml are referred to by their full path, i.e. `my_project::my_component`"
+        );
+        let value = serde_json::Value::Object([(path.clone(), value.clone())].into_iter().collect());
+        entity.merge(serde_json::from_value::<Entity>(value).with_context(|| format!("Invalid value for component `{path}`"))?);
+    }
+    Ok(entity)
+}
+
+#[cfg(test)]
+mod test {
+    use ambient_core::{name, transform::translation};
+    use glam::vec3;
+    use serde_json::json;
+
+    use super::*;
+
+    fn parse_node(node: serde_json::Value) -> PrefabNode {
+        ambient_core::init_all_components();
+        ambient_prefab::init_components();
+        serde_json::from_value(node).unwrap()
+    }
+
+    #[test]
+    fn spawn_node_builds_the_hierarchy() {
+        let node = parse_node(json!({
+            "components": { "core::app::name": "House" },
+            "children": [
+                { "prefab": "models/door.fbx", "components": { "core::transform::translation": [0.0, 2.0, 0.0] } },
+                { "prefab": "https://example.com/window.prefab.toml" }
+            ]
+        }));
+        let mut world = World::new("prefab_asset");
+        let root = spawn_node(&mut world, &node).unwrap();
+
+        assert_eq!(world.get_ref(root, name()).unwrap(), "House");
+        let root_children = world.get_ref(root, children()).unwrap().clone();
+        assert_eq!(root_children.len(), 2);
+        for &child in &root_children {
+            assert_eq!(world.get(child, parent()).unwrap(), root);
+        }
+        assert_eq!(world.get(root_children[0], translation()).unwrap(), vec3(0., 2., 0.));
+        assert_eq!(world.get_ref(root_children[0], prefab_from_url()).unwrap(), "../../models/door.fbx");
+        assert_eq!(world.get_ref(root_children[1], prefab_from_url()).unwrap(), "https://example.com/window.prefab.toml");
+    }
+
+    #[test]
+    fn unknown_components_are_rejected() {
+        let node = parse_node(json!({ "components": { "my_project::max_hitpoints": 500.0 } }));
+        let err = spawn_node(&mut World::new("prefab_asset"), &node).unwrap_err();
+        assert!(err.to_string().contains("No such component: `my_project::max_hitpoints`"));
+    }
+
+    #[test]
+    fn invalid_values_are_rejected() {
+        let node = parse_node(json!({ "children": [{ "components": { "core::transform::translation": "up" } }] }));
+        let err = spawn_node(&mut World::new("prefab_asset"), &node).unwrap_err();
+        assert_eq!(err.to_string(), "In child 0");
+        assert!(format!("{err:#}").contains("Invalid value for component `core::transform::translation`"));
+    }
+}
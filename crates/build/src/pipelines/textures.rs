@@ -0,0 +1,244 @@
+use std::collections::HashSet;
+
+use ambient_model_import::{dotdot_path, model_crate::ModelCrate};
+use ambient_std::{
+    asset_url::AssetUrl,
+    ktx2::{Ktx2, Ktx2Format},
+};
+use glam::Vec3;
+use image::{imageops, imageops::FilterType, RgbaImage};
+use intel_tex_2::{bc4, bc5, bc7, RSurface, RgSurface, RgbaSurface};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+/// How textures are compressed at build time. Compressed textures are stored as KTX2 files, with their mipmaps.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TextureCompression {
+    /// The format to store the textures in. Defaults to `Bc`.
+    #[serde(default)]
+    pub format: TextureCompressionFormat,
+    /// How long to spend searching for the best encoding of each block. Defaults to `Normal`.
+    #[serde(default)]
+    pub quality: TextureCompressionQuality,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TextureCompressionFormat {
+    /// Block compression: BC7 for color textures, and BC5 for normal maps and metallic-roughness maps.
+    /// Supported by almost all desktop GPUs.
+    #[default]
+    Bc,
+    /// Uncompressed RGBA8, for GPUs without block compression. Mipmaps are still generated.
+    Uncompressed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TextureCompressionQuality {
+    Fast,
+    #[default]
+    Normal,
+    High,
+}
+
+/// What a texture is used for, which decides how it is compressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextureRole {
+    /// An sRGB color texture, with alpha.
+    Color,
+    /// A tangent space normal map. Only x and y are stored; the shader reconstructs z.
+    Normal,
+    /// Metallic in the red channel and roughness in the green channel.
+    MetallicRoughness,
+}
+impl TextureRole {
+    pub fn name(self) -> &'static str {
+        match self {
+            TextureRole::Color => "color",
+            TextureRole::Normal => "normal",
+            TextureRole::MetallicRoughness => "mr",
+        }
+    }
+}
+
+/// Encodes `image` and a full chain of mipmaps for it as a KTX2 file.
+pub fn encode_texture(image: &RgbaImage, role: TextureRole, compression: &TextureCompression) -> Vec<u8> {
+    let compressed = compression.format == TextureCompressionFormat::Bc;
+    let format = match (compressed, role) {
+        (false, TextureRole::Color) => Ktx2Format::Rgba8Srgb,
+        (false, _) => Ktx2Format::Rgba8Unorm,
+        (true, TextureRole::Color) => Ktx2Format::Bc7Srgb,
+        (true, _) => Ktx2Format::Bc5Unorm,
+    };
+    // The full size level of a block compressed texture has to be made of whole blocks
+    let mut level = if compressed && (image.width() % 4 != 0 || image.height() % 4 != 0) {
+        imageops::resize(image, round_up_to_block(image.width()), round_up_to_block(image.height()), FilterType::CatmullRom)
+    } else {
+        image.clone()
+    };
+    let (width, height) = level.dimensions();
+
+    let mut levels = vec![encode_level(&level, format, compression.quality)];
+    while level.width() > 1 || level.height() > 1 {
+        level = imageops::resize(&level, (level.width() / 2).max(1), (level.height() / 2).max(1), FilterType::Triangle);
+        if role == TextureRole::Normal {
+            renormalize(&mut level);
+        }
+        levels.push(encode_level(&level, format, compression.quality));
+    }
+    Ktx2 { format, width, height, levels }.to_bytes()
+}
+
+fn round_up_to_block(size: u32) -> u32 {
+    (size + 3) / 4 * 4
+}
+
+fn encode_level(image: &RgbaImage, format: Ktx2Format, quality: TextureCompressionQuality) -> Vec<u8> {
+    if !format.is_compressed() {
+        return image.as_raw().clone();
+    }
+    // Mip levels smaller than a block are padded by repeating their edges
+    let (width, height) = (round_up_to_block(image.width()), round_up_to_block(image.height()));
+    let image = RgbaImage::from_fn(width, height, |x, y| *image.get_pixel(x.min(image.width() - 1), y.min(image.height() - 1)));
+    match format {
+        Ktx2Format::Bc7Unorm | Ktx2Format::Bc7Srgb => {
+            let settings = match quality {
+                TextureCompressionQuality::Fast => bc7::alpha_very_fast_settings(),
+                TextureCompressionQuality::Normal => bc7::alpha_basic_settings(),
+                TextureCompressionQuality::High => bc7::alpha_slow_settings(),
+            };
+            bc7::compress_blocks(&settings, &RgbaSurface { data: image.as_raw(), width, height, stride: width * 4 })
+        }
+        Ktx2Format::Bc5Unorm => {
+            let data = image.pixels().flat_map(|p| [p[0], p[1]]).collect_vec();
+            bc5::compress_blocks(&RgSurface { data: &data, width, height, stride: width * 2 })
+        }
+        Ktx2Format::Bc4Unorm => {
+            let data = image.pixels().map(|p| p[0]).collect_vec();
+            bc4::compress_blocks(&RSurface { data: &data, width, height, stride: width })
+        }
+        Ktx2Format::Rgba8Unorm | Ktx2Format::Rgba8Srgb => unreachable!(),
+    }
+}
+
+/// Averaging normals shortens them, so they are scaled back to unit length after each downsample.
+fn renormalize(image: &mut RgbaImage) {
+    for p in image.pixels_mut() {
+        let normal = Vec3::new(p[0] as f32, p[1] as f32, p[2] as f32) / 255. * 2. - 1.;
+        let normal = (normal.normalize_or_zero() + 1.) / 2. * 255.;
+        p[0] = normal.x.round() as u8;
+        p[1] = normal.y.round() as u8;
+        p[2] = normal.z.round() as u8;
+    }
+}
+
+/// Stores the luminance of `opacity` in the alpha channel of `color`.
+pub fn merge_opacity(color: &mut RgbaImage, opacity: &RgbaImage) {
+    let opacity = imageops::grayscale(opacity);
+    let opacity = if opacity.dimensions() != color.dimensions() {
+        imageops::resize(&opacity, color.width(), color.height(), FilterType::Triangle)
+    } else {
+        opacity
+    };
+    for (color, opacity) in color.pixels_mut().zip(opacity.pixels()) {
+        color[3] = opacity[0];
+    }
+}
+
+/// Replaces the images used by the materials of `model_crate` with compressed textures. Opacity maps are merged into
+/// the alpha of the base color, and images that are no longer used by any material are removed. Base colors with an
+/// opacity map that isn't part of the crate are left uncompressed.
+pub fn compress_model_textures(model_crate: &mut ModelCrate, compression: &TextureCompression) {
+    let ModelCrate { images, materials, textures, .. } = model_crate;
+    let image_id = |url: &Option<AssetUrl>| url.as_ref().and_then(|url| images.loc.id_from_path(url.path()));
+
+    for material in materials.content.values_mut() {
+        let opacity_id = image_id(&material.opacity).filter(|id| images.content.contains_key(id));
+        // An opacity map from outside of the crate can't be merged, so the base color is left as it is for it to apply
+        let external_opacity = material.opacity.is_some() && opacity_id.is_none();
+        let mut merged_opacity = false;
+        let roles = [
+            (&mut material.base_color, TextureRole::Color),
+            (&mut material.normalmap, TextureRole::Normal),
+            (&mut material.metallic_roughness, TextureRole::MetallicRoughness),
+        ];
+        for (url, role) in roles {
+            let Some((id, image)) = image_id(&*url).and_then(|id| Some((id.clone(), images.content.get(&id)?))) else { continue; };
+            if role == TextureRole::Color && external_opacity {
+                continue;
+            }
+            let opacity_id = opacity_id.as_ref().filter(|_| role == TextureRole::Color);
+            let texture_id = match opacity_id {
+                Some(opacity_id) => format!("{id}_{opacity_id}_{}", role.name()),
+                None => format!("{id}_{}", role.name()),
+            };
+            if !textures.content.contains_key(&texture_id) {
+                let mut image = image.clone();
+                if let Some(opacity_id) = opacity_id {
+                    merge_opacity(&mut image, &images.content[opacity_id]);
+                }
+                textures.insert(texture_id.clone(), encode_texture(&image, role, compression));
+            }
+            *url = Some(dotdot_path(textures.loc.path(&texture_id)).into());
+            merged_opacity |= opacity_id.is_some();
+        }
+        if merged_opacity {
+            material.opacity = None;
+        }
+    }
+
+    let used = materials
+        .content
+        .values()
+        .flat_map(|material| [&material.base_color, &material.opacity, &material.normalmap, &material.metallic_roughness])
+        .filter_map(image_id)
+        .collect::<HashSet<_>>();
+    images.content.retain(|id, _| used.contains(id));
+}
+
+#[cfg(test)]
+mod test {
+    use ambient_renderer::materials::pbr_material::PbrMaterialDesc;
+    use image::Rgba;
+
+    use super::*;
+
+    fn model_crate_with_material(opacity: impl FnOnce(&mut ModelCrate) -> AssetUrl) -> ModelCrate {
+        let mut model_crate = ModelCrate::new();
+        let base_color = dotdot_path(model_crate.images.insert("base_color", RgbaImage::from_pixel(4, 4, Rgba([255, 0, 0, 255]))).path);
+        let opacity = opacity(&mut model_crate);
+        model_crate
+            .materials
+            .insert("main", PbrMaterialDesc { base_color: Some(base_color.into()), opacity: Some(opacity), ..Default::default() });
+        model_crate
+    }
+
+    fn compression() -> TextureCompression {
+        TextureCompression { format: TextureCompressionFormat::Uncompressed, quality: TextureCompressionQuality::Fast }
+    }
+
+    #[test]
+    fn merges_opacity_into_base_color() {
+        let mut model_crate = model_crate_with_material(|model_crate| {
+            dotdot_path(model_crate.images.insert("opacity", RgbaImage::from_pixel(4, 4, Rgba([128, 128, 128, 255]))).path).into()
+        });
+        compress_model_textures(&mut model_crate, &compression());
+
+        let material = &model_crate.materials.content["main"];
+        assert_eq!(material.opacity, None);
+        assert!(model_crate.textures.loc.id_from_path(material.base_color.as_ref().unwrap().path()).is_some());
+        assert!(model_crate.images.content.is_empty());
+    }
+
+    #[test]
+    fn keeps_base_color_with_external_opacity() {
+        let opacity = AssetUrl::parse("https://example.com/opacity.png").unwrap();
+        let mut model_crate = model_crate_with_material(|_| opacity.clone());
+        compress_model_textures(&mut model_crate, &compression());
+
+        let material = &model_crate.materials.content["main"];
+        assert_eq!(material.opacity, Some(opacity));
+        assert_eq!(model_crate.images.loc.id_from_path(material.base_color.as_ref().unwrap().path()).as_deref(), Some("base_color"));
+        assert!(model_crate.images.content.contains_key("base_color"));
+        assert!(model_crate.textures.content.is_empty());
+    }
+}
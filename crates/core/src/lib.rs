@@ -100,8 +100,53 @@ components!("app", {
         Description["The name of the project, from the manifest.\nDefaults to \"Ambient\"."]
     ]
     project_name: String,
+
+    @[
+        Debuggable, Networked,
+        Name["Reloaded assets"],
+        Description["Paths, relative to the build directory, of the files that changed in the latest rebuild of the project.\nAssets loaded from these paths are dropped from the asset cache and reloaded."]
+    ]
+    reloaded_assets: Vec<String>,
 });
 
+/// Returns true if the asset cache key, URL or path `key` refers to one of the `reloaded` paths (see [reloaded_assets]).
+///
+/// The paths are compared component by component after normalization, so `assets/a.json` matches
+/// `http://host/content/assets/a.json` and `assets/./a.json`, but not `assets/a.json.bak` or `other_assets/a.json`.
+pub fn is_reloaded_asset(key: &str, reloaded: &[String]) -> bool {
+    let reloaded = reloaded.iter().map(|path| path_components(path)).filter(|path| !path.is_empty()).collect::<Vec<_>>();
+    if reloaded.is_empty() {
+        return false;
+    }
+    // Asset cache keys are debug representations that can hold several URLs and paths, so each of them is checked
+    key.replace('\\', "/")
+        .split(|c: char| c.is_whitespace() || "\"'(){}[],".contains(c))
+        .map(|token| {
+            // Only the path of a URL is relevant
+            let token = match token.split_once("://") {
+                Some((_, rest)) => rest.find('/').map_or("", |i| &rest[i..]),
+                None => token,
+            };
+            path_components(token.split(['?', '#']).next().unwrap_or_default())
+        })
+        .any(|components| reloaded.iter().any(|path| components.windows(path.len()).any(|window| window == path.as_slice())))
+}
+
+/// The components of a `/`-separated path, with `.` and `..` resolved
+fn path_components(path: &str) -> Vec<&str> {
+    let mut components = Vec::new();
+    for component in path.split('/') {
+        match component {
+            "" | "." => {}
+            ".." => {
+                components.pop();
+            }
+            component => components.push(component),
+        }
+    }
+    components
+}
+
 pub fn init_all_components() {
     init_components();
     player::init_components();
@@ -185,3 +230,27 @@ pub enum GameMode {
     Edit,
     Play,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reloaded_assets_are_matched_by_path_components() {
+        let reloaded = vec!["assets/models/tree.glb/models/main.json".to_string(), "server/game.wasm".to_string()];
+        assert!(is_reloaded_asset("assets/models/tree.glb/models/main.json", &reloaded));
+        assert!(is_reloaded_asset("http://127.0.0.1:8999/content/assets/models/tree.glb/models/main.json", &reloaded));
+        assert!(is_reloaded_asset("http://127.0.0.1:8999/content/assets/models/./tree.glb/models/main.json?v=1#0", &reloaded));
+        assert!(is_reloaded_asset("C:\\project\\build\\server\\game.wasm", &reloaded));
+        assert!(is_reloaded_asset(
+            r#"BytesFromUrl { url: AbsAssetUrl(Url { scheme: "http", path: "/content/server/game.wasm", query: None }), cache_on_disk: true }"#,
+            &reloaded
+        ));
+
+        assert!(!is_reloaded_asset("http://127.0.0.1:8999/content/server/game.wasm.bak", &reloaded));
+        assert!(!is_reloaded_asset("http://127.0.0.1:8999/content/other_server/game.wasm", &reloaded));
+        assert!(!is_reloaded_asset("http://server/game.wasm", &reloaded));
+        assert!(!is_reloaded_asset("assets/models/tree.glb/models/main.json", &[]));
+        assert!(!is_reloaded_asset("anything", &["".to_string(), "./".to_string()]));
+    }
+}
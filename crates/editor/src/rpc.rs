@@ -61,7 +61,7 @@ pub async fn rpc_select(args: GameRpcArgs, (method, mode): (SelectMethod, Select
             SelectMethod::Ray(ray) => {
                 if let Some((entity, _)) = raycast_filtered(
                     world,
-                    RaycastFilter { entities: Some(ArchetypeFilter::new().incl(selectable())), collider_type: None },
+                    RaycastFilter { entities: Some(ArchetypeFilter::new().incl(selectable())), collider_type: None, layers: None },
                     ray,
                 ) {
                     Selection::new([entity])
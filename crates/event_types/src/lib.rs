@@ -2,10 +2,19 @@
 
 /// Fired each frame.
 pub const FRAME: &str = "core/frame";
-/// Fired on a collision. Components will contain the `ids` of the objects.
+/// Fired when a collision begins. Components will contain the `ids` of the objects, the `contact_points`, the `contact_normals`
+/// (pointing from the second object towards the first) and the total `contact_impulse`.
 pub const COLLISION: &str = "core/collision";
+/// Fired each physics step while a collision persists, for objects with a `contact_report_threshold`. Components are the same as for [COLLISION].
+pub const COLLISION_PERSIST: &str = "core/collision_persist";
+/// Fired when a collision ends, for objects with a `contact_report_threshold`. Components will contain the `ids` of the objects.
+pub const COLLISION_END: &str = "core/collision_end";
 /// Fired when a collider is loaded. Components will contain the `id` of the object.
 pub const COLLIDER_LOAD: &str = "core/collider_load";
+/// Fired when an entity enters a trigger area. Components will contain the `ids` of the trigger area and the entity, in that order.
+pub const TRIGGER_ENTER: &str = "core/trigger_enter";
+/// Fired when an entity leaves a trigger area. Components will contain the `ids` of the trigger area and the entity, in that order.
+pub const TRIGGER_EXIT: &str = "core/trigger_exit";
 /// Fired when the module is loaded.
 pub const MODULE_LOAD: &str = "core/module_load";
 /// Fired when the module is unloaded.
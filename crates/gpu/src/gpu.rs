@@ -77,6 +77,8 @@ impl Gpu {
         let features = wgpu::Features::empty();
         #[cfg(not(target_os = "macos"))]
         let features = wgpu::Features::MULTI_DRAW_INDIRECT | wgpu::Features::MULTI_DRAW_INDIRECT_COUNT;
+        // Block compressed textures are produced by the asset pipelines, but are not available on every GPU
+        let features = features | (adapter.features() & wgpu::Features::TEXTURE_COMPRESSION_BC);
 
         let (device, queue) = adapter
             .request_device(
@@ -9,7 +9,10 @@ use std::{
     },
 };
 
-use ambient_std::asset_cache::{AssetCache, SyncAssetKeyExt};
+use ambient_std::{
+    asset_cache::{AssetCache, SyncAssetKeyExt},
+    ktx2::{Ktx2, Ktx2Format},
+};
 use bytemuck::Pod;
 use byteorder::{ByteOrder, LittleEndian};
 use glam::{uvec2, UVec4, Vec4};
@@ -51,8 +54,12 @@ impl Texture {
     }
 
     fn size_in_bytes_from_desc(descriptor: &wgpu::TextureDescriptor) -> u64 {
-        let mut mip_size = (descriptor.size.width as u64 * descriptor.size.height as u64 * descriptor.size.depth_or_array_layers as u64)
-            * descriptor.format.describe().block_size as u64;
+        let info = descriptor.format.describe();
+        let (block_width, block_height) = (info.block_dimensions.0 as u64, info.block_dimensions.1 as u64);
+        let mut mip_size = (descriptor.size.width as u64 / block_width)
+            * (descriptor.size.height as u64 / block_height)
+            * descriptor.size.depth_or_array_layers as u64
+            * info.block_size as u64;
         let mut size_in_bytes = mip_size;
         for _ in 1..descriptor.mip_level_count {
             mip_size /= 2;
@@ -132,6 +139,35 @@ impl Texture {
         gpu.queue.submit(Some(encoder.finish()));
         texture
     }
+    /// Creates a texture from a KTX2 file, using the format and mip levels it was encoded with.
+    pub fn from_ktx2(gpu: Arc<Gpu>, ktx2: &Ktx2, label: wgpu::Label) -> anyhow::Result<Self> {
+        let format = match ktx2.format {
+            Ktx2Format::Rgba8Unorm => wgpu::TextureFormat::Rgba8Unorm,
+            Ktx2Format::Rgba8Srgb => wgpu::TextureFormat::Rgba8UnormSrgb,
+            Ktx2Format::Bc4Unorm => wgpu::TextureFormat::Bc4RUnorm,
+            Ktx2Format::Bc5Unorm => wgpu::TextureFormat::Bc5RgUnorm,
+            Ktx2Format::Bc7Unorm => wgpu::TextureFormat::Bc7RgbaUnorm,
+            Ktx2Format::Bc7Srgb => wgpu::TextureFormat::Bc7RgbaUnormSrgb,
+        };
+        let required_features = format.describe().required_features;
+        anyhow::ensure!(
+            gpu.device.features().contains(required_features),
+            "This GPU does not support {format:?} textures. Rebuild the assets with `\"format\": \"Uncompressed\"` texture compression"
+        );
+        Ok(Self::new_with_data(
+            gpu,
+            &wgpu::TextureDescriptor {
+                size: wgpu::Extent3d { width: ktx2.width, height: ktx2.height, depth_or_array_layers: 1 },
+                mip_level_count: ktx2.levels.len() as u32,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                label,
+            },
+            &ktx2.levels.concat(),
+        ))
+    }
     pub fn from_image(gpu: Arc<Gpu>, image: DynamicImage, format: wgpu::TextureFormat, label: wgpu::Label) -> Self {
         let img = image.into_rgba8();
 
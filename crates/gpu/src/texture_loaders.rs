@@ -1,9 +1,10 @@
 use std::{borrow::Cow, fmt, io::Cursor, sync::Arc};
 
 use ambient_std::{
-    asset_cache::{AssetCache, AsyncAssetKey, AsyncAssetKeyExt},
+    asset_cache::{AssetCache, AsyncAssetKey, AsyncAssetKeyExt, SyncAssetKeyExt},
     asset_url::AbsAssetUrl,
     download_asset::{AssetError, AssetResult, BytesFromUrl},
+    ktx2::Ktx2,
     CowStr,
 };
 use ambient_sys::task;
@@ -11,7 +12,7 @@ use async_trait::async_trait;
 use futures::future::join_all;
 use image::{DynamicImage, ImageFormat, Rgba, RgbaImage};
 
-use crate::texture::Texture;
+use crate::{gpu::GpuKey, texture::Texture};
 
 #[derive(Debug, Clone)]
 pub struct ImageFromUrl {
@@ -59,6 +60,16 @@ impl AsyncAssetKey<Result<Arc<Texture>, AssetError>> for TextureFromUrl {
     }
     #[tracing::instrument(level = "info", name = "texture_from_url")]
     async fn load(self, assets: AssetCache) -> Result<Arc<Texture>, AssetError> {
+        // KTX2 textures are already in a GPU format, with their mip levels, so `format` is not used
+        if self.url.extension_is(Ktx2::EXTENSION) {
+            let data = BytesFromUrl::new(self.url.clone(), true).get(&assets).await?;
+            return task::block_in_place(|| -> anyhow::Result<_> {
+                let ktx2 = Ktx2::from_bytes(&data)?;
+                Ok(Arc::new(Texture::from_ktx2(GpuKey.get(&assets), &ktx2, Some(&self.url.to_string()))?))
+            })
+            .with_context(|| format!("Failed to load texture {}", self.url))
+            .map_err(AssetError::from);
+        }
         let image = image_from_url(assets.clone(), self.url.clone()).await?;
         task::block_in_place(|| Ok(Arc::new(Texture::from_image_mipmapped(assets, image, self.format, Some(&self.url.to_string())))))
     }
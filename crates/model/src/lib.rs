@@ -5,7 +5,7 @@ use ambient_core::{
     async_ecs::{async_run, AsyncRun},
     bounding::{local_bounding_aabb, world_bounding_aabb, world_bounding_sphere},
     hierarchy::{children, despawn_recursive},
-    main_scene, runtime,
+    is_reloaded_asset, main_scene, reloaded_assets, runtime,
     transform::{get_world_position, inv_local_to_world, local_to_world, mesh_to_world},
 };
 use ambient_ecs::{
@@ -31,7 +31,7 @@ use ambient_std::{
 };
 use async_trait::async_trait;
 use futures::StreamExt;
-use glam::{vec4, Vec3};
+use glam::{vec4, Quat, Vec3};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 mod model;
@@ -50,6 +50,8 @@ components!("model", {
     animation_binder: HashMap<String, EntityId>,
     @[Debuggable, Networked, Store]
     animation_bind_id: String,
+    /// A pose blended over the animation of this model, i.e. to let physics drive its skeleton
+    animation_pose_override: AnimationPoseOverride,
 
     model: Arc<Model>,
     @[Debuggable, Networked, Store, Name["Model from URL"], Description["Load a model from the given URL or relative path."]]
@@ -70,6 +72,14 @@ components!("model", {
     is_model_node: (),
 });
 
+/// The `translation` and `rotation` of some of the entities in the `animation_binder` of a model, by their `animation_bind_id`.
+#[derive(Debug, Clone, Default)]
+pub struct AnimationPoseOverride {
+    /// How much of this pose to use, from 0 (only the animation) to 1 (only this pose)
+    pub weight: f32,
+    pub pose: HashMap<String, (Vec3, Quat)>,
+}
+
 #[tracing::instrument(skip(assets, async_run))]
 async fn internal_spawn_models_from_defs(
     assets: &AssetCache,
@@ -191,6 +201,24 @@ pub fn model_systems() -> SystemGroup {
                     remove_model(world, id);
                 }
             }),
+            query(reloaded_assets().changed()).to_system(|q, world, qs, _| {
+                for (_, reloaded) in q.collect_cloned(world, qs) {
+                    world.resource(asset_cache()).invalidate(|key| is_reloaded_asset(key, &reloaded));
+
+                    let models = query(model_from_url())
+                        .iter(world, None)
+                        .filter(|(_, url)| {
+                            let url = if url.contains("/models/") { url.to_string() } else { format!("{url}/models/main.json") };
+                            is_reloaded_asset(&url, &reloaded)
+                        })
+                        .map(|(id, url)| (id, url.clone()))
+                        .collect_vec();
+                    for (id, url) in models {
+                        // Setting the URL again marks it as changed, which respawns the model below
+                        world.set(id, model_from_url(), url).ok();
+                    }
+                }
+            }),
             query((model_from_url().changed(),)).to_system(|q, world, qs, _| {
                 let mut new_models = HashMap::<String, Vec<EntityId>>::new();
                 for (id, (model_from_url,)) in q.iter(world, qs) {
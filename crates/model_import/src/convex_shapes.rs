@@ -0,0 +1,127 @@
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use ambient_std::{mesh::Mesh, shapes::AABB};
+use glam::{vec3, Vec3};
+use itertools::Itertools;
+use parry3d::{
+    math::Point,
+    transformation::vhacd::{VHACDParameters, VHACD},
+};
+
+/// Splits `mesh` into at most `max_hulls` approximately convex parts using V-HACD, and returns the points of each.
+pub fn convex_decomposition(mesh: &Mesh, max_hulls: u32) -> Vec<Vec<Vec3>> {
+    let (Some(positions), Some(indices)) = (&mesh.positions, &mesh.indices) else { return vec![]; };
+    if indices.len() < 3 {
+        return vec![];
+    }
+    let points = positions.iter().map(|p| Point::new(p.x, p.y, p.z)).collect_vec();
+    let triangles = indices.chunks_exact(3).map(|t| [t[0], t[1], t[2]]).collect_vec();
+
+    let params = VHACDParameters { max_convex_hulls: max_hulls, ..Default::default() };
+    VHACD::decompose(&params, &points, &triangles, false)
+        .compute_convex_hulls(params.convex_hull_downsampling)
+        .into_iter()
+        .map(|(points, _)| points.into_iter().map(|p| vec3(p.x, p.y, p.z)).collect_vec())
+        .filter(|points| points.len() >= 4)
+        .collect()
+}
+
+/// The corners of the box that fits tightly around `points`.
+pub fn box_points(points: &[Vec3]) -> Vec<Vec3> {
+    AABB::from_points(points).to_box().points
+}
+
+/// Points on a capsule that fits tightly around `points`, lying along the longest side of their bounding box. The
+/// capsule's convex hull approximates it with a few dozen vertices.
+pub fn capsule_points(points: &[Vec3]) -> Vec<Vec3> {
+    const SEGMENTS: usize = 12;
+    const RINGS: usize = 4;
+
+    let aabb = AABB::from_points(points);
+    let center = aabb.center();
+    let size = aabb.size();
+    // The capsule's axis and two axes perpendicular to it. Z is preferred when the sides are equal, so that capsules stand up
+    let (axis, side_a, side_b) = if size.z >= size.x && size.z >= size.y {
+        (Vec3::Z, Vec3::X, Vec3::Y)
+    } else if size.x >= size.y {
+        (Vec3::X, Vec3::Y, Vec3::Z)
+    } else {
+        (Vec3::Y, Vec3::Z, Vec3::X)
+    };
+    let radius = size.dot(side_a).max(size.dot(side_b)) / 2.;
+    // Half the length of the cylinder between the two hemispheres
+    let half_length = (size.dot(axis) / 2. - radius).max(0.);
+
+    let mut res = vec![center + axis * (half_length + radius), center - axis * (half_length + radius)];
+    for ring in 0..RINGS {
+        let angle = ring as f32 / RINGS as f32 * FRAC_PI_2;
+        let (ring_radius, height) = (radius * angle.cos(), half_length + radius * angle.sin());
+        for segment in 0..SEGMENTS {
+            let angle = segment as f32 / SEGMENTS as f32 * TAU;
+            let offset = (side_a * angle.cos() + side_b * angle.sin()) * ring_radius;
+            res.push(center + offset + axis * height);
+            res.push(center + offset - axis * height);
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+fn cube(center: Vec3, half_size: f32) -> (Vec<Vec3>, Vec<u32>) {
+    let points = AABB { min: center - half_size, max: center + half_size }.to_box().points;
+    // The corners are ordered by x, then y, then z
+    let indices = vec![
+        0, 2, 6, 0, 6, 4, 1, 5, 7, 1, 7, 3, 0, 1, 3, 0, 3, 2, 4, 6, 7, 4, 7, 5, 0, 4, 5, 0, 5, 1, 2, 3, 7, 2, 7, 6,
+    ];
+    (points, indices)
+}
+
+#[test]
+fn test_convex_decomposition() {
+    assert!(convex_decomposition(&Mesh::default(), 8).is_empty());
+
+    // Two cubes apart from each other can't be covered by a single convex hull without including the gap between them
+    let (mut positions, mut indices) = cube(Vec3::ZERO, 1.);
+    let (other_positions, other_indices) = cube(vec3(5., 0., 0.), 1.);
+    indices.extend(other_indices.into_iter().map(|i| i + positions.len() as u32));
+    positions.extend(other_positions);
+    let mesh = Mesh { positions: Some(positions.clone()), indices: Some(indices), ..Default::default() };
+
+    let hulls = convex_decomposition(&mesh, 8);
+    assert!(hulls.len() >= 2 && hulls.len() <= 8);
+    // The hulls are computed from a voxelization of the mesh, so they can stick out of it by a fraction of a voxel
+    let bounds = AABB::from_points(&positions);
+    for hull in &hulls {
+        assert!(hull.len() >= 4);
+        assert!(hull.iter().all(|p| p.cmpge(bounds.min - 0.1).all() && p.cmple(bounds.max + 0.1).all()));
+    }
+    assert!(hulls.iter().any(|hull| hull.iter().all(|p| p.x < 2.)));
+    assert!(hulls.iter().any(|hull| hull.iter().all(|p| p.x > 3.)));
+}
+
+#[test]
+fn test_box_points() {
+    let points = box_points(&[vec3(-1., 0., 2.), vec3(1., 3., 0.), vec3(0., 1., 1.)]);
+    assert_eq!(points.len(), 8);
+    assert_eq!(AABB::from_points(&points), AABB { min: vec3(-1., 0., 0.), max: vec3(1., 3., 2.) });
+}
+
+#[test]
+fn test_capsule_points() {
+    for (axis, side) in [(Vec3::X, Vec3::Y), (Vec3::Y, Vec3::Z), (Vec3::Z, Vec3::X)] {
+        // A shape 4 long along `axis` and 1 wide
+        let center = vec3(1., 2., 3.);
+        let half_size = axis * 2. + (Vec3::ONE - axis) * 0.5;
+        let points = capsule_points(&[center - half_size, center + half_size]);
+
+        let bounds = AABB::from_points(&points);
+        assert!((bounds.min - (center - half_size)).abs().max_element() < 1e-4);
+        assert!((bounds.max - (center + half_size)).abs().max_element() < 1e-4);
+        for point in &points {
+            // Every point is at most the radius away from the segment between the centers of the hemispheres
+            let along = (*point - center).dot(axis).clamp(-1.5, 1.5);
+            assert!(point.distance(center + axis * along) < 0.5 + 1e-4);
+        }
+        assert!(points.iter().any(|&p| ((p - center).dot(side) - 0.5).abs() < 1e-4));
+    }
+}
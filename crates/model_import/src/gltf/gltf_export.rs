@@ -0,0 +1,623 @@
+use std::{collections::HashMap, io::Cursor};
+
+use ambient_animation::{animation_bind_id_from_name, AnimationOutputs};
+use ambient_core::{
+    hierarchy::children,
+    name,
+    transform::{mesh_to_local, rotation, scale, translation},
+};
+use ambient_ecs::{EntityId, World};
+use ambient_model::{
+    animation_bind_id, model_from_url, model_skin_ix, model_skins, pbr_renderer_primitives_from_url, Model, PbrRenderPrimitiveFromUrl,
+};
+use ambient_std::{asset_url::AssetUrl, mesh::Mesh};
+use anyhow::Context;
+use glam::{Mat4, Quat, Vec3, Vec4};
+use image::ImageOutputFormat;
+use itertools::Itertools;
+use serde_json::{json, Value};
+
+use crate::model_crate::ModelCrate;
+
+const FLOAT: u32 = 5126;
+const UNSIGNED_SHORT: u32 = 5123;
+const UNSIGNED_INT: u32 = 5125;
+const ARRAY_BUFFER: u32 = 34962;
+const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+
+/// A glTF 2.0 document, and the binary buffer its accessors and images point into.
+pub struct GltfExport {
+    json: Value,
+    buffer: Vec<u8>,
+}
+impl GltfExport {
+    /// Binary glTF (`.glb`), with the buffer stored in the same file.
+    pub fn to_glb(&self) -> Vec<u8> {
+        let mut json = self.json.clone();
+        if !self.buffer.is_empty() {
+            json["buffers"] = json!([{ "byteLength": self.buffer.len() }]);
+        }
+        let mut json = serde_json::to_vec(&json).unwrap();
+        pad_to_4(&mut json, b' ');
+        let mut buffer = self.buffer.clone();
+        pad_to_4(&mut buffer, 0);
+
+        let bin_chunk_size = if buffer.is_empty() { 0 } else { 8 + buffer.len() };
+        let total_size = 12 + 8 + json.len() + bin_chunk_size;
+        let mut res = Vec::with_capacity(total_size);
+        res.extend(b"glTF");
+        res.extend(2u32.to_le_bytes());
+        res.extend((total_size as u32).to_le_bytes());
+        res.extend((json.len() as u32).to_le_bytes());
+        res.extend(b"JSON");
+        res.extend(json);
+        if !buffer.is_empty() {
+            res.extend((buffer.len() as u32).to_le_bytes());
+            res.extend(b"BIN\0");
+            res.extend(buffer);
+        }
+        res
+    }
+    /// JSON glTF (`.gltf`), with the buffer embedded as a base64 data URI.
+    pub fn to_gltf(&self) -> Vec<u8> {
+        let mut json = self.json.clone();
+        if !self.buffer.is_empty() {
+            json["buffers"] = json!([{
+                "byteLength": self.buffer.len(),
+                "uri": format!("data:application/octet-stream;base64,{}", base64::encode(&self.buffer)),
+            }]);
+        }
+        serde_json::to_vec_pretty(&json).unwrap()
+    }
+}
+
+fn pad_to_4(data: &mut Vec<u8>, padding: u8) {
+    while data.len() % 4 != 0 {
+        data.push(padding);
+    }
+}
+
+/// Exports the model `model_id` of `model_crate`, along with its meshes, materials, skins, and the animations of the
+/// crate that target its nodes.
+///
+/// Textures that aren't images in the crate, such as compressed textures, can't be exported, and fail the export unless
+/// `skip_missing_textures` is set.
+pub fn export_model(model_crate: &ModelCrate, model_id: &str, skip_missing_textures: bool) -> anyhow::Result<GltfExport> {
+    let model = model_crate.models.content.get(model_id).with_context(|| format!("There is no model {model_id:?} in the crate"))?;
+    let mut exporter = GltfExporter::new(skip_missing_textures);
+    let roots = exporter.add_model(model_crate, &model.0)?;
+    Ok(exporter.finish(roots))
+}
+
+/// Exports the prefab `prefab_id` of `model_crate`. The prefab's entities become nodes, and the models they load with
+/// `model_from_url` are placed under them.
+///
+/// References to other prefabs are not followed; load the prefab with `PrefabFromUrl` and use [export_world] to include
+/// them.
+pub fn export_prefab(model_crate: &ModelCrate, prefab_id: &str, skip_missing_textures: bool) -> anyhow::Result<GltfExport> {
+    let prefab = model_crate.prefabs.content.get(prefab_id).with_context(|| format!("There is no prefab {prefab_id:?} in the crate"))?;
+    let roots = prefab.resource_opt(children()).cloned().unwrap_or_default();
+    export_world(
+        prefab,
+        &roots,
+        |url| {
+            let model = model_crate.models.get_by_path(url).with_context(|| format!("The prefab's model {url} is not in the crate"))?;
+            Ok((model_crate, model))
+        },
+        skip_missing_textures,
+    )
+}
+
+/// Exports the entities `roots` of `world` and their descendants, i.e. a prefab loaded with `PrefabFromUrl`, or a part of
+/// a running world. The entities become nodes, and the models they load with `model_from_url` are placed under them.
+///
+/// `model` looks up the model at a `model_from_url` url, along with the crate it's in.
+pub fn export_world<'a>(
+    world: &World,
+    roots: &[EntityId],
+    model: impl Fn(&str) -> anyhow::Result<(&'a ModelCrate, &'a Model)>,
+    skip_missing_textures: bool,
+) -> anyhow::Result<GltfExport> {
+    let mut exporter = GltfExporter::new(skip_missing_textures);
+    let roots = roots.iter().map(|&id| exporter.add_world_node(world, id, &model)).collect::<anyhow::Result<Vec<_>>>()?;
+    Ok(exporter.finish(roots))
+}
+
+/// Exports the models of one or more crates. Meshes, materials and textures are identified by the index of their crate in
+/// `crates` and their path in it.
+struct GltfExporter<'a> {
+    skip_missing_textures: bool,
+    crates: Vec<&'a ModelCrate>,
+    buffer: Vec<u8>,
+    buffer_views: Vec<Value>,
+    accessors: Vec<Value>,
+    nodes: Vec<Value>,
+    meshes: Vec<Value>,
+    materials: Vec<Value>,
+    images: Vec<Value>,
+    textures: Vec<Value>,
+    skins: Vec<Value>,
+    /// The attributes and indices of each exported mesh, by path, so that meshes used by several nodes are only
+    /// written once
+    primitives: HashMap<(usize, String), Value>,
+    material_indices: HashMap<(usize, String), usize>,
+    texture_indices: HashMap<(usize, String), usize>,
+    /// The nodes that the animation tracks of each crate can target
+    bind_ids: HashMap<(usize, String), usize>,
+}
+impl<'a> GltfExporter<'a> {
+    fn new(skip_missing_textures: bool) -> Self {
+        Self {
+            skip_missing_textures,
+            crates: Vec::new(),
+            buffer: Vec::new(),
+            buffer_views: Vec::new(),
+            accessors: Vec::new(),
+            nodes: Vec::new(),
+            meshes: Vec::new(),
+            materials: Vec::new(),
+            images: Vec::new(),
+            textures: Vec::new(),
+            skins: Vec::new(),
+            primitives: HashMap::new(),
+            material_indices: HashMap::new(),
+            texture_indices: HashMap::new(),
+            bind_ids: HashMap::new(),
+        }
+    }
+
+    fn add_buffer_view(&mut self, data: &[u8], target: Option<u32>) -> usize {
+        pad_to_4(&mut self.buffer, 0);
+        let mut view = json!({ "buffer": 0, "byteOffset": self.buffer.len(), "byteLength": data.len() });
+        if let Some(target) = target {
+            view["target"] = json!(target);
+        }
+        self.buffer.extend_from_slice(data);
+        self.buffer_views.push(view);
+        self.buffer_views.len() - 1
+    }
+    fn add_accessor(
+        &mut self,
+        data: &[u8],
+        count: usize,
+        component_type: u32,
+        type_: &str,
+        target: Option<u32>,
+        min_max: Option<(Value, Value)>,
+    ) -> usize {
+        let view = self.add_buffer_view(data, target);
+        let mut accessor = json!({ "bufferView": view, "componentType": component_type, "count": count, "type": type_ });
+        if let Some((min, max)) = min_max {
+            accessor["min"] = min;
+            accessor["max"] = max;
+        }
+        self.accessors.push(accessor);
+        self.accessors.len() - 1
+    }
+    fn add_node(&mut self, node: Value) -> usize {
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+
+    fn crate_index(&mut self, model_crate: &'a ModelCrate) -> usize {
+        if let Some(index) = self.crates.iter().position(|c| std::ptr::eq(*c, model_crate)) {
+            return index;
+        }
+        self.crates.push(model_crate);
+        self.crates.len() - 1
+    }
+
+    /// Adds the nodes of a model of `model_crate`, and returns its roots.
+    fn add_model(&mut self, model_crate: &'a ModelCrate, world: &World) -> anyhow::Result<Vec<usize>> {
+        let c = self.crate_index(model_crate);
+        let mut nodes = HashMap::new();
+        let roots = world
+            .resource_opt(children())
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|id| self.add_model_node(c, world, id, &mut nodes))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        // Skins refer to their joints by node, so they can only be added once all of the nodes have been
+        let skin_offset = self.skins.len();
+        for skin in world.resource_opt(model_skins()).cloned().unwrap_or_default() {
+            let joints = skin.joints.iter().map(|id| nodes.get(id).map(|(node, _)| *node)).collect::<Option<Vec<_>>>();
+            let mut skin_json = json!({ "joints": joints.context("A skin has a joint outside of the model")? });
+            if !skin.inverse_bind_matrices.is_empty() {
+                let matrices = &*skin.inverse_bind_matrices;
+                skin_json["inverseBindMatrices"] =
+                    json!(self.add_accessor(bytemuck::cast_slice(matrices), matrices.len(), FLOAT, "MAT4", None, None));
+            }
+            self.skins.push(skin_json);
+        }
+        for (id, (_, mesh_node)) in &nodes {
+            if let Ok(skin) = world.get(*id, model_skin_ix()) {
+                self.nodes[*mesh_node]["skin"] = json!(skin_offset + skin);
+            }
+        }
+        Ok(roots)
+    }
+    /// Adds a node of a model of the crate `c` and its children. `nodes` maps each entity to its node, and to the node its
+    /// mesh is on.
+    fn add_model_node(
+        &mut self,
+        c: usize,
+        world: &World,
+        id: EntityId,
+        nodes: &mut HashMap<EntityId, (usize, usize)>,
+    ) -> anyhow::Result<usize> {
+        let mut children_nodes = world
+            .get_ref(id, children())
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|child| self.add_model_node(c, world, child, nodes))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let mut node = node_json(world, id);
+        let mut mesh_node = None;
+        if let Ok(primitives) = world.get_ref(id, pbr_renderer_primitives_from_url()) {
+            let mesh = self.add_mesh(c, primitives)?;
+            match world.get(id, mesh_to_local()) {
+                // glTF has no separate transform for meshes, so the mesh is put on a child node instead
+                Ok(mesh_to_local) if mesh_to_local != Mat4::IDENTITY => {
+                    let child = self.add_node(json!({ "mesh": mesh, "matrix": mesh_to_local.to_cols_array() }));
+                    children_nodes.push(child);
+                    mesh_node = Some(child);
+                }
+                _ => node["mesh"] = json!(mesh),
+            }
+        }
+        if !children_nodes.is_empty() {
+            node["children"] = json!(children_nodes);
+        }
+        let index = self.add_node(node);
+        nodes.insert(id, (index, mesh_node.unwrap_or(index)));
+
+        let bind_id = world
+            .get_ref(id, animation_bind_id())
+            .cloned()
+            .ok()
+            .or_else(|| world.get_ref(id, name()).ok().map(|name| animation_bind_id_from_name(name)));
+        if let Some(bind_id) = bind_id {
+            self.bind_ids.entry((c, bind_id)).or_insert(index);
+        }
+        Ok(index)
+    }
+    fn add_world_node(
+        &mut self,
+        world: &World,
+        id: EntityId,
+        model: &impl Fn(&str) -> anyhow::Result<(&'a ModelCrate, &'a Model)>,
+    ) -> anyhow::Result<usize> {
+        let mut children_nodes = world
+            .get_ref(id, children())
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|child| self.add_world_node(world, child, model))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        if let Ok(url) = world.get_ref(id, model_from_url()) {
+            let (model_crate, model) = model(url)?;
+            children_nodes.extend(self.add_model(model_crate, &model.0)?);
+        }
+        let mut node = node_json(world, id);
+        if !children_nodes.is_empty() {
+            node["children"] = json!(children_nodes);
+        }
+        Ok(self.add_node(node))
+    }
+
+    fn add_mesh(&mut self, c: usize, primitives: &[PbrRenderPrimitiveFromUrl]) -> anyhow::Result<usize> {
+        let mut primitives_json = Vec::new();
+        // Levels of detail are not exported, as glTF has no standard way to express them
+        for primitive in primitives.iter().filter(|primitive| primitive.lod == 0) {
+            let mut primitive_json = self.add_primitive(c, &primitive.mesh)?;
+            if let Some(material) = &primitive.material {
+                primitive_json["material"] = json!(self.add_material(c, material)?);
+            }
+            primitives_json.push(primitive_json);
+        }
+        self.meshes.push(json!({ "primitives": primitives_json }));
+        Ok(self.meshes.len() - 1)
+    }
+    fn add_primitive(&mut self, c: usize, url: &AssetUrl) -> anyhow::Result<Value> {
+        let key = (c, url.path().to_string());
+        if let Some(primitive) = self.primitives.get(&key) {
+            return Ok(primitive.clone());
+        }
+        let model_crate = self.crates[c];
+        let mesh: &Mesh = model_crate.meshes.get_by_path(url.path()).with_context(|| format!("The mesh {url} is not in the crate"))?;
+
+        let mut attributes = serde_json::Map::new();
+        if let Some(positions) = mesh.positions.as_ref().filter(|positions| !positions.is_empty()) {
+            let min = positions.iter().fold(Vec3::splat(f32::MAX), |min, p| min.min(*p));
+            let max = positions.iter().fold(Vec3::splat(f32::MIN), |max, p| max.max(*p));
+            let min_max = Some((json!(min.to_array()), json!(max.to_array())));
+            let accessor = self.add_accessor(bytemuck::cast_slice(positions), positions.len(), FLOAT, "VEC3", Some(ARRAY_BUFFER), min_max);
+            attributes.insert("POSITION".to_string(), json!(accessor));
+        }
+        if let Some(normals) = &mesh.normals {
+            let accessor = self.add_accessor(bytemuck::cast_slice(normals), normals.len(), FLOAT, "VEC3", Some(ARRAY_BUFFER), None);
+            attributes.insert("NORMAL".to_string(), json!(accessor));
+        }
+        if let Some(tangents) = &mesh.tangents {
+            // Tangents are stored without their handedness, which is always positive
+            let tangents = tangents.iter().map(|t| t.extend(1.)).collect_vec();
+            let accessor = self.add_accessor(bytemuck::cast_slice(&tangents), tangents.len(), FLOAT, "VEC4", Some(ARRAY_BUFFER), None);
+            attributes.insert("TANGENT".to_string(), json!(accessor));
+        }
+        for (i, texcoords) in mesh.texcoords.iter().enumerate() {
+            let accessor = self.add_accessor(bytemuck::cast_slice(texcoords), texcoords.len(), FLOAT, "VEC2", Some(ARRAY_BUFFER), None);
+            attributes.insert(format!("TEXCOORD_{i}"), json!(accessor));
+        }
+        if let Some(colors) = &mesh.colors {
+            let accessor = self.add_accessor(bytemuck::cast_slice(colors), colors.len(), FLOAT, "VEC4", Some(ARRAY_BUFFER), None);
+            attributes.insert("COLOR_0".to_string(), json!(accessor));
+        }
+        if let Some(joints) = &mesh.joint_indices {
+            let joints = joints.iter().map(|j| [j.x as u16, j.y as u16, j.z as u16, j.w as u16]).collect_vec();
+            let accessor = self.add_accessor(bytemuck::cast_slice(&joints), joints.len(), UNSIGNED_SHORT, "VEC4", Some(ARRAY_BUFFER), None);
+            attributes.insert("JOINTS_0".to_string(), json!(accessor));
+        }
+        if let Some(weights) = &mesh.joint_weights {
+            let accessor = self.add_accessor(bytemuck::cast_slice(weights), weights.len(), FLOAT, "VEC4", Some(ARRAY_BUFFER), None);
+            attributes.insert("WEIGHTS_0".to_string(), json!(accessor));
+        }
+
+        let mut primitive = json!({ "attributes": attributes });
+        if let Some(indices) = &mesh.indices {
+            // The winding order is flipped when importing glTF, so it's flipped back here
+            let indices = indices.chunks(3).flat_map(|t| if t.len() == 3 { vec![t[0], t[2], t[1]] } else { t.to_vec() }).collect_vec();
+            let accessor =
+                self.add_accessor(bytemuck::cast_slice(&indices), indices.len(), UNSIGNED_INT, "SCALAR", Some(ELEMENT_ARRAY_BUFFER), None);
+            primitive["indices"] = json!(accessor);
+        }
+        self.primitives.insert(key, primitive.clone());
+        Ok(primitive)
+    }
+
+    fn add_material(&mut self, c: usize, url: &AssetUrl) -> anyhow::Result<usize> {
+        let key = (c, url.path().to_string());
+        if let Some(index) = self.material_indices.get(&key) {
+            return Ok(*index);
+        }
+        let model_crate = self.crates[c];
+        let material = model_crate.materials.get_by_path(url.path()).with_context(|| format!("The material {url} is not in the crate"))?;
+
+        let mut pbr = json!({
+            "baseColorFactor": material.base_color_factor.unwrap_or(Vec4::ONE).to_array(),
+            "metallicFactor": material.metallic,
+            "roughnessFactor": material.roughness,
+        });
+        if let Some(texture) =
+            material.base_color.as_ref().map(|url| self.add_texture(c, url, material.opacity.as_ref(), false)).transpose()?.flatten()
+        {
+            pbr["baseColorTexture"] = json!({ "index": texture });
+        }
+        if let Some(texture) = material.metallic_roughness.as_ref().map(|url| self.add_texture(c, url, None, true)).transpose()?.flatten() {
+            pbr["metallicRoughnessTexture"] = json!({ "index": texture });
+        }
+        let mut material_json = json!({ "pbrMetallicRoughness": pbr, "doubleSided": material.double_sided.unwrap_or(false) });
+        if let Some(name) = &material.name {
+            material_json["name"] = json!(name);
+        }
+        if let Some(texture) = material.normalmap.as_ref().map(|url| self.add_texture(c, url, None, false)).transpose()?.flatten() {
+            material_json["normalTexture"] = json!({ "index": texture });
+        }
+        if let Some(emissive) = material.emissive_factor {
+            material_json["emissiveFactor"] = json!(emissive.truncate().to_array());
+        }
+        if material.transparent == Some(true) {
+            material_json["alphaMode"] = json!("BLEND");
+        } else if let Some(alpha_cutoff) = material.alpha_cutoff {
+            material_json["alphaMode"] = json!("MASK");
+            material_json["alphaCutoff"] = json!(alpha_cutoff);
+        }
+
+        self.materials.push(material_json);
+        self.material_indices.insert(key, self.materials.len() - 1);
+        Ok(self.materials.len() - 1)
+    }
+    /// Adds an image of the crate `c` as a PNG texture. Textures that aren't images in the crate, such as compressed
+    /// textures, are an error, or are left out with `None` if `skip_missing_textures` is set.
+    fn add_texture(
+        &mut self,
+        c: usize,
+        url: &AssetUrl,
+        opacity: Option<&AssetUrl>,
+        metallic_roughness: bool,
+    ) -> anyhow::Result<Option<usize>> {
+        let key = (c, format!("{}:{}:{metallic_roughness}", url.path(), opacity.map(|url| url.path()).unwrap_or_default()));
+        if let Some(index) = self.texture_indices.get(&key) {
+            return Ok(Some(*index));
+        }
+        let model_crate = self.crates[c];
+        let Some(image) = model_crate.images.get_by_path(url.path()) else {
+            anyhow::ensure!(
+                self.skip_missing_textures,
+                "The texture {url} is not an image of the crate, i.e. a compressed texture, and can't be exported"
+            );
+            log::warn!("Skipping texture {url}, as only the images of the crate can be exported");
+            return Ok(None);
+        };
+        let mut image = image.clone();
+        // glTF keeps the opacity in the alpha channel of the base color
+        if let Some(opacity) = opacity.and_then(|url| model_crate.images.get_by_path(url.path())) {
+            if opacity.dimensions() == image.dimensions() {
+                for (color, opacity) in image.pixels_mut().zip(opacity.pixels()) {
+                    color[3] = opacity[0];
+                }
+            }
+        }
+        // Metallic is in the red channel in Ambient, and in the blue channel in glTF
+        if metallic_roughness {
+            for p in image.pixels_mut() {
+                p[2] = p[0];
+                p[0] = 0;
+            }
+        }
+        let mut data = Cursor::new(Vec::new());
+        image.write_to(&mut data, ImageOutputFormat::Png).unwrap();
+        let view = self.add_buffer_view(&data.into_inner(), None);
+        self.images.push(json!({ "bufferView": view, "mimeType": "image/png" }));
+        self.textures.push(json!({ "source": self.images.len() - 1 }));
+        self.texture_indices.insert(key, self.textures.len() - 1);
+        Ok(Some(self.textures.len() - 1))
+    }
+
+    fn add_animations(&mut self) -> Vec<Value> {
+        let mut animations = Vec::new();
+        for (c, model_crate) in self.crates.clone().into_iter().enumerate() {
+            for (id, clip) in model_crate.animations.content.iter().sorted_by_key(|(id, _)| *id) {
+                let mut clip = clip.clone();
+                clip.merge_field_tracks();
+                let mut samplers = Vec::new();
+                let mut channels = Vec::new();
+                for track in &clip.tracks {
+                    let bind_id = track.target.bind_id().map(|bind_id| (c, bind_id.to_string()));
+                    let Some(node) = bind_id.and_then(|bind_id| self.bind_ids.get(&bind_id)).copied() else { continue; };
+                    let (path, output) = match &track.outputs {
+                        AnimationOutputs::Vec3 { component, data } if *component == translation() || *component == scale() => {
+                            let path = if *component == translation() { "translation" } else { "scale" };
+                            (path, self.add_accessor(bytemuck::cast_slice(data), data.len(), FLOAT, "VEC3", None, None))
+                        }
+                        AnimationOutputs::Quat { component, data } if *component == rotation() => {
+                            ("rotation", self.add_accessor(bytemuck::cast_slice(data), data.len(), FLOAT, "VEC4", None, None))
+                        }
+                        _ => continue,
+                    };
+                    let min = track.inputs.iter().copied().fold(f32::MAX, f32::min);
+                    let max = track.inputs.iter().copied().fold(f32::MIN, f32::max);
+                    let input = self.add_accessor(
+                        bytemuck::cast_slice(&track.inputs),
+                        track.inputs.len(),
+                        FLOAT,
+                        "SCALAR",
+                        None,
+                        Some((json!([min]), json!([max]))),
+                    );
+                    samplers.push(json!({ "input": input, "output": output }));
+                    channels.push(json!({ "sampler": samplers.len() - 1, "target": { "node": node, "path": path } }));
+                }
+                if !channels.is_empty() {
+                    let name = if clip.id.is_empty() { id } else { &clip.id };
+                    animations.push(json!({ "name": name, "samplers": samplers, "channels": channels }));
+                }
+            }
+        }
+        animations
+    }
+
+    fn finish(mut self, roots: Vec<usize>) -> GltfExport {
+        let animations = self.add_animations();
+        let mut json = json!({
+            "asset": { "version": "2.0", "generator": "Ambient" },
+            "scene": 0,
+            "scenes": [{ "nodes": roots }],
+        });
+        // glTF doesn't allow empty arrays, so they're left out instead
+        for (key, values) in [
+            ("nodes", self.nodes),
+            ("meshes", self.meshes),
+            ("materials", self.materials),
+            ("images", self.images),
+            ("textures", self.textures),
+            ("skins", self.skins),
+            ("animations", animations),
+            ("accessors", self.accessors),
+            ("bufferViews", self.buffer_views),
+        ] {
+            if !values.is_empty() {
+                json[key] = json!(values);
+            }
+        }
+        GltfExport { json, buffer: self.buffer }
+    }
+}
+
+fn node_json(world: &World, id: EntityId) -> Value {
+    let mut node = json!({
+        "translation": world.get(id, translation()).unwrap_or(Vec3::ZERO).to_array(),
+        "rotation": world.get(id, rotation()).unwrap_or(Quat::IDENTITY).to_array(),
+        "scale": world.get(id, scale()).unwrap_or(Vec3::ONE).to_array(),
+    });
+    if let Ok(name) = world.get_ref(id, name()) {
+        node["name"] = json!(name);
+    }
+    node
+}
+
+#[test]
+fn test_roundtrip() {
+    use ambient_ecs::Entity;
+    use ambient_model::Model;
+    use ambient_renderer::materials::pbr_material::PbrMaterialDesc;
+    use glam::{vec3, vec4};
+
+    use super::{gltf_import::GltfImport, import};
+    use crate::dotdot_path;
+
+    ambient_app::init_all_components();
+    let positions = vec![Vec3::ZERO, Vec3::X, Vec3::Y];
+    let mut model_crate = ModelCrate::new();
+    let mesh =
+        Mesh { positions: Some(positions.clone()), normals: Some(vec![Vec3::Z; 3]), indices: Some(vec![0, 1, 2]), ..Default::default() };
+    let mesh_path = model_crate.meshes.insert("triangle", mesh).path;
+    let material = PbrMaterialDesc { base_color_factor: Some(vec4(1., 0., 0., 1.)), ..Default::default() };
+    let material_path = model_crate.materials.insert("red", material).path;
+    let mut world = World::new("model");
+    let primitive =
+        PbrRenderPrimitiveFromUrl { mesh: dotdot_path(mesh_path).into(), material: Some(dotdot_path(material_path).into()), lod: 0 };
+    let node = Entity::new()
+        .with(name(), "triangle".to_string())
+        .with(translation(), vec3(1., 2., 3.))
+        .with(pbr_renderer_primitives_from_url(), vec![primitive])
+        .spawn(&mut world);
+    world.add_resource(children(), vec![node]);
+    model_crate.models.insert(ModelCrate::MAIN, Model(world));
+
+    let glb = export_model(&model_crate, ModelCrate::MAIN, false).unwrap().to_glb();
+    let mut imported = ModelCrate::new();
+    let gltf = GltfImport::from_slice("test".to_string(), true, &glb).unwrap();
+    futures::executor::block_on(import(&gltf, &mut imported)).unwrap();
+
+    let mesh = imported.meshes.content.values().next().unwrap();
+    assert_eq!(mesh.positions, Some(positions));
+    assert_eq!(mesh.indices, Some(vec![0, 1, 2]));
+    let material = imported.materials.content.values().next().unwrap();
+    assert_eq!(material.base_color_factor, Some(vec4(1., 0., 0., 1.)));
+    let world = imported.model_world();
+    let node = world.resource(children())[0];
+    assert_eq!(world.get(node, translation()).unwrap(), vec3(1., 2., 3.));
+    assert_eq!(world.get_ref(node, name()).unwrap(), "triangle");
+}
+
+#[test]
+fn test_missing_textures() {
+    use ambient_ecs::Entity;
+    use ambient_model::Model;
+    use ambient_renderer::materials::pbr_material::PbrMaterialDesc;
+
+    use crate::dotdot_path;
+
+    ambient_app::init_all_components();
+    let mut model_crate = ModelCrate::new();
+    let mesh = Mesh { positions: Some(vec![Vec3::ZERO, Vec3::X, Vec3::Y]), indices: Some(vec![0, 1, 2]), ..Default::default() };
+    let mesh_path = model_crate.meshes.insert("triangle", mesh).path;
+    // Compressed textures are stored as files in the crate, rather than as images
+    let material = PbrMaterialDesc { base_color: Some(dotdot_path("textures/base_color.ktx2").into()), ..Default::default() };
+    let material_path = model_crate.materials.insert("textured", material).path;
+    let mut world = World::new("model");
+    let primitive =
+        PbrRenderPrimitiveFromUrl { mesh: dotdot_path(mesh_path).into(), material: Some(dotdot_path(material_path).into()), lod: 0 };
+    let node = Entity::new().with(pbr_renderer_primitives_from_url(), vec![primitive]).spawn(&mut world);
+    world.add_resource(children(), vec![node]);
+    model_crate.models.insert(ModelCrate::MAIN, Model(world));
+
+    let err = export_model(&model_crate, ModelCrate::MAIN, false).err().expect("missing textures should fail the export");
+    assert!(err.to_string().contains("textures/base_color.ktx2"));
+    let export = export_model(&model_crate, ModelCrate::MAIN, true).unwrap();
+    assert!(export.json["materials"][0]["pbrMetallicRoughness"].get("baseColorTexture").is_none());
+}
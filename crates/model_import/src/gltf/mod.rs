@@ -16,9 +16,11 @@ use gltf::animation::util::ReadOutputs;
 use itertools::Itertools;
 use relative_path::RelativePathBuf;
 
+pub use self::gltf_export::{export_model, export_prefab, GltfExport};
 use self::gltf_import::GltfImport;
 use crate::{dotdot_path, model_crate::ModelCrate};
 
+mod gltf_export;
 mod gltf_import;
 
 pub async fn import_url(assets: &AssetCache, url: &AbsAssetUrl, asset_crate: &mut ModelCrate) -> anyhow::Result<RelativePathBuf> {
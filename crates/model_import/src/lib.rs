@@ -19,8 +19,10 @@ use relative_path::RelativePathBuf;
 use serde::{Deserialize, Serialize};
 
 pub mod assimp;
+pub mod convex_shapes;
 pub mod fbx;
 pub mod gltf;
+pub mod mesh_lod;
 pub mod model_crate;
 
 pub type TextureResolver = Arc<dyn Fn(String) -> futures::future::BoxFuture<'static, Option<RgbaImage>> + Sync + Send>;
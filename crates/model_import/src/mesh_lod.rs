@@ -0,0 +1,80 @@
+use ambient_std::mesh::Mesh;
+use glam::Vec3;
+use meshopt::VertexDataAdapter;
+use serde::{Deserialize, Serialize};
+
+/// A level of detail to generate by simplifying a mesh.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LodLevel {
+    /// The fraction of the original mesh's triangles to keep, i.e. `0.5` for half of them.
+    pub ratio: f32,
+    /// The largest deviation from the original surface allowed, relative to the size of the mesh.
+    /// Simplification stops short of `ratio` rather than exceed this. Defaults to `0.01`.
+    #[serde(default = "default_max_error")]
+    pub max_error: f32,
+}
+impl LodLevel {
+    /// Half, a quarter and an eighth of the original triangles.
+    pub fn defaults() -> Vec<Self> {
+        [0.5, 0.25, 0.125].into_iter().map(|ratio| Self { ratio, max_error: default_max_error() }).collect()
+    }
+}
+
+fn default_max_error() -> f32 {
+    0.01
+}
+
+fn position_adapter(positions: &[Vec3]) -> VertexDataAdapter<'_> {
+    VertexDataAdapter::new(bytemuck::cast_slice(positions), std::mem::size_of::<Vec3>(), 0).unwrap()
+}
+
+/// Returns a copy of `mesh` simplified by quadric edge collapse, or `None` if no triangles could be removed within
+/// the error allowed by `level`.
+pub fn simplify_mesh(mesh: &Mesh, level: LodLevel) -> Option<Mesh> {
+    let (Some(positions), Some(indices)) = (&mesh.positions, &mesh.indices) else { return None; };
+    let target_count = ((indices.len() / 3) as f32 * level.ratio.clamp(0., 1.)) as usize * 3;
+    let simplified = meshopt::simplify(indices, &position_adapter(positions), target_count, level.max_error);
+    if simplified.is_empty() || simplified.len() >= indices.len() {
+        return None;
+    }
+
+    let mut mesh = Mesh { indices: Some(simplified), ..mesh.clone() };
+    mesh.remove_unused_vertices();
+    Some(mesh)
+}
+
+/// Reorders the triangles of `mesh` to make better use of the GPU's vertex cache, and then to reduce overdraw.
+pub fn optimize_mesh(mesh: &mut Mesh) {
+    let (Some(positions), Some(indices)) = (&mesh.positions, &mut mesh.indices) else { return; };
+    let mut optimized = meshopt::optimize_vertex_cache(indices, positions.len());
+    // Allow the vertex cache efficiency to get up to 5% worse in exchange for less overdraw
+    meshopt::optimize_overdraw_in_place(&mut optimized, &position_adapter(positions), 1.05);
+    *indices = optimized;
+}
+
+#[test]
+fn test_simplify_mesh() {
+    // A flat 16x16 grid, which can be collapsed to very few triangles without any error
+    let size = 17;
+    let positions = (0..size * size).map(|i| Vec3::new((i % size) as f32, (i / size) as f32, 0.)).collect::<Vec<_>>();
+    let mut indices = Vec::new();
+    for y in 0..size - 1 {
+        for x in 0..size - 1 {
+            let i = y * size + x;
+            indices.extend([i, i + 1, i + size, i + 1, i + size + 1, i + size]);
+        }
+    }
+    let mesh = Mesh { positions: Some(positions), indices: Some(indices.clone()), ..Default::default() };
+
+    let simplified = simplify_mesh(&mesh, LodLevel { ratio: 0.25, max_error: 0.01 }).unwrap();
+    let simplified_indices = simplified.indices.as_ref().unwrap();
+    assert!(simplified_indices.len() <= indices.len() / 4);
+    assert!(simplified_indices.iter().all(|&i| (i as usize) < simplified.positions.as_ref().unwrap().len()));
+
+    let mut optimized = mesh;
+    optimize_mesh(&mut optimized);
+    let mut optimized_indices = optimized.indices.unwrap();
+    indices.sort();
+    optimized_indices.sort();
+    assert_eq!(optimized_indices, indices);
+}
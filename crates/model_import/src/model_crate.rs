@@ -1,4 +1,9 @@
-use std::{collections::HashMap, io::Cursor, path::PathBuf, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    io::Cursor,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use ambient_animation::{animation_bind_id_from_name, AnimationClip};
 use ambient_core::{
@@ -14,11 +19,12 @@ use ambient_model::{
 use ambient_physics::{
     collider::{character_controller_height, character_controller_radius, collider, ColliderDef, ColliderFromUrls},
     mesh::PhysxGeometryFromUrl,
-    physx::PhysicsKey,
+    physx::{Physics, PhysicsKey},
+    ragdoll::{ragdoll, RagdollBone, RagdollDef, MAX_RAGDOLL_BONES},
 };
 use ambient_renderer::{
     double_sided,
-    lod::{gpu_lod, lod_cutoffs, LodCutoffs},
+    lod::{gpu_lod, lod_cutoffs, LodCutoffs, MAX_LOD_LEVELS},
     materials::pbr_material::PbrMaterialDesc,
 };
 use ambient_std::{
@@ -30,14 +36,19 @@ use ambient_std::{
 };
 use anyhow::Context;
 use futures::FutureExt;
-use glam::{Mat4, Vec3};
+use glam::{Mat4, Quat, Vec3};
 use image::{ImageOutputFormat, RgbaImage};
 use itertools::Itertools;
 use ordered_float::Float;
 use physxx::{PxConvexFlag, PxConvexMeshDesc, PxDefaultMemoryOutputStream, PxMeshFlag, PxTriangleMeshDesc};
 use relative_path::RelativePathBuf;
 
-use crate::{dotdot_path, MaterialFilter, TextureResolver};
+use crate::{
+    convex_shapes::{box_points, capsule_points, convex_decomposition},
+    dotdot_path,
+    mesh_lod::{optimize_mesh, simplify_mesh, LodLevel},
+    MaterialFilter, TextureResolver,
+};
 
 #[derive(Debug, Clone)]
 pub struct AssetLoc {
@@ -85,6 +96,18 @@ impl<T: Send + 'static> AssetMap<T> {
         self.content.insert(id.clone(), content);
         AssetLoc { path: self.loc.path(&id), id }
     }
+    /// Reads every file in this map's directory under `root`, as written by [ModelCrate::write_to_fs].
+    fn read_from_fs(&mut self, root: &Path, parse: impl Fn(&[u8]) -> anyhow::Result<T>) -> anyhow::Result<()> {
+        let Ok(entries) = std::fs::read_dir(root.join(&self.loc.store)) else { return Ok(()); };
+        let suffix = format!(".{}", self.loc.extension);
+        for entry in entries {
+            let path = entry?.path();
+            let Some(id) = path.file_name().and_then(|name| name.to_str()).and_then(|name| name.strip_suffix(&suffix)) else { continue; };
+            let content = parse(&std::fs::read(&path)?).with_context(|| format!("Failed to read {path:?}"))?;
+            self.content.insert(id.to_string(), content);
+        }
+        Ok(())
+    }
     pub fn to_items(&self) -> Vec<AssetItem> {
         self.content.iter().map(|(id, content)| AssetItem { path: self.loc.path(id), data: Arc::new((self.serialize)(content)) }).collect()
     }
@@ -96,6 +119,8 @@ pub struct ModelCrate {
     pub meshes: AssetMap<Mesh>,
     pub animations: AssetMap<AnimationClip>,
     pub images: AssetMap<image::RgbaImage>,
+    /// Textures that have been encoded for the GPU, as KTX2 files
+    pub textures: AssetMap<Vec<u8>>,
     pub materials: AssetMap<PbrMaterialDesc>,
     pub px_triangle_meshes: AssetMap<Vec<u8>>,
     pub px_convex_meshes: AssetMap<Vec<u8>>,
@@ -113,6 +138,7 @@ impl ModelCrate {
                 v.write_to(&mut data, ImageOutputFormat::Png).unwrap();
                 data.into_inner()
             }),
+            textures: AssetMap::new("textures", "ktx2", |v| v.clone()),
             materials: AssetMap::new("materials", "json", |v| serde_json::to_vec(v).unwrap()),
             px_triangle_meshes: AssetMap::new("px_triangle_meshes", "pxtm", |v| v.clone()),
             px_convex_meshes: AssetMap::new("px_convex_meshes", "pxcm", |v| v.clone()),
@@ -152,6 +178,19 @@ impl ModelCrate {
             tokio::fs::write(&item_path, &*item.data).await.context(format!("Failed to write file: {item_path:?}")).unwrap();
         }
     }
+    /// Reads back the models, prefabs, meshes, animations, materials, images and textures of a crate written with
+    /// [Self::write_to_fs].
+    pub fn read_from_fs(path: &Path) -> anyhow::Result<Self> {
+        let mut res = Self::new();
+        res.models.read_from_fs(path, Model::from_slice)?;
+        res.prefabs.read_from_fs(path, World::from_slice)?;
+        res.meshes.read_from_fs(path, |data| Ok(bincode::deserialize(data)?))?;
+        res.animations.read_from_fs(path, |data| Ok(bincode::deserialize(data)?))?;
+        res.images.read_from_fs(path, |data| Ok(image::load_from_memory(data)?.into_rgba8()))?;
+        res.textures.read_from_fs(path, |data| Ok(data.to_vec()))?;
+        res.materials.read_from_fs(path, |data| Ok(serde_json::from_slice(data)?))?;
+        Ok(res)
+    }
     pub fn to_items(&self) -> Vec<AssetItem> {
         [
             self.models.to_items().into_iter(),
@@ -159,6 +198,7 @@ impl ModelCrate {
             self.meshes.to_items().into_iter(),
             self.animations.to_items().into_iter(),
             self.images.to_items().into_iter(),
+            self.textures.to_items().into_iter(),
             self.materials.to_items().into_iter(),
             self.px_triangle_meshes.to_items().into_iter(),
             self.px_convex_meshes.to_items().into_iter(),
@@ -242,9 +282,7 @@ impl ModelCrate {
         Ok(())
     }
     pub fn merge_mesh_lods(&mut self, cutoffs: Option<Vec<f32>>, lods: Vec<ModelNodeRef>) {
-        let default_min_screen_size = 0.04; // i.e. 4%
-        let lod_step = (1. / default_min_screen_size).powf(1. / (lods.len() - 1) as f32);
-        let cutoffs = cutoffs.unwrap_or_else(|| (0..lods.len()).map(|i| 1. / lod_step.powi(i as i32)).collect_vec());
+        let cutoffs = cutoffs.unwrap_or_else(|| default_lod_cutoffs(lods.len()));
 
         let lod_0_node = lods[0].get_node_id();
         let lod_0_world = lods[0].world();
@@ -303,6 +341,79 @@ impl ModelCrate {
         });
         self.merge_mesh_lods(cutoffs, lods.into_iter().map(|id| ModelNodeRef { model: source, root: Some(id) }).collect())
     }
+    /// Adds a simplified version of each mesh in the model for every one of `levels`, along with the cutoffs that
+    /// select between them. Nodes that already have levels of detail are left as they are.
+    ///
+    /// There must be a cutoff for the original mesh and for every one of `levels`.
+    pub fn generate_mesh_lods(&mut self, levels: &[LodLevel], cutoffs: Option<Vec<f32>>) -> anyhow::Result<()> {
+        if levels.is_empty() {
+            return Ok(());
+        }
+        let count = levels.len() + 1;
+        anyhow::ensure!(
+            count <= MAX_LOD_LEVELS,
+            "At most {} levels of detail can be generated, but {} were given",
+            MAX_LOD_LEVELS - 1,
+            levels.len()
+        );
+        let cutoffs = match cutoffs {
+            Some(cutoffs) => {
+                anyhow::ensure!(
+                    cutoffs.len() == count,
+                    "{} LOD cutoffs were given, but {count} are needed: one for the original mesh and one for each of the {} levels",
+                    cutoffs.len(),
+                    levels.len()
+                );
+                cutoffs
+            }
+            None => default_lod_cutoffs(count),
+        };
+        let cutoffs = LodCutoffs::new(&cutoffs);
+
+        // The URLs of each level of the meshes that have been simplified, so that meshes shared by several nodes are only
+        // simplified once
+        let mut mesh_lods: HashMap<String, Vec<_>> = HashMap::new();
+        for (id, primitives) in query(pbr_renderer_primitives_from_url()).collect_cloned(self.model_world(), None) {
+            if self.model_world().has_component(id, lod_cutoffs()) || primitives.iter().any(|p| p.lod != 0) {
+                continue;
+            }
+            let mut lod_primitives = primitives.clone();
+            for primitive in &primitives {
+                let Some(mesh_id) = self.meshes.loc.id_from_path(primitive.mesh.path()) else { continue; };
+                if !mesh_lods.contains_key(&mesh_id) {
+                    let Some(mesh) = self.meshes.content.get(&mesh_id).cloned() else { continue; };
+                    // Levels that could not be simplified any further reuse the previous level's mesh
+                    let mut mesh_url = primitive.mesh.clone();
+                    let mut urls = Vec::with_capacity(levels.len());
+                    for (i, level) in levels.iter().enumerate() {
+                        if let Some(simplified) = simplify_mesh(&mesh, *level) {
+                            mesh_url = dotdot_path(self.meshes.insert(format!("{mesh_id}_lod{}", i + 1), simplified).path).into();
+                        }
+                        urls.push(mesh_url.clone());
+                    }
+                    mesh_lods.insert(mesh_id.clone(), urls);
+                }
+                for (i, mesh_url) in mesh_lods[&mesh_id].iter().enumerate() {
+                    lod_primitives.push(PbrRenderPrimitiveFromUrl {
+                        mesh: mesh_url.clone(),
+                        material: primitive.material.clone(),
+                        lod: i + 1,
+                    });
+                }
+            }
+            let world = self.model_world_mut();
+            world.set(id, pbr_renderer_primitives_from_url(), lod_primitives).unwrap();
+            world.add_component(id, lod_cutoffs(), cutoffs).unwrap();
+            world.add_component(id, gpu_lod(), ()).unwrap();
+        }
+        Ok(())
+    }
+    /// Reorders the triangles of every mesh for faster rendering; see [optimize_mesh].
+    pub fn optimize_meshes(&mut self) {
+        for mesh in self.meshes.content.values_mut() {
+            optimize_mesh(mesh);
+        }
+    }
     pub fn set_all_material(&mut self, material: PbrMaterialDesc) {
         self.materials.content.clear();
         let mat_path = dotdot_path(self.materials.insert("main".to_string(), material).path);
@@ -462,82 +573,227 @@ impl ModelCrate {
         world.add_component(object, character_controller_height(), height.unwrap_or(2.0)).unwrap();
     }
     pub fn create_collider_from_model(&mut self, assets: &AssetCache, flip_normals: bool, reverse_indices: bool) -> anyhow::Result<()> {
-        self.update_transforms();
         let physics = PhysicsKey.get(assets);
-        let create_triangle_mesh = |asset_crate: &mut ModelCrate, id: &str| -> bool {
-            if asset_crate.px_triangle_meshes.content.contains_key(id) {
-                return true;
+        let mut convex = Vec::new();
+        let mut triangle = Vec::new();
+        for (mesh_id, transform) in self.collider_meshes() {
+            let (scale, rot, pos) = transform.to_scale_rotation_translation();
+            if let Some(triangle_path) = self.create_triangle_mesh(&physics, &mesh_id, flip_normals, reverse_indices) {
+                let points = self.meshes.content.get(&mesh_id).unwrap().positions.clone().unwrap_or_default();
+                if let Some(convex_path) = self.create_convex_mesh(&physics, &mesh_id, &points, scale.signum(), None) {
+                    let convex_path = dotdot_path(convex_path);
+                    convex.push((Mat4::from_scale_rotation_translation(scale.abs(), rot, pos), PhysxGeometryFromUrl(convex_path.into())));
+                    triangle.push((transform, PhysxGeometryFromUrl(dotdot_path(triangle_path).into())));
+                }
             }
-            let mesh = asset_crate.meshes.content.get(id).unwrap();
-            if let Some(desc) = physx_triangle_mesh_desc_from_mesh(mesh, flip_normals, reverse_indices) {
-                let stream = PxDefaultMemoryOutputStream::new();
-                let mut res = physxx::PxTriangleMeshCookingResult::Success;
-                if !physics.cooking.cook_triangle_mesh(&desc, &stream, &mut res) {
-                    log::error!("Failed to cook triangle mesh: {:?}", res);
-                    return false;
+        }
+        self.set_collider(ColliderFromUrls { convex, concave: triangle });
+        Ok(())
+    }
+    /// Like [Self::create_collider_from_model], but dynamic objects use up to `max_hulls` convex hulls per mesh, with at most
+    /// `max_vertices_per_hull` vertices each, instead of a single convex hull around each mesh.
+    ///
+    /// `max_vertices_per_hull` must be between 4 and 255, which are the limits of PhysX.
+    pub fn create_convex_decomposition_collider_from_model(
+        &mut self,
+        assets: &AssetCache,
+        flip_normals: bool,
+        reverse_indices: bool,
+        max_hulls: u32,
+        max_vertices_per_hull: u16,
+    ) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            (4..=255).contains(&max_vertices_per_hull),
+            "The maximum number of vertices per hull must be between 4 and 255, but is {max_vertices_per_hull}"
+        );
+        let physics = PhysicsKey.get(assets);
+        let mut convex = Vec::new();
+        let mut triangle = Vec::new();
+        for (mesh_id, transform) in self.collider_meshes() {
+            let Some(triangle_path) = self.create_triangle_mesh(&physics, &mesh_id, flip_normals, reverse_indices) else { continue; };
+            triangle.push((transform, PhysxGeometryFromUrl(dotdot_path(triangle_path).into())));
+
+            let (scale, rot, pos) = transform.to_scale_rotation_translation();
+            let hulls = convex_decomposition(self.meshes.content.get(&mesh_id).unwrap(), max_hulls);
+            for (i, points) in hulls.into_iter().enumerate() {
+                let id = format!("{mesh_id}_hull{i}");
+                if let Some(path) = self.create_convex_mesh(&physics, &id, &points, scale.signum(), Some(max_vertices_per_hull)) {
+                    convex.push((
+                        Mat4::from_scale_rotation_translation(scale.abs(), rot, pos),
+                        PhysxGeometryFromUrl(dotdot_path(path).into()),
+                    ));
                 }
-                asset_crate.px_triangle_meshes.content.insert(id.to_string(), stream.get_data());
-                true
-            } else {
-                false
             }
-        };
-        let create_convex_mesh = |asset_crate: &mut ModelCrate, id: &str, scale_signum: Vec3| -> Option<RelativePathBuf> {
-            // Physx doesn't support negative scaling on Convex meshes, so we need to generate a mesh with the right
-            // scale signum first, and then scale that with the absolute scale
-            let to_sign = |v| if v >= 0. { "p" } else { "n" }.to_string();
-            let full_id = format!("{id}_{}{}{}", to_sign(scale_signum.x), to_sign(scale_signum.y), to_sign(scale_signum.z));
-            if asset_crate.px_convex_meshes.content.contains_key(&full_id) {
-                return Some(asset_crate.px_convex_meshes.loc.path(&full_id));
+        }
+        self.set_collider(ColliderFromUrls { convex, concave: triangle });
+        Ok(())
+    }
+    /// Creates a collider made of a box fitted around each mesh of the model, for both static and dynamic objects.
+    pub fn create_box_collider_from_model(&mut self, assets: &AssetCache) {
+        let physics = PhysicsKey.get(assets);
+        let mut shapes = Vec::new();
+        for (mesh_id, transform) in self.collider_meshes() {
+            let points = self.meshes.content.get(&mesh_id).unwrap().positions.clone().unwrap_or_default();
+            if points.is_empty() {
+                continue;
+            }
+            let (scale, rot, pos) = transform.to_scale_rotation_translation();
+            let id = format!("{mesh_id}_box");
+            if let Some(path) = self.create_convex_mesh(&physics, &id, &box_points(&points), scale.signum(), None) {
+                shapes.push((Mat4::from_scale_rotation_translation(scale.abs(), rot, pos), PhysxGeometryFromUrl(dotdot_path(path).into())));
             }
-            let mesh = asset_crate.meshes.content.get(id).unwrap();
+        }
+        self.set_collider(ColliderFromUrls { convex: shapes.clone(), concave: shapes });
+    }
+    /// Creates a collider made of a single capsule fitted around the whole model, along the longest side of its bounding box.
+    pub fn create_capsule_collider_from_model(&mut self, assets: &AssetCache) {
+        let physics = PhysicsKey.get(assets);
+        let mut points = Vec::new();
+        for (mesh_id, transform) in self.collider_meshes() {
+            let positions = self.meshes.content.get(&mesh_id).unwrap().positions.iter().flatten();
+            points.extend(positions.map(|&p| transform.transform_point3(p)));
+        }
+        let mut shapes = Vec::new();
+        if !points.is_empty() {
+            if let Some(path) = self.create_convex_mesh(&physics, "capsule", &capsule_points(&points), Vec3::ONE, None) {
+                shapes.push((Mat4::IDENTITY, PhysxGeometryFromUrl(dotdot_path(path).into())));
+            }
+        }
+        self.set_collider(ColliderFromUrls { convex: shapes.clone(), concave: shapes });
+    }
+    /// Creates a ragdoll from the first skin of the model, with a capsule from each bone to its children, and adds it to the prefab.
+    /// Bones shorter than `min_bone_length` are left out, together with their children.
+    pub fn create_ragdoll_from_model(&mut self, radius_scale: f32, min_bone_length: f32, swing_limit: f32, twist_limit: f32) {
+        self.update_transforms();
+        let model_transform = self.model().get_transform().unwrap_or_default();
+        let world = self.model_world();
+        let Some(skin) = self.model().skins().and_then(|skins| skins.first()) else {
+            log::warn!("Can't generate a ragdoll for a model without a skin");
+            return;
+        };
+        let joints = skin.joints.iter().copied().collect::<HashSet<_>>();
+        let mut parents = HashMap::new();
+        for (id, children) in query(children()).iter(world, None) {
+            parents.extend(children.iter().map(|&child| (child, id)));
+        }
+        let pose = |id: EntityId| model_transform * world.get(id, local_to_world()).unwrap_or_default();
+        let joint_children = |id: EntityId| {
+            world
+                .get_ref(id, children())
+                .map(|children| children.iter().copied().filter(|c| joints.contains(c)).collect_vec())
+                .unwrap_or_default()
+        };
+        // The root is the joint that isn't the child of another joint
+        let root = skin.joints.iter().copied().find(|joint| !parents.get(joint).map_or(false, |parent| joints.contains(parent)));
+        let Some(root) = root else { return };
 
-            let desc = PxConvexMeshDesc {
-                // Apply the correct mirroring according to the base scale
-                points: mesh.positions.as_ref().unwrap().iter().map(|&p| p * scale_signum).collect_vec(),
-                indices: mesh.indices.clone(),
-                vertex_limit: None,
-                flags: Some(PxConvexFlag::COMPUTE_CONVEX),
+        let mut bones: Vec<RagdollBone> = Vec::new();
+        let mut queue = VecDeque::from([(root, None)]);
+        while let Some((joint, parent)) = queue.pop_front() {
+            if bones.len() >= MAX_RAGDOLL_BONES {
+                log::warn!("The ragdoll was limited to {MAX_RAGDOLL_BONES} bones");
+                break;
+            }
+            let bind_pose = pose(joint);
+            let (_, rot, pos) = bind_pose.to_scale_rotation_translation();
+            let children = joint_children(joint);
+            let end = if !children.is_empty() {
+                children.iter().map(|&child| pose(child).w_axis.truncate()).sum::<Vec3>() / children.len() as f32
+            } else if let Some(parent) = parent {
+                // Leaf bones continue in the direction of their parent, at half its length
+                let parent_pos = bones[parent].bind_pose.w_axis.truncate();
+                pos + (pos - parent_pos) * 0.5
+            } else {
+                continue;
             };
+            let length = pos.distance(end);
+            if length < min_bone_length.max(f32::EPSILON) {
+                continue;
+            }
+            let radius = length * radius_scale;
+            queue.extend(children.into_iter().map(|child| (child, Some(bones.len()))));
+            bones.push(RagdollBone {
+                bind_id: world.get_cloned(joint, animation_bind_id()).unwrap_or_default(),
+                parent,
+                bind_pose,
+                capsule_center: rot.inverse() * (end - pos) / 2.,
+                capsule_rotation: rot.inverse() * Quat::from_rotation_arc(Vec3::X, (end - pos) / length),
+                radius,
+                // Ragdoll capsules must have a cylindrical section, however short
+                half_height: (length / 2. - radius).max(length * 0.01),
+                swing_limit,
+                twist_limit,
+            });
+        }
+        let root_parent_pose = parents.get(&root).map(|&parent| pose(parent)).unwrap_or(model_transform);
+        self.add_component_to_prefab(ragdoll(), RagdollDef { bones, root_parent_pose });
+    }
+    /// The meshes to create colliders from, with their transforms relative to the model.
+    /// Only the least detailed level of each node is used.
+    fn collider_meshes(&mut self) -> Vec<(String, Mat4)> {
+        self.update_transforms();
+        let world_transform = self.model().get_transform().unwrap_or_default();
+        let world = self.model_world();
+        let mut meshes = Vec::new();
+        for (id, prims) in query(pbr_renderer_primitives_from_url()).collect_cloned(world, None) {
+            let Some(max_lod) = prims.iter().map(|x| x.lod).max() else { continue; };
+            let ltw = world.get(id, local_to_world()).unwrap_or_default();
+            let mtl = world.get(id, mesh_to_local()).unwrap_or_default();
+            for primitive in prims.into_iter().filter(|x| x.lod == max_lod) {
+                meshes.push((self.meshes.loc.id_from_path(primitive.mesh.path()).unwrap(), world_transform * ltw * mtl));
+            }
+        }
+        meshes
+    }
+    /// Cooks the triangles of the mesh `id`, if that hasn't been done yet, and returns the path of the result. Meshes without
+    /// triangles, or that can't be cooked, are skipped.
+    fn create_triangle_mesh(&mut self, physics: &Physics, id: &str, flip_normals: bool, reverse_indices: bool) -> Option<RelativePathBuf> {
+        if !self.px_triangle_meshes.content.contains_key(id) {
+            let desc = physx_triangle_mesh_desc_from_mesh(self.meshes.content.get(id).unwrap(), flip_normals, reverse_indices)?;
             let stream = PxDefaultMemoryOutputStream::new();
-            let mut res = physxx::PxConvexMeshCookingResult::Success;
-            if !physics.cooking.cook_convex_mesh(&desc, &stream, &mut res) {
-                log::error!("Failed to cook convex mesh: {:?}", res);
+            let mut res = physxx::PxTriangleMeshCookingResult::Success;
+            if !physics.cooking.cook_triangle_mesh(&desc, &stream, &mut res) {
+                log::error!("Failed to cook triangle mesh: {:?}", res);
                 return None;
             }
-            Some(asset_crate.px_convex_meshes.insert(full_id, stream.get_data()).path)
-        };
-        let mut convex = Vec::new();
-        let mut triangle = Vec::new();
-        let world_transform = self.model().get_transform().unwrap_or_default();
-        let entities = {
-            let world = self.model_world();
-            query(pbr_renderer_primitives_from_url()).collect_cloned(world, None)
+            self.px_triangle_meshes.content.insert(id.to_string(), stream.get_data());
+        }
+        Some(self.px_triangle_meshes.loc.path(id))
+    }
+    /// Cooks a convex mesh around `points`, and returns its path.
+    fn create_convex_mesh(
+        &mut self,
+        physics: &Physics,
+        id: &str,
+        points: &[Vec3],
+        scale_signum: Vec3,
+        vertex_limit: Option<u16>,
+    ) -> Option<RelativePathBuf> {
+        // Physx doesn't support negative scaling on Convex meshes, so we need to generate a mesh with the right
+        // scale signum first, and then scale that with the absolute scale
+        let to_sign = |v| if v >= 0. { "p" } else { "n" }.to_string();
+        let full_id = format!("{id}_{}{}{}", to_sign(scale_signum.x), to_sign(scale_signum.y), to_sign(scale_signum.z));
+        if self.px_convex_meshes.content.contains_key(&full_id) {
+            return Some(self.px_convex_meshes.loc.path(&full_id));
+        }
+
+        let desc = PxConvexMeshDesc {
+            // Apply the correct mirroring according to the base scale
+            points: points.iter().map(|&p| p * scale_signum).collect_vec(),
+            indices: None,
+            vertex_limit,
+            flags: Some(PxConvexFlag::COMPUTE_CONVEX),
         };
-        for (id, prims) in entities {
-            let ltw = self.model_world().get(id, local_to_world()).unwrap_or_default();
-            if let Some(max_lod) = prims.iter().map(|x| x.lod).max() {
-                let mtl = self.model_world().get(id, mesh_to_local()).unwrap_or_default();
-                // Only use the "max" lod for colliders
-                for primitive in prims.into_iter().filter(|x| x.lod == max_lod) {
-                    let transform = world_transform * ltw * mtl;
-                    let (scale, rot, pos) = transform.to_scale_rotation_translation();
-                    let mesh_id = self.meshes.loc.id_from_path(primitive.mesh.path()).unwrap();
-                    if create_triangle_mesh(self, &mesh_id) {
-                        if let Some(convex_path) = create_convex_mesh(self, &mesh_id, scale.signum()) {
-                            let convex_path = dotdot_path(convex_path);
-                            let triangle_path = dotdot_path(self.px_triangle_meshes.loc.path(mesh_id));
-                            convex.push((
-                                Mat4::from_scale_rotation_translation(scale.abs(), rot, pos),
-                                PhysxGeometryFromUrl(convex_path.into()),
-                            ));
-                            triangle.push((transform, PhysxGeometryFromUrl(triangle_path.into())));
-                        }
-                    }
-                }
-            }
+        let stream = PxDefaultMemoryOutputStream::new();
+        let mut res = physxx::PxConvexMeshCookingResult::Success;
+        if !physics.cooking.cook_convex_mesh(&desc, &stream, &mut res) {
+            log::error!("Failed to cook convex mesh: {:?}", res);
+            return None;
         }
-        let obj_collider = self.colliders.insert(ModelCrate::MAIN.to_string(), ColliderFromUrls { convex, concave: triangle });
+        Some(self.px_convex_meshes.insert(full_id, stream.get_data()).path)
+    }
+    fn set_collider(&mut self, collider_from_urls: ColliderFromUrls) {
+        let obj_collider = self.colliders.insert(ModelCrate::MAIN.to_string(), collider_from_urls);
         let prefab = self.prefab_world_mut();
         prefab
             .add_component(
@@ -546,9 +802,15 @@ impl ModelCrate {
                 ColliderDef::Asset { collider: dotdot_path(obj_collider.path).into() },
             )
             .unwrap();
-        Ok(())
     }
 }
+/// Cutoffs for `count` levels of detail, spaced evenly on a log scale from the full screen down to 4% of it.
+fn default_lod_cutoffs(count: usize) -> Vec<f32> {
+    let default_min_screen_size = 0.04; // i.e. 4%
+    let lod_step = (1. / default_min_screen_size).powf(1. / (count - 1) as f32);
+    (0..count).map(|i| 1. / lod_step.powi(i as i32)).collect_vec()
+}
+
 pub struct AssetItem {
     pub path: RelativePathBuf,
     pub data: Arc<Vec<u8>>,
@@ -595,3 +857,41 @@ pub fn physx_triangle_mesh_desc_from_mesh(mesh: &Mesh, flip_normals: bool, rever
     }
     Some(desc)
 }
+
+#[test]
+fn test_generate_mesh_lods() {
+    ambient_app::init_all_components();
+    // A flat grid, which can be simplified without any error
+    let size = 9;
+    let positions = (0..size * size).map(|i| Vec3::new((i % size) as f32, (i / size) as f32, 0.)).collect_vec();
+    let mut indices = Vec::new();
+    for y in 0..size - 1 {
+        for x in 0..size - 1 {
+            let i = y * size + x;
+            indices.extend([i, i + 1, i + size, i + 1, i + size + 1, i + size]);
+        }
+    }
+    let mut model_crate = ModelCrate::new();
+    let mesh_path =
+        model_crate.meshes.insert("grid", Mesh { positions: Some(positions), indices: Some(indices), ..Default::default() }).path;
+    let mut world = World::new("model");
+    let primitive = PbrRenderPrimitiveFromUrl { mesh: dotdot_path(mesh_path).into(), material: None, lod: 0 };
+    let nodes =
+        (0..2).map(|_| Entity::new().with(pbr_renderer_primitives_from_url(), vec![primitive.clone()]).spawn(&mut world)).collect_vec();
+    world.add_resource(children(), nodes.clone());
+    model_crate.models.insert(ModelCrate::MAIN, Model(world));
+
+    let levels = [LodLevel { ratio: 0.5, max_error: 0.01 }];
+    assert!(model_crate.generate_mesh_lods(&levels, Some(vec![1.0])).is_err());
+    assert!(model_crate.generate_mesh_lods(&[LodLevel { ratio: 0.5, max_error: 0.01 }; MAX_LOD_LEVELS], None).is_err());
+
+    model_crate.generate_mesh_lods(&levels, Some(vec![1.0, 0.5])).unwrap();
+    // The mesh shared by both nodes is only simplified once
+    assert_eq!(model_crate.meshes.content.len(), 2);
+    for node in nodes {
+        let primitives = model_crate.model_world().get_ref(node, pbr_renderer_primitives_from_url()).unwrap();
+        assert_eq!(primitives.iter().map(|p| p.lod).collect_vec(), vec![0, 1]);
+        assert_eq!(primitives[1].mesh.path(), dotdot_path(model_crate.meshes.loc.path("grid_lod1")).as_str());
+        assert!(model_crate.model_world().has_component(node, lod_cutoffs()));
+    }
+}
@@ -15,17 +15,17 @@ use ambient_ui::Editable;
 use anyhow::Context;
 use async_trait::async_trait;
 use futures::future::try_join_all;
-use glam::{vec3, Mat4, Quat, Vec3};
+use glam::{vec3, Mat4, Quat, UVec2, Vec2, Vec3};
 use itertools::Itertools;
 use physxx::{
-    AsPxActor, AsPxRigidActor, PxActor, PxActorFlag, PxBase, PxBoxGeometry, PxControllerDesc, PxControllerShapeDesc, PxConvexMeshGeometry, PxGeometry, PxMaterial, PxMeshScale, PxPlaneGeometry, PxRigidActor, PxRigidBody, PxRigidBodyFlag, PxRigidDynamicRef, PxRigidStaticRef, PxShape, PxShapeFlag, PxSphereGeometry, PxTransform, PxTriangleMeshGeometry, PxUserData
+    AsPxActor, AsPxRigidActor, PxActor, PxActorFlag, PxBase, PxBoxGeometry, PxCapsuleGeometry, PxControllerDesc, PxControllerShapeDesc, PxConvexFlag, PxConvexMesh, PxConvexMeshDesc, PxConvexMeshGeometry, PxGeometry, PxHeightFieldDesc, PxHeightFieldGeometry, PxMaterial, PxMeshScale, PxPlaneGeometry, PxQuantizedHeightFieldSamples, PxRigidActor, PxRigidBody, PxRigidBodyFlag, PxRigidDynamicRef, PxRigidStaticRef, PxShape, PxShapeFlag, PxSphereGeometry, PxTransform, PxTriangleMeshGeometry, PxUserData
 };
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    main_controller_manager, make_physics_static, mesh::{PhysxGeometry, PhysxGeometryFromUrl}, physx::{
-        angular_velocity, character_controller, contact_offset, linear_velocity, physics, physics_controlled, physics_shape, rest_offset, rigid_actor, Physics
-    }, wood_physics_material, ColliderScene, PxActorUserData, PxShapeUserData, PxWoodMaterialKey
+    collision_layers::{collision_filter_data, set_filter_data}, main_controller_manager, make_physics_static, material::PhysicsMaterial, mesh::{PhysxGeometry, PhysxGeometryFromUrl}, physx::{
+        angular_velocity, character_controller, contact_offset, linear_velocity, physics, physics_controlled, physics_shape, rest_offset, rigid_actor, Physics, PhysicsKey
+    }, ColliderScene, PxActorUserData, PxShapeUserData, PxWoodMaterialKey
 };
 
 components!("physics", {
@@ -47,6 +47,36 @@ components!("physics", {
         Description["If attached, this entity will have a sphere physics collider.\nThe value corresponds to the radius of the sphere."]
     ]
     sphere_collider: f32,
+    @[
+        Debuggable, Networked, Store,
+        Name["Capsule collider"],
+        Description["If attached, this entity will have a capsule physics collider along the Z axis.\n`x` is the radius of the capsule, and `y` is its total height."]
+    ]
+    capsule_collider: Vec2,
+    @[
+        Debuggable, Networked, Store,
+        Name["Cylinder collider"],
+        Description["If attached, this entity will have a cylinder physics collider along the Z axis, approximated by a convex mesh.\n`x` is the radius of the cylinder, and `y` is its height."]
+    ]
+    cylinder_collider: Vec2,
+    @[
+        Debuggable, Networked, Store,
+        Name["Convex collider from points"],
+        Description["If attached, this entity will have a physics collider that is the convex hull of these points."]
+    ]
+    convex_collider_from_points: Vec<Vec3>,
+    @[
+        Debuggable, Networked, Store,
+        Name["Height field collider"],
+        Description["If attached, this entity will have a height field physics collider with these heights.\nThe heights are laid out row by row along the X axis, with one unit between samples. Requires `height_field_collider_size`."]
+    ]
+    height_field_collider: Vec<f32>,
+    @[
+        Debuggable, Networked, Store,
+        Name["Height field collider size"],
+        Description["The number of samples of the `height_field_collider` along the X and Y axes. Both must be at least 2."]
+    ]
+    height_field_collider_size: UVec2,
     @[
         Debuggable, Networked, Store,
         Name["Collider from URL"],
@@ -166,6 +196,34 @@ pub fn server_systems() -> SystemGroup {
                     world.add_component(id, collider(), ColliderDef::Box { size, center: Vec3::ZERO }).unwrap();
                 }
             }),
+            query(capsule_collider().changed()).to_system(|q, world, qs, _| {
+                for (id, size) in changed_or_missing(q, world, qs, collider()) {
+                    world
+                        .add_component(id, collider(), ColliderDef::Capsule { radius: size.x, height: size.y, center: Vec3::ZERO })
+                        .unwrap();
+                }
+            }),
+            query(cylinder_collider().changed()).to_system(|q, world, qs, _| {
+                for (id, size) in changed_or_missing(q, world, qs, collider()) {
+                    world
+                        .add_component(id, collider(), ColliderDef::Cylinder { radius: size.x, height: size.y, center: Vec3::ZERO })
+                        .unwrap();
+                }
+            }),
+            query(convex_collider_from_points().changed()).to_system(|q, world, qs, _| {
+                for (id, points) in changed_or_missing(q, world, qs, collider()) {
+                    world.add_component(id, collider(), ColliderDef::ConvexFromPoints { points }).unwrap();
+                }
+            }),
+            query((height_field_collider().changed(), height_field_collider_size().changed())).to_system(|q, world, qs, _| {
+                for (id, (heights, size)) in changed_or_missing(q, world, qs, collider()) {
+                    if size.x < 2 || size.y < 2 || heights.len() != (size.x * size.y) as usize {
+                        log::warn!("Invalid height field collider of size {size} with {} heights", heights.len());
+                        continue;
+                    }
+                    world.add_component(id, collider(), ColliderDef::HeightField { heights, size }).unwrap();
+                }
+            }),
             query(collider_from_url().changed()).to_system(|q, world, qs, _| {
                 for (id, url) in changed_or_missing(q, world, qs, collider()) {
                     tracing::debug!("Loading collider: {url:#?}");
@@ -191,7 +249,7 @@ pub fn server_systems() -> SystemGroup {
                             old.release();
                         }
                         let controller_manager = world.resource(main_controller_manager());
-                        let physics_material = world.resource(wood_physics_material()).clone();
+                        let physics_material = PhysicsMaterial::from_entity(world, id).px_material(world.resource(asset_cache()));
 
                         let mut desc = PxControllerDesc::new(
                             PxControllerShapeDesc::Capsule { radius, height: height - radius * 2. },
@@ -204,6 +262,7 @@ pub fn server_systems() -> SystemGroup {
                             for shape in controller.get_actor().get_shapes() {
                                 shape.set_flag(PxShapeFlag::VISUALIZATION, false);
                             }
+                            set_filter_data(&controller.get_actor().get_shapes(), collision_filter_data(world, id));
                             let actor = controller.get_actor();
                             actor.as_actor().set_user_data(id);
                             actor.get_shapes()[0].set_user_data(PxShapeUserData { entity: id, density: 1., ..Default::default() });
@@ -256,6 +315,8 @@ pub fn server_systems() -> SystemGroup {
                                         shape.set_flag(PxShapeFlag::SCENE_QUERY_SHAPE, true);
                                         shape.set_flag(PxShapeFlag::VISUALIZATION, false);
                                     }
+                                    // Queries with layers never hit shapes without filter data, so assign it right away
+                                    set_filter_data(shapes.iter().chain(convex.iter()), collision_filter_data(world, id));
 
                                     world.add_component(id, collider_shapes(), shapes).unwrap();
                                     world.add_component(id, collider_shapes_convex(), convex).unwrap();
@@ -389,6 +450,59 @@ pub enum ColliderDef {
         center: Vec3,
     },
     Plane,
+    /// A capsule along the Z axis; `height` includes the caps
+    Capsule {
+        #[serde(default = "one_value")]
+        radius: f32,
+        #[serde(default = "one_value")]
+        height: f32,
+        #[serde(default = "vec3_zero_value")]
+        center: Vec3,
+    },
+    /// A cylinder along the Z axis, approximated by a convex mesh
+    Cylinder {
+        #[serde(default = "one_value")]
+        radius: f32,
+        #[serde(default = "one_value")]
+        height: f32,
+        #[serde(default = "vec3_zero_value")]
+        center: Vec3,
+    },
+    /// The convex hull of `points`
+    ConvexFromPoints {
+        points: Vec<Vec3>,
+    },
+    /// A height field with `size.x * size.y` samples laid out row by row along the X axis, one unit apart
+    HeightField {
+        heights: Vec<f32>,
+        size: UVec2,
+    },
+}
+
+/// The number of segments used to approximate the circumference of a [ColliderDef::Cylinder]
+const CYLINDER_SEGMENTS: usize = 16;
+
+fn cook_convex_mesh(physics: &Physics, points: Vec<Vec3>) -> anyhow::Result<PxConvexMesh> {
+    let desc = PxConvexMeshDesc { points, indices: None, vertex_limit: None, flags: Some(PxConvexFlag::COMPUTE_CONVEX) };
+    PxConvexMesh::from_desc(physics.physics, physics.cooking, desc).map_err(|err| anyhow::anyhow!("Failed to cook convex mesh: {err:?}"))
+}
+
+fn convex_mesh_spawner(mesh: PxConvexMesh, center: Vec3, material: PxMaterial, density: f32) -> ColliderSpawner {
+    Box::new(move |physics, scale| {
+        let geometry = PxConvexMeshGeometry::new(&mesh, Some(PxMeshScale::from_scale(scale.abs())), None);
+        if !geometry.is_valid() {
+            tracing::warn!("Invalid convex collider geometry. scale={scale:?}");
+            return (vec![], vec![]);
+        }
+        let shape = PxShape::new(physics.physics, &geometry, &[&material], Some(true), None);
+        shape.set_local_pose(&PxTransform::from_translation(center * scale));
+        shape.set_user_data(PxShapeUserData {
+            entity: EntityId::null(),
+            density,
+            base_pose: Mat4::from_scale_rotation_translation(Vec3::ONE, Default::default(), center * scale),
+        });
+        (vec![shape.clone()], vec![shape])
+    })
 }
 
 type ColliderSpawner = Box<dyn Fn(&Physics, Vec3) -> (Vec<PxShape>, Vec<PxShape>) + Sync + Send>;
@@ -409,7 +523,13 @@ impl ColliderDef {
     /// **Note**: this scale is applied after the initial base_pose scale.
     pub async fn spawner(&self, assets: AssetCache, density: f32) -> Result<ColliderSpawner, AssetError> {
         let material = PxWoodMaterialKey.get(&assets);
-        match self.clone() {
+        let def = match self.clone() {
+            // Capsules that are too short for their radius are spheres. Their half height would be zero, which would give
+            // them a base pose that can't be decomposed when rescaling them
+            ColliderDef::Capsule { radius, height, center } if height / 2. <= radius => ColliderDef::Sphere { radius, center },
+            def => def,
+        };
+        match def {
             ColliderDef::Box { size, center } => Ok(Box::new(move |physics, scale| {
                 let size = size * scale;
                 let geometry = PxBoxGeometry::new(size.x / 2., size.y / 2., size.x / 2.);
@@ -443,6 +563,67 @@ impl ColliderDef {
                 shape.set_user_data(PxShapeUserData { entity: EntityId::null(), density, base_pose: Mat4::from_rotation_y(-PI / 2.) });
                 (vec![shape.clone()], vec![shape])
             })),
+            ColliderDef::Capsule { radius, height, center } => Ok(Box::new(move |physics, scale| {
+                if radius <= 0. {
+                    return (vec![], vec![]);
+                }
+                let half_height = height / 2. - radius;
+                // PhysX capsules extend along the X axis, so they are rotated to extend along Z
+                let geometry = PxCapsuleGeometry::new(radius * scale.x, half_height * scale.z);
+                let shape = PxShape::new(physics.physics, &geometry, &[&material], Some(true), None);
+                let rotation = Quat::from_rotation_y(-PI / 2.);
+                shape.set_local_pose(&PxTransform::new(center * scale, rotation));
+                shape.set_user_data(PxShapeUserData {
+                    entity: EntityId::null(),
+                    density,
+                    base_pose: Mat4::from_scale_rotation_translation(vec3(half_height, radius, radius), rotation, center * scale),
+                });
+                (vec![shape.clone()], vec![shape])
+            })),
+            ColliderDef::Cylinder { radius, height, center } => {
+                let physics = PhysicsKey.get(&assets);
+                let points = (0..CYLINDER_SEGMENTS)
+                    .flat_map(|i| {
+                        let angle = i as f32 / CYLINDER_SEGMENTS as f32 * PI * 2.;
+                        let (y, x) = angle.sin_cos();
+                        [vec3(x * radius, y * radius, -height / 2.), vec3(x * radius, y * radius, height / 2.)]
+                    })
+                    .collect_vec();
+                Ok(convex_mesh_spawner(cook_convex_mesh(&physics, points)?, center, material, density))
+            }
+            ColliderDef::ConvexFromPoints { points } => {
+                let physics = PhysicsKey.get(&assets);
+                Ok(convex_mesh_spawner(cook_convex_mesh(&physics, points)?, Vec3::ZERO, material, density))
+            }
+            ColliderDef::HeightField { heights, size } => {
+                let physics = PhysicsKey.get(&assets);
+                // PhysX height field rows extend along X and columns along Z, which is rotated to become -Y, so the columns are
+                // flipped to extend along +Y
+                let samples = (0..size.x)
+                    .flat_map(|x| (0..size.y).rev().map(move |y| (y * size.x + x) as usize))
+                    .map(|i| heights.get(i).copied().unwrap_or_default())
+                    .collect_vec();
+                let mut quantized = PxQuantizedHeightFieldSamples::new_from_f32_array(&samples);
+                for sample in &mut quantized.samples {
+                    sample.set_tesselation(true);
+                }
+                let desc = PxHeightFieldDesc::new(size.x, size.y, &quantized.samples);
+                let height_field = physics.cooking.create_height_field(&physics.physics, &desc);
+                let (height_scale, min_height) = (quantized.height_scale, quantized.min_height);
+
+                Ok(Box::new(move |physics, scale| {
+                    let geometry = PxHeightFieldGeometry::new(&mut height_field.clone(), height_scale * scale.z, scale.x, scale.y);
+                    let shape = PxShape::new(physics.physics, &geometry, &[&material], Some(true), None);
+                    let translation = vec3(0., (size.y - 1) as f32, min_height) * scale;
+                    shape.set_local_pose(&PxTransform::new(translation, Quat::from_rotation_x(PI / 2.)));
+                    shape.set_user_data(PxShapeUserData {
+                        entity: EntityId::null(),
+                        density,
+                        base_pose: Mat4::from_rotation_translation(Quat::from_rotation_x(PI / 2.), translation),
+                    });
+                    (vec![shape.clone()], vec![shape])
+                }))
+            }
             ColliderDef::Asset { collider } => {
                 let collider = collider.unwrap_abs();
                 let collider_from_urls: Arc<ColliderFromUrls> = JsonFromUrl::new(collider.clone(), true).get(&assets).await?;
@@ -544,3 +725,61 @@ impl Collider {
             .collect_vec()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use physxx::PxGeometryType;
+
+    use super::*;
+    use crate::{helpers::scale_shape, physx::test_physics};
+
+    fn spawn(def: ColliderDef) -> Vec<PxShape> {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let assets = AssetCache::new(runtime.handle().clone());
+        PhysicsKey.insert(&assets, test_physics());
+        let spawner = runtime.block_on(def.spawner(assets, 1.)).unwrap();
+        spawner(&test_physics(), Vec3::ONE).0
+    }
+
+    fn base_pose(shape: &PxShape) -> Mat4 {
+        shape.get_user_data::<PxShapeUserData>().unwrap().base_pose
+    }
+
+    #[test]
+    fn capsules_can_be_rescaled() {
+        let shapes = spawn(ColliderDef::Capsule { radius: 0.5, height: 3., center: Vec3::ZERO });
+        let geometry = shapes[0].get_geometry().as_capsule().unwrap();
+        assert_eq!((geometry.radius(), geometry.half_height()), (0.5, 1.));
+
+        scale_shape(shapes[0].clone(), Vec3::splat(2.));
+        let geometry = shapes[0].get_geometry().as_capsule().unwrap();
+        assert!((geometry.radius() - 1.).abs() < 1e-5 && (geometry.half_height() - 2.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn short_capsules_are_spheres() {
+        let shapes = spawn(ColliderDef::Capsule { radius: 0.5, height: 1., center: Vec3::ZERO });
+        assert_eq!(shapes[0].get_geometry().as_sphere().unwrap().radius(), 0.5);
+        let (scale, rotation, _) = base_pose(&shapes[0]).to_scale_rotation_translation();
+        assert!(scale.is_finite() && rotation.is_finite());
+
+        scale_shape(shapes[0].clone(), Vec3::splat(2.));
+        assert_eq!(shapes[0].get_geometry().as_sphere().unwrap().radius(), 1.);
+    }
+
+    #[test]
+    fn cylinders_and_point_clouds_are_convex() {
+        let cylinder = spawn(ColliderDef::Cylinder { radius: 1., height: 2., center: Vec3::ZERO });
+        assert_eq!(cylinder[0].get_geometry_type(), PxGeometryType::ConvexMesh);
+
+        let points = vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::Z];
+        let convex = spawn(ColliderDef::ConvexFromPoints { points });
+        assert_eq!(convex[0].get_geometry_type(), PxGeometryType::ConvexMesh);
+    }
+
+    #[test]
+    fn height_fields_are_spawned() {
+        let shapes = spawn(ColliderDef::HeightField { heights: vec![0., 1., 2., 3.], size: UVec2::new(2, 2) });
+        assert_eq!(shapes[0].get_geometry_type(), PxGeometryType::HEIGHTFIELD);
+    }
+}
@@ -0,0 +1,129 @@
+use ambient_ecs::{components, query, Debuggable, Description, EntityId, Name, Networked, Store, SystemGroup, World};
+use physxx::{PxFilterData, PxQueryFilterData, PxShape};
+
+use crate::{
+    collider::{collider_shapes, collider_shapes_convex},
+    contact_report_threshold,
+    physx::character_controller,
+};
+
+components!("physics", {
+    @[
+        Debuggable, Networked, Store,
+        Name["Collision layer"],
+        Description["The collision layers this entity's colliders are in, as a mask.\nThe `default` layer is `1`, and the layers declared in `ambient.toml` use the following bits. Defaults to the `default` layer."]
+    ]
+    collision_layer: u32,
+    @[
+        Debuggable, Networked, Store,
+        Name["Collides with"],
+        Description["The collision layers this entity's colliders collide with, as a mask.\nTwo colliders only collide if each of them is in a layer the other collides with. Defaults to all layers."]
+    ]
+    collides_with: u32,
+});
+
+/// The layer of colliders that have no `collision_layer`.
+pub const DEFAULT_COLLISION_LAYER: u32 = 1;
+/// A mask of every collision layer.
+pub const ALL_COLLISION_LAYERS: u32 = u32::MAX;
+
+/// The filter data of the shapes of `id`. `word0` holds the layers the shapes are in, `word1` the layers they collide with,
+/// and `word2` is `1` if the entity wants persist and end contact reports. `word3` is left for the bones of ragdolls.
+pub fn collision_filter_data(world: &World, id: EntityId) -> PxFilterData {
+    PxFilterData::new(
+        world.get(id, collision_layer()).unwrap_or(DEFAULT_COLLISION_LAYER),
+        world.get(id, collides_with()).unwrap_or(ALL_COLLISION_LAYERS),
+        world.has_component(id, contact_report_threshold()) as u32,
+        0,
+    )
+}
+
+/// Whether the shapes with the filter data `a` and `b` collide. Shapes without filter data, such as those that were just
+/// created, are in the default layer and collide with all layers.
+pub(crate) fn layers_collide(a: PxFilterData, b: PxFilterData) -> bool {
+    let layers =
+        |data: PxFilterData| if data.word0 == 0 { (DEFAULT_COLLISION_LAYER, ALL_COLLISION_LAYERS) } else { (data.word0, data.word1) };
+    let ((layer_a, mask_a), (layer_b, mask_b)) = (layers(a), layers(b));
+    (layer_a & mask_b) != 0 && (layer_b & mask_a) != 0
+}
+
+/// Whether contacts of the shape with the filter data `data` should be reported for every phase, not just when they begin.
+pub(crate) fn reports_all_contact_phases(data: PxFilterData) -> bool {
+    data.word2 != 0
+}
+
+/// Restricts the scene query of `filter_data` to shapes in `layers`. Without layers, every shape can be hit.
+///
+/// As in [layers_collide], `0` stands for the default layer.
+pub fn filter_query_layers(filter_data: &mut PxQueryFilterData, layers: Option<u32>) {
+    if let Some(layers) = layers {
+        filter_data.set_data(query_filter_data(layers));
+    }
+}
+
+/// The query filter data of shapes in `layers`, and of queries for them. PhysX skips shapes whose query filter data
+/// doesn't share a bit with the query's, so `0` is replaced by the default layer.
+pub fn query_filter_data(layers: u32) -> PxFilterData {
+    PxFilterData::new(if layers == 0 { DEFAULT_COLLISION_LAYER } else { layers }, 0, 0, 0)
+}
+
+/// Whether `id` is in any of `layers`.
+pub fn in_layers(world: &World, id: EntityId, layers: u32) -> bool {
+    world.get(id, collision_layer()).unwrap_or(DEFAULT_COLLISION_LAYER) & layers != 0
+}
+
+pub(crate) fn set_filter_data<'a>(shapes: impl IntoIterator<Item = &'a PxShape>, data: PxFilterData) {
+    for shape in shapes {
+        shape.set_simulation_filter_data(data);
+        // Scene queries only compare the layers the shape is in
+        shape.set_query_filter_data(query_filter_data(data.word0));
+    }
+}
+
+pub fn server_systems() -> SystemGroup {
+    SystemGroup::new(
+        "physics/collision_layers/server",
+        vec![
+            query((collider_shapes().changed(),))
+                .optional_changed(collision_layer())
+                .optional_changed(collides_with())
+                .optional_changed(contact_report_threshold())
+                .to_system(|q, world, qs, _| {
+                    for (id, (shapes,)) in q.collect_cloned(world, qs) {
+                        let convex = world.get_ref(id, collider_shapes_convex()).cloned().unwrap_or_default();
+                        set_filter_data(shapes.iter().chain(convex.iter()), collision_filter_data(world, id));
+                    }
+                }),
+            query((character_controller().changed(),))
+                .optional_changed(collision_layer())
+                .optional_changed(collides_with())
+                .optional_changed(contact_report_threshold())
+                .to_system(|q, world, qs, _| {
+                    for (id, (controller,)) in q.collect_cloned(world, qs) {
+                        set_filter_data(&controller.get_actor().get_shapes(), collision_filter_data(world, id));
+                    }
+                }),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn unassigned_shapes_are_in_the_default_layer() {
+        let unassigned = PxFilterData::new(0, 0, 0, 0);
+        let other = PxFilterData::new(1 << 1, ALL_COLLISION_LAYERS, 0, 0);
+        assert!(layers_collide(unassigned, PxFilterData::new(DEFAULT_COLLISION_LAYER, DEFAULT_COLLISION_LAYER, 0, 0)));
+        assert!(layers_collide(unassigned, other));
+        assert!(!layers_collide(unassigned, PxFilterData::new(1 << 1, 1 << 1, 0, 0)));
+    }
+
+    #[test]
+    fn queries_use_the_default_layer_for_zero() {
+        assert_eq!(query_filter_data(0).word0, DEFAULT_COLLISION_LAYER);
+        assert_eq!(query_filter_data(1 << 3).word0, 1 << 3);
+        assert_eq!(query_filter_data(0).word0 & query_filter_data(DEFAULT_COLLISION_LAYER).word0, DEFAULT_COLLISION_LAYER);
+    }
+}
@@ -1,16 +1,17 @@
-use std::collections::HashSet;
+use std::collections::{hash_map::Entry, HashMap, HashSet};
 
 use ambient_core::transform::{get_world_position, rotation, translation};
-use ambient_ecs::{query, ECSError, EntityId, World};
+use ambient_ecs::{query, ECSError, Entity, EntityId, World};
 use anyhow::{bail, Context};
-use glam::{vec3, Vec3};
+use glam::{vec3, Mat4, Quat, Vec3};
 use itertools::Itertools;
 use physxx::{
-    AsPxActor, AsPxRigidActor, PxActor, PxActorTypeFlag, PxBase, PxBoxGeometry, PxConvexMeshGeometry, PxJoint, PxMeshScale, PxOverlapCallback, PxQueryFilterData, PxQueryFlag, PxRevoluteJointRef, PxRigidActor, PxRigidActorRef, PxRigidBody, PxRigidBodyFlag, PxRigidDynamicRef, PxRigidStaticRef, PxSceneRef, PxShape, PxSphereGeometry, PxTransform, PxTriangleMeshGeometry, PxUserData
+    AsPxActor, AsPxRigidActor, PxActor, PxActorTypeFlag, PxAny, PxBase, PxBoxGeometry, PxCapsuleGeometry, PxConvexMeshGeometry, PxDistanceJointRef, PxFixedJointRef, PxJoint, PxJointRef, PxMeshScale, PxOverlapCallback, PxPrismaticJointRef, PxQueryFilterData, PxQueryFlag, PxRevoluteJointRef, PxRigidActor, PxRigidActorRef, PxRigidBody, PxRigidBodyFlag, PxRigidDynamicRef, PxRigidStaticRef, PxSceneRef, PxShape, PxSphereGeometry, PxSphericalJointRef, PxTransform, PxTriangleMeshGeometry, PxUserData
 };
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    collider::{collider_shapes_convex, collider_type, kinematic}, main_physics_scene, physx::{physics, physics_controlled, physics_shape, revolute_joint, rigid_dynamic}, unit_mass, unit_velocity, ColliderScene, PxActorUserData, PxShapeUserData
+    collider::{collider_shapes_convex, collider_type, kinematic}, contact_report_threshold, contacts, main_physics_scene, physx::{angular_velocity, created_joint, distance_joint, fixed_joint, linear_velocity, physics, physics_controlled, physics_shape, prismatic_joint, revolute_joint, rigid_dynamic, spherical_joint}, unit_mass, unit_velocity, ColliderScene, ContactPhase, PxActorUserData, PxShapeUserData, ShapeContact
 };
 
 pub fn convert_rigid_static_to_dynamic(world: &mut World, id: EntityId) {
@@ -103,6 +104,94 @@ pub fn get_linear_velocity(world: &World, id: EntityId) -> Result<Vec3, ECSError
     Ok(v)
 }
 
+fn get_rigid_dynamic(world: &World, id: EntityId) -> anyhow::Result<PxRigidDynamicRef> {
+    if let Ok(body) = world.get(id, rigid_dynamic()) {
+        return Ok(body);
+    }
+    world
+        .get_ref(id, physics_shape())
+        .ok()
+        .and_then(|shape| shape.get_actor())
+        .and_then(|actor| actor.to_rigid_dynamic())
+        .with_context(|| format!("Entity {id} is not a dynamic physics object"))
+}
+
+pub fn set_linear_velocity(world: &mut World, id: EntityId, velocity: Vec3) -> anyhow::Result<()> {
+    get_rigid_dynamic(world, id)?.set_linear_velocity(velocity, true);
+    world.set_if_changed(id, linear_velocity(), velocity).ok();
+    Ok(())
+}
+
+pub fn set_angular_velocity(world: &mut World, id: EntityId, velocity: Vec3) -> anyhow::Result<()> {
+    get_rigid_dynamic(world, id)?.set_angular_velocity(velocity, true);
+    world.set_if_changed(id, angular_velocity(), velocity).ok();
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct EntityContact {
+    pub other: EntityId,
+    pub position: Vec3,
+    /// Points towards the entity the contacts were requested for
+    pub normal: Vec3,
+    pub separation: f32,
+    pub impulse: Vec3,
+}
+
+/// Returns the contact points of `id` reported by the last simulation step
+pub fn get_contacts(world: &World, id: EntityId) -> Vec<EntityContact> {
+    let contacts = world.resource(contacts()).lock();
+    let mut res = Vec::new();
+    for contact in contacts.iter() {
+        let (other, sign) = match contact.entities {
+            [a, b] if a == id => (b, 1.),
+            [a, b] if b == id => (a, -1.),
+            _ => continue,
+        };
+        res.extend(contact.points.iter().map(|point| EntityContact {
+            other,
+            position: point.position,
+            normal: point.normal * sign,
+            separation: point.separation,
+            impulse: point.impulse * sign,
+        }));
+    }
+    res
+}
+
+/// Returns the contacts reported by the last simulation step, with the contacts between the shapes of the same two entities
+/// merged. Persist contacts are only reported for entities with a `contact_report_threshold`, and begin and persist contacts whose
+/// total impulse is below the `contact_report_threshold` of either entity are skipped.
+pub fn get_reported_contacts(world: &World) -> Vec<ShapeContact> {
+    let contacts = world.resource(contacts()).lock();
+    let mut res: Vec<ShapeContact> = Vec::new();
+    // The index in `res` of the merged contact of each pair and phase
+    let mut indices = HashMap::new();
+    for contact in contacts.iter() {
+        match indices.entry((contact.entities, contact.phase)) {
+            Entry::Occupied(index) => {
+                let merged = &mut res[*index.get()];
+                merged.points.extend(contact.points.iter().copied());
+                merged.total_impulse += contact.total_impulse;
+            }
+            Entry::Vacant(index) => {
+                index.insert(res.len());
+                res.push(contact.clone());
+            }
+        }
+    }
+    res.retain(|contact| {
+        let thresholds = contact.entities.iter().filter_map(|&id| world.get(id, contact_report_threshold()).ok()).collect_vec();
+        match contact.phase {
+            ContactPhase::End => true,
+            // Touches persist for all entities, but they're only reported for the ones that asked for them
+            ContactPhase::Persist if thresholds.is_empty() => false,
+            _ => contact.total_impulse >= thresholds.into_iter().fold(0., f32::max),
+        }
+    });
+    res
+}
+
 /// Returns both the simulated and faked convex shapes of an entity
 pub fn get_shapes(world: &World, id: EntityId) -> impl Iterator<Item = PxShape> + '_ {
     world
@@ -150,6 +239,10 @@ pub fn scale_shape(shape: PxShape, scale: Vec3) {
     } else if let Some(_geo) = geo.as_box() {
         let new_geo = PxBoxGeometry::new(size.x, size.y, size.z);
         shape.set_geometry(&new_geo);
+    } else if let Some(_geo) = geo.as_capsule() {
+        // The base scale of capsules is their half height along the X axis, and their radius along the other axes
+        let new_geo = PxCapsuleGeometry::new(size.z.abs(), size.x.abs());
+        shape.set_geometry(&new_geo);
     } else {
         // TODO
     }
@@ -187,6 +280,158 @@ pub fn get_entity_revolute_joint(world: &World, id: EntityId) -> Option<PxRevolu
     None
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JointType {
+    Fixed,
+    Revolute,
+    Prismatic,
+    Spherical,
+    Distance,
+}
+
+/// How a joint was made by [create_joint], so that it can be recreated when its physics objects are rebuilt.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct JointDescription {
+    pub joint_type: JointType,
+    pub first: EntityId,
+    pub second: Option<EntityId>,
+    /// The pose of the joint relative to `first` and to `second` (or the world)
+    pub local_frames: [(Vec3, Quat); 2],
+}
+
+/// Creates a joint between `first` and `second` (or the world, if `second` is `None`) at the world space `position` and `rotation`.
+///
+/// The joint is stored on a new entity, which is returned. Revolute and prismatic joints use the X axis of `rotation` as their axis.
+pub fn create_joint(
+    world: &mut World,
+    joint_type: JointType,
+    first: EntityId,
+    second: Option<EntityId>,
+    position: Vec3,
+    rotation: Quat,
+) -> anyhow::Result<EntityId> {
+    let joint_pose = Mat4::from_rotation_translation(rotation, position);
+    let actor_pose = |id: EntityId| get_joint_actor(world, id).map(|actor| actor.get_global_pose().to_mat4());
+    let local_frames =
+        [joint_local_frame(Some(actor_pose(first)?), joint_pose), joint_local_frame(second.map(actor_pose).transpose()?, joint_pose)];
+    let desc = JointDescription { joint_type, first, second, local_frames };
+
+    let (data, joint) = build_joint(world, &desc)?;
+    let id = data.with(created_joint(), desc).spawn(world);
+    joint.set_user_data(id);
+    Ok(id)
+}
+
+fn get_joint_actor(world: &World, id: EntityId) -> anyhow::Result<PxRigidActorRef> {
+    world
+        .get_ref(id, physics_shape())
+        .with_context(|| format!("Entity {id} has no physics shape"))?
+        .get_actor()
+        .context("Shape has no actor")
+}
+
+/// Returns the pose of a joint at the world space `joint_pose` relative to an actor at `actor_pose`, or to the world
+fn joint_local_frame(actor_pose: Option<Mat4>, joint_pose: Mat4) -> (Vec3, Quat) {
+    let pose = match actor_pose {
+        Some(actor_pose) => actor_pose.inverse() * joint_pose,
+        None => joint_pose,
+    };
+    let (_, rotation, translation) = pose.to_scale_rotation_translation();
+    (translation, rotation)
+}
+
+/// Creates the PhysX joint described by `desc`, and the components that store it
+fn build_joint(world: &World, desc: &JointDescription) -> anyhow::Result<(Entity, PxJointRef)> {
+    let actor0 = get_joint_actor(world, desc.first)?;
+    let actor1 = desc.second.map(|id| get_joint_actor(world, id)).transpose()?;
+    if actor0.to_rigid_dynamic().is_none() && actor1.and_then(|a| a.to_rigid_dynamic()).is_none() {
+        bail!("At least one of the jointed entities must be dynamic");
+    }
+    let [frame0, frame1] = desc.local_frames.map(|(translation, rotation)| PxTransform::new(translation, rotation));
+
+    let physics = world.resource(physics()).physics;
+    Ok(match desc.joint_type {
+        JointType::Fixed => {
+            let joint = PxFixedJointRef::new(physics, Some(actor0), &frame0, actor1, &frame1);
+            (Entity::new().with(fixed_joint(), joint), joint.as_joint())
+        }
+        JointType::Revolute => {
+            let joint = PxRevoluteJointRef::new(physics, Some(actor0), &frame0, actor1, &frame1);
+            (Entity::new().with(revolute_joint(), joint), joint.as_joint())
+        }
+        JointType::Prismatic => {
+            let joint = PxPrismaticJointRef::new(physics, Some(actor0), &frame0, actor1, &frame1);
+            (Entity::new().with(prismatic_joint(), joint), joint.as_joint())
+        }
+        JointType::Spherical => {
+            let joint = PxSphericalJointRef::new(physics, Some(actor0), &frame0, actor1, &frame1);
+            (Entity::new().with(spherical_joint(), joint), joint.as_joint())
+        }
+        JointType::Distance => {
+            let joint = PxDistanceJointRef::new(physics, Some(actor0), &frame0, actor1, &frame1);
+            (Entity::new().with(distance_joint(), joint), joint.as_joint())
+        }
+    })
+}
+
+/// Recreates the joints made by [create_joint] that were lost when the physics objects of the world were rebuilt, for instance
+/// after forking or loading it. Joints whose entities don't have physics objects yet are skipped.
+pub fn rebuild_created_joints(world: &mut World) {
+    let lost = query(created_joint())
+        .excl(fixed_joint())
+        .excl(revolute_joint())
+        .excl(prismatic_joint())
+        .excl(spherical_joint())
+        .excl(distance_joint())
+        .collect_cloned(world, None);
+    for (id, desc) in lost {
+        if let Ok((data, joint)) = build_joint(world, &desc) {
+            joint.set_user_data(id);
+            world.add_components(id, data).unwrap();
+        }
+    }
+}
+
+/// Returns the joint stored on `id`, if any
+pub fn get_entity_joint(world: &World, id: EntityId) -> Option<PxJointRef> {
+    world
+        .get(id, fixed_joint())
+        .map(|j| j.as_joint())
+        .or_else(|_| world.get(id, revolute_joint()).map(|j| j.as_joint()))
+        .or_else(|_| world.get(id, prismatic_joint()).map(|j| j.as_joint()))
+        .or_else(|_| world.get(id, spherical_joint()).map(|j| j.as_joint()))
+        .or_else(|_| world.get(id, distance_joint()).map(|j| j.as_joint()))
+        .ok()
+}
+
+/// Releases the joint stored on `id`. Entities spawned by [create_joint] are despawned, other entities only lose the joint component
+pub fn break_joint(world: &mut World, id: EntityId) -> anyhow::Result<()> {
+    let joint = get_entity_joint(world, id).context("Entity doesn't have a joint")?;
+    joint.remove_user_data::<EntityId>();
+    if world.has_component(id, created_joint()) {
+        world.despawn(id);
+    } else {
+        remove_joint_component(world, id, joint);
+    }
+    joint.release();
+    Ok(())
+}
+
+fn remove_joint_component(world: &mut World, entity: EntityId, joint: PxJointRef) {
+    match joint.as_px_any() {
+        PxAny::PxFixedJoint(_) => world.remove_component(entity, fixed_joint()),
+        PxAny::PxRevoluteJoint(_) => world.remove_component(entity, revolute_joint()),
+        PxAny::PxPrismaticJoint(_) => world.remove_component(entity, prismatic_joint()),
+        PxAny::PxSphericalJoint(_) => world.remove_component(entity, spherical_joint()),
+        PxAny::PxDistanceJoint(_) => world.remove_component(entity, distance_joint()),
+        // Joints of other kinds aren't stored in components
+        _ => Ok(()),
+    }
+    .ok();
+    // The joint is gone for good, so it mustn't be recreated
+    world.remove_component(entity, created_joint()).ok();
+}
+
 pub fn weld_multi(world: &mut World, selected: Vec<EntityId>) {
     let mut selected = selected
         .into_iter()
@@ -256,11 +501,7 @@ pub fn weld_two(world: &mut World, first: EntityId, second: EntityId) {
                 joint.set_local_pose(0, &PxTransform::new(translation, rotation));
             } else {
                 let entity = joint.get_user_data::<EntityId>().unwrap();
-                if joint.to_revolute_joint().is_some() {
-                    world.remove_component(entity, revolute_joint()).ok();
-                } else {
-                    unimplemented!()
-                }
+                remove_joint_component(world, entity, joint);
                 joint.release();
             }
         } else if can_have_joint(&a0) {
@@ -274,11 +515,7 @@ pub fn weld_two(world: &mut World, first: EntityId, second: EntityId) {
             joint.set_local_pose(1, &PxTransform::new(translation, rotation));
         } else {
             let entity = joint.get_user_data::<EntityId>().unwrap();
-            if joint.to_revolute_joint().is_some() {
-                world.remove_component(entity, revolute_joint()).ok();
-            } else {
-                unimplemented!()
-            }
+            remove_joint_component(world, entity, joint);
             joint.release();
         }
     }
@@ -473,3 +710,120 @@ pub fn release_px_scene(scene: PxSceneRef) {
     }
     scene.release();
 }
+
+#[cfg(test)]
+mod test {
+    use std::{f32::consts::FRAC_PI_2, sync::Arc};
+
+    use ambient_ecs::{Entity, EntityId, World};
+    use glam::{vec3, Mat4, Quat, Vec3};
+    use parking_lot::Mutex;
+    use physxx::PxContactPairPoint;
+
+    use super::{get_contacts, get_reported_contacts, joint_local_frame};
+    use crate::{contact_report_threshold, contacts, init_all_components, ContactPhase, ShapeContact};
+
+    fn contact(entities: [EntityId; 2], phase: ContactPhase, impulse: f32) -> ShapeContact {
+        let point = PxContactPairPoint { position: Vec3::ONE, normal: Vec3::Z, separation: -0.1, impulse: Vec3::Z * impulse };
+        ShapeContact { entities, phase, points: vec![point], total_impulse: impulse }
+    }
+
+    fn contact_world(contacts: impl FnOnce(EntityId, EntityId, EntityId) -> Vec<ShapeContact>) -> (World, [EntityId; 3]) {
+        init_all_components();
+        let mut world = World::new("contacts");
+        let [a, b, c] = [(); 3].map(|_| world.spawn(Entity::new()));
+        world.add_resource(self::contacts(), Arc::new(Mutex::new(contacts(a, b, c))));
+        (world, [a, b, c])
+    }
+
+    #[test]
+    fn contact_normals_point_towards_the_entity() {
+        let (world, [a, b, c]) = contact_world(|a, b, _| vec![contact([a, b], ContactPhase::Begin, 2.)]);
+
+        let of_a = get_contacts(&world, a);
+        assert_eq!(of_a.len(), 1);
+        assert_eq!(of_a[0].other, b);
+        assert_eq!(of_a[0].normal, Vec3::Z);
+        assert_eq!(of_a[0].impulse, Vec3::Z * 2.);
+
+        let of_b = get_contacts(&world, b);
+        assert_eq!(of_b.len(), 1);
+        assert_eq!(of_b[0].other, a);
+        assert_eq!(of_b[0].normal, -Vec3::Z);
+        assert_eq!(of_b[0].impulse, -Vec3::Z * 2.);
+
+        assert!(get_contacts(&world, c).is_empty());
+    }
+
+    #[test]
+    fn persisting_contacts_are_returned() {
+        let (world, [a, _, _]) = contact_world(|a, b, _| vec![contact([a, b], ContactPhase::Persist, 1.)]);
+        assert_eq!(get_contacts(&world, a).len(), 1);
+    }
+
+    #[test]
+    fn reported_contacts_are_merged_and_filtered() {
+        let (mut world, [a, b, c]) = contact_world(|a, b, c| {
+            vec![
+                contact([a, b], ContactPhase::Begin, 1.),
+                contact([a, b], ContactPhase::Begin, 2.),
+                contact([a, b], ContactPhase::Persist, 3.),
+                contact([a, c], ContactPhase::Begin, 0.5),
+                contact([b, c], ContactPhase::End, 0.),
+            ]
+        });
+
+        // Without thresholds, persisting contacts aren't reported
+        let reported = get_reported_contacts(&world);
+        assert_eq!(reported.len(), 3);
+        assert_eq!(reported[0].entities, [a, b]);
+        assert_eq!(reported[0].points.len(), 2);
+        assert_eq!(reported[0].total_impulse, 3.);
+        assert!(reported.iter().all(|contact| contact.phase != ContactPhase::Persist));
+
+        world.add_component(a, contact_report_threshold(), 1.).unwrap();
+        let reported = get_reported_contacts(&world);
+        let phases = reported.iter().map(|contact| (contact.entities, contact.phase)).collect::<Vec<_>>();
+        assert_eq!(phases, vec![([a, b], ContactPhase::Begin), ([a, b], ContactPhase::Persist), ([b, c], ContactPhase::End)]);
+    }
+
+    #[test]
+    fn reported_contacts_keep_their_order() {
+        let (world, [a, b, c]) = contact_world(|a, b, c| {
+            vec![
+                contact([b, c], ContactPhase::Begin, 1.),
+                contact([a, b], ContactPhase::Begin, 1.),
+                contact([b, c], ContactPhase::Begin, 1.),
+                contact([c, b], ContactPhase::Begin, 1.),
+                contact([a, b], ContactPhase::End, 0.),
+                contact([a, b], ContactPhase::Begin, 1.),
+            ]
+        });
+
+        let reported = get_reported_contacts(&world);
+        let pairs = reported.iter().map(|contact| (contact.entities, contact.phase, contact.points.len())).collect::<Vec<_>>();
+        // Pairs are ordered, as the normals point from the second entity towards the first
+        assert_eq!(
+            pairs,
+            vec![
+                ([b, c], ContactPhase::Begin, 2),
+                ([a, b], ContactPhase::Begin, 2),
+                ([c, b], ContactPhase::Begin, 1),
+                ([a, b], ContactPhase::End, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn joint_frames_are_relative_to_the_actor() {
+        let joint_pose = Mat4::from_translation(vec3(1., 1., 0.));
+        let actor_pose = Mat4::from_rotation_translation(Quat::from_rotation_z(FRAC_PI_2), vec3(1., 0., 0.));
+
+        let (translation, rotation) = joint_local_frame(Some(actor_pose), joint_pose);
+        assert!(translation.abs_diff_eq(vec3(1., 0., 0.), 1e-5));
+        assert!(rotation.abs_diff_eq(Quat::from_rotation_z(-FRAC_PI_2), 1e-5));
+        assert!((actor_pose * Mat4::from_rotation_translation(rotation, translation)).abs_diff_eq(joint_pose, 1e-5));
+
+        assert_eq!(joint_local_frame(None, joint_pose), (vec3(1., 1., 0.), Quat::IDENTITY));
+    }
+}
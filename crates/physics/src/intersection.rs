@@ -5,43 +5,56 @@ use ambient_ecs::{query, ArchetypeFilter, EntityId, World};
 use ambient_meshes::cuboid::CuboidMesh;
 use ambient_network::client::GameRpcArgs;
 use ambient_std::{asset_cache::SyncAssetKeyExt, mesh::Mesh, shapes::Ray};
-use glam::Vec3;
+use glam::{Quat, Vec3};
 use itertools::Itertools;
 use ordered_float::OrderedFloat;
 use physxx::{
-    PxConvexFlag, PxConvexMesh, PxConvexMeshDesc, PxConvexMeshGeometry, PxOverlapCallback, PxQueryFilterData, PxRaycastCallback,
-    PxRigidActor, PxShape, PxTransform, PxUserData,
+    PxBoxGeometry, PxCapsuleGeometry, PxConvexFlag, PxConvexMesh, PxConvexMeshDesc, PxConvexMeshGeometry, PxGeometry, PxOverlapCallback,
+    PxQueryFilterData, PxQueryFlag, PxRaycastCallback, PxRigidActor, PxShape, PxSphereGeometry, PxTransform, PxUserData,
 };
 use serde::{Deserialize, Serialize};
 
-use crate::{main_physics_scene, physx::PhysicsKey, ColliderScene, PxShapeUserData};
+use crate::{
+    collision_layers::{filter_query_layers, in_layers},
+    main_physics_scene,
+    physx::PhysicsKey,
+    ColliderScene, PxShapeUserData,
+};
 
-pub fn get_entities_in_radius(world: &World, center: Vec3, radius: f32) -> Vec<EntityId> {
+/// Returns the entities within `radius` of `center`. If `layers` is set, only entities in those collision layers are returned.
+pub fn get_entities_in_radius(world: &World, center: Vec3, radius: f32, layers: Option<u32>) -> Vec<EntityId> {
     query((translation(),))
         .iter(world, None)
         .filter_map(|(id, (&pos,))| if (pos - center).length() <= radius { Some(id) } else { None })
+        .filter(|&id| layers.map(|layers| in_layers(world, id, layers)).unwrap_or(true))
         .collect_vec()
 }
 
-pub fn raycast_first(world: &World, ray: Ray) -> Option<(EntityId, f32)> {
-    raycast_first_px(world, ray).and_then(|(shape, dist)| shape.get_user_data::<PxShapeUserData>().map(|ud| (ud.entity, dist)))
+pub fn raycast_first(world: &World, ray: Ray, layers: Option<u32>) -> Option<(EntityId, f32)> {
+    raycast_first_px(world, ray, layers).and_then(|(shape, dist)| shape.get_user_data::<PxShapeUserData>().map(|ud| (ud.entity, dist)))
 }
 
-fn raycast_first_px(world: &World, ray: Ray) -> Option<(PxShape, f32)> {
+fn raycast_first_px(world: &World, ray: Ray, layers: Option<u32>) -> Option<(PxShape, f32)> {
     (0..3)
-        .filter_map(|i| raycast_first_collider_type_px(world, ColliderScene::from_usize(i), ray))
+        .filter_map(|i| raycast_first_collider_type_px(world, ColliderScene::from_usize(i), ray, layers))
         .sorted_by_key(|x| OrderedFloat(x.1))
         .next()
 }
 
-pub fn raycast_first_collider_type(world: &World, collider_type: ColliderScene, ray: Ray) -> Option<(EntityId, f32)> {
-    raycast_first_collider_type_px(world, collider_type, ray)
+pub fn raycast_first_collider_type(world: &World, collider_type: ColliderScene, ray: Ray, layers: Option<u32>) -> Option<(EntityId, f32)> {
+    raycast_first_collider_type_px(world, collider_type, ray, layers)
         .and_then(|(shape, dist)| shape.get_user_data::<PxShapeUserData>().map(|ud| (ud.entity, dist)))
 }
-pub fn raycast_first_collider_type_px(world: &World, collider_type: ColliderScene, ray: Ray) -> Option<(PxShape, f32)> {
+pub fn raycast_first_collider_type_px(
+    world: &World,
+    collider_type: ColliderScene,
+    ray: Ray,
+    layers: Option<u32>,
+) -> Option<(PxShape, f32)> {
     let mut hit = PxRaycastCallback::new(0);
     let scene = collider_type.get_scene(world);
-    let filter_data = PxQueryFilterData::new();
+    let mut filter_data = PxQueryFilterData::new();
+    filter_query_layers(&mut filter_data, layers);
     if scene.raycast(ray.origin, ray.dir, f32::MAX, &mut hit, None, &filter_data) {
         let block = hit.block().unwrap();
         if let Some(shape) = block.shape {
@@ -51,36 +64,142 @@ pub fn raycast_first_collider_type_px(world: &World, collider_type: ColliderScen
     None
 }
 
-pub fn raycast(world: &World, ray: Ray) -> Vec<(EntityId, f32)> {
-    raycast_px(world, ray)
+pub fn raycast(world: &World, ray: Ray, layers: Option<u32>) -> Vec<(EntityId, f32)> {
+    raycast_px(world, ray, layers)
         .into_iter()
         .flat_map(|(shape, dist)| shape.get_user_data::<PxShapeUserData>().map(|ud| (ud.entity, dist)))
         .collect_vec()
 }
 
-fn raycast_px(world: &World, ray: Ray) -> Vec<(PxShape, f32)> {
+fn raycast_px(world: &World, ray: Ray, layers: Option<u32>) -> Vec<(PxShape, f32)> {
     (0..3)
-        .flat_map(|i| raycast_collider_type_px(world, ColliderScene::from_usize(i), ray).into_iter())
+        .flat_map(|i| raycast_collider_type_px(world, ColliderScene::from_usize(i), ray, layers).into_iter())
         .sorted_by_key(|x| OrderedFloat(x.1))
         .collect_vec()
 }
 
-pub fn raycast_collider_type(world: &World, collider_type: ColliderScene, ray: Ray) -> Vec<(EntityId, f32)> {
-    raycast_collider_type_px(world, collider_type, ray)
+pub fn raycast_collider_type(world: &World, collider_type: ColliderScene, ray: Ray, layers: Option<u32>) -> Vec<(EntityId, f32)> {
+    raycast_collider_type_px(world, collider_type, ray, layers)
         .into_iter()
         .filter_map(|(shape, dist)| shape.get_user_data::<PxShapeUserData>().map(|ud| (ud.entity, dist)))
         .collect()
 }
-pub fn raycast_collider_type_px(world: &World, collider_type: ColliderScene, ray: Ray) -> Vec<(PxShape, f32)> {
+pub fn raycast_collider_type_px(world: &World, collider_type: ColliderScene, ray: Ray, layers: Option<u32>) -> Vec<(PxShape, f32)> {
     let mut hit = PxRaycastCallback::new(100);
     let scene = collider_type.get_scene(world);
-    let filter_data = PxQueryFilterData::new();
+    let mut filter_data = PxQueryFilterData::new();
+    filter_query_layers(&mut filter_data, layers);
     if scene.raycast(ray.origin, ray.dir, f32::MAX, &mut hit, None, &filter_data) {
         return hit.touches().into_iter().filter_map(|hit| hit.shape.map(|shape| (shape, hit.distance))).collect_vec();
     }
     Vec::new()
 }
 
+/// A shape used for [sweep] and [overlap] queries
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum QueryShape {
+    Sphere {
+        radius: f32,
+    },
+    Box {
+        half_extents: Vec3,
+    },
+    /// A capsule along the X axis
+    Capsule {
+        radius: f32,
+        half_height: f32,
+    },
+}
+impl QueryShape {
+    fn with_geometry<R>(&self, f: impl FnOnce(&dyn PxGeometry) -> R) -> R {
+        match *self {
+            QueryShape::Sphere { radius } => f(&PxSphereGeometry::new(radius)),
+            QueryShape::Box { half_extents } => f(&PxBoxGeometry::new(half_extents.x, half_extents.y, half_extents.z)),
+            QueryShape::Capsule { radius, half_height } => f(&PxCapsuleGeometry::new(radius, half_height)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SweepHit {
+    pub entity: EntityId,
+    pub position: Vec3,
+    pub normal: Vec3,
+    pub distance: f32,
+}
+
+fn query_scenes(collider_type: Option<ColliderScene>) -> Vec<ColliderScene> {
+    match collider_type {
+        Some(collider_type) => vec![collider_type],
+        None => (0..3).map(ColliderScene::from_usize).collect(),
+    }
+}
+
+/// Filter data that reports every hit as a touch, so that all hits are returned
+pub(crate) fn touching_filter_data() -> PxQueryFilterData {
+    let mut filter_data = PxQueryFilterData::new();
+    filter_data.set_flags(PxQueryFlag::STATIC | PxQueryFlag::DYNAMIC | PxQueryFlag::NO_BLOCK);
+    filter_data
+}
+
+/// Sweeps `shape` from `position` along `direction` (which must be normalized), and returns all hits sorted by distance.
+/// If `layers` is set, only colliders in those collision layers are hit.
+#[allow(clippy::too_many_arguments)]
+pub fn sweep(
+    world: &World,
+    shape: QueryShape,
+    position: Vec3,
+    rotation: Quat,
+    direction: Vec3,
+    max_distance: f32,
+    collider_type: Option<ColliderScene>,
+    layers: Option<u32>,
+) -> Vec<SweepHit> {
+    let pose = PxTransform::new(position, rotation);
+    query_scenes(collider_type)
+        .into_iter()
+        .flat_map(|scene| {
+            let scene = scene.get_scene(world);
+            let mut filter_data = touching_filter_data();
+            filter_query_layers(&mut filter_data, layers);
+            shape.with_geometry(|geo| scene.sweep(geo, &pose, direction, max_distance, filter_data)).touches()
+        })
+        .filter_map(|hit| {
+            let ud = hit.shape?.get_user_data::<PxShapeUserData>()?;
+            Some(SweepHit { entity: ud.entity, position: hit.position, normal: hit.normal, distance: hit.distance })
+        })
+        .sorted_by_key(|hit| OrderedFloat(hit.distance))
+        .collect_vec()
+}
+
+/// Returns all entities whose colliders overlap `shape` placed at `position` and `rotation`. If `layers` is set, only
+/// colliders in those collision layers are returned.
+pub fn overlap(
+    world: &World,
+    shape: QueryShape,
+    position: Vec3,
+    rotation: Quat,
+    collider_type: Option<ColliderScene>,
+    layers: Option<u32>,
+) -> Vec<EntityId> {
+    let pose = PxTransform::new(position, rotation);
+    let mut filter_data = touching_filter_data();
+    filter_query_layers(&mut filter_data, layers);
+    let mut res = HashSet::new();
+    for scene in query_scenes(collider_type) {
+        let scene = scene.get_scene(world);
+        let mut hit_call = PxOverlapCallback::new(1000);
+        if shape.with_geometry(|geo| scene.overlap(geo, pose, &mut hit_call, &filter_data)) {
+            for hit in hit_call.touches() {
+                if let Some(ud) = hit.shape.get_user_data::<PxShapeUserData>() {
+                    res.insert(ud.entity);
+                }
+            }
+        }
+    }
+    res.into_iter().collect()
+}
+
 pub fn intersect_frustum(world: &World, frustum_corners: &[Vec3; 8]) -> Vec<EntityId> {
     let mut hit_call = PxOverlapCallback::new(1000);
     let filter_data = PxQueryFilterData::new();
@@ -122,8 +241,11 @@ pub async fn rpc_pick(args: GameRpcArgs, (ray, filter): (Ray, RaycastFilter)) ->
 }
 
 pub fn raycast_filtered(world: &World, filter: RaycastFilter, ray: Ray) -> Option<(EntityId, f32)> {
-    let hits =
-        if let Some(collider_type) = filter.collider_type { raycast_collider_type(world, collider_type, ray) } else { raycast(world, ray) };
+    let hits = if let Some(collider_type) = filter.collider_type {
+        raycast_collider_type(world, collider_type, ray, filter.layers)
+    } else {
+        raycast(world, ray, filter.layers)
+    };
     if let Some(filter) = &filter.entities {
         hits.into_iter().filter(|(id, _)| filter.matches_entity(world, *id)).min_by_key(|(_, dist)| OrderedFloat(*dist))
     } else {
@@ -134,4 +256,7 @@ pub fn raycast_filtered(world: &World, filter: RaycastFilter, ray: Ray) -> Optio
 pub struct RaycastFilter {
     pub entities: Option<ArchetypeFilter>,
     pub collider_type: Option<ColliderScene>,
+    /// Only hit entities in these collision layers
+    #[serde(default)]
+    pub layers: Option<u32>,
 }
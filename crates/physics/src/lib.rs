@@ -9,25 +9,32 @@ use ambient_std::asset_cache::{AssetCache, SyncAssetKey, SyncAssetKeyExt};
 use collider::{collider_shapes, collider_shapes_convex};
 use glam::{vec3, Mat4, Vec3};
 use helpers::release_px_scene;
+use itertools::Itertools;
 use parking_lot::Mutex;
 use physx::{
-    actor_aggregate, articulation_cache, articulation_link, articulation_reduce_coordinate, character_controller, fixed_joint,
-    physics_shape, revolute_joint, rigid_actor, rigid_dynamic, rigid_static,
+    actor_aggregate, articulation_cache, articulation_link, articulation_reduce_coordinate, character_controller, distance_joint,
+    fixed_joint, physics_shape, prismatic_joint, revolute_joint, rigid_actor, rigid_dynamic, rigid_static, spherical_joint,
 };
 use physxx::{
-    AsPxActor, PxContactPairHeader, PxControllerManagerRef, PxMaterial, PxPvdSceneFlag, PxRigidActor, PxRigidActorRef, PxSceneDesc,
-    PxSceneFlags, PxSceneRef, PxSimulationEventCallback, PxUserData,
+    AsPxActor, PxContactPairHeader, PxContactPairPoint, PxControllerManagerRef, PxFilterData, PxMaterial, PxPairFlag, PxPvdSceneFlag,
+    PxRigidActor, PxRigidActorRef, PxSceneDesc, PxSceneFlags, PxSceneRef, PxSimulationEventCallback, PxUserData,
 };
 use serde::{Deserialize, Serialize};
 
-use crate::physx::PhysicsKey;
+use crate::{physx::PhysicsKey, snapshot::PhysicsSnapshot};
 
 pub mod collider;
+pub mod collision_layers;
 pub mod helpers;
 pub mod intersection;
+pub mod material;
 pub mod mesh;
 pub mod physx;
+pub mod ragdoll;
 pub mod rc_asset;
+pub mod snapshot;
+pub mod triggers;
+pub mod vehicle;
 pub mod visualization;
 
 components!("physics", {
@@ -43,6 +50,32 @@ components!("physics", {
     wood_physics_material: PxMaterial,
     @[Debuggable, Resource]
     collisions: Arc<Mutex<Vec<(PxRigidActorRef, PxRigidActorRef)>>>,
+    @[Debuggable, Resource]
+    contacts: Arc<Mutex<Vec<ShapeContact>>>,
+    @[
+        Debuggable, Networked, Store,
+        Name["Contact report threshold"],
+        Description["Enables persist and end contact reports for this entity's colliders, and skips begin and persist reports whose total impulse is below this value."]
+    ]
+    contact_report_threshold: f32,
+    @[
+        Debuggable,
+        Name["Contact points"],
+        Description["The world space contact points of a collision. Used in collision events."]
+    ]
+    contact_points: Vec<Vec3>,
+    @[
+        Debuggable,
+        Name["Contact normals"],
+        Description["The contact normals of a collision, pointing from the second entity towards the first. Used in collision events."]
+    ]
+    contact_normals: Vec<Vec3>,
+    @[
+        Debuggable,
+        Name["Contact impulse"],
+        Description["The total impulse applied at the contact points of a collision. Used in collision events."]
+    ]
+    contact_impulse: f32,
 
     @[
         Debuggable, Networked, Store,
@@ -79,6 +112,12 @@ pub fn init_all_components() {
     init_components();
     physx::init_components();
     collider::init_components();
+    collision_layers::init_components();
+    material::init_components();
+    ragdoll::init_components();
+    snapshot::init_components();
+    triggers::init_components();
+    vehicle::init_components();
     visualization::init_components();
 }
 
@@ -93,19 +132,38 @@ pub fn create_server_resources(assets: &AssetCache, server_resources: &mut Entit
     main_scene_desc.update_flags(|flags| flags | PxSceneFlags::ENABLE_CCD);
     main_scene_desc.set_filter_shader(main_physx_scene_filter_shader, true);
     let collisions = Arc::new(Mutex::new(Vec::new()));
+    let contacts = Arc::new(Mutex::new(Vec::new()));
     {
         let collisions = collisions.clone();
+        let contacts = contacts.clone();
         main_scene_desc.set_simulation_event_callbacks(PxSimulationEventCallback {
             collision_callback: Some(Box::new(move |header: &PxContactPairHeader| {
                 if let (Some(a), Some(b)) = (header.actors[0], header.actors[1]) {
                     collisions.lock().push((a, b));
                 }
+                let mut contacts = contacts.lock();
+                for pair in &header.pairs {
+                    let entities = pair.shapes.iter().flatten().filter_map(|shape| shape.get_user_data::<PxShapeUserData>()).collect_vec();
+                    if let [a, b] = &entities[..] {
+                        let total_impulse = pair.points.iter().map(|point| point.impulse.length()).sum();
+                        for phase in ContactPhase::from_events(pair.events) {
+                            contacts.push(ShapeContact {
+                                entities: [a.entity, b.entity],
+                                phase,
+                                points: pair.points.clone(),
+                                total_impulse,
+                            });
+                        }
+                    }
+                }
             })),
         });
     }
     let main_scene = PxSceneRef::new(&physics.physics, &main_scene_desc);
     server_resources.set(self::collisions(), collisions);
+    server_resources.set(self::contacts(), contacts);
     server_resources.set(self::collider_loads(), vec![]);
+    server_resources.set(triggers::trigger_events(), vec![]);
 
     main_scene.get_scene_pvd_client().set_scene_pvd_flags(
         PxPvdSceneFlag::TRANSMIT_CONSTRAINTS | PxPvdSceneFlag::TRANSMIT_SCENEQUERIES | PxPvdSceneFlag::TRANSMIT_CONTACTS,
@@ -142,6 +200,39 @@ impl Default for PxShapeUserData {
     }
 }
 
+/// Whether a contact started, continued or ended in the last simulation step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContactPhase {
+    Begin,
+    /// Only included in the reported contacts for entities with a `contact_report_threshold`
+    Persist,
+    /// Only reported for entities with a `contact_report_threshold`
+    End,
+}
+impl ContactPhase {
+    fn from_events(events: PxPairFlag) -> impl Iterator<Item = Self> {
+        [
+            (PxPairFlag::NOTIFY_TOUCH_FOUND, Self::Begin),
+            (PxPairFlag::NOTIFY_TOUCH_PERSISTS, Self::Persist),
+            (PxPairFlag::NOTIFY_TOUCH_LOST, Self::End),
+        ]
+        .into_iter()
+        .filter(move |(flag, _)| events.contains(*flag))
+        .map(|(_, phase)| phase)
+    }
+}
+
+/// A contact between the shapes of two entities, as reported by the last simulation step.
+#[derive(Debug, Clone)]
+pub struct ShapeContact {
+    pub entities: [EntityId; 2],
+    pub phase: ContactPhase,
+    /// The contact points; normals point from the second entity towards the first. Empty for ended contacts
+    pub points: Vec<PxContactPairPoint>,
+    /// The sum of the magnitudes of the impulses applied at the contact points
+    pub total_impulse: f32,
+}
+
 #[derive(Debug, Clone)]
 pub struct PxActorUserData {
     pub serialize: bool,
@@ -188,11 +279,20 @@ impl SyncAssetKey<PxMaterial> for PxWoodMaterialKey {
 }
 
 unsafe extern "C" fn main_physx_scene_filter_shader(mut info: *mut physxx::sys::FilterShaderCallbackInfo) -> u16 {
+    let (data0, data1) = (PxFilterData::from_physx(&(*info).filterData0), PxFilterData::from_physx(&(*info).filterData1));
+    if !collision_layers::layers_collide(data0, data1) || ragdoll::ragdoll_siblings(data0, data1) {
+        return physxx::sys::PxFilterFlag::eKILL as u16;
+    }
+    if collision_layers::reports_all_contact_phases(data0) || collision_layers::reports_all_contact_phases(data1) {
+        (*(*info).pairFlags).mBits |= physxx::sys::PxPairFlag::eNOTIFY_TOUCH_LOST as u16;
+    }
     (*(*info).pairFlags).mBits |= (physxx::sys::PxPairFlag::eSOLVE_CONTACT
         | physxx::sys::PxPairFlag::eDETECT_DISCRETE_CONTACT
         | physxx::sys::PxPairFlag::eDETECT_CCD_CONTACT
         | physxx::sys::PxPairFlag::eCONTACT_DEFAULT
-        | physxx::sys::PxPairFlag::eNOTIFY_TOUCH_FOUND) as u16;
+        | physxx::sys::PxPairFlag::eNOTIFY_TOUCH_FOUND
+        | physxx::sys::PxPairFlag::eNOTIFY_TOUCH_PERSISTS
+        | physxx::sys::PxPairFlag::eNOTIFY_CONTACT_POINTS) as u16;
     (physxx::sys::PxFilterFlag::eDEFAULT) as u16
 }
 
@@ -222,14 +322,27 @@ pub fn server_systems() -> SystemGroup {
                     controller.release();
                 }
             }),
+            // Worlds that were saved with their physics continue from their snapshot instead of rebuilding their colliders
+            Box::new(FnSystem::new(|world, _| snapshot::restore_stored_snapshot(world))),
             Box::new(collider::server_systems()),
+            Box::new(FnSystem::new(|world, _| {
+                // Joints can only be recreated once the colliders of their entities have been loaded
+                if !world.resource(collider_loads()).is_empty() {
+                    helpers::rebuild_created_joints(world);
+                }
+            })),
+            Box::new(material::server_systems()),
+            Box::new(collision_layers::server_systems()),
+            Box::new(ragdoll::server_systems()),
+            Box::new(triggers::server_systems()),
+            Box::new(vehicle::server_systems()),
             Box::new(visualization::server_systems()),
         ],
     )
 }
 
 pub fn client_systems() -> SystemGroup {
-    SystemGroup::new("physics", vec![Box::new(visualization::client_systems())])
+    SystemGroup::new("physics", vec![Box::new(ragdoll::client_systems()), Box::new(visualization::client_systems())])
 }
 
 /// Starts the physx simulation step concurrently.
@@ -251,7 +364,9 @@ pub fn fetch_simulation_system() -> DynSystem {
         profiling::scope!("fetch_simulation_system");
 
         world.resource(collisions()).lock().clear();
+        world.resource(contacts()).lock().clear();
         world.resource_mut(collider_loads()).clear();
+        world.resource_mut(triggers::trigger_events()).clear();
         let scene = world.resource(main_physics_scene());
         // Ensure the previous simulation has completed
         scene.fetch_results(true);
@@ -262,6 +377,10 @@ pub fn on_forking_systems() -> SystemGroup<ForkingEvent> {
     SystemGroup::new(
         "physics/on_forking_systems",
         vec![Box::new(FnSystem::new(|world, _| {
+            // The world still refers to the physics objects of the instance it was forked from, so their full state is
+            // carried over before they're replaced
+            let snapshot = PhysicsSnapshot::capture(world);
+
             let mut ed = Entity::new();
             create_server_resources(world.resource(asset_cache()), &mut ed);
             world.add_components(world.resource_entity(), ed).unwrap();
@@ -287,6 +406,15 @@ pub fn on_forking_systems() -> SystemGroup<ForkingEvent> {
             for (id, _) in query(()).incl(revolute_joint()).collect_cloned(world, None) {
                 world.remove_component(id, revolute_joint()).unwrap();
             }
+            for (id, _) in query(()).incl(prismatic_joint()).collect_cloned(world, None) {
+                world.remove_component(id, prismatic_joint()).unwrap();
+            }
+            for (id, _) in query(()).incl(spherical_joint()).collect_cloned(world, None) {
+                world.remove_component(id, spherical_joint()).unwrap();
+            }
+            for (id, _) in query(()).incl(distance_joint()).collect_cloned(world, None) {
+                world.remove_component(id, distance_joint()).unwrap();
+            }
             for (id, _) in query(()).incl(articulation_reduce_coordinate()).collect_cloned(world, None) {
                 world.remove_component(id, articulation_reduce_coordinate()).unwrap();
             }
@@ -296,6 +424,9 @@ pub fn on_forking_systems() -> SystemGroup<ForkingEvent> {
             for (id, _) in query(()).incl(articulation_cache()).collect_cloned(world, None) {
                 world.remove_component(id, articulation_cache()).unwrap();
             }
+            for (id, _) in query(()).incl(ragdoll::ragdoll_links()).collect_cloned(world, None) {
+                world.remove_component(id, ragdoll::ragdoll_links()).unwrap();
+            }
             for (id, _) in query(()).incl(character_controller()).collect_cloned(world, None) {
                 world.remove_component(id, character_controller()).unwrap();
             }
@@ -305,6 +436,11 @@ pub fn on_forking_systems() -> SystemGroup<ForkingEvent> {
             for (id, _) in query(()).incl(collider_shapes_convex()).collect_cloned(world, None) {
                 world.remove_component(id, collider_shapes_convex()).unwrap();
             }
+
+            // Anything that can't be restored is rebuilt from its components instead
+            if let Err(err) = snapshot.and_then(|snapshot| snapshot.restore(world)) {
+                tracing::warn!("Failed to carry the physics scene over to the forked instance: {:#}", err);
+            }
         }))],
     )
 }
@@ -0,0 +1,261 @@
+use std::{collections::HashMap, sync::Arc};
+
+use ambient_core::{asset_cache, async_ecs::async_run, runtime};
+use ambient_ecs::{components, query, Debuggable, Description, EntityId, Name, Networked, Store, SystemGroup, World};
+use ambient_std::{
+    asset_cache::{AssetCache, AsyncAssetKeyExt, SyncAssetKey, SyncAssetKeyExt},
+    asset_url::AbsAssetUrl,
+    download_asset::JsonFromUrl,
+};
+use anyhow::Context;
+use parking_lot::Mutex;
+use physxx::{PxCombineMode, PxMaterial, PxReferenceCounted};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    collider::{collider_shapes, collider_shapes_convex},
+    physx::PhysicsKey,
+};
+
+components!("physics", {
+    @[
+        Debuggable, Networked, Store,
+        Name["Static friction"],
+        Description["The friction coefficient of this entity's colliders when they are not sliding. Defaults to 0.5."]
+    ]
+    static_friction: f32,
+    @[
+        Debuggable, Networked, Store,
+        Name["Dynamic friction"],
+        Description["The friction coefficient of this entity's colliders when they are sliding. Defaults to 0.5."]
+    ]
+    dynamic_friction: f32,
+    @[
+        Debuggable, Networked, Store,
+        Name["Restitution"],
+        Description["How bouncy this entity's colliders are, from 0 (no bounce) to 1 (keeps all of its energy). Defaults to 0.6."]
+    ]
+    restitution: f32,
+    @[
+        Debuggable, Networked, Store,
+        Name["Friction combine mode"],
+        Description["How the friction of this entity is combined with the friction of what it touches. Defaults to `Average`."]
+    ]
+    friction_combine_mode: PhysicsCombineMode,
+    @[
+        Debuggable, Networked, Store,
+        Name["Restitution combine mode"],
+        Description["How the restitution of this entity is combined with the restitution of what it touches. Defaults to `Average`."]
+    ]
+    restitution_combine_mode: PhysicsCombineMode,
+    @[
+        Debuggable, Networked, Store,
+        Name["Physics material from URL"],
+        Description["Load the physics material of this entity from a JSON file at this URL.\nA file with several named materials can be used by naming the material in the URL's fragment, i.e. `materials.json#ice`.\nThe `static_friction`, `dynamic_friction`, `restitution` and combine mode components override the loaded material."]
+    ]
+    physics_material_from_url: String,
+    @[
+        Debuggable,
+        Name["Physics material preset"],
+        Description["The physics material loaded from `physics_material_from_url`."]
+    ]
+    physics_material_preset: PhysicsMaterial,
+});
+
+/// How the friction or restitution of the two entities in a contact are combined.
+/// If the two entities use different modes, the one listed last is used.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PhysicsCombineMode {
+    #[default]
+    Average,
+    Min,
+    Multiply,
+    Max,
+}
+impl From<PhysicsCombineMode> for PxCombineMode {
+    fn from(mode: PhysicsCombineMode) -> Self {
+        match mode {
+            PhysicsCombineMode::Average => PxCombineMode::Average,
+            PhysicsCombineMode::Min => PxCombineMode::Min,
+            PhysicsCombineMode::Multiply => PxCombineMode::Multiply,
+            PhysicsCombineMode::Max => PxCombineMode::Max,
+        }
+    }
+}
+
+/// The surface properties of a collider. Properties that are not set use the defaults of the components.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct PhysicsMaterial {
+    pub static_friction: Option<f32>,
+    pub dynamic_friction: Option<f32>,
+    pub restitution: Option<f32>,
+    pub friction_combine_mode: Option<PhysicsCombineMode>,
+    pub restitution_combine_mode: Option<PhysicsCombineMode>,
+}
+impl PhysicsMaterial {
+    /// The material of `id`: its preset, overridden by its material components.
+    pub fn from_entity(world: &World, id: EntityId) -> Self {
+        let preset = world.get_ref(id, physics_material_preset()).cloned().unwrap_or_default();
+        Self {
+            static_friction: world.get(id, static_friction()).ok().or(preset.static_friction),
+            dynamic_friction: world.get(id, dynamic_friction()).ok().or(preset.dynamic_friction),
+            restitution: world.get(id, restitution()).ok().or(preset.restitution),
+            friction_combine_mode: world.get(id, friction_combine_mode()).ok().or(preset.friction_combine_mode),
+            restitution_combine_mode: world.get(id, restitution_combine_mode()).ok().or(preset.restitution_combine_mode),
+        }
+    }
+
+    /// Returns a [PxMaterial] with these properties. Materials with the same properties are shared.
+    pub fn px_material(&self, assets: &AssetCache) -> PxMaterial {
+        let materials = PxMaterialsKey.get(assets);
+        let mut materials = materials.lock();
+        // Materials that are only referenced by the cache are no longer used by any shape
+        materials.retain(|_, material| material.get_reference_count() > 1);
+        materials.entry(format!("{self:?}")).or_insert_with(|| self.create_px_material(assets)).clone()
+    }
+
+    fn create_px_material(&self, assets: &AssetCache) -> PxMaterial {
+        let physics = PhysicsKey.get(assets);
+        let material = PxMaterial::new(
+            physics.physics,
+            self.static_friction.unwrap_or(0.5),
+            self.dynamic_friction.unwrap_or(0.5),
+            self.restitution.unwrap_or(0.6),
+        );
+        material.set_friction_combine_mode(self.friction_combine_mode.unwrap_or_default().into());
+        material.set_restitution_combine_mode(self.restitution_combine_mode.unwrap_or_default().into());
+        material
+    }
+}
+
+/// The [PxMaterial]s created by [PhysicsMaterial::px_material], by the [PhysicsMaterial] they were created from.
+#[derive(Debug, Clone)]
+struct PxMaterialsKey;
+impl SyncAssetKey<Arc<Mutex<HashMap<String, PxMaterial>>>> for PxMaterialsKey {
+    fn load(&self, _assets: AssetCache) -> Arc<Mutex<HashMap<String, PxMaterial>>> {
+        Default::default()
+    }
+}
+
+/// Loads a physics material file. If `url` has a fragment, it names the material to use in a file of named materials.
+async fn load_physics_material(assets: &AssetCache, url: &str) -> anyhow::Result<PhysicsMaterial> {
+    let mut url = AbsAssetUrl::parse(url)?;
+    let name = url.0.fragment().map(|name| name.to_string());
+    url.0.set_fragment(None);
+
+    let file = JsonFromUrl::<serde_json::Value>::new(url.clone(), true).get(assets).await?;
+    let material = match name {
+        Some(name) => file.get(&name).with_context(|| format!("No physics material named {name} in {url}"))?.clone(),
+        None => (*file).clone(),
+    };
+    Ok(serde_json::from_value(material)?)
+}
+
+pub fn server_systems() -> SystemGroup {
+    SystemGroup::new(
+        "physics/material/server",
+        vec![
+            query(physics_material_from_url().changed()).to_system(|q, world, qs, _| {
+                let assets = world.resource(asset_cache()).clone();
+                let runtime = world.resource(runtime()).clone();
+                let async_run = world.resource(async_run()).clone();
+                for (id, url) in q.collect_cloned(world, qs) {
+                    let assets = assets.clone();
+                    let async_run = async_run.clone();
+                    runtime.spawn(async move {
+                        match load_physics_material(&assets, &url).await {
+                            Ok(material) => async_run.run(move |world| {
+                                // The url may have changed while this one was loading
+                                if world.get_ref(id, physics_material_from_url()).ok() == Some(&url) {
+                                    world.add_component(id, physics_material_preset(), material).unwrap();
+                                }
+                            }),
+                            Err(err) => log::warn!("Failed to load physics material from {url}: {err:?}"),
+                        }
+                    });
+                }
+            }),
+            query((collider_shapes().changed(),))
+                .optional_changed(static_friction())
+                .optional_changed(dynamic_friction())
+                .optional_changed(restitution())
+                .optional_changed(friction_combine_mode())
+                .optional_changed(restitution_combine_mode())
+                .optional_changed(physics_material_preset())
+                .to_system(|q, world, qs, _| {
+                    let assets = world.resource(asset_cache()).clone();
+                    for (id, (shapes,)) in q.collect_cloned(world, qs) {
+                        let material = PhysicsMaterial::from_entity(world, id).px_material(&assets);
+                        let convex = world.get_ref(id, collider_shapes_convex()).cloned().unwrap_or_default();
+                        for shape in shapes.iter().chain(convex.iter()) {
+                            shape.set_materials(&[&material]);
+                        }
+                    }
+                }),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use ambient_ecs::Entity;
+    use ambient_std::asset_cache::SyncAssetKeyExt;
+
+    use super::*;
+    use crate::physx::test_physics;
+
+    fn assets(runtime: &tokio::runtime::Runtime) -> AssetCache {
+        let assets = AssetCache::new(runtime.handle().clone());
+        PhysicsKey.insert(&assets, test_physics());
+        assets
+    }
+
+    #[test]
+    fn components_override_the_preset() {
+        crate::init_all_components();
+        let mut world = World::new("material");
+        let preset = PhysicsMaterial { static_friction: Some(0.1), restitution: Some(0.2), ..Default::default() };
+        let id = Entity::new().with(physics_material_preset(), preset).with(restitution(), 0.9).spawn(&mut world);
+
+        let material = PhysicsMaterial::from_entity(&world, id);
+        assert_eq!(material, PhysicsMaterial { static_friction: Some(0.1), restitution: Some(0.9), ..Default::default() });
+    }
+
+    #[test]
+    fn px_materials_are_shared_and_released() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let assets = assets(&runtime);
+        let ice = PhysicsMaterial { static_friction: Some(0.05), dynamic_friction: Some(0.02), ..Default::default() };
+
+        let a = ice.px_material(&assets);
+        let b = ice.px_material(&assets);
+        assert_eq!(a.get_static_friction(), 0.05);
+        // Referenced by the cache, `a` and `b`
+        assert_eq!(a.get_reference_count(), 3);
+        assert_eq!(PxMaterialsKey.get(&assets).lock().len(), 1);
+
+        drop(a);
+        drop(b);
+        let rubber = PhysicsMaterial { restitution: Some(0.9), ..Default::default() }.px_material(&assets);
+        assert_eq!(rubber.get_restitution(), 0.9);
+        let materials = PxMaterialsKey.get(&assets);
+        let materials = materials.lock();
+        assert_eq!(materials.len(), 1);
+        assert!(materials.contains_key(&format!("{:?}", PhysicsMaterial { restitution: Some(0.9), ..Default::default() })));
+    }
+
+    #[test]
+    fn named_materials_are_loaded_from_the_fragment() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let assets = AssetCache::new(runtime.handle().clone());
+        let path = std::env::temp_dir().join(format!("ambient_physics_materials_{}.json", std::process::id()));
+        std::fs::write(&path, r#"{ "ice": { "static_friction": 0.05 }, "rubber": { "restitution": 0.9 } }"#).unwrap();
+        let url = AbsAssetUrl::from_file_path(&path);
+
+        let rubber = runtime.block_on(load_physics_material(&assets, &format!("{url}#rubber"))).unwrap();
+        assert_eq!(rubber, PhysicsMaterial { restitution: Some(0.9), ..Default::default() });
+        assert!(runtime.block_on(load_physics_material(&assets, &format!("{url}#wood"))).is_err());
+        std::fs::remove_file(&path).ok();
+    }
+}
@@ -14,7 +14,7 @@ use physxx::{articulation_reduced_coordinate::*, *};
 
 use crate::{
     collider::kinematic,
-    helpers::{get_shapes, scale_shape},
+    helpers::{get_shapes, scale_shape, JointDescription},
 };
 
 components!("physics", {
@@ -27,6 +27,15 @@ components!("physics", {
     physics_shape: PxShape,
     fixed_joint: PxFixedJointRef,
     revolute_joint: PxRevoluteJointRef,
+    prismatic_joint: PxPrismaticJointRef,
+    spherical_joint: PxSphericalJointRef,
+    distance_joint: PxDistanceJointRef,
+    @[
+        Debuggable, Store,
+        Name["Created joint"],
+        Description["The joint that was created on this entity with `create_joint`.\nUsed to recreate the joint when the physics objects of the world are rebuilt."]
+    ]
+    created_joint: JointDescription,
     articulation_reduce_coordinate: PxArticulationRef,
     articulation_link: PxArticulationLinkRef,
     articulation_cache: Option<PxArticulationCacheRef>,
@@ -113,6 +122,13 @@ impl Physics {
     }
 }
 
+/// PhysX can only be initialized once per process, so tests share one instance.
+#[cfg(test)]
+pub(crate) fn test_physics() -> Physics {
+    static PHYSICS: once_cell::sync::Lazy<Physics> = once_cell::sync::Lazy::new(Physics::new);
+    PHYSICS.clone()
+}
+
 fn vec3_changed(old: Vec3, new: Vec3) -> bool {
     (new - old).length() > 0.001
 }
@@ -0,0 +1,398 @@
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicU32, Ordering},
+};
+
+use ambient_core::{
+    asset_cache, dtime,
+    transform::{rotation, scale, translation},
+};
+use ambient_ecs::{components, query, Debuggable, Description, EntityId, Name, Networked, Store, SystemGroup, World};
+use ambient_model::{animation_pose_override, AnimationPoseOverride};
+use glam::{EulerRot, Mat4, Quat, Vec3};
+use physxx::{
+    articulation_reduced_coordinate::{PxArticulationJointRef, PxArticulationRef},
+    PxArticulationAxis, PxArticulationBase, PxArticulationJointBase, PxArticulationJointType, PxArticulationLinkRef, PxArticulationMotion,
+    PxCapsuleGeometry, PxFilterData, PxRigidActor, PxRigidBody, PxShape, PxShapeFlag, PxTransform, PxUserData,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    collider::density,
+    collision_layers::{collision_filter_data, set_filter_data},
+    main_physics_scene,
+    material::PhysicsMaterial,
+    physx::{articulation_reduce_coordinate, character_controller, physics},
+    PxShapeUserData,
+};
+
+components!("physics", {
+    @[
+        Debuggable, Networked, Store,
+        Name["Ragdoll"],
+        Description["The ragdoll of a skinned model, with a capsule per bone. Usually generated by the `generate_ragdoll` option of the models pipeline."]
+    ]
+    ragdoll: RagdollDef,
+    @[
+        Debuggable, Networked, Store,
+        Name["Ragdoll active"],
+        Description["If true, the skeleton of this entity's model is driven by its `ragdoll` instead of its animation."]
+    ]
+    ragdoll_active: bool,
+    @[
+        Debuggable, Networked, Store,
+        Name["Ragdoll blend time"],
+        Description["The time in seconds it takes to blend between the animation and the ragdoll. Defaults to 0.25."]
+    ]
+    ragdoll_blend_time: f32,
+    @[
+        Debuggable, Networked,
+        Name["Ragdoll bone poses"],
+        Description["The transforms of the bones of the ragdoll relative to this entity, in the order of the bones of the `ragdoll`."]
+    ]
+    ragdoll_bone_poses: Vec<Mat4>,
+    @[
+        Debuggable,
+        Name["Ragdoll blend"],
+        Description["How much the ragdoll drives the skeleton of this entity's model right now, from 0 (not at all) to 1 (completely)."]
+    ]
+    ragdoll_blend: f32,
+    ragdoll_links: Vec<RagdollLink>,
+});
+
+const DEFAULT_BLEND_TIME: f32 = 0.25;
+/// PhysX articulations can't have more links than this
+pub const MAX_RAGDOLL_BONES: usize = 64;
+
+/// A bone of a ragdoll. The bone's link is simulated without scale; its capsule and joint are relative to that link.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RagdollBone {
+    pub bind_id: String,
+    /// The index of the parent bone. Parents always come before their children.
+    pub parent: Option<usize>,
+    /// The transform of the bone relative to the model in its bind pose
+    pub bind_pose: Mat4,
+    pub capsule_center: Vec3,
+    /// Rotates the X axis onto the capsule's axis, which is also the twist axis of the joint to the parent bone
+    pub capsule_rotation: Quat,
+    pub radius: f32,
+    pub half_height: f32,
+    /// The maximum swing of the joint to the parent bone, in radians
+    pub swing_limit: f32,
+    /// The maximum twist of the joint to the parent bone, in radians
+    pub twist_limit: f32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RagdollDef {
+    /// The bones of the ragdoll; the first one is the root
+    pub bones: Vec<RagdollBone>,
+    /// The transform of the parent of the root bone, relative to the model
+    pub root_parent_pose: Mat4,
+}
+impl RagdollDef {
+    /// Converts the `bone_poses`, relative to the model, into the `translation` and `rotation` of each bone relative to its parent.
+    pub fn local_poses(&self, bone_poses: &[Mat4]) -> HashMap<String, (Vec3, Quat)> {
+        self.bones
+            .iter()
+            .zip(bone_poses)
+            .map(|(bone, pose)| {
+                let parent = bone.parent.and_then(|parent| bone_poses.get(parent)).copied().unwrap_or(self.root_parent_pose);
+                let (_, rot, pos) = (parent.inverse() * *pose).to_scale_rotation_translation();
+                (bone.bind_id.clone(), (pos, rot))
+            })
+            .collect()
+    }
+
+    /// Checks that the ragdoll can be simulated: that it fits in an articulation, that every bone but the root has a parent
+    /// before it, and that every capsule has a size.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.bones.len() <= MAX_RAGDOLL_BONES,
+            "The ragdoll has {} bones, but can have at most {MAX_RAGDOLL_BONES}",
+            self.bones.len()
+        );
+        for (i, bone) in self.bones.iter().enumerate() {
+            match bone.parent {
+                None => anyhow::ensure!(i == 0, "Bone {i} ({}) has no parent, but only the root bone can", bone.bind_id),
+                Some(parent) => {
+                    anyhow::ensure!(parent < i, "The parent of bone {i} ({}) is bone {parent}, which doesn't come before it", bone.bind_id)
+                }
+            }
+            anyhow::ensure!(
+                bone.radius.is_finite() && bone.radius > 0. && bone.half_height.is_finite() && bone.half_height > 0.,
+                "The capsule of bone {i} ({}) has a radius of {} and a half height of {}, which must be positive",
+                bone.bind_id,
+                bone.radius,
+                bone.half_height
+            );
+        }
+        Ok(())
+    }
+}
+
+/// The ragdolls created so far, used to tell the bones of different ragdolls apart in their filter data
+static RAGDOLL_GROUPS: AtomicU32 = AtomicU32::new(0);
+
+/// The `word3` of the filter data of a bone with the `parent` bone in the ragdoll `group`. The upper half holds the group, and
+/// the lower half the index of the parent plus one, so that sibling bones share the same value.
+fn sibling_filter_word(group: u32, parent: Option<usize>) -> u32 {
+    (group << 16) | parent.map_or(0, |parent| parent as u32 + 1)
+}
+
+/// Whether the shapes with the filter data `a` and `b` are sibling bones of the same ragdoll. PhysX only keeps the links of a
+/// joint from colliding, so the capsules of siblings, such as thighs, would push each other apart where they meet.
+pub(crate) fn ragdoll_siblings(a: PxFilterData, b: PxFilterData) -> bool {
+    a.word3 & 0xffff != 0 && a.word3 == b.word3
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RagdollLink {
+    pub link: PxArticulationLinkRef,
+    /// The world space scale of the bone, which the link can't hold
+    pub scale: Vec3,
+}
+
+fn entity_pose(world: &World, id: EntityId) -> Mat4 {
+    Mat4::from_scale_rotation_translation(
+        world.get(id, scale()).unwrap_or(Vec3::ONE),
+        world.get(id, rotation()).unwrap_or_default(),
+        world.get(id, translation()).unwrap_or_default(),
+    )
+}
+
+/// Creates the articulation of the ragdoll of `id` in its bind pose, at the current transform of the entity. `def` must be
+/// valid; see [RagdollDef::validate].
+fn create_ragdoll(world: &World, id: EntityId, def: &RagdollDef) -> (PxArticulationRef, Vec<RagdollLink>) {
+    let physics = world.resource(physics()).clone();
+    let entity_pose = entity_pose(world, id);
+    // Capsules can't be scaled non-uniformly
+    let entity_scale = entity_pose.to_scale_rotation_translation().0.max_element();
+    let material = PhysicsMaterial::from_entity(world, id).px_material(world.resource(asset_cache()));
+    let density = world.get(id, density()).unwrap_or(1.);
+    let filter_data = collision_filter_data(world, id);
+    // Zero is left for shapes that aren't ragdoll bones
+    let group = RAGDOLL_GROUPS.fetch_add(1, Ordering::Relaxed) % 0xffff + 1;
+
+    let articulation = PxArticulationRef::new(&physics.physics);
+    let mut links: Vec<RagdollLink> = Vec::with_capacity(def.bones.len());
+    for bone in &def.bones {
+        let (scale, rot, pos) = (entity_pose * bone.bind_pose).to_scale_rotation_translation();
+        let parent = bone.parent.map(|parent| links[parent].link);
+        let link = PxArticulationLinkRef::new(&articulation, parent.as_ref(), &PxTransform::new(pos, rot));
+
+        let geometry = PxCapsuleGeometry::new(bone.radius * entity_scale, bone.half_height * entity_scale);
+        let shape = PxShape::new(physics.physics, &geometry, &[&material], Some(true), None);
+        let center = bone.capsule_center * entity_scale;
+        shape.set_local_pose(&PxTransform::new(center, bone.capsule_rotation));
+        shape.set_user_data(PxShapeUserData {
+            entity: id,
+            density,
+            base_pose: Mat4::from_rotation_translation(bone.capsule_rotation, center),
+        });
+        set_filter_data([&shape], PxFilterData { word3: sibling_filter_word(group, bone.parent), ..filter_data });
+        link.attach_shape(&shape);
+        link.update_mass_and_inertia_uniform(density, None, None);
+
+        if let (Some(mut joint), Some(parent)) = (PxArticulationJointRef::from_inbound_link(&link), parent) {
+            let parent_pose = parent.get_global_pose();
+            let parent_rot_inv = parent_pose.rotation().inverse();
+            joint.set_joint_type(PxArticulationJointType::Spherical);
+            joint.set_parent_pose(&PxTransform::new(
+                parent_rot_inv * (pos - parent_pose.translation()),
+                parent_rot_inv * rot * bone.capsule_rotation,
+            ));
+            joint.set_child_pose(&PxTransform::from_rotation(bone.capsule_rotation));
+            joint.set_motion(PxArticulationAxis::Twist, PxArticulationMotion::Limited);
+            joint.set_limit(PxArticulationAxis::Twist, -bone.twist_limit, bone.twist_limit);
+            for axis in [PxArticulationAxis::Swing1, PxArticulationAxis::Swing2] {
+                joint.set_motion(axis, PxArticulationMotion::Limited);
+                joint.set_limit(axis, -bone.swing_limit, bone.swing_limit);
+            }
+        }
+        links.push(RagdollLink { link, scale });
+    }
+    world.resource(main_physics_scene()).add_articulation(&articulation);
+    articulation.wake_up();
+    (articulation, links)
+}
+
+fn link_pose(link: &RagdollLink) -> Mat4 {
+    let pose = link.link.get_global_pose();
+    Mat4::from_scale_rotation_translation(link.scale, pose.rotation(), pose.translation())
+}
+
+/// Moves `id` to where the root bone of its ragdoll ended up, so that it blends back to its animation there. Only the heading
+/// of the ragdoll is kept, so that the entity stays upright.
+fn move_to_ragdoll(world: &mut World, id: EntityId, def: &RagdollDef, links: &[RagdollLink]) {
+    let Some((root, root_link)) = def.bones.first().zip(links.first()) else { return };
+    let link_poses = links.iter().map(link_pose).collect::<Vec<_>>();
+    let scale = entity_pose(world, id).to_scale_rotation_translation().0;
+    let (_, ragdoll_rot, pos) = (link_pose(root_link) * root.bind_pose.inverse()).to_scale_rotation_translation();
+    let (heading, _, _) = ragdoll_rot.to_euler(EulerRot::ZYX);
+    let rot = Quat::from_rotation_z(heading);
+    world.add_component(id, translation(), pos).unwrap();
+    world.add_component(id, rotation(), rot).unwrap();
+
+    // The bones are blended back from where the ragdoll left them
+    let entity_pose_inv = Mat4::from_scale_rotation_translation(scale, rot, pos).inverse();
+    world.add_component(id, ragdoll_bone_poses(), link_poses.iter().map(|pose| entity_pose_inv * *pose).collect()).unwrap();
+}
+
+fn release_ragdoll(world: &World, mut articulation: PxArticulationRef) {
+    world.resource(main_physics_scene()).remove_articulation(&articulation, true);
+    // Releases the links and their shapes as well
+    articulation.release();
+}
+
+/// The character controller would push the ragdoll around, so it stops colliding while the ragdoll is active
+fn set_character_collisions(world: &World, id: EntityId, enabled: bool) {
+    if let Ok(controller) = world.get(id, character_controller()) {
+        for shape in controller.get_actor().get_shapes() {
+            shape.set_flag(PxShapeFlag::SIMULATION_SHAPE, enabled);
+        }
+    }
+}
+
+pub fn server_systems() -> SystemGroup {
+    SystemGroup::new(
+        "physics/ragdoll/server",
+        vec![
+            query(ragdoll().changed()).to_system(|q, world, qs, _| {
+                for (id, def) in q.iter(world, qs) {
+                    if let Err(err) = def.validate() {
+                        log::warn!("The ragdoll of {id} is invalid, and won't be simulated: {err}");
+                    }
+                }
+            }),
+            // Not only on changes, as the links are removed without deactivating the ragdoll when the physics scene is forked
+            query(ragdoll_active()).to_system(|q, world, qs, _| {
+                for (id, active) in q.collect_cloned(world, qs) {
+                    let created = world.has_component(id, ragdoll_links());
+                    if active && !created {
+                        let Ok(def) = world.get_cloned(id, ragdoll()) else { continue };
+                        // Invalid ragdolls are reported when they're set
+                        if def.bones.is_empty() || def.validate().is_err() {
+                            continue;
+                        }
+                        let (articulation, links) = create_ragdoll(world, id, &def);
+                        set_character_collisions(world, id, false);
+                        world.add_component(id, articulation_reduce_coordinate(), articulation).unwrap();
+                        world.add_component(id, ragdoll_links(), links).unwrap();
+                    } else if !active && created {
+                        if let (Ok(def), Ok(links)) = (world.get_cloned(id, ragdoll()), world.get_cloned(id, ragdoll_links())) {
+                            move_to_ragdoll(world, id, &def, &links);
+                        }
+                        if let Ok(articulation) = world.get(id, articulation_reduce_coordinate()) {
+                            release_ragdoll(world, articulation);
+                            world.remove_component(id, articulation_reduce_coordinate()).unwrap();
+                        }
+                        set_character_collisions(world, id, true);
+                        world.remove_component(id, ragdoll_links()).unwrap();
+                    }
+                }
+            }),
+            query(ragdoll_links()).to_system(|q, world, qs, _| {
+                for (id, links) in q.collect_cloned(world, qs) {
+                    let entity_pose_inv = entity_pose(world, id).inverse();
+                    let poses = links.iter().map(|link| entity_pose_inv * link_pose(link)).collect();
+                    world.add_component(id, ragdoll_bone_poses(), poses).unwrap();
+                }
+            }),
+            query(articulation_reduce_coordinate()).incl(ragdoll_links()).despawned().to_system(|q, world, qs, _| {
+                for (_, articulation) in q.iter(world, qs) {
+                    release_ragdoll(world, *articulation);
+                }
+            }),
+        ],
+    )
+}
+
+pub fn client_systems() -> SystemGroup {
+    SystemGroup::new(
+        "physics/ragdoll/client",
+        vec![query((ragdoll(), ragdoll_bone_poses())).to_system(|q, world, qs, _| {
+            let dtime = *world.resource(dtime());
+            for (id, (def, poses)) in q.collect_cloned(world, qs) {
+                let active = world.get(id, ragdoll_active()).unwrap_or(false);
+                let blend_time = world.get(id, ragdoll_blend_time()).unwrap_or(DEFAULT_BLEND_TIME);
+                let step = if blend_time > 0. { dtime / blend_time } else { 1. };
+                let previous = world.get(id, ragdoll_blend()).unwrap_or(0.);
+                let blend = (previous + if active { step } else { -step }).clamp(0., 1.);
+                if blend == 0. && previous == 0. {
+                    continue;
+                }
+                world.add_component(id, ragdoll_blend(), blend).unwrap();
+                world
+                    .add_component(id, animation_pose_override(), AnimationPoseOverride { weight: blend, pose: def.local_poses(&poses) })
+                    .unwrap();
+            }
+        })],
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use glam::vec3;
+
+    use super::*;
+
+    fn bone(bind_id: &str, parent: Option<usize>) -> RagdollBone {
+        RagdollBone { bind_id: bind_id.to_string(), parent, ..Default::default() }
+    }
+
+    #[test]
+    fn local_poses_are_relative_to_the_parent_bone() {
+        let root_parent_pose = Mat4::from_translation(vec3(0., 0., 1.));
+        let def = RagdollDef { bones: vec![bone("hips", None), bone("spine", Some(0))], root_parent_pose };
+        let hips = Mat4::from_rotation_translation(Quat::from_rotation_z(1.), vec3(1., 0., 1.));
+        let spine = hips * Mat4::from_rotation_translation(Quat::from_rotation_x(0.5), vec3(0., 0., 0.5));
+
+        let poses = def.local_poses(&[hips, spine]);
+        let (pos, rot) = poses["hips"];
+        assert!(pos.abs_diff_eq(vec3(1., 0., 0.), 1e-5));
+        assert!(rot.abs_diff_eq(Quat::from_rotation_z(1.), 1e-5));
+        let (pos, rot) = poses["spine"];
+        assert!(pos.abs_diff_eq(vec3(0., 0., 0.5), 1e-5));
+        assert!(rot.abs_diff_eq(Quat::from_rotation_x(0.5), 1e-5));
+    }
+
+    #[test]
+    fn local_poses_ignore_missing_bones() {
+        let def = RagdollDef { bones: vec![bone("hips", None), bone("spine", Some(0))], root_parent_pose: Mat4::IDENTITY };
+        let poses = def.local_poses(&[Mat4::IDENTITY]);
+        assert_eq!(poses.len(), 1);
+        assert!(poses.contains_key("hips"));
+    }
+
+    #[test]
+    fn invalid_ragdolls_are_rejected() {
+        let capsule = |bind_id, parent| RagdollBone { radius: 0.1, half_height: 0.2, ..bone(bind_id, parent) };
+        let def = |bones| RagdollDef { bones, root_parent_pose: Mat4::IDENTITY };
+        assert!(def(vec![capsule("hips", None), capsule("spine", Some(0))]).validate().is_ok());
+
+        // Parents must come before their children
+        assert!(def(vec![capsule("hips", Some(1)), capsule("spine", Some(0))]).validate().is_err());
+        assert!(def(vec![capsule("hips", None), capsule("spine", Some(1))]).validate().is_err());
+        assert!(def(vec![capsule("hips", None), capsule("spine", Some(5))]).validate().is_err());
+        // There can only be one root
+        assert!(def(vec![capsule("hips", None), capsule("spine", None)]).validate().is_err());
+        // Capsules must have a size
+        assert!(def(vec![RagdollBone { radius: 0., ..capsule("hips", None) }]).validate().is_err());
+        assert!(def(vec![RagdollBone { half_height: f32::NAN, ..capsule("hips", None) }]).validate().is_err());
+        // And articulations have a limited number of links
+        let bones = (0..=MAX_RAGDOLL_BONES).map(|i| capsule("bone", if i == 0 { None } else { Some(i - 1) })).collect();
+        assert!(def(bones).validate().is_err());
+    }
+
+    #[test]
+    fn only_siblings_of_the_same_ragdoll_are_filtered() {
+        let data = |group, parent| PxFilterData::new(1, u32::MAX, 0, sibling_filter_word(group, parent));
+        assert!(ragdoll_siblings(data(1, Some(0)), data(1, Some(0))));
+        assert!(!ragdoll_siblings(data(1, Some(0)), data(1, Some(1))));
+        assert!(!ragdoll_siblings(data(1, Some(0)), data(2, Some(0))));
+        // Root bones and other shapes have no siblings
+        assert!(!ragdoll_siblings(data(1, None), data(1, None)));
+        assert!(!ragdoll_siblings(PxFilterData::default(), PxFilterData::default()));
+    }
+}
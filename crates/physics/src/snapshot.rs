@@ -0,0 +1,503 @@
+use std::collections::{HashMap, HashSet};
+
+use ambient_core::asset_cache;
+use ambient_ecs::{components, query, Description, Entity, EntityId, Name, Store, World};
+use anyhow::Context;
+use glam::{Mat4, Quat, Vec3};
+use itertools::Itertools;
+use physxx::{
+    AsPxActor, AsPxBase, AsPxJoint, AsPxRigidActor, PxAny, PxBaseRef, PxCollectionRef, PxConvexMesh, PxDefaultMemoryInputData,
+    PxDefaultMemoryOutputStream, PxHeightField, PxJoint, PxJointRef, PxRigidActor, PxSerialObjectId, PxShape, PxTransform, PxTriangleMesh,
+    PxUserData,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    collider::{collider_shapes, collider_shapes_convex, collider_type, ColliderType},
+    helpers::get_entity_joint,
+    material::PhysicsMaterial,
+    physx::{
+        distance_joint, fixed_joint, physics, physics_shape, prismatic_joint, revolute_joint, rigid_actor, rigid_dynamic, rigid_static,
+        spherical_joint,
+    },
+    PxActorUserData, PxShapeUserData,
+};
+
+components!("physics", {
+    @[
+        Store,
+        Name["Physics snapshot"],
+        Description["The physics of this world, stored before it was saved. Restored and removed once the world is loaded again."]
+    ]
+    physics_snapshot: PhysicsSnapshot,
+});
+
+/// What an object in a [PhysicsSnapshot] belongs to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SnapshotObject {
+    /// A rigid actor, and the kinematic target it hadn't reached yet
+    Actor {
+        entity: EntityId,
+        kinematic_target: Option<(Vec3, Quat)>,
+    },
+    /// A shape, and whether it's in the `collider_shapes` (`concave`) and `collider_shapes_convex` (`convex`) of its entity
+    Shape {
+        entity: EntityId,
+        density: f32,
+        base_pose: Mat4,
+        concave: bool,
+        convex: bool,
+    },
+    Joint {
+        entity: EntityId,
+    },
+}
+
+/// A mesh that the shapes of a [PhysicsSnapshot] use
+#[derive(Debug, Clone)]
+enum SnapshotMesh {
+    Convex(PxConvexMesh),
+    Triangle(PxTriangleMesh),
+    HeightField(PxHeightField),
+}
+impl AsPxBase for SnapshotMesh {
+    fn as_base(&self) -> PxBaseRef {
+        match self {
+            SnapshotMesh::Convex(mesh) => mesh.as_base(),
+            SnapshotMesh::Triangle(mesh) => mesh.as_base(),
+            SnapshotMesh::HeightField(mesh) => mesh.as_base(),
+        }
+    }
+}
+
+/// The full state of the rigid actors and joints of a world, including the velocities, sleep state, kinematic targets and
+/// joint settings that the ECS doesn't hold.
+///
+/// Used to carry the physics of a forked instance over without any popping, and to save a world with its physics through
+/// [store_snapshot]. Materials come from the material cache and meshes are shared with the world the snapshot was taken
+/// from, so neither is duplicated. Character controllers and ragdolls aren't included; they are rebuilt from their components.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PhysicsSnapshot {
+    /// The actors, their shapes and the joints between them, serialized by PhysX as XML
+    pub data: Vec<u8>,
+    /// The entity of each actor, shape and joint, by its serial id in `data`
+    pub objects: Vec<(PxSerialObjectId, SnapshotObject)>,
+    /// Objects in `data` that were only included because the shapes use them, such as materials outside the cache
+    pub referenced: Vec<PxSerialObjectId>,
+    /// The materials of the shapes, by their serial id. They're taken from the material cache rather than `data`
+    pub materials: Vec<(PxSerialObjectId, PhysicsMaterial)>,
+    /// The serial ids of the meshes of the shapes, which are serialized to `mesh_data` rather than `data`
+    pub meshes: Vec<PxSerialObjectId>,
+    pub mesh_data: Vec<u8>,
+    /// The meshes themselves, so that a snapshot that was never saved restores its shapes without copying them
+    #[serde(skip)]
+    live_meshes: Vec<(PxSerialObjectId, SnapshotMesh)>,
+}
+impl PhysicsSnapshot {
+    /// Serializes the rigid actors of `world`, their shapes, and the joints between them
+    pub fn capture(world: &World) -> anyhow::Result<Self> {
+        let physics = world.resource(physics());
+        let assets = world.resource(asset_cache());
+        let mut collection = PxCollectionRef::new();
+        let mut externals = PxCollectionRef::new();
+        let mut snapshot = Self::default();
+        // Serial ids start at 1, as 0 means the object has no id
+        let mut ids = 1..;
+        let mut add = |collection: &mut PxCollectionRef, object: PxBaseRef| {
+            let id = ids.next().unwrap();
+            collection.add_with_serial_id(object, id);
+            id
+        };
+
+        let mut px_materials = Vec::new();
+        let mut added = HashSet::new();
+        let mut actors = Vec::new();
+        for (id, actor) in query(rigid_actor()).iter(world, None) {
+            if !actor.as_actor().get_user_data::<PxActorUserData>().map_or(false, |ud| ud.serialize) {
+                continue;
+            }
+            let kinematic_target = actor.to_rigid_dynamic().and_then(|body| body.get_kinematic_target());
+            let kinematic_target = kinematic_target.map(|target| (target.translation(), target.rotation()));
+            snapshot.objects.push((add(&mut collection, actor.as_base()), SnapshotObject::Actor { entity: id, kinematic_target }));
+
+            // The shapes of the other collider types aren't attached, but are needed if the entity changes its type
+            let unattached = world.get_ref(id, collider_shapes()).into_iter().chain(world.get_ref(id, collider_shapes_convex())).flatten();
+            for shape in actor.get_shapes().into_iter().chain(unattached.cloned()) {
+                let Some(ud) = shape.get_user_data::<PxShapeUserData>() else { continue };
+                if !added.insert(shape.clone()) {
+                    continue;
+                }
+                let concave = world.get_ref(ud.entity, collider_shapes()).map_or(false, |shapes| shapes.contains(&shape));
+                let convex = world.get_ref(ud.entity, collider_shapes_convex()).map_or(false, |shapes| shapes.contains(&shape));
+
+                let material = PhysicsMaterial::from_entity(world, ud.entity);
+                if !snapshot.materials.iter().any(|(_, other)| other == &material) {
+                    let px_material = material.px_material(assets);
+                    snapshot.materials.push((add(&mut externals, px_material.as_base()), material));
+                    px_materials.push(px_material);
+                }
+                let geometry = shape.get_geometry();
+                let mesh = geometry
+                    .as_convex_mesh()
+                    .map(|geometry| SnapshotMesh::Convex(geometry.mesh()))
+                    .or_else(|| geometry.as_triangle_mesh().map(|geometry| SnapshotMesh::Triangle(geometry.mesh())))
+                    .or_else(|| geometry.as_height_field().map(|geometry| SnapshotMesh::HeightField(geometry.height_field())));
+                if let Some(mesh) = mesh {
+                    if !snapshot.live_meshes.iter().any(|(_, other)| other.as_base() == mesh.as_base()) {
+                        snapshot.live_meshes.push((add(&mut externals, mesh.as_base()), mesh));
+                    }
+                }
+
+                let object = SnapshotObject::Shape { entity: ud.entity, density: ud.density, base_pose: ud.base_pose, concave, convex };
+                snapshot.objects.push((add(&mut collection, shape.as_base()), object));
+            }
+            actors.push(*actor);
+        }
+        for (id, joint) in joints(world) {
+            // Joints to actors that aren't in the snapshot would pull those actors in as well
+            let (actor0, actor1) = joint.get_actors();
+            if [actor0, actor1].into_iter().flatten().all(|actor| actors.contains(&actor)) {
+                snapshot.objects.push((add(&mut collection, joint.as_base()), SnapshotObject::Joint { entity: id }));
+            }
+        }
+
+        // Adds anything else the shapes use, which can't be shared
+        physics.serialization_registry.complete_full(collection, Some(externals), false);
+        let base = ids.next().unwrap();
+        collection.create_serial_object_ids(base);
+        snapshot.referenced = (base..base + collection.get_nb_objects() - snapshot.objects.len()).collect();
+        let stream = PxDefaultMemoryOutputStream::new();
+        let serialized = physics.serialization_registry.serialize_collection_to_xml(&stream, collection, Some(externals));
+        collection.release();
+        externals.release();
+        anyhow::ensure!(serialized, "Failed to serialize the physics scene");
+        snapshot.data = stream.get_data();
+
+        let mut meshes = PxCollectionRef::new();
+        for (id, mesh) in &snapshot.live_meshes {
+            meshes.add_with_serial_id(mesh.as_base(), *id);
+        }
+        let stream = PxDefaultMemoryOutputStream::new();
+        let serialized = physics.serialization_registry.serialize_collection_to_xml(&stream, meshes, None);
+        meshes.release();
+        anyhow::ensure!(serialized, "Failed to serialize the meshes of the physics scene");
+        snapshot.meshes = snapshot.live_meshes.iter().map(|(id, _)| *id).collect();
+        snapshot.mesh_data = stream.get_data();
+        Ok(snapshot)
+    }
+
+    /// Recreates the actors and joints of this snapshot in the physics scenes of `world`, and attaches them to their entities.
+    /// Objects whose entity no longer exists, or already has an actor or joint, are released.
+    pub fn restore(&self, world: &mut World) -> anyhow::Result<()> {
+        let physics = world.resource(physics()).clone();
+        let assets = world.resource(asset_cache()).clone();
+        let mut externals = PxCollectionRef::new();
+        // Kept until the shapes hold their own references to the materials
+        let _px_materials = (self.materials.iter())
+            .map(|(id, material)| {
+                let px_material = material.px_material(&assets);
+                externals.add_with_serial_id(px_material.as_base(), *id);
+                px_material
+            })
+            .collect_vec();
+        // A snapshot that was saved and loaded again no longer has the meshes it was captured from
+        let loaded_meshes = self.live_meshes.is_empty() && !self.meshes.is_empty();
+        let meshes = if loaded_meshes {
+            let input = PxDefaultMemoryInputData::new(self.mesh_data.clone());
+            let mut meshes = physics
+                .serialization_registry
+                .create_collection_from_xml(&input, &physics.cooking, None)
+                .context("Failed to deserialize the meshes of the physics scene")?;
+            let found = self.meshes.iter().filter_map(|id| Some((*id, meshes.find_base(*id)?))).collect_vec();
+            meshes.release();
+            found
+        } else {
+            self.live_meshes.iter().map(|(id, mesh)| (*id, mesh.as_base())).collect_vec()
+        };
+        for (id, mesh) in &meshes {
+            externals.add_with_serial_id(*mesh, *id);
+        }
+
+        let input = PxDefaultMemoryInputData::new(self.data.clone());
+        let collection = physics.serialization_registry.create_collection_from_xml(&input, &physics.cooking, Some(externals));
+        externals.release();
+        if let Some(mut collection) = collection {
+            self.attach(world, collection);
+            collection.release();
+        }
+        if loaded_meshes {
+            // The shapes hold their own references to the meshes
+            for (_, mesh) in meshes {
+                mesh.release();
+            }
+        }
+        anyhow::ensure!(collection.is_some(), "Failed to deserialize the physics scene");
+        Ok(())
+    }
+
+    /// Attaches the objects of the deserialized `collection` to their entities
+    fn attach(&self, world: &mut World, collection: PxCollectionRef) {
+        let existing = query(()).incl(rigid_actor()).iter(world, None).map(|(id, _)| id).collect::<HashSet<_>>();
+        let mut actors = Vec::new();
+        let mut shapes: HashMap<EntityId, Vec<(PxShape, bool, bool)>> = HashMap::new();
+        let mut joints = Vec::new();
+        for (serial_id, object) in &self.objects {
+            match (object, collection.find(*serial_id)) {
+                (&SnapshotObject::Actor { entity, kinematic_target }, Some(PxAny::PxRigidDynamic(body))) => {
+                    actors.push((entity, body.as_rigid_actor(), Entity::new().with(rigid_dynamic(), body), kinematic_target));
+                }
+                (&SnapshotObject::Actor { entity, .. }, Some(PxAny::PxRigidStatic(body))) => {
+                    actors.push((entity, body.as_rigid_actor(), Entity::new().with(rigid_static(), body), None));
+                }
+                (&SnapshotObject::Shape { entity, density, base_pose, concave, convex }, Some(PxAny::PxShape(shape))) => {
+                    shape.set_user_data(PxShapeUserData { entity, density, base_pose });
+                    // `shape` holds a reference of its own, so the one it was created with can go
+                    shape.as_base().release();
+                    shapes.entry(entity).or_default().push((shape, concave, convex));
+                }
+                (&SnapshotObject::Joint { entity }, Some(any)) => joints.push((entity, any)),
+                _ => {}
+            }
+        }
+
+        // Actors are only released once no joint refers to them anymore
+        let mut restored = Vec::new();
+        let mut released = Vec::new();
+        for (id, actor, data, kinematic_target) in actors {
+            if existing.contains(&id) || world.add_components(id, data.with(rigid_actor(), actor)).is_err() {
+                released.push(actor);
+                continue;
+            }
+            actor.as_actor().set_user_data(PxActorUserData::default());
+            let scene = world.get(id, collider_type()).unwrap_or(ColliderType::Static).scene().get_scene(world);
+            scene.add_actor(&actor);
+            if let (Some(body), Some((translation, rotation))) = (actor.to_rigid_dynamic(), kinematic_target) {
+                body.set_kinematic_target(&PxTransform::new(translation, rotation));
+            }
+            restored.push(actor);
+        }
+
+        for (id, shapes) in shapes {
+            // Shapes of released actors are detached, and entities without an actor are rebuilt from their components
+            if existing.contains(&id) {
+                continue;
+            }
+            let Some((first, ..)) = shapes.iter().find(|(shape, ..)| shape.get_actor().is_some()) else { continue };
+            let concave = shapes.iter().filter(|(_, concave, _)| *concave).map(|(shape, ..)| shape.clone()).collect_vec();
+            let convex = shapes.iter().filter(|(_, _, convex)| *convex).map(|(shape, ..)| shape.clone()).collect_vec();
+            let data =
+                Entity::new().with(physics_shape(), first.clone()).with(collider_shapes(), concave).with(collider_shapes_convex(), convex);
+            world.add_components(id, data).ok();
+        }
+
+        for (id, any) in joints {
+            let (data, joint) = match any {
+                PxAny::PxFixedJoint(joint) => (Entity::new().with(fixed_joint(), joint), joint.as_joint()),
+                PxAny::PxRevoluteJoint(joint) => (Entity::new().with(revolute_joint(), joint), joint.as_joint()),
+                PxAny::PxPrismaticJoint(joint) => (Entity::new().with(prismatic_joint(), joint), joint.as_joint()),
+                PxAny::PxSphericalJoint(joint) => (Entity::new().with(spherical_joint(), joint), joint.as_joint()),
+                PxAny::PxDistanceJoint(joint) => (Entity::new().with(distance_joint(), joint), joint.as_joint()),
+                _ => continue,
+            };
+            let (actor0, actor1) = joint.get_actors();
+            let attached = [actor0, actor1].into_iter().flatten().all(|actor| restored.contains(&actor));
+            if attached && get_entity_joint(world, id).is_none() && world.add_components(id, data).is_ok() {
+                joint.set_user_data(id);
+            } else {
+                joint.release();
+            }
+        }
+        for actor in released {
+            actor.release();
+        }
+
+        // The shapes hold their own references to anything else they use
+        for id in &self.referenced {
+            if let Some(object) = collection.find_base(*id) {
+                object.release();
+            }
+        }
+    }
+}
+
+/// Stores a [PhysicsSnapshot] of `world` in its `physics_snapshot` resource, so that the physics are saved along with the world.
+/// [restore_stored_snapshot] picks it up once the world is loaded again.
+pub fn store_snapshot(world: &mut World) -> anyhow::Result<()> {
+    let snapshot = PhysicsSnapshot::capture(world)?;
+    world.add_resource(physics_snapshot(), snapshot);
+    Ok(())
+}
+
+/// Restores and removes the `physics_snapshot` of a world that was just loaded.
+///
+/// Must run before the collider systems, which would otherwise rebuild the colliders from their components.
+pub fn restore_stored_snapshot(world: &mut World) {
+    let Some(snapshot) = world.resource_opt(physics_snapshot()).cloned() else { return };
+    world.remove_component(world.resource_entity(), physics_snapshot()).unwrap();
+    if let Err(err) = snapshot.restore(world) {
+        tracing::warn!("Failed to restore the stored physics snapshot: {:#}", err);
+    }
+}
+
+/// The joints of `world`, with the entities they are stored on
+fn joints(world: &World) -> Vec<(EntityId, PxJointRef)> {
+    let mut joints = query(fixed_joint()).iter(world, None).map(|(id, joint)| (id, joint.as_joint())).collect_vec();
+    joints.extend(query(revolute_joint()).iter(world, None).map(|(id, joint)| (id, joint.as_joint())));
+    joints.extend(query(prismatic_joint()).iter(world, None).map(|(id, joint)| (id, joint.as_joint())));
+    joints.extend(query(spherical_joint()).iter(world, None).map(|(id, joint)| (id, joint.as_joint())));
+    joints.extend(query(distance_joint()).iter(world, None).map(|(id, joint)| (id, joint.as_joint())));
+    joints
+}
+
+#[cfg(test)]
+mod test {
+    use ambient_ecs::System;
+    use ambient_network::server::ForkingEvent;
+    use ambient_std::asset_cache::{AssetCache, SyncAssetKeyExt};
+    use physxx::{
+        PxActor, PxBoxGeometry, PxConvexFlag, PxConvexMeshDesc, PxConvexMeshGeometry, PxFixedJointRef, PxGeometry, PxRigidBody,
+        PxRigidBodyFlag, PxRigidDynamicRef,
+    };
+
+    use super::*;
+    use crate::{
+        create_server_resources, main_physics_scene,
+        physx::{test_physics, PhysicsKey},
+    };
+
+    fn world(runtime: &tokio::runtime::Runtime) -> World {
+        crate::init_all_components();
+        let assets = AssetCache::new(runtime.handle().clone());
+        PhysicsKey.insert(&assets, test_physics());
+        let mut world = World::new("snapshot");
+        let mut resources = Entity::new().with(asset_cache(), assets.clone());
+        create_server_resources(&assets, &mut resources);
+        world.add_components(world.resource_entity(), resources).unwrap();
+        world
+    }
+
+    /// Spawns a dynamic body with a convex shape of `geometry`, and a separate concave shape for when it's static
+    fn spawn_body(world: &mut World, geometry: &dyn PxGeometry, kinematic: bool) -> (EntityId, PxRigidDynamicRef) {
+        let physics = world.resource(physics()).clone();
+        let material = PhysicsMaterial::default().px_material(world.resource(asset_cache()));
+        let id = Entity::new().spawn(world);
+        let new_shape = || {
+            let shape = PxShape::new(physics.physics, geometry, &[&material], Some(true), None);
+            shape.set_user_data(PxShapeUserData { entity: id, ..Default::default() });
+            shape
+        };
+        let (convex, concave) = (new_shape(), new_shape());
+        let body = PxRigidDynamicRef::new(physics.physics, &PxTransform::from_translation(Vec3::Z));
+        body.set_rigid_body_flag(PxRigidBodyFlag::KINEMATIC, kinematic);
+        body.attach_shape(&convex);
+        body.as_actor().set_user_data(PxActorUserData::default());
+        world.resource(main_physics_scene()).add_actor(&body);
+        let data = Entity::new()
+            .with(rigid_actor(), body.as_rigid_actor())
+            .with(rigid_dynamic(), body)
+            .with(physics_shape(), convex.clone())
+            .with(collider_shapes(), vec![concave])
+            .with(collider_shapes_convex(), vec![convex]);
+        world.add_components(id, data).unwrap();
+        (id, body)
+    }
+
+    fn spawn_box(world: &mut World, kinematic: bool) -> (EntityId, PxRigidDynamicRef) {
+        spawn_body(world, &PxBoxGeometry::new(0.5, 0.5, 0.5), kinematic)
+    }
+
+    fn fork(world: &mut World) {
+        crate::on_forking_systems().run(world, &ForkingEvent);
+    }
+
+    #[test]
+    fn forks_keep_velocities_and_shape_roles() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let mut world = world(&runtime);
+        let (id, body) = spawn_box(&mut world, false);
+        body.set_linear_velocity(Vec3::X * 3., true);
+        let concave = world.get_ref(id, collider_shapes()).unwrap()[0].clone();
+
+        fork(&mut world);
+        let restored = world.get(id, rigid_dynamic()).unwrap();
+        assert_ne!(restored, body);
+        assert_eq!(restored.get_scene(), Some(*world.resource(main_physics_scene())));
+        assert!((restored.get_linear_velocity() - Vec3::X * 3.).length() < 1e-5);
+        assert!((restored.get_global_pose().translation() - Vec3::Z).length() < 1e-5);
+
+        let shape = world.get_ref(id, physics_shape()).unwrap().clone();
+        assert_eq!(shape.get_actor(), Some(restored.as_rigid_actor()));
+        assert_eq!(shape.get_user_data::<PxShapeUserData>().unwrap().entity, id);
+        assert_eq!(world.get_ref(id, collider_shapes_convex()).unwrap(), &vec![shape.clone()]);
+        let shapes = world.get_ref(id, collider_shapes()).unwrap();
+        assert_eq!(shapes.len(), 1);
+        assert!(shapes[0] != shape && shapes[0] != concave && shapes[0].get_actor().is_none());
+    }
+
+    #[test]
+    fn forks_keep_kinematic_targets() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let mut world = world(&runtime);
+        let (id, body) = spawn_box(&mut world, true);
+        body.set_kinematic_target(&PxTransform::from_translation(Vec3::Z * 3.));
+
+        fork(&mut world);
+        let target = world.get(id, rigid_dynamic()).unwrap().get_kinematic_target().unwrap();
+        assert!((target.translation() - Vec3::Z * 3.).length() < 1e-5);
+    }
+
+    #[test]
+    fn joints_need_both_of_their_actors() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let mut world = world(&runtime);
+        let physics = world.resource(physics()).clone();
+        let (a, a_body) = spawn_box(&mut world, false);
+        let (b, b_body) = spawn_box(&mut world, false);
+        let identity = PxTransform::identity();
+        let joint =
+            PxFixedJointRef::new(physics.physics, Some(a_body.as_rigid_actor()), &identity, Some(b_body.as_rigid_actor()), &identity);
+        let id = Entity::new().with(fixed_joint(), joint).spawn(&mut world);
+
+        fork(&mut world);
+        let joint = world.get(id, fixed_joint()).unwrap();
+        let actors = (world.get(a, rigid_actor()).ok(), world.get(b, rigid_actor()).ok());
+        assert_eq!(joint.get_actors(), actors);
+
+        let snapshot = PhysicsSnapshot::capture(&world).unwrap();
+        world.despawn(b);
+        for component in [rigid_actor().desc(), rigid_dynamic().desc(), physics_shape().desc(), collider_shapes().desc()] {
+            world.remove_component(a, component).unwrap();
+        }
+        world.remove_component(a, collider_shapes_convex()).unwrap();
+        world.remove_component(id, fixed_joint()).unwrap();
+        snapshot.restore(&mut world).unwrap();
+        assert!(world.has_component(a, rigid_actor()));
+        assert!(!world.has_component(id, fixed_joint()));
+    }
+
+    #[test]
+    fn stored_snapshots_restore_their_meshes() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let mut world = world(&runtime);
+        let physics = world.resource(physics()).clone();
+        let points = vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::Z];
+        let desc = PxConvexMeshDesc { points, indices: None, vertex_limit: None, flags: Some(PxConvexFlag::COMPUTE_CONVEX) };
+        let mesh = PxConvexMesh::from_desc(physics.physics, physics.cooking, desc).unwrap();
+        let (id, _) = spawn_body(&mut world, &PxConvexMeshGeometry::new(&mesh, None, None), false);
+
+        store_snapshot(&mut world).unwrap();
+        // Saving the world only keeps the serialized meshes
+        let stored = serde_json::to_vec(world.resource(physics_snapshot())).unwrap();
+        world.add_resource(physics_snapshot(), serde_json::from_slice(&stored).unwrap());
+        for component in [rigid_actor().desc(), rigid_dynamic().desc(), physics_shape().desc(), collider_shapes().desc()] {
+            world.remove_component(id, component).unwrap();
+        }
+        world.remove_component(id, collider_shapes_convex()).unwrap();
+
+        restore_stored_snapshot(&mut world);
+        assert!(world.resource_opt(physics_snapshot()).is_none());
+        let shape = world.get_ref(id, physics_shape()).unwrap();
+        let restored = shape.get_geometry().as_convex_mesh().unwrap().mesh();
+        assert_ne!(restored.as_base(), mesh.as_base());
+    }
+}
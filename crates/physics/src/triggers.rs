@@ -0,0 +1,202 @@
+use std::collections::BTreeSet;
+
+use ambient_ecs::{components, query, Debuggable, Description, EntityId, Name, Networked, Resource, Store, SystemGroup, World};
+use physxx::{PxGeometryType, PxOverlapCallback, PxRigidActor, PxRigidActorRef, PxShape, PxUserData};
+
+use crate::{
+    collider::{collider_shapes, collider_type, ColliderType},
+    collision_layers::{collides_with, filter_query_layers},
+    intersection::touching_filter_data,
+    main_physics_scene,
+    physx::rigid_actor,
+    PxShapeUserData,
+};
+
+components!("physics", {
+    @[
+        Debuggable, Networked, Store,
+        Name["Trigger occupants"],
+        Description["The entities whose colliders are inside this trigger area. Updated every physics step.\nOnly entities in the layers of `collides_with` are included, if it is set."]
+    ]
+    trigger_occupants: Vec<EntityId>,
+    @[Debuggable, Resource]
+    trigger_events: Vec<TriggerEvent>,
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriggerEventKind {
+    Enter,
+    Exit,
+}
+
+/// An entity that entered or left a trigger area during the last physics step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TriggerEvent {
+    pub kind: TriggerEventKind,
+    pub trigger: EntityId,
+    pub other: EntityId,
+}
+
+/// The events of the entities that entered or left the trigger area `trigger`, given its `previous` and current `occupants`.
+fn occupancy_events<'a>(
+    trigger: EntityId,
+    previous: &'a BTreeSet<EntityId>,
+    occupants: &'a BTreeSet<EntityId>,
+) -> impl Iterator<Item = TriggerEvent> + 'a {
+    let enter = occupants.difference(previous).map(move |&other| TriggerEvent { kind: TriggerEventKind::Enter, trigger, other });
+    let exit = previous.difference(occupants).map(move |&other| TriggerEvent { kind: TriggerEventKind::Exit, trigger, other });
+    enter.chain(exit)
+}
+
+/// Whether overlap queries, which trigger areas use, support the geometry of `shape`.
+fn is_convex(shape: &PxShape) -> bool {
+    matches!(shape.get_geometry_type(), PxGeometryType::Box | PxGeometryType::SPHERE | PxGeometryType::Capsule | PxGeometryType::ConvexMesh)
+}
+
+/// Returns the entities in the main physics scene that overlap the shapes of the trigger area `id`.
+fn overlapping_entities(world: &World, id: EntityId, actor: PxRigidActorRef) -> BTreeSet<EntityId> {
+    let scene = world.resource(main_physics_scene());
+    let mut filter_data = touching_filter_data();
+    filter_query_layers(&mut filter_data, world.get(id, collides_with()).ok());
+
+    let mut res = BTreeSet::new();
+    for shape in actor.get_shapes() {
+        // Non-convex shapes are reported when the trigger area is created
+        if !is_convex(&shape) {
+            continue;
+        }
+        let mut hit_call = PxOverlapCallback::new(1000);
+        if scene.overlap(&shape.get_geometry(), shape.get_global_pose(actor), &mut hit_call, &filter_data) {
+            for hit in hit_call.touches() {
+                if let Some(ud) = hit.shape.get_user_data::<PxShapeUserData>() {
+                    if ud.entity != id {
+                        res.insert(ud.entity);
+                    }
+                }
+            }
+        }
+    }
+    res
+}
+
+pub fn server_systems() -> SystemGroup {
+    SystemGroup::new(
+        "physics/triggers/server",
+        vec![
+            query((collider_type(), rigid_actor())).to_system(|q, world, qs, _| {
+                let mut events = Vec::new();
+                for (id, (collider_type, actor)) in q.collect_cloned(world, qs) {
+                    if collider_type != ColliderType::TriggerArea {
+                        continue;
+                    }
+                    let occupants = overlapping_entities(world, id, actor);
+                    let previous = match world.get_ref(id, trigger_occupants()) {
+                        Ok(previous) => previous.iter().copied().collect::<BTreeSet<_>>(),
+                        Err(_) => BTreeSet::new(),
+                    };
+                    if occupants == previous && world.has_component(id, trigger_occupants()) {
+                        continue;
+                    }
+                    events.extend(occupancy_events(id, &previous, &occupants));
+                    world.add_component(id, trigger_occupants(), occupants.into_iter().collect()).unwrap();
+                }
+                world.resource_mut(trigger_events()).extend(events);
+            }),
+            query((collider_type().changed(), rigid_actor().changed())).optional_changed(collider_shapes()).to_system(|q, world, qs, _| {
+                for (id, (collider_type, actor)) in q.iter(world, qs) {
+                    if *collider_type != ColliderType::TriggerArea {
+                        continue;
+                    }
+                    let unsupported = actor.get_shapes().iter().filter(|shape| !is_convex(shape)).count();
+                    if unsupported > 0 {
+                        tracing::warn!(
+                            "Trigger area {id} has {unsupported} non-convex shape(s), which can't detect occupants. Use a convex collider instead."
+                        );
+                    }
+                }
+            }),
+            // Trigger areas that are removed or despawned are left by all their occupants
+            query(collider_type().changed()).incl(trigger_occupants()).to_system(|q, world, qs, _| {
+                let mut events = Vec::new();
+                for (id, collider_type) in q.collect_cloned(world, qs) {
+                    if collider_type != ColliderType::TriggerArea {
+                        let previous: BTreeSet<_> = world.get_ref(id, trigger_occupants()).unwrap().iter().copied().collect();
+                        events.extend(occupancy_events(id, &previous, &BTreeSet::new()));
+                        world.remove_component(id, trigger_occupants()).unwrap();
+                    }
+                }
+                world.resource_mut(trigger_events()).extend(events);
+            }),
+            query((trigger_occupants(),)).despawned().to_system(|q, world, qs, _| {
+                let mut events = Vec::new();
+                for (id, (occupants,)) in q.iter(world, qs) {
+                    let previous: BTreeSet<_> = occupants.iter().copied().collect();
+                    events.extend(occupancy_events(id, &previous, &BTreeSet::new()));
+                }
+                world.resource_mut(trigger_events()).extend(events);
+            }),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use ambient_ecs::{Entity, FrameEvent, System};
+
+    use super::*;
+
+    fn world() -> World {
+        crate::init_all_components();
+        let mut world = World::new("triggers");
+        world.add_resource(trigger_events(), vec![]);
+        world
+    }
+
+    fn spawn_trigger(world: &mut World, occupants: Vec<EntityId>) -> EntityId {
+        Entity::new().with(collider_type(), ColliderType::TriggerArea).with(trigger_occupants(), occupants).spawn(world)
+    }
+
+    #[test]
+    fn occupancy_changes_are_reported() {
+        let (trigger, a, b, c) = (EntityId::new(), EntityId::new(), EntityId::new(), EntityId::new());
+        let previous = BTreeSet::from([a, b]);
+        let occupants = BTreeSet::from([b, c]);
+
+        let events = occupancy_events(trigger, &previous, &occupants).collect::<Vec<_>>();
+        assert_eq!(
+            events,
+            vec![
+                TriggerEvent { kind: TriggerEventKind::Enter, trigger, other: c },
+                TriggerEvent { kind: TriggerEventKind::Exit, trigger, other: a },
+            ]
+        );
+    }
+
+    #[test]
+    fn despawned_triggers_are_left() {
+        let mut world = world();
+        let occupant = Entity::new().spawn(&mut world);
+        let trigger = spawn_trigger(&mut world, vec![occupant]);
+        let mut systems = server_systems();
+        systems.run(&mut world, &FrameEvent);
+        assert!(world.resource(trigger_events()).is_empty());
+
+        world.despawn(trigger);
+        systems.run(&mut world, &FrameEvent);
+        assert_eq!(world.resource(trigger_events()), &vec![TriggerEvent { kind: TriggerEventKind::Exit, trigger, other: occupant }]);
+    }
+
+    #[test]
+    fn triggers_that_stop_being_triggers_are_left() {
+        let mut world = world();
+        let occupant = Entity::new().spawn(&mut world);
+        let trigger = spawn_trigger(&mut world, vec![occupant]);
+        let mut systems = server_systems();
+        systems.run(&mut world, &FrameEvent);
+
+        world.set(trigger, collider_type(), ColliderType::Dynamic).unwrap();
+        systems.run(&mut world, &FrameEvent);
+        assert!(!world.has_component(trigger, trigger_occupants()));
+        assert_eq!(world.resource(trigger_events()), &vec![TriggerEvent { kind: TriggerEventKind::Exit, trigger, other: occupant }]);
+    }
+}
@@ -0,0 +1,353 @@
+use std::f32::consts::PI;
+
+use ambient_ecs::{components, query, Debuggable, Description, Entity, EntityId, Name, Networked, Store, SystemGroup, World};
+use glam::{Quat, Vec2, Vec3};
+use ordered_float::OrderedFloat;
+use physxx::{PxRaycastCallback, PxRaycastHit, PxRigidActor, PxRigidBody, PxUserData};
+
+use crate::{
+    collision_layers::{collides_with, filter_query_layers},
+    intersection::touching_filter_data,
+    main_physics_scene,
+    physx::rigid_dynamic,
+    PxShapeUserData,
+};
+
+components!("physics", {
+    @[
+        Debuggable, Networked, Store,
+        Name["Vehicle throttle"],
+        Description["The throttle of the vehicle, from -1 (full reverse) to 1 (full forward). The vehicle drives along its local X axis."]
+    ]
+    vehicle_throttle: f32,
+    @[
+        Debuggable, Networked, Store,
+        Name["Vehicle brake"],
+        Description["How hard the vehicle brakes, from 0 to 1."]
+    ]
+    vehicle_brake: f32,
+    @[
+        Debuggable, Networked, Store,
+        Name["Vehicle steering"],
+        Description["The steering of the vehicle, from -1 (full right) to 1 (full left)."]
+    ]
+    vehicle_steering: f32,
+
+    @[
+        Debuggable, Networked, Store,
+        Name["Wheel vehicle"],
+        Description["Makes this entity a wheel of the vehicle with this id.\nThe vehicle must be a dynamic physics entity; its throttle, brake and steering drive the wheel."]
+    ]
+    wheel_vehicle: EntityId,
+    @[
+        Debuggable, Networked, Store,
+        Name["Wheel attachment"],
+        Description["The point where the suspension of this wheel is attached, relative to the vehicle. Defaults to the vehicle's origin."]
+    ]
+    wheel_attachment: Vec3,
+    @[
+        Debuggable, Networked, Store,
+        Name["Wheel radius"],
+        Description["The radius of this wheel, in meters. Defaults to 0.4."]
+    ]
+    wheel_radius: f32,
+    @[
+        Debuggable, Networked, Store,
+        Name["Wheel suspension rest length"],
+        Description["The length of the suspension of this wheel when it is not compressed, in meters. Defaults to 0.3."]
+    ]
+    wheel_suspension_rest_length: f32,
+    @[
+        Debuggable, Networked, Store,
+        Name["Wheel suspension stiffness"],
+        Description["The force the suspension of this wheel pushes with per meter of compression. Defaults to 20000."]
+    ]
+    wheel_suspension_stiffness: f32,
+    @[
+        Debuggable, Networked, Store,
+        Name["Wheel suspension damping"],
+        Description["The force that resists the suspension of this wheel moving, per meter/second. Defaults to 2000."]
+    ]
+    wheel_suspension_damping: f32,
+    @[
+        Debuggable, Networked, Store,
+        Name["Wheel friction curve"],
+        Description["The friction coefficient of this wheel (`y`) at a sideways slip angle in radians (`x`), linearly interpolated between the points.\nThe highest coefficient also limits the total grip of the wheel. The points must be in strictly increasing order of `x`. Defaults to a peak of 1 at 0.15 radians, falling to 0.8, which is also used for invalid curves."]
+    ]
+    wheel_friction_curve: Vec<Vec2>,
+    @[
+        Debuggable, Networked, Store,
+        Name["Wheel max steering angle"],
+        Description["The angle this wheel turns at full steering, in radians. Defaults to 0."]
+    ]
+    wheel_max_steering_angle: f32,
+    @[
+        Debuggable, Networked, Store,
+        Name["Wheel drive force"],
+        Description["The force this wheel drives with at full throttle, in newtons. Defaults to 0."]
+    ]
+    wheel_drive_force: f32,
+    @[
+        Debuggable, Networked, Store,
+        Name["Wheel brake force"],
+        Description["The force this wheel brakes with at full brake, in newtons. Defaults to 0."]
+    ]
+    wheel_brake_force: f32,
+
+    @[
+        Debuggable, Networked,
+        Name["Wheel in contact"],
+        Description["Whether this wheel touched the ground in the last physics step."]
+    ]
+    wheel_in_contact: bool,
+    @[
+        Debuggable, Networked,
+        Name["Wheel contact point"],
+        Description["Where this wheel touched the ground in the last physics step. If it is not in contact, this is the bottom of the fully extended wheel."]
+    ]
+    wheel_contact_point: Vec3,
+    @[
+        Debuggable, Networked,
+        Name["Wheel suspension length"],
+        Description["The current length of the suspension of this wheel, in meters."]
+    ]
+    wheel_suspension_length: f32,
+    @[
+        Debuggable, Networked,
+        Name["Wheel steering angle"],
+        Description["The current steering angle of this wheel, in radians."]
+    ]
+    wheel_steering_angle: f32,
+    @[
+        Debuggable, Networked,
+        Name["Wheel RPM"],
+        Description["The revolutions per minute of this wheel. Positive when rolling forward."]
+    ]
+    wheel_rpm: f32,
+});
+
+/// How much of its spin a wheel keeps each physics step while it is in the air
+const WHEEL_AIR_SPIN_DECAY: f32 = 0.98;
+
+fn default_friction_curve() -> Vec<Vec2> {
+    vec![Vec2::new(0., 0.), Vec2::new(0.15, 1.), Vec2::new(0.5, 0.8)]
+}
+
+/// A friction curve can only be interpolated if its points are finite, and strictly increasing in `x`
+fn is_valid_friction_curve(curve: &[Vec2]) -> bool {
+    curve.iter().all(|point| point.is_finite()) && curve.windows(2).all(|points| points[0].x < points[1].x)
+}
+
+/// The wheel parameters of an entity, with defaults for any that are missing.
+#[derive(Debug, Clone)]
+pub struct Wheel {
+    pub vehicle: EntityId,
+    pub attachment: Vec3,
+    pub radius: f32,
+    pub rest_length: f32,
+    pub stiffness: f32,
+    pub damping: f32,
+    pub friction_curve: Vec<Vec2>,
+    pub max_steering_angle: f32,
+    pub drive_force: f32,
+    pub brake_force: f32,
+}
+impl Wheel {
+    pub fn from_entity(world: &World, id: EntityId) -> Option<Self> {
+        Some(Self {
+            vehicle: world.get(id, wheel_vehicle()).ok()?,
+            attachment: world.get(id, wheel_attachment()).unwrap_or(Vec3::ZERO),
+            radius: world.get(id, wheel_radius()).unwrap_or(0.4),
+            rest_length: world.get(id, wheel_suspension_rest_length()).unwrap_or(0.3),
+            stiffness: world.get(id, wheel_suspension_stiffness()).unwrap_or(20000.),
+            damping: world.get(id, wheel_suspension_damping()).unwrap_or(2000.),
+            // Invalid curves are reported by `server_systems` when they are set
+            friction_curve: world
+                .get_cloned(id, wheel_friction_curve())
+                .ok()
+                .filter(|curve| is_valid_friction_curve(curve))
+                .unwrap_or_else(default_friction_curve),
+            max_steering_angle: world.get(id, wheel_max_steering_angle()).unwrap_or(0.),
+            drive_force: world.get(id, wheel_drive_force()).unwrap_or(0.),
+            brake_force: world.get(id, wheel_brake_force()).unwrap_or(0.),
+        })
+    }
+
+    /// The friction coefficient at the sideways `slip_angle`
+    pub fn friction(&self, slip_angle: f32) -> f32 {
+        let curve = &self.friction_curve;
+        if curve.is_empty() {
+            return 1.;
+        }
+        match curve.iter().position(|point| point.x > slip_angle) {
+            Some(0) => curve[0].y,
+            Some(i) => {
+                let (a, b) = (curve[i - 1], curve[i]);
+                a.y + (b.y - a.y) * (slip_angle - a.x) / (b.x - a.x)
+            }
+            None => curve[curve.len() - 1].y,
+        }
+    }
+
+    /// The highest friction coefficient of the friction curve
+    pub fn max_friction(&self) -> f32 {
+        self.friction_curve.iter().map(|point| point.y).reduce(f32::max).unwrap_or(1.)
+    }
+}
+
+/// Casts the suspension ray of a wheel of `vehicle`, ignoring the colliders of the vehicle itself and of its wheels
+fn cast_suspension(world: &World, vehicle: EntityId, origin: Vec3, direction: Vec3, max_distance: f32) -> Option<PxRaycastHit> {
+    let scene = world.resource(main_physics_scene());
+    let mut filter_data = touching_filter_data();
+    filter_query_layers(&mut filter_data, world.get(vehicle, collides_with()).ok());
+    let mut hit_call = PxRaycastCallback::new(32);
+    if !scene.raycast(origin, direction, max_distance, &mut hit_call, None, &filter_data) {
+        return None;
+    }
+    hit_call
+        .touches()
+        .into_iter()
+        .filter(|hit| {
+            hit.shape
+                .as_ref()
+                .and_then(|shape| shape.get_user_data::<PxShapeUserData>())
+                .map_or(false, |ud| ud.entity != vehicle && world.get(ud.entity, wheel_vehicle()).map_or(true, |other| other != vehicle))
+        })
+        .min_by_key(|hit| OrderedFloat(hit.distance))
+}
+
+/// Applies the suspension and tire forces of the wheel `id` to its vehicle, and updates the wheel's state
+fn update_wheel(world: &mut World, id: EntityId, wheel: &Wheel) {
+    let Ok(body) = world.get(wheel.vehicle, rigid_dynamic()) else { return };
+    let pose = body.get_global_pose();
+    let rotation = pose.rotation();
+    let up = rotation * Vec3::Z;
+    let attachment = pose.translation() + rotation * wheel.attachment;
+    let steering_angle = world.get(wheel.vehicle, vehicle_steering()).unwrap_or(0.).clamp(-1., 1.) * wheel.max_steering_angle;
+
+    let hit = cast_suspension(world, wheel.vehicle, attachment, -up, wheel.rest_length + wheel.radius);
+    let (suspension_length, contact_point, rpm) = match &hit {
+        Some(hit) => {
+            let suspension_length = (hit.distance - wheel.radius).clamp(0., wheel.rest_length);
+            let velocity = body.get_velocity_at_pos(hit.position);
+            let normal_force = (wheel.stiffness * (wheel.rest_length - suspension_length) - wheel.damping * velocity.dot(up)).max(0.);
+            body.add_force_at_pos(up * normal_force, attachment, None, None);
+
+            let forward = (rotation * Quat::from_rotation_z(steering_angle) * Vec3::X).reject_from(hit.normal).normalize_or_zero();
+            let side = hit.normal.cross(forward);
+            let (forward_speed, side_speed) = (velocity.dot(forward), velocity.dot(side));
+            let throttle = world.get(wheel.vehicle, vehicle_throttle()).unwrap_or(0.).clamp(-1., 1.);
+            let brake = world.get(wheel.vehicle, vehicle_brake()).unwrap_or(0.).clamp(0., 1.);
+
+            // Clamping the speeds keeps the forces from jittering when the vehicle is almost at rest
+            let longitudinal = throttle * wheel.drive_force - forward_speed.clamp(-1., 1.) * brake * wheel.brake_force;
+            let slip_angle = side_speed.atan2(forward_speed.abs());
+            let lateral = -side_speed.clamp(-1., 1.) * wheel.friction(slip_angle.abs()) * normal_force;
+            let tire_force = (forward * longitudinal + side * lateral).clamp_length_max(wheel.max_friction() * normal_force);
+            body.add_force_at_pos(tire_force, hit.position, None, None);
+
+            (suspension_length, hit.position, forward_speed / (2. * PI * wheel.radius) * 60.)
+        }
+        None => (
+            wheel.rest_length,
+            attachment - up * (wheel.rest_length + wheel.radius),
+            world.get(id, wheel_rpm()).unwrap_or(0.) * WHEEL_AIR_SPIN_DECAY,
+        ),
+    };
+
+    world
+        .add_components(
+            id,
+            Entity::new()
+                .with(wheel_in_contact(), hit.is_some())
+                .with(wheel_contact_point(), contact_point)
+                .with(wheel_suspension_length(), suspension_length)
+                .with(wheel_steering_angle(), steering_angle)
+                .with(wheel_rpm(), rpm),
+        )
+        .unwrap();
+}
+
+pub fn server_systems() -> SystemGroup {
+    SystemGroup::new(
+        "physics/vehicle/server",
+        vec![
+            query(wheel_friction_curve().changed()).to_system(|q, world, qs, _| {
+                for (id, curve) in q.iter(world, qs) {
+                    if !is_valid_friction_curve(curve) {
+                        log::warn!(
+                            "The wheel_friction_curve of {id} must be finite and strictly increasing in x; using the default curve instead"
+                        );
+                    }
+                }
+            }),
+            query(wheel_vehicle()).to_system(|q, world, qs, _| {
+                for (id, _) in q.collect_cloned(world, qs) {
+                    if let Some(wheel) = Wheel::from_entity(world, id) {
+                        update_wheel(world, id, &wheel);
+                    }
+                }
+            }),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn wheel(friction_curve: Vec<Vec2>) -> Wheel {
+        Wheel {
+            vehicle: EntityId::null(),
+            attachment: Vec3::ZERO,
+            radius: 0.4,
+            rest_length: 0.3,
+            stiffness: 20000.,
+            damping: 2000.,
+            friction_curve,
+            max_steering_angle: 0.,
+            drive_force: 0.,
+            brake_force: 0.,
+        }
+    }
+
+    #[test]
+    fn friction_is_interpolated_along_the_curve() {
+        let wheel = wheel(vec![Vec2::new(0.1, 0.2), Vec2::new(0.2, 1.), Vec2::new(0.6, 0.6)]);
+        // Clamped to the ends of the curve
+        assert_eq!(wheel.friction(0.), 0.2);
+        assert_eq!(wheel.friction(1.), 0.6);
+        // Exactly at and between points
+        assert_eq!(wheel.friction(0.2), 1.);
+        assert!((wheel.friction(0.15) - 0.6).abs() < 1e-5);
+        assert!((wheel.friction(0.4) - 0.8).abs() < 1e-5);
+        assert_eq!(wheel.max_friction(), 1.);
+    }
+
+    #[test]
+    fn invalid_friction_curves_fall_back_to_the_default() {
+        crate::init_all_components();
+        let mut world = World::new("vehicle");
+        let curves = [
+            vec![Vec2::new(0.3, 1.), Vec2::new(0.1, 0.5)],
+            vec![Vec2::new(0.1, 1.), Vec2::new(0.1, 0.5)],
+            vec![Vec2::new(0., 1.), Vec2::new(f32::NAN, 0.5)],
+        ];
+        for curve in curves {
+            let id = Entity::new().with(wheel_vehicle(), EntityId::null()).with(wheel_friction_curve(), curve).spawn(&mut world);
+            let wheel = Wheel::from_entity(&world, id).unwrap();
+            assert_eq!(wheel.friction_curve, default_friction_curve());
+            assert!(wheel.friction(0.3).is_finite());
+        }
+        let curve = vec![Vec2::new(0.1, 0.2), Vec2::new(0.2, 1.)];
+        let id = Entity::new().with(wheel_vehicle(), EntityId::null()).with(wheel_friction_curve(), curve.clone()).spawn(&mut world);
+        assert_eq!(Wheel::from_entity(&world, id).unwrap().friction_curve, curve);
+    }
+
+    #[test]
+    fn wheels_without_a_curve_have_full_friction() {
+        let wheel = wheel(vec![]);
+        assert_eq!(wheel.friction(0.3), 1.);
+        assert_eq!(wheel.max_friction(), 1.);
+    }
+}
@@ -1,21 +1,29 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, future::Future, pin::Pin, sync::Arc};
 
-use ambient_core::{asset_cache, async_ecs::async_run, hierarchy::children, runtime};
+use ambient_core::{
+    asset_cache,
+    async_ecs::async_run,
+    hierarchy::{add_child, children, despawn_recursive, parent},
+    is_reloaded_asset, reloaded_assets, runtime,
+};
 use ambient_decals::decal;
 use ambient_ecs::{
-    components, query, query_mut, Debuggable, Description, DeserWorldWithWarnings, EntityId, Name, Networked, Store, SystemGroup, World,
+    components, query, query_mut, Debuggable, Description, DeserWorldWithWarnings, Entity, EntityId, Name, Networked, Store, SystemGroup,
+    World,
 };
 use ambient_model::model_from_url;
-use ambient_physics::collider::collider;
+use ambient_physics::{collider::collider, material::physics_material_from_url};
 use ambient_std::{
     asset_cache::{AssetCache, AsyncAssetKey, AsyncAssetKeyExt},
-    asset_url::AssetUrl,
+    asset_url::{AbsAssetUrl, AssetUrl},
     download_asset::{AssetError, BytesFromUrl},
-    unwrap_log_err,
+    log_result, unwrap_log_err,
 };
 use anyhow::Context;
 use async_trait::async_trait;
 
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
 components!("prefab", {
     @[
         Debuggable, Networked, Store,
@@ -29,46 +37,196 @@ components!("prefab", {
         Description["If attached, this entity was built from a prefab that has finished spawning."]
     ]
     spawned: (),
+    @[
+        Debuggable,
+        Name["Prefab version"],
+        Description["The version of the world at which the prefab components of this entity were last written.\nComponents written after it were changed at runtime, and are kept when the prefab is reloaded."]
+    ]
+    prefab_version: u64,
+    @[
+        Debuggable,
+        Name["Prefab child"],
+        Description["If attached, this entity was spawned as part of the hierarchy of its parent's prefab, and is updated or replaced when that prefab is reloaded."]
+    ]
+    prefab_child: (),
 });
 
 pub fn systems() -> SystemGroup {
     SystemGroup::new(
         "prefab",
-        vec![query(prefab_from_url()).spawned().to_system(|q, world, qs, _| {
-            let mut to_load = HashMap::<String, Vec<EntityId>>::new();
-            for (id, url) in q.collect_cloned(world, qs) {
-                let url = if url.ends_with("/prefabs/main.json") { url } else { format!("{url}/prefabs/main.json") };
-                to_load.entry(url).or_default().push(id);
-            }
-            for (url, ids) in to_load {
-                let assets = world.resource(asset_cache()).clone();
-                let url = unwrap_log_err!(AssetUrl::parse(url));
-                let url = PrefabFromUrl(url);
-                let runtime = world.resource(runtime()).clone();
-                let async_run = world.resource(async_run()).clone();
-                runtime.spawn(async move {
-                    let obj = unwrap_log_err!(url.get(&assets).await);
-                    let base_ent_id = obj.resource(children())[0];
-                    // TODO: This only handles prefabs with a single entity
-                    let entity = obj.clone_entity(base_ent_id).unwrap();
-                    async_run.run(move |world| {
-                        for id in ids {
-                            world.add_components(id, entity.clone()).unwrap();
-                            world.add_component(id, spawned(), ()).unwrap();
-                        }
-                    });
-                });
-            }
-        })],
+        vec![
+            query(prefab_from_url()).spawned().to_system(|q, world, qs, _| {
+                let prefabs = q.collect_cloned(world, qs);
+                spawn_prefabs(world, prefabs);
+            }),
+            query(reloaded_assets().changed()).to_system(|q, world, qs, _| {
+                for (_, reloaded) in q.collect_cloned(world, qs) {
+                    world.resource(asset_cache()).invalidate(|key| is_reloaded_asset(key, &reloaded));
+
+                    let prefabs = query(prefab_from_url())
+                        .iter(world, None)
+                        .filter(|(_, url)| is_reloaded_asset(&prefab_url(url), &reloaded))
+                        .map(|(id, url)| (id, url.clone()))
+                        .collect::<Vec<_>>();
+                    spawn_prefabs(world, prefabs);
+                }
+            }),
+        ],
     )
 }
 
+fn prefab_url(url: &str) -> String {
+    if url.ends_with("/prefabs/main.json") {
+        url.to_string()
+    } else {
+        format!("{url}/prefabs/main.json")
+    }
+}
+
+/// Loads the prefab of each entity, and adds its components to that entity and spawns its children once loaded.
+///
+/// When the prefab of an entity is loaded again, e.g. after it was rebuilt, components written since it was last
+/// applied are kept, and the children it spawned are updated in place when the prefab still has as many.
+fn spawn_prefabs(world: &World, prefabs: Vec<(EntityId, String)>) {
+    let mut to_load = HashMap::<String, Vec<EntityId>>::new();
+    for (id, url) in prefabs {
+        to_load.entry(prefab_url(&url)).or_default().push(id);
+    }
+    for (url, ids) in to_load {
+        let assets = world.resource(asset_cache()).clone();
+        let url = unwrap_log_err!(AssetUrl::parse(url));
+        let url = PrefabFromUrl(url);
+        let runtime = world.resource(runtime()).clone();
+        let async_run = world.resource(async_run()).clone();
+        runtime.spawn(async move {
+            let obj = unwrap_log_err!(url.get(&assets).await);
+            let base_ent_id = obj.resource(children())[0];
+            let mut entity = obj.clone_entity(base_ent_id).unwrap();
+            let prefab_children = entity.remove_self(children()).unwrap_or_default();
+            async_run.run(move |world| {
+                for id in ids {
+                    log_result!(apply_prefab(&obj, world, id, entity.clone(), &prefab_children));
+                    world.add_component(id, spawned(), ()).unwrap();
+                }
+            });
+        });
+    }
+}
+
+/// Applies `data` to `id` and makes the prefab children of `id` match `source_children`, from the prefab `source`.
+fn apply_prefab(source: &World, world: &mut World, id: EntityId, data: Entity, source_children: &[EntityId]) -> anyhow::Result<()> {
+    apply_prefab_components(world, id, data)?;
+
+    let current = world.get_ref(id, children()).cloned().unwrap_or_default();
+    let (previous, kept): (Vec<_>, Vec<_>) = current.into_iter().partition(|&c| world.has_component(c, prefab_child()));
+    if previous.len() == source_children.len() {
+        for (&child, &source_child) in previous.iter().zip(source_children) {
+            let mut data = source.clone_entity(source_child)?;
+            let grandchildren = data.remove_self(children()).unwrap_or_default();
+            apply_prefab(source, world, child, data, &grandchildren)?;
+        }
+    } else {
+        // The hierarchy of the prefab changed, so its children are replaced
+        for child in previous {
+            despawn_recursive(world, child);
+        }
+        if world.has_component(id, children()) {
+            world.set(id, children(), kept)?;
+        }
+        for &child in source_children {
+            spawn_prefab_child(source, child, world, id)?;
+        }
+    }
+    Ok(())
+}
+
+/// Adds the components of `data` to `id`. If a prefab was applied to `id` before, the components that were written
+/// since then are left as they are.
+fn apply_prefab_components(world: &mut World, id: EntityId, mut data: Entity) -> anyhow::Result<()> {
+    if let Ok(version) = world.get(id, prefab_version()) {
+        data.filter(&|component| world.get_component_content_version(id, component.index()).map_or(true, |v| v <= version));
+    }
+    world.add_components(id, data.with(prefab_version(), 0))?;
+    update_prefab_version(world, id)
+}
+
+/// Copies `entity` and its descendants from the prefab `source` to `target`, as prefab children of `parent_id`.
+fn spawn_prefab_child(source: &World, entity: EntityId, target: &mut World, parent_id: EntityId) -> anyhow::Result<()> {
+    let mut data = source.clone_entity(entity)?;
+    let entity_children = data.remove_self(children()).unwrap_or_default();
+    let id = data.with(parent(), parent_id).with(prefab_child(), ()).with(prefab_version(), 0).spawn(target);
+    update_prefab_version(target, id)?;
+    add_child(target, parent_id, id)?;
+    for child in entity_children {
+        spawn_prefab_child(source, child, target, id)?;
+    }
+    Ok(())
+}
+
+/// Sets [prefab_version] to the version it was written at, which is the version the prefab components were written at.
+fn update_prefab_version(world: &mut World, id: EntityId) -> anyhow::Result<()> {
+    let version = world.get_component_content_version(id, prefab_version().desc().index())?;
+    world.set(id, prefab_version(), version)?;
+    Ok(())
+}
+
+/// Copies `entity` and its descendants from `source` to `target`, as a child of `parent_id`. Returns the id of the copy.
+fn copy_hierarchy(source: &World, entity: EntityId, target: &mut World, parent_id: EntityId) -> anyhow::Result<EntityId> {
+    let mut data = source.clone_entity(entity)?;
+    let entity_children = data.remove_self(children()).unwrap_or_default();
+    let id = data.with(parent(), parent_id).spawn(target);
+    add_child(target, parent_id, id)?;
+    for child in entity_children {
+        copy_hierarchy(source, child, target, id)?;
+    }
+    Ok(id)
+}
+
+/// Replaces the prefab reference of `id` with the root of the referenced prefab, keeping the components already set on
+/// `id` as overrides, and copies the rest of the referenced hierarchy under it.
+///
+/// `stack` is the chain of prefabs being loaded that led to `world`, which the referenced prefab must not be part of.
+async fn expand_prefab_reference(
+    assets: &AssetCache,
+    world: &mut World,
+    id: EntityId,
+    url: &AbsAssetUrl,
+    stack: &[AbsAssetUrl],
+) -> Result<(), AssetError> {
+    let url = AbsAssetUrl::parse(prefab_url(&url.to_string())).context("Invalid prefab url")?;
+    if stack.contains(&url) {
+        let chain = stack.iter().chain(std::iter::once(&url)).map(|url| url.to_string()).collect::<Vec<_>>();
+        return Err(anyhow::anyhow!("Prefab references itself: {}", chain.join(" -> ")).into());
+    }
+    let prefab = load_prefab(assets.clone(), url, stack.to_vec()).await?;
+    world.remove_component(id, prefab_from_url()).context("Failed to remove prefab reference")?;
+    let overrides = world.clone_entity(id).context("Failed to clone prefab reference")?;
+
+    let prefab_root = prefab.resource(children())[0];
+    let mut root = prefab.clone_entity(prefab_root).context("Failed to clone prefab root")?;
+    let root_children = root.remove_self(children()).unwrap_or_default();
+    root.merge(overrides);
+    world.add_components(id, root).context("Failed to add prefab components")?;
+    for child in root_children {
+        copy_hierarchy(&prefab, child, world, id)?;
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone)]
 pub struct PrefabFromUrl(pub AssetUrl);
 #[async_trait]
 impl AsyncAssetKey<Result<Arc<World>, AssetError>> for PrefabFromUrl {
     async fn load(self, assets: AssetCache) -> Result<Arc<World>, AssetError> {
         let obj_url = self.0.abs().context(format!("PrefabFromUrl got relative url: {}", self.0))?;
+        Ok(Arc::new(load_prefab(assets, obj_url, Vec::new()).await?))
+    }
+}
+
+/// Loads the prefab at `obj_url`, and expands the prefabs it references. `stack` is the chain of prefabs that led to this
+/// one, which it can't reference, as loading them would then never finish.
+fn load_prefab(assets: AssetCache, obj_url: AbsAssetUrl, mut stack: Vec<AbsAssetUrl>) -> BoxFuture<'static, Result<World, AssetError>> {
+    Box::pin(async move {
         let data = BytesFromUrl::new(obj_url.clone(), true).get(&assets).await?;
         let DeserWorldWithWarnings { mut world, warnings } = tokio::task::block_in_place(|| serde_json::from_slice(&data))
             .with_context(|| format!("Failed to deserialize object2 from url {obj_url}"))?;
@@ -79,9 +237,180 @@ impl AsyncAssetKey<Result<Arc<World>, AssetError>> for PrefabFromUrl {
         for (_id, (def,), _) in query_mut((collider(),), ()).iter(&mut world, None) {
             def.resolve(&obj_url).context("Failed to resolve collider")?;
         }
+        for (_id, (url,), _) in query_mut((physics_material_from_url(),), ()).iter(&mut world, None) {
+            *url = AssetUrl::parse(&url)
+                .context("Invalid physics material url")?
+                .resolve(&obj_url)
+                .context("Failed to resolve physics material url")?
+                .into();
+        }
         for (_id, (def,), _) in query_mut((decal(),), ()).iter(&mut world, None) {
             *def = def.resolve(&obj_url).context("Failed to resolve decal")?.into();
         }
-        Ok(Arc::new(world))
+        // Prefabs can be composed from other prefabs, which are loaded and copied into this one
+        let references = query(prefab_from_url()).iter(&world, None).map(|(id, url)| (id, url.clone())).collect::<Vec<_>>();
+        stack.push(obj_url.clone());
+        for (id, url) in references {
+            let url = AssetUrl::parse(&url).context("Invalid prefab url")?.resolve(&obj_url).context("Failed to resolve prefab url")?;
+            expand_prefab_reference(&assets, &mut world, id, &url, &stack).await?;
+        }
+        Ok(world)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use ambient_core::{name, transform::translation};
+    use glam::Vec3;
+
+    use super::*;
+
+    fn init() {
+        ambient_core::init_all_components();
+        init_components();
+    }
+
+    fn named(name_: &str) -> Entity {
+        Entity::new().with(name(), name_.to_string())
+    }
+
+    /// Returns the name of each child of `id`, in order.
+    fn child_names(world: &World, id: EntityId) -> Vec<String> {
+        world.get_ref(id, children()).cloned().unwrap_or_default().into_iter().map(|c| world.get_ref(c, name()).unwrap().clone()).collect()
+    }
+
+    /// A prefab world with `root` as its root, and `root_children` as the children of the root.
+    fn prefab_world(root: Entity, root_children: Vec<Entity>) -> World {
+        let mut world = World::new("prefab_asset");
+        let root = root.spawn(&mut world);
+        for child in root_children {
+            let child = child.with(parent(), root).spawn(&mut world);
+            add_child(&mut world, root, child).unwrap();
+        }
+        world.add_resource(children(), vec![root]);
+        world
+    }
+
+    fn write_prefab(dir: &Path, id: &str, world: &World) {
+        let path = dir.join(id).join("prefabs");
+        std::fs::create_dir_all(&path).unwrap();
+        std::fs::write(path.join("main.json"), serde_json::to_vec(world).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn copy_hierarchy_keeps_the_structure() {
+        init();
+        let source = prefab_world(named("root"), vec![named("a"), named("b")]);
+        let source_root = source.resource(children())[0];
+        let mut target = World::new("target");
+        let target_parent = named("parent").spawn(&mut target);
+
+        let copy = copy_hierarchy(&source, source_root, &mut target, target_parent).unwrap();
+
+        assert_eq!(target.get_ref(target_parent, children()).unwrap(), &vec![copy]);
+        assert_eq!(target.get(copy, parent()).unwrap(), target_parent);
+        assert_eq!(target.get_ref(copy, name()).unwrap(), "root");
+        assert_eq!(child_names(&target, copy), ["a", "b"]);
+        for child in target.get_ref(copy, children()).unwrap() {
+            assert_eq!(target.get(*child, parent()).unwrap(), copy);
+        }
+    }
+
+    #[test]
+    fn reloading_keeps_runtime_changes() {
+        init();
+        let mut world = World::new("reload");
+        let id = Entity::new().spawn(&mut world);
+        apply_prefab_components(&mut world, id, named("a").with(translation(), Vec3::Z)).unwrap();
+        assert_eq!(world.get(id, translation()).unwrap(), Vec3::Z);
+
+        world.set(id, translation(), Vec3::X).unwrap();
+        apply_prefab_components(&mut world, id, named("b").with(translation(), Vec3::Y)).unwrap();
+        assert_eq!(world.get_ref(id, name()).unwrap(), "b");
+        assert_eq!(world.get(id, translation()).unwrap(), Vec3::X);
+    }
+
+    /// Applies the prefab `source` to `id`, as when it's spawned or reloaded
+    fn apply(source: &World, world: &mut World, id: EntityId) {
+        let root = source.resource(children())[0];
+        let mut data = source.clone_entity(root).unwrap();
+        let source_children = data.remove_self(children()).unwrap_or_default();
+        apply_prefab(source, world, id, data, &source_children).unwrap();
+    }
+
+    #[test]
+    fn prefab_children_are_spawned_and_updated() {
+        init();
+        let mut world = World::new("children");
+        let id = Entity::new().spawn(&mut world);
+        let own_child = named("own").with(parent(), id).spawn(&mut world);
+        add_child(&mut world, id, own_child).unwrap();
+
+        apply(&prefab_world(named("root"), vec![named("a"), named("b")]), &mut world, id);
+        assert_eq!(world.get_ref(id, name()).unwrap(), "root");
+        assert_eq!(child_names(&world, id), ["own", "a", "b"]);
+        let spawned = world.get_ref(id, children()).unwrap()[1..].to_vec();
+        for child in &spawned {
+            assert!(world.has_component(*child, prefab_child()));
+            assert_eq!(world.get(*child, parent()).unwrap(), id);
+        }
+
+        // With as many children, they're updated in place and keep their runtime changes
+        world.add_component(spawned[0], translation(), Vec3::X).unwrap();
+        apply(&prefab_world(named("root"), vec![named("c").with(translation(), Vec3::Y), named("d")]), &mut world, id);
+        assert_eq!(world.get_ref(id, children()).unwrap()[1..], spawned[..]);
+        assert_eq!(child_names(&world, id), ["own", "c", "d"]);
+        assert_eq!(world.get(spawned[0], translation()).unwrap(), Vec3::X);
+
+        // Otherwise they're replaced, and the children that aren't from the prefab are kept
+        apply(&prefab_world(named("root"), vec![named("e")]), &mut world, id);
+        assert_eq!(child_names(&world, id), ["own", "e"]);
+        assert!(spawned.iter().all(|child| !world.exists(*child)));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn prefab_reference_cycles_fail_to_load() {
+        init();
+        let dir = std::env::temp_dir().join(format!("ambient_prefab_cycle_{}", std::process::id()));
+        write_prefab(&dir, "a", &prefab_world(named("a"), vec![named("b").with(prefab_from_url(), "../../b".to_string())]));
+        write_prefab(&dir, "b", &prefab_world(named("b").with(prefab_from_url(), "../../a".to_string()), vec![]));
+
+        let assets = AssetCache::new(tokio::runtime::Handle::current());
+        let url = AbsAssetUrl::from_file_path(dir.join("a").join("prefabs").join("main.json"));
+        let err = PrefabFromUrl(url.into()).get(&assets).await.err().expect("The cycle should fail to load");
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(format!("{err:?}").contains("references itself"), "{err:?}");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn nested_prefab_references_are_expanded() {
+        init();
+        let dir = std::env::temp_dir().join(format!("ambient_prefab_nested_{}", std::process::id()));
+        // `door` is based on `frame`, and the house uses `door` as a child with its name overridden. The children of a
+        // referenced prefab are added after those of the entity referencing it
+        write_prefab(&dir, "frame", &prefab_world(named("frame").with(translation(), Vec3::Z), vec![named("hinge")]));
+        write_prefab(&dir, "door", &prefab_world(named("door").with(prefab_from_url(), "../../frame".to_string()), vec![named("handle")]));
+        write_prefab(
+            &dir,
+            "house",
+            &prefab_world(named("house"), vec![named("front door").with(prefab_from_url(), "../../door".to_string())]),
+        );
+
+        let assets = AssetCache::new(tokio::runtime::Handle::current());
+        let url = AbsAssetUrl::from_file_path(dir.join("house").join("prefabs").join("main.json"));
+        let world = PrefabFromUrl(url.into()).get(&assets).await.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let house = world.resource(children())[0];
+        assert_eq!(child_names(&world, house), ["front door"]);
+        let door = world.get_ref(house, children()).unwrap()[0];
+        assert!(!world.has_component(door, prefab_from_url()));
+        assert_eq!(world.get(door, translation()).unwrap(), Vec3::Z);
+        assert_eq!(child_names(&world, door), ["handle", "hinge"]);
+        for child in world.get_ref(door, children()).unwrap() {
+            assert_eq!(world.get(*child, parent()).unwrap(), door);
+        }
     }
 }
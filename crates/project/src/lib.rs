@@ -1,4 +1,9 @@
-use std::{collections::HashMap, fmt::Display, num::NonZeroUsize};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Display,
+    num::NonZeroUsize,
+    path::PathBuf,
+};
 
 use ambient_ecs::{
     components, Debuggable, ExternalComponentAttributes, ExternalComponentDesc, ExternalComponentFlagAttributes, Networked,
@@ -24,12 +29,64 @@ pub struct Manifest {
     pub components: HashMap<IdentifierPathBuf, NamespaceOrComponent>,
     #[serde(default)]
     pub concepts: HashMap<IdentifierPathBuf, NamespaceOrConcept>,
+    #[serde(default)]
+    pub capabilities: Capabilities,
+    #[serde(default)]
+    pub modules: BTreeMap<Identifier, Module>,
+    #[serde(default)]
+    pub dependencies: BTreeMap<Identifier, Dependency>,
+    #[serde(default)]
+    pub physics: Physics,
 }
 impl Manifest {
     pub fn parse(manifest: &str) -> Result<Self, toml::de::Error> {
         toml::from_str(manifest)
     }
 
+    /// Returns this project's modules, ordered so that each module comes after the modules it depends on.
+    ///
+    /// A project without a `[modules]` section has a single module with the same ID as the project.
+    pub fn modules_in_load_order(&self) -> Result<Vec<(Identifier, Module)>, ModuleOrderError> {
+        if self.modules.is_empty() {
+            return Ok(vec![(self.project.id.clone(), Module::default())]);
+        }
+
+        fn visit<'a>(
+            modules: &'a BTreeMap<Identifier, Module>,
+            id: &'a Identifier,
+            visiting: &mut HashSet<&'a Identifier>,
+            visited: &mut HashSet<&'a Identifier>,
+            ordered: &mut Vec<(Identifier, Module)>,
+        ) -> Result<(), ModuleOrderError> {
+            if visited.contains(id) {
+                return Ok(());
+            }
+            if !visiting.insert(id) {
+                return Err(ModuleOrderError::CircularDependency(id.clone()));
+            }
+
+            let module = &modules[id];
+            for dependency in &module.dependencies {
+                let Some((dependency, _)) = modules.get_key_value(dependency) else {
+                    return Err(ModuleOrderError::UnknownDependency { module: id.clone(), dependency: dependency.clone() });
+                };
+                visit(modules, dependency, visiting, visited, ordered)?;
+            }
+
+            visiting.remove(id);
+            visited.insert(id);
+            ordered.push((id.clone(), module.clone()));
+            Ok(())
+        }
+
+        let mut ordered = Vec::with_capacity(self.modules.len());
+        let (mut visiting, mut visited) = (HashSet::new(), HashSet::new());
+        for id in self.modules.keys() {
+            visit(&self.modules, id, &mut visiting, &mut visited, &mut ordered)?;
+        }
+        Ok(ordered)
+    }
+
     pub fn all_defined_components(&self, global_namespace: bool) -> Result<Vec<ExternalComponentDesc>, &'static str> {
         let project_path: Vec<_> = if global_namespace {
             vec![]
@@ -87,6 +144,93 @@ impl Default for BuildRust {
     }
 }
 
+/// The I/O capabilities granted to the project's server-side modules.
+/// Everything is denied by default.
+#[derive(Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct Capabilities {
+    /// Read-only access to the project's `build` directory
+    #[serde(default)]
+    pub assets: bool,
+    /// Read-write access to the project's `data` directory
+    #[serde(default)]
+    pub data: bool,
+    /// Hosts that HTTP(S) requests may be made to
+    #[serde(default)]
+    pub http: Vec<String>,
+}
+
+/// A WASM module built from a Cargo package in the project.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct Module {
+    /// The directory to build the module's package from, relative to the project. Defaults to the project root.
+    pub path: Option<PathBuf>,
+    /// Other modules in this project that must be loaded before this one
+    pub dependencies: Vec<Identifier>,
+    /// Whether the module is enabled when the project starts
+    pub enabled: bool,
+}
+impl Default for Module {
+    fn default() -> Self {
+        Self { path: None, dependencies: vec![], enabled: true }
+    }
+}
+
+/// Another Ambient project whose components and modules this project uses.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct Dependency {
+    /// The path to the other project, relative to this project
+    pub path: PathBuf,
+}
+
+/// The physics settings of the project.
+#[derive(Deserialize, Clone, Debug, PartialEq, Default)]
+#[serde(default)]
+pub struct Physics {
+    /// Named collision layers. Each layer is assigned a bit of the masks used by the `collision_layer` and `collides_with`
+    /// components, in order, after the `default` layer which uses the first bit.
+    pub collision_layers: Vec<Identifier>,
+}
+impl Physics {
+    /// The maximum number of named collision layers; the masks are 32 bits, one of which is used by the `default` layer.
+    pub const MAX_COLLISION_LAYERS: usize = 31;
+
+    /// Returns the named collision layers with their masks.
+    pub fn collision_layer_masks(&self) -> Result<Vec<(Identifier, u32)>, CollisionLayerError> {
+        if self.collision_layers.len() > Self::MAX_COLLISION_LAYERS {
+            return Err(CollisionLayerError::TooManyLayers(self.collision_layers.len()));
+        }
+        let mut seen = HashSet::new();
+        for layer in &self.collision_layers {
+            if layer.as_ref() == "default" {
+                return Err(CollisionLayerError::ReservedName);
+            }
+            if !seen.insert(layer) {
+                return Err(CollisionLayerError::DuplicateLayer(layer.clone()));
+            }
+        }
+        Ok(self.collision_layers.iter().enumerate().map(|(i, layer)| (layer.clone(), 1 << (i + 1))).collect())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum CollisionLayerError {
+    #[error("{0} collision layers were declared, but at most {} are supported", Physics::MAX_COLLISION_LAYERS)]
+    TooManyLayers(usize),
+    #[error("the `default` collision layer is always present and can't be declared")]
+    ReservedName,
+    #[error("collision layer `{0}` is declared more than once")]
+    DuplicateLayer(Identifier),
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ModuleOrderError {
+    #[error("module `{module}` depends on unknown module `{dependency}`")]
+    UnknownDependency { module: Identifier, dependency: Identifier },
+    #[error("circular dependency between modules involving `{0}`")]
+    CircularDependency(Identifier),
+}
+
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Namespace {
     pub name: String,
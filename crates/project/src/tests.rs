@@ -1,10 +1,13 @@
-use std::{collections::HashMap, num::NonZeroUsize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    num::NonZeroUsize,
+};
 
 use ambient_ecs::primitive_component_definitions;
 
 use crate::{
-    Build, BuildRust, Component, ComponentType, Concept, Identifier, IdentifierPathBuf, Manifest, Namespace, Project, Version,
-    VersionError, VersionSuffix,
+    Build, BuildRust, Capabilities, CollisionLayerError, Component, ComponentType, Concept, Identifier, IdentifierPathBuf, Manifest,
+    Module, ModuleOrderError, Namespace, Physics, Project, Version, VersionError, VersionSuffix,
 };
 
 #[test]
@@ -57,6 +60,10 @@ fn can_parse_tictactoe_toml() {
                 }
                 .into()
             )]),
+            capabilities: Capabilities::default(),
+            modules: BTreeMap::new(),
+            dependencies: BTreeMap::new(),
+            physics: Physics::default(),
         })
     )
 }
@@ -87,6 +94,10 @@ fn can_parse_rust_build_settings() {
             build: Build { rust: BuildRust { feature_multibuild: vec!["client".to_string()] } },
             components: HashMap::new(),
             concepts: HashMap::new(),
+            capabilities: Capabilities::default(),
+            modules: BTreeMap::new(),
+            dependencies: BTreeMap::new(),
+            physics: Physics::default(),
         })
     )
 }
@@ -133,6 +144,10 @@ fn can_parse_manifest_with_namespaces() {
                 )
             ]),
             concepts: HashMap::new(),
+            capabilities: Capabilities::default(),
+            modules: BTreeMap::new(),
+            dependencies: BTreeMap::new(),
+            physics: Physics::default(),
         })
     )
 }
@@ -241,10 +256,130 @@ fn can_parse_concepts_with_documented_namespace_from_manifest() {
                     .into()
                 )
             ]),
+            capabilities: Capabilities::default(),
+            modules: BTreeMap::new(),
+            dependencies: BTreeMap::new(),
+            physics: Physics::default(),
         })
     )
 }
 
+#[test]
+fn can_parse_capabilities() {
+    const TOML: &str = r#"
+    [project]
+    id = "weather"
+    version = "0.0.1"
+
+    [capabilities]
+    data = true
+    http = ["api.example.com"]
+    "#;
+
+    assert_eq!(
+        Manifest::parse(TOML).map(|m| m.capabilities),
+        Ok(Capabilities { assets: false, data: true, http: vec!["api.example.com".to_string()] })
+    )
+}
+
+#[test]
+fn can_assign_collision_layer_masks() {
+    const TOML: &str = r#"
+    [project]
+    id = "arena"
+    version = "0.0.1"
+
+    [physics]
+    collision_layers = ["player", "projectile"]
+    "#;
+
+    assert_eq!(
+        Manifest::parse(TOML).unwrap().physics.collision_layer_masks(),
+        Ok(vec![(Identifier::new("player").unwrap(), 0b10), (Identifier::new("projectile").unwrap(), 0b100)])
+    );
+
+    let duplicate = Physics { collision_layers: vec![Identifier::new("player").unwrap(), Identifier::new("player").unwrap()] };
+    assert_eq!(duplicate.collision_layer_masks(), Err(CollisionLayerError::DuplicateLayer(Identifier::new("player").unwrap())));
+
+    let too_many = Physics { collision_layers: (0..32).map(|i| Identifier::new(format!("layer_{i}")).unwrap()).collect() };
+    assert_eq!(too_many.collision_layer_masks(), Err(CollisionLayerError::TooManyLayers(32)));
+}
+
+#[test]
+fn can_order_modules_by_dependencies() {
+    const TOML: &str = r#"
+    [project]
+    id = "arena"
+    version = "0.0.1"
+
+    [modules.gameplay]
+    dependencies = ["ai", "physics_helpers"]
+
+    [modules.ai]
+    path = "modules/ai"
+    dependencies = ["physics_helpers"]
+
+    [modules.admin]
+    enabled = false
+
+    [modules.physics_helpers]
+    "#;
+
+    let manifest = Manifest::parse(TOML).unwrap();
+    assert_eq!(
+        manifest.modules.get(&Identifier::new("ai").unwrap()),
+        Some(&Module { path: Some("modules/ai".into()), dependencies: vec![Identifier::new("physics_helpers").unwrap()], enabled: true })
+    );
+
+    let order = manifest.modules_in_load_order().unwrap().into_iter().map(|(id, _)| id.to_string()).collect::<Vec<_>>();
+    assert_eq!(order, ["admin", "physics_helpers", "ai", "gameplay"]);
+}
+
+#[test]
+fn project_without_modules_has_one_module() {
+    const TOML: &str = r#"
+    [project]
+    id = "tictactoe"
+    version = "0.0.1"
+    "#;
+
+    assert_eq!(
+        Manifest::parse(TOML).unwrap().modules_in_load_order(),
+        Ok(vec![(Identifier::new("tictactoe").unwrap(), Module::default())])
+    );
+}
+
+#[test]
+fn rejects_invalid_module_dependencies() {
+    const CIRCULAR: &str = r#"
+    [project]
+    id = "arena"
+    version = "0.0.1"
+
+    [modules.a]
+    dependencies = ["b"]
+    [modules.b]
+    dependencies = ["a"]
+    "#;
+    assert_eq!(
+        Manifest::parse(CIRCULAR).unwrap().modules_in_load_order(),
+        Err(ModuleOrderError::CircularDependency(Identifier::new("a").unwrap()))
+    );
+
+    const UNKNOWN: &str = r#"
+    [project]
+    id = "arena"
+    version = "0.0.1"
+
+    [modules.a]
+    dependencies = ["missing"]
+    "#;
+    assert_eq!(
+        Manifest::parse(UNKNOWN).unwrap().modules_in_load_order(),
+        Err(ModuleOrderError::UnknownDependency { module: Identifier::new("a").unwrap(), dependency: Identifier::new("missing").unwrap() })
+    );
+}
+
 #[test]
 fn can_validate_identifiers() {
     use Identifier as I;
@@ -15,7 +15,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::primitives;
 
-const MAX_LOD_LEVELS: usize = 16;
+pub const MAX_LOD_LEVELS: usize = 16;
 #[repr(transparent)]
 /// Represents clip space size cutoffs for the lod levels.
 ///
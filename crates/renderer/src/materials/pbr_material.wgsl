@@ -39,7 +39,9 @@ fn get_material(in: MaterialInput) -> MaterialOutput {
     out.base_color = color.rgb;
     out.emissive_factor = pbr_params.emissive_factor.rgb;
     out.shading = 1.;
-    let normal = textureSample(normal_texture, base_color_sampler, in.texcoord).xyz * 2. - 1.;
+    // Only x and y are read, so that two channel (BC5) normal maps work too
+    let normal_xy = textureSample(normal_texture, base_color_sampler, in.texcoord).xy * 2. - 1.;
+    let normal = vec3<f32>(normal_xy, sqrt(max(0., 1. - dot(normal_xy, normal_xy))));
     out.normal = in.normal_matrix * normal;
     return out;
 }
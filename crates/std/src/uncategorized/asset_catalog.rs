@@ -0,0 +1,99 @@
+use std::path::Path;
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::asset_url::AssetType;
+
+/// Every asset produced by a build, written to `build/assets.json` so that tooling and modules can find assets
+/// without hard-coding their paths.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AssetCatalog {
+    pub assets: Vec<CatalogAsset>,
+}
+impl AssetCatalog {
+    pub const FILENAME: &'static str = "assets.json";
+
+    /// Loads the catalog from `build_path`. A project without a catalog has an empty one.
+    pub fn load(build_path: &Path) -> anyhow::Result<Self> {
+        let path = build_path.join(Self::FILENAME);
+        match std::fs::read(&path) {
+            Ok(data) => serde_json::from_slice(&data).with_context(|| format!("Failed to parse the asset catalog at {path:?}")),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err).with_context(|| format!("Failed to read {path:?}")),
+        }
+    }
+
+    pub fn save(&self, build_path: &Path) -> anyhow::Result<()> {
+        std::fs::write(build_path.join(Self::FILENAME), serde_json::to_vec_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Returns the assets that are not hidden and match every filter given.
+    pub fn query<'a>(
+        &'a self,
+        asset_type: Option<AssetType>,
+        tag: Option<&'a str>,
+        category: Option<&'a str>,
+    ) -> impl Iterator<Item = &'a CatalogAsset> {
+        self.assets.iter().filter(move |asset| {
+            !asset.hidden
+                && asset_type.map_or(true, |t| asset.type_ == t)
+                && tag.map_or(true, |tag| asset.tags.iter().any(|t| t == tag))
+                && category.map_or(true, |category| asset.categories.iter().any(|c| c == category))
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CatalogAsset {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: AssetType,
+    pub name: String,
+    /// If this asset is not displayed in search results
+    #[serde(default)]
+    pub hidden: bool,
+    pub tags: Vec<String>,
+    /// Every category level this asset is in, i.e. `["Vehicles", "Vehicles > Cars"]`
+    pub categories: Vec<String>,
+    /// The content of this asset, relative to the build directory. Collections have no content of their own.
+    pub content: Option<String>,
+    /// The ids of the assets in this collection
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub collection: Vec<String>,
+    /// The file this asset was built from, relative to the project's `assets` directory if it is within it
+    pub source: Option<String>,
+    /// A preview image of this asset, relative to the build directory
+    pub preview: Option<String>,
+}
+
+#[test]
+fn test_query() {
+    let asset = |id: &str, type_: AssetType, tags: &[&str], hidden: bool| CatalogAsset {
+        id: id.to_string(),
+        type_,
+        name: id.to_string(),
+        hidden,
+        tags: tags.iter().map(|t| t.to_string()).collect(),
+        categories: vec!["Nature".to_string(), "Nature > Trees".to_string()],
+        content: Some(format!("assets/{id}/prefabs/main.json")),
+        collection: vec![],
+        source: None,
+        preview: None,
+    };
+    let catalog = AssetCatalog {
+        assets: vec![
+            asset("oak", AssetType::Prefab, &["tree"], false),
+            asset("oak_model", AssetType::Model, &["tree"], false),
+            asset("rock", AssetType::Prefab, &["stone"], false),
+            asset("pine", AssetType::Prefab, &["tree"], true),
+        ],
+    };
+
+    let ids = |assets: Vec<&CatalogAsset>| assets.into_iter().map(|a| a.id.as_str()).collect::<Vec<_>>();
+    assert_eq!(ids(catalog.query(Some(AssetType::Prefab), Some("tree"), None).collect()), ["oak"]);
+    assert_eq!(ids(catalog.query(None, Some("tree"), None).collect()), ["oak", "oak_model"]);
+    assert_eq!(ids(catalog.query(None, None, Some("Nature > Trees")).collect()), ["oak", "oak_model", "rock"]);
+    assert!(catalog.query(None, None, Some("Vehicles")).next().is_none());
+}
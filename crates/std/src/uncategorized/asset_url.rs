@@ -13,6 +13,7 @@ use serde::{
     de::{DeserializeOwned, Visitor},
     Deserialize, Deserializer, Serialize, Serializer,
 };
+use strum::{EnumIter, IntoEnumIterator};
 use url::Url;
 
 use crate::{
@@ -454,7 +455,7 @@ impl<T: GetAssetType> PartialEq for AssetUrlCollection<T> {
 }
 impl<T: GetAssetType> Eq for AssetUrlCollection<T> {}
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, EnumIter)]
 pub enum AssetType {
     AssetCrate,
     Prefab,
@@ -479,6 +480,9 @@ impl AssetType {
     pub fn to_snake_case(&self) -> String {
         format!("{self:?}").to_case(Case::Snake)
     }
+    pub fn from_snake_case(name: &str) -> Option<Self> {
+        Self::iter().find(|t| t.to_snake_case() == name)
+    }
 }
 
 pub trait GetAssetType: std::fmt::Debug + Clone + Sync + Send {
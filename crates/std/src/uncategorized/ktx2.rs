@@ -0,0 +1,193 @@
+use anyhow::Context;
+
+/// The formats a [Ktx2] texture can be stored in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ktx2Format {
+    Rgba8Unorm,
+    Rgba8Srgb,
+    Bc4Unorm,
+    Bc5Unorm,
+    Bc7Unorm,
+    Bc7Srgb,
+}
+impl Ktx2Format {
+    const ALL: [Self; 6] = [Self::Rgba8Unorm, Self::Rgba8Srgb, Self::Bc4Unorm, Self::Bc5Unorm, Self::Bc7Unorm, Self::Bc7Srgb];
+
+    /// The `VkFormat` that identifies this format in a KTX2 file.
+    pub fn vk_format(self) -> u32 {
+        match self {
+            Self::Rgba8Unorm => 37,
+            Self::Rgba8Srgb => 43,
+            Self::Bc4Unorm => 139,
+            Self::Bc5Unorm => 141,
+            Self::Bc7Unorm => 145,
+            Self::Bc7Srgb => 146,
+        }
+    }
+    pub fn from_vk_format(vk_format: u32) -> Option<Self> {
+        Self::ALL.into_iter().find(|format| format.vk_format() == vk_format)
+    }
+    /// Block compressed formats store the texture in blocks of 4x4 pixels.
+    pub fn is_compressed(self) -> bool {
+        !matches!(self, Self::Rgba8Unorm | Self::Rgba8Srgb)
+    }
+    pub fn is_srgb(self) -> bool {
+        matches!(self, Self::Rgba8Srgb | Self::Bc7Srgb)
+    }
+    /// The size in bytes of a block for compressed formats, or of a pixel otherwise.
+    pub fn block_size(self) -> usize {
+        match self {
+            Self::Rgba8Unorm | Self::Rgba8Srgb => 4,
+            Self::Bc4Unorm => 8,
+            Self::Bc5Unorm | Self::Bc7Unorm | Self::Bc7Srgb => 16,
+        }
+    }
+    /// The size in bytes of a mip level of `width` by `height` pixels.
+    pub fn level_size(self, width: u32, height: u32) -> usize {
+        if self.is_compressed() {
+            ((width + 3) / 4) as usize * ((height + 3) / 4) as usize * self.block_size()
+        } else {
+            width as usize * height as usize * self.block_size()
+        }
+    }
+
+    /// The data format descriptor, which describes the layout of the texel blocks in a KTX2 file.
+    fn data_format_descriptor(self) -> Vec<u8> {
+        const MODEL_RGBSDA: u8 = 1;
+        const MODEL_BC4: u8 = 131;
+        const MODEL_BC5: u8 = 132;
+        const MODEL_BC7: u8 = 134;
+        const CHANNEL_ALPHA: u8 = 15;
+        // Set on the alpha channel of sRGB formats, as alpha is never sRGB encoded
+        const SAMPLE_LINEAR: u8 = 1 << 4;
+
+        // (bit offset, bit length, channel, lower, upper)
+        let (model, samples): (u8, Vec<(u16, u8, u8, u32, u32)>) = match self {
+            Self::Rgba8Unorm | Self::Rgba8Srgb => {
+                let alpha = if self.is_srgb() { CHANNEL_ALPHA | SAMPLE_LINEAR } else { CHANNEL_ALPHA };
+                (MODEL_RGBSDA, vec![(0, 8, 0, 0, 255), (8, 8, 1, 0, 255), (16, 8, 2, 0, 255), (24, 8, alpha, 0, 255)])
+            }
+            Self::Bc4Unorm => (MODEL_BC4, vec![(0, 64, 0, 0, u32::MAX)]),
+            Self::Bc5Unorm => (MODEL_BC5, vec![(0, 64, 0, 0, u32::MAX), (64, 64, 1, 0, u32::MAX)]),
+            Self::Bc7Unorm | Self::Bc7Srgb => (MODEL_BC7, vec![(0, 128, 0, 0, u32::MAX)]),
+        };
+        let block_dimension = if self.is_compressed() { 3 } else { 0 };
+        let block_size = 24 + 16 * samples.len() as u32;
+
+        let mut res = Vec::new();
+        res.extend((4 + block_size).to_le_bytes());
+        // Vendor and descriptor type
+        res.extend(0u32.to_le_bytes());
+        // Version 2, and the size of this block
+        res.extend((2 | (block_size << 16)).to_le_bytes());
+        // BT.709 primaries, and sRGB or linear transfer function
+        res.extend([model, 1, if self.is_srgb() { 2 } else { 1 }, 0]);
+        res.extend([block_dimension, block_dimension, 0, 0]);
+        res.extend([self.block_size() as u8, 0, 0, 0, 0, 0, 0, 0]);
+        for (offset, length, channel, lower, upper) in samples {
+            res.extend(offset.to_le_bytes());
+            res.extend([length - 1, channel]);
+            res.extend([0; 4]);
+            res.extend(lower.to_le_bytes());
+            res.extend(upper.to_le_bytes());
+        }
+        res
+    }
+}
+
+/// A 2D texture and its mip levels, stored in the KTX2 container format.
+///
+/// Only the subset of KTX2 produced by the asset pipelines is supported: a single layer and face, in one of the
+/// [Ktx2Format]s, without supercompression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ktx2 {
+    pub format: Ktx2Format,
+    pub width: u32,
+    pub height: u32,
+    /// The data of each mip level, starting with the full size one
+    pub levels: Vec<Vec<u8>>,
+}
+impl Ktx2 {
+    pub const EXTENSION: &'static str = "ktx2";
+    const IDENTIFIER: [u8; 12] = [0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A];
+    const HEADER_SIZE: usize = 80;
+    const LEVEL_INDEX_ENTRY_SIZE: usize = 24;
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let dfd = self.format.data_format_descriptor();
+        let dfd_offset = Self::HEADER_SIZE + self.levels.len() * Self::LEVEL_INDEX_ENTRY_SIZE;
+
+        // The levels are stored from the smallest to the largest, each aligned to the size of a block
+        let mut level_offsets = vec![0; self.levels.len()];
+        let mut end = dfd_offset + dfd.len();
+        for (i, level) in self.levels.iter().enumerate().rev() {
+            let alignment = self.format.block_size();
+            level_offsets[i] = (end + alignment - 1) / alignment * alignment;
+            end = level_offsets[i] + level.len();
+        }
+
+        let mut res = Vec::with_capacity(end);
+        res.extend(Self::IDENTIFIER);
+        // Format, type size, width, height, depth, layers, faces, levels and supercompression
+        for value in [self.format.vk_format(), 1, self.width, self.height, 0, 0, 1, self.levels.len() as u32, 0] {
+            res.extend(value.to_le_bytes());
+        }
+        // The data format descriptor, and the empty key/value data
+        for value in [dfd_offset as u32, dfd.len() as u32, 0, 0] {
+            res.extend(value.to_le_bytes());
+        }
+        // The empty supercompression global data
+        for value in [0u64, 0u64] {
+            res.extend(value.to_le_bytes());
+        }
+        for (offset, level) in level_offsets.iter().zip(&self.levels) {
+            for value in [*offset as u64, level.len() as u64, level.len() as u64] {
+                res.extend(value.to_le_bytes());
+            }
+        }
+        res.extend(dfd);
+        for (offset, level) in level_offsets.iter().zip(&self.levels).rev() {
+            res.resize(*offset, 0);
+            res.extend(level);
+        }
+        res
+    }
+
+    pub fn from_bytes(data: &[u8]) -> anyhow::Result<Self> {
+        anyhow::ensure!(data.starts_with(&Self::IDENTIFIER), "Not a KTX2 file");
+        let bytes = |offset: usize, len: usize| data.get(offset..offset.saturating_add(len)).context("Truncated KTX2 file");
+        let read_u32 = |offset: usize| -> anyhow::Result<u32> { Ok(u32::from_le_bytes(bytes(offset, 4)?.try_into().unwrap())) };
+        let read_u64 = |offset: usize| -> anyhow::Result<usize> { Ok(u64::from_le_bytes(bytes(offset, 8)?.try_into().unwrap()) as usize) };
+
+        let vk_format = read_u32(12)?;
+        let format = Ktx2Format::from_vk_format(vk_format).with_context(|| format!("Unsupported KTX2 format {vk_format}"))?;
+        let (width, height) = (read_u32(20)?, read_u32(24)?);
+        anyhow::ensure!(read_u32(28)? == 0 && read_u32(32)? <= 1 && read_u32(36)? == 1, "Only 2D KTX2 textures are supported");
+        anyhow::ensure!(read_u32(44)? == 0, "Supercompressed KTX2 textures are not supported");
+
+        let levels = (0..read_u32(40)?.max(1) as usize)
+            .map(|i| {
+                let entry = Self::HEADER_SIZE + i * Self::LEVEL_INDEX_ENTRY_SIZE;
+                let level = bytes(read_u64(entry)?, read_u64(entry + 8)?)?;
+                let expected = format.level_size((width >> i).max(1), (height >> i).max(1));
+                anyhow::ensure!(level.len() == expected, "Mip level {i} is {} bytes, expected {expected}", level.len());
+                Ok(level.to_vec())
+            })
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Self { format, width, height, levels })
+    }
+}
+
+#[test]
+fn test_roundtrip() {
+    for format in Ktx2Format::ALL {
+        let (width, height) = (10, 4);
+        let levels = (0..4)
+            .map(|i| (0..format.level_size((width >> i).max(1), (height >> i).max(1))).map(|j| (i * 7 + j) as u8).collect())
+            .collect();
+        let texture = Ktx2 { format, width, height, levels };
+        let data = texture.to_bytes();
+        assert_eq!(Ktx2::from_bytes(&data).unwrap(), texture);
+        assert!(Ktx2::from_bytes(&data[..data.len() - 1]).is_err());
+    }
+}
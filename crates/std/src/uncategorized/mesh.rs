@@ -154,6 +154,10 @@ impl Mesh {
             .normals
             .as_mut()
             .map(|normals| normals.drain(..).enumerate().filter_map(|(i, v)| if used[i] { Some(v) } else { None }).collect());
+        self.tangents = self
+            .tangents
+            .as_mut()
+            .map(|tangents| tangents.drain(..).enumerate().filter_map(|(i, v)| if used[i] { Some(v) } else { None }).collect());
         self.joint_indices = self
             .joint_indices
             .as_mut()
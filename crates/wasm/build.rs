@@ -42,33 +42,36 @@ fn main() {
     {
         // HACK: Build wit files ahead of time so that we don't need to use a macro in the guest code.
         if guest_path.file_name().unwrap_or_default() == "rust" {
-            use wit_bindgen_core::{wit_parser::Resolve, Files};
-
-            let mut generator = wit_bindgen_rust::Opts::default().build();
-            let mut resolve = Resolve::new();
-            let pkg = resolve.push_dir(Path::new("wit")).unwrap().0;
-
-            let mut files = Files::default();
-            let world = resolve.select_world(pkg, Some("main.bindings")).unwrap();
-            generator.generate(&resolve, world, &mut files);
-
-            for (filename, contents) in files.iter() {
-                std::fs::write(
-                    guest_path
-                        .join("api")
-                        .join("src")
-                        .join("internal")
-                        .join(filename),
-                    contents,
-                )
-                .unwrap();
-            }
+            generate_rust_bindings(
+                "main.bindings",
+                &guest_path.join("api").join("src").join("internal"),
+            );
+            generate_rust_bindings(
+                "pipeline.processor",
+                &guest_path.join("pipeline_api").join("src"),
+            );
         } else {
             copy_files(&guest_path, &files, &working_dir);
         }
     }
 }
 
+fn generate_rust_bindings(world: &str, target_dir: &Path) {
+    use wit_bindgen_core::{wit_parser::Resolve, Files};
+
+    let mut generator = wit_bindgen_rust::Opts::default().build();
+    let mut resolve = Resolve::new();
+    let pkg = resolve.push_dir(Path::new("wit")).unwrap().0;
+
+    let mut files = Files::default();
+    let world = resolve.select_world(pkg, Some(world)).unwrap();
+    generator.generate(&resolve, world, &mut files);
+
+    for (filename, contents) in files.iter() {
+        std::fs::write(target_dir.join(filename), contents).unwrap();
+    }
+}
+
 fn copy_files(guest_path: &Path, files: &[File], working_dir: &Path) {
     let target_wit_dir = guest_path.join("api").join("wit");
     std::fs::create_dir_all(&target_wit_dir).unwrap();
@@ -1,10 +1,17 @@
-use crate::shared::{self, client_bytecode_from_url, module_bytecode, wit, ModuleBytecode};
-use ambient_core::{asset_cache, async_ecs::async_run, runtime};
+use crate::shared::{self, client_bytecode_from_url, conversion::FromBindgen, wit, ModuleBytecode};
+use ambient_core::{
+    asset_cache, async_ecs::async_run, is_reloaded_asset, reloaded_assets, runtime,
+};
 use ambient_ecs::{query, EntityId, SystemGroup, World};
 use ambient_std::{
     asset_cache::AsyncAssetKeyExt, asset_url::AbsAssetUrl, download_asset::BytesFromUrl,
 };
-use std::sync::Arc;
+use ambient_world_audio::{
+    audio_emitter, audio_mixer, core::AudioEmitter, get_sound_instance, play_url_on_entity,
+    AudioSeed, SoundInstance, SoundInstanceId,
+};
+use parking_lot::Mutex;
+use std::{sync::Arc, time::Duration};
 
 pub fn initialize(
     world: &mut World,
@@ -43,19 +50,37 @@ pub fn systems() -> SystemGroup {
                             }
                             Ok(bytecode) => {
                                 async_run.run(move |world| {
-                                    world
-                                        .add_component(
-                                            id,
-                                            module_bytecode(),
-                                            ModuleBytecode(bytecode.to_vec()),
-                                        )
-                                        .ok();
+                                    shared::update_module_bytecode(
+                                        world,
+                                        id,
+                                        ModuleBytecode(bytecode.to_vec()),
+                                    );
                                 });
                             }
                         }
                     });
                 }
             }),
+            query(reloaded_assets().changed()).to_system(|q, world, qs, _| {
+                for (_, reloaded) in q.collect_cloned(world, qs) {
+                    let modules = query(client_bytecode_from_url())
+                        .iter(world, None)
+                        .filter(|(_, url)| is_reloaded_asset(url, &reloaded))
+                        .map(|(id, url)| (id, url.clone()))
+                        .collect::<Vec<_>>();
+                    if modules.is_empty() {
+                        continue;
+                    }
+
+                    world
+                        .resource(asset_cache())
+                        .invalidate(|key| is_reloaded_asset(key, &reloaded));
+                    for (id, url) in modules {
+                        // Setting the URL again marks it as changed, which downloads and reloads the module above
+                        world.set(id, client_bytecode_from_url(), url).ok();
+                    }
+                }
+            }),
             Box::new(shared::systems()),
         ],
     )
@@ -73,6 +98,9 @@ impl Bindings {
     pub fn world_mut(&mut self) -> &mut World {
         unsafe { self.world_ref.world_mut() }
     }
+    fn sound(&self, sound: u64) -> Option<SoundInstance> {
+        get_sound_instance(self.world(), SoundInstanceId(sound))
+    }
 }
 
 impl shared::bindings::BindingsBound for Bindings {
@@ -256,6 +284,20 @@ impl wit::event::Host for Bindings {
         )
     }
 }
+impl wit::profiling::Host for Bindings {
+    fn begin_scope(&mut self, name: String) -> anyhow::Result<()> {
+        shared::implementation::profiling::begin_scope(
+            unsafe { self.world_ref.world() },
+            self.base.module_id,
+            &mut self.base.profiling_scopes,
+            name,
+        )
+    }
+
+    fn end_scope(&mut self) -> anyhow::Result<()> {
+        shared::implementation::profiling::end_scope(&mut self.base.profiling_scopes)
+    }
+}
 
 fn unsupported<T>() -> anyhow::Result<T> {
     anyhow::bail!("This function is not supported on this side of the API. Please report this if you were able to access this function.")
@@ -320,6 +362,7 @@ impl wit::server_physics::Host for Bindings {
         &mut self,
         _origin: wit::types::Vec3,
         _direction: wit::types::Vec3,
+        _layers: Option<u32>,
     ) -> anyhow::Result<Option<(wit::types::EntityId, f32)>> {
         unsupported()
     }
@@ -328,6 +371,7 @@ impl wit::server_physics::Host for Bindings {
         &mut self,
         _origin: wit::types::Vec3,
         _direction: wit::types::Vec3,
+        _layers: Option<u32>,
     ) -> anyhow::Result<Vec<(wit::types::EntityId, f32)>> {
         unsupported()
     }
@@ -338,13 +382,205 @@ impl wit::server_physics::Host for Bindings {
         _displacement: wit::types::Vec3,
         _min_dist: f32,
         _elapsed_time: f32,
+        _layers: Option<u32>,
     ) -> anyhow::Result<wit::server_physics::CharacterCollision> {
         unsupported()
     }
+
+    fn sweep(
+        &mut self,
+        _shape: wit::server_physics::QueryShape,
+        _position: wit::types::Vec3,
+        _rotation: wit::types::Quat,
+        _direction: wit::types::Vec3,
+        _max_distance: f32,
+        _scene: Option<wit::server_physics::ColliderScene>,
+        _layers: Option<u32>,
+    ) -> anyhow::Result<Vec<wit::server_physics::SweepHit>> {
+        unsupported()
+    }
+
+    fn overlap(
+        &mut self,
+        _shape: wit::server_physics::QueryShape,
+        _position: wit::types::Vec3,
+        _rotation: wit::types::Quat,
+        _scene: Option<wit::server_physics::ColliderScene>,
+        _layers: Option<u32>,
+    ) -> anyhow::Result<Vec<wit::types::EntityId>> {
+        unsupported()
+    }
+
+    fn create_joint(
+        &mut self,
+        _joint_type: wit::server_physics::JointType,
+        _entity0: wit::types::EntityId,
+        _entity1: Option<wit::types::EntityId>,
+        _position: wit::types::Vec3,
+        _rotation: wit::types::Quat,
+    ) -> anyhow::Result<wit::types::EntityId> {
+        unsupported()
+    }
+
+    fn break_joint(&mut self, _joint: wit::types::EntityId) -> anyhow::Result<()> {
+        unsupported()
+    }
+
+    fn set_linear_velocity(
+        &mut self,
+        _entity: wit::types::EntityId,
+        _velocity: wit::types::Vec3,
+    ) -> anyhow::Result<()> {
+        unsupported()
+    }
+
+    fn set_angular_velocity(
+        &mut self,
+        _entity: wit::types::EntityId,
+        _velocity: wit::types::Vec3,
+    ) -> anyhow::Result<()> {
+        unsupported()
+    }
+
+    fn get_contacts(
+        &mut self,
+        _entity: wit::types::EntityId,
+    ) -> anyhow::Result<Vec<wit::server_physics::Contact>> {
+        unsupported()
+    }
 }
 
 impl wit::server_asset::Host for Bindings {
     fn url(&mut self, _path: String) -> anyhow::Result<Option<String>> {
         unsupported()
     }
+
+    fn catalog(
+        &mut self,
+        _asset_type: Option<wit::server_asset::AssetType>,
+        _tag: Option<String>,
+        _category: Option<String>,
+    ) -> anyhow::Result<Vec<wit::server_asset::CatalogAsset>> {
+        unsupported()
+    }
+}
+impl wit::server_io::Host for Bindings {
+    fn read_asset(&mut self, _path: String) -> anyhow::Result<Result<u64, String>> {
+        unsupported()
+    }
+
+    fn read_data(&mut self, _path: String) -> anyhow::Result<Result<u64, String>> {
+        unsupported()
+    }
+
+    fn write_data(&mut self, _path: String, _data: Vec<u8>) -> anyhow::Result<Result<u64, String>> {
+        unsupported()
+    }
+
+    fn http_get(&mut self, _url: String) -> anyhow::Result<Result<u64, String>> {
+        unsupported()
+    }
+
+    fn http_post(&mut self, _url: String, _body: Vec<u8>) -> anyhow::Result<Result<u64, String>> {
+        unsupported()
+    }
+
+    fn poll(&mut self, _request: u64) -> anyhow::Result<Option<Result<Vec<u8>, String>>> {
+        unsupported()
+    }
+}
+impl wit::server_storage::Host for Bindings {
+    fn get(&mut self, _key: String) -> anyhow::Result<Option<Vec<u8>>> {
+        unsupported()
+    }
+
+    fn set(&mut self, _key: String, _value: Vec<u8>) -> anyhow::Result<Result<(), String>> {
+        unsupported()
+    }
+
+    fn delete(&mut self, _key: String) -> anyhow::Result<Result<(), String>> {
+        unsupported()
+    }
+
+    fn list_prefix(&mut self, _prefix: String) -> anyhow::Result<Vec<String>> {
+        unsupported()
+    }
+
+    fn write_batch(
+        &mut self,
+        _writes: Vec<wit::server_storage::BatchWrite>,
+    ) -> anyhow::Result<Result<(), String>> {
+        unsupported()
+    }
+}
+
+impl wit::client_audio::Host for Bindings {
+    fn play(
+        &mut self,
+        entity: wit::types::EntityId,
+        url: String,
+    ) -> anyhow::Result<Result<u64, String>> {
+        let world = self.world_mut();
+        let entity = entity.from_bindgen();
+        if world.resource_opt(audio_mixer()).is_none() {
+            return Ok(Err("Audio is not available on this client".to_string()));
+        }
+        let url = match AbsAssetUrl::parse(&url) {
+            Ok(url) => url,
+            Err(err) => return Ok(Err(format!("Invalid url {url:?}: {err:#}"))),
+        };
+        if !world.has_component(entity, audio_emitter()) {
+            let emitter = Arc::new(Mutex::new(AudioEmitter::default()));
+            if let Err(err) = world.add_component(entity, audio_emitter(), emitter) {
+                return Ok(Err(err.to_string()));
+            }
+        }
+        Ok(play_url_on_entity(world, entity, url, AudioSeed::new())
+            .map(|sound| sound.0)
+            .map_err(|err| format!("{err:#}")))
+    }
+
+    fn stop(&mut self, sound: u64, fade_out: f32) -> anyhow::Result<()> {
+        if let Some(sound) = self.sound(sound) {
+            sound.stop(Duration::try_from_secs_f32(fade_out).unwrap_or_default());
+        }
+        Ok(())
+    }
+
+    fn set_gain(&mut self, sound: u64, gain: f32) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            gain.is_finite(),
+            "The gain of a sound must be finite, not {gain}"
+        );
+        if let Some(sound) = self.sound(sound) {
+            sound.set_gain(gain);
+        }
+        Ok(())
+    }
+
+    fn set_pitch(&mut self, sound: u64, pitch: f32) -> anyhow::Result<()> {
+        if let Some(sound) = self.sound(sound) {
+            sound.set_pitch(pitch);
+        }
+        Ok(())
+    }
+
+    fn set_cutoff(&mut self, sound: u64, cutoff: Option<f32>) -> anyhow::Result<()> {
+        if let Some(cutoff) = cutoff {
+            anyhow::ensure!(
+                cutoff.is_finite() && cutoff > 0.,
+                "The cutoff of a sound must be a positive frequency, not {cutoff}"
+            );
+        }
+        if let Some(sound) = self.sound(sound) {
+            sound.set_cutoff(cutoff);
+        }
+        Ok(())
+    }
+
+    fn is_playing(&mut self, sound: u64) -> anyhow::Result<bool> {
+        Ok(self
+            .sound(sound)
+            .map_or(false, |sound| !sound.is_finished()))
+    }
 }
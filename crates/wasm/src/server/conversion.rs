@@ -1,4 +1,14 @@
-use crate::shared::{conversion::IntoBindgen, wit};
+use ambient_physics::{
+    helpers::{EntityContact, JointType},
+    intersection::{QueryShape, SweepHit},
+    ColliderScene,
+};
+use ambient_std::asset_url::AssetType;
+
+use crate::shared::{
+    conversion::{FromBindgen, IntoBindgen},
+    wit,
+};
 
 impl IntoBindgen for ambient_input::PlayerRawInput {
     type Item = wit::server_player::RawInput;
@@ -387,3 +397,117 @@ impl IntoBindgen for ambient_window_types::MouseButton {
         }
     }
 }
+
+impl FromBindgen for wit::server_physics::ColliderScene {
+    type Item = ColliderScene;
+
+    fn from_bindgen(self) -> Self::Item {
+        match self {
+            Self::Physics => ColliderScene::Physics,
+            Self::TriggerArea => ColliderScene::TriggerArea,
+            Self::Picking => ColliderScene::Picking,
+        }
+    }
+}
+
+impl FromBindgen for wit::server_physics::QueryShape {
+    type Item = QueryShape;
+
+    fn from_bindgen(self) -> Self::Item {
+        match self {
+            Self::Sphere(radius) => QueryShape::Sphere { radius },
+            Self::Cuboid(half_extents) => QueryShape::Box {
+                half_extents: half_extents.from_bindgen(),
+            },
+            Self::Capsule(capsule) => QueryShape::Capsule {
+                radius: capsule.radius,
+                half_height: capsule.half_height,
+            },
+        }
+    }
+}
+
+impl FromBindgen for wit::server_physics::JointType {
+    type Item = JointType;
+
+    fn from_bindgen(self) -> Self::Item {
+        match self {
+            Self::Fixed => JointType::Fixed,
+            Self::Revolute => JointType::Revolute,
+            Self::Prismatic => JointType::Prismatic,
+            Self::Spherical => JointType::Spherical,
+            Self::Distance => JointType::Distance,
+        }
+    }
+}
+
+impl IntoBindgen for SweepHit {
+    type Item = wit::server_physics::SweepHit;
+
+    fn into_bindgen(self) -> Self::Item {
+        Self::Item {
+            entity: self.entity.into_bindgen(),
+            position: self.position.into_bindgen(),
+            normal: self.normal.into_bindgen(),
+            distance: self.distance,
+        }
+    }
+}
+
+impl IntoBindgen for EntityContact {
+    type Item = wit::server_physics::Contact;
+
+    fn into_bindgen(self) -> Self::Item {
+        Self::Item {
+            other: self.other.into_bindgen(),
+            position: self.position.into_bindgen(),
+            normal: self.normal.into_bindgen(),
+            separation: self.separation,
+            impulse: self.impulse.into_bindgen(),
+        }
+    }
+}
+
+impl IntoBindgen for AssetType {
+    type Item = wit::server_asset::AssetType;
+
+    fn into_bindgen(self) -> Self::Item {
+        match self {
+            Self::AssetCrate => Self::Item::AssetCrate,
+            Self::Prefab => Self::Item::Prefab,
+            Self::ScriptBundle => Self::Item::ScriptBundle,
+            Self::Model => Self::Item::Model,
+            Self::Image => Self::Item::Image,
+            Self::Animation => Self::Item::Animation,
+            Self::Material => Self::Item::Material,
+            Self::Collider => Self::Item::Collider,
+            Self::TerrainMaterial => Self::Item::TerrainMaterial,
+            Self::Atmosphere => Self::Item::Atmosphere,
+            Self::Biomes => Self::Item::Biomes,
+            Self::VorbisTrack => Self::Item::VorbisTrack,
+            Self::SoundGraph => Self::Item::SoundGraph,
+        }
+    }
+}
+
+impl FromBindgen for wit::server_asset::AssetType {
+    type Item = AssetType;
+
+    fn from_bindgen(self) -> Self::Item {
+        match self {
+            Self::AssetCrate => AssetType::AssetCrate,
+            Self::Prefab => AssetType::Prefab,
+            Self::ScriptBundle => AssetType::ScriptBundle,
+            Self::Model => AssetType::Model,
+            Self::Image => AssetType::Image,
+            Self::Animation => AssetType::Animation,
+            Self::Material => AssetType::Material,
+            Self::Collider => AssetType::Collider,
+            Self::TerrainMaterial => AssetType::TerrainMaterial,
+            Self::Atmosphere => AssetType::Atmosphere,
+            Self::Biomes => AssetType::Biomes,
+            Self::VorbisTrack => AssetType::VorbisTrack,
+            Self::SoundGraph => AssetType::SoundGraph,
+        }
+    }
+}
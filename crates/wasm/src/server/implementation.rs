@@ -1,6 +1,9 @@
 use ambient_core::asset_cache;
 use ambient_input::{player_prev_raw_input, player_raw_input};
-use ambient_physics::{helpers::PhysicsObjectCollection, physx::character_controller};
+use ambient_physics::{
+    collision_layers::query_filter_data, helpers::PhysicsObjectCollection,
+    physx::character_controller,
+};
 use ambient_std::{
     asset_cache::SyncAssetKeyExt,
     asset_url::{AssetUrl, ServerBaseUrlKey},
@@ -10,7 +13,7 @@ use anyhow::Context;
 use itertools::Itertools;
 use physxx::{PxControllerCollisionFlag, PxControllerFilters};
 
-use super::Bindings;
+use super::{asset_catalog, io, module_storage, Bindings};
 use crate::shared::{
     conversion::{FromBindgen, IntoBindgen},
     wit,
@@ -121,10 +124,12 @@ impl wit::server_physics::Host for Bindings {
         &mut self,
         origin: wit::types::Vec3,
         direction: wit::types::Vec3,
+        layers: Option<u32>,
     ) -> anyhow::Result<Option<(wit::types::EntityId, f32)>> {
         let result = ambient_physics::intersection::raycast_first(
             self.world(),
             Ray::new(origin.from_bindgen(), direction.from_bindgen()),
+            layers,
         )
         .map(|t| (t.0.into_bindgen(), t.1.into_bindgen()));
 
@@ -135,10 +140,12 @@ impl wit::server_physics::Host for Bindings {
         &mut self,
         origin: wit::types::Vec3,
         direction: wit::types::Vec3,
+        layers: Option<u32>,
     ) -> anyhow::Result<Vec<(wit::types::EntityId, f32)>> {
         let result = ambient_physics::intersection::raycast(
             self.world(),
             Ray::new(origin.from_bindgen(), direction.from_bindgen()),
+            layers,
         )
         .into_iter()
         .map(|t| (t.0.into_bindgen(), t.1.into_bindgen()))
@@ -153,17 +160,24 @@ impl wit::server_physics::Host for Bindings {
         displacement: wit::types::Vec3,
         min_dist: f32,
         elapsed_time: f32,
+        layers: Option<u32>,
     ) -> anyhow::Result<wit::server_physics::CharacterCollision> {
         match self
             .world()
             .get(entity.from_bindgen(), character_controller())
         {
             Ok(controller) => {
+                let filters = match layers {
+                    Some(layers) => {
+                        PxControllerFilters::with_filter_data(query_filter_data(layers))
+                    }
+                    None => PxControllerFilters::new(),
+                };
                 let res = controller.move_controller(
                     displacement.from_bindgen(),
                     min_dist,
                     elapsed_time,
-                    &PxControllerFilters::new(),
+                    &filters,
                     None,
                 );
                 Ok(wit::server_physics::CharacterCollision {
@@ -179,6 +193,105 @@ impl wit::server_physics::Host for Bindings {
             }),
         }
     }
+
+    fn sweep(
+        &mut self,
+        shape: wit::server_physics::QueryShape,
+        position: wit::types::Vec3,
+        rotation: wit::types::Quat,
+        direction: wit::types::Vec3,
+        max_distance: f32,
+        scene: Option<wit::server_physics::ColliderScene>,
+        layers: Option<u32>,
+    ) -> anyhow::Result<Vec<wit::server_physics::SweepHit>> {
+        Ok(ambient_physics::intersection::sweep(
+            self.world(),
+            shape.from_bindgen(),
+            position.from_bindgen(),
+            rotation.from_bindgen(),
+            direction.from_bindgen(),
+            max_distance,
+            scene.from_bindgen(),
+            layers,
+        )
+        .into_bindgen())
+    }
+
+    fn overlap(
+        &mut self,
+        shape: wit::server_physics::QueryShape,
+        position: wit::types::Vec3,
+        rotation: wit::types::Quat,
+        scene: Option<wit::server_physics::ColliderScene>,
+        layers: Option<u32>,
+    ) -> anyhow::Result<Vec<wit::types::EntityId>> {
+        Ok(ambient_physics::intersection::overlap(
+            self.world(),
+            shape.from_bindgen(),
+            position.from_bindgen(),
+            rotation.from_bindgen(),
+            scene.from_bindgen(),
+            layers,
+        )
+        .into_bindgen())
+    }
+
+    fn create_joint(
+        &mut self,
+        joint_type: wit::server_physics::JointType,
+        entity0: wit::types::EntityId,
+        entity1: Option<wit::types::EntityId>,
+        position: wit::types::Vec3,
+        rotation: wit::types::Quat,
+    ) -> anyhow::Result<wit::types::EntityId> {
+        Ok(ambient_physics::helpers::create_joint(
+            self.world_mut(),
+            joint_type.from_bindgen(),
+            entity0.from_bindgen(),
+            entity1.from_bindgen(),
+            position.from_bindgen(),
+            rotation.from_bindgen(),
+        )?
+        .into_bindgen())
+    }
+
+    fn break_joint(&mut self, joint: wit::types::EntityId) -> anyhow::Result<()> {
+        ambient_physics::helpers::break_joint(self.world_mut(), joint.from_bindgen())
+    }
+
+    fn set_linear_velocity(
+        &mut self,
+        entity: wit::types::EntityId,
+        velocity: wit::types::Vec3,
+    ) -> anyhow::Result<()> {
+        ambient_physics::helpers::set_linear_velocity(
+            self.world_mut(),
+            entity.from_bindgen(),
+            velocity.from_bindgen(),
+        )
+    }
+
+    fn set_angular_velocity(
+        &mut self,
+        entity: wit::types::EntityId,
+        velocity: wit::types::Vec3,
+    ) -> anyhow::Result<()> {
+        ambient_physics::helpers::set_angular_velocity(
+            self.world_mut(),
+            entity.from_bindgen(),
+            velocity.from_bindgen(),
+        )
+    }
+
+    fn get_contacts(
+        &mut self,
+        entity: wit::types::EntityId,
+    ) -> anyhow::Result<Vec<wit::server_physics::Contact>> {
+        Ok(
+            ambient_physics::helpers::get_contacts(self.world(), entity.from_bindgen())
+                .into_bindgen(),
+        )
+    }
 }
 
 impl wit::server_asset::Host for Bindings {
@@ -186,4 +299,175 @@ impl wit::server_asset::Host for Bindings {
         let base_url = ServerBaseUrlKey.get(self.world().resource(asset_cache()));
         Ok(Some(AssetUrl::parse(path)?.resolve(&base_url)?.to_string()))
     }
+
+    fn catalog(
+        &mut self,
+        asset_type: Option<wit::server_asset::AssetType>,
+        tag: Option<String>,
+        category: Option<String>,
+    ) -> anyhow::Result<Vec<wit::server_asset::CatalogAsset>> {
+        let world = self.world();
+        let Some(catalog) = world.resource_opt(asset_catalog()) else { return Ok(vec![]); };
+        let base_url = ServerBaseUrlKey.get(world.resource(asset_cache()));
+        let resolve = |path: &Option<String>| -> anyhow::Result<Option<String>> {
+            Ok(match path {
+                Some(path) => Some(AssetUrl::parse(path)?.resolve(&base_url)?.to_string()),
+                None => None,
+            })
+        };
+
+        catalog
+            .query(
+                asset_type.from_bindgen(),
+                tag.as_deref(),
+                category.as_deref(),
+            )
+            .map(|asset| {
+                Ok(wit::server_asset::CatalogAsset {
+                    id: asset.id.clone(),
+                    asset_type: asset.type_.into_bindgen(),
+                    name: asset.name.clone(),
+                    tags: asset.tags.clone(),
+                    categories: asset.categories.clone(),
+                    url: resolve(&asset.content)?,
+                    collection: asset.collection.clone(),
+                    source: asset.source.clone(),
+                    preview_url: resolve(&asset.preview)?,
+                })
+            })
+            .collect()
+    }
+}
+
+impl wit::server_io::Host for Bindings {
+    fn read_asset(&mut self, path: String) -> anyhow::Result<Result<u64, String>> {
+        let world = unsafe { self.world_ref.world() };
+        Ok(
+            io::read_asset(world, self.base.module_id, &mut self.io, &path)
+                .map_err(|err| format!("{err:#}")),
+        )
+    }
+
+    fn read_data(&mut self, path: String) -> anyhow::Result<Result<u64, String>> {
+        let world = unsafe { self.world_ref.world() };
+        Ok(
+            io::read_data(world, self.base.module_id, &mut self.io, &path)
+                .map_err(|err| format!("{err:#}")),
+        )
+    }
+
+    fn write_data(&mut self, path: String, data: Vec<u8>) -> anyhow::Result<Result<u64, String>> {
+        let world = unsafe { self.world_ref.world() };
+        Ok(
+            io::write_data(world, self.base.module_id, &mut self.io, &path, data)
+                .map_err(|err| format!("{err:#}")),
+        )
+    }
+
+    fn http_get(&mut self, url: String) -> anyhow::Result<Result<u64, String>> {
+        let world = unsafe { self.world_ref.world() };
+        Ok(io::http_request(
+            world,
+            self.base.module_id,
+            &mut self.io,
+            reqwest::Method::GET,
+            &url,
+            None,
+        )
+        .map_err(|err| format!("{err:#}")))
+    }
+
+    fn http_post(&mut self, url: String, body: Vec<u8>) -> anyhow::Result<Result<u64, String>> {
+        let world = unsafe { self.world_ref.world() };
+        Ok(io::http_request(
+            world,
+            self.base.module_id,
+            &mut self.io,
+            reqwest::Method::POST,
+            &url,
+            Some(body),
+        )
+        .map_err(|err| format!("{err:#}")))
+    }
+
+    fn poll(&mut self, request: u64) -> anyhow::Result<Option<Result<Vec<u8>, String>>> {
+        self.io.poll(request)
+    }
+}
+
+impl wit::server_storage::Host for Bindings {
+    fn get(&mut self, key: String) -> anyhow::Result<Option<Vec<u8>>> {
+        Ok(self.storage()?.lock().get(&key).map(|v| v.to_vec()))
+    }
+
+    fn set(&mut self, key: String, value: Vec<u8>) -> anyhow::Result<Result<(), String>> {
+        self.write_storage([(key, Some(value))])
+    }
+
+    fn delete(&mut self, key: String) -> anyhow::Result<Result<(), String>> {
+        self.write_storage([(key, None)])
+    }
+
+    fn list_prefix(&mut self, prefix: String) -> anyhow::Result<Vec<String>> {
+        Ok(self.storage()?.lock().list_prefix(&prefix))
+    }
+
+    fn write_batch(
+        &mut self,
+        writes: Vec<wit::server_storage::BatchWrite>,
+    ) -> anyhow::Result<Result<(), String>> {
+        self.write_storage(writes.into_iter().map(|w| (w.key, w.value)))
+    }
+}
+impl Bindings {
+    fn storage(&self) -> anyhow::Result<super::ModuleStorage> {
+        self.world()
+            .get_cloned(self.base.module_id, module_storage())
+            .context("This module has no storage")
+    }
+
+    fn write_storage(
+        &mut self,
+        writes: impl IntoIterator<Item = (String, Option<Vec<u8>>)>,
+    ) -> anyhow::Result<Result<(), String>> {
+        Ok(self
+            .storage()?
+            .lock()
+            .write(writes)
+            .map_err(|err| format!("{err:#}")))
+    }
+}
+
+fn unsupported<T>() -> anyhow::Result<T> {
+    anyhow::bail!("This function is not supported on this side of the API. Please report this if you were able to access this function.")
+}
+
+impl wit::client_audio::Host for Bindings {
+    fn play(
+        &mut self,
+        _entity: wit::types::EntityId,
+        _url: String,
+    ) -> anyhow::Result<Result<u64, String>> {
+        unsupported()
+    }
+
+    fn stop(&mut self, _sound: u64, _fade_out: f32) -> anyhow::Result<()> {
+        unsupported()
+    }
+
+    fn set_gain(&mut self, _sound: u64, _gain: f32) -> anyhow::Result<()> {
+        unsupported()
+    }
+
+    fn set_pitch(&mut self, _sound: u64, _pitch: f32) -> anyhow::Result<()> {
+        unsupported()
+    }
+
+    fn set_cutoff(&mut self, _sound: u64, _cutoff: Option<f32>) -> anyhow::Result<()> {
+        unsupported()
+    }
+
+    fn is_playing(&mut self, _sound: u64) -> anyhow::Result<bool> {
+        unsupported()
+    }
 }
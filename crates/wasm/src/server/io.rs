@@ -0,0 +1,347 @@
+use std::{
+    collections::HashMap,
+    path::{Component, Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use ambient_core::{asset_cache, runtime};
+use ambient_ecs::{EntityId, World};
+use ambient_std::{asset_cache::SyncAssetKeyExt, download_asset::ReqwestClientKey};
+use anyhow::Context;
+
+use super::module_capabilities;
+
+/// The resolved, per-module form of [ambient_project::Capabilities].
+#[derive(Clone, Debug, Default)]
+pub struct ModuleCapabilities {
+    /// Root of the read-only asset directory, if granted
+    pub assets_path: Option<PathBuf>,
+    /// Root of the read-write data directory, if granted
+    pub data_path: Option<PathBuf>,
+    /// Hosts that HTTP(S) requests may be made to
+    pub http_hosts: Vec<String>,
+}
+impl ModuleCapabilities {
+    pub fn new(capabilities: &ambient_project::Capabilities, project_path: &Path) -> Self {
+        Self {
+            assets_path: capabilities.assets.then(|| project_path.join("build")),
+            data_path: capabilities.data.then(|| project_path.join("data")),
+            http_hosts: capabilities.http.clone(),
+        }
+    }
+}
+
+type IoResult = Result<Vec<u8>, String>;
+
+/// How long the result of a request is kept after it finished without the guest polling it,
+/// e.g. because the future awaiting it was dropped.
+const ABANDONED_REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Clone)]
+struct PendingRequest {
+    receiver: flume::Receiver<IoResult>,
+    /// When the request was first seen to be finished
+    finished_at: Option<Instant>,
+}
+
+/// Outstanding I/O requests made by a module, keyed by the ID returned to the guest.
+#[derive(Clone, Default)]
+pub(super) struct IoRequests {
+    next_id: u64,
+    pending: HashMap<u64, PendingRequest>,
+}
+impl IoRequests {
+    fn insert(&mut self, receiver: flume::Receiver<IoResult>) -> u64 {
+        self.remove_abandoned(Instant::now());
+
+        let id = self.next_id;
+        self.next_id += 1;
+        self.pending.insert(
+            id,
+            PendingRequest {
+                receiver,
+                finished_at: None,
+            },
+        );
+        id
+    }
+
+    /// Drops the requests that finished more than [ABANDONED_REQUEST_TIMEOUT] before `now`
+    /// without being polled.
+    fn remove_abandoned(&mut self, now: Instant) {
+        self.pending.retain(|_, request| {
+            if request.finished_at.is_none()
+                && (!request.receiver.is_empty() || request.receiver.is_disconnected())
+            {
+                request.finished_at = Some(now);
+            }
+            request.finished_at.map_or(true, |finished_at| {
+                now.duration_since(finished_at) < ABANDONED_REQUEST_TIMEOUT
+            })
+        });
+    }
+
+    fn spawn_blocking(
+        &mut self,
+        world: &World,
+        f: impl FnOnce() -> anyhow::Result<Vec<u8>> + Send + 'static,
+    ) -> u64 {
+        let (tx, rx) = flume::bounded(1);
+        world.resource(runtime()).spawn_blocking(move || {
+            tx.send(f().map_err(|err| format!("{err:#}"))).ok();
+        });
+        self.insert(rx)
+    }
+
+    fn spawn(
+        &mut self,
+        world: &World,
+        fut: impl std::future::Future<Output = anyhow::Result<Vec<u8>>> + Send + 'static,
+    ) -> u64 {
+        let (tx, rx) = flume::bounded(1);
+        world.resource(runtime()).spawn(async move {
+            tx.send(fut.await.map_err(|err| format!("{err:#}"))).ok();
+        });
+        self.insert(rx)
+    }
+
+    pub fn poll(&mut self, request: u64) -> anyhow::Result<Option<IoResult>> {
+        let pending = self
+            .pending
+            .get(&request)
+            .with_context(|| format!("Unknown I/O request {request}"))?;
+
+        let result = match pending.receiver.try_recv() {
+            Ok(result) => result,
+            Err(flume::TryRecvError::Empty) => return Ok(None),
+            Err(flume::TryRecvError::Disconnected) => Err("I/O request was cancelled".to_string()),
+        };
+        self.pending.remove(&request);
+        Ok(Some(result))
+    }
+}
+
+pub(super) fn read_asset(
+    world: &World,
+    module_id: EntityId,
+    requests: &mut IoRequests,
+    path: &str,
+) -> anyhow::Result<u64> {
+    let root = capabilities(world, module_id)
+        .assets_path
+        .context("This project has not been granted the `assets` capability")?;
+    let path = sandboxed_path(&root, path)?;
+    Ok(requests.spawn_blocking(world, move || {
+        std::fs::read(&path).with_context(|| format!("Failed to read {path:?}"))
+    }))
+}
+
+pub(super) fn read_data(
+    world: &World,
+    module_id: EntityId,
+    requests: &mut IoRequests,
+    path: &str,
+) -> anyhow::Result<u64> {
+    let root = data_path(world, module_id)?;
+    let path = sandboxed_path(&root, path)?;
+    Ok(requests.spawn_blocking(world, move || {
+        std::fs::read(&path).with_context(|| format!("Failed to read {path:?}"))
+    }))
+}
+
+pub(super) fn write_data(
+    world: &World,
+    module_id: EntityId,
+    requests: &mut IoRequests,
+    path: &str,
+    data: Vec<u8>,
+) -> anyhow::Result<u64> {
+    let root = data_path(world, module_id)?;
+    let path = sandboxed_path(&root, path)?;
+    Ok(requests.spawn_blocking(world, move || {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, data).with_context(|| format!("Failed to write {path:?}"))?;
+        Ok(vec![])
+    }))
+}
+
+pub(super) fn http_request(
+    world: &World,
+    module_id: EntityId,
+    requests: &mut IoRequests,
+    method: reqwest::Method,
+    url: &str,
+    body: Option<Vec<u8>>,
+) -> anyhow::Result<u64> {
+    let url = allowed_url(&capabilities(world, module_id), url)?;
+    let client = ReqwestClientKey.get(world.resource(asset_cache()));
+    Ok(requests.spawn(world, async move {
+        let mut request = client.request(method, url);
+        if let Some(body) = body {
+            request = request.body(body);
+        }
+        let response = request.send().await?.error_for_status()?;
+        Ok(response.bytes().await?.to_vec())
+    }))
+}
+
+fn capabilities(world: &World, module_id: EntityId) -> ModuleCapabilities {
+    world
+        .get_cloned(module_id, module_capabilities())
+        .unwrap_or_default()
+}
+
+fn data_path(world: &World, module_id: EntityId) -> anyhow::Result<PathBuf> {
+    capabilities(world, module_id)
+        .data_path
+        .context("This project has not been granted the `data` capability")
+}
+
+/// Joins `path` onto `root`, rejecting anything that could escape it, including symlinks
+/// that point outside of `root`.
+fn sandboxed_path(root: &Path, path: &str) -> anyhow::Result<PathBuf> {
+    let relative = Path::new(path);
+    anyhow::ensure!(
+        relative
+            .components()
+            .all(|c| matches!(c, Component::Normal(_) | Component::CurDir)),
+        "The path {path:?} must be relative and cannot contain `..`"
+    );
+    let path = root.join(relative);
+
+    // Nothing below a missing root can be a symlink
+    let Ok(canonical_root) = root.canonicalize() else {
+        return Ok(path);
+    };
+    // The deepest part of the path that exists decides where the rest of it will end up
+    let existing = path
+        .ancestors()
+        .take_while(|p| p.starts_with(root))
+        .find(|p| p.symlink_metadata().is_ok())
+        .unwrap_or(root);
+    let resolved = existing
+        .canonicalize()
+        .with_context(|| format!("Failed to resolve {existing:?}"))?;
+    anyhow::ensure!(
+        resolved.starts_with(&canonical_root),
+        "The path {path:?} leads outside of {root:?}"
+    );
+    Ok(path)
+}
+
+fn allowed_url(capabilities: &ModuleCapabilities, url: &str) -> anyhow::Result<reqwest::Url> {
+    let url = reqwest::Url::parse(url)?;
+    anyhow::ensure!(
+        matches!(url.scheme(), "http" | "https"),
+        "Only HTTP(S) URLs are supported, got {url}"
+    );
+    let host = url.host_str().context("The URL has no host")?;
+    anyhow::ensure!(
+        capabilities.http_hosts.iter().any(|h| h == host),
+        "The host {host} is not in this project's `http` capability"
+    );
+    Ok(url)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("ambient_io_{name}_{}", std::process::id()));
+        std::fs::remove_dir_all(&root).ok();
+        std::fs::create_dir_all(&root).unwrap();
+        root
+    }
+
+    #[test]
+    fn sandboxed_path_accepts_relative_paths() {
+        let root = temp_root("relative");
+        assert_eq!(
+            sandboxed_path(&root, "saves/slot1.bin").unwrap(),
+            root.join("saves/slot1.bin")
+        );
+        assert_eq!(
+            sandboxed_path(&root, "./a.txt").unwrap(),
+            root.join("./a.txt")
+        );
+        // The root itself doesn't need to exist yet
+        let missing = root.join("missing");
+        assert_eq!(
+            sandboxed_path(&missing, "a.txt").unwrap(),
+            missing.join("a.txt")
+        );
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    #[test]
+    fn sandboxed_path_rejects_parent_and_absolute_paths() {
+        let root = temp_root("escape");
+        assert!(sandboxed_path(&root, "../secret.txt").is_err());
+        assert!(sandboxed_path(&root, "a/../../secret.txt").is_err());
+        assert!(sandboxed_path(&root, "a/..").is_err());
+        assert!(sandboxed_path(&root, "/etc/passwd").is_err());
+        #[cfg(windows)]
+        assert!(sandboxed_path(&root, "C:\\Windows\\win.ini").is_err());
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn sandboxed_path_rejects_symlinks_out_of_the_root() {
+        let root = temp_root("symlink");
+        let outside = temp_root("symlink_outside");
+        std::os::unix::fs::symlink(&outside, root.join("link")).unwrap();
+        std::os::unix::fs::symlink(outside.join("file"), root.join("dangling")).unwrap();
+        std::fs::create_dir(root.join("inside")).unwrap();
+        std::os::unix::fs::symlink(root.join("inside"), root.join("inside_link")).unwrap();
+
+        assert!(sandboxed_path(&root, "link").is_err());
+        assert!(sandboxed_path(&root, "link/file.txt").is_err());
+        assert!(sandboxed_path(&root, "link/new_dir/file.txt").is_err());
+        assert!(sandboxed_path(&root, "dangling").is_err());
+        assert!(sandboxed_path(&root, "inside_link/file.txt").is_ok());
+
+        std::fs::remove_dir_all(&root).ok();
+        std::fs::remove_dir_all(&outside).ok();
+    }
+
+    #[test]
+    fn allowed_url_checks_scheme_and_host() {
+        let capabilities = ModuleCapabilities {
+            http_hosts: vec!["example.com".to_string()],
+            ..Default::default()
+        };
+        assert!(allowed_url(&capabilities, "https://example.com/a?b=c").is_ok());
+        assert!(allowed_url(&capabilities, "http://example.com:8080/").is_ok());
+        assert!(allowed_url(&capabilities, "https://other.com/").is_err());
+        assert!(allowed_url(&capabilities, "https://sub.example.com/").is_err());
+        assert!(allowed_url(&capabilities, "https://example.com.evil.com/").is_err());
+        assert!(allowed_url(&capabilities, "https://example.com@evil.com/").is_err());
+        assert!(allowed_url(&capabilities, "file:///etc/passwd").is_err());
+        assert!(allowed_url(&capabilities, "ftp://example.com/").is_err());
+        assert!(allowed_url(&capabilities, "/relative/path").is_err());
+        assert!(allowed_url(&ModuleCapabilities::default(), "https://example.com/").is_err());
+    }
+
+    #[test]
+    fn abandoned_requests_are_removed() {
+        let mut requests = IoRequests::default();
+        let (tx, rx) = flume::bounded(1);
+        let finished = requests.insert(rx);
+        let (_pending_tx, pending_rx) = flume::bounded::<IoResult>(1);
+        let pending = requests.insert(pending_rx);
+        tx.send(Ok(vec![1])).unwrap();
+
+        let now = Instant::now();
+        requests.remove_abandoned(now);
+        assert_eq!(requests.pending.len(), 2);
+        requests.remove_abandoned(now + ABANDONED_REQUEST_TIMEOUT);
+        assert!(!requests.pending.contains_key(&finished));
+        assert!(requests.pending.contains_key(&pending));
+        assert!(requests.poll(finished).is_err());
+        assert_eq!(requests.poll(pending).unwrap(), None);
+    }
+}
@@ -1,10 +1,30 @@
-use crate::shared::{self, wit};
-use ambient_ecs::{query, EntityId, FnSystem, SystemGroup, World};
+use crate::shared::{self, wit, ModuleBytecode};
+use ambient_core::{is_reloaded_asset, reloaded_assets};
+use ambient_ecs::{
+    components, query, Debuggable, Description, EntityId, FnSystem, Resource, SystemGroup, World,
+};
 use ambient_network::server::{ForkingEvent, ShutdownEvent};
-use std::sync::Arc;
+use ambient_std::asset_catalog::AssetCatalog;
+use std::{path::PathBuf, sync::Arc};
 
 mod conversion;
 mod implementation;
+mod io;
+mod storage;
+
+pub use io::ModuleCapabilities;
+pub use storage::{ModuleStorage, Storage};
+
+components!("wasm::server", {
+    @[Debuggable, Description["The I/O capabilities granted to this module by its project's manifest."]]
+    module_capabilities: ModuleCapabilities,
+    @[Description["The persistent key-value store used by this module. Shared between all modules of a project."]]
+    module_storage: ModuleStorage,
+    @[Debuggable, Description["The file the bytecode of this module was read from. The module is reloaded from it when it is rebuilt."]]
+    module_bytecode_path: PathBuf,
+    @[Resource, Debuggable, Description["The catalog of the assets built for this project, which modules can query."]]
+    asset_catalog: AssetCatalog,
+});
 
 pub fn initialize(
     world: &mut World,
@@ -16,6 +36,7 @@ pub fn initialize(
         Bindings {
             base: Default::default(),
             world_ref: Default::default(),
+            io: Default::default(),
         },
     )?;
 
@@ -23,7 +44,31 @@ pub fn initialize(
 }
 
 pub fn systems() -> SystemGroup {
-    shared::systems()
+    SystemGroup::new(
+        "core/wasm/server",
+        vec![
+            query(reloaded_assets().changed()).to_system(|q, world, qs, _| {
+                for (_, reloaded) in q.collect_cloned(world, qs) {
+                    let modules = query(module_bytecode_path())
+                        .iter(world, None)
+                        .filter(|(_, path)| {
+                            is_reloaded_asset(&path.to_string_lossy().replace('\\', "/"), &reloaded)
+                        })
+                        .map(|(id, path)| (id, path.clone()))
+                        .collect::<Vec<_>>();
+                    for (id, path) in modules {
+                        match std::fs::read(&path) {
+                            Ok(bytecode) => {
+                                shared::update_module_bytecode(world, id, ModuleBytecode(bytecode))
+                            }
+                            Err(err) => log::warn!("Failed to reload module from {path:?}: {err}"),
+                        }
+                    }
+                }
+            }),
+            Box::new(shared::systems()),
+        ],
+    )
 }
 
 pub fn on_forking_systems() -> SystemGroup<ForkingEvent> {
@@ -53,6 +98,7 @@ pub fn on_shutdown_systems() -> SystemGroup<ShutdownEvent> {
 struct Bindings {
     base: shared::bindings::BindingsBase,
     world_ref: shared::bindings::WorldRef,
+    io: io::IoRequests,
 }
 impl Bindings {
     pub fn world(&self) -> &World {
@@ -244,3 +290,17 @@ impl wit::event::Host for Bindings {
         )
     }
 }
+impl wit::profiling::Host for Bindings {
+    fn begin_scope(&mut self, name: String) -> anyhow::Result<()> {
+        shared::implementation::profiling::begin_scope(
+            unsafe { self.world_ref.world() },
+            self.base.module_id,
+            &mut self.base.profiling_scopes,
+            name,
+        )
+    }
+
+    fn end_scope(&mut self) -> anyhow::Result<()> {
+        shared::implementation::profiling::end_scope(&mut self.base.profiling_scopes)
+    }
+}
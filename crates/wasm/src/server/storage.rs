@@ -0,0 +1,242 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use parking_lot::Mutex;
+
+pub type ModuleStorage = Arc<Mutex<Storage>>;
+
+type Write = (String, Option<Vec<u8>>);
+
+/// How long writes are collected before the store is persisted
+const WRITE_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A persistent key-value store held in memory and written to a single file.
+///
+/// Writes are applied in memory immediately, and persisted by a background thread that batches
+/// all the writes made within [WRITE_INTERVAL]. The file is replaced through a temporary file, so
+/// that the store on disk is always either entirely before or entirely after a given write.
+/// Pending writes are persisted when the store is dropped.
+///
+/// Each batch rewrites the whole file, so persisting takes time proportional to the total size of
+/// the store rather than to the size of the writes. It is meant for small amounts of data, such as
+/// settings and scores, rather than for large or frequently written values.
+#[derive(Debug)]
+pub struct Storage {
+    entries: BTreeMap<String, Vec<u8>>,
+    writes: Option<flume::Sender<Vec<Write>>>,
+    writer: Option<JoinHandle<()>>,
+}
+impl Storage {
+    /// Opens the store for the project `project_id` within `storage_dir`, creating it if it does not exist.
+    ///
+    /// `storage_dir` must not be accessible to modules, as they could otherwise tamper with the stores.
+    pub fn open(storage_dir: &Path, project_id: &str) -> anyhow::Result<Self> {
+        let path = storage_dir.join(format!("{project_id}.bin"));
+        let entries: BTreeMap<String, Vec<u8>> = match std::fs::read(&path) {
+            Ok(bytes) => bincode::deserialize(&bytes)
+                .with_context(|| format!("Failed to parse the storage at {path:?}"))?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(err).with_context(|| format!("Failed to read {path:?}")),
+        };
+
+        let (writes, receiver) = flume::unbounded();
+        let writer = std::thread::Builder::new()
+            .name(format!("storage ({project_id})"))
+            .spawn({
+                let entries = entries.clone();
+                move || persist_writes(path, entries, receiver)
+            })?;
+        Ok(Self {
+            entries,
+            writes: Some(writes),
+            writer: Some(writer),
+        })
+    }
+
+    pub fn get(&self, key: &str) -> Option<&[u8]> {
+        self.entries.get(key).map(|v| v.as_slice())
+    }
+
+    /// Returns all keys starting with `prefix`, in order.
+    pub fn list_prefix(&self, prefix: &str) -> Vec<String> {
+        self.entries
+            .range(prefix.to_string()..)
+            .take_while(|(k, _)| k.starts_with(prefix))
+            .map(|(k, _)| k.clone())
+            .collect()
+    }
+
+    /// Applies all of `writes` (with `None` deleting the key), and queues them to be persisted together.
+    pub fn write(&mut self, writes: impl IntoIterator<Item = Write>) -> anyhow::Result<()> {
+        let writes: Vec<_> = writes.into_iter().collect();
+        self.writes
+            .as_ref()
+            .context("The storage is closed")?
+            .send(writes.clone())
+            .ok()
+            .context("The storage writer has stopped")?;
+
+        for (key, value) in writes {
+            match value {
+                Some(value) => self.entries.insert(key, value),
+                None => self.entries.remove(&key),
+            };
+        }
+        Ok(())
+    }
+}
+impl Drop for Storage {
+    fn drop(&mut self) {
+        // Closing the channel makes the writer persist what is left and stop
+        self.writes.take();
+        if let Some(writer) = self.writer.take() {
+            writer.join().ok();
+        }
+    }
+}
+
+/// Applies the batches sent through `receiver` to its own copy of the entries, and writes them to
+/// `path` at most once every [WRITE_INTERVAL], until the channel is closed.
+fn persist_writes(
+    path: PathBuf,
+    mut entries: BTreeMap<String, Vec<u8>>,
+    receiver: flume::Receiver<Vec<Write>>,
+) {
+    let apply = |entries: &mut BTreeMap<String, Vec<u8>>, writes: Vec<Write>| {
+        for (key, value) in writes {
+            match value {
+                Some(value) => entries.insert(key, value),
+                None => entries.remove(&key),
+            };
+        }
+    };
+
+    while let Ok(writes) = receiver.recv() {
+        apply(&mut entries, writes);
+
+        let deadline = Instant::now() + WRITE_INTERVAL;
+        let closed = loop {
+            match receiver.recv_deadline(deadline) {
+                Ok(writes) => apply(&mut entries, writes),
+                Err(flume::RecvTimeoutError::Timeout) => break false,
+                Err(flume::RecvTimeoutError::Disconnected) => break true,
+            }
+        };
+
+        if let Err(err) = persist(&path, &entries) {
+            log::error!("Failed to persist the storage: {err:?}");
+        }
+        if closed {
+            break;
+        }
+    }
+}
+
+fn persist(path: &Path, entries: &BTreeMap<String, Vec<u8>>) -> anyhow::Result<()> {
+    let tmp_path = path.with_extension("bin.tmp");
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&tmp_path, bincode::serialize(entries)?)
+        .with_context(|| format!("Failed to write {tmp_path:?}"))?;
+    std::fs::rename(&tmp_path, path).with_context(|| format!("Failed to replace {path:?}"))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("ambient_storage_{name}_{}", std::process::id()));
+        std::fs::remove_dir_all(&dir).ok();
+        dir
+    }
+
+    fn set(storage: &mut Storage, key: &str, value: &[u8]) {
+        storage
+            .write([(key.to_string(), Some(value.to_vec()))])
+            .unwrap();
+    }
+
+    #[test]
+    fn get_set_delete() {
+        let dir = temp_dir("get_set_delete");
+        let mut storage = Storage::open(&dir, "project").unwrap();
+        assert_eq!(storage.get("a"), None);
+
+        set(&mut storage, "a", b"1");
+        assert_eq!(storage.get("a"), Some(&b"1"[..]));
+        set(&mut storage, "a", b"2");
+        assert_eq!(storage.get("a"), Some(&b"2"[..]));
+
+        storage.write([("a".to_string(), None)]).unwrap();
+        assert_eq!(storage.get("a"), None);
+        // Deleting a missing key is not an error
+        storage.write([("a".to_string(), None)]).unwrap();
+
+        drop(storage);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn list_prefix() {
+        let dir = temp_dir("list_prefix");
+        let mut storage = Storage::open(&dir, "project").unwrap();
+        for key in [
+            "players/b/score",
+            "players/a/score",
+            "player",
+            "players0",
+            "teams/a",
+        ] {
+            set(&mut storage, key, b"");
+        }
+
+        assert_eq!(
+            storage.list_prefix("players/"),
+            vec!["players/a/score", "players/b/score"]
+        );
+        assert_eq!(
+            storage.list_prefix("player"),
+            vec!["player", "players/a/score", "players/b/score", "players0"]
+        );
+        assert_eq!(storage.list_prefix("").len(), 5);
+        assert!(storage.list_prefix("z").is_empty());
+
+        drop(storage);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn writes_are_persisted() {
+        let dir = temp_dir("persisted");
+        let mut storage = Storage::open(&dir, "project").unwrap();
+        set(&mut storage, "kept", b"1");
+        set(&mut storage, "deleted", b"2");
+        storage
+            .write([
+                ("deleted".to_string(), None),
+                ("batched".to_string(), Some(b"3".to_vec())),
+            ])
+            .unwrap();
+        drop(storage);
+
+        let storage = Storage::open(&dir, "project").unwrap();
+        assert_eq!(storage.get("kept"), Some(&b"1"[..]));
+        assert_eq!(storage.get("deleted"), None);
+        assert_eq!(storage.get("batched"), Some(&b"3"[..]));
+        // Other projects have their own store
+        assert_eq!(Storage::open(&dir, "other").unwrap().get("kept"), None);
+
+        drop(storage);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -9,9 +9,12 @@ pub type QueryStateMap =
 
 #[derive(Clone, Default)]
 pub struct BindingsBase {
+    pub module_id: EntityId,
     pub spawned_entities: HashSet<EntityId>,
     pub subscribed_events: HashSet<String>,
     pub query_states: QueryStateMap,
+    /// Guest profiling scopes opened during the current run
+    pub profiling_scopes: Vec<Option<usize>>,
 }
 
 pub trait BindingsBound:
@@ -19,9 +22,13 @@ pub trait BindingsBound:
     + wit::component::Host
     + wit::entity::Host
     + wit::event::Host
+    + wit::profiling::Host
     + wit::server_player::Host
     + wit::server_physics::Host
     + wit::server_asset::Host
+    + wit::server_io::Host
+    + wit::server_storage::Host
+    + wit::client_audio::Host
     + Clone
     + Sync
     + Send
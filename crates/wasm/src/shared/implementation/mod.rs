@@ -1,3 +1,4 @@
 pub mod component;
 pub mod entity;
 pub mod event;
+pub mod profiling;
@@ -0,0 +1,32 @@
+use ambient_ecs::{EntityId, World};
+use profiling::puffin;
+
+use crate::shared::get_module_name;
+
+pub fn begin_scope(
+    world: &World,
+    module_id: EntityId,
+    scopes: &mut Vec<Option<usize>>,
+    name: String,
+) -> anyhow::Result<()> {
+    // Scopes are still tracked when profiling is off so that begin/end pairs stay balanced.
+    let start = puffin::are_scopes_on().then(|| {
+        let location = get_module_name(world, module_id).to_string();
+        puffin::ThreadProfiler::call(|tp| tp.begin_scope(&name, &location, ""))
+    });
+    scopes.push(start);
+    Ok(())
+}
+
+pub fn end_scope(scopes: &mut Vec<Option<usize>>) -> anyhow::Result<()> {
+    if let Some(Some(start)) = scopes.pop() {
+        puffin::ThreadProfiler::call(|tp| tp.end_scope(start));
+    }
+    Ok(())
+}
+
+pub(crate) fn end_all_scopes(scopes: &mut Vec<Option<usize>>) {
+    while !scopes.is_empty() {
+        end_scope(scopes).ok();
+    }
+}
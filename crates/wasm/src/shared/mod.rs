@@ -5,20 +5,25 @@ pub mod conversion;
 pub mod host_guest_state;
 pub(crate) mod implementation;
 mod module;
+pub mod pipeline;
 pub mod wit;
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Instant};
 
 use ambient_ecs::{
     components, dont_despawn_on_unload, query, world_events, ComponentEntry, Debuggable,
     Description, Entity, EntityId, FnSystem, Networked, Resource, Store, SystemGroup, World,
     WorldEventReader,
 };
-use ambient_physics::{collider_loads, collisions, PxShapeUserData};
+use ambient_physics::{
+    collider_loads, contact_impulse, contact_normals, contact_points, contacts,
+    helpers::get_reported_contacts,
+    triggers::{trigger_events, TriggerEventKind},
+    ContactPhase,
+};
 use ambient_project::Identifier;
 use itertools::Itertools;
 pub use module::*;
-use physxx::{PxRigidActor, PxRigidActorRef, PxUserData};
 
 components!("wasm::shared", {
     @[Networked, Store, Debuggable]
@@ -30,6 +35,8 @@ components!("wasm::shared", {
     client_bytecode_from_url: String,
     @[Networked, Store, Debuggable]
     module_enabled: bool,
+    @[Networked, Store, Debuggable, Description["Modules are loaded and run in ascending order of this value, so that they come after their dependencies."]]
+    module_load_order: u32,
     @[Networked, Store, Debuggable]
     module_errors: ModuleErrors,
 
@@ -37,6 +44,8 @@ components!("wasm::shared", {
     messenger: Arc<dyn Fn(&World, EntityId, MessageType, &str) + Send + Sync>,
     @[Resource]
     module_state_maker: Arc<dyn Fn(ModuleStateArgs<'_>) -> anyhow::Result<ModuleState> + Sync + Send>,
+    @[Resource, Debuggable, Description["Per-module, per-event execution times and host call counts."]]
+    module_profiles: ModuleProfiles,
 });
 
 pub const MAXIMUM_ERROR_COUNT: usize = 5;
@@ -83,6 +92,7 @@ pub fn systems() -> SystemGroup {
                             **enabled != has_state
                         })
                         .map(|(id, (bytecode, enabled))| (id, enabled.then(|| bytecode.clone())))
+                        .sorted_by_key(|(id, _)| load_order(world, *id))
                         .collect_vec();
 
                     for (id, bytecode) in modules {
@@ -108,31 +118,53 @@ pub fn systems() -> SystemGroup {
             })),
             Box::new(FnSystem::new(move |world, _| {
                 profiling::scope!("WASM module collision event");
-                // trigger collision event
-                let collisions = match world.resource_opt(collisions()) {
-                    Some(collisions) => collisions.lock().clone(),
+                // trigger collision events
+                if world.resource_opt(contacts()).is_none() {
+                    return;
+                }
+                for contact in get_reported_contacts(world) {
+                    let event_name = match contact.phase {
+                        ContactPhase::Begin => "core/collision",
+                        ContactPhase::Persist => "core/collision_persist",
+                        ContactPhase::End => "core/collision_end",
+                    };
+                    let data = Entity::new()
+                        .with(ambient_ecs::ids(), contact.entities.to_vec())
+                        .with(
+                            contact_points(),
+                            contact.points.iter().map(|point| point.position).collect(),
+                        )
+                        .with(
+                            contact_normals(),
+                            contact.points.iter().map(|point| point.normal).collect(),
+                        )
+                        .with(contact_impulse(), contact.total_impulse);
+
+                    run_all(world, &RunContext::new(world, event_name, data));
+                }
+            })),
+            Box::new(FnSystem::new(move |world, _| {
+                profiling::scope!("WASM module trigger events");
+                // trigger trigger area enter/exit events
+                let trigger_events = match world.resource_opt(trigger_events()) {
+                    Some(trigger_events) => trigger_events.clone(),
                     None => return,
                 };
-                for (a, b) in collisions.into_iter() {
-                    let select_entity = |px: PxRigidActorRef| {
-                        px.get_shapes()
-                            .into_iter()
-                            .next()
-                            .and_then(|shape| shape.get_user_data::<PxShapeUserData>())
-                            .map(|ud| ud.entity)
+                for event in trigger_events {
+                    let event_name = match event.kind {
+                        TriggerEventKind::Enter => "core/trigger_enter",
+                        TriggerEventKind::Exit => "core/trigger_exit",
                     };
-
-                    let ids = [select_entity(a), select_entity(b)]
-                        .into_iter()
-                        .flatten()
-                        .collect_vec();
-
                     run_all(
                         world,
                         &RunContext::new(
                             world,
-                            "core/collision",
-                            vec![ComponentEntry::new(ambient_ecs::ids(), ids)].into(),
+                            event_name,
+                            vec![ComponentEntry::new(
+                                ambient_ecs::ids(),
+                                vec![event.trigger, event.other],
+                            )]
+                            .into(),
                         ),
                     );
                 }
@@ -169,6 +201,7 @@ pub fn initialize<Bindings: bindings::BindingsBound + 'static>(
         self::module_state_maker(),
         ModuleState::create_state_maker(bindings),
     );
+    world.add_resource(module_profiles(), ModuleProfiles::default());
 
     Ok(())
 }
@@ -177,6 +210,7 @@ pub(crate) fn reload_all(world: &mut World) {
     let modules = query((module(), module_bytecode(), module_enabled()))
         .iter(world, None)
         .map(|(id, (_, bc, enabled))| (id, enabled.then(|| bc.clone())))
+        .sorted_by_key(|(id, _)| load_order(world, *id))
         .collect_vec();
 
     for (module_id, bytecode) in modules {
@@ -188,12 +222,23 @@ pub fn run_all(world: &mut World, context: &RunContext) {
     let errors: Vec<(EntityId, String)> = query(module_state())
         .collect_cloned(world, None)
         .into_iter()
+        .sorted_by_key(|(id, _)| load_order(world, *id))
         .flat_map(|(id, sms)| run(world, id, sms, context))
         .collect();
 
     update_errors(world, &errors);
 }
 
+/// Replaces the bytecode of a module, reloading the module if it is running.
+pub fn update_module_bytecode(world: &mut World, module_id: EntityId, bytecode: ModuleBytecode) {
+    world
+        .add_component(module_id, module_bytecode(), bytecode.clone())
+        .ok();
+    if world.has_component(module_id, module_state()) {
+        reload(world, module_id, Some(bytecode));
+    }
+}
+
 fn reload(world: &mut World, module_id: EntityId, bytecode: Option<ModuleBytecode>) {
     let mut errors = unload(world, module_id, "reloading");
 
@@ -217,6 +262,7 @@ fn load(
     let module_state_maker = world.resource(module_state_maker()).clone();
     let result = run_and_catch_panics(|| {
         module_state_maker(module::ModuleStateArgs {
+            module_id,
             component_bytecode,
             stdout_output: Box::new({
                 let messenger = messenger.clone();
@@ -272,6 +318,9 @@ pub(crate) fn unload(
     }
 
     world.remove_component(module_id, module_state()).unwrap();
+    if let Some(profiles) = world.resource_mut_opt(module_profiles()) {
+        profiles.0.remove(&module_id);
+    }
 
     for id in spawned_entities {
         if !world.has_component(id, dont_despawn_on_unload()) {
@@ -330,7 +379,13 @@ fn run(
         return None;
     }
 
+    let start = Instant::now();
     let result = run_and_catch_panics(|| state.run(world, context));
+    let elapsed = start.elapsed();
+
+    if let Some(profiles) = world.resource_mut_opt(module_profiles()) {
+        profiles.record(id, &context.event_name, elapsed, state.host_calls());
+    }
     world.set(id, module_state(), state).ok();
 
     result.err().map(|err| (id, err))
@@ -352,6 +407,10 @@ pub fn spawn_module(
     Ok(ed.spawn(world))
 }
 
+fn load_order(world: &World, id: EntityId) -> u32 {
+    world.get(id, module_load_order()).unwrap_or_default()
+}
+
 pub fn get_module_name(world: &World, id: EntityId) -> Identifier {
     Identifier::new(world.get_cloned(id, ambient_core::name()).unwrap()).unwrap()
 }
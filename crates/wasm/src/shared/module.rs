@@ -1,4 +1,9 @@
-use std::{any::Any, collections::HashSet, sync::Arc};
+use std::{
+    any::Any,
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
 
 use ambient_ecs::{EntityId, World};
 use data_encoding::BASE64;
@@ -6,8 +11,10 @@ use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 
 use super::{
-    bindings::BindingsBound, borrowed_types::ValueBorrow, implementation::component, wit,
-    RunContext,
+    bindings::BindingsBound,
+    borrowed_types::ValueBorrow,
+    implementation::{component, profiling},
+    wit, RunContext,
 };
 
 #[derive(Clone)]
@@ -67,20 +74,73 @@ impl<'de> Deserialize<'de> for ModuleBytecode {
 #[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct ModuleErrors(pub Vec<String>);
 
+/// Execution statistics for one event type handled by a module.
+#[derive(Default, Clone, Debug)]
+pub struct EventProfile {
+    pub runs: u64,
+    pub total_time: Duration,
+    pub last_time: Duration,
+    pub max_time: Duration,
+    pub total_host_calls: u64,
+    pub last_host_calls: u64,
+}
+impl EventProfile {
+    pub fn average_time(&self) -> Duration {
+        if self.runs == 0 {
+            Duration::ZERO
+        } else {
+            // `Duration` can only be divided by a `u32`, which `runs` can outgrow
+            Duration::from_nanos((self.total_time.as_nanos() / self.runs as u128) as u64)
+        }
+    }
+}
+
+/// Execution statistics for every loaded module, keyed by module and then by event name.
+#[derive(Default, Clone, Debug)]
+pub struct ModuleProfiles(pub HashMap<EntityId, HashMap<String, EventProfile>>);
+impl ModuleProfiles {
+    pub fn record(
+        &mut self,
+        module_id: EntityId,
+        event_name: &str,
+        time: Duration,
+        host_calls: u64,
+    ) {
+        let profile = self
+            .0
+            .entry(module_id)
+            .or_default()
+            .entry(event_name.to_string())
+            .or_default();
+
+        profile.runs += 1;
+        profile.total_time += time;
+        profile.last_time = time;
+        profile.max_time = profile.max_time.max(time);
+        profile.total_host_calls += host_calls;
+        profile.last_host_calls = host_calls;
+    }
+}
+
 struct WasmContext<Bindings: BindingsBound> {
     wasi: ambient_wasmtime_wasi::WasiCtx,
     bindings: Bindings,
+    /// The number of host functions called during the current run
+    host_calls: u64,
 }
 
 pub trait ModuleStateBehavior: Sync + Send {
     fn run(&mut self, world: &mut World, context: &RunContext) -> anyhow::Result<()>;
     fn drain_spawned_entities(&mut self) -> HashSet<EntityId>;
     fn supports_event(&self, event_name: &str) -> bool;
+    /// The number of host functions called during the last run
+    fn host_calls(&self) -> u64;
 }
 
 pub type Messenger = Box<dyn Fn(&World, &str) + Sync + Send>;
 
 pub struct ModuleStateArgs<'a> {
+    pub module_id: EntityId,
     pub component_bytecode: &'a [u8],
     pub stdout_output: Messenger,
     pub stderr_output: Messenger,
@@ -95,13 +155,15 @@ pub struct ModuleState {
 impl ModuleState {
     fn new<Bindings: BindingsBound + 'static>(
         args: ModuleStateArgs<'_>,
-        bindings: Bindings,
+        mut bindings: Bindings,
     ) -> anyhow::Result<Self> {
         let ModuleStateArgs {
+            module_id,
             component_bytecode,
             stdout_output,
             stderr_output,
         } = args;
+        bindings.base_mut().module_id = module_id;
 
         Ok(Self {
             inner: Arc::new(RwLock::new(ModuleStateInnerImpl::new(
@@ -131,6 +193,10 @@ impl ModuleStateBehavior for ModuleState {
     fn supports_event(&self, event_name: &str) -> bool {
         self.inner.read().supports_event(event_name)
     }
+
+    fn host_calls(&self) -> u64 {
+        self.inner.read().host_calls()
+    }
 }
 
 struct ModuleStateInnerImpl<Bindings: BindingsBound> {
@@ -171,12 +237,16 @@ impl<Bindings: BindingsBound> ModuleStateInnerImpl<Bindings> {
                     .stderr(stderr_output)
                     .build(),
                 bindings,
+                host_calls: 0,
             },
         );
 
         let mut linker = wasmtime::component::Linker::<WasmContext<Bindings>>::new(&engine);
         ambient_wasmtime_wasi::add_to_linker(&mut linker, |x| &mut x.wasi)?;
-        wit::Bindings::add_to_linker(&mut linker, |x| &mut x.bindings)?;
+        wit::Bindings::add_to_linker(&mut linker, |x| {
+            x.host_calls += 1;
+            &mut x.bindings
+        })?;
 
         let component = wasmtime::component::Component::from_binary(&engine, component_bytecode)?;
 
@@ -205,6 +275,7 @@ impl<Bindings: BindingsBound> ModuleStateBehavior for ModuleStateInnerImpl<Bindi
             time,
         } = context;
 
+        self.store.data_mut().host_calls = 0;
         self.store.data_mut().bindings.set_world(world);
 
         let components = component::convert_entity_data_to_components(event_data);
@@ -213,9 +284,13 @@ impl<Bindings: BindingsBound> ModuleStateBehavior for ModuleStateInnerImpl<Bindi
             .map(|(k, v)| (*k, ValueBorrow::from(v)))
             .collect();
         let components: Vec<_> = components.iter().map(|(k, v)| (*k, v.as_wit())).collect();
-        self.guest_bindings
-            .guest()
-            .call_exec(&mut self.store, *time, event_name, &components)?;
+        let result =
+            self.guest_bindings
+                .guest()
+                .call_exec(&mut self.store, *time, event_name, &components);
+        // Guest scopes cannot outlive the run that opened them.
+        profiling::end_all_scopes(&mut self.store.data_mut().bindings.base_mut().profiling_scopes);
+        result?;
 
         self.store.data_mut().bindings.clear_world();
 
@@ -237,6 +312,10 @@ impl<Bindings: BindingsBound> ModuleStateBehavior for ModuleStateInnerImpl<Bindi
             .subscribed_events
             .contains(event_name)
     }
+
+    fn host_calls(&self) -> u64 {
+        self.store.data().host_calls
+    }
 }
 
 struct WasiOutputStream(flume::Sender<String>);
@@ -0,0 +1,275 @@
+//! Runs the WASM processors of custom asset pipelines. Processors are components that implement the `processor`
+//! world, and only have access to the files of their pipeline through [pipeline_host::Host].
+
+use std::{
+    any::Any,
+    sync::mpsc::{self, RecvTimeoutError},
+    time::Duration,
+};
+
+wasmtime::component::bindgen!("pipeline.processor" in "wit");
+
+/// How much a processor can do before it is stopped, so that a processor stuck in a loop can't stall the build.
+#[derive(Debug, Clone, Copy)]
+pub struct ProcessorLimits {
+    /// The fuel the processor can consume, which is roughly the number of WASM instructions it can run.
+    pub fuel: u64,
+    /// How long the processor can run for.
+    pub timeout: Duration,
+}
+impl Default for ProcessorLimits {
+    fn default() -> Self {
+        Self {
+            fuel: 1_000_000_000_000,
+            timeout: Duration::from_secs(10 * 60),
+        }
+    }
+}
+
+struct ProcessorContext<Host> {
+    wasi: ambient_wasmtime_wasi::WasiCtx,
+    host: Host,
+}
+
+/// Runs the `process` function of the processor in `component_bytecode` with `config`, giving it access to `host`,
+/// and returns `host` once the processor is done.
+///
+/// Anything the processor prints is logged, prefixed with `name`. The processor is stopped with an error if it goes
+/// over `limits`.
+pub fn run_processor<Host: pipeline_host::Host + Send + 'static>(
+    name: &str,
+    component_bytecode: &[u8],
+    host: Host,
+    config: &str,
+    limits: ProcessorLimits,
+) -> anyhow::Result<Host> {
+    let mut engine_config = wasmtime::Config::new();
+    engine_config.wasm_backtrace_details(wasmtime::WasmBacktraceDetails::Enable);
+    engine_config.wasm_component_model(true);
+    engine_config.consume_fuel(true);
+    engine_config.epoch_interruption(true);
+    let engine = wasmtime::Engine::new(&engine_config)?;
+
+    let mut store = wasmtime::Store::new(
+        &engine,
+        ProcessorContext {
+            wasi: ambient_wasmtime_wasi::WasiCtxBuilder::new()
+                .stdout(Box::new(LogOutputStream {
+                    name: name.to_string(),
+                    level: log::Level::Info,
+                }))
+                .stderr(Box::new(LogOutputStream {
+                    name: name.to_string(),
+                    level: log::Level::Warn,
+                }))
+                .build(),
+            host,
+        },
+    );
+    store.add_fuel(limits.fuel)?;
+    store.set_epoch_deadline(1);
+
+    let mut linker = wasmtime::component::Linker::<ProcessorContext<Host>>::new(&engine);
+    ambient_wasmtime_wasi::add_to_linker(&mut linker, |x| &mut x.wasi)?;
+    Processor::add_to_linker(&mut linker, |x| &mut x.host)?;
+
+    let component = wasmtime::component::Component::new(&engine, component_bytecode)?;
+
+    // The epoch is only incremented if the processor is still running once the timeout has passed
+    let (done, timeout) = mpsc::channel::<()>();
+    let timer = std::thread::spawn({
+        let engine = engine.clone();
+        move || {
+            if let Err(RecvTimeoutError::Timeout) = timeout.recv_timeout(limits.timeout) {
+                engine.increment_epoch();
+            }
+        }
+    });
+    let result = Processor::instantiate(&mut store, &component, &linker).and_then(
+        |(processor, _instance)| processor.pipeline_guest().call_process(&mut store, config),
+    );
+    drop(done);
+    timer.join().ok();
+
+    let result = result.map_err(|err| match err.downcast_ref::<wasmtime::Trap>() {
+        Some(wasmtime::Trap::OutOfFuel) => err.context(format!(
+            "The processor ran out of its {} units of fuel",
+            limits.fuel
+        )),
+        Some(wasmtime::Trap::Interrupt) => err.context(format!(
+            "The processor was stopped after running for {:?}",
+            limits.timeout
+        )),
+        _ => err,
+    })?;
+    result.map_err(|err| anyhow::anyhow!(err))?;
+
+    Ok(store.into_data().host)
+}
+
+struct LogOutputStream {
+    name: String,
+    level: log::Level,
+}
+
+#[async_trait::async_trait]
+impl wasi_common::OutputStream for LogOutputStream {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn writable(&self) -> Result<(), wasi_common::Error> {
+        Ok(())
+    }
+
+    async fn write(&mut self, buf: &[u8]) -> Result<u64, wasi_common::Error> {
+        let msg = std::str::from_utf8(buf)
+            .map_err(|e| wasi_common::Error::trap(e.into()))?
+            .trim();
+        log::log!(self.level, "[{}] {}", self.name, msg);
+        Ok(buf.len().try_into()?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Writes `hello` to `out.txt` with the host, and returns the host's result.
+    const WRITING_PROCESSOR: &str = r#"
+        (component
+            (import "pipeline-host" (instance $host
+                (export "write-file" (func (param "path" string) (param "contents" (list u8)) (result (result (error string)))))
+            ))
+            (core module $memory-module
+                (memory (export "memory") 1)
+                (func (export "realloc") (param i32 i32 i32 i32) (result i32) i32.const 1024)
+            )
+            (core instance $memory (instantiate $memory-module))
+            (core func $write-file (canon lower (func $host "write-file")
+                (memory $memory "memory") (realloc (func $memory "realloc"))))
+            (core module $processor-module
+                (import "memory" "memory" (memory 1))
+                (import "host" "write-file" (func $write-file (param i32 i32 i32 i32 i32)))
+                (data (i32.const 16) "out.txt")
+                (data (i32.const 32) "hello")
+                (func (export "process") (param i32 i32) (result i32)
+                    (call $write-file (i32.const 16) (i32.const 7) (i32.const 32) (i32.const 5) (i32.const 48))
+                    i32.const 48)
+            )
+            (core instance $processor (instantiate $processor-module
+                (with "memory" (instance $memory))
+                (with "host" (instance (export "write-file" (func $write-file))))
+            ))
+            (func $process (param "config" string) (result (result (error string)))
+                (canon lift (core func $processor "process")
+                    (memory $memory "memory") (realloc (func $memory "realloc"))))
+            (instance $guest (export "process" (func $process)))
+            (export "pipeline-guest" (instance $guest))
+        )
+    "#;
+
+    /// Never returns.
+    const LOOPING_PROCESSOR: &str = r#"
+        (component
+            (core module $processor-module
+                (memory (export "memory") 1)
+                (func (export "realloc") (param i32 i32 i32 i32) (result i32) i32.const 1024)
+                (func (export "process") (param i32 i32) (result i32)
+                    (loop $forever (br $forever))
+                    i32.const 0)
+            )
+            (core instance $processor (instantiate $processor-module))
+            (func $process (param "config" string) (result (result (error string)))
+                (canon lift (core func $processor "process")
+                    (memory $processor "memory") (realloc (func $processor "realloc"))))
+            (instance $guest (export "process" (func $process)))
+            (export "pipeline-guest" (instance $guest))
+        )
+    "#;
+
+    #[derive(Default)]
+    struct TestHost {
+        written: Vec<(String, Vec<u8>)>,
+    }
+    impl pipeline_host::Host for TestHost {
+        fn files(&mut self) -> anyhow::Result<Vec<String>> {
+            Ok(vec![])
+        }
+
+        fn read_file(&mut self, path: String) -> anyhow::Result<Result<Vec<u8>, String>> {
+            Ok(Err(format!("`{path}` is not an input of this pipeline")))
+        }
+
+        fn write_file(
+            &mut self,
+            path: String,
+            contents: Vec<u8>,
+        ) -> anyhow::Result<Result<(), String>> {
+            self.written.push((path, contents));
+            Ok(Ok(()))
+        }
+
+        fn add_asset(
+            &mut self,
+            _path: String,
+            _asset_type: String,
+            _name: String,
+            _tags: Vec<String>,
+            _source: Option<String>,
+        ) -> anyhow::Result<Result<(), String>> {
+            Ok(Ok(()))
+        }
+    }
+
+    #[test]
+    fn processor_writes_through_the_host() {
+        let host = run_processor(
+            "writer",
+            WRITING_PROCESSOR.as_bytes(),
+            TestHost::default(),
+            "{}",
+            ProcessorLimits::default(),
+        )
+        .unwrap();
+        assert_eq!(host.written, [("out.txt".to_string(), b"hello".to_vec())]);
+    }
+
+    #[test]
+    fn processor_is_stopped_when_out_of_fuel() {
+        let limits = ProcessorLimits {
+            fuel: 10_000,
+            ..Default::default()
+        };
+        let err = run_processor(
+            "looper",
+            LOOPING_PROCESSOR.as_bytes(),
+            TestHost::default(),
+            "{}",
+            limits,
+        )
+        .err()
+        .unwrap();
+        assert!(err
+            .to_string()
+            .contains("ran out of its 10000 units of fuel"));
+    }
+
+    #[test]
+    fn processor_is_stopped_after_the_timeout() {
+        let limits = ProcessorLimits {
+            timeout: Duration::from_millis(100),
+            ..Default::default()
+        };
+        let err = run_processor(
+            "looper",
+            LOOPING_PROCESSOR.as_bytes(),
+            TestHost::default(),
+            "{}",
+            limits,
+        )
+        .err()
+        .unwrap();
+        assert!(err.to_string().contains("was stopped after running for"));
+    }
+}
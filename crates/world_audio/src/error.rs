@@ -8,6 +8,8 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub enum Error {
     #[error("The sound effect {0:?} does not exist")]
     MissingEffect(String),
+    #[error("Invalid sound graph: {0}")]
+    InvalidGraph(String),
     #[error("There are no more available sinks")]
     NoAvailableSink,
     #[error(transparent)]
@@ -1,7 +1,9 @@
+use std::time::Duration;
+
 use ambient_core::asset_cache;
 use ambient_ecs::{EntityId, World};
 
-use crate::{play_sound_on_entity, AudioNode, AudioSeed};
+use crate::{play_labeled_sound_on_entity, playing_sounds, AudioNode, AudioSeed};
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
 /// Plays a sound on an entity. Requires that the entity has an AudioEmitterDef on the server.
 /// Otherwise, the audio is played on a temporary emitter
@@ -23,16 +25,25 @@ pub fn play_local_sound(world: &mut World, event: PlayLocalSound) -> anyhow::Res
         }
     };
 
-    play_sound_on_entity(world, event.id, source)?;
+    play_labeled_sound_on_entity(world, event.id, source, Some(event.label))?;
     Ok(())
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+/// Stops the sounds played on an entity with [PlayLocalSound] whose label is `name`
 pub struct StopLocalSound {
     pub id: EntityId,
     pub name: String,
+    /// The time in seconds to fade the sounds out over
+    #[serde(default)]
+    pub fade_out: f32,
 }
 
-pub fn stop_local_sound(_world: &mut World, _event: StopLocalSound) -> anyhow::Result<()> {
-    anyhow::bail!("stopping a sound is not supported")
+pub fn stop_local_sound(world: &mut World, event: StopLocalSound) -> anyhow::Result<()> {
+    let sounds = world.get_ref(event.id, playing_sounds())?;
+    let fade_out = Duration::try_from_secs_f32(event.fade_out)?;
+    for sound in sounds.values().filter(|sound| sound.label() == Some(event.name.as_str())) {
+        sound.stop(fade_out);
+    }
+    Ok(())
 }
@@ -1,18 +1,21 @@
-use std::fmt::Debug;
+use std::{collections::HashMap, fmt::Debug, sync::Arc, time::Duration};
 
-use ambient_audio::{Source, VorbisFromUrl};
+use ambient_audio::{vorbis::VorbisTrack, BufferedSource, Frame, SampleRate, Source, Uniform, VorbisFromUrl};
 use ambient_std::{
     self,
     asset_cache::{AssetCache, AsyncAssetKeyExt},
     asset_url::AbsAssetUrl,
 };
-use rand::{thread_rng, Rng, SeedableRng};
+use itertools::Itertools;
+use rand::{distributions::WeightedIndex, prelude::Distribution, thread_rng, Rng, SeedableRng};
 use rand_chacha::ChaCha12Rng;
 
-use crate::error::Result;
+use crate::error::{Error, Result};
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 /// Textual representation of a node in the audio graph which specifies how to construct a Sound.
+///
+/// All times and durations are in seconds.
 pub enum AudioNode {
     /// A source which does nothing
     Identity,
@@ -21,6 +24,34 @@ pub enum AudioNode {
         /// Url asset
         url: String,
     },
+    /// Multiply the volume of the input
+    Gain { input: Box<AudioNode>, gain: f32 },
+    /// Play all inputs at the same time, until the longest one ends.
+    /// Inputs that never end are cut off when the others end.
+    Mix { inputs: Vec<AudioNode> },
+    /// Play the inputs one after another
+    Chain { inputs: Vec<AudioNode> },
+    /// Play `from`, and fade over to `to` during the last `duration` of `from`
+    Crossfade { from: Box<AudioNode>, to: Box<AudioNode>, duration: f32 },
+    /// Play the input `count` times, or forever if not specified.
+    /// The input is built again for each repetition, so its random choices are made again.
+    Repeat { input: Box<AudioNode>, count: Option<u32> },
+    /// Play the input from `start` to `end`, or to its end if not specified
+    Slice { input: Box<AudioNode>, start: f32, end: Option<f32> },
+    /// Attenuate the frequencies of the input above `freq`, in Hz
+    LowPass { input: Box<AudioNode>, freq: f32, bandwidth: f32 },
+    /// Attenuate the frequencies of the input below `freq`, in Hz
+    HighPass { input: Box<AudioNode>, freq: f32, bandwidth: f32 },
+    /// Play one of the inputs, picked at random with the seed of the sound.
+    /// The inputs are equally likely unless `weights` are specified, with one weight per input.
+    RandomChoice { inputs: Vec<AudioNode>, weights: Option<Vec<f32>> },
+    /// Play the input faster or slower by `factor`, which also raises or lowers its pitch
+    Speed { input: Box<AudioNode>, factor: f32 },
+    /// Play silence for `duration` before the input
+    Delay { input: Box<AudioNode>, duration: f32 },
+    /// Multiply the volume of the input by a gain which is linearly interpolated between `(time, gain)` points.
+    /// The gain is held at the first point before it, and at the last point after it.
+    Envelope { input: Box<AudioNode>, points: Vec<(f32, f32)> },
 }
 
 impl Default for AudioNode {
@@ -31,17 +62,271 @@ impl Default for AudioNode {
 
 impl AudioNode {
     /// Builds the adapter into a proper source.
-    /// If the graph can not immediately be built, it returns None
-    pub fn try_build(self, assets: &AssetCache, _seed: AudioSeed) -> Result<Option<Box<dyn Source>>> {
+    /// If the audio files of the graph are not loaded yet, it returns None
+    pub fn try_build(self, assets: &AssetCache, seed: AudioSeed) -> Result<Option<Box<dyn Source>>> {
+        let Some(tracks) = self.peek_tracks(assets)? else { return Ok(None) };
+        self.build(&Arc::new(tracks), seed).map(Some)
+    }
+
+    /// The tracks of all the Vorbis files in this graph, including the inputs that random choices may not pick,
+    /// or None if some of them are not loaded yet
+    fn peek_tracks(&self, assets: &AssetCache) -> Result<Option<Tracks>> {
+        let mut tracks = Tracks::new();
+        let mut nodes = vec![self];
+        while let Some(node) = nodes.pop() {
+            if let AudioNode::Vorbis { url } = node {
+                let parsed = AbsAssetUrl::parse(url).map_err(|err| Error::InvalidGraph(format!("Invalid url {url:?}: {err}")))?;
+                let Some(track) = VorbisFromUrl { url: parsed }.peek(assets).transpose()? else { return Ok(None) };
+                tracks.insert(url.clone(), track);
+            }
+            nodes.extend(node.inputs());
+        }
+        Ok(Some(tracks))
+    }
+
+    /// Builds the source with the tracks of [Self::peek_tracks]
+    fn build(self, tracks: &Arc<Tracks>, seed: AudioSeed) -> Result<Box<dyn Source>> {
+        let mut rng = ChaCha12Rng::from_seed(seed.rng_seed);
+        let source: Box<dyn Source> = match self {
+            AudioNode::Identity => empty_source(),
+            AudioNode::Vorbis { url } => Box::new(tracks[&url].decode()),
+            AudioNode::Gain { input, gain } => Box::new(input.build(tracks, AudioSeed::from_rng(&mut rng))?.gain(gain)),
+            AudioNode::Mix { inputs } => {
+                let inputs = build_all(inputs, tracks, &mut rng)?;
+                // Mixing stops when any input ends, so the inputs are padded to the longest one that ends
+                let longest = inputs.iter().filter_map(|input| input.duration()).max();
+                let inputs = inputs.into_iter().map(|input| match longest {
+                    Some(longest) => Box::new(input.pad_to(longest)) as Box<dyn Source>,
+                    None => input,
+                });
+                inputs.reduce(|acc, input| Box::new(acc.mix(input)) as Box<dyn Source>).unwrap_or_else(empty_source)
+            }
+            AudioNode::Chain { inputs } => {
+                let inputs = build_all(inputs, tracks, &mut rng)?;
+                inputs.into_iter().reduce(|acc, input| Box::new(acc.chain(input)) as Box<dyn Source>).unwrap_or_else(empty_source)
+            }
+            AudioNode::Crossfade { from, to, duration } => {
+                let from = from.build(tracks, AudioSeed::from_rng(&mut rng))?;
+                let to = to.build(tracks, AudioSeed::from_rng(&mut rng))?;
+                let duration = from.duration().map_or(duration_from_secs(duration), |d| d.min(duration_from_secs(duration)));
+                Box::new(from.crossfade(to, duration))
+            }
+            AudioNode::Repeat { input, count } => {
+                let source = input.clone().build(tracks, AudioSeed::from_rng(&mut rng))?;
+                Box::new(RepeatNode {
+                    node: *input,
+                    tracks: tracks.clone(),
+                    sample_rate: source.sample_rate(),
+                    source,
+                    rng,
+                    remaining: count.map(|count| count.saturating_sub(1)),
+                    played: false,
+                })
+            }
+            AudioNode::Slice { input, start, end } => {
+                let input = input.build(tracks, AudioSeed::from_rng(&mut rng))?;
+                let start = duration_from_secs(start);
+                match end {
+                    Some(end) => Box::new(input.slice(start..duration_from_secs(end).max(start))),
+                    None => Box::new(input.skip(start)),
+                }
+            }
+            AudioNode::LowPass { input, freq, bandwidth } => {
+                let input = input.build(tracks, AudioSeed::from_rng(&mut rng))?;
+                Box::new(input.low_pass(freq, bandwidth))
+            }
+            AudioNode::HighPass { input, freq, bandwidth } => {
+                let input = input.build(tracks, AudioSeed::from_rng(&mut rng))?;
+                Box::new(input.high_pass(freq, bandwidth))
+            }
+            AudioNode::RandomChoice { mut inputs, weights } => {
+                if inputs.is_empty() {
+                    return Ok(empty_source());
+                }
+                let index = match weights {
+                    Some(weights) if weights.len() != inputs.len() => {
+                        return Err(Error::InvalidGraph(format!("RandomChoice has {} inputs but {} weights", inputs.len(), weights.len())));
+                    }
+                    Some(weights) => WeightedIndex::new(weights)
+                        .map_err(|err| Error::InvalidGraph(format!("Invalid random choice weights: {err}")))?
+                        .sample(&mut rng),
+                    None => rng.gen_range(0..inputs.len()),
+                };
+                return inputs.swap_remove(index).build(tracks, AudioSeed::from_rng(&mut rng));
+            }
+            AudioNode::Speed { input, factor } => {
+                let input = input.build(tracks, AudioSeed::from_rng(&mut rng))?;
+                Box::new(input.speed(factor))
+            }
+            AudioNode::Delay { input, duration } => {
+                let input = input.build(tracks, AudioSeed::from_rng(&mut rng))?;
+                Box::new(input.delay(duration_from_secs(duration)))
+            }
+            AudioNode::Envelope { input, points } => {
+                let input = input.build(tracks, AudioSeed::from_rng(&mut rng))?;
+                Box::new(input.envelope(points.into_iter().map(|(time, gain)| (duration_from_secs(time), gain)).collect_vec()))
+            }
+        };
+        Ok(source)
+    }
+
+    /// The nodes this node plays
+    pub fn inputs(&self) -> Vec<&AudioNode> {
         match self {
-            AudioNode::Vorbis { url } => {
-                let track = VorbisFromUrl { url: AbsAssetUrl::parse(url).unwrap() }.peek(assets).transpose()?;
-                match track {
-                    Some(track) => Ok(Some(Box::new(track.decode()))),
-                    None => Ok(None),
+            AudioNode::Identity | AudioNode::Vorbis { .. } => Vec::new(),
+            AudioNode::Mix { inputs } | AudioNode::Chain { inputs } | AudioNode::RandomChoice { inputs, .. } => inputs.iter().collect(),
+            AudioNode::Crossfade { from, to, .. } => vec![&**from, &**to],
+            AudioNode::Gain { input, .. }
+            | AudioNode::Repeat { input, .. }
+            | AudioNode::Slice { input, .. }
+            | AudioNode::LowPass { input, .. }
+            | AudioNode::HighPass { input, .. }
+            | AudioNode::Speed { input, .. }
+            | AudioNode::Delay { input, .. }
+            | AudioNode::Envelope { input, .. } => vec![&**input],
+        }
+    }
+
+    /// The nodes this node plays
+    pub fn inputs_mut(&mut self) -> Vec<&mut AudioNode> {
+        match self {
+            AudioNode::Identity | AudioNode::Vorbis { .. } => Vec::new(),
+            AudioNode::Mix { inputs } | AudioNode::Chain { inputs } | AudioNode::RandomChoice { inputs, .. } => inputs.iter_mut().collect(),
+            AudioNode::Crossfade { from, to, .. } => vec![&mut **from, &mut **to],
+            AudioNode::Gain { input, .. }
+            | AudioNode::Repeat { input, .. }
+            | AudioNode::Slice { input, .. }
+            | AudioNode::LowPass { input, .. }
+            | AudioNode::HighPass { input, .. }
+            | AudioNode::Speed { input, .. }
+            | AudioNode::Delay { input, .. }
+            | AudioNode::Envelope { input, .. } => vec![&mut **input],
+        }
+    }
+
+    /// Checks that the parameters of this node and its inputs are in range, so that the graph can be built
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |message: String| Err(Error::InvalidGraph(message));
+        let check_time = |name: &str, value: f32| {
+            if value.is_finite() && value >= 0. {
+                Ok(())
+            } else {
+                invalid(format!("{name} must be zero or more seconds, but is {value}"))
+            }
+        };
+        match self {
+            AudioNode::Identity | AudioNode::Vorbis { .. } => {}
+            AudioNode::Gain { gain, .. } if !gain.is_finite() => return invalid(format!("Gain must be finite, but is {gain}")),
+            AudioNode::Mix { inputs } | AudioNode::Chain { inputs } | AudioNode::RandomChoice { inputs, .. } if inputs.is_empty() => {
+                return invalid("Mix, Chain and RandomChoice need at least one input".to_string());
+            }
+            AudioNode::RandomChoice { inputs, weights: Some(weights) } => {
+                if weights.len() != inputs.len() {
+                    return invalid(format!("RandomChoice has {} inputs but {} weights", inputs.len(), weights.len()));
                 }
+                if weights.iter().any(|w| !w.is_finite() || *w < 0.) || weights.iter().all(|w| *w == 0.) {
+                    return invalid(format!("RandomChoice weights must be positive, and not all zero, but are {weights:?}"));
+                }
+            }
+            AudioNode::Crossfade { duration, .. } => check_time("Crossfade duration", *duration)?,
+            AudioNode::Repeat { count: Some(0), .. } => return invalid("Repeat count must be at least 1".to_string()),
+            AudioNode::Slice { start, end, .. } => {
+                check_time("Slice start", *start)?;
+                if let Some(end) = end {
+                    check_time("Slice end", *end)?;
+                    if end < start {
+                        return invalid(format!("Slice end ({end}) is before its start ({start})"));
+                    }
+                }
+            }
+            AudioNode::LowPass { freq, bandwidth, .. } | AudioNode::HighPass { freq, bandwidth, .. } => {
+                if !(freq.is_finite() && *freq > 0. && bandwidth.is_finite() && *bandwidth > 0.) {
+                    return invalid(format!("Filter frequency ({freq}) and bandwidth ({bandwidth}) must be greater than zero"));
+                }
+            }
+            AudioNode::Speed { factor, .. } if !(factor.is_finite() && *factor > 0.) => {
+                return invalid(format!("Speed factor must be greater than zero, but is {factor}"));
+            }
+            AudioNode::Delay { duration, .. } => check_time("Delay duration", *duration)?,
+            AudioNode::Envelope { points, .. } => {
+                for (time, gain) in points {
+                    check_time("Envelope time", *time)?;
+                    if !gain.is_finite() {
+                        return invalid(format!("Envelope gain must be finite, but is {gain}"));
+                    }
+                }
+            }
+            _ => {}
+        }
+        self.inputs().into_iter().try_for_each(|input| input.validate())
+    }
+}
+
+/// The decoded Vorbis files of a graph, by their url
+type Tracks = HashMap<String, Arc<VorbisTrack>>;
+
+fn build_all(inputs: Vec<AudioNode>, tracks: &Arc<Tracks>, rng: &mut ChaCha12Rng) -> Result<Vec<Box<dyn Source>>> {
+    inputs.into_iter().map(|input| input.build(tracks, AudioSeed::from_rng(rng))).collect()
+}
+
+fn empty_source() -> Box<dyn Source> {
+    Box::new(BufferedSource::new([0.0f32; 0], 1, 44100))
+}
+
+/// Negative and non-finite times are treated as zero; [AudioNode::validate] rejects them
+fn duration_from_secs(secs: f32) -> Duration {
+    Duration::try_from_secs_f32(secs).unwrap_or_default()
+}
+
+/// Plays a node repeatedly, building it again each time it ends
+struct RepeatNode {
+    node: AudioNode,
+    /// The tracks of every Vorbis file in `node`, so that whichever inputs its random choices pick can be built
+    tracks: Arc<Tracks>,
+    rng: ChaCha12Rng,
+    sample_rate: SampleRate,
+    source: Box<dyn Source>,
+    /// The number of repetitions left after the current one, or None to repeat forever
+    remaining: Option<u32>,
+    /// Whether the current repetition has played any samples, so that empty inputs don't repeat forever
+    played: bool,
+}
+
+impl Source for RepeatNode {
+    fn next_sample(&mut self) -> Option<Frame> {
+        loop {
+            if let Some(sample) = self.source.next_sample() {
+                self.played = true;
+                return Some(sample);
+            }
+            if !self.played {
+                return None;
             }
-            _ => unimplemented!(),
+            match &mut self.remaining {
+                Some(0) => return None,
+                Some(remaining) => *remaining -= 1,
+                None => {}
+            }
+            let source = match self.node.clone().build(&self.tracks, AudioSeed::from_rng(&mut self.rng)) {
+                Ok(source) => source,
+                Err(err) => {
+                    tracing::warn!("Failed to repeat sound: {err}");
+                    return None;
+                }
+            };
+            self.source = Box::new(Uniform::new(source, self.sample_rate));
+            self.played = false;
+        }
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        self.sample_rate
+    }
+
+    fn sample_count(&self) -> Option<u64> {
+        match self.remaining {
+            Some(0) => self.source.sample_count(),
+            _ => None,
         }
     }
 }
@@ -56,6 +341,11 @@ impl AudioSeed {
     pub fn new() -> Self {
         Self { rng_seed: thread_rng().gen() }
     }
+
+    /// A seed drawn from `rng`, for building the inputs of a node deterministically
+    fn from_rng(rng: &mut impl Rng) -> Self {
+        Self { rng_seed: rng.gen() }
+    }
 }
 
 impl Default for AudioSeed {
@@ -63,3 +353,89 @@ impl Default for AudioSeed {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn delay(duration: f32) -> AudioNode {
+        AudioNode::Delay { input: Box::new(AudioNode::Identity), duration }
+    }
+
+    fn build(node: AudioNode, seed: AudioSeed) -> Box<dyn Source> {
+        let tracks = node.peek_tracks(&AssetCache::new(tokio::runtime::Handle::current())).unwrap().unwrap();
+        node.build(&Arc::new(tracks), seed).unwrap()
+    }
+
+    fn count_samples(mut source: Box<dyn Source>, cap: usize) -> usize {
+        std::iter::from_fn(|| source.next_sample()).take(cap).count()
+    }
+
+    #[test]
+    fn validate() {
+        let repeat = |count| AudioNode::Repeat { input: Box::new(delay(1.)), count };
+        assert!(AudioNode::Mix { inputs: vec![repeat(Some(2)), repeat(None)] }.validate().is_ok());
+        assert!(AudioNode::Mix { inputs: vec![repeat(Some(2)), repeat(Some(0))] }.validate().is_err());
+        assert!(AudioNode::Mix { inputs: Vec::new() }.validate().is_err());
+
+        let slice = |start, end| AudioNode::Slice { input: Box::new(delay(1.)), start, end };
+        assert!(slice(0.25, Some(0.5)).validate().is_ok());
+        assert!(slice(0.5, Some(0.25)).validate().is_err());
+        assert!(slice(-1., None).validate().is_err());
+
+        let choice = |weights| AudioNode::RandomChoice { inputs: vec![delay(1.), delay(2.)], weights };
+        assert!(choice(Some(vec![1., 3.])).validate().is_ok());
+        assert!(choice(Some(vec![1.])).validate().is_err());
+        assert!(choice(Some(vec![0., 0.])).validate().is_err());
+        assert!(AudioNode::Speed { input: Box::new(delay(1.)), factor: 0. }.validate().is_err());
+    }
+
+    #[tokio::test]
+    async fn try_build_waits_for_every_vorbis_file() {
+        let assets = AssetCache::new(tokio::runtime::Handle::current());
+        let missing = || AudioNode::Vorbis { url: "https://example.com/missing.ogg".to_string() };
+
+        assert!(AudioNode::Identity.try_build(&assets, AudioSeed::new()).unwrap().is_some());
+        assert!(missing().try_build(&assets, AudioSeed::new()).unwrap().is_none());
+        // Whichever input is picked, a repeat may pick the other one later
+        let repeat = AudioNode::Repeat {
+            input: Box::new(AudioNode::RandomChoice { inputs: vec![AudioNode::Identity, missing()], weights: Some(vec![1., 0.]) }),
+            count: None,
+        };
+        assert!(repeat.try_build(&assets, AudioSeed::new()).unwrap().is_none());
+        assert!(AudioNode::Vorbis { url: "not a url".to_string() }.try_build(&assets, AudioSeed::new()).is_err());
+    }
+
+    #[tokio::test]
+    async fn random_choices_follow_the_seed() {
+        let choice = AudioNode::RandomChoice { inputs: (1..=8).map(|i| delay(i as f32 * 0.125)).collect(), weights: None };
+        let sample_counts = (0..8u8)
+            .map(|n| {
+                let sample_count = build(choice.clone(), AudioSeed { rng_seed: [n; 32] }).sample_count();
+                assert_eq!(build(choice.clone(), AudioSeed { rng_seed: [n; 32] }).sample_count(), sample_count);
+                sample_count
+            })
+            .collect_vec();
+        assert!(sample_counts.iter().any(|count| *count != sample_counts[0]), "{sample_counts:?}");
+    }
+
+    #[tokio::test]
+    async fn speed() {
+        let fast = build(AudioNode::Speed { input: Box::new(delay(1.)), factor: 2. }, AudioSeed::new());
+        assert_eq!(fast.duration(), Some(Duration::from_millis(500)));
+    }
+
+    #[tokio::test]
+    async fn mix_pads_to_the_longest_finite_input() {
+        let forever = AudioNode::Repeat { input: Box::new(delay(0.25)), count: None };
+        let mix = build(AudioNode::Mix { inputs: vec![delay(1.), delay(0.5), forever] }, AudioSeed::new());
+        assert_eq!(count_samples(mix, 4 * 44100), 44100);
+    }
+
+    #[tokio::test]
+    async fn repeat() {
+        let repeat = build(AudioNode::Repeat { input: Box::new(delay(0.25)), count: Some(3) }, AudioSeed::new());
+        assert_eq!(repeat.sample_count(), None);
+        assert_eq!(count_samples(repeat, 4 * 44100), 3 * 11025);
+    }
+}
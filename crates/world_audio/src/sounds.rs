@@ -1,9 +1,25 @@
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
-use ambient_audio::{hrtf::HrtfLib, Attenuation, AudioEmitter, AudioListener, AudioMixer, Sound, Source};
-use ambient_ecs::{components, query, EntityId, Resource, World};
+use ambient_audio::{
+    hrtf::HrtfLib, Attenuation, AudioEmitter, AudioListener, AudioMixer, Frame, SampleRate, Sound, SoundParams, Source, VorbisFromUrl,
+};
+use ambient_core::{asset_cache, async_ecs::async_run, runtime};
+use ambient_ecs::{components, query, Debuggable, Description, EntityId, Name, Resource, World};
 use ambient_element::ElementComponentExt;
-use ambient_std::{cb, Cb};
+use ambient_std::{
+    asset_cache::{AssetCache, AsyncAssetKeyExt},
+    asset_url::AbsAssetUrl,
+    cb,
+    download_asset::JsonFromUrl,
+    Cb,
+};
 use ambient_ui::{
     graph::{Graph, GraphStyle},
     Editor, FlowColumn,
@@ -15,6 +31,8 @@ use itertools::Itertools;
 use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 
+use crate::{AudioNode, AudioSeed};
+
 components!("audio", {
     @[Resource]
     hrtf_lib: Arc<HrtfLib>,
@@ -23,8 +41,111 @@ components!("audio", {
 
     @[Resource]
     audio_mixer: AudioMixer,
+
+    @[
+        Debuggable,
+        Name["Playing sounds"],
+        Description["The sounds playing on this entity, by their id. Sounds are removed once they finish."]
+    ]
+    playing_sounds: HashMap<SoundInstanceId, SoundInstance>,
 });
 
+/// The lowest low-pass filter cutoff, in Hz
+const MIN_CUTOFF: f32 = 10.;
+/// The highest low-pass filter cutoff, in Hz, which stays below the Nyquist frequency of the mixer
+const MAX_CUTOFF: f32 = 20_000.;
+
+/// Identifies a sound playing on an entity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct SoundInstanceId(pub u64);
+
+impl SoundInstanceId {
+    fn next() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(1);
+        Self(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// Controls a sound playing on an entity while it plays
+#[derive(Debug, Clone, Default)]
+pub struct SoundInstance {
+    params: Arc<Mutex<SoundParams>>,
+    finished: Arc<AtomicBool>,
+    label: Option<String>,
+}
+
+impl SoundInstance {
+    /// Fades the sound out over `fade_out`, and stops it
+    pub fn stop(&self, fade_out: Duration) {
+        self.params.lock().stop.get_or_insert(fade_out);
+    }
+
+    /// Sets the volume multiplier of the sound. Negative gains are treated as 0, and gains that aren't finite are ignored
+    pub fn set_gain(&self, gain: f32) {
+        if gain.is_finite() {
+            self.params.lock().gain = gain.max(0.);
+        }
+    }
+
+    /// Sets the playback speed multiplier of the sound, which also raises or lowers its pitch
+    pub fn set_pitch(&self, pitch: f32) {
+        self.params.lock().pitch = pitch;
+    }
+
+    /// Sets the cutoff frequency of the low-pass filter of the sound, in Hz, or removes the filter. The cutoff is clamped to
+    /// the range of human hearing, which the filter is stable in, and cutoffs that aren't finite are ignored
+    pub fn set_cutoff(&self, cutoff: Option<f32>) {
+        match cutoff {
+            Some(cutoff) if !cutoff.is_finite() => {}
+            _ => self.params.lock().cutoff = cutoff.map(|cutoff| cutoff.clamp(MIN_CUTOFF, MAX_CUTOFF)),
+        }
+    }
+
+    /// Whether the sound has finished playing, or failed to load
+    pub fn is_finished(&self) -> bool {
+        self.finished.load(Ordering::Relaxed)
+    }
+
+    /// The human readable label of the sound, if it was played with one
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+}
+
+/// Marks its sound instance as finished once the mixer drops it
+struct Tracked<S> {
+    source: S,
+    finished: Arc<AtomicBool>,
+}
+
+impl<S: Source> Source for Tracked<S> {
+    fn next_sample(&mut self) -> Option<Frame> {
+        self.source.next_sample()
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        self.source.sample_rate()
+    }
+
+    fn sample_count(&self) -> Option<u64> {
+        self.source.sample_count()
+    }
+}
+
+impl<S> Drop for Tracked<S> {
+    fn drop(&mut self) {
+        self.finished.store(true, Ordering::Relaxed);
+    }
+}
+
+/// A sound playing on an entity
+pub struct SoundHandle {
+    pub id: SoundInstanceId,
+    pub instance: SoundInstance,
+    /// Can be used to wait until the sound has finished
+    pub sound: Sound,
+}
+
 /// TODO: hook this into the Attenuation inside ambient_audio
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, DerefMut, Deref, From, Into)]
 pub struct AttenuationEditorVisual(Attenuation);
@@ -69,13 +190,99 @@ fn get_audio_listener(world: &World) -> anyhow::Result<&Arc<Mutex<AudioListener>
     Ok(listener)
 }
 
-/// Makes a sound source emit from the entity
-pub fn play_sound_on_entity<S: 'static + Source>(world: &World, id: EntityId, source: S) -> anyhow::Result<Sound> {
+fn play_instance<S: 'static + Source>(world: &World, id: EntityId, source: S, instance: &SoundInstance) -> anyhow::Result<Sound> {
     let hrtf_lib = world.resource(hrtf_lib());
     let mixer = world.resource(audio_mixer());
     let emitter = world.get_ref(id, audio_emitter()).context("No audio emitter on entity")?;
 
     let listener = get_audio_listener(world)?;
 
+    let source = Tracked { source: source.controlled(instance.params.clone()), finished: instance.finished.clone() };
     Ok(mixer.play(source.spatial(hrtf_lib, listener.clone(), emitter.clone())))
 }
+
+fn add_instance(world: &mut World, id: EntityId, instance: SoundInstance) -> SoundInstanceId {
+    let sound_id = SoundInstanceId::next();
+    if let Ok(sounds) = world.get_mut(id, playing_sounds()) {
+        sounds.insert(sound_id, instance);
+    } else {
+        world.add_component(id, playing_sounds(), HashMap::from([(sound_id, instance)])).ok();
+    }
+    sound_id
+}
+
+/// Makes a sound source emit from the entity.
+/// The sound can be controlled with the returned handle, or by its id in the [playing_sounds] of the entity.
+pub fn play_sound_on_entity<S: 'static + Source>(world: &mut World, id: EntityId, source: S) -> anyhow::Result<SoundHandle> {
+    play_labeled_sound_on_entity(world, id, source, None)
+}
+
+pub(crate) fn play_labeled_sound_on_entity<S: 'static + Source>(
+    world: &mut World,
+    id: EntityId,
+    source: S,
+    label: Option<String>,
+) -> anyhow::Result<SoundHandle> {
+    let instance = SoundInstance { label, ..Default::default() };
+    let sound = play_instance(world, id, source, &instance)?;
+    Ok(SoundHandle { id: add_instance(world, id, instance.clone()), instance, sound })
+}
+
+/// Loads the sound graph or Ogg Vorbis file at `url` and its audio files, and then emits it from the entity.
+///
+/// The sound can be controlled by the returned id right away, and is marked as finished if it fails to load.
+pub fn play_url_on_entity(world: &mut World, id: EntityId, url: AbsAssetUrl, seed: AudioSeed) -> anyhow::Result<SoundInstanceId> {
+    anyhow::ensure!(world.has_component(id, audio_emitter()), "No audio emitter on entity");
+    let instance = SoundInstance::default();
+    let sound_id = add_instance(world, id, instance.clone());
+
+    let assets = world.resource(asset_cache()).clone();
+    let async_run = world.resource(async_run()).clone();
+    world.resource(runtime()).spawn(async move {
+        let graph = match load_graph(&assets, url.clone()).await {
+            Ok(graph) => graph,
+            Err(err) => {
+                tracing::warn!("Failed to load sound {url}: {err:?}");
+                instance.finished.store(true, Ordering::Relaxed);
+                return;
+            }
+        };
+        async_run.run(move |world| {
+            let assets = world.resource(asset_cache()).clone();
+            let result = match graph.try_build(&assets, seed) {
+                Ok(Some(source)) => play_instance(world, id, source, &instance).map(|_| ()),
+                Ok(None) => Err(anyhow::anyhow!("The sound was unloaded before it could play")),
+                Err(err) => Err(err.into()),
+            };
+            if let Err(err) = result {
+                tracing::warn!("Failed to play sound {url}: {err:?}");
+                instance.finished.store(true, Ordering::Relaxed);
+            }
+        });
+    });
+    Ok(sound_id)
+}
+
+/// Loads the graph at `url`, or a graph playing the file if it is Ogg Vorbis, and the audio files it plays
+async fn load_graph(assets: &AssetCache, url: AbsAssetUrl) -> anyhow::Result<AudioNode> {
+    let graph = if url.extension_is("ogg") {
+        AudioNode::Vorbis { url: url.to_string() }
+    } else {
+        (*JsonFromUrl::<AudioNode>::new(url, true).get(assets).await?).clone()
+    };
+    graph.validate()?;
+
+    let mut nodes = vec![&graph];
+    while let Some(node) = nodes.pop() {
+        if let AudioNode::Vorbis { url } = node {
+            VorbisFromUrl { url: AbsAssetUrl::parse(url)? }.get(assets).await?;
+        }
+        nodes.extend(node.inputs());
+    }
+    Ok(graph)
+}
+
+/// Finds a sound playing on any entity by its id
+pub fn get_sound_instance(world: &World, sound_id: SoundInstanceId) -> Option<SoundInstance> {
+    query(playing_sounds()).iter(world, None).find_map(|(_, sounds)| sounds.get(&sound_id).cloned())
+}
@@ -1,11 +1,13 @@
-use std::{io::Cursor, sync::Arc};
+use std::{io::Cursor, sync::Arc, time::Duration};
 
-use ambient_audio::{hrtf::HrtfLib, AudioMixer};
-use ambient_core::transform::local_to_world;
-use ambient_ecs::{query, SystemGroup, World};
-use glam::{vec4, Mat4};
+use ambient_audio::{hrtf::HrtfLib, AudioListener, AudioMixer};
+use ambient_core::{camera::get_active_camera, main_scene, player::local_user_id, transform::local_to_world};
+use ambient_ecs::{query, FnSystem, SystemGroup, World};
+use glam::{vec4, Mat4, Vec3};
+use itertools::Itertools;
+use parking_lot::Mutex;
 
-use crate::{audio_emitter, audio_listener, audio_mixer, hrtf_lib};
+use crate::{audio_emitter, audio_listener, audio_mixer, hrtf_lib, playing_sounds};
 
 /// Initializes the HRTF sphere and adds the appropriate resources
 ///
@@ -42,10 +44,152 @@ pub fn spatial_audio_systems() -> SystemGroup {
                     listener.transform = Y_UP_LHS * ltw;
                 }
             }),
+            query(playing_sounds()).to_system(|q, world, qs, _| {
+                let finished = q.iter(world, qs).filter(|(_, sounds)| sounds.values().any(|sound| sound.is_finished())).map(|(id, _)| id);
+                for id in finished.collect_vec() {
+                    if let Ok(sounds) = world.get_mut(id, playing_sounds()) {
+                        sounds.retain(|_, sound| !sound.is_finished());
+                    }
+                }
+            }),
+            // The mixer keeps playing the sounds of despawned emitters from where they were last
+            query(playing_sounds()).despawned().to_system(|q, world, qs, _| {
+                for (_, sounds) in q.iter(world, qs) {
+                    for sound in sounds.values() {
+                        sound.stop(Duration::ZERO);
+                    }
+                }
+            }),
         ],
     )
 }
 
 pub fn client_systems() -> SystemGroup {
-    SystemGroup::new("Spatial audio", vec![Box::new(spatial_audio_systems())])
+    SystemGroup::new(
+        "Spatial audio",
+        vec![
+            // Sounds are heard from the local player's active camera, unless a listener was placed elsewhere
+            Box::new(FnSystem::new(|world, _| {
+                if query(audio_listener()).iter(world, None).next().is_some() {
+                    return;
+                }
+                let Some(camera) = get_active_camera(world, main_scene(), world.resource_opt(local_user_id())) else { return };
+                let listener = AudioListener::new(Mat4::IDENTITY, Vec3::X * 0.3);
+                world.add_component(camera, audio_listener(), Arc::new(Mutex::new(listener))).ok();
+            })),
+            Box::new(spatial_audio_systems()),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use ambient_audio::{AudioEmitter, BufferedSource, Frame, SineWave, Source};
+    use ambient_core::camera::active_camera;
+    use ambient_ecs::{Entity, EntityId, FrameEvent, System};
+
+    use super::*;
+    use crate::{get_sound_instance, play_labeled_sound_on_entity, play_sound_on_entity, stop_local_sound, StopLocalSound};
+
+    fn world() -> World {
+        ambient_core::init_all_components();
+        crate::init_components();
+        let mut world = World::new("audio");
+        setup_audio(&mut world, AudioMixer::new(44100)).unwrap();
+        let listener = AudioListener::new(Mat4::IDENTITY, Vec3::X * 0.3);
+        Entity::new().with(audio_listener(), Arc::new(Mutex::new(listener))).spawn(&mut world);
+        world
+    }
+
+    fn spawn_emitter(world: &mut World) -> EntityId {
+        let emitter = AudioEmitter { pos: Vec3::Y * 2., ..Default::default() };
+        Entity::new().with(audio_emitter(), Arc::new(Mutex::new(emitter))).spawn(world)
+    }
+
+    /// Mixes a second of audio, in which the mixer drops the sounds that end
+    fn mix(world: &World) {
+        let mut mixer = world.resource(audio_mixer()).clone();
+        mixer.sample_buffered(&mut vec![Frame::ZERO; 44100]);
+    }
+
+    #[test]
+    fn finished_sounds_are_removed() {
+        let mut world = world();
+        let mut systems = spatial_audio_systems();
+        let id = spawn_emitter(&mut world);
+        let short = play_sound_on_entity(&mut world, id, BufferedSource::new([0.5f32; 441], 1, 44100)).unwrap();
+        let long = play_sound_on_entity(&mut world, id, SineWave::new(440.)).unwrap();
+        assert_ne!(short.id, long.id);
+        assert!(get_sound_instance(&world, short.id).is_some());
+
+        systems.run(&mut world, &FrameEvent);
+        assert_eq!(world.get_ref(id, playing_sounds()).unwrap().len(), 2);
+
+        mix(&world);
+        assert!(short.instance.is_finished());
+        assert!(!long.instance.is_finished());
+        systems.run(&mut world, &FrameEvent);
+        assert_eq!(world.get_ref(id, playing_sounds()).unwrap().keys().collect_vec(), vec![&long.id]);
+        assert!(get_sound_instance(&world, short.id).is_none());
+        assert!(get_sound_instance(&world, long.id).is_some());
+    }
+
+    #[test]
+    fn despawned_emitters_stop_their_sounds() {
+        let mut world = world();
+        let mut systems = spatial_audio_systems();
+        let id = spawn_emitter(&mut world);
+        let sound = play_sound_on_entity(&mut world, id, SineWave::new(440.)).unwrap();
+        systems.run(&mut world, &FrameEvent);
+
+        world.despawn(id);
+        systems.run(&mut world, &FrameEvent);
+        mix(&world);
+        assert!(sound.instance.is_finished());
+    }
+
+    #[test]
+    fn stop_local_sound_stops_the_sounds_with_its_label() {
+        let mut world = world();
+        let id = spawn_emitter(&mut world);
+        let play = |world: &mut World, label: &str| {
+            play_labeled_sound_on_entity(world, id, SineWave::new(440.), Some(label.to_string())).unwrap().instance
+        };
+        let (music, steps) = (play(&mut world, "music"), play(&mut world, "steps"));
+
+        stop_local_sound(&mut world, StopLocalSound { id, name: "music".to_string(), fade_out: 0.5 }).unwrap();
+        mix(&world);
+        assert!(music.is_finished());
+        assert!(!steps.is_finished());
+
+        assert!(stop_local_sound(&mut world, StopLocalSound { id, name: "steps".to_string(), fade_out: -1. }).is_err());
+        let unknown = spawn_emitter(&mut world);
+        assert!(stop_local_sound(&mut world, StopLocalSound { id: unknown, name: "steps".to_string(), fade_out: 0. }).is_err());
+    }
+
+    fn listeners(world: &World) -> Vec<EntityId> {
+        query(audio_listener()).iter(world, None).map(|(id, _)| id).collect_vec()
+    }
+
+    #[test]
+    fn the_active_camera_listens_when_there_is_no_listener() {
+        let mut world = world();
+        for id in listeners(&world) {
+            world.despawn(id);
+        }
+        let camera = Entity::new().with(main_scene(), ()).with(active_camera(), 0.).spawn(&mut world);
+
+        client_systems().run(&mut world, &FrameEvent);
+        assert_eq!(listeners(&world), vec![camera]);
+    }
+
+    #[test]
+    fn placed_listeners_are_left_in_place() {
+        let mut world = world();
+        let placed = listeners(&world);
+        Entity::new().with(main_scene(), ()).with(active_camera(), 0.).spawn(&mut world);
+
+        client_systems().run(&mut world, &FrameEvent);
+        assert_eq!(listeners(&world), placed);
+    }
 }
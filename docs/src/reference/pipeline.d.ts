@@ -41,6 +41,24 @@ export type Pipeline = {
       flip_normals?: boolean,
       /// Whether or not the indices should be reversed for each triangle. On by default.
       reverse_indices?: boolean,
+    } | {
+      /// Split the model into convex hulls that approximate its shape, so that it can be used by dynamic objects.
+      /// Static objects still use the model's triangles.
+      type: "ConvexDecomposition",
+      /// Whether or not the normals should be flipped.
+      flip_normals?: boolean,
+      /// Whether or not the indices should be reversed for each triangle. On by default.
+      reverse_indices?: boolean,
+      /// The maximum number of convex hulls to split each mesh into. Defaults to 16.
+      max_hulls?: u32,
+      /// The maximum number of vertices in each convex hull, from 4 to 255. Defaults to 64.
+      max_vertices_per_hull?: u32,
+    } | {
+      /// Fit a box around each mesh of the model.
+      type: "SimplifiedBoxes",
+    } | {
+      /// Fit a single capsule around the whole model, lying along the longest side of its bounding box.
+      type: "Capsule",
     } | {
       /// Use a spherical character collider.
       type: "Character",
@@ -188,6 +206,49 @@ export type Pipeline = {
       /// Re-center this mesh such that the root is located at the origin.
       type: "Center",
     })[],
+    /// If specified, simplified versions of the meshes will be generated and rendered instead of the originals
+    /// as the model gets smaller on screen.
+    generate_lods?: {
+      /// The levels of detail to generate, from the most to the least detailed. The original mesh is always used as the
+      /// first level, before these. Defaults to half, a quarter and an eighth of the original triangles.
+      levels?: {
+        /// The fraction of the original mesh's triangles to keep, i.e. `0.5` for half of them.
+        ratio: f32,
+        /// The largest deviation from the original surface allowed, relative to the size of the mesh.
+        /// Simplification stops short of `ratio` rather than exceed this. Defaults to `0.01`.
+        max_error?: f32,
+      }[],
+      /// The size on screen, as a fraction of the screen, below which each level stops being used, starting with the
+      /// original mesh, so there must be one more cutoff than there are `levels`. The model is not rendered below the last
+      /// cutoff. Defaults to cutoffs spaced evenly down to 4% of the screen.
+      lod_cutoffs?: f32[],
+    },
+    /// Reorder the triangles of the meshes so that they render faster, by making better use of the GPU's vertex cache
+    /// and reducing overdraw. The meshes look exactly the same.
+    optimize_meshes?: boolean,
+    /// If specified, the textures of this model will be compressed, and have their mipmaps generated, at build time.
+    compress_textures?: {
+      /// The format to store the textures in. Defaults to `Bc`.
+      /// - `Bc`: block compression; BC7 for color textures, and BC5 for normal maps and metallic-roughness maps.
+      ///   Supported by almost all desktop GPUs.
+      /// - `Uncompressed`: uncompressed RGBA8, for GPUs without block compression. Mipmaps are still generated.
+      format?: "Bc" | "Uncompressed",
+      /// How long to spend searching for the best encoding of each block. Defaults to `Normal`.
+      quality?: "Fast" | "Normal" | "High",
+    },
+    /// If specified, a ragdoll with a capsule per bone will be generated from the skeleton of this model, so that its
+    /// skeleton can be driven by physics by setting `ragdoll_active`.
+    generate_ragdoll?: {
+      /// The radius of the capsule of each bone, as a fraction of the length of the bone. Defaults to 0.25.
+      radius_scale?: f32,
+      /// Bones shorter than this, in meters, are left out of the ragdoll together with their children, and keep following
+      /// the animation. Defaults to 0.05.
+      min_bone_length?: f32,
+      /// How far each bone can swing away from its parent, in degrees. Defaults to 45.
+      swing_limit?: f32,
+      /// How far each bone can twist around its own axis, in degrees. Defaults to 30.
+      twist_limit?: f32,
+    },
   } | {
     /// The materials asset pipeline.
     /// Will import specific materials without needing to be part of a model.
@@ -235,10 +296,44 @@ export type Pipeline = {
     },
     /// Whether or not decal prefabs should be created for each of these materials.
     output_decals?: boolean,
+    /// If specified, the textures of these materials will be compressed, and have their mipmaps generated, at build time.
+    compress_textures?: {
+      /// The format to store the textures in. Defaults to `Bc`.
+      /// - `Bc`: block compression; BC7 for color textures, and BC5 for normal maps and metallic-roughness maps.
+      ///   Supported by almost all desktop GPUs.
+      /// - `Uncompressed`: uncompressed RGBA8, for GPUs without block compression. Mipmaps are still generated.
+      format?: "Bc" | "Uncompressed",
+      /// How long to spend searching for the best encoding of each block. Defaults to `Normal`.
+      quality?: "Fast" | "Normal" | "High",
+    },
   } | {
     /// The audio asset pipeline.
     /// Will import supported audio file formats and produce Ogg Vorbis files to be used by the runtime.
+    /// Sound graphs authored as `.sgr` JSON files are validated, and their relative urls are pointed at the imported files.
     type: "Audio",
+    /// Resample the audio to this sample rate, in Hz. The source's sample rate is kept if not specified.
+    sample_rate?: u32,
+    /// Mix all channels down to a single channel.
+    downmix_to_mono?: boolean,
+    /// Scale the audio so that its average (RMS) level is this many decibels relative to full scale, i.e. `-20`.
+    /// The audio is never amplified to the point of clipping.
+    normalize_loudness?: f32,
+    /// Remove the silence at the start and end of the audio, treating anything quieter than this many decibels
+    /// relative to full scale as silent, i.e. `-60`.
+    trim_silence?: f32,
+  } | {
+    /// The prefabs asset pipeline.
+    /// Will compose prefabs from `.prefab.toml` and `.prefab.json` files, which describe a hierarchy of entities and their components.
+    type: "Prefabs",
+  } | {
+    /// A custom asset pipeline.
+    /// Will build a processor from a Rust package in the project, and run it with the files of this pipeline.
+    type: "Custom",
+    /// The path of the Rust package of the processor, relative to this pipeline, i.e. `../processors/csv`.
+    /// It is built for WASM and must declare its processing function with `ambient_pipeline_api::processor!`.
+    processor: string,
+    /// Passed to the processor as a JSON string.
+    config?: any,
   },
   /// Filter the sources used to feed this pipeline.
   /// This is a list of glob patterns for accepted files.
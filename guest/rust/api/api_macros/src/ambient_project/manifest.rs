@@ -13,6 +13,8 @@ pub struct Manifest {
     pub components: BTreeMap<IdentifierPathBuf, NamespaceOr<Component>>,
     #[serde(default)]
     pub concepts: BTreeMap<IdentifierPathBuf, NamespaceOr<Concept>>,
+    #[serde(default)]
+    pub physics: Physics,
 }
 impl Manifest {
     pub fn project_path(&self) -> IdentifierPathBuf {
@@ -31,6 +33,12 @@ pub struct Project {
     pub organization: Option<Identifier>,
 }
 
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Physics {
+    pub collision_layers: Vec<Identifier>,
+}
+
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 pub struct Namespace {
     pub name: String,
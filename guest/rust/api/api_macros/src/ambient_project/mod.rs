@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::{collections::HashSet, path::PathBuf};
 
 use anyhow::Context;
 use quote::quote;
@@ -43,6 +43,7 @@ pub fn implementation(
     let components_tokens =
         component::tree_to_token_stream(&component_tree, &api_name, project_path.as_path())?;
     let concept_tokens = concept::tree_to_token_stream(&concept_tree, &component_tree, &api_name)?;
+    let collision_layer_tokens = collision_layers_to_token_stream(&manifest.physics)?;
 
     let manifest = file_path.map(
         |file_path| quote! { const _PROJECT_MANIFEST: &'static str = include_str!(#file_path); },
@@ -59,5 +60,49 @@ pub fn implementation(
         pub mod concepts {
             #concept_tokens
         }
+        #collision_layer_tokens
     ))
 }
+
+/// Generates a constant for the mask of each collision layer declared in `[physics]`. The `default` layer uses the
+/// first bit, so declared layers start from the second.
+fn collision_layers_to_token_stream(
+    physics: &manifest::Physics,
+) -> anyhow::Result<Option<proc_macro2::TokenStream>> {
+    if physics.collision_layers.is_empty() {
+        return Ok(None);
+    }
+    anyhow::ensure!(
+        physics.collision_layers.len() <= 31,
+        "at most 31 collision layers can be declared, but {} were",
+        physics.collision_layers.len()
+    );
+
+    let mut seen = HashSet::new();
+    let mut constants = Vec::new();
+    for (i, layer) in physics.collision_layers.iter().enumerate() {
+        anyhow::ensure!(
+            layer.as_ref() != "default",
+            "the `default` collision layer is always present and can't be declared"
+        );
+        anyhow::ensure!(
+            seen.insert(layer),
+            "collision layer `{layer}` is declared more than once"
+        );
+
+        let ident: syn::Path = syn::parse_str(&layer.as_ref().to_ascii_uppercase())?;
+        let mask = 1u32 << (i + 1);
+        let doc = format!("The mask of the `{layer}` collision layer.");
+        constants.push(quote! {
+            #[doc = #doc]
+            pub const #ident: u32 = #mask;
+        });
+    }
+
+    Ok(Some(quote! {
+        /// Auto-generated collision layer masks. These come from `[physics]` in `ambient.toml`, and are used with the `collision_layer` and `collides_with` components.
+        pub mod collision_layers {
+            #(#constants)*
+        }
+    }))
+}
@@ -264,6 +264,50 @@ fn can_generate_components_from_manifest_with_org() {
     assert_eq!(result.to_string(), expected_output.to_string());
 }
 
+#[test]
+fn can_generate_collision_layers_from_manifest() {
+    let manifest = indoc::indoc! {r#"
+        [project]
+        id = "my_project"
+        name = "My Project"
+
+        [physics]
+        collision_layers = ["player", "projectile"]
+        "#};
+
+    let expected_output = quote::quote! {
+        const _PROJECT_MANIFEST: &'static str = include_str!("ambient.toml");
+        #[doc = r" Auto-generated component definitions. These come from `ambient.toml` in the root of the project."]
+        pub mod components {
+            use ambient_api2::{once_cell::sync::Lazy, ecs::{Component, __internal_get_component}};
+        }
+        #[doc = r" Auto-generated concept definitions. Concepts are collections of components that describe some form of gameplay concept."]
+        #[doc = r""]
+        #[doc = r" They do not have any runtime representation outside of the components that compose them."]
+        pub mod concepts {
+            use super :: components ;
+            use ambient_api2::prelude::*;
+        }
+        #[doc = r" Auto-generated collision layer masks. These come from `[physics]` in `ambient.toml`, and are used with the `collision_layer` and `collides_with` components."]
+        pub mod collision_layers {
+            #[doc = "The mask of the `player` collision layer."]
+            pub const PLAYER: u32 = 2u32;
+            #[doc = "The mask of the `projectile` collision layer."]
+            pub const PROJECTILE: u32 = 4u32;
+        }
+    };
+
+    let result = implementation(
+        (Some("ambient.toml".to_string()), manifest.to_string()),
+        api_name(),
+        false,
+        true,
+    )
+    .unwrap();
+
+    assert_eq!(result.to_string(), expected_output.to_string());
+}
+
 #[test]
 fn can_generate_components_with_documented_namespace_from_manifest() {
     let manifest = indoc::indoc! {r#"
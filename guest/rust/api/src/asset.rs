@@ -1,8 +1,171 @@
 #[cfg(feature = "server")]
-use crate::internal::wit;
+use crate::internal::{
+    conversion::{FromBindgen, IntoBindgen},
+    wit,
+};
 
 /// Resolves a asset path for an Ambient asset in this project to an absolute URL.
 #[cfg(feature = "server")]
 pub fn url(path: impl AsRef<str>) -> Option<String> {
     wit::server_asset::url(path.as_ref())
 }
+
+/// The type of an asset in the [catalog].
+#[cfg(feature = "server")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AssetType {
+    /// A model crate, which bundles the models, prefabs, materials and animations imported from a file.
+    AssetCrate,
+    /// A prefab that can be spawned.
+    Prefab,
+    /// A bundle of scripts.
+    ScriptBundle,
+    /// A model.
+    Model,
+    /// An image.
+    Image,
+    /// An animation.
+    Animation,
+    /// A material.
+    Material,
+    /// A collider.
+    Collider,
+    /// A terrain material.
+    TerrainMaterial,
+    /// An atmosphere.
+    Atmosphere,
+    /// A set of biomes.
+    Biomes,
+    /// An Ogg Vorbis audio track.
+    VorbisTrack,
+    /// A sound graph.
+    SoundGraph,
+}
+#[cfg(feature = "server")]
+impl IntoBindgen for AssetType {
+    type Item = wit::server_asset::AssetType;
+    fn into_bindgen(self) -> Self::Item {
+        match self {
+            Self::AssetCrate => Self::Item::AssetCrate,
+            Self::Prefab => Self::Item::Prefab,
+            Self::ScriptBundle => Self::Item::ScriptBundle,
+            Self::Model => Self::Item::Model,
+            Self::Image => Self::Item::Image,
+            Self::Animation => Self::Item::Animation,
+            Self::Material => Self::Item::Material,
+            Self::Collider => Self::Item::Collider,
+            Self::TerrainMaterial => Self::Item::TerrainMaterial,
+            Self::Atmosphere => Self::Item::Atmosphere,
+            Self::Biomes => Self::Item::Biomes,
+            Self::VorbisTrack => Self::Item::VorbisTrack,
+            Self::SoundGraph => Self::Item::SoundGraph,
+        }
+    }
+}
+#[cfg(feature = "server")]
+impl FromBindgen for wit::server_asset::AssetType {
+    type Item = AssetType;
+    fn from_bindgen(self) -> Self::Item {
+        match self {
+            Self::AssetCrate => Self::Item::AssetCrate,
+            Self::Prefab => Self::Item::Prefab,
+            Self::ScriptBundle => Self::Item::ScriptBundle,
+            Self::Model => Self::Item::Model,
+            Self::Image => Self::Item::Image,
+            Self::Animation => Self::Item::Animation,
+            Self::Material => Self::Item::Material,
+            Self::Collider => Self::Item::Collider,
+            Self::TerrainMaterial => Self::Item::TerrainMaterial,
+            Self::Atmosphere => Self::Item::Atmosphere,
+            Self::Biomes => Self::Item::Biomes,
+            Self::VorbisTrack => Self::Item::VorbisTrack,
+            Self::SoundGraph => Self::Item::SoundGraph,
+        }
+    }
+}
+
+/// An asset built for this project, as listed in its asset catalog (`build/assets.json`).
+#[cfg(feature = "server")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CatalogAsset {
+    /// A unique ID identifying this asset.
+    pub id: String,
+    /// The type of the asset.
+    pub asset_type: AssetType,
+    /// A human-friendly name for this asset.
+    pub name: String,
+    /// The tags this asset was given in its pipeline.
+    pub tags: Vec<String>,
+    /// Every category level this asset is in, i.e. `["Vehicles", "Vehicles > Cars"]`.
+    pub categories: Vec<String>,
+    /// The URL of this asset's content. Collections have no content of their own.
+    pub url: Option<String>,
+    /// The IDs of the assets in this collection.
+    pub collection: Vec<String>,
+    /// The file this asset was built from.
+    pub source: Option<String>,
+    /// The URL of a preview image of this asset, if it has one.
+    pub preview_url: Option<String>,
+}
+
+/// Filters for [catalog]. Only assets that match every filter that is set are returned.
+#[cfg(feature = "server")]
+#[derive(Clone, Debug, Default)]
+pub struct CatalogQuery {
+    /// The type the asset must be.
+    pub asset_type: Option<AssetType>,
+    /// A tag the asset must have.
+    pub tag: Option<String>,
+    /// A category the asset must be in, i.e. `Vehicles > Cars`.
+    pub category: Option<String>,
+}
+#[cfg(feature = "server")]
+impl CatalogQuery {
+    /// Creates a query that matches every asset.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Only match assets of this type.
+    pub fn asset_type(mut self, asset_type: AssetType) -> Self {
+        self.asset_type = Some(asset_type);
+        self
+    }
+    /// Only match assets with this tag.
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tag = Some(tag.into());
+        self
+    }
+    /// Only match assets in this category.
+    pub fn category(mut self, category: impl Into<String>) -> Self {
+        self.category = Some(category.into());
+        self
+    }
+}
+
+/// Finds the assets built for this project that match `query`, so that their paths do not need to be hard-coded.
+///
+/// For example, all prefabs tagged `tree`:
+/// ```ignore
+/// let trees = asset::catalog(&CatalogQuery::new().asset_type(AssetType::Prefab).tag("tree"));
+/// ```
+#[cfg(feature = "server")]
+pub fn catalog(query: &CatalogQuery) -> Vec<CatalogAsset> {
+    wit::server_asset::catalog(
+        query.asset_type.into_bindgen(),
+        query.tag.as_deref(),
+        query.category.as_deref(),
+    )
+    .into_iter()
+    .map(|asset| CatalogAsset {
+        id: asset.id,
+        asset_type: asset.asset_type.from_bindgen(),
+        name: asset.name,
+        tags: asset.tags,
+        categories: asset.categories,
+        url: asset.url,
+        collection: asset.collection,
+        source: asset.source,
+        preview_url: asset.preview_url,
+    })
+    .collect()
+}
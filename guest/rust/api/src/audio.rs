@@ -0,0 +1,46 @@
+use crate::{
+    global::EntityId,
+    internal::{conversion::IntoBindgen, wit},
+};
+
+/// A sound playing on an entity, which can be stopped or changed while it plays.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Sound(u64);
+
+/// Plays the sound graph or Ogg Vorbis file at `url` from `entity`, once it has loaded.
+///
+/// The entity is given an audio emitter if it doesn't have one. The returned [Sound] can be
+/// controlled right away, even before the sound has loaded.
+pub fn play(entity: EntityId, url: impl AsRef<str>) -> anyhow::Result<Sound> {
+    wit::client_audio::play(entity.into_bindgen(), url.as_ref())
+        .map(Sound)
+        .map_err(anyhow::Error::msg)
+}
+
+impl Sound {
+    /// Fades the sound out over `fade_out` seconds, and stops it. Does nothing if the sound has finished.
+    pub fn stop(self, fade_out: f32) {
+        wit::client_audio::stop(self.0, fade_out)
+    }
+
+    /// Sets the volume multiplier of the sound. The default is `1.0`. The gain must be finite.
+    pub fn set_gain(self, gain: f32) {
+        wit::client_audio::set_gain(self.0, gain)
+    }
+
+    /// Sets the playback speed multiplier of the sound, which also raises or lowers its pitch. The default is `1.0`.
+    pub fn set_pitch(self, pitch: f32) {
+        wit::client_audio::set_pitch(self.0, pitch)
+    }
+
+    /// Filters out the frequencies of the sound above `cutoff` Hz, or removes the filter if `None`. The cutoff must be
+    /// positive, and is clamped to the range of human hearing.
+    pub fn set_cutoff(self, cutoff: Option<f32>) {
+        wit::client_audio::set_cutoff(self.0, cutoff)
+    }
+
+    /// Returns true until the sound has finished playing, been stopped, or failed to load.
+    pub fn is_playing(self) -> bool {
+        wit::client_audio::is_playing(self.0)
+    }
+}
@@ -7,6 +7,9 @@ pub use state::*;
 mod runtime;
 pub use runtime::*;
 
+mod profiling;
+pub use profiling::*;
+
 mod entity_id;
 pub use entity_id::*;
 
@@ -0,0 +1,31 @@
+use crate::internal::{executor::EXECUTOR, wit};
+
+/// A named scope that shows up in the host's profiler. The scope ends when this is dropped.
+///
+/// Scopes cannot span multiple event handler invocations, so a scope must not be held across an
+/// `.await`: the host ends every scope still open when the current handler returns, and dropping
+/// the scope in a later invocation does nothing.
+#[must_use = "the scope ends as soon as this is dropped"]
+pub struct ProfilingScope {
+    run: u64,
+}
+impl Drop for ProfilingScope {
+    fn drop(&mut self) {
+        // Ending the scope after the host has already done so would end a scope of the current run instead
+        if self.run == EXECUTOR.current_run() {
+            wit::profiling::end_scope();
+        }
+    }
+}
+
+/// Begins a profiling scope called `name`, which lasts until the returned [ProfilingScope] is dropped.
+///
+/// ```ignore
+/// let _scope = profiling_scope("update_enemies");
+/// ```
+pub fn profiling_scope(name: impl AsRef<str>) -> ProfilingScope {
+    wit::profiling::begin_scope(name.as_ref());
+    ProfilingScope {
+        run: EXECUTOR.current_run(),
+    }
+}
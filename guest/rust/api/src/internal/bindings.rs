@@ -6375,6 +6375,49 @@ pub mod component{
                                   }
                                   
                                   
+                                  #[allow(clippy::all)]
+                                  pub mod profiling{
+                                    #[allow(clippy::all)]
+                                    pub fn begin_scope(name: &str,){
+                                      
+                                      #[allow(unused_imports)]
+                                      use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                      unsafe {
+                                        let vec0 = name;
+                                        let ptr0 = vec0.as_ptr() as i32;
+                                        let len0 = vec0.len() as i32;
+                                        
+                                        #[link(wasm_import_module = "profiling")]
+                                        extern "C" {
+                                          #[cfg_attr(target_arch = "wasm32", link_name = "begin-scope")]
+                                          #[cfg_attr(not(target_arch = "wasm32"), link_name = "profiling_begin-scope")]
+                                          fn wit_import(
+                                          _: i32, _: i32, );
+                                        }
+                                        wit_import(ptr0, len0);
+                                      }
+                                    }
+                                    #[allow(clippy::all)]
+                                    pub fn end_scope(){
+                                      
+                                      #[allow(unused_imports)]
+                                      use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                      unsafe {
+                                        
+                                        #[link(wasm_import_module = "profiling")]
+                                        extern "C" {
+                                          #[cfg_attr(target_arch = "wasm32", link_name = "end-scope")]
+                                          #[cfg_attr(not(target_arch = "wasm32"), link_name = "profiling_end-scope")]
+                                          fn wit_import(
+                                          );
+                                        }
+                                        wit_import();
+                                      }
+                                    }
+                                    
+                                  }
+                                  
+                                  
                                   #[allow(clippy::all)]
                                   pub mod server_player{
                                     pub type Vec2 = super::types::Vec2;
@@ -7547,6 +7590,7 @@ pub mod component{
                                   #[allow(clippy::all)]
                                   pub mod server_physics{
                                     pub type EntityId = super::types::EntityId;
+                                    pub type Quat = super::types::Quat;
                                     pub type Vec3 = super::types::Vec3;
                                     #[repr(C)]
                                     #[derive(Copy, Clone)]
@@ -7560,6 +7604,117 @@ pub mod component{
                                         f.debug_struct("CharacterCollision").field("side", &self.side).field("up", &self.up).field("down", &self.down).finish()
                                       }
                                     }
+                                    #[repr(u8)]
+                                    #[derive(Clone, Copy, PartialEq, Eq)]
+                                    pub enum ColliderScene {
+                                      Physics,
+                                      TriggerArea,
+                                      Picking,
+                                    }
+                                    impl core::fmt::Debug for ColliderScene {
+                                      fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                                        match self {
+                                          ColliderScene::Physics => {
+                                            f.debug_tuple("ColliderScene::Physics").finish()
+                                          }
+                                          ColliderScene::TriggerArea => {
+                                            f.debug_tuple("ColliderScene::TriggerArea").finish()
+                                          }
+                                          ColliderScene::Picking => {
+                                            f.debug_tuple("ColliderScene::Picking").finish()
+                                          }
+                                        }
+                                      }
+                                    }
+                                    #[repr(C)]
+                                    #[derive(Copy, Clone)]
+                                    pub struct CapsuleShape {
+                                      pub radius: f32,
+                                      pub half_height: f32,
+                                    }
+                                    impl core::fmt::Debug for CapsuleShape {
+                                      fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                                        f.debug_struct("CapsuleShape").field("radius", &self.radius).field("half-height", &self.half_height).finish()
+                                      }
+                                    }
+                                    #[derive(Clone, Copy)]
+                                    pub enum QueryShape{
+                                      Sphere(f32),
+                                      Cuboid(Vec3),
+                                      Capsule(CapsuleShape),
+                                    }
+                                    impl core::fmt::Debug for QueryShape {
+                                      fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                                        match self {
+                                          QueryShape::Sphere(e) => {
+                                            f.debug_tuple("QueryShape::Sphere").field(e).finish()
+                                          }
+                                          QueryShape::Cuboid(e) => {
+                                            f.debug_tuple("QueryShape::Cuboid").field(e).finish()
+                                          }
+                                          QueryShape::Capsule(e) => {
+                                            f.debug_tuple("QueryShape::Capsule").field(e).finish()
+                                          }
+                                        }
+                                      }
+                                    }
+                                    #[repr(C)]
+                                    #[derive(Copy, Clone)]
+                                    pub struct SweepHit {
+                                      pub entity: EntityId,
+                                      pub position: Vec3,
+                                      pub normal: Vec3,
+                                      pub distance: f32,
+                                    }
+                                    impl core::fmt::Debug for SweepHit {
+                                      fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                                        f.debug_struct("SweepHit").field("entity", &self.entity).field("position", &self.position).field("normal", &self.normal).field("distance", &self.distance).finish()
+                                      }
+                                    }
+                                    #[repr(u8)]
+                                    #[derive(Clone, Copy, PartialEq, Eq)]
+                                    pub enum JointType {
+                                      Fixed,
+                                      Revolute,
+                                      Prismatic,
+                                      Spherical,
+                                      Distance,
+                                    }
+                                    impl core::fmt::Debug for JointType {
+                                      fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                                        match self {
+                                          JointType::Fixed => {
+                                            f.debug_tuple("JointType::Fixed").finish()
+                                          }
+                                          JointType::Revolute => {
+                                            f.debug_tuple("JointType::Revolute").finish()
+                                          }
+                                          JointType::Prismatic => {
+                                            f.debug_tuple("JointType::Prismatic").finish()
+                                          }
+                                          JointType::Spherical => {
+                                            f.debug_tuple("JointType::Spherical").finish()
+                                          }
+                                          JointType::Distance => {
+                                            f.debug_tuple("JointType::Distance").finish()
+                                          }
+                                        }
+                                      }
+                                    }
+                                    #[repr(C)]
+                                    #[derive(Copy, Clone)]
+                                    pub struct Contact {
+                                      pub other: EntityId,
+                                      pub position: Vec3,
+                                      pub normal: Vec3,
+                                      pub separation: f32,
+                                      pub impulse: Vec3,
+                                    }
+                                    impl core::fmt::Debug for Contact {
+                                      fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                                        f.debug_struct("Contact").field("other", &self.other).field("position", &self.position).field("normal", &self.normal).field("separation", &self.separation).field("impulse", &self.impulse).finish()
+                                      }
+                                    }
                                     #[allow(clippy::all)]
                                     pub fn apply_force(entities: &[EntityId],force: Vec3,){
                                       
@@ -7695,7 +7850,7 @@ pub mod component{
                                       }
                                     }
                                     #[allow(clippy::all)]
-                                    pub fn raycast_first(origin: Vec3,direction: Vec3,) -> Option<(EntityId,f32,)>{
+                                    pub fn raycast_first(origin: Vec3,direction: Vec3,layers: Option<u32>,) -> Option<(EntityId,f32,)>{
                                       
                                       #[allow(unused_imports)]
                                       use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
@@ -7706,18 +7861,23 @@ pub mod component{
                                         let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
                                         let super::types::Vec3{ x:x0, y:y0, z:z0, } = origin;
                                         let super::types::Vec3{ x:x1, y:y1, z:z1, } = direction;
-                                        let ptr2 = ret_area.as_mut_ptr() as i32;
+                                        let (result2_0,result2_1,) = match layers {
+                                          Some(e) => (1i32, wit_bindgen::rt::as_i32(e)),
+                                          None => {
+                                            (0i32, 0i32)
+                                          },
+                                        };let ptr3 = ret_area.as_mut_ptr() as i32;
                                         #[link(wasm_import_module = "server-physics")]
                                         extern "C" {
                                           #[cfg_attr(target_arch = "wasm32", link_name = "raycast-first")]
                                           #[cfg_attr(not(target_arch = "wasm32"), link_name = "server-physics_raycast-first")]
                                           fn wit_import(
-                                          _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, );
+                                          _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, _: i32, _: i32, );
                                         }
-                                        wit_import(wit_bindgen::rt::as_f32(x0), wit_bindgen::rt::as_f32(y0), wit_bindgen::rt::as_f32(z0), wit_bindgen::rt::as_f32(x1), wit_bindgen::rt::as_f32(y1), wit_bindgen::rt::as_f32(z1), ptr2);
-                                        match i32::from(*((ptr2 + 0) as *const u8)) {
+                                        wit_import(wit_bindgen::rt::as_f32(x0), wit_bindgen::rt::as_f32(y0), wit_bindgen::rt::as_f32(z0), wit_bindgen::rt::as_f32(x1), wit_bindgen::rt::as_f32(y1), wit_bindgen::rt::as_f32(z1), result2_0, result2_1, ptr3);
+                                        match i32::from(*((ptr3 + 0) as *const u8)) {
                                           0 => None,
-                                          1 => Some((super::types::EntityId{id0:*((ptr2 + 8) as *const i64) as u64, id1:*((ptr2 + 16) as *const i64) as u64, }, *((ptr2 + 24) as *const f32))),
+                                          1 => Some((super::types::EntityId{id0:*((ptr3 + 8) as *const i64) as u64, id1:*((ptr3 + 16) as *const i64) as u64, }, *((ptr3 + 24) as *const f32))),
                                           #[cfg(not(debug_assertions))]
                                           _ => core::hint::unreachable_unchecked(),
                                           #[cfg(debug_assertions)]
@@ -7726,7 +7886,7 @@ pub mod component{
                                       }
                                     }
                                     #[allow(clippy::all)]
-                                    pub fn raycast(origin: Vec3,direction: Vec3,) -> wit_bindgen::rt::vec::Vec::<(EntityId,f32,)>{
+                                    pub fn raycast(origin: Vec3,direction: Vec3,layers: Option<u32>,) -> wit_bindgen::rt::vec::Vec::<(EntityId,f32,)>{
                                       
                                       #[allow(unused_imports)]
                                       use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
@@ -7737,21 +7897,26 @@ pub mod component{
                                         let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
                                         let super::types::Vec3{ x:x0, y:y0, z:z0, } = origin;
                                         let super::types::Vec3{ x:x1, y:y1, z:z1, } = direction;
-                                        let ptr2 = ret_area.as_mut_ptr() as i32;
+                                        let (result2_0,result2_1,) = match layers {
+                                          Some(e) => (1i32, wit_bindgen::rt::as_i32(e)),
+                                          None => {
+                                            (0i32, 0i32)
+                                          },
+                                        };let ptr3 = ret_area.as_mut_ptr() as i32;
                                         #[link(wasm_import_module = "server-physics")]
                                         extern "C" {
                                           #[cfg_attr(target_arch = "wasm32", link_name = "raycast")]
                                           #[cfg_attr(not(target_arch = "wasm32"), link_name = "server-physics_raycast")]
                                           fn wit_import(
-                                          _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, );
+                                          _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, _: i32, _: i32, );
                                         }
-                                        wit_import(wit_bindgen::rt::as_f32(x0), wit_bindgen::rt::as_f32(y0), wit_bindgen::rt::as_f32(z0), wit_bindgen::rt::as_f32(x1), wit_bindgen::rt::as_f32(y1), wit_bindgen::rt::as_f32(z1), ptr2);
-                                        let len3 = *((ptr2 + 4) as *const i32) as usize;
-                                        Vec::from_raw_parts(*((ptr2 + 0) as *const i32) as *mut _, len3, len3)
+                                        wit_import(wit_bindgen::rt::as_f32(x0), wit_bindgen::rt::as_f32(y0), wit_bindgen::rt::as_f32(z0), wit_bindgen::rt::as_f32(x1), wit_bindgen::rt::as_f32(y1), wit_bindgen::rt::as_f32(z1), result2_0, result2_1, ptr3);
+                                        let len4 = *((ptr3 + 4) as *const i32) as usize;
+                                        Vec::from_raw_parts(*((ptr3 + 0) as *const i32) as *mut _, len4, len4)
                                       }
                                     }
                                     #[allow(clippy::all)]
-                                    pub fn move_character(entity: EntityId,displacement: Vec3,min_dist: f32,elapsed_time: f32,) -> CharacterCollision{
+                                    pub fn move_character(entity: EntityId,displacement: Vec3,min_dist: f32,elapsed_time: f32,layers: Option<u32>,) -> CharacterCollision{
                                       
                                       #[allow(unused_imports)]
                                       use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
@@ -7762,21 +7927,26 @@ pub mod component{
                                         let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
                                         let super::types::EntityId{ id0:id00, id1:id10, } = entity;
                                         let super::types::Vec3{ x:x1, y:y1, z:z1, } = displacement;
-                                        let ptr2 = ret_area.as_mut_ptr() as i32;
+                                        let (result2_0,result2_1,) = match layers {
+                                          Some(e) => (1i32, wit_bindgen::rt::as_i32(e)),
+                                          None => {
+                                            (0i32, 0i32)
+                                          },
+                                        };let ptr3 = ret_area.as_mut_ptr() as i32;
                                         #[link(wasm_import_module = "server-physics")]
                                         extern "C" {
                                           #[cfg_attr(target_arch = "wasm32", link_name = "move-character")]
                                           #[cfg_attr(not(target_arch = "wasm32"), link_name = "server-physics_move-character")]
                                           fn wit_import(
-                                          _: i64, _: i64, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, );
+                                          _: i64, _: i64, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, _: i32, _: i32, );
                                         }
-                                        wit_import(wit_bindgen::rt::as_i64(id00), wit_bindgen::rt::as_i64(id10), wit_bindgen::rt::as_f32(x1), wit_bindgen::rt::as_f32(y1), wit_bindgen::rt::as_f32(z1), wit_bindgen::rt::as_f32(min_dist), wit_bindgen::rt::as_f32(elapsed_time), ptr2);
+                                        wit_import(wit_bindgen::rt::as_i64(id00), wit_bindgen::rt::as_i64(id10), wit_bindgen::rt::as_f32(x1), wit_bindgen::rt::as_f32(y1), wit_bindgen::rt::as_f32(z1), wit_bindgen::rt::as_f32(min_dist), wit_bindgen::rt::as_f32(elapsed_time), result2_0, result2_1, ptr3);
                                         CharacterCollision{side:{
                                           #[cfg(not(debug_assertions))]
-                                          { core::mem::transmute::<u8, bool>(i32::from(*((ptr2 + 0) as *const u8)) as u8) }
+                                          { core::mem::transmute::<u8, bool>(i32::from(*((ptr3 + 0) as *const u8)) as u8) }
                                           #[cfg(debug_assertions)]
                                           {
-                                            match i32::from(*((ptr2 + 0) as *const u8)) {
+                                            match i32::from(*((ptr3 + 0) as *const u8)) {
                                               0 => false,
                                               1 => true,
                                               _ => panic!("invalid bool discriminant"),
@@ -7784,10 +7954,10 @@ pub mod component{
                                           }
                                         }, up:{
                                           #[cfg(not(debug_assertions))]
-                                          { core::mem::transmute::<u8, bool>(i32::from(*((ptr2 + 1) as *const u8)) as u8) }
+                                          { core::mem::transmute::<u8, bool>(i32::from(*((ptr3 + 1) as *const u8)) as u8) }
                                           #[cfg(debug_assertions)]
                                           {
-                                            match i32::from(*((ptr2 + 1) as *const u8)) {
+                                            match i32::from(*((ptr3 + 1) as *const u8)) {
                                               0 => false,
                                               1 => true,
                                               _ => panic!("invalid bool discriminant"),
@@ -7795,10 +7965,10 @@ pub mod component{
                                           }
                                         }, down:{
                                           #[cfg(not(debug_assertions))]
-                                          { core::mem::transmute::<u8, bool>(i32::from(*((ptr2 + 2) as *const u8)) as u8) }
+                                          { core::mem::transmute::<u8, bool>(i32::from(*((ptr3 + 2) as *const u8)) as u8) }
                                           #[cfg(debug_assertions)]
                                           {
-                                            match i32::from(*((ptr2 + 2) as *const u8)) {
+                                            match i32::from(*((ptr3 + 2) as *const u8)) {
                                               0 => false,
                                               1 => true,
                                               _ => panic!("invalid bool discriminant"),
@@ -7807,12 +7977,350 @@ pub mod component{
                                         }, }
                                       }
                                     }
+                                    #[allow(clippy::all)]
+                                    pub fn sweep(shape: QueryShape,position: Vec3,rotation: Quat,direction: Vec3,max_distance: f32,scene: Option<ColliderScene>,layers: Option<u32>,) -> wit_bindgen::rt::vec::Vec::<SweepHit>{
+                                      
+                                      #[allow(unused_imports)]
+                                      use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                      unsafe {
+                                        
+                                        #[repr(align(4))]
+                                        struct RetArea([u8; 72]);
+                                        let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
+                                        let ptr0 = ret_area.as_mut_ptr() as i32;match shape {
+                                          QueryShape::Sphere(e) => {
+                                            *((ptr0 + 0) as *mut u8) = (0i32) as u8;
+                                            *((ptr0 + 4) as *mut f32) = wit_bindgen::rt::as_f32(e);
+                                            
+                                          },
+                                          QueryShape::Cuboid(e) => {
+                                            *((ptr0 + 0) as *mut u8) = (1i32) as u8;
+                                            let super::types::Vec3{ x:x1, y:y1, z:z1, } = e;
+                                            *((ptr0 + 4) as *mut f32) = wit_bindgen::rt::as_f32(x1);
+                                            *((ptr0 + 8) as *mut f32) = wit_bindgen::rt::as_f32(y1);
+                                            *((ptr0 + 12) as *mut f32) = wit_bindgen::rt::as_f32(z1);
+                                            
+                                          },
+                                          QueryShape::Capsule(e) => {
+                                            *((ptr0 + 0) as *mut u8) = (2i32) as u8;
+                                            let CapsuleShape{ radius:radius2, half_height:half_height2, } = e;
+                                            *((ptr0 + 4) as *mut f32) = wit_bindgen::rt::as_f32(radius2);
+                                            *((ptr0 + 8) as *mut f32) = wit_bindgen::rt::as_f32(half_height2);
+                                            
+                                          },
+                                        };
+                                        let super::types::Vec3{ x:x3, y:y3, z:z3, } = position;
+                                        *((ptr0 + 16) as *mut f32) = wit_bindgen::rt::as_f32(x3);
+                                        *((ptr0 + 20) as *mut f32) = wit_bindgen::rt::as_f32(y3);
+                                        *((ptr0 + 24) as *mut f32) = wit_bindgen::rt::as_f32(z3);
+                                        let super::types::Quat{ x:x4, y:y4, z:z4, w:w4, } = rotation;
+                                        *((ptr0 + 28) as *mut f32) = wit_bindgen::rt::as_f32(x4);
+                                        *((ptr0 + 32) as *mut f32) = wit_bindgen::rt::as_f32(y4);
+                                        *((ptr0 + 36) as *mut f32) = wit_bindgen::rt::as_f32(z4);
+                                        *((ptr0 + 40) as *mut f32) = wit_bindgen::rt::as_f32(w4);
+                                        let super::types::Vec3{ x:x5, y:y5, z:z5, } = direction;
+                                        *((ptr0 + 44) as *mut f32) = wit_bindgen::rt::as_f32(x5);
+                                        *((ptr0 + 48) as *mut f32) = wit_bindgen::rt::as_f32(y5);
+                                        *((ptr0 + 52) as *mut f32) = wit_bindgen::rt::as_f32(z5);
+                                        *((ptr0 + 56) as *mut f32) = wit_bindgen::rt::as_f32(max_distance);
+                                        match scene {
+                                          Some(e) => {
+                                            *((ptr0 + 60) as *mut u8) = (1i32) as u8;
+                                            *((ptr0 + 61) as *mut u8) = (match e {
+                                              ColliderScene::Physics => 0,
+                                              ColliderScene::TriggerArea => 1,
+                                              ColliderScene::Picking => 2,
+                                            }) as u8;
+                                            
+                                          },
+                                          None => {
+                                            {
+                                              *((ptr0 + 60) as *mut u8) = (0i32) as u8;
+                                              
+                                            }
+                                          },
+                                        };match layers {
+                                          Some(e) => {
+                                            *((ptr0 + 64) as *mut u8) = (1i32) as u8;
+                                            *((ptr0 + 68) as *mut i32) = wit_bindgen::rt::as_i32(e);
+                                            
+                                          },
+                                          None => {
+                                            {
+                                              *((ptr0 + 64) as *mut u8) = (0i32) as u8;
+                                              
+                                            }
+                                          },
+                                        };let ptr6 = ret_area.as_mut_ptr() as i32;
+                                        #[link(wasm_import_module = "server-physics")]
+                                        extern "C" {
+                                          #[cfg_attr(target_arch = "wasm32", link_name = "sweep")]
+                                          #[cfg_attr(not(target_arch = "wasm32"), link_name = "server-physics_sweep")]
+                                          fn wit_import(
+                                          _: i32, _: i32, );
+                                        }
+                                        wit_import(ptr0, ptr6);
+                                        let len7 = *((ptr6 + 4) as *const i32) as usize;
+                                        Vec::from_raw_parts(*((ptr6 + 0) as *const i32) as *mut _, len7, len7)
+                                      }
+                                    }
+                                    #[allow(clippy::all)]
+                                    pub fn overlap(shape: QueryShape,position: Vec3,rotation: Quat,scene: Option<ColliderScene>,layers: Option<u32>,) -> wit_bindgen::rt::vec::Vec::<EntityId>{
+                                      
+                                      #[allow(unused_imports)]
+                                      use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                      unsafe {
+                                        
+                                        #[repr(align(4))]
+                                        struct RetArea([u8; 8]);
+                                        let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
+                                        let (result2_0,result2_1,result2_2,result2_3,) = match shape {
+                                          QueryShape::Sphere(e) => (0i32, wit_bindgen::rt::as_f32(e), 0.0f32, 0.0f32),
+                                          QueryShape::Cuboid(e) => {
+                                            let super::types::Vec3{ x:x0, y:y0, z:z0, } = e;
+                                            
+                                            (1i32, wit_bindgen::rt::as_f32(x0), wit_bindgen::rt::as_f32(y0), wit_bindgen::rt::as_f32(z0))
+                                          },
+                                          QueryShape::Capsule(e) => {
+                                            let CapsuleShape{ radius:radius1, half_height:half_height1, } = e;
+                                            
+                                            (2i32, wit_bindgen::rt::as_f32(radius1), wit_bindgen::rt::as_f32(half_height1), 0.0f32)
+                                          },
+                                        };
+                                        let super::types::Vec3{ x:x3, y:y3, z:z3, } = position;
+                                        let super::types::Quat{ x:x4, y:y4, z:z4, w:w4, } = rotation;
+                                        let (result5_0,result5_1,) = match scene {
+                                          Some(e) => (1i32, match e {
+                                            ColliderScene::Physics => 0,
+                                            ColliderScene::TriggerArea => 1,
+                                            ColliderScene::Picking => 2,
+                                          }),
+                                          None => {
+                                            (0i32, 0i32)
+                                          },
+                                        };let (result6_0,result6_1,) = match layers {
+                                          Some(e) => (1i32, wit_bindgen::rt::as_i32(e)),
+                                          None => {
+                                            (0i32, 0i32)
+                                          },
+                                        };let ptr7 = ret_area.as_mut_ptr() as i32;
+                                        #[link(wasm_import_module = "server-physics")]
+                                        extern "C" {
+                                          #[cfg_attr(target_arch = "wasm32", link_name = "overlap")]
+                                          #[cfg_attr(not(target_arch = "wasm32"), link_name = "server-physics_overlap")]
+                                          fn wit_import(
+                                          _: i32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, _: i32, _: i32, _: i32, _: i32, );
+                                        }
+                                        wit_import(result2_0, result2_1, result2_2, result2_3, wit_bindgen::rt::as_f32(x3), wit_bindgen::rt::as_f32(y3), wit_bindgen::rt::as_f32(z3), wit_bindgen::rt::as_f32(x4), wit_bindgen::rt::as_f32(y4), wit_bindgen::rt::as_f32(z4), wit_bindgen::rt::as_f32(w4), result5_0, result5_1, result6_0, result6_1, ptr7);
+                                        let len8 = *((ptr7 + 4) as *const i32) as usize;
+                                        Vec::from_raw_parts(*((ptr7 + 0) as *const i32) as *mut _, len8, len8)
+                                      }
+                                    }
+                                    #[allow(clippy::all)]
+                                    pub fn create_joint(joint_type: JointType,entity0: EntityId,entity1: Option<EntityId>,position: Vec3,rotation: Quat,) -> EntityId{
+                                      
+                                      #[allow(unused_imports)]
+                                      use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                      unsafe {
+                                        
+                                        #[repr(align(8))]
+                                        struct RetArea([u8; 16]);
+                                        let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
+                                        let super::types::EntityId{ id0:id00, id1:id10, } = entity0;
+                                        let (result2_0,result2_1,result2_2,) = match entity1 {
+                                          Some(e) => {
+                                            let super::types::EntityId{ id0:id01, id1:id11, } = e;
+                                            
+                                            (1i32, wit_bindgen::rt::as_i64(id01), wit_bindgen::rt::as_i64(id11))
+                                          },
+                                          None => {
+                                            (0i32, 0i64, 0i64)
+                                          },
+                                        };let super::types::Vec3{ x:x3, y:y3, z:z3, } = position;
+                                        let super::types::Quat{ x:x4, y:y4, z:z4, w:w4, } = rotation;
+                                        let ptr5 = ret_area.as_mut_ptr() as i32;
+                                        #[link(wasm_import_module = "server-physics")]
+                                        extern "C" {
+                                          #[cfg_attr(target_arch = "wasm32", link_name = "create-joint")]
+                                          #[cfg_attr(not(target_arch = "wasm32"), link_name = "server-physics_create-joint")]
+                                          fn wit_import(
+                                          _: i32, _: i64, _: i64, _: i32, _: i64, _: i64, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32, _: i32, );
+                                        }
+                                        wit_import(match joint_type {
+                                          JointType::Fixed => 0,
+                                          JointType::Revolute => 1,
+                                          JointType::Prismatic => 2,
+                                          JointType::Spherical => 3,
+                                          JointType::Distance => 4,
+                                        }, wit_bindgen::rt::as_i64(id00), wit_bindgen::rt::as_i64(id10), result2_0, result2_1, result2_2, wit_bindgen::rt::as_f32(x3), wit_bindgen::rt::as_f32(y3), wit_bindgen::rt::as_f32(z3), wit_bindgen::rt::as_f32(x4), wit_bindgen::rt::as_f32(y4), wit_bindgen::rt::as_f32(z4), wit_bindgen::rt::as_f32(w4), ptr5);
+                                        super::types::EntityId{id0:*((ptr5 + 0) as *const i64) as u64, id1:*((ptr5 + 8) as *const i64) as u64, }
+                                      }
+                                    }
+                                    #[allow(clippy::all)]
+                                    pub fn break_joint(joint: EntityId,){
+                                      
+                                      #[allow(unused_imports)]
+                                      use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                      unsafe {
+                                        let super::types::EntityId{ id0:id00, id1:id10, } = joint;
+                                        
+                                        #[link(wasm_import_module = "server-physics")]
+                                        extern "C" {
+                                          #[cfg_attr(target_arch = "wasm32", link_name = "break-joint")]
+                                          #[cfg_attr(not(target_arch = "wasm32"), link_name = "server-physics_break-joint")]
+                                          fn wit_import(
+                                          _: i64, _: i64, );
+                                        }
+                                        wit_import(wit_bindgen::rt::as_i64(id00), wit_bindgen::rt::as_i64(id10));
+                                      }
+                                    }
+                                    #[allow(clippy::all)]
+                                    pub fn set_linear_velocity(entity: EntityId,velocity: Vec3,){
+                                      
+                                      #[allow(unused_imports)]
+                                      use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                      unsafe {
+                                        let super::types::EntityId{ id0:id00, id1:id10, } = entity;
+                                        let super::types::Vec3{ x:x1, y:y1, z:z1, } = velocity;
+                                        
+                                        #[link(wasm_import_module = "server-physics")]
+                                        extern "C" {
+                                          #[cfg_attr(target_arch = "wasm32", link_name = "set-linear-velocity")]
+                                          #[cfg_attr(not(target_arch = "wasm32"), link_name = "server-physics_set-linear-velocity")]
+                                          fn wit_import(
+                                          _: i64, _: i64, _: f32, _: f32, _: f32, );
+                                        }
+                                        wit_import(wit_bindgen::rt::as_i64(id00), wit_bindgen::rt::as_i64(id10), wit_bindgen::rt::as_f32(x1), wit_bindgen::rt::as_f32(y1), wit_bindgen::rt::as_f32(z1));
+                                      }
+                                    }
+                                    #[allow(clippy::all)]
+                                    pub fn set_angular_velocity(entity: EntityId,velocity: Vec3,){
+                                      
+                                      #[allow(unused_imports)]
+                                      use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                      unsafe {
+                                        let super::types::EntityId{ id0:id00, id1:id10, } = entity;
+                                        let super::types::Vec3{ x:x1, y:y1, z:z1, } = velocity;
+                                        
+                                        #[link(wasm_import_module = "server-physics")]
+                                        extern "C" {
+                                          #[cfg_attr(target_arch = "wasm32", link_name = "set-angular-velocity")]
+                                          #[cfg_attr(not(target_arch = "wasm32"), link_name = "server-physics_set-angular-velocity")]
+                                          fn wit_import(
+                                          _: i64, _: i64, _: f32, _: f32, _: f32, );
+                                        }
+                                        wit_import(wit_bindgen::rt::as_i64(id00), wit_bindgen::rt::as_i64(id10), wit_bindgen::rt::as_f32(x1), wit_bindgen::rt::as_f32(y1), wit_bindgen::rt::as_f32(z1));
+                                      }
+                                    }
+                                    #[allow(clippy::all)]
+                                    pub fn get_contacts(entity: EntityId,) -> wit_bindgen::rt::vec::Vec::<Contact>{
+                                      
+                                      #[allow(unused_imports)]
+                                      use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                      unsafe {
+                                        
+                                        #[repr(align(4))]
+                                        struct RetArea([u8; 8]);
+                                        let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
+                                        let super::types::EntityId{ id0:id00, id1:id10, } = entity;
+                                        let ptr1 = ret_area.as_mut_ptr() as i32;
+                                        #[link(wasm_import_module = "server-physics")]
+                                        extern "C" {
+                                          #[cfg_attr(target_arch = "wasm32", link_name = "get-contacts")]
+                                          #[cfg_attr(not(target_arch = "wasm32"), link_name = "server-physics_get-contacts")]
+                                          fn wit_import(
+                                          _: i64, _: i64, _: i32, );
+                                        }
+                                        wit_import(wit_bindgen::rt::as_i64(id00), wit_bindgen::rt::as_i64(id10), ptr1);
+                                        let len2 = *((ptr1 + 4) as *const i32) as usize;
+                                        Vec::from_raw_parts(*((ptr1 + 0) as *const i32) as *mut _, len2, len2)
+                                      }
+                                    }
                                     
                                   }
                                   
                                   
                                   #[allow(clippy::all)]
                                   pub mod server_asset{
+                                    #[repr(u8)]
+                                    #[derive(Clone, Copy, PartialEq, Eq)]
+                                    pub enum AssetType {
+                                      AssetCrate,
+                                      Prefab,
+                                      ScriptBundle,
+                                      Model,
+                                      Image,
+                                      Animation,
+                                      Material,
+                                      Collider,
+                                      TerrainMaterial,
+                                      Atmosphere,
+                                      Biomes,
+                                      VorbisTrack,
+                                      SoundGraph,
+                                    }
+                                    impl core::fmt::Debug for AssetType {
+                                      fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                                        match self {
+                                          AssetType::AssetCrate => {
+                                            f.debug_tuple("AssetType::AssetCrate").finish()
+                                          }
+                                          AssetType::Prefab => {
+                                            f.debug_tuple("AssetType::Prefab").finish()
+                                          }
+                                          AssetType::ScriptBundle => {
+                                            f.debug_tuple("AssetType::ScriptBundle").finish()
+                                          }
+                                          AssetType::Model => {
+                                            f.debug_tuple("AssetType::Model").finish()
+                                          }
+                                          AssetType::Image => {
+                                            f.debug_tuple("AssetType::Image").finish()
+                                          }
+                                          AssetType::Animation => {
+                                            f.debug_tuple("AssetType::Animation").finish()
+                                          }
+                                          AssetType::Material => {
+                                            f.debug_tuple("AssetType::Material").finish()
+                                          }
+                                          AssetType::Collider => {
+                                            f.debug_tuple("AssetType::Collider").finish()
+                                          }
+                                          AssetType::TerrainMaterial => {
+                                            f.debug_tuple("AssetType::TerrainMaterial").finish()
+                                          }
+                                          AssetType::Atmosphere => {
+                                            f.debug_tuple("AssetType::Atmosphere").finish()
+                                          }
+                                          AssetType::Biomes => {
+                                            f.debug_tuple("AssetType::Biomes").finish()
+                                          }
+                                          AssetType::VorbisTrack => {
+                                            f.debug_tuple("AssetType::VorbisTrack").finish()
+                                          }
+                                          AssetType::SoundGraph => {
+                                            f.debug_tuple("AssetType::SoundGraph").finish()
+                                          }
+                                        }
+                                      }
+                                    }
+                                    #[derive(Clone)]
+                                    pub struct CatalogAsset {
+                                      pub id: wit_bindgen::rt::string::String,
+                                      pub asset_type: AssetType,
+                                      pub name: wit_bindgen::rt::string::String,
+                                      pub tags: wit_bindgen::rt::vec::Vec::<wit_bindgen::rt::string::String>,
+                                      pub categories: wit_bindgen::rt::vec::Vec::<wit_bindgen::rt::string::String>,
+                                      pub url: Option<wit_bindgen::rt::string::String>,
+                                      pub collection: wit_bindgen::rt::vec::Vec::<wit_bindgen::rt::string::String>,
+                                      pub source: Option<wit_bindgen::rt::string::String>,
+                                      pub preview_url: Option<wit_bindgen::rt::string::String>,
+                                    }
+                                    impl core::fmt::Debug for CatalogAsset {
+                                      fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                                        f.debug_struct("CatalogAsset").field("id", &self.id).field("asset-type", &self.asset_type).field("name", &self.name).field("tags", &self.tags).field("categories", &self.categories).field("url", &self.url).field("collection", &self.collection).field("source", &self.source).field("preview-url", &self.preview_url).finish()
+                                      }
+                                    }
                                     #[allow(clippy::all)]
                                     pub fn url(path: &str,) -> Option<wit_bindgen::rt::string::String>{
                                       
@@ -7849,595 +8357,1378 @@ pub mod component{
                                         }
                                       }
                                     }
-                                    
-                                  }
-                                  
-                                  
-                                  #[allow(clippy::all)]
-                                  pub mod guest{
-                                    pub type Entity = super::component::EntityResult;
-                                    pub trait Guest {
-                                      fn init();
-                                      fn exec(time: f32,event_name: wit_bindgen::rt::string::String,event_data: Entity,);
-                                    }
-                                    
-                                    #[doc(hidden)]
-                                    pub unsafe fn call_init<T: Guest>() {
-                                      
-                                      #[allow(unused_imports)]
-                                      use wit_bindgen::rt::{alloc, vec::Vec, string::String};
-                                      
-                                      // Before executing any other code, use this function to run all static
-                                      // constructors, if they have not yet been run. This is a hack required
-                                      // to work around wasi-libc ctors calling import functions to initialize
-                                      // the environment.
-                                      //
-                                      // This functionality will be removed once rust 1.69.0 is stable, at which
-                                      // point wasi-libc will no longer have this behavior.
-                                      //
-                                      // See
-                                      // https://github.com/bytecodealliance/preview2-prototyping/issues/99
-                                      // for more details.
-                                      #[cfg(target_arch="wasm32")]
-                                      wit_bindgen::rt::run_ctors_once();
-                                      
-                                      T::init();
-                                    }
-                                    
-                                    #[doc(hidden)]
-                                    pub unsafe fn call_exec<T: Guest>(arg0: f32,arg1: i32,arg2: i32,arg3: i32,arg4: i32,) {
+                                    #[allow(clippy::all)]
+                                    pub fn catalog(asset_type: Option<AssetType>,tag: Option<&str>,category: Option<&str>,) -> wit_bindgen::rt::vec::Vec::<CatalogAsset>{
                                       
                                       #[allow(unused_imports)]
-                                      use wit_bindgen::rt::{alloc, vec::Vec, string::String};
-                                      
-                                      // Before executing any other code, use this function to run all static
-                                      // constructors, if they have not yet been run. This is a hack required
-                                      // to work around wasi-libc ctors calling import functions to initialize
-                                      // the environment.
-                                      //
-                                      // This functionality will be removed once rust 1.69.0 is stable, at which
-                                      // point wasi-libc will no longer have this behavior.
-                                      //
-                                      // See
-                                      // https://github.com/bytecodealliance/preview2-prototyping/issues/99
-                                      // for more details.
-                                      #[cfg(target_arch="wasm32")]
-                                      wit_bindgen::rt::run_ctors_once();
-                                      
-                                      let len0 = arg2 as usize;
-                                      let base21 = arg3;
-                                      let len21 = arg4;
-                                      let mut result21 = Vec::with_capacity(len21 as usize);
-                                      for i in 0..len21 {
-                                        let base = base21 + i *96;
-                                        result21.push((*((base + 0) as *const i32) as u32, {{match i32::from(*((base + 8) as *const u8)) {
-                                          0 => super::component::ValueResult::TypeEmpty(()),
-                                          1 => super::component::ValueResult::TypeBool({
-                                            #[cfg(not(debug_assertions))]
-                                            { core::mem::transmute::<u8, bool>(i32::from(*((base + 16) as *const u8)) as u8) }
-                                            #[cfg(debug_assertions)]
-                                            {
-                                              match i32::from(*((base + 16) as *const u8)) {
-                                                0 => false,
-                                                1 => true,
-                                                _ => panic!("invalid bool discriminant"),
-                                              }
-                                            }
+                                      use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                      unsafe {
+                                        
+                                        #[repr(align(4))]
+                                        struct RetArea([u8; 8]);
+                                        let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
+                                        let (result0_0,result0_1,) = match asset_type {
+                                          Some(e) => (1i32, match e {
+                                            AssetType::AssetCrate => 0,
+                                            AssetType::Prefab => 1,
+                                            AssetType::ScriptBundle => 2,
+                                            AssetType::Model => 3,
+                                            AssetType::Image => 4,
+                                            AssetType::Animation => 5,
+                                            AssetType::Material => 6,
+                                            AssetType::Collider => 7,
+                                            AssetType::TerrainMaterial => 8,
+                                            AssetType::Atmosphere => 9,
+                                            AssetType::Biomes => 10,
+                                            AssetType::VorbisTrack => 11,
+                                            AssetType::SoundGraph => 12,
                                           }),
-                                          2 => super::component::ValueResult::TypeEntityId(super::types::EntityId{id0:*((base + 16) as *const i64) as u64, id1:*((base + 24) as *const i64) as u64, }),
-                                          3 => super::component::ValueResult::TypeF32(*((base + 16) as *const f32)),
-                                          4 => super::component::ValueResult::TypeF64(*((base + 16) as *const f64)),
-                                          5 => super::component::ValueResult::TypeMat4(super::types::Mat4{x:super::types::Vec4{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), z:*((base + 24) as *const f32), w:*((base + 28) as *const f32), }, y:super::types::Vec4{x:*((base + 32) as *const f32), y:*((base + 36) as *const f32), z:*((base + 40) as *const f32), w:*((base + 44) as *const f32), }, z:super::types::Vec4{x:*((base + 48) as *const f32), y:*((base + 52) as *const f32), z:*((base + 56) as *const f32), w:*((base + 60) as *const f32), }, w:super::types::Vec4{x:*((base + 64) as *const f32), y:*((base + 68) as *const f32), z:*((base + 72) as *const f32), w:*((base + 76) as *const f32), }, }),
-                                          6 => super::component::ValueResult::TypeI32(*((base + 16) as *const i32)),
-                                          7 => super::component::ValueResult::TypeQuat(super::types::Quat{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), z:*((base + 24) as *const f32), w:*((base + 28) as *const f32), }),
-                                          8 => super::component::ValueResult::TypeString({
-                                            let len1 = *((base + 20) as *const i32) as usize;
+                                          None => {
+                                            (0i32, 0i32)
+                                          },
+                                        };let (result2_0,result2_1,result2_2,) = match tag {
+                                          Some(e) => {
+                                            let vec1 = e;
+                                            let ptr1 = vec1.as_ptr() as i32;
+                                            let len1 = vec1.len() as i32;
                                             
-                                            {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 16) as *const i32) as *mut _, len1, len1))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 16) as *const i32) as *mut _, len1, len1)).unwrap()}}
-                                          }),
-                                          9 => super::component::ValueResult::TypeU32(*((base + 16) as *const i32) as u32),
-                                          10 => super::component::ValueResult::TypeU64(*((base + 16) as *const i64) as u64),
-                                          11 => super::component::ValueResult::TypeVec2(super::types::Vec2{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), }),
-                                          12 => super::component::ValueResult::TypeVec3(super::types::Vec3{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), z:*((base + 24) as *const f32), }),
-                                          13 => super::component::ValueResult::TypeVec4(super::types::Vec4{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), z:*((base + 24) as *const f32), w:*((base + 28) as *const f32), }),
-                                          14 => super::component::ValueResult::TypeUvec2(super::types::Uvec2{x:*((base + 16) as *const i32) as u32, y:*((base + 20) as *const i32) as u32, }),
-                                          15 => super::component::ValueResult::TypeUvec3(super::types::Uvec3{x:*((base + 16) as *const i32) as u32, y:*((base + 20) as *const i32) as u32, z:*((base + 24) as *const i32) as u32, }),
-                                          16 => super::component::ValueResult::TypeUvec4(super::types::Uvec4{x:*((base + 16) as *const i32) as u32, y:*((base + 20) as *const i32) as u32, z:*((base + 24) as *const i32) as u32, w:*((base + 28) as *const i32) as u32, }),
-                                          17 => super::component::ValueResult::TypeVec({{match i32::from(*((base + 16) as *const u8)) {
-                                            0 => super::component::VecValueResult::TypeEmpty({
-                                              let len2 = *((base + 24) as *const i32) as usize;
-                                              
-                                              Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len2, len2)
-                                            }),
-                                            1 => super::component::VecValueResult::TypeBool({
-                                              let base3 = *((base + 20) as *const i32);
-                                              let len3 = *((base + 24) as *const i32);
-                                              let mut result3 = Vec::with_capacity(len3 as usize);
-                                              for i in 0..len3 {
-                                                let base = base3 + i *1;
-                                                result3.push({
-                                                  #[cfg(not(debug_assertions))]
-                                                  { core::mem::transmute::<u8, bool>(i32::from(*((base + 0) as *const u8)) as u8) }
-                                                  #[cfg(debug_assertions)]
-                                                  {
-                                                    match i32::from(*((base + 0) as *const u8)) {
-                                                      0 => false,
-                                                      1 => true,
-                                                      _ => panic!("invalid bool discriminant"),
-                                                    }
-                                                  }
-                                                });
-                                              }
-                                              wit_bindgen::rt::dealloc(base3, (len3 as usize) * 1, 1);
-                                              
-                                              result3
-                                            }),
-                                            2 => super::component::VecValueResult::TypeEntityId({
-                                              let len4 = *((base + 24) as *const i32) as usize;
-                                              
-                                              Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len4, len4)
-                                            }),
-                                            3 => super::component::VecValueResult::TypeF32({
-                                              let len5 = *((base + 24) as *const i32) as usize;
-                                              
-                                              Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len5, len5)
-                                            }),
-                                            4 => super::component::VecValueResult::TypeF64({
-                                              let len6 = *((base + 24) as *const i32) as usize;
-                                              
-                                              Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len6, len6)
-                                            }),
-                                            5 => super::component::VecValueResult::TypeMat4({
-                                              let len7 = *((base + 24) as *const i32) as usize;
-                                              
-                                              Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len7, len7)
-                                            }),
-                                            6 => super::component::VecValueResult::TypeI32({
-                                              let len8 = *((base + 24) as *const i32) as usize;
-                                              
-                                              Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len8, len8)
-                                            }),
-                                            7 => super::component::VecValueResult::TypeQuat({
-                                              let len9 = *((base + 24) as *const i32) as usize;
-                                              
-                                              Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len9, len9)
-                                            }),
-                                            8 => super::component::VecValueResult::TypeString({
-                                              let base11 = *((base + 20) as *const i32);
-                                              let len11 = *((base + 24) as *const i32);
-                                              let mut result11 = Vec::with_capacity(len11 as usize);
-                                              for i in 0..len11 {
-                                                let base = base11 + i *8;
-                                                result11.push({
-                                                  let len10 = *((base + 4) as *const i32) as usize;
-                                                  
-                                                  {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len10, len10))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len10, len10)).unwrap()}}
-                                                });
-                                              }
-                                              wit_bindgen::rt::dealloc(base11, (len11 as usize) * 8, 4);
-                                              
-                                              result11
-                                            }),
-                                            9 => super::component::VecValueResult::TypeU32({
-                                              let len12 = *((base + 24) as *const i32) as usize;
-                                              
-                                              Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len12, len12)
-                                            }),
-                                            10 => super::component::VecValueResult::TypeU64({
-                                              let len13 = *((base + 24) as *const i32) as usize;
-                                              
-                                              Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len13, len13)
-                                            }),
-                                            11 => super::component::VecValueResult::TypeVec2({
-                                              let len14 = *((base + 24) as *const i32) as usize;
-                                              
-                                              Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len14, len14)
-                                            }),
-                                            12 => super::component::VecValueResult::TypeVec3({
-                                              let len15 = *((base + 24) as *const i32) as usize;
-                                              
-                                              Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len15, len15)
-                                            }),
-                                            13 => super::component::VecValueResult::TypeVec4({
-                                              let len16 = *((base + 24) as *const i32) as usize;
-                                              
-                                              Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len16, len16)
-                                            }),
-                                            14 => super::component::VecValueResult::TypeUvec2({
-                                              let len17 = *((base + 24) as *const i32) as usize;
-                                              
-                                              Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len17, len17)
-                                            }),
-                                            15 => super::component::VecValueResult::TypeUvec3({
-                                              let len18 = *((base + 24) as *const i32) as usize;
-                                              
-                                              Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len18, len18)
-                                            }),
-                                            #[cfg(debug_assertions)]16 => super::component::VecValueResult::TypeUvec4({
-                                              let len19 = *((base + 24) as *const i32) as usize;
-                                              
-                                              Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len19, len19)
-                                            }),
-                                            #[cfg(not(debug_assertions))]_ => super::component::VecValueResult::TypeUvec4({
-                                              let len19 = *((base + 24) as *const i32) as usize;
-                                              
-                                              Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len19, len19)
-                                            }),
-                                            #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
-                                          }}}),
-                                          #[cfg(debug_assertions)]18 => super::component::ValueResult::TypeOption({{match i32::from(*((base + 16) as *const u8)) {
-                                            0 => super::component::OptionValueResult::TypeEmpty(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(()),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
+                                            (1i32, ptr1, len1)
+                                          },
+                                          None => {
+                                            (0i32, 0i32, 0i32)
+                                          },
+                                        };let (result4_0,result4_1,result4_2,) = match category {
+                                          Some(e) => {
+                                            let vec3 = e;
+                                            let ptr3 = vec3.as_ptr() as i32;
+                                            let len3 = vec3.len() as i32;
+                                            
+                                            (1i32, ptr3, len3)
+                                          },
+                                          None => {
+                                            (0i32, 0i32, 0i32)
+                                          },
+                                        };let ptr5 = ret_area.as_mut_ptr() as i32;
+                                        #[link(wasm_import_module = "server-asset")]
+                                        extern "C" {
+                                          #[cfg_attr(target_arch = "wasm32", link_name = "catalog")]
+                                          #[cfg_attr(not(target_arch = "wasm32"), link_name = "server-asset_catalog")]
+                                          fn wit_import(
+                                          _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, );
+                                        }
+                                        wit_import(result0_0, result0_1, result2_0, result2_1, result2_2, result4_0, result4_1, result4_2, ptr5);
+                                        let base17 = *((ptr5 + 0) as *const i32);
+                                        let len17 = *((ptr5 + 4) as *const i32);
+                                        let mut result17 = Vec::with_capacity(len17 as usize);
+                                        for i in 0..len17 {
+                                          let base = base17 + i *80;
+                                          result17.push({
+                                            let len6 = *((base + 4) as *const i32) as usize;
+                                            let len7 = *((base + 16) as *const i32) as usize;
+                                            let base9 = *((base + 20) as *const i32);
+                                            let len9 = *((base + 24) as *const i32);
+                                            let mut result9 = Vec::with_capacity(len9 as usize);
+                                            for i in 0..len9 {
+                                              let base = base9 + i *8;
+                                              result9.push({
+                                                let len8 = *((base + 4) as *const i32) as usize;
+                                                
+                                                {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len8, len8))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len8, len8)).unwrap()}}
+                                              });
+                                            }
+                                            wit_bindgen::rt::dealloc(base9, (len9 as usize) * 8, 4);
+                                            let base11 = *((base + 28) as *const i32);
+                                            let len11 = *((base + 32) as *const i32);
+                                            let mut result11 = Vec::with_capacity(len11 as usize);
+                                            for i in 0..len11 {
+                                              let base = base11 + i *8;
+                                              result11.push({
+                                                let len10 = *((base + 4) as *const i32) as usize;
+                                                
+                                                {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len10, len10))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len10, len10)).unwrap()}}
+                                              });
+                                            }
+                                            wit_bindgen::rt::dealloc(base11, (len11 as usize) * 8, 4);
+                                            let base14 = *((base + 48) as *const i32);
+                                            let len14 = *((base + 52) as *const i32);
+                                            let mut result14 = Vec::with_capacity(len14 as usize);
+                                            for i in 0..len14 {
+                                              let base = base14 + i *8;
+                                              result14.push({
+                                                let len13 = *((base + 4) as *const i32) as usize;
+                                                
+                                                {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len13, len13))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len13, len13)).unwrap()}}
+                                              });
+                                            }
+                                            wit_bindgen::rt::dealloc(base14, (len14 as usize) * 8, 4);
+                                            
+                                            CatalogAsset{id:{#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len6, len6))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len6, len6)).unwrap()}}, asset_type:{#[cfg(debug_assertions)]{match i32::from(*((base + 8) as *const u8)) {
+                                              0 => AssetType::AssetCrate,
+                                              1 => AssetType::Prefab,
+                                              2 => AssetType::ScriptBundle,
+                                              3 => AssetType::Model,
+                                              4 => AssetType::Image,
+                                              5 => AssetType::Animation,
+                                              6 => AssetType::Material,
+                                              7 => AssetType::Collider,
+                                              8 => AssetType::TerrainMaterial,
+                                              9 => AssetType::Atmosphere,
+                                              10 => AssetType::Biomes,
+                                              11 => AssetType::VorbisTrack,
+                                              12 => AssetType::SoundGraph,
                                               _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            1 => super::component::OptionValueResult::TypeBool(match i32::from(*((base + 24) as *const u8)) {
+                                            }}#[cfg(not(debug_assertions))]{core::mem::transmute::<_, AssetType>(i32::from(*((base + 8) as *const u8)) as u8)}}, name:{#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len7, len7))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 12) as *const i32) as *mut _, len7, len7)).unwrap()}}, tags:result9, categories:result11, url:match i32::from(*((base + 36) as *const u8)) {
                                               0 => None,
                                               1 => Some({
-                                                #[cfg(not(debug_assertions))]
-                                                { core::mem::transmute::<u8, bool>(i32::from(*((base + 25) as *const u8)) as u8) }
-                                                #[cfg(debug_assertions)]
-                                                {
-                                                  match i32::from(*((base + 25) as *const u8)) {
-                                                    0 => false,
-                                                    1 => true,
-                                                    _ => panic!("invalid bool discriminant"),
-                                                  }
-                                                }
+                                                let len12 = *((base + 44) as *const i32) as usize;
+                                                
+                                                {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 40) as *const i32) as *mut _, len12, len12))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 40) as *const i32) as *mut _, len12, len12)).unwrap()}}
                                               }),
                                               #[cfg(not(debug_assertions))]
                                               _ => core::hint::unreachable_unchecked(),
                                               #[cfg(debug_assertions)]
                                               _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            2 => super::component::OptionValueResult::TypeEntityId(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(super::types::EntityId{id0:*((base + 32) as *const i64) as u64, id1:*((base + 40) as *const i64) as u64, }),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            3 => super::component::OptionValueResult::TypeF32(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(*((base + 28) as *const f32)),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            4 => super::component::OptionValueResult::TypeF64(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(*((base + 32) as *const f64)),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            5 => super::component::OptionValueResult::TypeMat4(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(super::types::Mat4{x:super::types::Vec4{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }, y:super::types::Vec4{x:*((base + 44) as *const f32), y:*((base + 48) as *const f32), z:*((base + 52) as *const f32), w:*((base + 56) as *const f32), }, z:super::types::Vec4{x:*((base + 60) as *const f32), y:*((base + 64) as *const f32), z:*((base + 68) as *const f32), w:*((base + 72) as *const f32), }, w:super::types::Vec4{x:*((base + 76) as *const f32), y:*((base + 80) as *const f32), z:*((base + 84) as *const f32), w:*((base + 88) as *const f32), }, }),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            6 => super::component::OptionValueResult::TypeI32(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(*((base + 28) as *const i32)),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            7 => super::component::OptionValueResult::TypeQuat(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(super::types::Quat{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            8 => super::component::OptionValueResult::TypeString(match i32::from(*((base + 24) as *const u8)) {
+                                            }, collection:result14, source:match i32::from(*((base + 56) as *const u8)) {
                                               0 => None,
                                               1 => Some({
-                                                let len20 = *((base + 32) as *const i32) as usize;
+                                                let len15 = *((base + 64) as *const i32) as usize;
                                                 
-                                                {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 28) as *const i32) as *mut _, len20, len20))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 28) as *const i32) as *mut _, len20, len20)).unwrap()}}
+                                                {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 60) as *const i32) as *mut _, len15, len15))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 60) as *const i32) as *mut _, len15, len15)).unwrap()}}
                                               }),
                                               #[cfg(not(debug_assertions))]
                                               _ => core::hint::unreachable_unchecked(),
                                               #[cfg(debug_assertions)]
                                               _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            9 => super::component::OptionValueResult::TypeU32(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(*((base + 28) as *const i32) as u32),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            10 => super::component::OptionValueResult::TypeU64(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(*((base + 32) as *const i64) as u64),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            11 => super::component::OptionValueResult::TypeVec2(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(super::types::Vec2{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), }),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            12 => super::component::OptionValueResult::TypeVec3(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(super::types::Vec3{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), }),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            13 => super::component::OptionValueResult::TypeVec4(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(super::types::Vec4{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            14 => super::component::OptionValueResult::TypeUvec2(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(super::types::Uvec2{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, }),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            15 => super::component::OptionValueResult::TypeUvec3(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(super::types::Uvec3{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, z:*((base + 36) as *const i32) as u32, }),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            #[cfg(debug_assertions)]16 => super::component::OptionValueResult::TypeUvec4(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(super::types::Uvec4{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, z:*((base + 36) as *const i32) as u32, w:*((base + 40) as *const i32) as u32, }),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            #[cfg(not(debug_assertions))]_ => super::component::OptionValueResult::TypeUvec4(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(super::types::Uvec4{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, z:*((base + 36) as *const i32) as u32, w:*((base + 40) as *const i32) as u32, }),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
-                                          }}}),
-                                          #[cfg(not(debug_assertions))]_ => super::component::ValueResult::TypeOption({{match i32::from(*((base + 16) as *const u8)) {
-                                            0 => super::component::OptionValueResult::TypeEmpty(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(()),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            1 => super::component::OptionValueResult::TypeBool(match i32::from(*((base + 24) as *const u8)) {
+                                            }, preview_url:match i32::from(*((base + 68) as *const u8)) {
                                               0 => None,
                                               1 => Some({
-                                                #[cfg(not(debug_assertions))]
-                                                { core::mem::transmute::<u8, bool>(i32::from(*((base + 25) as *const u8)) as u8) }
-                                                #[cfg(debug_assertions)]
-                                                {
-                                                  match i32::from(*((base + 25) as *const u8)) {
-                                                    0 => false,
-                                                    1 => true,
-                                                    _ => panic!("invalid bool discriminant"),
-                                                  }
-                                                }
+                                                let len16 = *((base + 76) as *const i32) as usize;
+                                                
+                                                {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 72) as *const i32) as *mut _, len16, len16))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 72) as *const i32) as *mut _, len16, len16)).unwrap()}}
                                               }),
                                               #[cfg(not(debug_assertions))]
                                               _ => core::hint::unreachable_unchecked(),
                                               #[cfg(debug_assertions)]
                                               _ => panic!("invalid enum discriminant"),
+                                            }, }
+                                          });
+                                        }
+                                        wit_bindgen::rt::dealloc(base17, (len17 as usize) * 80, 4);
+                                        result17
+                                      }
+                                    }
+                                    
+                                  }
+                                  
+                                  
+                                  #[allow(clippy::all)]
+                                  pub mod server_io{
+                                    #[allow(clippy::all)]
+                                    pub fn read_asset(path: &str,) -> Result<u64,wit_bindgen::rt::string::String>{
+                                      
+                                      #[allow(unused_imports)]
+                                      use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                      unsafe {
+                                        
+                                        #[repr(align(8))]
+                                        struct RetArea([u8; 16]);
+                                        let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
+                                        let vec0 = path;
+                                        let ptr0 = vec0.as_ptr() as i32;
+                                        let len0 = vec0.len() as i32;
+                                        let ptr1 = ret_area.as_mut_ptr() as i32;
+                                        #[link(wasm_import_module = "server-io")]
+                                        extern "C" {
+                                          #[cfg_attr(target_arch = "wasm32", link_name = "read-asset")]
+                                          #[cfg_attr(not(target_arch = "wasm32"), link_name = "server-io_read-asset")]
+                                          fn wit_import(
+                                          _: i32, _: i32, _: i32, );
+                                        }
+                                        wit_import(ptr0, len0, ptr1);
+                                        match i32::from(*((ptr1 + 0) as *const u8)) {
+                                          0 => Ok(*((ptr1 + 8) as *const i64) as u64),
+                                          1 => Err({
+                                            let len2 = *((ptr1 + 12) as *const i32) as usize;
+                                            
+                                            {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((ptr1 + 8) as *const i32) as *mut _, len2, len2))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((ptr1 + 8) as *const i32) as *mut _, len2, len2)).unwrap()}}
+                                          }),
+                                          #[cfg(not(debug_assertions))]
+                                          _ => core::hint::unreachable_unchecked(),
+                                          #[cfg(debug_assertions)]
+                                          _ => panic!("invalid enum discriminant"),
+                                        }
+                                      }
+                                    }
+                                    #[allow(clippy::all)]
+                                    pub fn read_data(path: &str,) -> Result<u64,wit_bindgen::rt::string::String>{
+                                      
+                                      #[allow(unused_imports)]
+                                      use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                      unsafe {
+                                        
+                                        #[repr(align(8))]
+                                        struct RetArea([u8; 16]);
+                                        let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
+                                        let vec0 = path;
+                                        let ptr0 = vec0.as_ptr() as i32;
+                                        let len0 = vec0.len() as i32;
+                                        let ptr1 = ret_area.as_mut_ptr() as i32;
+                                        #[link(wasm_import_module = "server-io")]
+                                        extern "C" {
+                                          #[cfg_attr(target_arch = "wasm32", link_name = "read-data")]
+                                          #[cfg_attr(not(target_arch = "wasm32"), link_name = "server-io_read-data")]
+                                          fn wit_import(
+                                          _: i32, _: i32, _: i32, );
+                                        }
+                                        wit_import(ptr0, len0, ptr1);
+                                        match i32::from(*((ptr1 + 0) as *const u8)) {
+                                          0 => Ok(*((ptr1 + 8) as *const i64) as u64),
+                                          1 => Err({
+                                            let len2 = *((ptr1 + 12) as *const i32) as usize;
+                                            
+                                            {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((ptr1 + 8) as *const i32) as *mut _, len2, len2))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((ptr1 + 8) as *const i32) as *mut _, len2, len2)).unwrap()}}
+                                          }),
+                                          #[cfg(not(debug_assertions))]
+                                          _ => core::hint::unreachable_unchecked(),
+                                          #[cfg(debug_assertions)]
+                                          _ => panic!("invalid enum discriminant"),
+                                        }
+                                      }
+                                    }
+                                    #[allow(clippy::all)]
+                                    pub fn write_data(path: &str,data: &[u8],) -> Result<u64,wit_bindgen::rt::string::String>{
+                                      
+                                      #[allow(unused_imports)]
+                                      use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                      unsafe {
+                                        
+                                        #[repr(align(8))]
+                                        struct RetArea([u8; 16]);
+                                        let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
+                                        let vec0 = path;
+                                        let ptr0 = vec0.as_ptr() as i32;
+                                        let len0 = vec0.len() as i32;
+                                        let vec1 = data;
+                                        let ptr1 = vec1.as_ptr() as i32;
+                                        let len1 = vec1.len() as i32;
+                                        let ptr2 = ret_area.as_mut_ptr() as i32;
+                                        #[link(wasm_import_module = "server-io")]
+                                        extern "C" {
+                                          #[cfg_attr(target_arch = "wasm32", link_name = "write-data")]
+                                          #[cfg_attr(not(target_arch = "wasm32"), link_name = "server-io_write-data")]
+                                          fn wit_import(
+                                          _: i32, _: i32, _: i32, _: i32, _: i32, );
+                                        }
+                                        wit_import(ptr0, len0, ptr1, len1, ptr2);
+                                        match i32::from(*((ptr2 + 0) as *const u8)) {
+                                          0 => Ok(*((ptr2 + 8) as *const i64) as u64),
+                                          1 => Err({
+                                            let len3 = *((ptr2 + 12) as *const i32) as usize;
+                                            
+                                            {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((ptr2 + 8) as *const i32) as *mut _, len3, len3))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((ptr2 + 8) as *const i32) as *mut _, len3, len3)).unwrap()}}
+                                          }),
+                                          #[cfg(not(debug_assertions))]
+                                          _ => core::hint::unreachable_unchecked(),
+                                          #[cfg(debug_assertions)]
+                                          _ => panic!("invalid enum discriminant"),
+                                        }
+                                      }
+                                    }
+                                    #[allow(clippy::all)]
+                                    pub fn http_get(url: &str,) -> Result<u64,wit_bindgen::rt::string::String>{
+                                      
+                                      #[allow(unused_imports)]
+                                      use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                      unsafe {
+                                        
+                                        #[repr(align(8))]
+                                        struct RetArea([u8; 16]);
+                                        let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
+                                        let vec0 = url;
+                                        let ptr0 = vec0.as_ptr() as i32;
+                                        let len0 = vec0.len() as i32;
+                                        let ptr1 = ret_area.as_mut_ptr() as i32;
+                                        #[link(wasm_import_module = "server-io")]
+                                        extern "C" {
+                                          #[cfg_attr(target_arch = "wasm32", link_name = "http-get")]
+                                          #[cfg_attr(not(target_arch = "wasm32"), link_name = "server-io_http-get")]
+                                          fn wit_import(
+                                          _: i32, _: i32, _: i32, );
+                                        }
+                                        wit_import(ptr0, len0, ptr1);
+                                        match i32::from(*((ptr1 + 0) as *const u8)) {
+                                          0 => Ok(*((ptr1 + 8) as *const i64) as u64),
+                                          1 => Err({
+                                            let len2 = *((ptr1 + 12) as *const i32) as usize;
+                                            
+                                            {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((ptr1 + 8) as *const i32) as *mut _, len2, len2))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((ptr1 + 8) as *const i32) as *mut _, len2, len2)).unwrap()}}
+                                          }),
+                                          #[cfg(not(debug_assertions))]
+                                          _ => core::hint::unreachable_unchecked(),
+                                          #[cfg(debug_assertions)]
+                                          _ => panic!("invalid enum discriminant"),
+                                        }
+                                      }
+                                    }
+                                    #[allow(clippy::all)]
+                                    pub fn http_post(url: &str,body: &[u8],) -> Result<u64,wit_bindgen::rt::string::String>{
+                                      
+                                      #[allow(unused_imports)]
+                                      use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                      unsafe {
+                                        
+                                        #[repr(align(8))]
+                                        struct RetArea([u8; 16]);
+                                        let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
+                                        let vec0 = url;
+                                        let ptr0 = vec0.as_ptr() as i32;
+                                        let len0 = vec0.len() as i32;
+                                        let vec1 = body;
+                                        let ptr1 = vec1.as_ptr() as i32;
+                                        let len1 = vec1.len() as i32;
+                                        let ptr2 = ret_area.as_mut_ptr() as i32;
+                                        #[link(wasm_import_module = "server-io")]
+                                        extern "C" {
+                                          #[cfg_attr(target_arch = "wasm32", link_name = "http-post")]
+                                          #[cfg_attr(not(target_arch = "wasm32"), link_name = "server-io_http-post")]
+                                          fn wit_import(
+                                          _: i32, _: i32, _: i32, _: i32, _: i32, );
+                                        }
+                                        wit_import(ptr0, len0, ptr1, len1, ptr2);
+                                        match i32::from(*((ptr2 + 0) as *const u8)) {
+                                          0 => Ok(*((ptr2 + 8) as *const i64) as u64),
+                                          1 => Err({
+                                            let len3 = *((ptr2 + 12) as *const i32) as usize;
+                                            
+                                            {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((ptr2 + 8) as *const i32) as *mut _, len3, len3))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((ptr2 + 8) as *const i32) as *mut _, len3, len3)).unwrap()}}
+                                          }),
+                                          #[cfg(not(debug_assertions))]
+                                          _ => core::hint::unreachable_unchecked(),
+                                          #[cfg(debug_assertions)]
+                                          _ => panic!("invalid enum discriminant"),
+                                        }
+                                      }
+                                    }
+                                    #[allow(clippy::all)]
+                                    pub fn poll(request: u64,) -> Option<Result<wit_bindgen::rt::vec::Vec::<u8>,wit_bindgen::rt::string::String>>{
+                                      
+                                      #[allow(unused_imports)]
+                                      use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                      unsafe {
+                                        
+                                        #[repr(align(4))]
+                                        struct RetArea([u8; 16]);
+                                        let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
+                                        let ptr0 = ret_area.as_mut_ptr() as i32;
+                                        #[link(wasm_import_module = "server-io")]
+                                        extern "C" {
+                                          #[cfg_attr(target_arch = "wasm32", link_name = "poll")]
+                                          #[cfg_attr(not(target_arch = "wasm32"), link_name = "server-io_poll")]
+                                          fn wit_import(
+                                          _: i64, _: i32, );
+                                        }
+                                        wit_import(wit_bindgen::rt::as_i64(request), ptr0);
+                                        match i32::from(*((ptr0 + 0) as *const u8)) {
+                                          0 => None,
+                                          1 => Some(match i32::from(*((ptr0 + 4) as *const u8)) {
+                                            0 => Ok({
+                                              let len1 = *((ptr0 + 12) as *const i32) as usize;
+                                              
+                                              Vec::from_raw_parts(*((ptr0 + 8) as *const i32) as *mut _, len1, len1)
                                             }),
-                                            2 => super::component::OptionValueResult::TypeEntityId(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(super::types::EntityId{id0:*((base + 32) as *const i64) as u64, id1:*((base + 40) as *const i64) as u64, }),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            3 => super::component::OptionValueResult::TypeF32(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(*((base + 28) as *const f32)),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            4 => super::component::OptionValueResult::TypeF64(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(*((base + 32) as *const f64)),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            5 => super::component::OptionValueResult::TypeMat4(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(super::types::Mat4{x:super::types::Vec4{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }, y:super::types::Vec4{x:*((base + 44) as *const f32), y:*((base + 48) as *const f32), z:*((base + 52) as *const f32), w:*((base + 56) as *const f32), }, z:super::types::Vec4{x:*((base + 60) as *const f32), y:*((base + 64) as *const f32), z:*((base + 68) as *const f32), w:*((base + 72) as *const f32), }, w:super::types::Vec4{x:*((base + 76) as *const f32), y:*((base + 80) as *const f32), z:*((base + 84) as *const f32), w:*((base + 88) as *const f32), }, }),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            6 => super::component::OptionValueResult::TypeI32(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(*((base + 28) as *const i32)),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            7 => super::component::OptionValueResult::TypeQuat(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(super::types::Quat{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            8 => super::component::OptionValueResult::TypeString(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some({
-                                                let len20 = *((base + 32) as *const i32) as usize;
-                                                
-                                                {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 28) as *const i32) as *mut _, len20, len20))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 28) as *const i32) as *mut _, len20, len20)).unwrap()}}
-                                              }),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            9 => super::component::OptionValueResult::TypeU32(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(*((base + 28) as *const i32) as u32),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            10 => super::component::OptionValueResult::TypeU64(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(*((base + 32) as *const i64) as u64),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            11 => super::component::OptionValueResult::TypeVec2(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(super::types::Vec2{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), }),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            12 => super::component::OptionValueResult::TypeVec3(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(super::types::Vec3{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), }),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
-                                            }),
-                                            13 => super::component::OptionValueResult::TypeVec4(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(super::types::Vec4{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
+                                            1 => Err({
+                                              let len2 = *((ptr0 + 12) as *const i32) as usize;
+                                              
+                                              {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((ptr0 + 8) as *const i32) as *mut _, len2, len2))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((ptr0 + 8) as *const i32) as *mut _, len2, len2)).unwrap()}}
                                             }),
-                                            14 => super::component::OptionValueResult::TypeUvec2(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(super::types::Uvec2{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, }),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
+                                            #[cfg(not(debug_assertions))]
+                                            _ => core::hint::unreachable_unchecked(),
+                                            #[cfg(debug_assertions)]
+                                            _ => panic!("invalid enum discriminant"),
+                                          }),
+                                          #[cfg(not(debug_assertions))]
+                                          _ => core::hint::unreachable_unchecked(),
+                                          #[cfg(debug_assertions)]
+                                          _ => panic!("invalid enum discriminant"),
+                                        }
+                                      }
+                                    }
+                                    
+                                  }
+                                  
+                                  
+                                  #[allow(clippy::all)]
+                                  pub mod server_storage{
+                                    #[derive(Clone)]
+                                    pub struct BatchWrite<'a,> {
+                                      pub key: &'a str,
+                                      pub value: Option<&'a [u8]>,
+                                    }
+                                    impl<'a,> core::fmt::Debug for BatchWrite<'a,> {
+                                      fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                                        f.debug_struct("BatchWrite").field("key", &self.key).field("value", &self.value).finish()
+                                      }
+                                    }
+                                    #[allow(clippy::all)]
+                                    pub fn get(key: &str,) -> Option<wit_bindgen::rt::vec::Vec::<u8>>{
+                                      
+                                      #[allow(unused_imports)]
+                                      use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                      unsafe {
+                                        
+                                        #[repr(align(4))]
+                                        struct RetArea([u8; 12]);
+                                        let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
+                                        let vec0 = key;
+                                        let ptr0 = vec0.as_ptr() as i32;
+                                        let len0 = vec0.len() as i32;
+                                        let ptr1 = ret_area.as_mut_ptr() as i32;
+                                        #[link(wasm_import_module = "server-storage")]
+                                        extern "C" {
+                                          #[cfg_attr(target_arch = "wasm32", link_name = "get")]
+                                          #[cfg_attr(not(target_arch = "wasm32"), link_name = "server-storage_get")]
+                                          fn wit_import(
+                                          _: i32, _: i32, _: i32, );
+                                        }
+                                        wit_import(ptr0, len0, ptr1);
+                                        match i32::from(*((ptr1 + 0) as *const u8)) {
+                                          0 => None,
+                                          1 => Some({
+                                            let len2 = *((ptr1 + 8) as *const i32) as usize;
+                                            
+                                            Vec::from_raw_parts(*((ptr1 + 4) as *const i32) as *mut _, len2, len2)
+                                          }),
+                                          #[cfg(not(debug_assertions))]
+                                          _ => core::hint::unreachable_unchecked(),
+                                          #[cfg(debug_assertions)]
+                                          _ => panic!("invalid enum discriminant"),
+                                        }
+                                      }
+                                    }
+                                    #[allow(clippy::all)]
+                                    pub fn set(key: &str,value: &[u8],) -> Result<(),wit_bindgen::rt::string::String>{
+                                      
+                                      #[allow(unused_imports)]
+                                      use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                      unsafe {
+                                        
+                                        #[repr(align(4))]
+                                        struct RetArea([u8; 12]);
+                                        let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
+                                        let vec0 = key;
+                                        let ptr0 = vec0.as_ptr() as i32;
+                                        let len0 = vec0.len() as i32;
+                                        let vec1 = value;
+                                        let ptr1 = vec1.as_ptr() as i32;
+                                        let len1 = vec1.len() as i32;
+                                        let ptr2 = ret_area.as_mut_ptr() as i32;
+                                        #[link(wasm_import_module = "server-storage")]
+                                        extern "C" {
+                                          #[cfg_attr(target_arch = "wasm32", link_name = "set")]
+                                          #[cfg_attr(not(target_arch = "wasm32"), link_name = "server-storage_set")]
+                                          fn wit_import(
+                                          _: i32, _: i32, _: i32, _: i32, _: i32, );
+                                        }
+                                        wit_import(ptr0, len0, ptr1, len1, ptr2);
+                                        match i32::from(*((ptr2 + 0) as *const u8)) {
+                                          0 => Ok(()),
+                                          1 => Err({
+                                            let len3 = *((ptr2 + 8) as *const i32) as usize;
+                                            
+                                            {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((ptr2 + 4) as *const i32) as *mut _, len3, len3))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((ptr2 + 4) as *const i32) as *mut _, len3, len3)).unwrap()}}
+                                          }),
+                                          #[cfg(not(debug_assertions))]
+                                          _ => core::hint::unreachable_unchecked(),
+                                          #[cfg(debug_assertions)]
+                                          _ => panic!("invalid enum discriminant"),
+                                        }
+                                      }
+                                    }
+                                    #[allow(clippy::all)]
+                                    pub fn delete(key: &str,) -> Result<(),wit_bindgen::rt::string::String>{
+                                      
+                                      #[allow(unused_imports)]
+                                      use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                      unsafe {
+                                        
+                                        #[repr(align(4))]
+                                        struct RetArea([u8; 12]);
+                                        let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
+                                        let vec0 = key;
+                                        let ptr0 = vec0.as_ptr() as i32;
+                                        let len0 = vec0.len() as i32;
+                                        let ptr1 = ret_area.as_mut_ptr() as i32;
+                                        #[link(wasm_import_module = "server-storage")]
+                                        extern "C" {
+                                          #[cfg_attr(target_arch = "wasm32", link_name = "delete")]
+                                          #[cfg_attr(not(target_arch = "wasm32"), link_name = "server-storage_delete")]
+                                          fn wit_import(
+                                          _: i32, _: i32, _: i32, );
+                                        }
+                                        wit_import(ptr0, len0, ptr1);
+                                        match i32::from(*((ptr1 + 0) as *const u8)) {
+                                          0 => Ok(()),
+                                          1 => Err({
+                                            let len2 = *((ptr1 + 8) as *const i32) as usize;
+                                            
+                                            {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((ptr1 + 4) as *const i32) as *mut _, len2, len2))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((ptr1 + 4) as *const i32) as *mut _, len2, len2)).unwrap()}}
+                                          }),
+                                          #[cfg(not(debug_assertions))]
+                                          _ => core::hint::unreachable_unchecked(),
+                                          #[cfg(debug_assertions)]
+                                          _ => panic!("invalid enum discriminant"),
+                                        }
+                                      }
+                                    }
+                                    #[allow(clippy::all)]
+                                    pub fn list_prefix(prefix: &str,) -> wit_bindgen::rt::vec::Vec::<wit_bindgen::rt::string::String>{
+                                      
+                                      #[allow(unused_imports)]
+                                      use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                      unsafe {
+                                        
+                                        #[repr(align(4))]
+                                        struct RetArea([u8; 8]);
+                                        let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
+                                        let vec0 = prefix;
+                                        let ptr0 = vec0.as_ptr() as i32;
+                                        let len0 = vec0.len() as i32;
+                                        let ptr1 = ret_area.as_mut_ptr() as i32;
+                                        #[link(wasm_import_module = "server-storage")]
+                                        extern "C" {
+                                          #[cfg_attr(target_arch = "wasm32", link_name = "list-prefix")]
+                                          #[cfg_attr(not(target_arch = "wasm32"), link_name = "server-storage_list-prefix")]
+                                          fn wit_import(
+                                          _: i32, _: i32, _: i32, );
+                                        }
+                                        wit_import(ptr0, len0, ptr1);
+                                        let base3 = *((ptr1 + 0) as *const i32);
+                                        let len3 = *((ptr1 + 4) as *const i32);
+                                        let mut result3 = Vec::with_capacity(len3 as usize);
+                                        for i in 0..len3 {
+                                          let base = base3 + i *8;
+                                          result3.push({
+                                            let len2 = *((base + 4) as *const i32) as usize;
+                                            
+                                            {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len2, len2))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len2, len2)).unwrap()}}
+                                          });
+                                        }
+                                        wit_bindgen::rt::dealloc(base3, (len3 as usize) * 8, 4);
+                                        result3
+                                      }
+                                    }
+                                    #[allow(clippy::all)]
+                                    pub fn write_batch(writes: &[BatchWrite<'_,>],) -> Result<(),wit_bindgen::rt::string::String>{
+                                      
+                                      #[allow(unused_imports)]
+                                      use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                      unsafe {
+                                        
+                                        #[repr(align(4))]
+                                        struct RetArea([u8; 12]);
+                                        let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
+                                        let vec3 = writes;
+                                        let len3 = vec3.len() as i32;
+                                        let layout3 = alloc::Layout::from_size_align_unchecked(vec3.len() * 20, 4);
+                                        let result3 = if layout3.size() != 0
+                                        {
+                                          let ptr = alloc::alloc(layout3);
+                                          if ptr.is_null()
+                                          {
+                                            alloc::handle_alloc_error(layout3);
+                                          }
+                                          ptr
+                                        }else {
+                                          core::ptr::null_mut()
+                                        };
+                                        for (i, e) in vec3.into_iter().enumerate() {
+                                          let base = result3 as i32 + (i as i32) * 20;
+                                          {
+                                            let BatchWrite{ key:key0, value:value0, } = e;
+                                            let vec1 = key0;
+                                            let ptr1 = vec1.as_ptr() as i32;
+                                            let len1 = vec1.len() as i32;
+                                            *((base + 4) as *mut i32) = len1;
+                                            *((base + 0) as *mut i32) = ptr1;
+                                            match value0 {
+                                              Some(e) => {
+                                                *((base + 8) as *mut u8) = (1i32) as u8;
+                                                let vec2 = e;
+                                                let ptr2 = vec2.as_ptr() as i32;
+                                                let len2 = vec2.len() as i32;
+                                                *((base + 16) as *mut i32) = len2;
+                                                *((base + 12) as *mut i32) = ptr2;
+                                                
+                                              },
+                                              None => {
+                                                {
+                                                  *((base + 8) as *mut u8) = (0i32) as u8;
+                                                  
+                                                }
+                                              },
+                                            };
+                                          }}
+                                          let ptr4 = ret_area.as_mut_ptr() as i32;
+                                          #[link(wasm_import_module = "server-storage")]
+                                          extern "C" {
+                                            #[cfg_attr(target_arch = "wasm32", link_name = "write-batch")]
+                                            #[cfg_attr(not(target_arch = "wasm32"), link_name = "server-storage_write-batch")]
+                                            fn wit_import(
+                                            _: i32, _: i32, _: i32, );
+                                          }
+                                          wit_import(result3 as i32, len3, ptr4);
+                                          if layout3.size() != 0 {
+                                            alloc::dealloc(result3, layout3);
+                                          }
+                                          match i32::from(*((ptr4 + 0) as *const u8)) {
+                                            0 => Ok(()),
+                                            1 => Err({
+                                              let len5 = *((ptr4 + 8) as *const i32) as usize;
+                                              
+                                              {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((ptr4 + 4) as *const i32) as *mut _, len5, len5))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((ptr4 + 4) as *const i32) as *mut _, len5, len5)).unwrap()}}
                                             }),
-                                            15 => super::component::OptionValueResult::TypeUvec3(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(super::types::Uvec3{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, z:*((base + 36) as *const i32) as u32, }),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
+                                            #[cfg(not(debug_assertions))]
+                                            _ => core::hint::unreachable_unchecked(),
+                                            #[cfg(debug_assertions)]
+                                            _ => panic!("invalid enum discriminant"),
+                                          }
+                                        }
+                                      }
+                                      
+                                    }
+                                    
+                                    
+                                    #[allow(clippy::all)]
+                                    pub mod client_audio{
+                                      pub type EntityId = super::types::EntityId;
+                                      #[allow(clippy::all)]
+                                      pub fn play(entity: EntityId,url: &str,) -> Result<u64,wit_bindgen::rt::string::String>{
+                                        
+                                        #[allow(unused_imports)]
+                                        use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                        unsafe {
+                                          
+                                          #[repr(align(8))]
+                                          struct RetArea([u8; 16]);
+                                          let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
+                                          let super::types::EntityId{ id0:id00, id1:id10, } = entity;
+                                          let vec1 = url;
+                                          let ptr1 = vec1.as_ptr() as i32;
+                                          let len1 = vec1.len() as i32;
+                                          let ptr2 = ret_area.as_mut_ptr() as i32;
+                                          #[link(wasm_import_module = "client-audio")]
+                                          extern "C" {
+                                            #[cfg_attr(target_arch = "wasm32", link_name = "play")]
+                                            #[cfg_attr(not(target_arch = "wasm32"), link_name = "client-audio_play")]
+                                            fn wit_import(
+                                            _: i64, _: i64, _: i32, _: i32, _: i32, );
+                                          }
+                                          wit_import(wit_bindgen::rt::as_i64(id00), wit_bindgen::rt::as_i64(id10), ptr1, len1, ptr2);
+                                          match i32::from(*((ptr2 + 0) as *const u8)) {
+                                            0 => Ok(*((ptr2 + 8) as *const i64) as u64),
+                                            1 => Err({
+                                              let len3 = *((ptr2 + 12) as *const i32) as usize;
+                                              
+                                              {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((ptr2 + 8) as *const i32) as *mut _, len3, len3))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((ptr2 + 8) as *const i32) as *mut _, len3, len3)).unwrap()}}
                                             }),
-                                            #[cfg(debug_assertions)]16 => super::component::OptionValueResult::TypeUvec4(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(super::types::Uvec4{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, z:*((base + 36) as *const i32) as u32, w:*((base + 40) as *const i32) as u32, }),
+                                            #[cfg(not(debug_assertions))]
+                                            _ => core::hint::unreachable_unchecked(),
+                                            #[cfg(debug_assertions)]
+                                            _ => panic!("invalid enum discriminant"),
+                                          }
+                                        }
+                                      }
+                                      #[allow(clippy::all)]
+                                      pub fn stop(sound: u64,fade_out: f32,){
+                                        
+                                        #[allow(unused_imports)]
+                                        use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                        unsafe {
+                                          
+                                          #[link(wasm_import_module = "client-audio")]
+                                          extern "C" {
+                                            #[cfg_attr(target_arch = "wasm32", link_name = "stop")]
+                                            #[cfg_attr(not(target_arch = "wasm32"), link_name = "client-audio_stop")]
+                                            fn wit_import(
+                                            _: i64, _: f32, );
+                                          }
+                                          wit_import(wit_bindgen::rt::as_i64(sound), wit_bindgen::rt::as_f32(fade_out));
+                                        }
+                                      }
+                                      #[allow(clippy::all)]
+                                      pub fn set_gain(sound: u64,gain: f32,){
+                                        
+                                        #[allow(unused_imports)]
+                                        use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                        unsafe {
+                                          
+                                          #[link(wasm_import_module = "client-audio")]
+                                          extern "C" {
+                                            #[cfg_attr(target_arch = "wasm32", link_name = "set-gain")]
+                                            #[cfg_attr(not(target_arch = "wasm32"), link_name = "client-audio_set-gain")]
+                                            fn wit_import(
+                                            _: i64, _: f32, );
+                                          }
+                                          wit_import(wit_bindgen::rt::as_i64(sound), wit_bindgen::rt::as_f32(gain));
+                                        }
+                                      }
+                                      #[allow(clippy::all)]
+                                      pub fn set_pitch(sound: u64,pitch: f32,){
+                                        
+                                        #[allow(unused_imports)]
+                                        use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                        unsafe {
+                                          
+                                          #[link(wasm_import_module = "client-audio")]
+                                          extern "C" {
+                                            #[cfg_attr(target_arch = "wasm32", link_name = "set-pitch")]
+                                            #[cfg_attr(not(target_arch = "wasm32"), link_name = "client-audio_set-pitch")]
+                                            fn wit_import(
+                                            _: i64, _: f32, );
+                                          }
+                                          wit_import(wit_bindgen::rt::as_i64(sound), wit_bindgen::rt::as_f32(pitch));
+                                        }
+                                      }
+                                      #[allow(clippy::all)]
+                                      pub fn set_cutoff(sound: u64,cutoff: Option<f32>,){
+                                        
+                                        #[allow(unused_imports)]
+                                        use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                        unsafe {
+                                          let (result0_0,result0_1,) = match cutoff {
+                                            Some(e) => (1i32, wit_bindgen::rt::as_f32(e)),
+                                            None => {
+                                              (0i32, 0.0f32)
+                                            },
+                                          };
+                                          #[link(wasm_import_module = "client-audio")]
+                                          extern "C" {
+                                            #[cfg_attr(target_arch = "wasm32", link_name = "set-cutoff")]
+                                            #[cfg_attr(not(target_arch = "wasm32"), link_name = "client-audio_set-cutoff")]
+                                            fn wit_import(
+                                            _: i64, _: i32, _: f32, );
+                                          }
+                                          wit_import(wit_bindgen::rt::as_i64(sound), result0_0, result0_1);
+                                        }
+                                      }
+                                      #[allow(clippy::all)]
+                                      pub fn is_playing(sound: u64,) -> bool{
+                                        
+                                        #[allow(unused_imports)]
+                                        use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+                                        unsafe {
+                                          
+                                          #[link(wasm_import_module = "client-audio")]
+                                          extern "C" {
+                                            #[cfg_attr(target_arch = "wasm32", link_name = "is-playing")]
+                                            #[cfg_attr(not(target_arch = "wasm32"), link_name = "client-audio_is-playing")]
+                                            fn wit_import(
+                                            _: i64, ) -> i32;
+                                          }
+                                          let ret = wit_import(wit_bindgen::rt::as_i64(sound));
+                                          {
+                                            #[cfg(not(debug_assertions))]
+                                            { core::mem::transmute::<u8, bool>(ret as u8) }
+                                            #[cfg(debug_assertions)]
+                                            {
+                                              match ret {
+                                                0 => false,
+                                                1 => true,
+                                                _ => panic!("invalid bool discriminant"),
+                                              }
+                                            }
+                                          }
+                                        }
+                                      }
+                                      
+                                    }
+                                    
+                                    
+                                    #[allow(clippy::all)]
+                                    pub mod guest{
+                                      pub type Entity = super::component::EntityResult;
+                                      pub trait Guest {
+                                        fn init();
+                                        fn exec(time: f32,event_name: wit_bindgen::rt::string::String,event_data: Entity,);
+                                      }
+                                      
+                                      #[doc(hidden)]
+                                      pub unsafe fn call_init<T: Guest>() {
+                                        
+                                        #[allow(unused_imports)]
+                                        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                        
+                                        // Before executing any other code, use this function to run all static
+                                        // constructors, if they have not yet been run. This is a hack required
+                                        // to work around wasi-libc ctors calling import functions to initialize
+                                        // the environment.
+                                        //
+                                        // This functionality will be removed once rust 1.69.0 is stable, at which
+                                        // point wasi-libc will no longer have this behavior.
+                                        //
+                                        // See
+                                        // https://github.com/bytecodealliance/preview2-prototyping/issues/99
+                                        // for more details.
+                                        #[cfg(target_arch="wasm32")]
+                                        wit_bindgen::rt::run_ctors_once();
+                                        
+                                        T::init();
+                                      }
+                                      
+                                      #[doc(hidden)]
+                                      pub unsafe fn call_exec<T: Guest>(arg0: f32,arg1: i32,arg2: i32,arg3: i32,arg4: i32,) {
+                                        
+                                        #[allow(unused_imports)]
+                                        use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+                                        
+                                        // Before executing any other code, use this function to run all static
+                                        // constructors, if they have not yet been run. This is a hack required
+                                        // to work around wasi-libc ctors calling import functions to initialize
+                                        // the environment.
+                                        //
+                                        // This functionality will be removed once rust 1.69.0 is stable, at which
+                                        // point wasi-libc will no longer have this behavior.
+                                        //
+                                        // See
+                                        // https://github.com/bytecodealliance/preview2-prototyping/issues/99
+                                        // for more details.
+                                        #[cfg(target_arch="wasm32")]
+                                        wit_bindgen::rt::run_ctors_once();
+                                        
+                                        let len0 = arg2 as usize;
+                                        let base21 = arg3;
+                                        let len21 = arg4;
+                                        let mut result21 = Vec::with_capacity(len21 as usize);
+                                        for i in 0..len21 {
+                                          let base = base21 + i *96;
+                                          result21.push((*((base + 0) as *const i32) as u32, {{match i32::from(*((base + 8) as *const u8)) {
+                                            0 => super::component::ValueResult::TypeEmpty(()),
+                                            1 => super::component::ValueResult::TypeBool({
                                               #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
+                                              { core::mem::transmute::<u8, bool>(i32::from(*((base + 16) as *const u8)) as u8) }
                                               #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
+                                              {
+                                                match i32::from(*((base + 16) as *const u8)) {
+                                                  0 => false,
+                                                  1 => true,
+                                                  _ => panic!("invalid bool discriminant"),
+                                                }
+                                              }
                                             }),
-                                            #[cfg(not(debug_assertions))]_ => super::component::OptionValueResult::TypeUvec4(match i32::from(*((base + 24) as *const u8)) {
-                                              0 => None,
-                                              1 => Some(super::types::Uvec4{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, z:*((base + 36) as *const i32) as u32, w:*((base + 40) as *const i32) as u32, }),
-                                              #[cfg(not(debug_assertions))]
-                                              _ => core::hint::unreachable_unchecked(),
-                                              #[cfg(debug_assertions)]
-                                              _ => panic!("invalid enum discriminant"),
+                                            2 => super::component::ValueResult::TypeEntityId(super::types::EntityId{id0:*((base + 16) as *const i64) as u64, id1:*((base + 24) as *const i64) as u64, }),
+                                            3 => super::component::ValueResult::TypeF32(*((base + 16) as *const f32)),
+                                            4 => super::component::ValueResult::TypeF64(*((base + 16) as *const f64)),
+                                            5 => super::component::ValueResult::TypeMat4(super::types::Mat4{x:super::types::Vec4{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), z:*((base + 24) as *const f32), w:*((base + 28) as *const f32), }, y:super::types::Vec4{x:*((base + 32) as *const f32), y:*((base + 36) as *const f32), z:*((base + 40) as *const f32), w:*((base + 44) as *const f32), }, z:super::types::Vec4{x:*((base + 48) as *const f32), y:*((base + 52) as *const f32), z:*((base + 56) as *const f32), w:*((base + 60) as *const f32), }, w:super::types::Vec4{x:*((base + 64) as *const f32), y:*((base + 68) as *const f32), z:*((base + 72) as *const f32), w:*((base + 76) as *const f32), }, }),
+                                            6 => super::component::ValueResult::TypeI32(*((base + 16) as *const i32)),
+                                            7 => super::component::ValueResult::TypeQuat(super::types::Quat{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), z:*((base + 24) as *const f32), w:*((base + 28) as *const f32), }),
+                                            8 => super::component::ValueResult::TypeString({
+                                              let len1 = *((base + 20) as *const i32) as usize;
+                                              
+                                              {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 16) as *const i32) as *mut _, len1, len1))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 16) as *const i32) as *mut _, len1, len1)).unwrap()}}
                                             }),
+                                            9 => super::component::ValueResult::TypeU32(*((base + 16) as *const i32) as u32),
+                                            10 => super::component::ValueResult::TypeU64(*((base + 16) as *const i64) as u64),
+                                            11 => super::component::ValueResult::TypeVec2(super::types::Vec2{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), }),
+                                            12 => super::component::ValueResult::TypeVec3(super::types::Vec3{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), z:*((base + 24) as *const f32), }),
+                                            13 => super::component::ValueResult::TypeVec4(super::types::Vec4{x:*((base + 16) as *const f32), y:*((base + 20) as *const f32), z:*((base + 24) as *const f32), w:*((base + 28) as *const f32), }),
+                                            14 => super::component::ValueResult::TypeUvec2(super::types::Uvec2{x:*((base + 16) as *const i32) as u32, y:*((base + 20) as *const i32) as u32, }),
+                                            15 => super::component::ValueResult::TypeUvec3(super::types::Uvec3{x:*((base + 16) as *const i32) as u32, y:*((base + 20) as *const i32) as u32, z:*((base + 24) as *const i32) as u32, }),
+                                            16 => super::component::ValueResult::TypeUvec4(super::types::Uvec4{x:*((base + 16) as *const i32) as u32, y:*((base + 20) as *const i32) as u32, z:*((base + 24) as *const i32) as u32, w:*((base + 28) as *const i32) as u32, }),
+                                            17 => super::component::ValueResult::TypeVec({{match i32::from(*((base + 16) as *const u8)) {
+                                              0 => super::component::VecValueResult::TypeEmpty({
+                                                let len2 = *((base + 24) as *const i32) as usize;
+                                                
+                                                Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len2, len2)
+                                              }),
+                                              1 => super::component::VecValueResult::TypeBool({
+                                                let base3 = *((base + 20) as *const i32);
+                                                let len3 = *((base + 24) as *const i32);
+                                                let mut result3 = Vec::with_capacity(len3 as usize);
+                                                for i in 0..len3 {
+                                                  let base = base3 + i *1;
+                                                  result3.push({
+                                                    #[cfg(not(debug_assertions))]
+                                                    { core::mem::transmute::<u8, bool>(i32::from(*((base + 0) as *const u8)) as u8) }
+                                                    #[cfg(debug_assertions)]
+                                                    {
+                                                      match i32::from(*((base + 0) as *const u8)) {
+                                                        0 => false,
+                                                        1 => true,
+                                                        _ => panic!("invalid bool discriminant"),
+                                                      }
+                                                    }
+                                                  });
+                                                }
+                                                wit_bindgen::rt::dealloc(base3, (len3 as usize) * 1, 1);
+                                                
+                                                result3
+                                              }),
+                                              2 => super::component::VecValueResult::TypeEntityId({
+                                                let len4 = *((base + 24) as *const i32) as usize;
+                                                
+                                                Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len4, len4)
+                                              }),
+                                              3 => super::component::VecValueResult::TypeF32({
+                                                let len5 = *((base + 24) as *const i32) as usize;
+                                                
+                                                Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len5, len5)
+                                              }),
+                                              4 => super::component::VecValueResult::TypeF64({
+                                                let len6 = *((base + 24) as *const i32) as usize;
+                                                
+                                                Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len6, len6)
+                                              }),
+                                              5 => super::component::VecValueResult::TypeMat4({
+                                                let len7 = *((base + 24) as *const i32) as usize;
+                                                
+                                                Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len7, len7)
+                                              }),
+                                              6 => super::component::VecValueResult::TypeI32({
+                                                let len8 = *((base + 24) as *const i32) as usize;
+                                                
+                                                Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len8, len8)
+                                              }),
+                                              7 => super::component::VecValueResult::TypeQuat({
+                                                let len9 = *((base + 24) as *const i32) as usize;
+                                                
+                                                Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len9, len9)
+                                              }),
+                                              8 => super::component::VecValueResult::TypeString({
+                                                let base11 = *((base + 20) as *const i32);
+                                                let len11 = *((base + 24) as *const i32);
+                                                let mut result11 = Vec::with_capacity(len11 as usize);
+                                                for i in 0..len11 {
+                                                  let base = base11 + i *8;
+                                                  result11.push({
+                                                    let len10 = *((base + 4) as *const i32) as usize;
+                                                    
+                                                    {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len10, len10))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len10, len10)).unwrap()}}
+                                                  });
+                                                }
+                                                wit_bindgen::rt::dealloc(base11, (len11 as usize) * 8, 4);
+                                                
+                                                result11
+                                              }),
+                                              9 => super::component::VecValueResult::TypeU32({
+                                                let len12 = *((base + 24) as *const i32) as usize;
+                                                
+                                                Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len12, len12)
+                                              }),
+                                              10 => super::component::VecValueResult::TypeU64({
+                                                let len13 = *((base + 24) as *const i32) as usize;
+                                                
+                                                Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len13, len13)
+                                              }),
+                                              11 => super::component::VecValueResult::TypeVec2({
+                                                let len14 = *((base + 24) as *const i32) as usize;
+                                                
+                                                Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len14, len14)
+                                              }),
+                                              12 => super::component::VecValueResult::TypeVec3({
+                                                let len15 = *((base + 24) as *const i32) as usize;
+                                                
+                                                Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len15, len15)
+                                              }),
+                                              13 => super::component::VecValueResult::TypeVec4({
+                                                let len16 = *((base + 24) as *const i32) as usize;
+                                                
+                                                Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len16, len16)
+                                              }),
+                                              14 => super::component::VecValueResult::TypeUvec2({
+                                                let len17 = *((base + 24) as *const i32) as usize;
+                                                
+                                                Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len17, len17)
+                                              }),
+                                              15 => super::component::VecValueResult::TypeUvec3({
+                                                let len18 = *((base + 24) as *const i32) as usize;
+                                                
+                                                Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len18, len18)
+                                              }),
+                                              #[cfg(debug_assertions)]16 => super::component::VecValueResult::TypeUvec4({
+                                                let len19 = *((base + 24) as *const i32) as usize;
+                                                
+                                                Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len19, len19)
+                                              }),
+                                              #[cfg(not(debug_assertions))]_ => super::component::VecValueResult::TypeUvec4({
+                                                let len19 = *((base + 24) as *const i32) as usize;
+                                                
+                                                Vec::from_raw_parts(*((base + 20) as *const i32) as *mut _, len19, len19)
+                                              }),
+                                              #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
+                                            }}}),
+                                            #[cfg(debug_assertions)]18 => super::component::ValueResult::TypeOption({{match i32::from(*((base + 16) as *const u8)) {
+                                              0 => super::component::OptionValueResult::TypeEmpty(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(()),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              1 => super::component::OptionValueResult::TypeBool(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some({
+                                                  #[cfg(not(debug_assertions))]
+                                                  { core::mem::transmute::<u8, bool>(i32::from(*((base + 25) as *const u8)) as u8) }
+                                                  #[cfg(debug_assertions)]
+                                                  {
+                                                    match i32::from(*((base + 25) as *const u8)) {
+                                                      0 => false,
+                                                      1 => true,
+                                                      _ => panic!("invalid bool discriminant"),
+                                                    }
+                                                  }
+                                                }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              2 => super::component::OptionValueResult::TypeEntityId(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(super::types::EntityId{id0:*((base + 32) as *const i64) as u64, id1:*((base + 40) as *const i64) as u64, }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              3 => super::component::OptionValueResult::TypeF32(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(*((base + 28) as *const f32)),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              4 => super::component::OptionValueResult::TypeF64(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(*((base + 32) as *const f64)),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              5 => super::component::OptionValueResult::TypeMat4(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(super::types::Mat4{x:super::types::Vec4{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }, y:super::types::Vec4{x:*((base + 44) as *const f32), y:*((base + 48) as *const f32), z:*((base + 52) as *const f32), w:*((base + 56) as *const f32), }, z:super::types::Vec4{x:*((base + 60) as *const f32), y:*((base + 64) as *const f32), z:*((base + 68) as *const f32), w:*((base + 72) as *const f32), }, w:super::types::Vec4{x:*((base + 76) as *const f32), y:*((base + 80) as *const f32), z:*((base + 84) as *const f32), w:*((base + 88) as *const f32), }, }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              6 => super::component::OptionValueResult::TypeI32(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(*((base + 28) as *const i32)),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              7 => super::component::OptionValueResult::TypeQuat(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(super::types::Quat{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              8 => super::component::OptionValueResult::TypeString(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some({
+                                                  let len20 = *((base + 32) as *const i32) as usize;
+                                                  
+                                                  {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 28) as *const i32) as *mut _, len20, len20))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 28) as *const i32) as *mut _, len20, len20)).unwrap()}}
+                                                }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              9 => super::component::OptionValueResult::TypeU32(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(*((base + 28) as *const i32) as u32),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              10 => super::component::OptionValueResult::TypeU64(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(*((base + 32) as *const i64) as u64),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              11 => super::component::OptionValueResult::TypeVec2(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(super::types::Vec2{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              12 => super::component::OptionValueResult::TypeVec3(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(super::types::Vec3{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              13 => super::component::OptionValueResult::TypeVec4(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(super::types::Vec4{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              14 => super::component::OptionValueResult::TypeUvec2(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(super::types::Uvec2{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              15 => super::component::OptionValueResult::TypeUvec3(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(super::types::Uvec3{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, z:*((base + 36) as *const i32) as u32, }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              #[cfg(debug_assertions)]16 => super::component::OptionValueResult::TypeUvec4(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(super::types::Uvec4{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, z:*((base + 36) as *const i32) as u32, w:*((base + 40) as *const i32) as u32, }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              #[cfg(not(debug_assertions))]_ => super::component::OptionValueResult::TypeUvec4(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(super::types::Uvec4{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, z:*((base + 36) as *const i32) as u32, w:*((base + 40) as *const i32) as u32, }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
+                                            }}}),
+                                            #[cfg(not(debug_assertions))]_ => super::component::ValueResult::TypeOption({{match i32::from(*((base + 16) as *const u8)) {
+                                              0 => super::component::OptionValueResult::TypeEmpty(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(()),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              1 => super::component::OptionValueResult::TypeBool(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some({
+                                                  #[cfg(not(debug_assertions))]
+                                                  { core::mem::transmute::<u8, bool>(i32::from(*((base + 25) as *const u8)) as u8) }
+                                                  #[cfg(debug_assertions)]
+                                                  {
+                                                    match i32::from(*((base + 25) as *const u8)) {
+                                                      0 => false,
+                                                      1 => true,
+                                                      _ => panic!("invalid bool discriminant"),
+                                                    }
+                                                  }
+                                                }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              2 => super::component::OptionValueResult::TypeEntityId(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(super::types::EntityId{id0:*((base + 32) as *const i64) as u64, id1:*((base + 40) as *const i64) as u64, }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              3 => super::component::OptionValueResult::TypeF32(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(*((base + 28) as *const f32)),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              4 => super::component::OptionValueResult::TypeF64(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(*((base + 32) as *const f64)),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              5 => super::component::OptionValueResult::TypeMat4(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(super::types::Mat4{x:super::types::Vec4{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }, y:super::types::Vec4{x:*((base + 44) as *const f32), y:*((base + 48) as *const f32), z:*((base + 52) as *const f32), w:*((base + 56) as *const f32), }, z:super::types::Vec4{x:*((base + 60) as *const f32), y:*((base + 64) as *const f32), z:*((base + 68) as *const f32), w:*((base + 72) as *const f32), }, w:super::types::Vec4{x:*((base + 76) as *const f32), y:*((base + 80) as *const f32), z:*((base + 84) as *const f32), w:*((base + 88) as *const f32), }, }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              6 => super::component::OptionValueResult::TypeI32(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(*((base + 28) as *const i32)),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              7 => super::component::OptionValueResult::TypeQuat(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(super::types::Quat{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              8 => super::component::OptionValueResult::TypeString(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some({
+                                                  let len20 = *((base + 32) as *const i32) as usize;
+                                                  
+                                                  {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 28) as *const i32) as *mut _, len20, len20))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 28) as *const i32) as *mut _, len20, len20)).unwrap()}}
+                                                }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              9 => super::component::OptionValueResult::TypeU32(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(*((base + 28) as *const i32) as u32),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              10 => super::component::OptionValueResult::TypeU64(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(*((base + 32) as *const i64) as u64),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              11 => super::component::OptionValueResult::TypeVec2(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(super::types::Vec2{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              12 => super::component::OptionValueResult::TypeVec3(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(super::types::Vec3{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              13 => super::component::OptionValueResult::TypeVec4(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(super::types::Vec4{x:*((base + 28) as *const f32), y:*((base + 32) as *const f32), z:*((base + 36) as *const f32), w:*((base + 40) as *const f32), }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              14 => super::component::OptionValueResult::TypeUvec2(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(super::types::Uvec2{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              15 => super::component::OptionValueResult::TypeUvec3(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(super::types::Uvec3{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, z:*((base + 36) as *const i32) as u32, }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              #[cfg(debug_assertions)]16 => super::component::OptionValueResult::TypeUvec4(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(super::types::Uvec4{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, z:*((base + 36) as *const i32) as u32, w:*((base + 40) as *const i32) as u32, }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              #[cfg(not(debug_assertions))]_ => super::component::OptionValueResult::TypeUvec4(match i32::from(*((base + 24) as *const u8)) {
+                                                0 => None,
+                                                1 => Some(super::types::Uvec4{x:*((base + 28) as *const i32) as u32, y:*((base + 32) as *const i32) as u32, z:*((base + 36) as *const i32) as u32, w:*((base + 40) as *const i32) as u32, }),
+                                                #[cfg(not(debug_assertions))]
+                                                _ => core::hint::unreachable_unchecked(),
+                                                #[cfg(debug_assertions)]
+                                                _ => panic!("invalid enum discriminant"),
+                                              }),
+                                              #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
+                                            }}}),
                                             #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
-                                          }}}),
-                                          #[cfg(debug_assertions)]_ => panic!("invalid enum discriminant"),
-                                        }}}));
+                                          }}}));
+                                        }
+                                        wit_bindgen::rt::dealloc(base21, (len21 as usize) * 96, 8);
+                                        T::exec(arg0, {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(arg1 as *mut _, len0, len0))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(arg1 as *mut _, len0, len0)).unwrap()}}, result21);
                                       }
-                                      wit_bindgen::rt::dealloc(base21, (len21 as usize) * 96, 8);
-                                      T::exec(arg0, {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(arg1 as *mut _, len0, len0))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(arg1 as *mut _, len0, len0)).unwrap()}}, result21);
+                                      
                                     }
                                     
-                                  }
-                                  
-                                  
-                                  /// Declares the export of the component's world for the
-                                  /// given type.
-                                  
-                                  macro_rules! export_bindings(($t:ident) => {
-                                    const _: () = {
+                                    
+                                    /// Declares the export of the component's world for the
+                                    /// given type.
+                                    
+                                    macro_rules! export_bindings(($t:ident) => {
+                                      const _: () = {
+                                        
+                                        #[doc(hidden)]
+                                        #[export_name = "guest#init"]
+                                        #[allow(non_snake_case)]
+                                        unsafe extern "C" fn __export_guest_init() {
+                                          guest::call_init::<$t>()
+                                        }
+                                        
+                                        #[doc(hidden)]
+                                        #[export_name = "guest#exec"]
+                                        #[allow(non_snake_case)]
+                                        unsafe extern "C" fn __export_guest_exec(arg0: f32,arg1: i32,arg2: i32,arg3: i32,arg4: i32,) {
+                                          guest::call_exec::<$t>(arg0,arg1,arg2,arg3,arg4,)
+                                        }
+                                        
+                                      };
                                       
+                                      #[used]
                                       #[doc(hidden)]
-                                      #[export_name = "guest#init"]
-                                      #[allow(non_snake_case)]
-                                      unsafe extern "C" fn __export_guest_init() {
-                                        guest::call_init::<$t>()
-                                      }
-                                      
+                                      #[cfg(target_arch = "wasm32")]
+                                      static __FORCE_SECTION_REF: fn() = __force_section_ref;
                                       #[doc(hidden)]
-                                      #[export_name = "guest#exec"]
-                                      #[allow(non_snake_case)]
-                                      unsafe extern "C" fn __export_guest_exec(arg0: f32,arg1: i32,arg2: i32,arg3: i32,arg4: i32,) {
-                                        guest::call_exec::<$t>(arg0,arg1,arg2,arg3,arg4,)
+                                      #[cfg(target_arch = "wasm32")]
+                                      fn __force_section_ref() {
+                                        __link_section()
                                       }
-                                      
-                                    };
+                                    });
                                     
-                                    #[used]
-                                    #[doc(hidden)]
                                     #[cfg(target_arch = "wasm32")]
-                                    static __FORCE_SECTION_REF: fn() = __force_section_ref;
+                                    #[link_section = "component-type:bindings"]
+                                    #[doc(hidden)]pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 19514] = [2, 0, 3, 119, 105, 116, 4, 109, 97, 105, 110, 8, 98, 105, 110, 100, 105, 110, 103, 115, 0, 97, 115, 109, 12, 0, 1, 0, 7, 245, 1, 1, 65, 2, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 4, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 11, 21, 1, 5, 116, 121, 112, 101, 115, 10, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 3, 0, 0, 7, 221, 12, 1, 65, 6, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 3, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 2, 3, 0, 0, 4, 118, 101, 99, 50, 2, 3, 0, 0, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 66, 16, 2, 3, 2, 1, 1, 4, 4, 118, 101, 99, 50, 0, 3, 0, 0, 2, 3, 2, 1, 2, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 2, 1, 109, 163, 1, 4, 107, 101, 121, 49, 4, 107, 101, 121, 50, 4, 107, 101, 121, 51, 4, 107, 101, 121, 52, 4, 107, 101, 121, 53, 4, 107, 101, 121, 54, 4, 107, 101, 121, 55, 4, 107, 101, 121, 56, 4, 107, 101, 121, 57, 4, 107, 101, 121, 48, 1, 97, 1, 98, 1, 99, 1, 100, 1, 101, 1, 102, 1, 103, 1, 104, 1, 105, 1, 106, 1, 107, 1, 108, 1, 109, 1, 110, 1, 111, 1, 112, 1, 113, 1, 114, 1, 115, 1, 116, 1, 117, 1, 118, 1, 119, 1, 120, 1, 121, 1, 122, 6, 101, 115, 99, 97, 112, 101, 2, 102, 49, 2, 102, 50, 2, 102, 51, 2, 102, 52, 2, 102, 53, 2, 102, 54, 2, 102, 55, 2, 102, 56, 2, 102, 57, 3, 102, 49, 48, 3, 102, 49, 49, 3, 102, 49, 50, 3, 102, 49, 51, 3, 102, 49, 52, 3, 102, 49, 53, 3, 102, 49, 54, 3, 102, 49, 55, 3, 102, 49, 56, 3, 102, 49, 57, 3, 102, 50, 48, 3, 102, 50, 49, 3, 102, 50, 50, 3, 102, 50, 51, 3, 102, 50, 52, 8, 115, 110, 97, 112, 115, 104, 111, 116, 6, 115, 99, 114, 111, 108, 108, 5, 112, 97, 117, 115, 101, 6, 105, 110, 115, 101, 114, 116, 4, 104, 111, 109, 101, 6, 100, 101, 108, 101, 116, 101, 3, 101, 110, 100, 9, 112, 97, 103, 101, 45, 100, 111, 119, 110, 7, 112, 97, 103, 101, 45, 117, 112, 4, 108, 101, 102, 116, 2, 117, 112, 5, 114, 105, 103, 104, 116, 4, 100, 111, 119, 110, 4, 98, 97, 99, 107, 6, 114, 101, 116, 117, 114, 110, 5, 115, 112, 97, 99, 101, 7, 99, 111, 109, 112, 111, 115, 101, 5, 99, 97, 114, 101, 116, 7, 110, 117, 109, 108, 111, 99, 107, 7, 110, 117, 109, 112, 97, 100, 48, 7, 110, 117, 109, 112, 97, 100, 49, 7, 110, 117, 109, 112, 97, 100, 50, 7, 110, 117, 109, 112, 97, 100, 51, 7, 110, 117, 109, 112, 97, 100, 52, 7, 110, 117, 109, 112, 97, 100, 53, 7, 110, 117, 109, 112, 97, 100, 54, 7, 110, 117, 109, 112, 97, 100, 55, 7, 110, 117, 109, 112, 97, 100, 56, 7, 110, 117, 109, 112, 97, 100, 57, 10, 110, 117, 109, 112, 97, 100, 45, 97, 100, 100, 13, 110, 117, 109, 112, 97, 100, 45, 100, 105, 118, 105, 100, 101, 14, 110, 117, 109, 112, 97, 100, 45, 100, 101, 99, 105, 109, 97, 108, 12, 110, 117, 109, 112, 97, 100, 45, 99, 111, 109, 109, 97, 12, 110, 117, 109, 112, 97, 100, 45, 101, 110, 116, 101, 114, 13, 110, 117, 109, 112, 97, 100, 45, 101, 113, 117, 97, 108, 115, 15, 110, 117, 109, 112, 97, 100, 45, 109, 117, 108, 116, 105, 112, 108, 121, 15, 110, 117, 109, 112, 97, 100, 45, 115, 117, 98, 116, 114, 97, 99, 116, 7, 97, 98, 110, 116, 45, 99, 49, 7, 97, 98, 110, 116, 45, 99, 50, 10, 97, 112, 111, 115, 116, 114, 111, 112, 104, 101, 4, 97, 112, 112, 115, 8, 97, 115, 116, 101, 114, 105, 115, 107, 2, 97, 116, 2, 97, 120, 9, 98, 97, 99, 107, 115, 108, 97, 115, 104, 10, 99, 97, 108, 99, 117, 108, 97, 116, 111, 114, 7, 99, 97, 112, 105, 116, 97, 108, 5, 99, 111, 108, 111, 110, 5, 99, 111, 109, 109, 97, 7, 99, 111, 110, 118, 101, 114, 116, 6, 101, 113, 117, 97, 108, 115, 5, 103, 114, 97, 118, 101, 4, 107, 97, 110, 97, 5, 107, 97, 110, 106, 105, 5, 108, 45, 97, 108, 116, 9, 108, 45, 98, 114, 97, 99, 107, 101, 116, 9, 108, 45, 99, 111, 110, 116, 114, 111, 108, 7, 108, 45, 115, 104, 105, 102, 116, 5, 108, 45, 119, 105, 110, 4, 109, 97, 105, 108, 12, 109, 101, 100, 105, 97, 45, 115, 101, 108, 101, 99, 116, 10, 109, 101, 100, 105, 97, 45, 115, 116, 111, 112, 5, 109, 105, 110, 117, 115, 4, 109, 117, 116, 101, 11, 109, 121, 45, 99, 111, 109, 112, 117, 116, 101, 114, 16, 110, 97, 118, 105, 103, 97, 116, 101, 45, 102, 111, 114, 119, 97, 114, 100, 17, 110, 97, 118, 105, 103, 97, 116, 101, 45, 98, 97, 99, 107, 119, 97, 114, 100, 10, 110, 101, 120, 116, 45, 116, 114, 97, 99, 107, 10, 110, 111, 45, 99, 111, 110, 118, 101, 114, 116, 6, 111, 101, 109, 49, 48, 50, 6, 112, 101, 114, 105, 111, 100, 10, 112, 108, 97, 121, 45, 112, 97, 117, 115, 101, 4, 112, 108, 117, 115, 5, 112, 111, 119, 101, 114, 10, 112, 114, 101, 118, 45, 116, 114, 97, 99, 107, 5, 114, 45, 97, 108, 116, 9, 114, 45, 98, 114, 97, 99, 107, 101, 116, 9, 114, 45, 99, 111, 110, 116, 114, 111, 108, 7, 114, 45, 115, 104, 105, 102, 116, 5, 114, 45, 119, 105, 110, 9, 115, 101, 109, 105, 99, 111, 108, 111, 110, 5, 115, 108, 97, 115, 104, 5, 115, 108, 101, 101, 112, 4, 115, 116, 111, 112, 5, 115, 121, 115, 114, 113, 3, 116, 97, 98, 9, 117, 110, 100, 101, 114, 108, 105, 110, 101, 9, 117, 110, 108, 97, 98, 101, 108, 101, 100, 11, 118, 111, 108, 117, 109, 101, 45, 100, 111, 119, 110, 9, 118, 111, 108, 117, 109, 101, 45, 117, 112, 4, 119, 97, 107, 101, 8, 119, 101, 98, 45, 98, 97, 99, 107, 13, 119, 101, 98, 45, 102, 97, 118, 111, 114, 105, 116, 101, 115, 11, 119, 101, 98, 45, 102, 111, 114, 119, 97, 114, 100, 8, 119, 101, 98, 45, 104, 111, 109, 101, 11, 119, 101, 98, 45, 114, 101, 102, 114, 101, 115, 104, 10, 119, 101, 98, 45, 115, 101, 97, 114, 99, 104, 8, 119, 101, 98, 45, 115, 116, 111, 112, 3, 121, 101, 110, 4, 99, 111, 112, 121, 5, 112, 97, 115, 116, 101, 3, 99, 117, 116, 4, 16, 118, 105, 114, 116, 117, 97, 108, 45, 107, 101, 121, 45, 99, 111, 100, 101, 0, 3, 0, 4, 1, 113, 4, 4, 108, 101, 102, 116, 0, 0, 5, 114, 105, 103, 104, 116, 0, 0, 6, 109, 105, 100, 100, 108, 101, 0, 0, 5, 111, 116, 104, 101, 114, 1, 123, 0, 4, 12, 109, 111, 117, 115, 101, 45, 98, 117, 116, 116, 111, 110, 0, 3, 0, 6, 1, 112, 5, 1, 112, 7, 1, 114, 5, 4, 107, 101, 121, 115, 8, 14, 109, 111, 117, 115, 101, 45, 112, 111, 115, 105, 116, 105, 111, 110, 1, 15, 99, 117, 114, 115, 111, 114, 45, 112, 111, 115, 105, 116, 105, 111, 110, 1, 11, 109, 111, 117, 115, 101, 45, 119, 104, 101, 101, 108, 118, 13, 109, 111, 117, 115, 101, 45, 98, 117, 116, 116, 111, 110, 115, 9, 4, 9, 114, 97, 119, 45, 105, 110, 112, 117, 116, 0, 3, 0, 10, 1, 107, 11, 1, 64, 1, 6, 112, 108, 97, 121, 101, 114, 3, 0, 12, 4, 13, 103, 101, 116, 45, 114, 97, 119, 45, 105, 110, 112, 117, 116, 0, 1, 13, 4, 18, 103, 101, 116, 45, 112, 114, 101, 118, 45, 114, 97, 119, 45, 105, 110, 112, 117, 116, 0, 1, 13, 4, 13, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 32, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 47, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 5, 3, 11, 37, 1, 13, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 18, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 3, 2, 0, 7, 241, 11, 1, 65, 7, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 3, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 2, 3, 0, 0, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 2, 3, 0, 0, 4, 113, 117, 97, 116, 2, 3, 0, 0, 4, 118, 101, 99, 51, 1, 66, 61, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 2, 4, 4, 113, 117, 97, 116, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 118, 101, 99, 51, 0, 3, 0, 4, 1, 114, 3, 4, 115, 105, 100, 101, 127, 2, 117, 112, 127, 4, 100, 111, 119, 110, 127, 4, 19, 99, 104, 97, 114, 97, 99, 116, 101, 114, 45, 99, 111, 108, 108, 105, 115, 105, 111, 110, 0, 3, 0, 6, 1, 109, 3, 7, 112, 104, 121, 115, 105, 99, 115, 12, 116, 114, 105, 103, 103, 101, 114, 45, 97, 114, 101, 97, 7, 112, 105, 99, 107, 105, 110, 103, 4, 14, 99, 111, 108, 108, 105, 100, 101, 114, 45, 115, 99, 101, 110, 101, 0, 3, 0, 8, 1, 114, 2, 6, 114, 97, 100, 105, 117, 115, 118, 11, 104, 97, 108, 102, 45, 104, 101, 105, 103, 104, 116, 118, 4, 13, 99, 97, 112, 115, 117, 108, 101, 45, 115, 104, 97, 112, 101, 0, 3, 0, 10, 1, 113, 3, 6, 115, 112, 104, 101, 114, 101, 1, 118, 0, 6, 99, 117, 98, 111, 105, 100, 1, 5, 0, 7, 99, 97, 112, 115, 117, 108, 101, 1, 11, 0, 4, 11, 113, 117, 101, 114, 121, 45, 115, 104, 97, 112, 101, 0, 3, 0, 12, 1, 114, 4, 6, 101, 110, 116, 105, 116, 121, 1, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 6, 110, 111, 114, 109, 97, 108, 5, 8, 100, 105, 115, 116, 97, 110, 99, 101, 118, 4, 9, 115, 119, 101, 101, 112, 45, 104, 105, 116, 0, 3, 0, 14, 1, 109, 5, 5, 102, 105, 120, 101, 100, 8, 114, 101, 118, 111, 108, 117, 116, 101, 9, 112, 114, 105, 115, 109, 97, 116, 105, 99, 9, 115, 112, 104, 101, 114, 105, 99, 97, 108, 8, 100, 105, 115, 116, 97, 110, 99, 101, 4, 10, 106, 111, 105, 110, 116, 45, 116, 121, 112, 101, 0, 3, 0, 16, 1, 114, 5, 5, 111, 116, 104, 101, 114, 1, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 6, 110, 111, 114, 109, 97, 108, 5, 10, 115, 101, 112, 97, 114, 97, 116, 105, 111, 110, 118, 7, 105, 109, 112, 117, 108, 115, 101, 5, 4, 7, 99, 111, 110, 116, 97, 99, 116, 0, 3, 0, 18, 1, 112, 1, 1, 64, 2, 8, 101, 110, 116, 105, 116, 105, 101, 115, 20, 5, 102, 111, 114, 99, 101, 5, 1, 0, 4, 11, 97, 112, 112, 108, 121, 45, 102, 111, 114, 99, 101, 0, 1, 21, 1, 107, 118, 1, 64, 4, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 5, 102, 111, 114, 99, 101, 118, 6, 114, 97, 100, 105, 117, 115, 118, 14, 102, 97, 108, 108, 111, 102, 102, 45, 114, 97, 100, 105, 117, 115, 22, 1, 0, 4, 12, 101, 120, 112, 108, 111, 100, 101, 45, 98, 111, 109, 98, 0, 1, 23, 1, 64, 1, 7, 103, 114, 97, 118, 105, 116, 121, 5, 1, 0, 4, 11, 115, 101, 116, 45, 103, 114, 97, 118, 105, 116, 121, 0, 1, 24, 1, 64, 1, 6, 101, 110, 116, 105, 116, 121, 1, 1, 0, 4, 8, 117, 110, 102, 114, 101, 101, 122, 101, 0, 1, 25, 4, 6, 102, 114, 101, 101, 122, 101, 0, 1, 25, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 8, 118, 101, 108, 111, 99, 105, 116, 121, 118, 1, 0, 4, 11, 115, 116, 97, 114, 116, 45, 109, 111, 116, 111, 114, 0, 1, 26, 4, 10, 115, 116, 111, 112, 45, 109, 111, 116, 111, 114, 0, 1, 25, 1, 107, 121, 1, 111, 2, 1, 118, 1, 107, 28, 1, 64, 3, 6, 111, 114, 105, 103, 105, 110, 5, 9, 100, 105, 114, 101, 99, 116, 105, 111, 110, 5, 6, 108, 97, 121, 101, 114, 115, 27, 0, 29, 4, 13, 114, 97, 121, 99, 97, 115, 116, 45, 102, 105, 114, 115, 116, 0, 1, 30, 1, 112, 28, 1, 64, 3, 6, 111, 114, 105, 103, 105, 110, 5, 9, 100, 105, 114, 101, 99, 116, 105, 111, 110, 5, 6, 108, 97, 121, 101, 114, 115, 27, 0, 31, 4, 7, 114, 97, 121, 99, 97, 115, 116, 0, 1, 32, 1, 64, 5, 6, 101, 110, 116, 105, 116, 121, 1, 12, 100, 105, 115, 112, 108, 97, 99, 101, 109, 101, 110, 116, 5, 8, 109, 105, 110, 45, 100, 105, 115, 116, 118, 12, 101, 108, 97, 112, 115, 101, 100, 45, 116, 105, 109, 101, 118, 6, 108, 97, 121, 101, 114, 115, 27, 0, 7, 4, 14, 109, 111, 118, 101, 45, 99, 104, 97, 114, 97, 99, 116, 101, 114, 0, 1, 33, 1, 107, 9, 1, 112, 15, 1, 64, 7, 5, 115, 104, 97, 112, 101, 13, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 8, 114, 111, 116, 97, 116, 105, 111, 110, 3, 9, 100, 105, 114, 101, 99, 116, 105, 111, 110, 5, 12, 109, 97, 120, 45, 100, 105, 115, 116, 97, 110, 99, 101, 118, 5, 115, 99, 101, 110, 101, 34, 6, 108, 97, 121, 101, 114, 115, 27, 0, 35, 4, 5, 115, 119, 101, 101, 112, 0, 1, 36, 1, 64, 5, 5, 115, 104, 97, 112, 101, 13, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 8, 114, 111, 116, 97, 116, 105, 111, 110, 3, 5, 115, 99, 101, 110, 101, 34, 6, 108, 97, 121, 101, 114, 115, 27, 0, 20, 4, 7, 111, 118, 101, 114, 108, 97, 112, 0, 1, 37, 1, 107, 1, 1, 64, 5, 10, 106, 111, 105, 110, 116, 45, 116, 121, 112, 101, 17, 7, 101, 110, 116, 105, 116, 121, 48, 1, 7, 101, 110, 116, 105, 116, 121, 49, 38, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 8, 114, 111, 116, 97, 116, 105, 111, 110, 3, 0, 1, 4, 12, 99, 114, 101, 97, 116, 101, 45, 106, 111, 105, 110, 116, 0, 1, 39, 1, 64, 1, 5, 106, 111, 105, 110, 116, 1, 1, 0, 4, 11, 98, 114, 101, 97, 107, 45, 106, 111, 105, 110, 116, 0, 1, 40, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 8, 118, 101, 108, 111, 99, 105, 116, 121, 5, 1, 0, 4, 19, 115, 101, 116, 45, 108, 105, 110, 101, 97, 114, 45, 118, 101, 108, 111, 99, 105, 116, 121, 0, 1, 41, 4, 20, 115, 101, 116, 45, 97, 110, 103, 117, 108, 97, 114, 45, 118, 101, 108, 111, 99, 105, 116, 121, 0, 1, 41, 1, 112, 19, 1, 64, 1, 6, 101, 110, 116, 105, 116, 121, 1, 0, 42, 4, 12, 103, 101, 116, 45, 99, 111, 110, 116, 97, 99, 116, 115, 0, 1, 43, 4, 14, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 34, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 47, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 5, 4, 11, 39, 1, 14, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 19, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 3, 4, 0, 7, 252, 2, 1, 65, 2, 1, 66, 12, 1, 109, 13, 11, 97, 115, 115, 101, 116, 45, 99, 114, 97, 116, 101, 6, 112, 114, 101, 102, 97, 98, 13, 115, 99, 114, 105, 112, 116, 45, 98, 117, 110, 100, 108, 101, 5, 109, 111, 100, 101, 108, 5, 105, 109, 97, 103, 101, 9, 97, 110, 105, 109, 97, 116, 105, 111, 110, 8, 109, 97, 116, 101, 114, 105, 97, 108, 8, 99, 111, 108, 108, 105, 100, 101, 114, 16, 116, 101, 114, 114, 97, 105, 110, 45, 109, 97, 116, 101, 114, 105, 97, 108, 10, 97, 116, 109, 111, 115, 112, 104, 101, 114, 101, 6, 98, 105, 111, 109, 101, 115, 12, 118, 111, 114, 98, 105, 115, 45, 116, 114, 97, 99, 107, 11, 115, 111, 117, 110, 100, 45, 103, 114, 97, 112, 104, 4, 10, 97, 115, 115, 101, 116, 45, 116, 121, 112, 101, 0, 3, 0, 0, 1, 112, 115, 1, 107, 115, 1, 114, 9, 2, 105, 100, 115, 10, 97, 115, 115, 101, 116, 45, 116, 121, 112, 101, 1, 4, 110, 97, 109, 101, 115, 4, 116, 97, 103, 115, 2, 10, 99, 97, 116, 101, 103, 111, 114, 105, 101, 115, 2, 3, 117, 114, 108, 3, 10, 99, 111, 108, 108, 101, 99, 116, 105, 111, 110, 2, 6, 115, 111, 117, 114, 99, 101, 3, 11, 112, 114, 101, 118, 105, 101, 119, 45, 117, 114, 108, 3, 4, 13, 99, 97, 116, 97, 108, 111, 103, 45, 97, 115, 115, 101, 116, 0, 3, 0, 4, 1, 64, 1, 4, 112, 97, 116, 104, 115, 0, 3, 4, 3, 117, 114, 108, 0, 1, 6, 1, 107, 1, 1, 112, 5, 1, 64, 3, 10, 97, 115, 115, 101, 116, 45, 116, 121, 112, 101, 7, 3, 116, 97, 103, 3, 8, 99, 97, 116, 101, 103, 111, 114, 121, 3, 0, 8, 4, 7, 99, 97, 116, 97, 108, 111, 103, 0, 1, 9, 4, 12, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 30, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 47, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 5, 0, 11, 35, 1, 12, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 17, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 3, 6, 0, 7, 247, 14, 1, 65, 13, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 3, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 2, 3, 0, 0, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 2, 3, 0, 0, 4, 109, 97, 116, 52, 2, 3, 0, 0, 4, 113, 117, 97, 116, 2, 3, 0, 0, 4, 118, 101, 99, 50, 2, 3, 0, 0, 4, 118, 101, 99, 51, 2, 3, 0, 0, 4, 118, 101, 99, 52, 2, 3, 0, 0, 5, 117, 118, 101, 99, 50, 2, 3, 0, 0, 5, 117, 118, 101, 99, 51, 2, 3, 0, 0, 5, 117, 118, 101, 99, 52, 1, 66, 92, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 2, 4, 4, 109, 97, 116, 52, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 4, 2, 3, 2, 1, 4, 4, 4, 118, 101, 99, 50, 0, 3, 0, 6, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 8, 2, 3, 2, 1, 6, 4, 4, 118, 101, 99, 52, 0, 3, 0, 10, 2, 3, 2, 1, 7, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 12, 2, 3, 2, 1, 8, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 14, 2, 3, 2, 1, 9, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 16, 1, 109, 3, 5, 102, 114, 97, 109, 101, 5, 115, 112, 97, 119, 110, 7, 100, 101, 115, 112, 97, 119, 110, 4, 11, 113, 117, 101, 114, 121, 45, 101, 118, 101, 110, 116, 0, 3, 0, 18, 1, 112, 121, 1, 114, 4, 10, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 20, 7, 105, 110, 99, 108, 117, 100, 101, 20, 7, 101, 120, 99, 108, 117, 100, 101, 20, 7, 99, 104, 97, 110, 103, 101, 100, 20, 4, 11, 113, 117, 101, 114, 121, 45, 98, 117, 105, 108, 100, 0, 3, 0, 21, 1, 111, 0, 1, 112, 23, 1, 112, 127, 1, 112, 1, 1, 112, 118, 1, 112, 117, 1, 112, 3, 1, 112, 122, 1, 112, 5, 1, 112, 115, 1, 112, 119, 1, 112, 7, 1, 112, 9, 1, 112, 11, 1, 112, 13, 1, 112, 15, 1, 112, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 24, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 25, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 26, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 27, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 28, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 29, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 30, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 31, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 32, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 20, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 33, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 34, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 35, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 36, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 37, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 38, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 39, 0, 4, 9, 118, 101, 99, 45, 118, 97, 108, 117, 101, 0, 3, 0, 40, 1, 107, 23, 1, 107, 127, 1, 107, 1, 1, 107, 118, 1, 107, 117, 1, 107, 3, 1, 107, 122, 1, 107, 5, 1, 107, 115, 1, 107, 121, 1, 107, 119, 1, 107, 7, 1, 107, 9, 1, 107, 11, 1, 107, 13, 1, 107, 15, 1, 107, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 42, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 43, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 44, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 45, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 46, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 47, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 48, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 49, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 50, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 51, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 52, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 53, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 54, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 55, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 56, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 57, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 58, 0, 4, 12, 111, 112, 116, 105, 111, 110, 45, 118, 97, 108, 117, 101, 0, 3, 0, 59, 1, 113, 19, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 23, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 127, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 1, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 118, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 117, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 3, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 122, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 5, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 115, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 121, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 119, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 7, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 9, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 11, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 13, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 15, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 17, 0, 8, 116, 121, 112, 101, 45, 118, 101, 99, 1, 41, 0, 11, 116, 121, 112, 101, 45, 111, 112, 116, 105, 111, 110, 1, 60, 0, 4, 5, 118, 97, 108, 117, 101, 0, 3, 0, 61, 1, 111, 2, 121, 62, 1, 112, 63, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 64, 1, 64, 1, 2, 105, 100, 115, 0, 51, 4, 9, 103, 101, 116, 45, 105, 110, 100, 101, 120, 0, 1, 66, 1, 107, 62, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 0, 195, 0, 4, 13, 103, 101, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 68, 1, 64, 3, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 5, 118, 97, 108, 117, 101, 62, 1, 0, 4, 13, 97, 100, 100, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 69, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 4, 100, 97, 116, 97, 193, 0, 1, 0, 4, 14, 97, 100, 100, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 70, 4, 13, 115, 101, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 69, 4, 14, 115, 101, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 70, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 0, 127, 4, 13, 104, 97, 115, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 71, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 7, 105, 110, 100, 105, 99, 101, 115, 20, 0, 127, 4, 14, 104, 97, 115, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 72, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 1, 0, 4, 16, 114, 101, 109, 111, 118, 101, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 73, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 7, 105, 110, 100, 105, 99, 101, 115, 20, 1, 0, 4, 17, 114, 101, 109, 111, 118, 101, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 74, 1, 64, 2, 1, 113, 22, 1, 116, 19, 0, 119, 4, 5, 113, 117, 101, 114, 121, 0, 1, 75, 1, 112, 62, 1, 111, 2, 1, 204, 0, 1, 112, 205, 0, 1, 64, 1, 1, 113, 119, 0, 206, 0, 4, 10, 113, 117, 101, 114, 121, 45, 101, 118, 97, 108, 0, 1, 79, 4, 9, 99, 111, 109, 112, 111, 110, 101, 110, 116, 24, 112, 107, 103, 58, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 5, 10, 11, 29, 1, 9, 99, 111, 109, 112, 111, 110, 101, 110, 116, 14, 112, 107, 103, 58, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 3, 8, 0, 7, 210, 12, 1, 65, 16, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 3, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 2, 3, 0, 0, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 2, 3, 0, 0, 4, 109, 97, 116, 52, 2, 3, 0, 0, 4, 113, 117, 97, 116, 2, 3, 0, 0, 4, 118, 101, 99, 50, 2, 3, 0, 0, 4, 118, 101, 99, 51, 2, 3, 0, 0, 4, 118, 101, 99, 52, 2, 3, 0, 0, 5, 117, 118, 101, 99, 50, 2, 3, 0, 0, 5, 117, 118, 101, 99, 51, 2, 3, 0, 0, 5, 117, 118, 101, 99, 52, 1, 66, 66, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 2, 4, 4, 109, 97, 116, 52, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 4, 2, 3, 2, 1, 4, 4, 4, 118, 101, 99, 50, 0, 3, 0, 6, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 8, 2, 3, 2, 1, 6, 4, 4, 118, 101, 99, 52, 0, 3, 0, 10, 2, 3, 2, 1, 7, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 12, 2, 3, 2, 1, 8, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 14, 2, 3, 2, 1, 9, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 16, 1, 109, 3, 5, 102, 114, 97, 109, 101, 5, 115, 112, 97, 119, 110, 7, 100, 101, 115, 112, 97, 119, 110, 4, 11, 113, 117, 101, 114, 121, 45, 101, 118, 101, 110, 116, 0, 3, 0, 18, 1, 112, 121, 1, 114, 4, 10, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 20, 7, 105, 110, 99, 108, 117, 100, 101, 20, 7, 101, 120, 99, 108, 117, 100, 101, 20, 7, 99, 104, 97, 110, 103, 101, 100, 20, 4, 11, 113, 117, 101, 114, 121, 45, 98, 117, 105, 108, 100, 0, 3, 0, 21, 1, 111, 0, 1, 112, 23, 1, 112, 127, 1, 112, 1, 1, 112, 118, 1, 112, 117, 1, 112, 3, 1, 112, 122, 1, 112, 5, 1, 112, 115, 1, 112, 119, 1, 112, 7, 1, 112, 9, 1, 112, 11, 1, 112, 13, 1, 112, 15, 1, 112, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 24, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 25, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 26, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 27, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 28, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 29, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 30, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 31, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 32, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 20, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 33, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 34, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 35, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 36, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 37, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 38, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 39, 0, 4, 9, 118, 101, 99, 45, 118, 97, 108, 117, 101, 0, 3, 0, 40, 1, 107, 23, 1, 107, 127, 1, 107, 1, 1, 107, 118, 1, 107, 117, 1, 107, 3, 1, 107, 122, 1, 107, 5, 1, 107, 115, 1, 107, 121, 1, 107, 119, 1, 107, 7, 1, 107, 9, 1, 107, 11, 1, 107, 13, 1, 107, 15, 1, 107, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 42, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 43, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 44, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 45, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 46, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 47, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 48, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 49, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 50, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 51, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 52, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 53, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 54, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 55, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 56, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 57, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 58, 0, 4, 12, 111, 112, 116, 105, 111, 110, 45, 118, 97, 108, 117, 101, 0, 3, 0, 59, 1, 113, 19, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 23, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 127, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 1, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 118, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 117, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 3, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 122, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 5, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 115, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 121, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 119, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 7, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 9, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 11, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 13, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 15, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 17, 0, 8, 116, 121, 112, 101, 45, 118, 101, 99, 1, 41, 0, 11, 116, 121, 112, 101, 45, 111, 112, 116, 105, 111, 110, 1, 60, 0, 4, 5, 118, 97, 108, 117, 101, 0, 3, 0, 61, 1, 111, 2, 121, 62, 1, 112, 63, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 64, 3, 9, 99, 111, 109, 112, 111, 110, 101, 110, 116, 24, 112, 107, 103, 58, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 5, 10, 2, 3, 0, 1, 6, 101, 110, 116, 105, 116, 121, 1, 66, 6, 2, 3, 2, 1, 11, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 0, 1, 64, 0, 1, 0, 4, 4, 105, 110, 105, 116, 0, 1, 2, 1, 64, 3, 4, 116, 105, 109, 101, 118, 10, 101, 118, 101, 110, 116, 45, 110, 97, 109, 101, 115, 10, 101, 118, 101, 110, 116, 45, 100, 97, 116, 97, 1, 1, 0, 4, 4, 101, 120, 101, 99, 0, 1, 3, 4, 5, 103, 117, 101, 115, 116, 16, 112, 107, 103, 58, 47, 103, 117, 101, 115, 116, 47, 103, 117, 101, 115, 116, 5, 12, 11, 21, 1, 5, 103, 117, 101, 115, 116, 10, 112, 107, 103, 58, 47, 103, 117, 101, 115, 116, 3, 10, 0, 7, 203, 12, 1, 65, 16, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 3, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 2, 3, 0, 0, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 2, 3, 0, 0, 4, 109, 97, 116, 52, 2, 3, 0, 0, 4, 113, 117, 97, 116, 2, 3, 0, 0, 4, 118, 101, 99, 50, 2, 3, 0, 0, 4, 118, 101, 99, 51, 2, 3, 0, 0, 4, 118, 101, 99, 52, 2, 3, 0, 0, 5, 117, 118, 101, 99, 50, 2, 3, 0, 0, 5, 117, 118, 101, 99, 51, 2, 3, 0, 0, 5, 117, 118, 101, 99, 52, 1, 66, 66, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 2, 4, 4, 109, 97, 116, 52, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 4, 2, 3, 2, 1, 4, 4, 4, 118, 101, 99, 50, 0, 3, 0, 6, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 8, 2, 3, 2, 1, 6, 4, 4, 118, 101, 99, 52, 0, 3, 0, 10, 2, 3, 2, 1, 7, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 12, 2, 3, 2, 1, 8, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 14, 2, 3, 2, 1, 9, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 16, 1, 109, 3, 5, 102, 114, 97, 109, 101, 5, 115, 112, 97, 119, 110, 7, 100, 101, 115, 112, 97, 119, 110, 4, 11, 113, 117, 101, 114, 121, 45, 101, 118, 101, 110, 116, 0, 3, 0, 18, 1, 112, 121, 1, 114, 4, 10, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 20, 7, 105, 110, 99, 108, 117, 100, 101, 20, 7, 101, 120, 99, 108, 117, 100, 101, 20, 7, 99, 104, 97, 110, 103, 101, 100, 20, 4, 11, 113, 117, 101, 114, 121, 45, 98, 117, 105, 108, 100, 0, 3, 0, 21, 1, 111, 0, 1, 112, 23, 1, 112, 127, 1, 112, 1, 1, 112, 118, 1, 112, 117, 1, 112, 3, 1, 112, 122, 1, 112, 5, 1, 112, 115, 1, 112, 119, 1, 112, 7, 1, 112, 9, 1, 112, 11, 1, 112, 13, 1, 112, 15, 1, 112, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 24, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 25, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 26, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 27, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 28, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 29, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 30, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 31, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 32, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 20, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 33, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 34, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 35, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 36, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 37, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 38, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 39, 0, 4, 9, 118, 101, 99, 45, 118, 97, 108, 117, 101, 0, 3, 0, 40, 1, 107, 23, 1, 107, 127, 1, 107, 1, 1, 107, 118, 1, 107, 117, 1, 107, 3, 1, 107, 122, 1, 107, 5, 1, 107, 115, 1, 107, 121, 1, 107, 119, 1, 107, 7, 1, 107, 9, 1, 107, 11, 1, 107, 13, 1, 107, 15, 1, 107, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 42, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 43, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 44, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 45, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 46, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 47, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 48, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 49, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 50, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 51, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 52, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 53, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 54, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 55, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 56, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 57, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 58, 0, 4, 12, 111, 112, 116, 105, 111, 110, 45, 118, 97, 108, 117, 101, 0, 3, 0, 59, 1, 113, 19, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 23, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 127, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 1, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 118, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 117, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 3, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 122, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 5, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 115, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 121, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 119, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 7, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 9, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 11, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 13, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 15, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 17, 0, 8, 116, 121, 112, 101, 45, 118, 101, 99, 1, 41, 0, 11, 116, 121, 112, 101, 45, 111, 112, 116, 105, 111, 110, 1, 60, 0, 4, 5, 118, 97, 108, 117, 101, 0, 3, 0, 61, 1, 111, 2, 121, 62, 1, 112, 63, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 64, 3, 9, 99, 111, 109, 112, 111, 110, 101, 110, 116, 24, 112, 107, 103, 58, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 5, 10, 2, 3, 0, 1, 6, 101, 110, 116, 105, 116, 121, 1, 66, 6, 2, 3, 2, 1, 11, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 0, 1, 64, 1, 4, 110, 97, 109, 101, 115, 1, 0, 4, 9, 115, 117, 98, 115, 99, 114, 105, 98, 101, 0, 1, 2, 1, 64, 2, 4, 110, 97, 109, 101, 115, 4, 100, 97, 116, 97, 1, 1, 0, 4, 4, 115, 101, 110, 100, 0, 1, 3, 4, 5, 101, 118, 101, 110, 116, 16, 112, 107, 103, 58, 47, 101, 118, 101, 110, 116, 47, 101, 118, 101, 110, 116, 5, 12, 11, 21, 1, 5, 101, 118, 101, 110, 116, 10, 112, 107, 103, 58, 47, 101, 118, 101, 110, 116, 3, 12, 0, 7, 189, 15, 1, 65, 16, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 3, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 2, 3, 0, 0, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 2, 3, 0, 0, 4, 109, 97, 116, 52, 2, 3, 0, 0, 4, 113, 117, 97, 116, 2, 3, 0, 0, 4, 118, 101, 99, 50, 2, 3, 0, 0, 4, 118, 101, 99, 51, 2, 3, 0, 0, 4, 118, 101, 99, 52, 2, 3, 0, 0, 5, 117, 118, 101, 99, 50, 2, 3, 0, 0, 5, 117, 118, 101, 99, 51, 2, 3, 0, 0, 5, 117, 118, 101, 99, 52, 1, 66, 66, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 2, 4, 4, 109, 97, 116, 52, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 4, 2, 3, 2, 1, 4, 4, 4, 118, 101, 99, 50, 0, 3, 0, 6, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 8, 2, 3, 2, 1, 6, 4, 4, 118, 101, 99, 52, 0, 3, 0, 10, 2, 3, 2, 1, 7, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 12, 2, 3, 2, 1, 8, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 14, 2, 3, 2, 1, 9, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 16, 1, 109, 3, 5, 102, 114, 97, 109, 101, 5, 115, 112, 97, 119, 110, 7, 100, 101, 115, 112, 97, 119, 110, 4, 11, 113, 117, 101, 114, 121, 45, 101, 118, 101, 110, 116, 0, 3, 0, 18, 1, 112, 121, 1, 114, 4, 10, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 20, 7, 105, 110, 99, 108, 117, 100, 101, 20, 7, 101, 120, 99, 108, 117, 100, 101, 20, 7, 99, 104, 97, 110, 103, 101, 100, 20, 4, 11, 113, 117, 101, 114, 121, 45, 98, 117, 105, 108, 100, 0, 3, 0, 21, 1, 111, 0, 1, 112, 23, 1, 112, 127, 1, 112, 1, 1, 112, 118, 1, 112, 117, 1, 112, 3, 1, 112, 122, 1, 112, 5, 1, 112, 115, 1, 112, 119, 1, 112, 7, 1, 112, 9, 1, 112, 11, 1, 112, 13, 1, 112, 15, 1, 112, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 24, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 25, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 26, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 27, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 28, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 29, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 30, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 31, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 32, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 20, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 33, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 34, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 35, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 36, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 37, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 38, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 39, 0, 4, 9, 118, 101, 99, 45, 118, 97, 108, 117, 101, 0, 3, 0, 40, 1, 107, 23, 1, 107, 127, 1, 107, 1, 1, 107, 118, 1, 107, 117, 1, 107, 3, 1, 107, 122, 1, 107, 5, 1, 107, 115, 1, 107, 121, 1, 107, 119, 1, 107, 7, 1, 107, 9, 1, 107, 11, 1, 107, 13, 1, 107, 15, 1, 107, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 42, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 43, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 44, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 45, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 46, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 47, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 48, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 49, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 50, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 51, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 52, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 53, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 54, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 55, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 56, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 57, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 58, 0, 4, 12, 111, 112, 116, 105, 111, 110, 45, 118, 97, 108, 117, 101, 0, 3, 0, 59, 1, 113, 19, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 23, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 127, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 1, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 118, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 117, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 3, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 122, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 5, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 115, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 121, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 119, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 7, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 9, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 11, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 13, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 15, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 17, 0, 8, 116, 121, 112, 101, 45, 118, 101, 99, 1, 41, 0, 11, 116, 121, 112, 101, 45, 111, 112, 116, 105, 111, 110, 1, 60, 0, 4, 5, 118, 97, 108, 117, 101, 0, 3, 0, 61, 1, 111, 2, 121, 62, 1, 112, 63, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 64, 3, 9, 99, 111, 109, 112, 111, 110, 101, 110, 116, 24, 112, 107, 103, 58, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 5, 10, 2, 3, 0, 1, 6, 101, 110, 116, 105, 116, 121, 1, 66, 29, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 4, 2, 3, 2, 1, 11, 4, 11, 101, 110, 116, 105, 116, 121, 45, 100, 97, 116, 97, 0, 3, 0, 6, 1, 114, 3, 8, 99, 108, 105, 112, 45, 117, 114, 108, 115, 7, 108, 111, 111, 112, 105, 110, 103, 127, 6, 119, 101, 105, 103, 104, 116, 118, 4, 16, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 97, 99, 116, 105, 111, 110, 0, 3, 0, 8, 1, 112, 9, 1, 114, 2, 7, 97, 99, 116, 105, 111, 110, 115, 10, 15, 97, 112, 112, 108, 121, 45, 98, 97, 115, 101, 45, 112, 111, 115, 101, 127, 4, 20, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 99, 111, 110, 116, 114, 111, 108, 108, 101, 114, 0, 3, 0, 11, 1, 64, 1, 4, 100, 97, 116, 97, 7, 0, 1, 4, 5, 115, 112, 97, 119, 110, 0, 1, 13, 1, 64, 1, 6, 101, 110, 116, 105, 116, 121, 1, 0, 127, 4, 7, 100, 101, 115, 112, 97, 119, 110, 0, 1, 14, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 20, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 99, 111, 110, 116, 114, 111, 108, 108, 101, 114, 12, 1, 0, 4, 24, 115, 101, 116, 45, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 99, 111, 110, 116, 114, 111, 108, 108, 101, 114, 0, 1, 15, 1, 112, 1, 1, 64, 2, 8, 112, 111, 115, 105, 116, 105, 111, 110, 3, 6, 114, 97, 100, 105, 117, 115, 118, 0, 16, 4, 7, 105, 110, 45, 97, 114, 101, 97, 0, 1, 17, 4, 6, 101, 120, 105, 115, 116, 115, 0, 1, 14, 1, 64, 1, 5, 105, 110, 100, 101, 120, 121, 0, 16, 4, 7, 103, 101, 116, 45, 97, 108, 108, 0, 1, 18, 1, 64, 0, 0, 1, 4, 9, 114, 101, 115, 111, 117, 114, 99, 101, 115, 0, 1, 19, 4, 22, 115, 121, 110, 99, 104, 114, 111, 110, 105, 122, 101, 100, 45, 114, 101, 115, 111, 117, 114, 99, 101, 115, 0, 1, 19, 4, 19, 112, 101, 114, 115, 105, 115, 116, 101, 100, 45, 114, 101, 115, 111, 117, 114, 99, 101, 115, 0, 1, 19, 4, 6, 101, 110, 116, 105, 116, 121, 18, 112, 107, 103, 58, 47, 101, 110, 116, 105, 116, 121, 47, 101, 110, 116, 105, 116, 121, 5, 12, 11, 23, 1, 6, 101, 110, 116, 105, 116, 121, 11, 112, 107, 103, 58, 47, 101, 110, 116, 105, 116, 121, 3, 14, 0, 7, 138, 4, 1, 65, 5, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 3, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 2, 3, 0, 0, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 66, 16, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 1, 106, 1, 119, 1, 115, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 3, 117, 114, 108, 115, 0, 2, 4, 4, 112, 108, 97, 121, 0, 1, 3, 1, 64, 2, 5, 115, 111, 117, 110, 100, 119, 8, 102, 97, 100, 101, 45, 111, 117, 116, 118, 1, 0, 4, 4, 115, 116, 111, 112, 0, 1, 4, 1, 64, 2, 5, 115, 111, 117, 110, 100, 119, 4, 103, 97, 105, 110, 118, 1, 0, 4, 8, 115, 101, 116, 45, 103, 97, 105, 110, 0, 1, 5, 1, 64, 2, 5, 115, 111, 117, 110, 100, 119, 5, 112, 105, 116, 99, 104, 118, 1, 0, 4, 9, 115, 101, 116, 45, 112, 105, 116, 99, 104, 0, 1, 6, 1, 107, 118, 1, 64, 2, 5, 115, 111, 117, 110, 100, 119, 6, 99, 117, 116, 111, 102, 102, 7, 1, 0, 4, 10, 115, 101, 116, 45, 99, 117, 116, 111, 102, 102, 0, 1, 8, 1, 64, 1, 5, 115, 111, 117, 110, 100, 119, 0, 127, 4, 10, 105, 115, 45, 112, 108, 97, 121, 105, 110, 103, 0, 1, 9, 4, 12, 99, 108, 105, 101, 110, 116, 45, 97, 117, 100, 105, 111, 30, 112, 107, 103, 58, 47, 99, 108, 105, 101, 110, 116, 45, 97, 117, 100, 105, 111, 47, 99, 108, 105, 101, 110, 116, 45, 97, 117, 100, 105, 111, 5, 2, 11, 35, 1, 12, 99, 108, 105, 101, 110, 116, 45, 97, 117, 100, 105, 111, 17, 112, 107, 103, 58, 47, 99, 108, 105, 101, 110, 116, 45, 97, 117, 100, 105, 111, 3, 16, 0, 7, 90, 1, 65, 2, 1, 66, 4, 1, 64, 1, 4, 110, 97, 109, 101, 115, 1, 0, 4, 11, 98, 101, 103, 105, 110, 45, 115, 99, 111, 112, 101, 0, 1, 0, 1, 64, 0, 1, 0, 4, 9, 101, 110, 100, 45, 115, 99, 111, 112, 101, 0, 1, 1, 4, 9, 112, 114, 111, 102, 105, 108, 105, 110, 103, 24, 112, 107, 103, 58, 47, 112, 114, 111, 102, 105, 108, 105, 110, 103, 47, 112, 114, 111, 102, 105, 108, 105, 110, 103, 5, 0, 11, 29, 1, 9, 112, 114, 111, 102, 105, 108, 105, 110, 103, 14, 112, 107, 103, 58, 47, 112, 114, 111, 102, 105, 108, 105, 110, 103, 3, 18, 0, 7, 210, 1, 1, 65, 2, 1, 66, 15, 1, 106, 1, 119, 1, 115, 1, 64, 1, 4, 112, 97, 116, 104, 115, 0, 0, 4, 10, 114, 101, 97, 100, 45, 97, 115, 115, 101, 116, 0, 1, 1, 4, 9, 114, 101, 97, 100, 45, 100, 97, 116, 97, 0, 1, 1, 1, 112, 125, 1, 64, 2, 4, 112, 97, 116, 104, 115, 4, 100, 97, 116, 97, 2, 0, 0, 4, 10, 119, 114, 105, 116, 101, 45, 100, 97, 116, 97, 0, 1, 3, 1, 64, 1, 3, 117, 114, 108, 115, 0, 0, 4, 8, 104, 116, 116, 112, 45, 103, 101, 116, 0, 1, 4, 1, 64, 2, 3, 117, 114, 108, 115, 4, 98, 111, 100, 121, 2, 0, 0, 4, 9, 104, 116, 116, 112, 45, 112, 111, 115, 116, 0, 1, 5, 1, 106, 1, 2, 1, 115, 1, 107, 6, 1, 64, 1, 7, 114, 101, 113, 117, 101, 115, 116, 119, 0, 7, 4, 4, 112, 111, 108, 108, 0, 1, 8, 4, 9, 115, 101, 114, 118, 101, 114, 45, 105, 111, 24, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 105, 111, 47, 115, 101, 114, 118, 101, 114, 45, 105, 111, 5, 0, 11, 29, 1, 9, 115, 101, 114, 118, 101, 114, 45, 105, 111, 14, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 105, 111, 3, 20, 0, 7, 230, 1, 1, 65, 2, 1, 66, 17, 1, 112, 125, 1, 107, 0, 1, 114, 2, 3, 107, 101, 121, 115, 5, 118, 97, 108, 117, 101, 1, 4, 11, 98, 97, 116, 99, 104, 45, 119, 114, 105, 116, 101, 0, 3, 0, 2, 1, 64, 1, 3, 107, 101, 121, 115, 0, 1, 4, 3, 103, 101, 116, 0, 1, 4, 1, 106, 0, 1, 115, 1, 64, 2, 3, 107, 101, 121, 115, 5, 118, 97, 108, 117, 101, 0, 0, 5, 4, 3, 115, 101, 116, 0, 1, 6, 1, 64, 1, 3, 107, 101, 121, 115, 0, 5, 4, 6, 100, 101, 108, 101, 116, 101, 0, 1, 7, 1, 112, 115, 1, 64, 1, 6, 112, 114, 101, 102, 105, 120, 115, 0, 8, 4, 11, 108, 105, 115, 116, 45, 112, 114, 101, 102, 105, 120, 0, 1, 9, 1, 112, 3, 1, 64, 1, 6, 119, 114, 105, 116, 101, 115, 10, 0, 5, 4, 11, 119, 114, 105, 116, 101, 45, 98, 97, 116, 99, 104, 0, 1, 11, 4, 14, 115, 101, 114, 118, 101, 114, 45, 115, 116, 111, 114, 97, 103, 101, 34, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 115, 116, 111, 114, 97, 103, 101, 47, 115, 101, 114, 118, 101, 114, 45, 115, 116, 111, 114, 97, 103, 101, 5, 0, 11, 39, 1, 14, 115, 101, 114, 118, 101, 114, 45, 115, 116, 111, 114, 97, 103, 101, 19, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 115, 116, 111, 114, 97, 103, 101, 3, 22, 0, 7, 221, 4, 1, 65, 6, 1, 66, 13, 1, 112, 115, 1, 64, 0, 0, 0, 4, 5, 102, 105, 108, 101, 115, 0, 1, 1, 1, 112, 125, 1, 106, 1, 2, 1, 115, 1, 64, 1, 4, 112, 97, 116, 104, 115, 0, 3, 4, 9, 114, 101, 97, 100, 45, 102, 105, 108, 101, 0, 1, 4, 1, 106, 0, 1, 115, 1, 64, 2, 4, 112, 97, 116, 104, 115, 8, 99, 111, 110, 116, 101, 110, 116, 115, 2, 0, 5, 4, 10, 119, 114, 105, 116, 101, 45, 102, 105, 108, 101, 0, 1, 6, 1, 107, 115, 1, 64, 5, 4, 112, 97, 116, 104, 115, 10, 97, 115, 115, 101, 116, 45, 116, 121, 112, 101, 115, 4, 110, 97, 109, 101, 115, 4, 116, 97, 103, 115, 0, 6, 115, 111, 117, 114, 99, 101, 7, 0, 5, 4, 9, 97, 100, 100, 45, 97, 115, 115, 101, 116, 0, 1, 8, 4, 13, 112, 105, 112, 101, 108, 105, 110, 101, 45, 104, 111, 115, 116, 27, 112, 107, 103, 58, 47, 112, 105, 112, 101, 108, 105, 110, 101, 47, 112, 105, 112, 101, 108, 105, 110, 101, 45, 104, 111, 115, 116, 5, 0, 1, 66, 3, 1, 106, 0, 1, 115, 1, 64, 1, 6, 99, 111, 110, 102, 105, 103, 115, 0, 0, 4, 7, 112, 114, 111, 99, 101, 115, 115, 0, 1, 1, 4, 14, 112, 105, 112, 101, 108, 105, 110, 101, 45, 103, 117, 101, 115, 116, 28, 112, 107, 103, 58, 47, 112, 105, 112, 101, 108, 105, 110, 101, 47, 112, 105, 112, 101, 108, 105, 110, 101, 45, 103, 117, 101, 115, 116, 5, 1, 1, 65, 4, 1, 66, 13, 1, 112, 115, 1, 64, 0, 0, 0, 4, 5, 102, 105, 108, 101, 115, 0, 1, 1, 1, 112, 125, 1, 106, 1, 2, 1, 115, 1, 64, 1, 4, 112, 97, 116, 104, 115, 0, 3, 4, 9, 114, 101, 97, 100, 45, 102, 105, 108, 101, 0, 1, 4, 1, 106, 0, 1, 115, 1, 64, 2, 4, 112, 97, 116, 104, 115, 8, 99, 111, 110, 116, 101, 110, 116, 115, 2, 0, 5, 4, 10, 119, 114, 105, 116, 101, 45, 102, 105, 108, 101, 0, 1, 6, 1, 107, 115, 1, 64, 5, 4, 112, 97, 116, 104, 115, 10, 97, 115, 115, 101, 116, 45, 116, 121, 112, 101, 115, 4, 110, 97, 109, 101, 115, 4, 116, 97, 103, 115, 0, 6, 115, 111, 117, 114, 99, 101, 7, 0, 5, 4, 9, 97, 100, 100, 45, 97, 115, 115, 101, 116, 0, 1, 8, 3, 13, 112, 105, 112, 101, 108, 105, 110, 101, 45, 104, 111, 115, 116, 27, 112, 107, 103, 58, 47, 112, 105, 112, 101, 108, 105, 110, 101, 47, 112, 105, 112, 101, 108, 105, 110, 101, 45, 104, 111, 115, 116, 5, 0, 1, 66, 3, 1, 106, 0, 1, 115, 1, 64, 1, 6, 99, 111, 110, 102, 105, 103, 115, 0, 0, 4, 7, 112, 114, 111, 99, 101, 115, 115, 0, 1, 1, 4, 14, 112, 105, 112, 101, 108, 105, 110, 101, 45, 103, 117, 101, 115, 116, 28, 112, 107, 103, 58, 47, 112, 105, 112, 101, 108, 105, 110, 101, 47, 112, 105, 112, 101, 108, 105, 110, 101, 45, 103, 117, 101, 115, 116, 5, 1, 4, 9, 112, 114, 111, 99, 101, 115, 115, 111, 114, 23, 112, 107, 103, 58, 47, 112, 105, 112, 101, 108, 105, 110, 101, 47, 112, 114, 111, 99, 101, 115, 115, 111, 114, 4, 2, 11, 27, 1, 8, 112, 105, 112, 101, 108, 105, 110, 101, 13, 112, 107, 103, 58, 47, 112, 105, 112, 101, 108, 105, 110, 101, 3, 24, 0, 7, 246, 49, 1, 65, 2, 1, 65, 34, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 3, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 2, 3, 0, 0, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 2, 3, 0, 0, 4, 109, 97, 116, 52, 2, 3, 0, 0, 4, 113, 117, 97, 116, 2, 3, 0, 0, 4, 118, 101, 99, 50, 2, 3, 0, 0, 4, 118, 101, 99, 51, 2, 3, 0, 0, 4, 118, 101, 99, 52, 2, 3, 0, 0, 5, 117, 118, 101, 99, 50, 2, 3, 0, 0, 5, 117, 118, 101, 99, 51, 2, 3, 0, 0, 5, 117, 118, 101, 99, 52, 1, 66, 92, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 2, 4, 4, 109, 97, 116, 52, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 4, 2, 3, 2, 1, 4, 4, 4, 118, 101, 99, 50, 0, 3, 0, 6, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 8, 2, 3, 2, 1, 6, 4, 4, 118, 101, 99, 52, 0, 3, 0, 10, 2, 3, 2, 1, 7, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 12, 2, 3, 2, 1, 8, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 14, 2, 3, 2, 1, 9, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 16, 1, 109, 3, 5, 102, 114, 97, 109, 101, 5, 115, 112, 97, 119, 110, 7, 100, 101, 115, 112, 97, 119, 110, 4, 11, 113, 117, 101, 114, 121, 45, 101, 118, 101, 110, 116, 0, 3, 0, 18, 1, 112, 121, 1, 114, 4, 10, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 20, 7, 105, 110, 99, 108, 117, 100, 101, 20, 7, 101, 120, 99, 108, 117, 100, 101, 20, 7, 99, 104, 97, 110, 103, 101, 100, 20, 4, 11, 113, 117, 101, 114, 121, 45, 98, 117, 105, 108, 100, 0, 3, 0, 21, 1, 111, 0, 1, 112, 23, 1, 112, 127, 1, 112, 1, 1, 112, 118, 1, 112, 117, 1, 112, 3, 1, 112, 122, 1, 112, 5, 1, 112, 115, 1, 112, 119, 1, 112, 7, 1, 112, 9, 1, 112, 11, 1, 112, 13, 1, 112, 15, 1, 112, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 24, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 25, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 26, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 27, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 28, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 29, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 30, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 31, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 32, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 20, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 33, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 34, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 35, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 36, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 37, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 38, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 39, 0, 4, 9, 118, 101, 99, 45, 118, 97, 108, 117, 101, 0, 3, 0, 40, 1, 107, 23, 1, 107, 127, 1, 107, 1, 1, 107, 118, 1, 107, 117, 1, 107, 3, 1, 107, 122, 1, 107, 5, 1, 107, 115, 1, 107, 121, 1, 107, 119, 1, 107, 7, 1, 107, 9, 1, 107, 11, 1, 107, 13, 1, 107, 15, 1, 107, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 42, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 43, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 44, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 45, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 46, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 47, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 48, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 49, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 50, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 51, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 52, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 53, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 54, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 55, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 56, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 57, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 58, 0, 4, 12, 111, 112, 116, 105, 111, 110, 45, 118, 97, 108, 117, 101, 0, 3, 0, 59, 1, 113, 19, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 23, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 127, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 1, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 118, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 117, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 3, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 122, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 5, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 115, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 121, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 119, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 7, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 9, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 11, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 13, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 15, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 17, 0, 8, 116, 121, 112, 101, 45, 118, 101, 99, 1, 41, 0, 11, 116, 121, 112, 101, 45, 111, 112, 116, 105, 111, 110, 1, 60, 0, 4, 5, 118, 97, 108, 117, 101, 0, 3, 0, 61, 1, 111, 2, 121, 62, 1, 112, 63, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 64, 1, 64, 1, 2, 105, 100, 115, 0, 51, 4, 9, 103, 101, 116, 45, 105, 110, 100, 101, 120, 0, 1, 66, 1, 107, 62, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 0, 195, 0, 4, 13, 103, 101, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 68, 1, 64, 3, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 5, 118, 97, 108, 117, 101, 62, 1, 0, 4, 13, 97, 100, 100, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 69, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 4, 100, 97, 116, 97, 193, 0, 1, 0, 4, 14, 97, 100, 100, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 70, 4, 13, 115, 101, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 69, 4, 14, 115, 101, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 70, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 0, 127, 4, 13, 104, 97, 115, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 71, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 7, 105, 110, 100, 105, 99, 101, 115, 20, 0, 127, 4, 14, 104, 97, 115, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 72, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 1, 0, 4, 16, 114, 101, 109, 111, 118, 101, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 73, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 7, 105, 110, 100, 105, 99, 101, 115, 20, 1, 0, 4, 17, 114, 101, 109, 111, 118, 101, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 74, 1, 64, 2, 1, 113, 22, 1, 116, 19, 0, 119, 4, 5, 113, 117, 101, 114, 121, 0, 1, 75, 1, 112, 62, 1, 111, 2, 1, 204, 0, 1, 112, 205, 0, 1, 64, 1, 1, 113, 119, 0, 206, 0, 4, 10, 113, 117, 101, 114, 121, 45, 101, 118, 97, 108, 0, 1, 79, 3, 9, 99, 111, 109, 112, 111, 110, 101, 110, 116, 24, 112, 107, 103, 58, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 5, 10, 2, 3, 0, 1, 6, 101, 110, 116, 105, 116, 121, 1, 66, 29, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 4, 2, 3, 2, 1, 11, 4, 11, 101, 110, 116, 105, 116, 121, 45, 100, 97, 116, 97, 0, 3, 0, 6, 1, 114, 3, 8, 99, 108, 105, 112, 45, 117, 114, 108, 115, 7, 108, 111, 111, 112, 105, 110, 103, 127, 6, 119, 101, 105, 103, 104, 116, 118, 4, 16, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 97, 99, 116, 105, 111, 110, 0, 3, 0, 8, 1, 112, 9, 1, 114, 2, 7, 97, 99, 116, 105, 111, 110, 115, 10, 15, 97, 112, 112, 108, 121, 45, 98, 97, 115, 101, 45, 112, 111, 115, 101, 127, 4, 20, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 99, 111, 110, 116, 114, 111, 108, 108, 101, 114, 0, 3, 0, 11, 1, 64, 1, 4, 100, 97, 116, 97, 7, 0, 1, 4, 5, 115, 112, 97, 119, 110, 0, 1, 13, 1, 64, 1, 6, 101, 110, 116, 105, 116, 121, 1, 0, 127, 4, 7, 100, 101, 115, 112, 97, 119, 110, 0, 1, 14, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 20, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 99, 111, 110, 116, 114, 111, 108, 108, 101, 114, 12, 1, 0, 4, 24, 115, 101, 116, 45, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 99, 111, 110, 116, 114, 111, 108, 108, 101, 114, 0, 1, 15, 1, 112, 1, 1, 64, 2, 8, 112, 111, 115, 105, 116, 105, 111, 110, 3, 6, 114, 97, 100, 105, 117, 115, 118, 0, 16, 4, 7, 105, 110, 45, 97, 114, 101, 97, 0, 1, 17, 4, 6, 101, 120, 105, 115, 116, 115, 0, 1, 14, 1, 64, 1, 5, 105, 110, 100, 101, 120, 121, 0, 16, 4, 7, 103, 101, 116, 45, 97, 108, 108, 0, 1, 18, 1, 64, 0, 0, 1, 4, 9, 114, 101, 115, 111, 117, 114, 99, 101, 115, 0, 1, 19, 4, 22, 115, 121, 110, 99, 104, 114, 111, 110, 105, 122, 101, 100, 45, 114, 101, 115, 111, 117, 114, 99, 101, 115, 0, 1, 19, 4, 19, 112, 101, 114, 115, 105, 115, 116, 101, 100, 45, 114, 101, 115, 111, 117, 114, 99, 101, 115, 0, 1, 19, 3, 6, 101, 110, 116, 105, 116, 121, 18, 112, 107, 103, 58, 47, 101, 110, 116, 105, 116, 121, 47, 101, 110, 116, 105, 116, 121, 5, 12, 1, 66, 6, 2, 3, 2, 1, 11, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 0, 1, 64, 1, 4, 110, 97, 109, 101, 115, 1, 0, 4, 9, 115, 117, 98, 115, 99, 114, 105, 98, 101, 0, 1, 2, 1, 64, 2, 4, 110, 97, 109, 101, 115, 4, 100, 97, 116, 97, 1, 1, 0, 4, 4, 115, 101, 110, 100, 0, 1, 3, 3, 5, 101, 118, 101, 110, 116, 16, 112, 107, 103, 58, 47, 101, 118, 101, 110, 116, 47, 101, 118, 101, 110, 116, 5, 13, 1, 66, 4, 1, 64, 1, 4, 110, 97, 109, 101, 115, 1, 0, 4, 11, 98, 101, 103, 105, 110, 45, 115, 99, 111, 112, 101, 0, 1, 0, 1, 64, 0, 1, 0, 4, 9, 101, 110, 100, 45, 115, 99, 111, 112, 101, 0, 1, 1, 3, 9, 112, 114, 111, 102, 105, 108, 105, 110, 103, 24, 112, 107, 103, 58, 47, 112, 114, 111, 102, 105, 108, 105, 110, 103, 47, 112, 114, 111, 102, 105, 108, 105, 110, 103, 5, 14, 1, 66, 16, 2, 3, 2, 1, 4, 4, 4, 118, 101, 99, 50, 0, 3, 0, 0, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 2, 1, 109, 163, 1, 4, 107, 101, 121, 49, 4, 107, 101, 121, 50, 4, 107, 101, 121, 51, 4, 107, 101, 121, 52, 4, 107, 101, 121, 53, 4, 107, 101, 121, 54, 4, 107, 101, 121, 55, 4, 107, 101, 121, 56, 4, 107, 101, 121, 57, 4, 107, 101, 121, 48, 1, 97, 1, 98, 1, 99, 1, 100, 1, 101, 1, 102, 1, 103, 1, 104, 1, 105, 1, 106, 1, 107, 1, 108, 1, 109, 1, 110, 1, 111, 1, 112, 1, 113, 1, 114, 1, 115, 1, 116, 1, 117, 1, 118, 1, 119, 1, 120, 1, 121, 1, 122, 6, 101, 115, 99, 97, 112, 101, 2, 102, 49, 2, 102, 50, 2, 102, 51, 2, 102, 52, 2, 102, 53, 2, 102, 54, 2, 102, 55, 2, 102, 56, 2, 102, 57, 3, 102, 49, 48, 3, 102, 49, 49, 3, 102, 49, 50, 3, 102, 49, 51, 3, 102, 49, 52, 3, 102, 49, 53, 3, 102, 49, 54, 3, 102, 49, 55, 3, 102, 49, 56, 3, 102, 49, 57, 3, 102, 50, 48, 3, 102, 50, 49, 3, 102, 50, 50, 3, 102, 50, 51, 3, 102, 50, 52, 8, 115, 110, 97, 112, 115, 104, 111, 116, 6, 115, 99, 114, 111, 108, 108, 5, 112, 97, 117, 115, 101, 6, 105, 110, 115, 101, 114, 116, 4, 104, 111, 109, 101, 6, 100, 101, 108, 101, 116, 101, 3, 101, 110, 100, 9, 112, 97, 103, 101, 45, 100, 111, 119, 110, 7, 112, 97, 103, 101, 45, 117, 112, 4, 108, 101, 102, 116, 2, 117, 112, 5, 114, 105, 103, 104, 116, 4, 100, 111, 119, 110, 4, 98, 97, 99, 107, 6, 114, 101, 116, 117, 114, 110, 5, 115, 112, 97, 99, 101, 7, 99, 111, 109, 112, 111, 115, 101, 5, 99, 97, 114, 101, 116, 7, 110, 117, 109, 108, 111, 99, 107, 7, 110, 117, 109, 112, 97, 100, 48, 7, 110, 117, 109, 112, 97, 100, 49, 7, 110, 117, 109, 112, 97, 100, 50, 7, 110, 117, 109, 112, 97, 100, 51, 7, 110, 117, 109, 112, 97, 100, 52, 7, 110, 117, 109, 112, 97, 100, 53, 7, 110, 117, 109, 112, 97, 100, 54, 7, 110, 117, 109, 112, 97, 100, 55, 7, 110, 117, 109, 112, 97, 100, 56, 7, 110, 117, 109, 112, 97, 100, 57, 10, 110, 117, 109, 112, 97, 100, 45, 97, 100, 100, 13, 110, 117, 109, 112, 97, 100, 45, 100, 105, 118, 105, 100, 101, 14, 110, 117, 109, 112, 97, 100, 45, 100, 101, 99, 105, 109, 97, 108, 12, 110, 117, 109, 112, 97, 100, 45, 99, 111, 109, 109, 97, 12, 110, 117, 109, 112, 97, 100, 45, 101, 110, 116, 101, 114, 13, 110, 117, 109, 112, 97, 100, 45, 101, 113, 117, 97, 108, 115, 15, 110, 117, 109, 112, 97, 100, 45, 109, 117, 108, 116, 105, 112, 108, 121, 15, 110, 117, 109, 112, 97, 100, 45, 115, 117, 98, 116, 114, 97, 99, 116, 7, 97, 98, 110, 116, 45, 99, 49, 7, 97, 98, 110, 116, 45, 99, 50, 10, 97, 112, 111, 115, 116, 114, 111, 112, 104, 101, 4, 97, 112, 112, 115, 8, 97, 115, 116, 101, 114, 105, 115, 107, 2, 97, 116, 2, 97, 120, 9, 98, 97, 99, 107, 115, 108, 97, 115, 104, 10, 99, 97, 108, 99, 117, 108, 97, 116, 111, 114, 7, 99, 97, 112, 105, 116, 97, 108, 5, 99, 111, 108, 111, 110, 5, 99, 111, 109, 109, 97, 7, 99, 111, 110, 118, 101, 114, 116, 6, 101, 113, 117, 97, 108, 115, 5, 103, 114, 97, 118, 101, 4, 107, 97, 110, 97, 5, 107, 97, 110, 106, 105, 5, 108, 45, 97, 108, 116, 9, 108, 45, 98, 114, 97, 99, 107, 101, 116, 9, 108, 45, 99, 111, 110, 116, 114, 111, 108, 7, 108, 45, 115, 104, 105, 102, 116, 5, 108, 45, 119, 105, 110, 4, 109, 97, 105, 108, 12, 109, 101, 100, 105, 97, 45, 115, 101, 108, 101, 99, 116, 10, 109, 101, 100, 105, 97, 45, 115, 116, 111, 112, 5, 109, 105, 110, 117, 115, 4, 109, 117, 116, 101, 11, 109, 121, 45, 99, 111, 109, 112, 117, 116, 101, 114, 16, 110, 97, 118, 105, 103, 97, 116, 101, 45, 102, 111, 114, 119, 97, 114, 100, 17, 110, 97, 118, 105, 103, 97, 116, 101, 45, 98, 97, 99, 107, 119, 97, 114, 100, 10, 110, 101, 120, 116, 45, 116, 114, 97, 99, 107, 10, 110, 111, 45, 99, 111, 110, 118, 101, 114, 116, 6, 111, 101, 109, 49, 48, 50, 6, 112, 101, 114, 105, 111, 100, 10, 112, 108, 97, 121, 45, 112, 97, 117, 115, 101, 4, 112, 108, 117, 115, 5, 112, 111, 119, 101, 114, 10, 112, 114, 101, 118, 45, 116, 114, 97, 99, 107, 5, 114, 45, 97, 108, 116, 9, 114, 45, 98, 114, 97, 99, 107, 101, 116, 9, 114, 45, 99, 111, 110, 116, 114, 111, 108, 7, 114, 45, 115, 104, 105, 102, 116, 5, 114, 45, 119, 105, 110, 9, 115, 101, 109, 105, 99, 111, 108, 111, 110, 5, 115, 108, 97, 115, 104, 5, 115, 108, 101, 101, 112, 4, 115, 116, 111, 112, 5, 115, 121, 115, 114, 113, 3, 116, 97, 98, 9, 117, 110, 100, 101, 114, 108, 105, 110, 101, 9, 117, 110, 108, 97, 98, 101, 108, 101, 100, 11, 118, 111, 108, 117, 109, 101, 45, 100, 111, 119, 110, 9, 118, 111, 108, 117, 109, 101, 45, 117, 112, 4, 119, 97, 107, 101, 8, 119, 101, 98, 45, 98, 97, 99, 107, 13, 119, 101, 98, 45, 102, 97, 118, 111, 114, 105, 116, 101, 115, 11, 119, 101, 98, 45, 102, 111, 114, 119, 97, 114, 100, 8, 119, 101, 98, 45, 104, 111, 109, 101, 11, 119, 101, 98, 45, 114, 101, 102, 114, 101, 115, 104, 10, 119, 101, 98, 45, 115, 101, 97, 114, 99, 104, 8, 119, 101, 98, 45, 115, 116, 111, 112, 3, 121, 101, 110, 4, 99, 111, 112, 121, 5, 112, 97, 115, 116, 101, 3, 99, 117, 116, 4, 16, 118, 105, 114, 116, 117, 97, 108, 45, 107, 101, 121, 45, 99, 111, 100, 101, 0, 3, 0, 4, 1, 113, 4, 4, 108, 101, 102, 116, 0, 0, 5, 114, 105, 103, 104, 116, 0, 0, 6, 109, 105, 100, 100, 108, 101, 0, 0, 5, 111, 116, 104, 101, 114, 1, 123, 0, 4, 12, 109, 111, 117, 115, 101, 45, 98, 117, 116, 116, 111, 110, 0, 3, 0, 6, 1, 112, 5, 1, 112, 7, 1, 114, 5, 4, 107, 101, 121, 115, 8, 14, 109, 111, 117, 115, 101, 45, 112, 111, 115, 105, 116, 105, 111, 110, 1, 15, 99, 117, 114, 115, 111, 114, 45, 112, 111, 115, 105, 116, 105, 111, 110, 1, 11, 109, 111, 117, 115, 101, 45, 119, 104, 101, 101, 108, 118, 13, 109, 111, 117, 115, 101, 45, 98, 117, 116, 116, 111, 110, 115, 9, 4, 9, 114, 97, 119, 45, 105, 110, 112, 117, 116, 0, 3, 0, 10, 1, 107, 11, 1, 64, 1, 6, 112, 108, 97, 121, 101, 114, 3, 0, 12, 4, 13, 103, 101, 116, 45, 114, 97, 119, 45, 105, 110, 112, 117, 116, 0, 1, 13, 4, 18, 103, 101, 116, 45, 112, 114, 101, 118, 45, 114, 97, 119, 45, 105, 110, 112, 117, 116, 0, 1, 13, 3, 13, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 32, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 47, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 5, 15, 1, 66, 61, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 2, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 4, 1, 114, 3, 4, 115, 105, 100, 101, 127, 2, 117, 112, 127, 4, 100, 111, 119, 110, 127, 4, 19, 99, 104, 97, 114, 97, 99, 116, 101, 114, 45, 99, 111, 108, 108, 105, 115, 105, 111, 110, 0, 3, 0, 6, 1, 109, 3, 7, 112, 104, 121, 115, 105, 99, 115, 12, 116, 114, 105, 103, 103, 101, 114, 45, 97, 114, 101, 97, 7, 112, 105, 99, 107, 105, 110, 103, 4, 14, 99, 111, 108, 108, 105, 100, 101, 114, 45, 115, 99, 101, 110, 101, 0, 3, 0, 8, 1, 114, 2, 6, 114, 97, 100, 105, 117, 115, 118, 11, 104, 97, 108, 102, 45, 104, 101, 105, 103, 104, 116, 118, 4, 13, 99, 97, 112, 115, 117, 108, 101, 45, 115, 104, 97, 112, 101, 0, 3, 0, 10, 1, 113, 3, 6, 115, 112, 104, 101, 114, 101, 1, 118, 0, 6, 99, 117, 98, 111, 105, 100, 1, 5, 0, 7, 99, 97, 112, 115, 117, 108, 101, 1, 11, 0, 4, 11, 113, 117, 101, 114, 121, 45, 115, 104, 97, 112, 101, 0, 3, 0, 12, 1, 114, 4, 6, 101, 110, 116, 105, 116, 121, 1, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 6, 110, 111, 114, 109, 97, 108, 5, 8, 100, 105, 115, 116, 97, 110, 99, 101, 118, 4, 9, 115, 119, 101, 101, 112, 45, 104, 105, 116, 0, 3, 0, 14, 1, 109, 5, 5, 102, 105, 120, 101, 100, 8, 114, 101, 118, 111, 108, 117, 116, 101, 9, 112, 114, 105, 115, 109, 97, 116, 105, 99, 9, 115, 112, 104, 101, 114, 105, 99, 97, 108, 8, 100, 105, 115, 116, 97, 110, 99, 101, 4, 10, 106, 111, 105, 110, 116, 45, 116, 121, 112, 101, 0, 3, 0, 16, 1, 114, 5, 5, 111, 116, 104, 101, 114, 1, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 6, 110, 111, 114, 109, 97, 108, 5, 10, 115, 101, 112, 97, 114, 97, 116, 105, 111, 110, 118, 7, 105, 109, 112, 117, 108, 115, 101, 5, 4, 7, 99, 111, 110, 116, 97, 99, 116, 0, 3, 0, 18, 1, 112, 1, 1, 64, 2, 8, 101, 110, 116, 105, 116, 105, 101, 115, 20, 5, 102, 111, 114, 99, 101, 5, 1, 0, 4, 11, 97, 112, 112, 108, 121, 45, 102, 111, 114, 99, 101, 0, 1, 21, 1, 107, 118, 1, 64, 4, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 5, 102, 111, 114, 99, 101, 118, 6, 114, 97, 100, 105, 117, 115, 118, 14, 102, 97, 108, 108, 111, 102, 102, 45, 114, 97, 100, 105, 117, 115, 22, 1, 0, 4, 12, 101, 120, 112, 108, 111, 100, 101, 45, 98, 111, 109, 98, 0, 1, 23, 1, 64, 1, 7, 103, 114, 97, 118, 105, 116, 121, 5, 1, 0, 4, 11, 115, 101, 116, 45, 103, 114, 97, 118, 105, 116, 121, 0, 1, 24, 1, 64, 1, 6, 101, 110, 116, 105, 116, 121, 1, 1, 0, 4, 8, 117, 110, 102, 114, 101, 101, 122, 101, 0, 1, 25, 4, 6, 102, 114, 101, 101, 122, 101, 0, 1, 25, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 8, 118, 101, 108, 111, 99, 105, 116, 121, 118, 1, 0, 4, 11, 115, 116, 97, 114, 116, 45, 109, 111, 116, 111, 114, 0, 1, 26, 4, 10, 115, 116, 111, 112, 45, 109, 111, 116, 111, 114, 0, 1, 25, 1, 107, 121, 1, 111, 2, 1, 118, 1, 107, 28, 1, 64, 3, 6, 111, 114, 105, 103, 105, 110, 5, 9, 100, 105, 114, 101, 99, 116, 105, 111, 110, 5, 6, 108, 97, 121, 101, 114, 115, 27, 0, 29, 4, 13, 114, 97, 121, 99, 97, 115, 116, 45, 102, 105, 114, 115, 116, 0, 1, 30, 1, 112, 28, 1, 64, 3, 6, 111, 114, 105, 103, 105, 110, 5, 9, 100, 105, 114, 101, 99, 116, 105, 111, 110, 5, 6, 108, 97, 121, 101, 114, 115, 27, 0, 31, 4, 7, 114, 97, 121, 99, 97, 115, 116, 0, 1, 32, 1, 64, 5, 6, 101, 110, 116, 105, 116, 121, 1, 12, 100, 105, 115, 112, 108, 97, 99, 101, 109, 101, 110, 116, 5, 8, 109, 105, 110, 45, 100, 105, 115, 116, 118, 12, 101, 108, 97, 112, 115, 101, 100, 45, 116, 105, 109, 101, 118, 6, 108, 97, 121, 101, 114, 115, 27, 0, 7, 4, 14, 109, 111, 118, 101, 45, 99, 104, 97, 114, 97, 99, 116, 101, 114, 0, 1, 33, 1, 107, 9, 1, 112, 15, 1, 64, 7, 5, 115, 104, 97, 112, 101, 13, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 8, 114, 111, 116, 97, 116, 105, 111, 110, 3, 9, 100, 105, 114, 101, 99, 116, 105, 111, 110, 5, 12, 109, 97, 120, 45, 100, 105, 115, 116, 97, 110, 99, 101, 118, 5, 115, 99, 101, 110, 101, 34, 6, 108, 97, 121, 101, 114, 115, 27, 0, 35, 4, 5, 115, 119, 101, 101, 112, 0, 1, 36, 1, 64, 5, 5, 115, 104, 97, 112, 101, 13, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 8, 114, 111, 116, 97, 116, 105, 111, 110, 3, 5, 115, 99, 101, 110, 101, 34, 6, 108, 97, 121, 101, 114, 115, 27, 0, 20, 4, 7, 111, 118, 101, 114, 108, 97, 112, 0, 1, 37, 1, 107, 1, 1, 64, 5, 10, 106, 111, 105, 110, 116, 45, 116, 121, 112, 101, 17, 7, 101, 110, 116, 105, 116, 121, 48, 1, 7, 101, 110, 116, 105, 116, 121, 49, 38, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 8, 114, 111, 116, 97, 116, 105, 111, 110, 3, 0, 1, 4, 12, 99, 114, 101, 97, 116, 101, 45, 106, 111, 105, 110, 116, 0, 1, 39, 1, 64, 1, 5, 106, 111, 105, 110, 116, 1, 1, 0, 4, 11, 98, 114, 101, 97, 107, 45, 106, 111, 105, 110, 116, 0, 1, 40, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 8, 118, 101, 108, 111, 99, 105, 116, 121, 5, 1, 0, 4, 19, 115, 101, 116, 45, 108, 105, 110, 101, 97, 114, 45, 118, 101, 108, 111, 99, 105, 116, 121, 0, 1, 41, 4, 20, 115, 101, 116, 45, 97, 110, 103, 117, 108, 97, 114, 45, 118, 101, 108, 111, 99, 105, 116, 121, 0, 1, 41, 1, 112, 19, 1, 64, 1, 6, 101, 110, 116, 105, 116, 121, 1, 0, 42, 4, 12, 103, 101, 116, 45, 99, 111, 110, 116, 97, 99, 116, 115, 0, 1, 43, 3, 14, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 34, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 47, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 5, 16, 1, 66, 12, 1, 109, 13, 11, 97, 115, 115, 101, 116, 45, 99, 114, 97, 116, 101, 6, 112, 114, 101, 102, 97, 98, 13, 115, 99, 114, 105, 112, 116, 45, 98, 117, 110, 100, 108, 101, 5, 109, 111, 100, 101, 108, 5, 105, 109, 97, 103, 101, 9, 97, 110, 105, 109, 97, 116, 105, 111, 110, 8, 109, 97, 116, 101, 114, 105, 97, 108, 8, 99, 111, 108, 108, 105, 100, 101, 114, 16, 116, 101, 114, 114, 97, 105, 110, 45, 109, 97, 116, 101, 114, 105, 97, 108, 10, 97, 116, 109, 111, 115, 112, 104, 101, 114, 101, 6, 98, 105, 111, 109, 101, 115, 12, 118, 111, 114, 98, 105, 115, 45, 116, 114, 97, 99, 107, 11, 115, 111, 117, 110, 100, 45, 103, 114, 97, 112, 104, 4, 10, 97, 115, 115, 101, 116, 45, 116, 121, 112, 101, 0, 3, 0, 0, 1, 112, 115, 1, 107, 115, 1, 114, 9, 2, 105, 100, 115, 10, 97, 115, 115, 101, 116, 45, 116, 121, 112, 101, 1, 4, 110, 97, 109, 101, 115, 4, 116, 97, 103, 115, 2, 10, 99, 97, 116, 101, 103, 111, 114, 105, 101, 115, 2, 3, 117, 114, 108, 3, 10, 99, 111, 108, 108, 101, 99, 116, 105, 111, 110, 2, 6, 115, 111, 117, 114, 99, 101, 3, 11, 112, 114, 101, 118, 105, 101, 119, 45, 117, 114, 108, 3, 4, 13, 99, 97, 116, 97, 108, 111, 103, 45, 97, 115, 115, 101, 116, 0, 3, 0, 4, 1, 64, 1, 4, 112, 97, 116, 104, 115, 0, 3, 4, 3, 117, 114, 108, 0, 1, 6, 1, 107, 1, 1, 112, 5, 1, 64, 3, 10, 97, 115, 115, 101, 116, 45, 116, 121, 112, 101, 7, 3, 116, 97, 103, 3, 8, 99, 97, 116, 101, 103, 111, 114, 121, 3, 0, 8, 4, 7, 99, 97, 116, 97, 108, 111, 103, 0, 1, 9, 3, 12, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 30, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 47, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 5, 17, 1, 66, 15, 1, 106, 1, 119, 1, 115, 1, 64, 1, 4, 112, 97, 116, 104, 115, 0, 0, 4, 10, 114, 101, 97, 100, 45, 97, 115, 115, 101, 116, 0, 1, 1, 4, 9, 114, 101, 97, 100, 45, 100, 97, 116, 97, 0, 1, 1, 1, 112, 125, 1, 64, 2, 4, 112, 97, 116, 104, 115, 4, 100, 97, 116, 97, 2, 0, 0, 4, 10, 119, 114, 105, 116, 101, 45, 100, 97, 116, 97, 0, 1, 3, 1, 64, 1, 3, 117, 114, 108, 115, 0, 0, 4, 8, 104, 116, 116, 112, 45, 103, 101, 116, 0, 1, 4, 1, 64, 2, 3, 117, 114, 108, 115, 4, 98, 111, 100, 121, 2, 0, 0, 4, 9, 104, 116, 116, 112, 45, 112, 111, 115, 116, 0, 1, 5, 1, 106, 1, 2, 1, 115, 1, 107, 6, 1, 64, 1, 7, 114, 101, 113, 117, 101, 115, 116, 119, 0, 7, 4, 4, 112, 111, 108, 108, 0, 1, 8, 3, 9, 115, 101, 114, 118, 101, 114, 45, 105, 111, 24, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 105, 111, 47, 115, 101, 114, 118, 101, 114, 45, 105, 111, 5, 18, 1, 66, 17, 1, 112, 125, 1, 107, 0, 1, 114, 2, 3, 107, 101, 121, 115, 5, 118, 97, 108, 117, 101, 1, 4, 11, 98, 97, 116, 99, 104, 45, 119, 114, 105, 116, 101, 0, 3, 0, 2, 1, 64, 1, 3, 107, 101, 121, 115, 0, 1, 4, 3, 103, 101, 116, 0, 1, 4, 1, 106, 0, 1, 115, 1, 64, 2, 3, 107, 101, 121, 115, 5, 118, 97, 108, 117, 101, 0, 0, 5, 4, 3, 115, 101, 116, 0, 1, 6, 1, 64, 1, 3, 107, 101, 121, 115, 0, 5, 4, 6, 100, 101, 108, 101, 116, 101, 0, 1, 7, 1, 112, 115, 1, 64, 1, 6, 112, 114, 101, 102, 105, 120, 115, 0, 8, 4, 11, 108, 105, 115, 116, 45, 112, 114, 101, 102, 105, 120, 0, 1, 9, 1, 112, 3, 1, 64, 1, 6, 119, 114, 105, 116, 101, 115, 10, 0, 5, 4, 11, 119, 114, 105, 116, 101, 45, 98, 97, 116, 99, 104, 0, 1, 11, 3, 14, 115, 101, 114, 118, 101, 114, 45, 115, 116, 111, 114, 97, 103, 101, 34, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 115, 116, 111, 114, 97, 103, 101, 47, 115, 101, 114, 118, 101, 114, 45, 115, 116, 111, 114, 97, 103, 101, 5, 19, 1, 66, 16, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 1, 106, 1, 119, 1, 115, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 3, 117, 114, 108, 115, 0, 2, 4, 4, 112, 108, 97, 121, 0, 1, 3, 1, 64, 2, 5, 115, 111, 117, 110, 100, 119, 8, 102, 97, 100, 101, 45, 111, 117, 116, 118, 1, 0, 4, 4, 115, 116, 111, 112, 0, 1, 4, 1, 64, 2, 5, 115, 111, 117, 110, 100, 119, 4, 103, 97, 105, 110, 118, 1, 0, 4, 8, 115, 101, 116, 45, 103, 97, 105, 110, 0, 1, 5, 1, 64, 2, 5, 115, 111, 117, 110, 100, 119, 5, 112, 105, 116, 99, 104, 118, 1, 0, 4, 9, 115, 101, 116, 45, 112, 105, 116, 99, 104, 0, 1, 6, 1, 107, 118, 1, 64, 2, 5, 115, 111, 117, 110, 100, 119, 6, 99, 117, 116, 111, 102, 102, 7, 1, 0, 4, 10, 115, 101, 116, 45, 99, 117, 116, 111, 102, 102, 0, 1, 8, 1, 64, 1, 5, 115, 111, 117, 110, 100, 119, 0, 127, 4, 10, 105, 115, 45, 112, 108, 97, 121, 105, 110, 103, 0, 1, 9, 3, 12, 99, 108, 105, 101, 110, 116, 45, 97, 117, 100, 105, 111, 30, 112, 107, 103, 58, 47, 99, 108, 105, 101, 110, 116, 45, 97, 117, 100, 105, 111, 47, 99, 108, 105, 101, 110, 116, 45, 97, 117, 100, 105, 111, 5, 20, 1, 66, 6, 2, 3, 2, 1, 11, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 0, 1, 64, 0, 1, 0, 4, 4, 105, 110, 105, 116, 0, 1, 2, 1, 64, 3, 4, 116, 105, 109, 101, 118, 10, 101, 118, 101, 110, 116, 45, 110, 97, 109, 101, 115, 10, 101, 118, 101, 110, 116, 45, 100, 97, 116, 97, 1, 1, 0, 4, 4, 101, 120, 101, 99, 0, 1, 3, 4, 5, 103, 117, 101, 115, 116, 16, 112, 107, 103, 58, 47, 103, 117, 101, 115, 116, 47, 103, 117, 101, 115, 116, 5, 21, 4, 8, 98, 105, 110, 100, 105, 110, 103, 115, 18, 112, 107, 103, 58, 47, 109, 97, 105, 110, 47, 98, 105, 110, 100, 105, 110, 103, 115, 4, 0, 0, 68, 9, 112, 114, 111, 100, 117, 99, 101, 114, 115, 1, 12, 112, 114, 111, 99, 101, 115, 115, 101, 100, 45, 98, 121, 2, 13, 119, 105, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 5, 48, 46, 55, 46, 51, 16, 119, 105, 116, 45, 98, 105, 110, 100, 103, 101, 110, 45, 114, 117, 115, 116, 5, 48, 46, 52, 46, 48, 11, 19, 1, 4, 109, 97, 105, 110, 9, 112, 107, 103, 58, 47, 109, 97, 105, 110, 3, 26, 0];
+                                    
+                                    #[inline(never)]
                                     #[doc(hidden)]
                                     #[cfg(target_arch = "wasm32")]
-                                    fn __force_section_ref() {
-                                      __link_section()
-                                    }
-                                  });
-                                  
-                                  #[cfg(target_arch = "wasm32")]
-                                  #[link_section = "component-type:bindings"]
-                                  #[doc(hidden)]pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 14826] = [2, 0, 3, 119, 105, 116, 4, 109, 97, 105, 110, 8, 98, 105, 110, 100, 105, 110, 103, 115, 0, 97, 115, 109, 12, 0, 1, 0, 7, 245, 1, 1, 65, 2, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 4, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 11, 21, 1, 5, 116, 121, 112, 101, 115, 10, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 3, 0, 0, 7, 221, 12, 1, 65, 6, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 3, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 2, 3, 0, 0, 4, 118, 101, 99, 50, 2, 3, 0, 0, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 66, 16, 2, 3, 2, 1, 1, 4, 4, 118, 101, 99, 50, 0, 3, 0, 0, 2, 3, 2, 1, 2, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 2, 1, 109, 163, 1, 4, 107, 101, 121, 49, 4, 107, 101, 121, 50, 4, 107, 101, 121, 51, 4, 107, 101, 121, 52, 4, 107, 101, 121, 53, 4, 107, 101, 121, 54, 4, 107, 101, 121, 55, 4, 107, 101, 121, 56, 4, 107, 101, 121, 57, 4, 107, 101, 121, 48, 1, 97, 1, 98, 1, 99, 1, 100, 1, 101, 1, 102, 1, 103, 1, 104, 1, 105, 1, 106, 1, 107, 1, 108, 1, 109, 1, 110, 1, 111, 1, 112, 1, 113, 1, 114, 1, 115, 1, 116, 1, 117, 1, 118, 1, 119, 1, 120, 1, 121, 1, 122, 6, 101, 115, 99, 97, 112, 101, 2, 102, 49, 2, 102, 50, 2, 102, 51, 2, 102, 52, 2, 102, 53, 2, 102, 54, 2, 102, 55, 2, 102, 56, 2, 102, 57, 3, 102, 49, 48, 3, 102, 49, 49, 3, 102, 49, 50, 3, 102, 49, 51, 3, 102, 49, 52, 3, 102, 49, 53, 3, 102, 49, 54, 3, 102, 49, 55, 3, 102, 49, 56, 3, 102, 49, 57, 3, 102, 50, 48, 3, 102, 50, 49, 3, 102, 50, 50, 3, 102, 50, 51, 3, 102, 50, 52, 8, 115, 110, 97, 112, 115, 104, 111, 116, 6, 115, 99, 114, 111, 108, 108, 5, 112, 97, 117, 115, 101, 6, 105, 110, 115, 101, 114, 116, 4, 104, 111, 109, 101, 6, 100, 101, 108, 101, 116, 101, 3, 101, 110, 100, 9, 112, 97, 103, 101, 45, 100, 111, 119, 110, 7, 112, 97, 103, 101, 45, 117, 112, 4, 108, 101, 102, 116, 2, 117, 112, 5, 114, 105, 103, 104, 116, 4, 100, 111, 119, 110, 4, 98, 97, 99, 107, 6, 114, 101, 116, 117, 114, 110, 5, 115, 112, 97, 99, 101, 7, 99, 111, 109, 112, 111, 115, 101, 5, 99, 97, 114, 101, 116, 7, 110, 117, 109, 108, 111, 99, 107, 7, 110, 117, 109, 112, 97, 100, 48, 7, 110, 117, 109, 112, 97, 100, 49, 7, 110, 117, 109, 112, 97, 100, 50, 7, 110, 117, 109, 112, 97, 100, 51, 7, 110, 117, 109, 112, 97, 100, 52, 7, 110, 117, 109, 112, 97, 100, 53, 7, 110, 117, 109, 112, 97, 100, 54, 7, 110, 117, 109, 112, 97, 100, 55, 7, 110, 117, 109, 112, 97, 100, 56, 7, 110, 117, 109, 112, 97, 100, 57, 10, 110, 117, 109, 112, 97, 100, 45, 97, 100, 100, 13, 110, 117, 109, 112, 97, 100, 45, 100, 105, 118, 105, 100, 101, 14, 110, 117, 109, 112, 97, 100, 45, 100, 101, 99, 105, 109, 97, 108, 12, 110, 117, 109, 112, 97, 100, 45, 99, 111, 109, 109, 97, 12, 110, 117, 109, 112, 97, 100, 45, 101, 110, 116, 101, 114, 13, 110, 117, 109, 112, 97, 100, 45, 101, 113, 117, 97, 108, 115, 15, 110, 117, 109, 112, 97, 100, 45, 109, 117, 108, 116, 105, 112, 108, 121, 15, 110, 117, 109, 112, 97, 100, 45, 115, 117, 98, 116, 114, 97, 99, 116, 7, 97, 98, 110, 116, 45, 99, 49, 7, 97, 98, 110, 116, 45, 99, 50, 10, 97, 112, 111, 115, 116, 114, 111, 112, 104, 101, 4, 97, 112, 112, 115, 8, 97, 115, 116, 101, 114, 105, 115, 107, 2, 97, 116, 2, 97, 120, 9, 98, 97, 99, 107, 115, 108, 97, 115, 104, 10, 99, 97, 108, 99, 117, 108, 97, 116, 111, 114, 7, 99, 97, 112, 105, 116, 97, 108, 5, 99, 111, 108, 111, 110, 5, 99, 111, 109, 109, 97, 7, 99, 111, 110, 118, 101, 114, 116, 6, 101, 113, 117, 97, 108, 115, 5, 103, 114, 97, 118, 101, 4, 107, 97, 110, 97, 5, 107, 97, 110, 106, 105, 5, 108, 45, 97, 108, 116, 9, 108, 45, 98, 114, 97, 99, 107, 101, 116, 9, 108, 45, 99, 111, 110, 116, 114, 111, 108, 7, 108, 45, 115, 104, 105, 102, 116, 5, 108, 45, 119, 105, 110, 4, 109, 97, 105, 108, 12, 109, 101, 100, 105, 97, 45, 115, 101, 108, 101, 99, 116, 10, 109, 101, 100, 105, 97, 45, 115, 116, 111, 112, 5, 109, 105, 110, 117, 115, 4, 109, 117, 116, 101, 11, 109, 121, 45, 99, 111, 109, 112, 117, 116, 101, 114, 16, 110, 97, 118, 105, 103, 97, 116, 101, 45, 102, 111, 114, 119, 97, 114, 100, 17, 110, 97, 118, 105, 103, 97, 116, 101, 45, 98, 97, 99, 107, 119, 97, 114, 100, 10, 110, 101, 120, 116, 45, 116, 114, 97, 99, 107, 10, 110, 111, 45, 99, 111, 110, 118, 101, 114, 116, 6, 111, 101, 109, 49, 48, 50, 6, 112, 101, 114, 105, 111, 100, 10, 112, 108, 97, 121, 45, 112, 97, 117, 115, 101, 4, 112, 108, 117, 115, 5, 112, 111, 119, 101, 114, 10, 112, 114, 101, 118, 45, 116, 114, 97, 99, 107, 5, 114, 45, 97, 108, 116, 9, 114, 45, 98, 114, 97, 99, 107, 101, 116, 9, 114, 45, 99, 111, 110, 116, 114, 111, 108, 7, 114, 45, 115, 104, 105, 102, 116, 5, 114, 45, 119, 105, 110, 9, 115, 101, 109, 105, 99, 111, 108, 111, 110, 5, 115, 108, 97, 115, 104, 5, 115, 108, 101, 101, 112, 4, 115, 116, 111, 112, 5, 115, 121, 115, 114, 113, 3, 116, 97, 98, 9, 117, 110, 100, 101, 114, 108, 105, 110, 101, 9, 117, 110, 108, 97, 98, 101, 108, 101, 100, 11, 118, 111, 108, 117, 109, 101, 45, 100, 111, 119, 110, 9, 118, 111, 108, 117, 109, 101, 45, 117, 112, 4, 119, 97, 107, 101, 8, 119, 101, 98, 45, 98, 97, 99, 107, 13, 119, 101, 98, 45, 102, 97, 118, 111, 114, 105, 116, 101, 115, 11, 119, 101, 98, 45, 102, 111, 114, 119, 97, 114, 100, 8, 119, 101, 98, 45, 104, 111, 109, 101, 11, 119, 101, 98, 45, 114, 101, 102, 114, 101, 115, 104, 10, 119, 101, 98, 45, 115, 101, 97, 114, 99, 104, 8, 119, 101, 98, 45, 115, 116, 111, 112, 3, 121, 101, 110, 4, 99, 111, 112, 121, 5, 112, 97, 115, 116, 101, 3, 99, 117, 116, 4, 16, 118, 105, 114, 116, 117, 97, 108, 45, 107, 101, 121, 45, 99, 111, 100, 101, 0, 3, 0, 4, 1, 113, 4, 4, 108, 101, 102, 116, 0, 0, 5, 114, 105, 103, 104, 116, 0, 0, 6, 109, 105, 100, 100, 108, 101, 0, 0, 5, 111, 116, 104, 101, 114, 1, 123, 0, 4, 12, 109, 111, 117, 115, 101, 45, 98, 117, 116, 116, 111, 110, 0, 3, 0, 6, 1, 112, 5, 1, 112, 7, 1, 114, 5, 4, 107, 101, 121, 115, 8, 14, 109, 111, 117, 115, 101, 45, 112, 111, 115, 105, 116, 105, 111, 110, 1, 15, 99, 117, 114, 115, 111, 114, 45, 112, 111, 115, 105, 116, 105, 111, 110, 1, 11, 109, 111, 117, 115, 101, 45, 119, 104, 101, 101, 108, 118, 13, 109, 111, 117, 115, 101, 45, 98, 117, 116, 116, 111, 110, 115, 9, 4, 9, 114, 97, 119, 45, 105, 110, 112, 117, 116, 0, 3, 0, 10, 1, 107, 11, 1, 64, 1, 6, 112, 108, 97, 121, 101, 114, 3, 0, 12, 4, 13, 103, 101, 116, 45, 114, 97, 119, 45, 105, 110, 112, 117, 116, 0, 1, 13, 4, 18, 103, 101, 116, 45, 112, 114, 101, 118, 45, 114, 97, 119, 45, 105, 110, 112, 117, 116, 0, 1, 13, 4, 13, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 32, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 47, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 5, 3, 11, 37, 1, 13, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 18, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 3, 2, 0, 7, 150, 6, 1, 65, 6, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 3, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 2, 3, 0, 0, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 2, 3, 0, 0, 4, 118, 101, 99, 51, 1, 66, 29, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 2, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 3, 4, 115, 105, 100, 101, 127, 2, 117, 112, 127, 4, 100, 111, 119, 110, 127, 4, 19, 99, 104, 97, 114, 97, 99, 116, 101, 114, 45, 99, 111, 108, 108, 105, 115, 105, 111, 110, 0, 3, 0, 4, 1, 112, 1, 1, 64, 2, 8, 101, 110, 116, 105, 116, 105, 101, 115, 6, 5, 102, 111, 114, 99, 101, 3, 1, 0, 4, 11, 97, 112, 112, 108, 121, 45, 102, 111, 114, 99, 101, 0, 1, 7, 1, 107, 118, 1, 64, 4, 8, 112, 111, 115, 105, 116, 105, 111, 110, 3, 5, 102, 111, 114, 99, 101, 118, 6, 114, 97, 100, 105, 117, 115, 118, 14, 102, 97, 108, 108, 111, 102, 102, 45, 114, 97, 100, 105, 117, 115, 8, 1, 0, 4, 12, 101, 120, 112, 108, 111, 100, 101, 45, 98, 111, 109, 98, 0, 1, 9, 1, 64, 1, 7, 103, 114, 97, 118, 105, 116, 121, 3, 1, 0, 4, 11, 115, 101, 116, 45, 103, 114, 97, 118, 105, 116, 121, 0, 1, 10, 1, 64, 1, 6, 101, 110, 116, 105, 116, 121, 1, 1, 0, 4, 8, 117, 110, 102, 114, 101, 101, 122, 101, 0, 1, 11, 4, 6, 102, 114, 101, 101, 122, 101, 0, 1, 11, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 8, 118, 101, 108, 111, 99, 105, 116, 121, 118, 1, 0, 4, 11, 115, 116, 97, 114, 116, 45, 109, 111, 116, 111, 114, 0, 1, 12, 4, 10, 115, 116, 111, 112, 45, 109, 111, 116, 111, 114, 0, 1, 11, 1, 111, 2, 1, 118, 1, 107, 13, 1, 64, 2, 6, 111, 114, 105, 103, 105, 110, 3, 9, 100, 105, 114, 101, 99, 116, 105, 111, 110, 3, 0, 14, 4, 13, 114, 97, 121, 99, 97, 115, 116, 45, 102, 105, 114, 115, 116, 0, 1, 15, 1, 112, 13, 1, 64, 2, 6, 111, 114, 105, 103, 105, 110, 3, 9, 100, 105, 114, 101, 99, 116, 105, 111, 110, 3, 0, 16, 4, 7, 114, 97, 121, 99, 97, 115, 116, 0, 1, 17, 1, 64, 4, 6, 101, 110, 116, 105, 116, 121, 1, 12, 100, 105, 115, 112, 108, 97, 99, 101, 109, 101, 110, 116, 3, 8, 109, 105, 110, 45, 100, 105, 115, 116, 118, 12, 101, 108, 97, 112, 115, 101, 100, 45, 116, 105, 109, 101, 118, 0, 5, 4, 14, 109, 111, 118, 101, 45, 99, 104, 97, 114, 97, 99, 116, 101, 114, 0, 1, 18, 4, 14, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 34, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 47, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 5, 3, 11, 39, 1, 14, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 19, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 3, 4, 0, 7, 75, 1, 65, 2, 1, 66, 3, 1, 107, 115, 1, 64, 1, 4, 112, 97, 116, 104, 115, 0, 0, 4, 3, 117, 114, 108, 0, 1, 1, 4, 12, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 30, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 47, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 5, 0, 11, 35, 1, 12, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 17, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 3, 6, 0, 7, 247, 14, 1, 65, 13, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 3, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 2, 3, 0, 0, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 2, 3, 0, 0, 4, 109, 97, 116, 52, 2, 3, 0, 0, 4, 113, 117, 97, 116, 2, 3, 0, 0, 4, 118, 101, 99, 50, 2, 3, 0, 0, 4, 118, 101, 99, 51, 2, 3, 0, 0, 4, 118, 101, 99, 52, 2, 3, 0, 0, 5, 117, 118, 101, 99, 50, 2, 3, 0, 0, 5, 117, 118, 101, 99, 51, 2, 3, 0, 0, 5, 117, 118, 101, 99, 52, 1, 66, 92, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 2, 4, 4, 109, 97, 116, 52, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 4, 2, 3, 2, 1, 4, 4, 4, 118, 101, 99, 50, 0, 3, 0, 6, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 8, 2, 3, 2, 1, 6, 4, 4, 118, 101, 99, 52, 0, 3, 0, 10, 2, 3, 2, 1, 7, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 12, 2, 3, 2, 1, 8, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 14, 2, 3, 2, 1, 9, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 16, 1, 109, 3, 5, 102, 114, 97, 109, 101, 5, 115, 112, 97, 119, 110, 7, 100, 101, 115, 112, 97, 119, 110, 4, 11, 113, 117, 101, 114, 121, 45, 101, 118, 101, 110, 116, 0, 3, 0, 18, 1, 112, 121, 1, 114, 4, 10, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 20, 7, 105, 110, 99, 108, 117, 100, 101, 20, 7, 101, 120, 99, 108, 117, 100, 101, 20, 7, 99, 104, 97, 110, 103, 101, 100, 20, 4, 11, 113, 117, 101, 114, 121, 45, 98, 117, 105, 108, 100, 0, 3, 0, 21, 1, 111, 0, 1, 112, 23, 1, 112, 127, 1, 112, 1, 1, 112, 118, 1, 112, 117, 1, 112, 3, 1, 112, 122, 1, 112, 5, 1, 112, 115, 1, 112, 119, 1, 112, 7, 1, 112, 9, 1, 112, 11, 1, 112, 13, 1, 112, 15, 1, 112, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 24, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 25, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 26, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 27, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 28, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 29, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 30, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 31, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 32, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 20, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 33, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 34, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 35, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 36, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 37, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 38, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 39, 0, 4, 9, 118, 101, 99, 45, 118, 97, 108, 117, 101, 0, 3, 0, 40, 1, 107, 23, 1, 107, 127, 1, 107, 1, 1, 107, 118, 1, 107, 117, 1, 107, 3, 1, 107, 122, 1, 107, 5, 1, 107, 115, 1, 107, 121, 1, 107, 119, 1, 107, 7, 1, 107, 9, 1, 107, 11, 1, 107, 13, 1, 107, 15, 1, 107, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 42, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 43, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 44, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 45, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 46, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 47, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 48, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 49, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 50, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 51, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 52, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 53, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 54, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 55, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 56, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 57, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 58, 0, 4, 12, 111, 112, 116, 105, 111, 110, 45, 118, 97, 108, 117, 101, 0, 3, 0, 59, 1, 113, 19, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 23, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 127, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 1, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 118, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 117, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 3, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 122, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 5, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 115, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 121, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 119, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 7, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 9, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 11, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 13, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 15, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 17, 0, 8, 116, 121, 112, 101, 45, 118, 101, 99, 1, 41, 0, 11, 116, 121, 112, 101, 45, 111, 112, 116, 105, 111, 110, 1, 60, 0, 4, 5, 118, 97, 108, 117, 101, 0, 3, 0, 61, 1, 111, 2, 121, 62, 1, 112, 63, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 64, 1, 64, 1, 2, 105, 100, 115, 0, 51, 4, 9, 103, 101, 116, 45, 105, 110, 100, 101, 120, 0, 1, 66, 1, 107, 62, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 0, 195, 0, 4, 13, 103, 101, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 68, 1, 64, 3, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 5, 118, 97, 108, 117, 101, 62, 1, 0, 4, 13, 97, 100, 100, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 69, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 4, 100, 97, 116, 97, 193, 0, 1, 0, 4, 14, 97, 100, 100, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 70, 4, 13, 115, 101, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 69, 4, 14, 115, 101, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 70, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 0, 127, 4, 13, 104, 97, 115, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 71, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 7, 105, 110, 100, 105, 99, 101, 115, 20, 0, 127, 4, 14, 104, 97, 115, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 72, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 1, 0, 4, 16, 114, 101, 109, 111, 118, 101, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 73, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 7, 105, 110, 100, 105, 99, 101, 115, 20, 1, 0, 4, 17, 114, 101, 109, 111, 118, 101, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 74, 1, 64, 2, 1, 113, 22, 1, 116, 19, 0, 119, 4, 5, 113, 117, 101, 114, 121, 0, 1, 75, 1, 112, 62, 1, 111, 2, 1, 204, 0, 1, 112, 205, 0, 1, 64, 1, 1, 113, 119, 0, 206, 0, 4, 10, 113, 117, 101, 114, 121, 45, 101, 118, 97, 108, 0, 1, 79, 4, 9, 99, 111, 109, 112, 111, 110, 101, 110, 116, 24, 112, 107, 103, 58, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 5, 10, 11, 29, 1, 9, 99, 111, 109, 112, 111, 110, 101, 110, 116, 14, 112, 107, 103, 58, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 3, 8, 0, 7, 210, 12, 1, 65, 16, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 3, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 2, 3, 0, 0, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 2, 3, 0, 0, 4, 109, 97, 116, 52, 2, 3, 0, 0, 4, 113, 117, 97, 116, 2, 3, 0, 0, 4, 118, 101, 99, 50, 2, 3, 0, 0, 4, 118, 101, 99, 51, 2, 3, 0, 0, 4, 118, 101, 99, 52, 2, 3, 0, 0, 5, 117, 118, 101, 99, 50, 2, 3, 0, 0, 5, 117, 118, 101, 99, 51, 2, 3, 0, 0, 5, 117, 118, 101, 99, 52, 1, 66, 66, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 2, 4, 4, 109, 97, 116, 52, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 4, 2, 3, 2, 1, 4, 4, 4, 118, 101, 99, 50, 0, 3, 0, 6, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 8, 2, 3, 2, 1, 6, 4, 4, 118, 101, 99, 52, 0, 3, 0, 10, 2, 3, 2, 1, 7, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 12, 2, 3, 2, 1, 8, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 14, 2, 3, 2, 1, 9, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 16, 1, 109, 3, 5, 102, 114, 97, 109, 101, 5, 115, 112, 97, 119, 110, 7, 100, 101, 115, 112, 97, 119, 110, 4, 11, 113, 117, 101, 114, 121, 45, 101, 118, 101, 110, 116, 0, 3, 0, 18, 1, 112, 121, 1, 114, 4, 10, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 20, 7, 105, 110, 99, 108, 117, 100, 101, 20, 7, 101, 120, 99, 108, 117, 100, 101, 20, 7, 99, 104, 97, 110, 103, 101, 100, 20, 4, 11, 113, 117, 101, 114, 121, 45, 98, 117, 105, 108, 100, 0, 3, 0, 21, 1, 111, 0, 1, 112, 23, 1, 112, 127, 1, 112, 1, 1, 112, 118, 1, 112, 117, 1, 112, 3, 1, 112, 122, 1, 112, 5, 1, 112, 115, 1, 112, 119, 1, 112, 7, 1, 112, 9, 1, 112, 11, 1, 112, 13, 1, 112, 15, 1, 112, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 24, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 25, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 26, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 27, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 28, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 29, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 30, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 31, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 32, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 20, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 33, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 34, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 35, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 36, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 37, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 38, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 39, 0, 4, 9, 118, 101, 99, 45, 118, 97, 108, 117, 101, 0, 3, 0, 40, 1, 107, 23, 1, 107, 127, 1, 107, 1, 1, 107, 118, 1, 107, 117, 1, 107, 3, 1, 107, 122, 1, 107, 5, 1, 107, 115, 1, 107, 121, 1, 107, 119, 1, 107, 7, 1, 107, 9, 1, 107, 11, 1, 107, 13, 1, 107, 15, 1, 107, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 42, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 43, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 44, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 45, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 46, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 47, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 48, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 49, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 50, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 51, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 52, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 53, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 54, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 55, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 56, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 57, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 58, 0, 4, 12, 111, 112, 116, 105, 111, 110, 45, 118, 97, 108, 117, 101, 0, 3, 0, 59, 1, 113, 19, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 23, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 127, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 1, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 118, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 117, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 3, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 122, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 5, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 115, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 121, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 119, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 7, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 9, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 11, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 13, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 15, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 17, 0, 8, 116, 121, 112, 101, 45, 118, 101, 99, 1, 41, 0, 11, 116, 121, 112, 101, 45, 111, 112, 116, 105, 111, 110, 1, 60, 0, 4, 5, 118, 97, 108, 117, 101, 0, 3, 0, 61, 1, 111, 2, 121, 62, 1, 112, 63, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 64, 3, 9, 99, 111, 109, 112, 111, 110, 101, 110, 116, 24, 112, 107, 103, 58, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 5, 10, 2, 3, 0, 1, 6, 101, 110, 116, 105, 116, 121, 1, 66, 6, 2, 3, 2, 1, 11, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 0, 1, 64, 0, 1, 0, 4, 4, 105, 110, 105, 116, 0, 1, 2, 1, 64, 3, 4, 116, 105, 109, 101, 118, 10, 101, 118, 101, 110, 116, 45, 110, 97, 109, 101, 115, 10, 101, 118, 101, 110, 116, 45, 100, 97, 116, 97, 1, 1, 0, 4, 4, 101, 120, 101, 99, 0, 1, 3, 4, 5, 103, 117, 101, 115, 116, 16, 112, 107, 103, 58, 47, 103, 117, 101, 115, 116, 47, 103, 117, 101, 115, 116, 5, 12, 11, 21, 1, 5, 103, 117, 101, 115, 116, 10, 112, 107, 103, 58, 47, 103, 117, 101, 115, 116, 3, 10, 0, 7, 203, 12, 1, 65, 16, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 3, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 2, 3, 0, 0, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 2, 3, 0, 0, 4, 109, 97, 116, 52, 2, 3, 0, 0, 4, 113, 117, 97, 116, 2, 3, 0, 0, 4, 118, 101, 99, 50, 2, 3, 0, 0, 4, 118, 101, 99, 51, 2, 3, 0, 0, 4, 118, 101, 99, 52, 2, 3, 0, 0, 5, 117, 118, 101, 99, 50, 2, 3, 0, 0, 5, 117, 118, 101, 99, 51, 2, 3, 0, 0, 5, 117, 118, 101, 99, 52, 1, 66, 66, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 2, 4, 4, 109, 97, 116, 52, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 4, 2, 3, 2, 1, 4, 4, 4, 118, 101, 99, 50, 0, 3, 0, 6, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 8, 2, 3, 2, 1, 6, 4, 4, 118, 101, 99, 52, 0, 3, 0, 10, 2, 3, 2, 1, 7, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 12, 2, 3, 2, 1, 8, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 14, 2, 3, 2, 1, 9, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 16, 1, 109, 3, 5, 102, 114, 97, 109, 101, 5, 115, 112, 97, 119, 110, 7, 100, 101, 115, 112, 97, 119, 110, 4, 11, 113, 117, 101, 114, 121, 45, 101, 118, 101, 110, 116, 0, 3, 0, 18, 1, 112, 121, 1, 114, 4, 10, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 20, 7, 105, 110, 99, 108, 117, 100, 101, 20, 7, 101, 120, 99, 108, 117, 100, 101, 20, 7, 99, 104, 97, 110, 103, 101, 100, 20, 4, 11, 113, 117, 101, 114, 121, 45, 98, 117, 105, 108, 100, 0, 3, 0, 21, 1, 111, 0, 1, 112, 23, 1, 112, 127, 1, 112, 1, 1, 112, 118, 1, 112, 117, 1, 112, 3, 1, 112, 122, 1, 112, 5, 1, 112, 115, 1, 112, 119, 1, 112, 7, 1, 112, 9, 1, 112, 11, 1, 112, 13, 1, 112, 15, 1, 112, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 24, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 25, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 26, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 27, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 28, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 29, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 30, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 31, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 32, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 20, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 33, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 34, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 35, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 36, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 37, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 38, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 39, 0, 4, 9, 118, 101, 99, 45, 118, 97, 108, 117, 101, 0, 3, 0, 40, 1, 107, 23, 1, 107, 127, 1, 107, 1, 1, 107, 118, 1, 107, 117, 1, 107, 3, 1, 107, 122, 1, 107, 5, 1, 107, 115, 1, 107, 121, 1, 107, 119, 1, 107, 7, 1, 107, 9, 1, 107, 11, 1, 107, 13, 1, 107, 15, 1, 107, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 42, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 43, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 44, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 45, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 46, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 47, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 48, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 49, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 50, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 51, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 52, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 53, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 54, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 55, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 56, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 57, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 58, 0, 4, 12, 111, 112, 116, 105, 111, 110, 45, 118, 97, 108, 117, 101, 0, 3, 0, 59, 1, 113, 19, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 23, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 127, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 1, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 118, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 117, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 3, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 122, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 5, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 115, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 121, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 119, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 7, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 9, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 11, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 13, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 15, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 17, 0, 8, 116, 121, 112, 101, 45, 118, 101, 99, 1, 41, 0, 11, 116, 121, 112, 101, 45, 111, 112, 116, 105, 111, 110, 1, 60, 0, 4, 5, 118, 97, 108, 117, 101, 0, 3, 0, 61, 1, 111, 2, 121, 62, 1, 112, 63, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 64, 3, 9, 99, 111, 109, 112, 111, 110, 101, 110, 116, 24, 112, 107, 103, 58, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 5, 10, 2, 3, 0, 1, 6, 101, 110, 116, 105, 116, 121, 1, 66, 6, 2, 3, 2, 1, 11, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 0, 1, 64, 1, 4, 110, 97, 109, 101, 115, 1, 0, 4, 9, 115, 117, 98, 115, 99, 114, 105, 98, 101, 0, 1, 2, 1, 64, 2, 4, 110, 97, 109, 101, 115, 4, 100, 97, 116, 97, 1, 1, 0, 4, 4, 115, 101, 110, 100, 0, 1, 3, 4, 5, 101, 118, 101, 110, 116, 16, 112, 107, 103, 58, 47, 101, 118, 101, 110, 116, 47, 101, 118, 101, 110, 116, 5, 12, 11, 21, 1, 5, 101, 118, 101, 110, 116, 10, 112, 107, 103, 58, 47, 101, 118, 101, 110, 116, 3, 12, 0, 7, 189, 15, 1, 65, 16, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 3, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 2, 3, 0, 0, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 2, 3, 0, 0, 4, 109, 97, 116, 52, 2, 3, 0, 0, 4, 113, 117, 97, 116, 2, 3, 0, 0, 4, 118, 101, 99, 50, 2, 3, 0, 0, 4, 118, 101, 99, 51, 2, 3, 0, 0, 4, 118, 101, 99, 52, 2, 3, 0, 0, 5, 117, 118, 101, 99, 50, 2, 3, 0, 0, 5, 117, 118, 101, 99, 51, 2, 3, 0, 0, 5, 117, 118, 101, 99, 52, 1, 66, 66, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 2, 4, 4, 109, 97, 116, 52, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 4, 2, 3, 2, 1, 4, 4, 4, 118, 101, 99, 50, 0, 3, 0, 6, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 8, 2, 3, 2, 1, 6, 4, 4, 118, 101, 99, 52, 0, 3, 0, 10, 2, 3, 2, 1, 7, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 12, 2, 3, 2, 1, 8, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 14, 2, 3, 2, 1, 9, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 16, 1, 109, 3, 5, 102, 114, 97, 109, 101, 5, 115, 112, 97, 119, 110, 7, 100, 101, 115, 112, 97, 119, 110, 4, 11, 113, 117, 101, 114, 121, 45, 101, 118, 101, 110, 116, 0, 3, 0, 18, 1, 112, 121, 1, 114, 4, 10, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 20, 7, 105, 110, 99, 108, 117, 100, 101, 20, 7, 101, 120, 99, 108, 117, 100, 101, 20, 7, 99, 104, 97, 110, 103, 101, 100, 20, 4, 11, 113, 117, 101, 114, 121, 45, 98, 117, 105, 108, 100, 0, 3, 0, 21, 1, 111, 0, 1, 112, 23, 1, 112, 127, 1, 112, 1, 1, 112, 118, 1, 112, 117, 1, 112, 3, 1, 112, 122, 1, 112, 5, 1, 112, 115, 1, 112, 119, 1, 112, 7, 1, 112, 9, 1, 112, 11, 1, 112, 13, 1, 112, 15, 1, 112, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 24, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 25, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 26, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 27, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 28, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 29, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 30, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 31, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 32, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 20, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 33, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 34, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 35, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 36, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 37, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 38, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 39, 0, 4, 9, 118, 101, 99, 45, 118, 97, 108, 117, 101, 0, 3, 0, 40, 1, 107, 23, 1, 107, 127, 1, 107, 1, 1, 107, 118, 1, 107, 117, 1, 107, 3, 1, 107, 122, 1, 107, 5, 1, 107, 115, 1, 107, 121, 1, 107, 119, 1, 107, 7, 1, 107, 9, 1, 107, 11, 1, 107, 13, 1, 107, 15, 1, 107, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 42, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 43, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 44, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 45, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 46, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 47, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 48, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 49, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 50, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 51, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 52, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 53, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 54, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 55, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 56, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 57, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 58, 0, 4, 12, 111, 112, 116, 105, 111, 110, 45, 118, 97, 108, 117, 101, 0, 3, 0, 59, 1, 113, 19, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 23, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 127, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 1, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 118, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 117, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 3, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 122, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 5, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 115, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 121, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 119, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 7, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 9, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 11, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 13, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 15, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 17, 0, 8, 116, 121, 112, 101, 45, 118, 101, 99, 1, 41, 0, 11, 116, 121, 112, 101, 45, 111, 112, 116, 105, 111, 110, 1, 60, 0, 4, 5, 118, 97, 108, 117, 101, 0, 3, 0, 61, 1, 111, 2, 121, 62, 1, 112, 63, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 64, 3, 9, 99, 111, 109, 112, 111, 110, 101, 110, 116, 24, 112, 107, 103, 58, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 5, 10, 2, 3, 0, 1, 6, 101, 110, 116, 105, 116, 121, 1, 66, 29, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 4, 2, 3, 2, 1, 11, 4, 11, 101, 110, 116, 105, 116, 121, 45, 100, 97, 116, 97, 0, 3, 0, 6, 1, 114, 3, 8, 99, 108, 105, 112, 45, 117, 114, 108, 115, 7, 108, 111, 111, 112, 105, 110, 103, 127, 6, 119, 101, 105, 103, 104, 116, 118, 4, 16, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 97, 99, 116, 105, 111, 110, 0, 3, 0, 8, 1, 112, 9, 1, 114, 2, 7, 97, 99, 116, 105, 111, 110, 115, 10, 15, 97, 112, 112, 108, 121, 45, 98, 97, 115, 101, 45, 112, 111, 115, 101, 127, 4, 20, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 99, 111, 110, 116, 114, 111, 108, 108, 101, 114, 0, 3, 0, 11, 1, 64, 1, 4, 100, 97, 116, 97, 7, 0, 1, 4, 5, 115, 112, 97, 119, 110, 0, 1, 13, 1, 64, 1, 6, 101, 110, 116, 105, 116, 121, 1, 0, 127, 4, 7, 100, 101, 115, 112, 97, 119, 110, 0, 1, 14, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 20, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 99, 111, 110, 116, 114, 111, 108, 108, 101, 114, 12, 1, 0, 4, 24, 115, 101, 116, 45, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 99, 111, 110, 116, 114, 111, 108, 108, 101, 114, 0, 1, 15, 1, 112, 1, 1, 64, 2, 8, 112, 111, 115, 105, 116, 105, 111, 110, 3, 6, 114, 97, 100, 105, 117, 115, 118, 0, 16, 4, 7, 105, 110, 45, 97, 114, 101, 97, 0, 1, 17, 4, 6, 101, 120, 105, 115, 116, 115, 0, 1, 14, 1, 64, 1, 5, 105, 110, 100, 101, 120, 121, 0, 16, 4, 7, 103, 101, 116, 45, 97, 108, 108, 0, 1, 18, 1, 64, 0, 0, 1, 4, 9, 114, 101, 115, 111, 117, 114, 99, 101, 115, 0, 1, 19, 4, 22, 115, 121, 110, 99, 104, 114, 111, 110, 105, 122, 101, 100, 45, 114, 101, 115, 111, 117, 114, 99, 101, 115, 0, 1, 19, 4, 19, 112, 101, 114, 115, 105, 115, 116, 101, 100, 45, 114, 101, 115, 111, 117, 114, 99, 101, 115, 0, 1, 19, 4, 6, 101, 110, 116, 105, 116, 121, 18, 112, 107, 103, 58, 47, 101, 110, 116, 105, 116, 121, 47, 101, 110, 116, 105, 116, 121, 5, 12, 11, 23, 1, 6, 101, 110, 116, 105, 116, 121, 11, 112, 107, 103, 58, 47, 101, 110, 116, 105, 116, 121, 3, 14, 0, 7, 227, 35, 1, 65, 2, 1, 65, 26, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 3, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 2, 3, 0, 0, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 2, 3, 0, 0, 4, 109, 97, 116, 52, 2, 3, 0, 0, 4, 113, 117, 97, 116, 2, 3, 0, 0, 4, 118, 101, 99, 50, 2, 3, 0, 0, 4, 118, 101, 99, 51, 2, 3, 0, 0, 4, 118, 101, 99, 52, 2, 3, 0, 0, 5, 117, 118, 101, 99, 50, 2, 3, 0, 0, 5, 117, 118, 101, 99, 51, 2, 3, 0, 0, 5, 117, 118, 101, 99, 52, 1, 66, 92, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 2, 4, 4, 109, 97, 116, 52, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 4, 2, 3, 2, 1, 4, 4, 4, 118, 101, 99, 50, 0, 3, 0, 6, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 8, 2, 3, 2, 1, 6, 4, 4, 118, 101, 99, 52, 0, 3, 0, 10, 2, 3, 2, 1, 7, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 12, 2, 3, 2, 1, 8, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 14, 2, 3, 2, 1, 9, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 16, 1, 109, 3, 5, 102, 114, 97, 109, 101, 5, 115, 112, 97, 119, 110, 7, 100, 101, 115, 112, 97, 119, 110, 4, 11, 113, 117, 101, 114, 121, 45, 101, 118, 101, 110, 116, 0, 3, 0, 18, 1, 112, 121, 1, 114, 4, 10, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 20, 7, 105, 110, 99, 108, 117, 100, 101, 20, 7, 101, 120, 99, 108, 117, 100, 101, 20, 7, 99, 104, 97, 110, 103, 101, 100, 20, 4, 11, 113, 117, 101, 114, 121, 45, 98, 117, 105, 108, 100, 0, 3, 0, 21, 1, 111, 0, 1, 112, 23, 1, 112, 127, 1, 112, 1, 1, 112, 118, 1, 112, 117, 1, 112, 3, 1, 112, 122, 1, 112, 5, 1, 112, 115, 1, 112, 119, 1, 112, 7, 1, 112, 9, 1, 112, 11, 1, 112, 13, 1, 112, 15, 1, 112, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 24, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 25, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 26, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 27, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 28, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 29, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 30, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 31, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 32, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 20, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 33, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 34, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 35, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 36, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 37, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 38, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 39, 0, 4, 9, 118, 101, 99, 45, 118, 97, 108, 117, 101, 0, 3, 0, 40, 1, 107, 23, 1, 107, 127, 1, 107, 1, 1, 107, 118, 1, 107, 117, 1, 107, 3, 1, 107, 122, 1, 107, 5, 1, 107, 115, 1, 107, 121, 1, 107, 119, 1, 107, 7, 1, 107, 9, 1, 107, 11, 1, 107, 13, 1, 107, 15, 1, 107, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 42, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 43, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 44, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 45, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 46, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 47, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 48, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 49, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 50, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 51, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 52, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 53, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 54, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 55, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 56, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 57, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 58, 0, 4, 12, 111, 112, 116, 105, 111, 110, 45, 118, 97, 108, 117, 101, 0, 3, 0, 59, 1, 113, 19, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 23, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 127, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 1, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 118, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 117, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 3, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 122, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 5, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 115, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 121, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 119, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 7, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 9, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 11, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 13, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 15, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 17, 0, 8, 116, 121, 112, 101, 45, 118, 101, 99, 1, 41, 0, 11, 116, 121, 112, 101, 45, 111, 112, 116, 105, 111, 110, 1, 60, 0, 4, 5, 118, 97, 108, 117, 101, 0, 3, 0, 61, 1, 111, 2, 121, 62, 1, 112, 63, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 64, 1, 64, 1, 2, 105, 100, 115, 0, 51, 4, 9, 103, 101, 116, 45, 105, 110, 100, 101, 120, 0, 1, 66, 1, 107, 62, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 0, 195, 0, 4, 13, 103, 101, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 68, 1, 64, 3, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 5, 118, 97, 108, 117, 101, 62, 1, 0, 4, 13, 97, 100, 100, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 69, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 4, 100, 97, 116, 97, 193, 0, 1, 0, 4, 14, 97, 100, 100, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 70, 4, 13, 115, 101, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 69, 4, 14, 115, 101, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 70, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 0, 127, 4, 13, 104, 97, 115, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 71, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 7, 105, 110, 100, 105, 99, 101, 115, 20, 0, 127, 4, 14, 104, 97, 115, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 72, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 1, 0, 4, 16, 114, 101, 109, 111, 118, 101, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 73, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 7, 105, 110, 100, 105, 99, 101, 115, 20, 1, 0, 4, 17, 114, 101, 109, 111, 118, 101, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 74, 1, 64, 2, 1, 113, 22, 1, 116, 19, 0, 119, 4, 5, 113, 117, 101, 114, 121, 0, 1, 75, 1, 112, 62, 1, 111, 2, 1, 204, 0, 1, 112, 205, 0, 1, 64, 1, 1, 113, 119, 0, 206, 0, 4, 10, 113, 117, 101, 114, 121, 45, 101, 118, 97, 108, 0, 1, 79, 3, 9, 99, 111, 109, 112, 111, 110, 101, 110, 116, 24, 112, 107, 103, 58, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 5, 10, 2, 3, 0, 1, 6, 101, 110, 116, 105, 116, 121, 1, 66, 29, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 4, 2, 3, 2, 1, 11, 4, 11, 101, 110, 116, 105, 116, 121, 45, 100, 97, 116, 97, 0, 3, 0, 6, 1, 114, 3, 8, 99, 108, 105, 112, 45, 117, 114, 108, 115, 7, 108, 111, 111, 112, 105, 110, 103, 127, 6, 119, 101, 105, 103, 104, 116, 118, 4, 16, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 97, 99, 116, 105, 111, 110, 0, 3, 0, 8, 1, 112, 9, 1, 114, 2, 7, 97, 99, 116, 105, 111, 110, 115, 10, 15, 97, 112, 112, 108, 121, 45, 98, 97, 115, 101, 45, 112, 111, 115, 101, 127, 4, 20, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 99, 111, 110, 116, 114, 111, 108, 108, 101, 114, 0, 3, 0, 11, 1, 64, 1, 4, 100, 97, 116, 97, 7, 0, 1, 4, 5, 115, 112, 97, 119, 110, 0, 1, 13, 1, 64, 1, 6, 101, 110, 116, 105, 116, 121, 1, 0, 127, 4, 7, 100, 101, 115, 112, 97, 119, 110, 0, 1, 14, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 20, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 99, 111, 110, 116, 114, 111, 108, 108, 101, 114, 12, 1, 0, 4, 24, 115, 101, 116, 45, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 99, 111, 110, 116, 114, 111, 108, 108, 101, 114, 0, 1, 15, 1, 112, 1, 1, 64, 2, 8, 112, 111, 115, 105, 116, 105, 111, 110, 3, 6, 114, 97, 100, 105, 117, 115, 118, 0, 16, 4, 7, 105, 110, 45, 97, 114, 101, 97, 0, 1, 17, 4, 6, 101, 120, 105, 115, 116, 115, 0, 1, 14, 1, 64, 1, 5, 105, 110, 100, 101, 120, 121, 0, 16, 4, 7, 103, 101, 116, 45, 97, 108, 108, 0, 1, 18, 1, 64, 0, 0, 1, 4, 9, 114, 101, 115, 111, 117, 114, 99, 101, 115, 0, 1, 19, 4, 22, 115, 121, 110, 99, 104, 114, 111, 110, 105, 122, 101, 100, 45, 114, 101, 115, 111, 117, 114, 99, 101, 115, 0, 1, 19, 4, 19, 112, 101, 114, 115, 105, 115, 116, 101, 100, 45, 114, 101, 115, 111, 117, 114, 99, 101, 115, 0, 1, 19, 3, 6, 101, 110, 116, 105, 116, 121, 18, 112, 107, 103, 58, 47, 101, 110, 116, 105, 116, 121, 47, 101, 110, 116, 105, 116, 121, 5, 12, 1, 66, 6, 2, 3, 2, 1, 11, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 0, 1, 64, 1, 4, 110, 97, 109, 101, 115, 1, 0, 4, 9, 115, 117, 98, 115, 99, 114, 105, 98, 101, 0, 1, 2, 1, 64, 2, 4, 110, 97, 109, 101, 115, 4, 100, 97, 116, 97, 1, 1, 0, 4, 4, 115, 101, 110, 100, 0, 1, 3, 3, 5, 101, 118, 101, 110, 116, 16, 112, 107, 103, 58, 47, 101, 118, 101, 110, 116, 47, 101, 118, 101, 110, 116, 5, 13, 1, 66, 16, 2, 3, 2, 1, 4, 4, 4, 118, 101, 99, 50, 0, 3, 0, 0, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 2, 1, 109, 163, 1, 4, 107, 101, 121, 49, 4, 107, 101, 121, 50, 4, 107, 101, 121, 51, 4, 107, 101, 121, 52, 4, 107, 101, 121, 53, 4, 107, 101, 121, 54, 4, 107, 101, 121, 55, 4, 107, 101, 121, 56, 4, 107, 101, 121, 57, 4, 107, 101, 121, 48, 1, 97, 1, 98, 1, 99, 1, 100, 1, 101, 1, 102, 1, 103, 1, 104, 1, 105, 1, 106, 1, 107, 1, 108, 1, 109, 1, 110, 1, 111, 1, 112, 1, 113, 1, 114, 1, 115, 1, 116, 1, 117, 1, 118, 1, 119, 1, 120, 1, 121, 1, 122, 6, 101, 115, 99, 97, 112, 101, 2, 102, 49, 2, 102, 50, 2, 102, 51, 2, 102, 52, 2, 102, 53, 2, 102, 54, 2, 102, 55, 2, 102, 56, 2, 102, 57, 3, 102, 49, 48, 3, 102, 49, 49, 3, 102, 49, 50, 3, 102, 49, 51, 3, 102, 49, 52, 3, 102, 49, 53, 3, 102, 49, 54, 3, 102, 49, 55, 3, 102, 49, 56, 3, 102, 49, 57, 3, 102, 50, 48, 3, 102, 50, 49, 3, 102, 50, 50, 3, 102, 50, 51, 3, 102, 50, 52, 8, 115, 110, 97, 112, 115, 104, 111, 116, 6, 115, 99, 114, 111, 108, 108, 5, 112, 97, 117, 115, 101, 6, 105, 110, 115, 101, 114, 116, 4, 104, 111, 109, 101, 6, 100, 101, 108, 101, 116, 101, 3, 101, 110, 100, 9, 112, 97, 103, 101, 45, 100, 111, 119, 110, 7, 112, 97, 103, 101, 45, 117, 112, 4, 108, 101, 102, 116, 2, 117, 112, 5, 114, 105, 103, 104, 116, 4, 100, 111, 119, 110, 4, 98, 97, 99, 107, 6, 114, 101, 116, 117, 114, 110, 5, 115, 112, 97, 99, 101, 7, 99, 111, 109, 112, 111, 115, 101, 5, 99, 97, 114, 101, 116, 7, 110, 117, 109, 108, 111, 99, 107, 7, 110, 117, 109, 112, 97, 100, 48, 7, 110, 117, 109, 112, 97, 100, 49, 7, 110, 117, 109, 112, 97, 100, 50, 7, 110, 117, 109, 112, 97, 100, 51, 7, 110, 117, 109, 112, 97, 100, 52, 7, 110, 117, 109, 112, 97, 100, 53, 7, 110, 117, 109, 112, 97, 100, 54, 7, 110, 117, 109, 112, 97, 100, 55, 7, 110, 117, 109, 112, 97, 100, 56, 7, 110, 117, 109, 112, 97, 100, 57, 10, 110, 117, 109, 112, 97, 100, 45, 97, 100, 100, 13, 110, 117, 109, 112, 97, 100, 45, 100, 105, 118, 105, 100, 101, 14, 110, 117, 109, 112, 97, 100, 45, 100, 101, 99, 105, 109, 97, 108, 12, 110, 117, 109, 112, 97, 100, 45, 99, 111, 109, 109, 97, 12, 110, 117, 109, 112, 97, 100, 45, 101, 110, 116, 101, 114, 13, 110, 117, 109, 112, 97, 100, 45, 101, 113, 117, 97, 108, 115, 15, 110, 117, 109, 112, 97, 100, 45, 109, 117, 108, 116, 105, 112, 108, 121, 15, 110, 117, 109, 112, 97, 100, 45, 115, 117, 98, 116, 114, 97, 99, 116, 7, 97, 98, 110, 116, 45, 99, 49, 7, 97, 98, 110, 116, 45, 99, 50, 10, 97, 112, 111, 115, 116, 114, 111, 112, 104, 101, 4, 97, 112, 112, 115, 8, 97, 115, 116, 101, 114, 105, 115, 107, 2, 97, 116, 2, 97, 120, 9, 98, 97, 99, 107, 115, 108, 97, 115, 104, 10, 99, 97, 108, 99, 117, 108, 97, 116, 111, 114, 7, 99, 97, 112, 105, 116, 97, 108, 5, 99, 111, 108, 111, 110, 5, 99, 111, 109, 109, 97, 7, 99, 111, 110, 118, 101, 114, 116, 6, 101, 113, 117, 97, 108, 115, 5, 103, 114, 97, 118, 101, 4, 107, 97, 110, 97, 5, 107, 97, 110, 106, 105, 5, 108, 45, 97, 108, 116, 9, 108, 45, 98, 114, 97, 99, 107, 101, 116, 9, 108, 45, 99, 111, 110, 116, 114, 111, 108, 7, 108, 45, 115, 104, 105, 102, 116, 5, 108, 45, 119, 105, 110, 4, 109, 97, 105, 108, 12, 109, 101, 100, 105, 97, 45, 115, 101, 108, 101, 99, 116, 10, 109, 101, 100, 105, 97, 45, 115, 116, 111, 112, 5, 109, 105, 110, 117, 115, 4, 109, 117, 116, 101, 11, 109, 121, 45, 99, 111, 109, 112, 117, 116, 101, 114, 16, 110, 97, 118, 105, 103, 97, 116, 101, 45, 102, 111, 114, 119, 97, 114, 100, 17, 110, 97, 118, 105, 103, 97, 116, 101, 45, 98, 97, 99, 107, 119, 97, 114, 100, 10, 110, 101, 120, 116, 45, 116, 114, 97, 99, 107, 10, 110, 111, 45, 99, 111, 110, 118, 101, 114, 116, 6, 111, 101, 109, 49, 48, 50, 6, 112, 101, 114, 105, 111, 100, 10, 112, 108, 97, 121, 45, 112, 97, 117, 115, 101, 4, 112, 108, 117, 115, 5, 112, 111, 119, 101, 114, 10, 112, 114, 101, 118, 45, 116, 114, 97, 99, 107, 5, 114, 45, 97, 108, 116, 9, 114, 45, 98, 114, 97, 99, 107, 101, 116, 9, 114, 45, 99, 111, 110, 116, 114, 111, 108, 7, 114, 45, 115, 104, 105, 102, 116, 5, 114, 45, 119, 105, 110, 9, 115, 101, 109, 105, 99, 111, 108, 111, 110, 5, 115, 108, 97, 115, 104, 5, 115, 108, 101, 101, 112, 4, 115, 116, 111, 112, 5, 115, 121, 115, 114, 113, 3, 116, 97, 98, 9, 117, 110, 100, 101, 114, 108, 105, 110, 101, 9, 117, 110, 108, 97, 98, 101, 108, 101, 100, 11, 118, 111, 108, 117, 109, 101, 45, 100, 111, 119, 110, 9, 118, 111, 108, 117, 109, 101, 45, 117, 112, 4, 119, 97, 107, 101, 8, 119, 101, 98, 45, 98, 97, 99, 107, 13, 119, 101, 98, 45, 102, 97, 118, 111, 114, 105, 116, 101, 115, 11, 119, 101, 98, 45, 102, 111, 114, 119, 97, 114, 100, 8, 119, 101, 98, 45, 104, 111, 109, 101, 11, 119, 101, 98, 45, 114, 101, 102, 114, 101, 115, 104, 10, 119, 101, 98, 45, 115, 101, 97, 114, 99, 104, 8, 119, 101, 98, 45, 115, 116, 111, 112, 3, 121, 101, 110, 4, 99, 111, 112, 121, 5, 112, 97, 115, 116, 101, 3, 99, 117, 116, 4, 16, 118, 105, 114, 116, 117, 97, 108, 45, 107, 101, 121, 45, 99, 111, 100, 101, 0, 3, 0, 4, 1, 113, 4, 4, 108, 101, 102, 116, 0, 0, 5, 114, 105, 103, 104, 116, 0, 0, 6, 109, 105, 100, 100, 108, 101, 0, 0, 5, 111, 116, 104, 101, 114, 1, 123, 0, 4, 12, 109, 111, 117, 115, 101, 45, 98, 117, 116, 116, 111, 110, 0, 3, 0, 6, 1, 112, 5, 1, 112, 7, 1, 114, 5, 4, 107, 101, 121, 115, 8, 14, 109, 111, 117, 115, 101, 45, 112, 111, 115, 105, 116, 105, 111, 110, 1, 15, 99, 117, 114, 115, 111, 114, 45, 112, 111, 115, 105, 116, 105, 111, 110, 1, 11, 109, 111, 117, 115, 101, 45, 119, 104, 101, 101, 108, 118, 13, 109, 111, 117, 115, 101, 45, 98, 117, 116, 116, 111, 110, 115, 9, 4, 9, 114, 97, 119, 45, 105, 110, 112, 117, 116, 0, 3, 0, 10, 1, 107, 11, 1, 64, 1, 6, 112, 108, 97, 121, 101, 114, 3, 0, 12, 4, 13, 103, 101, 116, 45, 114, 97, 119, 45, 105, 110, 112, 117, 116, 0, 1, 13, 4, 18, 103, 101, 116, 45, 112, 114, 101, 118, 45, 114, 97, 119, 45, 105, 110, 112, 117, 116, 0, 1, 13, 3, 13, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 32, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 47, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 5, 14, 1, 66, 29, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 3, 4, 115, 105, 100, 101, 127, 2, 117, 112, 127, 4, 100, 111, 119, 110, 127, 4, 19, 99, 104, 97, 114, 97, 99, 116, 101, 114, 45, 99, 111, 108, 108, 105, 115, 105, 111, 110, 0, 3, 0, 4, 1, 112, 1, 1, 64, 2, 8, 101, 110, 116, 105, 116, 105, 101, 115, 6, 5, 102, 111, 114, 99, 101, 3, 1, 0, 4, 11, 97, 112, 112, 108, 121, 45, 102, 111, 114, 99, 101, 0, 1, 7, 1, 107, 118, 1, 64, 4, 8, 112, 111, 115, 105, 116, 105, 111, 110, 3, 5, 102, 111, 114, 99, 101, 118, 6, 114, 97, 100, 105, 117, 115, 118, 14, 102, 97, 108, 108, 111, 102, 102, 45, 114, 97, 100, 105, 117, 115, 8, 1, 0, 4, 12, 101, 120, 112, 108, 111, 100, 101, 45, 98, 111, 109, 98, 0, 1, 9, 1, 64, 1, 7, 103, 114, 97, 118, 105, 116, 121, 3, 1, 0, 4, 11, 115, 101, 116, 45, 103, 114, 97, 118, 105, 116, 121, 0, 1, 10, 1, 64, 1, 6, 101, 110, 116, 105, 116, 121, 1, 1, 0, 4, 8, 117, 110, 102, 114, 101, 101, 122, 101, 0, 1, 11, 4, 6, 102, 114, 101, 101, 122, 101, 0, 1, 11, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 8, 118, 101, 108, 111, 99, 105, 116, 121, 118, 1, 0, 4, 11, 115, 116, 97, 114, 116, 45, 109, 111, 116, 111, 114, 0, 1, 12, 4, 10, 115, 116, 111, 112, 45, 109, 111, 116, 111, 114, 0, 1, 11, 1, 111, 2, 1, 118, 1, 107, 13, 1, 64, 2, 6, 111, 114, 105, 103, 105, 110, 3, 9, 100, 105, 114, 101, 99, 116, 105, 111, 110, 3, 0, 14, 4, 13, 114, 97, 121, 99, 97, 115, 116, 45, 102, 105, 114, 115, 116, 0, 1, 15, 1, 112, 13, 1, 64, 2, 6, 111, 114, 105, 103, 105, 110, 3, 9, 100, 105, 114, 101, 99, 116, 105, 111, 110, 3, 0, 16, 4, 7, 114, 97, 121, 99, 97, 115, 116, 0, 1, 17, 1, 64, 4, 6, 101, 110, 116, 105, 116, 121, 1, 12, 100, 105, 115, 112, 108, 97, 99, 101, 109, 101, 110, 116, 3, 8, 109, 105, 110, 45, 100, 105, 115, 116, 118, 12, 101, 108, 97, 112, 115, 101, 100, 45, 116, 105, 109, 101, 118, 0, 5, 4, 14, 109, 111, 118, 101, 45, 99, 104, 97, 114, 97, 99, 116, 101, 114, 0, 1, 18, 3, 14, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 34, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 47, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 5, 15, 1, 66, 3, 1, 107, 115, 1, 64, 1, 4, 112, 97, 116, 104, 115, 0, 0, 4, 3, 117, 114, 108, 0, 1, 1, 3, 12, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 30, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 47, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 5, 16, 1, 66, 6, 2, 3, 2, 1, 11, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 0, 1, 64, 0, 1, 0, 4, 4, 105, 110, 105, 116, 0, 1, 2, 1, 64, 3, 4, 116, 105, 109, 101, 118, 10, 101, 118, 101, 110, 116, 45, 110, 97, 109, 101, 115, 10, 101, 118, 101, 110, 116, 45, 100, 97, 116, 97, 1, 1, 0, 4, 4, 101, 120, 101, 99, 0, 1, 3, 4, 5, 103, 117, 101, 115, 116, 16, 112, 107, 103, 58, 47, 103, 117, 101, 115, 116, 47, 103, 117, 101, 115, 116, 5, 17, 4, 8, 98, 105, 110, 100, 105, 110, 103, 115, 18, 112, 107, 103, 58, 47, 109, 97, 105, 110, 47, 98, 105, 110, 100, 105, 110, 103, 115, 4, 0, 0, 68, 9, 112, 114, 111, 100, 117, 99, 101, 114, 115, 1, 12, 112, 114, 111, 99, 101, 115, 115, 101, 100, 45, 98, 121, 2, 13, 119, 105, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 5, 48, 46, 55, 46, 51, 16, 119, 105, 116, 45, 98, 105, 110, 100, 103, 101, 110, 45, 114, 117, 115, 116, 5, 48, 46, 52, 46, 48, 11, 19, 1, 4, 109, 97, 105, 110, 9, 112, 107, 103, 58, 47, 109, 97, 105, 110, 3, 16, 0];
-                                  
-                                  #[inline(never)]
-                                  #[doc(hidden)]
-                                  #[cfg(target_arch = "wasm32")]
-                                  pub fn __link_section() {}
-                                  
\ No newline at end of file
+                                    pub fn __link_section() {}
+                                    
\ No newline at end of file
@@ -1,5 +1,5 @@
 use std::{
-    cell::{Ref, RefCell},
+    cell::{Cell, Ref, RefCell},
     collections::HashMap,
     future::Future,
     pin::Pin,
@@ -32,6 +32,8 @@ pub(crate) struct Executor {
     current_callbacks: RefCell<Callbacks>,
     incoming_callbacks: RefCell<Callbacks>,
     frame_state: RefCell<FrameState>,
+    /// Incremented every time the host runs the module
+    run: Cell<u64>,
 }
 // WebAssembly, at time of writing, is single-threaded. This is a convenient little lie
 // to make it easy to use this in a global context.
@@ -47,11 +49,13 @@ impl Executor {
             current_callbacks: RefCell::new(Default::default()),
             incoming_callbacks: RefCell::new(Default::default()),
             frame_state: RefCell::new(Default::default()),
+            run: Cell::new(0),
         }
     }
 
     pub fn execute(&self, frame_state: FrameState, event_name: &str, components: &Entity) {
         *self.frame_state.borrow_mut() = frame_state;
+        self.run.set(self.run.get() + 1);
 
         // Load all pending callbacks.
         {
@@ -122,6 +126,11 @@ impl Executor {
         self.frame_state.borrow()
     }
 
+    /// Identifies the current run of the module; see [Self::execute]
+    pub fn current_run(&self) -> u64 {
+        self.run.get()
+    }
+
     pub fn register_callback(&self, event_name: String, callback: EventCallbackFn) -> u128 {
         let uid = random::<u128>();
         self.incoming_callbacks
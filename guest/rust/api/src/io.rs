@@ -0,0 +1,50 @@
+use std::task::Poll;
+
+use crate::internal::wit;
+
+/// Reads the file at `path`, relative to this project's `build` directory.
+///
+/// Requires `assets = true` in the `[capabilities]` section of `ambient.toml`.
+pub async fn read_asset(path: impl AsRef<str>) -> anyhow::Result<Vec<u8>> {
+    request(wit::server_io::read_asset(path.as_ref())).await
+}
+
+/// Reads the file at `path`, relative to this project's `data` directory.
+///
+/// Requires `data = true` in the `[capabilities]` section of `ambient.toml`.
+pub async fn read_data(path: impl AsRef<str>) -> anyhow::Result<Vec<u8>> {
+    request(wit::server_io::read_data(path.as_ref())).await
+}
+
+/// Writes `data` to the file at `path`, relative to this project's `data` directory.
+/// Any missing parent directories will be created.
+///
+/// Requires `data = true` in the `[capabilities]` section of `ambient.toml`.
+pub async fn write_data(path: impl AsRef<str>, data: &[u8]) -> anyhow::Result<()> {
+    request(wit::server_io::write_data(path.as_ref(), data))
+        .await
+        .map(|_| ())
+}
+
+/// Sends a HTTP GET request to `url` and returns the body of the response.
+///
+/// The URL's host must be listed in `http` in the `[capabilities]` section of `ambient.toml`.
+pub async fn http_get(url: impl AsRef<str>) -> anyhow::Result<Vec<u8>> {
+    request(wit::server_io::http_get(url.as_ref())).await
+}
+
+/// Sends a HTTP POST request with `body` to `url` and returns the body of the response.
+///
+/// The URL's host must be listed in `http` in the `[capabilities]` section of `ambient.toml`.
+pub async fn http_post(url: impl AsRef<str>, body: &[u8]) -> anyhow::Result<Vec<u8>> {
+    request(wit::server_io::http_post(url.as_ref(), body)).await
+}
+
+async fn request(request: Result<u64, String>) -> anyhow::Result<Vec<u8>> {
+    let request = request.map_err(anyhow::Error::msg)?;
+    std::future::poll_fn(move |_cx| match wit::server_io::poll(request) {
+        Some(result) => Poll::Ready(result.map_err(anyhow::Error::msg)),
+        None => Poll::Pending,
+    })
+    .await
+}
@@ -21,9 +21,18 @@ pub mod global;
 /// Player-related functionality.
 pub mod player;
 
+/// Sound playback, including stopping sounds and changing their gain, pitch and filtering while they play.
+#[cfg(feature = "client")]
+pub mod audio;
+/// Sandboxed asynchronous file and HTTP access, gated by the `[capabilities]` section of `ambient.toml`.
+#[cfg(feature = "server")]
+pub mod io;
 /// Physics-related functionality, including applying forces, changing physical properties, and more.
 #[cfg(feature = "server")]
 pub mod physics;
+/// Persistent key-value storage that is kept between server restarts. Each project has its own storage.
+#[cfg(feature = "server")]
+pub mod storage;
 
 /// Helpful imports that almost all Ambient projects will use.
 pub mod prelude;
@@ -1,5 +1,5 @@
 use crate::{
-    global::{EntityId, Vec3},
+    global::{EntityId, Quat, Vec3},
     internal::{
         conversion::{FromBindgen, IntoBindgen},
         wit,
@@ -58,6 +58,14 @@ pub fn stop_motor(entity: EntityId) {
     wit::server_physics::stop_motor(entity.into_bindgen())
 }
 
+/// The mask of the `default` collision layer, which entities without a `collision_layer` component are in.
+///
+/// The layers declared in the `[physics]` section of `ambient.toml` are available in the `collision_layers` module
+/// generated for your project.
+pub const DEFAULT_COLLISION_LAYER: u32 = 1;
+/// A mask of every collision layer.
+pub const ALL_COLLISION_LAYERS: u32 = u32::MAX;
+
 /// Where a [raycast] hit.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct RaycastHit {
@@ -72,7 +80,14 @@ pub struct RaycastHit {
 ///
 /// `direction` must be normalized.
 pub fn raycast(origin: Vec3, direction: Vec3) -> Vec<RaycastHit> {
-    wit::server_physics::raycast(origin.into_bindgen(), direction.into_bindgen())
+    raycast_impl(origin, direction, None)
+}
+/// Like [raycast], but only hits entities in the collision `layers`, which is a mask.
+pub fn raycast_in_layers(origin: Vec3, direction: Vec3, layers: u32) -> Vec<RaycastHit> {
+    raycast_impl(origin, direction, Some(layers))
+}
+fn raycast_impl(origin: Vec3, direction: Vec3, layers: Option<u32>) -> Vec<RaycastHit> {
+    wit::server_physics::raycast(origin.into_bindgen(), direction.into_bindgen(), layers)
         .into_iter()
         .map(|(entity, distance)| raycast_result_to_hit(origin, direction, entity, distance))
         .collect()
@@ -81,7 +96,14 @@ pub fn raycast(origin: Vec3, direction: Vec3) -> Vec<RaycastHit> {
 ///
 /// `direction` must be normalized.
 pub fn raycast_first(origin: Vec3, direction: Vec3) -> Option<RaycastHit> {
-    wit::server_physics::raycast_first(origin.into_bindgen(), direction.into_bindgen())
+    raycast_first_impl(origin, direction, None)
+}
+/// Like [raycast_first], but only hits entities in the collision `layers`, which is a mask.
+pub fn raycast_first_in_layers(origin: Vec3, direction: Vec3, layers: u32) -> Option<RaycastHit> {
+    raycast_first_impl(origin, direction, Some(layers))
+}
+fn raycast_first_impl(origin: Vec3, direction: Vec3, layers: Option<u32>) -> Option<RaycastHit> {
+    wit::server_physics::raycast_first(origin.into_bindgen(), direction.into_bindgen(), layers)
         .map(|(entity, distance)| raycast_result_to_hit(origin, direction, entity, distance))
 }
 fn raycast_result_to_hit(
@@ -114,12 +136,32 @@ pub fn move_character(
     displacement: Vec3,
     min_dist: f32,
     elapsed_time: f32,
+) -> CharacterCollision {
+    move_character_impl(entity, displacement, min_dist, elapsed_time, None)
+}
+/// Like [move_character], but the character only collides with entities in the collision `layers`, which is a mask.
+pub fn move_character_in_layers(
+    entity: EntityId,
+    displacement: Vec3,
+    min_dist: f32,
+    elapsed_time: f32,
+    layers: u32,
+) -> CharacterCollision {
+    move_character_impl(entity, displacement, min_dist, elapsed_time, Some(layers))
+}
+fn move_character_impl(
+    entity: EntityId,
+    displacement: Vec3,
+    min_dist: f32,
+    elapsed_time: f32,
+    layers: Option<u32>,
 ) -> CharacterCollision {
     let res = wit::server_physics::move_character(
         entity.into_bindgen(),
         displacement.into_bindgen(),
         min_dist,
         elapsed_time,
+        layers,
     );
     CharacterCollision {
         side: res.side,
@@ -127,3 +169,271 @@ pub fn move_character(
         down: res.down,
     }
 }
+
+/// The collider scene a query should be restricted to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColliderScene {
+    /// Colliders that take part in the physics simulation.
+    Physics,
+    /// Trigger area colliders.
+    TriggerArea,
+    /// Colliders that only exist for picking.
+    Picking,
+}
+impl IntoBindgen for ColliderScene {
+    type Item = wit::server_physics::ColliderScene;
+    fn into_bindgen(self) -> Self::Item {
+        match self {
+            Self::Physics => Self::Item::Physics,
+            Self::TriggerArea => Self::Item::TriggerArea,
+            Self::Picking => Self::Item::Picking,
+        }
+    }
+}
+
+/// The shape used by [sweep] and [overlap].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QueryShape {
+    /// A sphere with the given radius.
+    Sphere(f32),
+    /// A box with the given half extents.
+    Cuboid(Vec3),
+    /// A capsule along the X axis.
+    Capsule {
+        /// The radius of the capsule.
+        radius: f32,
+        /// Half of the length of the capsule's cylindrical section.
+        half_height: f32,
+    },
+}
+impl IntoBindgen for QueryShape {
+    type Item = wit::server_physics::QueryShape;
+    fn into_bindgen(self) -> Self::Item {
+        match self {
+            Self::Sphere(radius) => Self::Item::Sphere(radius),
+            Self::Cuboid(half_extents) => Self::Item::Cuboid(half_extents.into_bindgen()),
+            Self::Capsule {
+                radius,
+                half_height,
+            } => Self::Item::Capsule(wit::server_physics::CapsuleShape {
+                radius,
+                half_height,
+            }),
+        }
+    }
+}
+
+/// Where a [sweep] hit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SweepHit {
+    /// The entity that was hit.
+    pub entity: EntityId,
+    /// The position of the hit.
+    pub position: Vec3,
+    /// The normal of the surface that was hit.
+    pub normal: Vec3,
+    /// The distance the shape travelled before the hit.
+    pub distance: f32,
+}
+
+/// Sweeps `shape` from `position` with `rotation` along `direction`, up to `max_distance`, and returns the
+/// [SweepHit]s along the way, sorted by distance.
+///
+/// `direction` must be normalized. If `scene` is `None`, all collider scenes are queried.
+pub fn sweep(
+    shape: QueryShape,
+    position: Vec3,
+    rotation: Quat,
+    direction: Vec3,
+    max_distance: f32,
+    scene: Option<ColliderScene>,
+) -> Vec<SweepHit> {
+    sweep_impl(
+        shape,
+        position,
+        rotation,
+        direction,
+        max_distance,
+        scene,
+        None,
+    )
+}
+/// Like [sweep], but only hits entities in the collision `layers`, which is a mask.
+pub fn sweep_in_layers(
+    shape: QueryShape,
+    position: Vec3,
+    rotation: Quat,
+    direction: Vec3,
+    max_distance: f32,
+    scene: Option<ColliderScene>,
+    layers: u32,
+) -> Vec<SweepHit> {
+    sweep_impl(
+        shape,
+        position,
+        rotation,
+        direction,
+        max_distance,
+        scene,
+        Some(layers),
+    )
+}
+fn sweep_impl(
+    shape: QueryShape,
+    position: Vec3,
+    rotation: Quat,
+    direction: Vec3,
+    max_distance: f32,
+    scene: Option<ColliderScene>,
+    layers: Option<u32>,
+) -> Vec<SweepHit> {
+    wit::server_physics::sweep(
+        shape.into_bindgen(),
+        position.into_bindgen(),
+        rotation.into_bindgen(),
+        direction.into_bindgen(),
+        max_distance,
+        scene.into_bindgen(),
+        layers,
+    )
+    .into_iter()
+    .map(|hit| SweepHit {
+        entity: hit.entity.from_bindgen(),
+        position: hit.position.from_bindgen(),
+        normal: hit.normal.from_bindgen(),
+        distance: hit.distance,
+    })
+    .collect()
+}
+
+/// Returns all entities whose colliders overlap `shape` placed at `position` with `rotation`.
+///
+/// If `scene` is `None`, all collider scenes are queried.
+pub fn overlap(
+    shape: QueryShape,
+    position: Vec3,
+    rotation: Quat,
+    scene: Option<ColliderScene>,
+) -> Vec<EntityId> {
+    overlap_impl(shape, position, rotation, scene, None)
+}
+/// Like [overlap], but only returns entities in the collision `layers`, which is a mask.
+pub fn overlap_in_layers(
+    shape: QueryShape,
+    position: Vec3,
+    rotation: Quat,
+    scene: Option<ColliderScene>,
+    layers: u32,
+) -> Vec<EntityId> {
+    overlap_impl(shape, position, rotation, scene, Some(layers))
+}
+fn overlap_impl(
+    shape: QueryShape,
+    position: Vec3,
+    rotation: Quat,
+    scene: Option<ColliderScene>,
+    layers: Option<u32>,
+) -> Vec<EntityId> {
+    wit::server_physics::overlap(
+        shape.into_bindgen(),
+        position.into_bindgen(),
+        rotation.into_bindgen(),
+        scene.into_bindgen(),
+        layers,
+    )
+    .from_bindgen()
+}
+
+/// The type of joint to create with [create_joint].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JointType {
+    /// Locks all relative movement.
+    Fixed,
+    /// Allows rotation around the joint's X axis.
+    Revolute,
+    /// Allows sliding along the joint's X axis.
+    Prismatic,
+    /// Allows rotation around the joint's origin.
+    Spherical,
+    /// Keeps the entities within a distance of each other.
+    Distance,
+}
+impl IntoBindgen for JointType {
+    type Item = wit::server_physics::JointType;
+    fn into_bindgen(self) -> Self::Item {
+        match self {
+            Self::Fixed => Self::Item::Fixed,
+            Self::Revolute => Self::Item::Revolute,
+            Self::Prismatic => Self::Item::Prismatic,
+            Self::Spherical => Self::Item::Spherical,
+            Self::Distance => Self::Item::Distance,
+        }
+    }
+}
+
+/// Creates a joint of `joint_type` between `entity0` and `entity1`, or between `entity0` and the world if `entity1`
+/// is `None`. The joint is placed at `position` with `rotation` in world space.
+///
+/// At least one of the entities must be a dynamic physics object. Returns the entity that holds the joint,
+/// which can be passed to [break_joint].
+pub fn create_joint(
+    joint_type: JointType,
+    entity0: EntityId,
+    entity1: Option<EntityId>,
+    position: Vec3,
+    rotation: Quat,
+) -> EntityId {
+    wit::server_physics::create_joint(
+        joint_type.into_bindgen(),
+        entity0.into_bindgen(),
+        entity1.into_bindgen(),
+        position.into_bindgen(),
+        rotation.into_bindgen(),
+    )
+    .from_bindgen()
+}
+
+/// Breaks the `joint` stored on an entity. Entities returned by [create_joint] are despawned, while other
+/// entities only lose their joint.
+pub fn break_joint(joint: EntityId) {
+    wit::server_physics::break_joint(joint.into_bindgen())
+}
+
+/// Sets the linear velocity (meters/second) of a dynamic `entity` immediately.
+pub fn set_linear_velocity(entity: EntityId, velocity: Vec3) {
+    wit::server_physics::set_linear_velocity(entity.into_bindgen(), velocity.into_bindgen())
+}
+
+/// Sets the angular velocity (radians/second) of a dynamic `entity` immediately.
+pub fn set_angular_velocity(entity: EntityId, velocity: Vec3) {
+    wit::server_physics::set_angular_velocity(entity.into_bindgen(), velocity.into_bindgen())
+}
+
+/// A contact point returned by [get_contacts].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Contact {
+    /// The entity that was touched.
+    pub other: EntityId,
+    /// The position of the contact.
+    pub position: Vec3,
+    /// The contact normal, pointing towards the entity the contacts were requested for.
+    pub normal: Vec3,
+    /// The separation of the shapes at the contact; negative values indicate penetration.
+    pub separation: f32,
+    /// The impulse applied at the contact.
+    pub impulse: Vec3,
+}
+
+/// Returns the contact points of `entity` from the last physics step.
+pub fn get_contacts(entity: EntityId) -> Vec<Contact> {
+    wit::server_physics::get_contacts(entity.into_bindgen())
+        .into_iter()
+        .map(|contact| Contact {
+            other: contact.other.from_bindgen(),
+            position: contact.position.from_bindgen(),
+            normal: contact.normal.from_bindgen(),
+            separation: contact.separation,
+            impulse: contact.impulse.from_bindgen(),
+        })
+        .collect()
+}
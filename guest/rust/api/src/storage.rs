@@ -0,0 +1,36 @@
+use crate::internal::wit;
+
+/// Gets the value stored under `key`, if any.
+pub fn get(key: impl AsRef<str>) -> Option<Vec<u8>> {
+    wit::server_storage::get(key.as_ref())
+}
+
+/// Stores `value` under `key`, replacing any existing value.
+pub fn set(key: impl AsRef<str>, value: &[u8]) -> anyhow::Result<()> {
+    wit::server_storage::set(key.as_ref(), value).map_err(anyhow::Error::msg)
+}
+
+/// Removes the value stored under `key`, if any.
+pub fn delete(key: impl AsRef<str>) -> anyhow::Result<()> {
+    wit::server_storage::delete(key.as_ref()).map_err(anyhow::Error::msg)
+}
+
+/// Returns every key that starts with `prefix`, in sorted order.
+///
+/// Keys can be structured with a separator to make this useful, e.g. `players/<id>/score`.
+pub fn list_prefix(prefix: impl AsRef<str>) -> Vec<String> {
+    wit::server_storage::list_prefix(prefix.as_ref())
+}
+
+/// Applies several writes at once: either all of them are stored, or none are.
+///
+/// A value of `None` deletes the key.
+pub fn write_batch<'a>(
+    writes: impl IntoIterator<Item = (&'a str, Option<&'a [u8]>)>,
+) -> anyhow::Result<()> {
+    let writes: Vec<_> = writes
+        .into_iter()
+        .map(|(key, value)| wit::server_storage::BatchWrite { key, value })
+        .collect();
+    wit::server_storage::write_batch(&writes).map_err(anyhow::Error::msg)
+}
@@ -0,0 +1,187 @@
+//! # The Ambient asset pipeline processor API
+//!
+//! Processors are Rust packages in your project that are run by `Custom` asset pipelines at build time, to turn files
+//! that Ambient does not understand into assets, i.e. to convert CSV files to component data or to bake textures.
+//!
+//! A processor is a binary that declares its processing function with [processor!]:
+//!
+//! ```ignore
+//! use ambient_pipeline_api as pipeline;
+//!
+//! fn process(_config: String) -> pipeline::anyhow::Result<()> {
+//!     for file in pipeline::files().into_iter().filter(|file| file.ends_with(".csv")) {
+//!         let contents = pipeline::read_file(&file)?;
+//!         let path = format!("{file}.json");
+//!         pipeline::write_file(&path, &convert(&contents)?)?;
+//!         let asset = pipeline::OutAsset::new(path, pipeline::AssetType::Prefab, &file);
+//!         pipeline::add_asset(asset.source(&file))?;
+//!     }
+//!     Ok(())
+//! }
+//!
+//! pipeline::processor!(process);
+//! ```
+#![deny(missing_docs)]
+
+/// Internal implementation details.
+#[doc(hidden)]
+pub mod internal {
+    #![allow(warnings, unused, clippy::all)]
+
+    include!("processor.rs");
+
+    extern "Rust" {
+        fn __ambient_pipeline_process(config: String) -> anyhow::Result<()>;
+    }
+
+    struct Processor;
+    impl pipeline_guest::PipelineGuest for Processor {
+        fn process(config: String) -> Result<(), String> {
+            unsafe { __ambient_pipeline_process(config) }.map_err(|err| format!("{err:?}"))
+        }
+    }
+
+    export_processor!(Processor);
+}
+
+use internal::pipeline_host;
+
+/// Re-exports from other crates.
+pub use anyhow;
+
+/// Declares the function that processes the files of the pipeline. It is called once per build of the pipeline, with
+/// the pipeline's `config` as a JSON string.
+///
+/// This also declares the `main` function of the processor, so it should be used at the root of the binary.
+#[macro_export]
+macro_rules! processor {
+    ($process:path) => {
+        fn main() {}
+
+        #[no_mangle]
+        pub fn __ambient_pipeline_process(config: String) -> $crate::anyhow::Result<()> {
+            $process(config)
+        }
+    };
+}
+
+/// Returns the paths of the input files of the pipeline, relative to the folder of its `pipeline.json`.
+pub fn files() -> Vec<String> {
+    pipeline_host::files()
+}
+
+/// Reads an input file of the pipeline. `path` is relative to the folder of its `pipeline.json`.
+pub fn read_file(path: &str) -> anyhow::Result<Vec<u8>> {
+    pipeline_host::read_file(path).map_err(|err| anyhow::anyhow!(err))
+}
+
+/// Writes a file to the output of the pipeline. `path` is relative to the pipeline's output folder, which mirrors the
+/// folder of its `pipeline.json`.
+pub fn write_file(path: &str, contents: &[u8]) -> anyhow::Result<()> {
+    pipeline_host::write_file(path, contents).map_err(|err| anyhow::anyhow!(err))
+}
+
+/// The type of an asset produced by the processor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AssetType {
+    /// A model crate, which bundles the models, prefabs, materials and animations imported from a file.
+    AssetCrate,
+    /// A prefab that can be spawned.
+    Prefab,
+    /// A bundle of scripts.
+    ScriptBundle,
+    /// A model.
+    Model,
+    /// An image.
+    Image,
+    /// An animation.
+    Animation,
+    /// A material.
+    Material,
+    /// A collider.
+    Collider,
+    /// A terrain material.
+    TerrainMaterial,
+    /// An atmosphere.
+    Atmosphere,
+    /// A set of biomes.
+    Biomes,
+    /// An Ogg Vorbis audio track.
+    VorbisTrack,
+    /// A sound graph.
+    SoundGraph,
+}
+impl AssetType {
+    /// The name of the asset type in `snake_case`, as it appears in the asset catalog.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::AssetCrate => "asset_crate",
+            Self::Prefab => "prefab",
+            Self::ScriptBundle => "script_bundle",
+            Self::Model => "model",
+            Self::Image => "image",
+            Self::Animation => "animation",
+            Self::Material => "material",
+            Self::Collider => "collider",
+            Self::TerrainMaterial => "terrain_material",
+            Self::Atmosphere => "atmosphere",
+            Self::Biomes => "biomes",
+            Self::VorbisTrack => "vorbis_track",
+            Self::SoundGraph => "sound_graph",
+        }
+    }
+}
+
+/// An asset produced by the processor, which will be listed in the project's asset catalog.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OutAsset {
+    /// The path of the asset's content, as written with [write_file].
+    pub path: String,
+    /// The type of the asset.
+    pub asset_type: AssetType,
+    /// A human-friendly name for this asset.
+    pub name: String,
+    /// Tags to apply to the asset, in addition to those of the pipeline.
+    pub tags: Vec<String>,
+    /// The input file this asset was built from.
+    pub source: Option<String>,
+}
+impl OutAsset {
+    /// Creates an asset with no tags or source.
+    pub fn new(path: impl Into<String>, asset_type: AssetType, name: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            asset_type,
+            name: name.into(),
+            tags: Vec::new(),
+            source: None,
+        }
+    }
+    /// Adds a tag to the asset.
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+    /// Sets the input file the asset was built from.
+    pub fn source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+}
+
+/// Adds an asset to the output of the pipeline. Its content must already have been written with [write_file].
+pub fn add_asset(asset: OutAsset) -> anyhow::Result<()> {
+    let tags = asset
+        .tags
+        .iter()
+        .map(|tag| tag.as_str())
+        .collect::<Vec<_>>();
+    pipeline_host::add_asset(
+        &asset.path,
+        asset.asset_type.as_str(),
+        &asset.name,
+        &tags,
+        asset.source.as_deref(),
+    )
+    .map_err(|err| anyhow::anyhow!(err))
+}
@@ -0,0 +1,311 @@
+// Generated by `wit-bindgen` 0.4.0. DO NOT EDIT!
+
+#[allow(clippy::all)]
+pub mod pipeline_host{
+  #[allow(clippy::all)]
+  pub fn files() -> wit_bindgen::rt::vec::Vec::<wit_bindgen::rt::string::String>{
+    
+    #[allow(unused_imports)]
+    use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+    unsafe {
+      
+      #[repr(align(4))]
+      struct RetArea([u8; 8]);
+      let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
+      let ptr0 = ret_area.as_mut_ptr() as i32;
+      #[link(wasm_import_module = "pipeline-host")]
+      extern "C" {
+        #[cfg_attr(target_arch = "wasm32", link_name = "files")]
+        #[cfg_attr(not(target_arch = "wasm32"), link_name = "pipeline-host_files")]
+        fn wit_import(
+        _: i32, );
+      }
+      wit_import(ptr0);
+      let base2 = *((ptr0 + 0) as *const i32);
+      let len2 = *((ptr0 + 4) as *const i32);
+      let mut result2 = Vec::with_capacity(len2 as usize);
+      for i in 0..len2 {
+        let base = base2 + i *8;
+        result2.push({
+          let len1 = *((base + 4) as *const i32) as usize;
+          
+          {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len1, len1))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((base + 0) as *const i32) as *mut _, len1, len1)).unwrap()}}
+        });
+      }
+      wit_bindgen::rt::dealloc(base2, (len2 as usize) * 8, 4);
+      result2
+    }
+  }
+  #[allow(clippy::all)]
+  pub fn read_file(path: &str,) -> Result<wit_bindgen::rt::vec::Vec::<u8>,wit_bindgen::rt::string::String>{
+    
+    #[allow(unused_imports)]
+    use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+    unsafe {
+      
+      #[repr(align(4))]
+      struct RetArea([u8; 12]);
+      let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
+      let vec0 = path;
+      let ptr0 = vec0.as_ptr() as i32;
+      let len0 = vec0.len() as i32;
+      let ptr1 = ret_area.as_mut_ptr() as i32;
+      #[link(wasm_import_module = "pipeline-host")]
+      extern "C" {
+        #[cfg_attr(target_arch = "wasm32", link_name = "read-file")]
+        #[cfg_attr(not(target_arch = "wasm32"), link_name = "pipeline-host_read-file")]
+        fn wit_import(
+        _: i32, _: i32, _: i32, );
+      }
+      wit_import(ptr0, len0, ptr1);
+      match i32::from(*((ptr1 + 0) as *const u8)) {
+        0 => Ok({
+          let len2 = *((ptr1 + 8) as *const i32) as usize;
+          
+          Vec::from_raw_parts(*((ptr1 + 4) as *const i32) as *mut _, len2, len2)
+        }),
+        1 => Err({
+          let len3 = *((ptr1 + 8) as *const i32) as usize;
+          
+          {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((ptr1 + 4) as *const i32) as *mut _, len3, len3))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((ptr1 + 4) as *const i32) as *mut _, len3, len3)).unwrap()}}
+        }),
+        #[cfg(not(debug_assertions))]
+        _ => core::hint::unreachable_unchecked(),
+        #[cfg(debug_assertions)]
+        _ => panic!("invalid enum discriminant"),
+      }
+    }
+  }
+  #[allow(clippy::all)]
+  pub fn write_file(path: &str,contents: &[u8],) -> Result<(),wit_bindgen::rt::string::String>{
+    
+    #[allow(unused_imports)]
+    use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+    unsafe {
+      
+      #[repr(align(4))]
+      struct RetArea([u8; 12]);
+      let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
+      let vec0 = path;
+      let ptr0 = vec0.as_ptr() as i32;
+      let len0 = vec0.len() as i32;
+      let vec1 = contents;
+      let ptr1 = vec1.as_ptr() as i32;
+      let len1 = vec1.len() as i32;
+      let ptr2 = ret_area.as_mut_ptr() as i32;
+      #[link(wasm_import_module = "pipeline-host")]
+      extern "C" {
+        #[cfg_attr(target_arch = "wasm32", link_name = "write-file")]
+        #[cfg_attr(not(target_arch = "wasm32"), link_name = "pipeline-host_write-file")]
+        fn wit_import(
+        _: i32, _: i32, _: i32, _: i32, _: i32, );
+      }
+      wit_import(ptr0, len0, ptr1, len1, ptr2);
+      match i32::from(*((ptr2 + 0) as *const u8)) {
+        0 => Ok(()),
+        1 => Err({
+          let len3 = *((ptr2 + 8) as *const i32) as usize;
+          
+          {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((ptr2 + 4) as *const i32) as *mut _, len3, len3))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((ptr2 + 4) as *const i32) as *mut _, len3, len3)).unwrap()}}
+        }),
+        #[cfg(not(debug_assertions))]
+        _ => core::hint::unreachable_unchecked(),
+        #[cfg(debug_assertions)]
+        _ => panic!("invalid enum discriminant"),
+      }
+    }
+  }
+  #[allow(clippy::all)]
+  pub fn add_asset(path: &str,asset_type: &str,name: &str,tags: &[&str],source: Option<&str>,) -> Result<(),wit_bindgen::rt::string::String>{
+    
+    #[allow(unused_imports)]
+    use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+    unsafe {
+      
+      #[repr(align(4))]
+      struct RetArea([u8; 12]);
+      let mut ret_area = core::mem::MaybeUninit::<RetArea>::uninit();
+      let vec0 = path;
+      let ptr0 = vec0.as_ptr() as i32;
+      let len0 = vec0.len() as i32;
+      let vec1 = asset_type;
+      let ptr1 = vec1.as_ptr() as i32;
+      let len1 = vec1.len() as i32;
+      let vec2 = name;
+      let ptr2 = vec2.as_ptr() as i32;
+      let len2 = vec2.len() as i32;
+      let vec4 = tags;
+      let len4 = vec4.len() as i32;
+      let layout4 = alloc::Layout::from_size_align_unchecked(vec4.len() * 8, 4);
+      let result4 = if layout4.size() != 0
+      {
+        let ptr = alloc::alloc(layout4);
+        if ptr.is_null()
+        {
+          alloc::handle_alloc_error(layout4);
+        }
+        ptr
+      }else {
+        core::ptr::null_mut()
+      };
+      for (i, e) in vec4.into_iter().enumerate() {
+        let base = result4 as i32 + (i as i32) * 8;
+        {
+          let vec3 = e;
+          let ptr3 = vec3.as_ptr() as i32;
+          let len3 = vec3.len() as i32;
+          *((base + 4) as *mut i32) = len3;
+          *((base + 0) as *mut i32) = ptr3;
+          
+        }}
+        let (result6_0,result6_1,result6_2,) = match source {
+          Some(e) => {
+            let vec5 = e;
+            let ptr5 = vec5.as_ptr() as i32;
+            let len5 = vec5.len() as i32;
+            
+            (1i32, ptr5, len5)
+          },
+          None => {
+            (0i32, 0i32, 0i32)
+          },
+        };let ptr7 = ret_area.as_mut_ptr() as i32;
+        #[link(wasm_import_module = "pipeline-host")]
+        extern "C" {
+          #[cfg_attr(target_arch = "wasm32", link_name = "add-asset")]
+          #[cfg_attr(not(target_arch = "wasm32"), link_name = "pipeline-host_add-asset")]
+          fn wit_import(
+          _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, _: i32, );
+        }
+        wit_import(ptr0, len0, ptr1, len1, ptr2, len2, result4 as i32, len4, result6_0, result6_1, result6_2, ptr7);
+        if layout4.size() != 0 {
+          alloc::dealloc(result4, layout4);
+        }
+        match i32::from(*((ptr7 + 0) as *const u8)) {
+          0 => Ok(()),
+          1 => Err({
+            let len8 = *((ptr7 + 8) as *const i32) as usize;
+            
+            {#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(*((ptr7 + 4) as *const i32) as *mut _, len8, len8))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(*((ptr7 + 4) as *const i32) as *mut _, len8, len8)).unwrap()}}
+          }),
+          #[cfg(not(debug_assertions))]
+          _ => core::hint::unreachable_unchecked(),
+          #[cfg(debug_assertions)]
+          _ => panic!("invalid enum discriminant"),
+        }
+      }
+    }
+    
+  }
+  
+  
+  #[allow(clippy::all)]
+  pub mod pipeline_guest{
+    pub trait PipelineGuest {
+      fn process(config: wit_bindgen::rt::string::String,) -> Result<(),wit_bindgen::rt::string::String>;
+    }
+    
+    #[doc(hidden)]
+    pub unsafe fn call_process<T: PipelineGuest>(arg0: i32,arg1: i32,) -> i32 {
+      
+      #[allow(unused_imports)]
+      use wit_bindgen::rt::{alloc, vec::Vec, string::String};
+      
+      // Before executing any other code, use this function to run all static
+      // constructors, if they have not yet been run. This is a hack required
+      // to work around wasi-libc ctors calling import functions to initialize
+      // the environment.
+      //
+      // This functionality will be removed once rust 1.69.0 is stable, at which
+      // point wasi-libc will no longer have this behavior.
+      //
+      // See
+      // https://github.com/bytecodealliance/preview2-prototyping/issues/99
+      // for more details.
+      #[cfg(target_arch="wasm32")]
+      wit_bindgen::rt::run_ctors_once();
+      
+      let len0 = arg1 as usize;
+      let result1 = T::process({#[cfg(not(debug_assertions))]{String::from_utf8_unchecked(Vec::from_raw_parts(arg0 as *mut _, len0, len0))}#[cfg(debug_assertions)]{String::from_utf8(Vec::from_raw_parts(arg0 as *mut _, len0, len0)).unwrap()}});
+      let ptr2 = _RET_AREA.0.as_mut_ptr() as i32;
+      match result1 {
+        Ok(_) => { {
+          *((ptr2 + 0) as *mut u8) = (0i32) as u8;
+          
+        } },
+        Err(e) => { {
+          *((ptr2 + 0) as *mut u8) = (1i32) as u8;
+          let vec3 = (e.into_bytes()).into_boxed_slice();
+          let ptr3 = vec3.as_ptr() as i32;
+          let len3 = vec3.len() as i32;
+          core::mem::forget(vec3);
+          *((ptr2 + 8) as *mut i32) = len3;
+          *((ptr2 + 4) as *mut i32) = ptr3;
+          
+        } },
+      };ptr2
+    }
+    
+    #[doc(hidden)]
+    pub unsafe fn post_return_process<T: PipelineGuest>(arg0: i32,) {
+      match i32::from(*((arg0 + 0) as *const u8)) {
+        0 => (),
+        _ => {
+          wit_bindgen::rt::dealloc(*((arg0 + 4) as *const i32), (*((arg0 + 8) as *const i32)) as usize, 1);
+          
+        },
+      }
+    }
+    
+    #[allow(unused_imports)]
+    use wit_bindgen::rt::{{alloc, vec::Vec, string::String}};
+    
+    #[repr(align(4))]
+    struct _RetArea([u8; 12]);
+    static mut _RET_AREA: _RetArea = _RetArea([0; 12]);
+    
+  }
+  
+  
+  /// Declares the export of the component's world for the
+  /// given type.
+  
+  macro_rules! export_processor(($t:ident) => {
+    const _: () = {
+      
+      #[doc(hidden)]
+      #[export_name = "pipeline-guest#process"]
+      #[allow(non_snake_case)]
+      unsafe extern "C" fn __export_pipeline_guest_process(arg0: i32,arg1: i32,) -> i32 {
+        pipeline_guest::call_process::<$t>(arg0,arg1,)
+      }
+      
+      #[doc(hidden)]
+      #[export_name = "cabi_post_pipeline-guest#process"]
+      #[allow(non_snake_case)]
+      unsafe extern "C" fn __post_return_pipeline_guest_process(arg0: i32,) {
+        pipeline_guest::post_return_process::<$t>(arg0,)
+      }
+      
+    };
+    
+    #[used]
+    #[doc(hidden)]
+    #[cfg(target_arch = "wasm32")]
+    static __FORCE_SECTION_REF: fn() = __force_section_ref;
+    #[doc(hidden)]
+    #[cfg(target_arch = "wasm32")]
+    fn __force_section_ref() {
+      __link_section()
+    }
+  });
+  
+  #[cfg(target_arch = "wasm32")]
+  #[link_section = "component-type:processor"]
+  #[doc(hidden)]pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 18608] = [2, 0, 3, 119, 105, 116, 8, 112, 105, 112, 101, 108, 105, 110, 101, 9, 112, 114, 111, 99, 101, 115, 115, 111, 114, 0, 97, 115, 109, 12, 0, 1, 0, 7, 245, 1, 1, 65, 2, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 4, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 11, 21, 1, 5, 116, 121, 112, 101, 115, 10, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 3, 0, 0, 7, 221, 12, 1, 65, 6, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 3, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 2, 3, 0, 0, 4, 118, 101, 99, 50, 2, 3, 0, 0, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 66, 16, 2, 3, 2, 1, 1, 4, 4, 118, 101, 99, 50, 0, 3, 0, 0, 2, 3, 2, 1, 2, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 2, 1, 109, 163, 1, 4, 107, 101, 121, 49, 4, 107, 101, 121, 50, 4, 107, 101, 121, 51, 4, 107, 101, 121, 52, 4, 107, 101, 121, 53, 4, 107, 101, 121, 54, 4, 107, 101, 121, 55, 4, 107, 101, 121, 56, 4, 107, 101, 121, 57, 4, 107, 101, 121, 48, 1, 97, 1, 98, 1, 99, 1, 100, 1, 101, 1, 102, 1, 103, 1, 104, 1, 105, 1, 106, 1, 107, 1, 108, 1, 109, 1, 110, 1, 111, 1, 112, 1, 113, 1, 114, 1, 115, 1, 116, 1, 117, 1, 118, 1, 119, 1, 120, 1, 121, 1, 122, 6, 101, 115, 99, 97, 112, 101, 2, 102, 49, 2, 102, 50, 2, 102, 51, 2, 102, 52, 2, 102, 53, 2, 102, 54, 2, 102, 55, 2, 102, 56, 2, 102, 57, 3, 102, 49, 48, 3, 102, 49, 49, 3, 102, 49, 50, 3, 102, 49, 51, 3, 102, 49, 52, 3, 102, 49, 53, 3, 102, 49, 54, 3, 102, 49, 55, 3, 102, 49, 56, 3, 102, 49, 57, 3, 102, 50, 48, 3, 102, 50, 49, 3, 102, 50, 50, 3, 102, 50, 51, 3, 102, 50, 52, 8, 115, 110, 97, 112, 115, 104, 111, 116, 6, 115, 99, 114, 111, 108, 108, 5, 112, 97, 117, 115, 101, 6, 105, 110, 115, 101, 114, 116, 4, 104, 111, 109, 101, 6, 100, 101, 108, 101, 116, 101, 3, 101, 110, 100, 9, 112, 97, 103, 101, 45, 100, 111, 119, 110, 7, 112, 97, 103, 101, 45, 117, 112, 4, 108, 101, 102, 116, 2, 117, 112, 5, 114, 105, 103, 104, 116, 4, 100, 111, 119, 110, 4, 98, 97, 99, 107, 6, 114, 101, 116, 117, 114, 110, 5, 115, 112, 97, 99, 101, 7, 99, 111, 109, 112, 111, 115, 101, 5, 99, 97, 114, 101, 116, 7, 110, 117, 109, 108, 111, 99, 107, 7, 110, 117, 109, 112, 97, 100, 48, 7, 110, 117, 109, 112, 97, 100, 49, 7, 110, 117, 109, 112, 97, 100, 50, 7, 110, 117, 109, 112, 97, 100, 51, 7, 110, 117, 109, 112, 97, 100, 52, 7, 110, 117, 109, 112, 97, 100, 53, 7, 110, 117, 109, 112, 97, 100, 54, 7, 110, 117, 109, 112, 97, 100, 55, 7, 110, 117, 109, 112, 97, 100, 56, 7, 110, 117, 109, 112, 97, 100, 57, 10, 110, 117, 109, 112, 97, 100, 45, 97, 100, 100, 13, 110, 117, 109, 112, 97, 100, 45, 100, 105, 118, 105, 100, 101, 14, 110, 117, 109, 112, 97, 100, 45, 100, 101, 99, 105, 109, 97, 108, 12, 110, 117, 109, 112, 97, 100, 45, 99, 111, 109, 109, 97, 12, 110, 117, 109, 112, 97, 100, 45, 101, 110, 116, 101, 114, 13, 110, 117, 109, 112, 97, 100, 45, 101, 113, 117, 97, 108, 115, 15, 110, 117, 109, 112, 97, 100, 45, 109, 117, 108, 116, 105, 112, 108, 121, 15, 110, 117, 109, 112, 97, 100, 45, 115, 117, 98, 116, 114, 97, 99, 116, 7, 97, 98, 110, 116, 45, 99, 49, 7, 97, 98, 110, 116, 45, 99, 50, 10, 97, 112, 111, 115, 116, 114, 111, 112, 104, 101, 4, 97, 112, 112, 115, 8, 97, 115, 116, 101, 114, 105, 115, 107, 2, 97, 116, 2, 97, 120, 9, 98, 97, 99, 107, 115, 108, 97, 115, 104, 10, 99, 97, 108, 99, 117, 108, 97, 116, 111, 114, 7, 99, 97, 112, 105, 116, 97, 108, 5, 99, 111, 108, 111, 110, 5, 99, 111, 109, 109, 97, 7, 99, 111, 110, 118, 101, 114, 116, 6, 101, 113, 117, 97, 108, 115, 5, 103, 114, 97, 118, 101, 4, 107, 97, 110, 97, 5, 107, 97, 110, 106, 105, 5, 108, 45, 97, 108, 116, 9, 108, 45, 98, 114, 97, 99, 107, 101, 116, 9, 108, 45, 99, 111, 110, 116, 114, 111, 108, 7, 108, 45, 115, 104, 105, 102, 116, 5, 108, 45, 119, 105, 110, 4, 109, 97, 105, 108, 12, 109, 101, 100, 105, 97, 45, 115, 101, 108, 101, 99, 116, 10, 109, 101, 100, 105, 97, 45, 115, 116, 111, 112, 5, 109, 105, 110, 117, 115, 4, 109, 117, 116, 101, 11, 109, 121, 45, 99, 111, 109, 112, 117, 116, 101, 114, 16, 110, 97, 118, 105, 103, 97, 116, 101, 45, 102, 111, 114, 119, 97, 114, 100, 17, 110, 97, 118, 105, 103, 97, 116, 101, 45, 98, 97, 99, 107, 119, 97, 114, 100, 10, 110, 101, 120, 116, 45, 116, 114, 97, 99, 107, 10, 110, 111, 45, 99, 111, 110, 118, 101, 114, 116, 6, 111, 101, 109, 49, 48, 50, 6, 112, 101, 114, 105, 111, 100, 10, 112, 108, 97, 121, 45, 112, 97, 117, 115, 101, 4, 112, 108, 117, 115, 5, 112, 111, 119, 101, 114, 10, 112, 114, 101, 118, 45, 116, 114, 97, 99, 107, 5, 114, 45, 97, 108, 116, 9, 114, 45, 98, 114, 97, 99, 107, 101, 116, 9, 114, 45, 99, 111, 110, 116, 114, 111, 108, 7, 114, 45, 115, 104, 105, 102, 116, 5, 114, 45, 119, 105, 110, 9, 115, 101, 109, 105, 99, 111, 108, 111, 110, 5, 115, 108, 97, 115, 104, 5, 115, 108, 101, 101, 112, 4, 115, 116, 111, 112, 5, 115, 121, 115, 114, 113, 3, 116, 97, 98, 9, 117, 110, 100, 101, 114, 108, 105, 110, 101, 9, 117, 110, 108, 97, 98, 101, 108, 101, 100, 11, 118, 111, 108, 117, 109, 101, 45, 100, 111, 119, 110, 9, 118, 111, 108, 117, 109, 101, 45, 117, 112, 4, 119, 97, 107, 101, 8, 119, 101, 98, 45, 98, 97, 99, 107, 13, 119, 101, 98, 45, 102, 97, 118, 111, 114, 105, 116, 101, 115, 11, 119, 101, 98, 45, 102, 111, 114, 119, 97, 114, 100, 8, 119, 101, 98, 45, 104, 111, 109, 101, 11, 119, 101, 98, 45, 114, 101, 102, 114, 101, 115, 104, 10, 119, 101, 98, 45, 115, 101, 97, 114, 99, 104, 8, 119, 101, 98, 45, 115, 116, 111, 112, 3, 121, 101, 110, 4, 99, 111, 112, 121, 5, 112, 97, 115, 116, 101, 3, 99, 117, 116, 4, 16, 118, 105, 114, 116, 117, 97, 108, 45, 107, 101, 121, 45, 99, 111, 100, 101, 0, 3, 0, 4, 1, 113, 4, 4, 108, 101, 102, 116, 0, 0, 5, 114, 105, 103, 104, 116, 0, 0, 6, 109, 105, 100, 100, 108, 101, 0, 0, 5, 111, 116, 104, 101, 114, 1, 123, 0, 4, 12, 109, 111, 117, 115, 101, 45, 98, 117, 116, 116, 111, 110, 0, 3, 0, 6, 1, 112, 5, 1, 112, 7, 1, 114, 5, 4, 107, 101, 121, 115, 8, 14, 109, 111, 117, 115, 101, 45, 112, 111, 115, 105, 116, 105, 111, 110, 1, 15, 99, 117, 114, 115, 111, 114, 45, 112, 111, 115, 105, 116, 105, 111, 110, 1, 11, 109, 111, 117, 115, 101, 45, 119, 104, 101, 101, 108, 118, 13, 109, 111, 117, 115, 101, 45, 98, 117, 116, 116, 111, 110, 115, 9, 4, 9, 114, 97, 119, 45, 105, 110, 112, 117, 116, 0, 3, 0, 10, 1, 107, 11, 1, 64, 1, 6, 112, 108, 97, 121, 101, 114, 3, 0, 12, 4, 13, 103, 101, 116, 45, 114, 97, 119, 45, 105, 110, 112, 117, 116, 0, 1, 13, 4, 18, 103, 101, 116, 45, 112, 114, 101, 118, 45, 114, 97, 119, 45, 105, 110, 112, 117, 116, 0, 1, 13, 4, 13, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 32, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 47, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 5, 3, 11, 37, 1, 13, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 18, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 3, 2, 0, 7, 198, 11, 1, 65, 7, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 3, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 2, 3, 0, 0, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 2, 3, 0, 0, 4, 113, 117, 97, 116, 2, 3, 0, 0, 4, 118, 101, 99, 51, 1, 66, 60, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 2, 4, 4, 113, 117, 97, 116, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 118, 101, 99, 51, 0, 3, 0, 4, 1, 114, 3, 4, 115, 105, 100, 101, 127, 2, 117, 112, 127, 4, 100, 111, 119, 110, 127, 4, 19, 99, 104, 97, 114, 97, 99, 116, 101, 114, 45, 99, 111, 108, 108, 105, 115, 105, 111, 110, 0, 3, 0, 6, 1, 109, 3, 7, 112, 104, 121, 115, 105, 99, 115, 12, 116, 114, 105, 103, 103, 101, 114, 45, 97, 114, 101, 97, 7, 112, 105, 99, 107, 105, 110, 103, 4, 14, 99, 111, 108, 108, 105, 100, 101, 114, 45, 115, 99, 101, 110, 101, 0, 3, 0, 8, 1, 114, 2, 6, 114, 97, 100, 105, 117, 115, 118, 11, 104, 97, 108, 102, 45, 104, 101, 105, 103, 104, 116, 118, 4, 13, 99, 97, 112, 115, 117, 108, 101, 45, 115, 104, 97, 112, 101, 0, 3, 0, 10, 1, 113, 3, 6, 115, 112, 104, 101, 114, 101, 1, 118, 0, 6, 99, 117, 98, 111, 105, 100, 1, 5, 0, 7, 99, 97, 112, 115, 117, 108, 101, 1, 11, 0, 4, 11, 113, 117, 101, 114, 121, 45, 115, 104, 97, 112, 101, 0, 3, 0, 12, 1, 114, 4, 6, 101, 110, 116, 105, 116, 121, 1, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 6, 110, 111, 114, 109, 97, 108, 5, 8, 100, 105, 115, 116, 97, 110, 99, 101, 118, 4, 9, 115, 119, 101, 101, 112, 45, 104, 105, 116, 0, 3, 0, 14, 1, 109, 5, 5, 102, 105, 120, 101, 100, 8, 114, 101, 118, 111, 108, 117, 116, 101, 9, 112, 114, 105, 115, 109, 97, 116, 105, 99, 9, 115, 112, 104, 101, 114, 105, 99, 97, 108, 8, 100, 105, 115, 116, 97, 110, 99, 101, 4, 10, 106, 111, 105, 110, 116, 45, 116, 121, 112, 101, 0, 3, 0, 16, 1, 114, 5, 5, 111, 116, 104, 101, 114, 1, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 6, 110, 111, 114, 109, 97, 108, 5, 10, 115, 101, 112, 97, 114, 97, 116, 105, 111, 110, 118, 7, 105, 109, 112, 117, 108, 115, 101, 5, 4, 7, 99, 111, 110, 116, 97, 99, 116, 0, 3, 0, 18, 1, 112, 1, 1, 64, 2, 8, 101, 110, 116, 105, 116, 105, 101, 115, 20, 5, 102, 111, 114, 99, 101, 5, 1, 0, 4, 11, 97, 112, 112, 108, 121, 45, 102, 111, 114, 99, 101, 0, 1, 21, 1, 107, 118, 1, 64, 4, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 5, 102, 111, 114, 99, 101, 118, 6, 114, 97, 100, 105, 117, 115, 118, 14, 102, 97, 108, 108, 111, 102, 102, 45, 114, 97, 100, 105, 117, 115, 22, 1, 0, 4, 12, 101, 120, 112, 108, 111, 100, 101, 45, 98, 111, 109, 98, 0, 1, 23, 1, 64, 1, 7, 103, 114, 97, 118, 105, 116, 121, 5, 1, 0, 4, 11, 115, 101, 116, 45, 103, 114, 97, 118, 105, 116, 121, 0, 1, 24, 1, 64, 1, 6, 101, 110, 116, 105, 116, 121, 1, 1, 0, 4, 8, 117, 110, 102, 114, 101, 101, 122, 101, 0, 1, 25, 4, 6, 102, 114, 101, 101, 122, 101, 0, 1, 25, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 8, 118, 101, 108, 111, 99, 105, 116, 121, 118, 1, 0, 4, 11, 115, 116, 97, 114, 116, 45, 109, 111, 116, 111, 114, 0, 1, 26, 4, 10, 115, 116, 111, 112, 45, 109, 111, 116, 111, 114, 0, 1, 25, 1, 111, 2, 1, 118, 1, 107, 27, 1, 64, 2, 6, 111, 114, 105, 103, 105, 110, 5, 9, 100, 105, 114, 101, 99, 116, 105, 111, 110, 5, 0, 28, 4, 13, 114, 97, 121, 99, 97, 115, 116, 45, 102, 105, 114, 115, 116, 0, 1, 29, 1, 112, 27, 1, 64, 2, 6, 111, 114, 105, 103, 105, 110, 5, 9, 100, 105, 114, 101, 99, 116, 105, 111, 110, 5, 0, 30, 4, 7, 114, 97, 121, 99, 97, 115, 116, 0, 1, 31, 1, 64, 4, 6, 101, 110, 116, 105, 116, 121, 1, 12, 100, 105, 115, 112, 108, 97, 99, 101, 109, 101, 110, 116, 5, 8, 109, 105, 110, 45, 100, 105, 115, 116, 118, 12, 101, 108, 97, 112, 115, 101, 100, 45, 116, 105, 109, 101, 118, 0, 7, 4, 14, 109, 111, 118, 101, 45, 99, 104, 97, 114, 97, 99, 116, 101, 114, 0, 1, 32, 1, 107, 9, 1, 112, 15, 1, 64, 6, 5, 115, 104, 97, 112, 101, 13, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 8, 114, 111, 116, 97, 116, 105, 111, 110, 3, 9, 100, 105, 114, 101, 99, 116, 105, 111, 110, 5, 12, 109, 97, 120, 45, 100, 105, 115, 116, 97, 110, 99, 101, 118, 5, 115, 99, 101, 110, 101, 33, 0, 34, 4, 5, 115, 119, 101, 101, 112, 0, 1, 35, 1, 64, 4, 5, 115, 104, 97, 112, 101, 13, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 8, 114, 111, 116, 97, 116, 105, 111, 110, 3, 5, 115, 99, 101, 110, 101, 33, 0, 20, 4, 7, 111, 118, 101, 114, 108, 97, 112, 0, 1, 36, 1, 107, 1, 1, 64, 5, 10, 106, 111, 105, 110, 116, 45, 116, 121, 112, 101, 17, 7, 101, 110, 116, 105, 116, 121, 48, 1, 7, 101, 110, 116, 105, 116, 121, 49, 37, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 8, 114, 111, 116, 97, 116, 105, 111, 110, 3, 0, 1, 4, 12, 99, 114, 101, 97, 116, 101, 45, 106, 111, 105, 110, 116, 0, 1, 38, 1, 64, 1, 5, 106, 111, 105, 110, 116, 1, 1, 0, 4, 11, 98, 114, 101, 97, 107, 45, 106, 111, 105, 110, 116, 0, 1, 39, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 8, 118, 101, 108, 111, 99, 105, 116, 121, 5, 1, 0, 4, 19, 115, 101, 116, 45, 108, 105, 110, 101, 97, 114, 45, 118, 101, 108, 111, 99, 105, 116, 121, 0, 1, 40, 4, 20, 115, 101, 116, 45, 97, 110, 103, 117, 108, 97, 114, 45, 118, 101, 108, 111, 99, 105, 116, 121, 0, 1, 40, 1, 112, 19, 1, 64, 1, 6, 101, 110, 116, 105, 116, 121, 1, 0, 41, 4, 12, 103, 101, 116, 45, 99, 111, 110, 116, 97, 99, 116, 115, 0, 1, 42, 4, 14, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 34, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 47, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 5, 4, 11, 39, 1, 14, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 19, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 3, 4, 0, 7, 252, 2, 1, 65, 2, 1, 66, 12, 1, 109, 13, 11, 97, 115, 115, 101, 116, 45, 99, 114, 97, 116, 101, 6, 112, 114, 101, 102, 97, 98, 13, 115, 99, 114, 105, 112, 116, 45, 98, 117, 110, 100, 108, 101, 5, 109, 111, 100, 101, 108, 5, 105, 109, 97, 103, 101, 9, 97, 110, 105, 109, 97, 116, 105, 111, 110, 8, 109, 97, 116, 101, 114, 105, 97, 108, 8, 99, 111, 108, 108, 105, 100, 101, 114, 16, 116, 101, 114, 114, 97, 105, 110, 45, 109, 97, 116, 101, 114, 105, 97, 108, 10, 97, 116, 109, 111, 115, 112, 104, 101, 114, 101, 6, 98, 105, 111, 109, 101, 115, 12, 118, 111, 114, 98, 105, 115, 45, 116, 114, 97, 99, 107, 11, 115, 111, 117, 110, 100, 45, 103, 114, 97, 112, 104, 4, 10, 97, 115, 115, 101, 116, 45, 116, 121, 112, 101, 0, 3, 0, 0, 1, 112, 115, 1, 107, 115, 1, 114, 9, 2, 105, 100, 115, 10, 97, 115, 115, 101, 116, 45, 116, 121, 112, 101, 1, 4, 110, 97, 109, 101, 115, 4, 116, 97, 103, 115, 2, 10, 99, 97, 116, 101, 103, 111, 114, 105, 101, 115, 2, 3, 117, 114, 108, 3, 10, 99, 111, 108, 108, 101, 99, 116, 105, 111, 110, 2, 6, 115, 111, 117, 114, 99, 101, 3, 11, 112, 114, 101, 118, 105, 101, 119, 45, 117, 114, 108, 3, 4, 13, 99, 97, 116, 97, 108, 111, 103, 45, 97, 115, 115, 101, 116, 0, 3, 0, 4, 1, 64, 1, 4, 112, 97, 116, 104, 115, 0, 3, 4, 3, 117, 114, 108, 0, 1, 6, 1, 107, 1, 1, 112, 5, 1, 64, 3, 10, 97, 115, 115, 101, 116, 45, 116, 121, 112, 101, 7, 3, 116, 97, 103, 3, 8, 99, 97, 116, 101, 103, 111, 114, 121, 3, 0, 8, 4, 7, 99, 97, 116, 97, 108, 111, 103, 0, 1, 9, 4, 12, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 30, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 47, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 5, 0, 11, 35, 1, 12, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 17, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 3, 6, 0, 7, 247, 14, 1, 65, 13, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 3, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 2, 3, 0, 0, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 2, 3, 0, 0, 4, 109, 97, 116, 52, 2, 3, 0, 0, 4, 113, 117, 97, 116, 2, 3, 0, 0, 4, 118, 101, 99, 50, 2, 3, 0, 0, 4, 118, 101, 99, 51, 2, 3, 0, 0, 4, 118, 101, 99, 52, 2, 3, 0, 0, 5, 117, 118, 101, 99, 50, 2, 3, 0, 0, 5, 117, 118, 101, 99, 51, 2, 3, 0, 0, 5, 117, 118, 101, 99, 52, 1, 66, 92, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 2, 4, 4, 109, 97, 116, 52, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 4, 2, 3, 2, 1, 4, 4, 4, 118, 101, 99, 50, 0, 3, 0, 6, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 8, 2, 3, 2, 1, 6, 4, 4, 118, 101, 99, 52, 0, 3, 0, 10, 2, 3, 2, 1, 7, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 12, 2, 3, 2, 1, 8, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 14, 2, 3, 2, 1, 9, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 16, 1, 109, 3, 5, 102, 114, 97, 109, 101, 5, 115, 112, 97, 119, 110, 7, 100, 101, 115, 112, 97, 119, 110, 4, 11, 113, 117, 101, 114, 121, 45, 101, 118, 101, 110, 116, 0, 3, 0, 18, 1, 112, 121, 1, 114, 4, 10, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 20, 7, 105, 110, 99, 108, 117, 100, 101, 20, 7, 101, 120, 99, 108, 117, 100, 101, 20, 7, 99, 104, 97, 110, 103, 101, 100, 20, 4, 11, 113, 117, 101, 114, 121, 45, 98, 117, 105, 108, 100, 0, 3, 0, 21, 1, 111, 0, 1, 112, 23, 1, 112, 127, 1, 112, 1, 1, 112, 118, 1, 112, 117, 1, 112, 3, 1, 112, 122, 1, 112, 5, 1, 112, 115, 1, 112, 119, 1, 112, 7, 1, 112, 9, 1, 112, 11, 1, 112, 13, 1, 112, 15, 1, 112, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 24, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 25, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 26, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 27, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 28, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 29, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 30, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 31, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 32, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 20, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 33, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 34, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 35, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 36, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 37, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 38, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 39, 0, 4, 9, 118, 101, 99, 45, 118, 97, 108, 117, 101, 0, 3, 0, 40, 1, 107, 23, 1, 107, 127, 1, 107, 1, 1, 107, 118, 1, 107, 117, 1, 107, 3, 1, 107, 122, 1, 107, 5, 1, 107, 115, 1, 107, 121, 1, 107, 119, 1, 107, 7, 1, 107, 9, 1, 107, 11, 1, 107, 13, 1, 107, 15, 1, 107, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 42, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 43, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 44, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 45, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 46, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 47, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 48, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 49, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 50, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 51, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 52, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 53, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 54, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 55, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 56, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 57, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 58, 0, 4, 12, 111, 112, 116, 105, 111, 110, 45, 118, 97, 108, 117, 101, 0, 3, 0, 59, 1, 113, 19, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 23, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 127, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 1, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 118, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 117, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 3, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 122, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 5, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 115, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 121, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 119, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 7, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 9, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 11, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 13, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 15, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 17, 0, 8, 116, 121, 112, 101, 45, 118, 101, 99, 1, 41, 0, 11, 116, 121, 112, 101, 45, 111, 112, 116, 105, 111, 110, 1, 60, 0, 4, 5, 118, 97, 108, 117, 101, 0, 3, 0, 61, 1, 111, 2, 121, 62, 1, 112, 63, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 64, 1, 64, 1, 2, 105, 100, 115, 0, 51, 4, 9, 103, 101, 116, 45, 105, 110, 100, 101, 120, 0, 1, 66, 1, 107, 62, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 0, 195, 0, 4, 13, 103, 101, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 68, 1, 64, 3, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 5, 118, 97, 108, 117, 101, 62, 1, 0, 4, 13, 97, 100, 100, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 69, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 4, 100, 97, 116, 97, 193, 0, 1, 0, 4, 14, 97, 100, 100, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 70, 4, 13, 115, 101, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 69, 4, 14, 115, 101, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 70, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 0, 127, 4, 13, 104, 97, 115, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 71, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 7, 105, 110, 100, 105, 99, 101, 115, 20, 0, 127, 4, 14, 104, 97, 115, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 72, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 1, 0, 4, 16, 114, 101, 109, 111, 118, 101, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 73, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 7, 105, 110, 100, 105, 99, 101, 115, 20, 1, 0, 4, 17, 114, 101, 109, 111, 118, 101, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 74, 1, 64, 2, 1, 113, 22, 1, 116, 19, 0, 119, 4, 5, 113, 117, 101, 114, 121, 0, 1, 75, 1, 112, 62, 1, 111, 2, 1, 204, 0, 1, 112, 205, 0, 1, 64, 1, 1, 113, 119, 0, 206, 0, 4, 10, 113, 117, 101, 114, 121, 45, 101, 118, 97, 108, 0, 1, 79, 4, 9, 99, 111, 109, 112, 111, 110, 101, 110, 116, 24, 112, 107, 103, 58, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 5, 10, 11, 29, 1, 9, 99, 111, 109, 112, 111, 110, 101, 110, 116, 14, 112, 107, 103, 58, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 3, 8, 0, 7, 210, 12, 1, 65, 16, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 3, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 2, 3, 0, 0, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 2, 3, 0, 0, 4, 109, 97, 116, 52, 2, 3, 0, 0, 4, 113, 117, 97, 116, 2, 3, 0, 0, 4, 118, 101, 99, 50, 2, 3, 0, 0, 4, 118, 101, 99, 51, 2, 3, 0, 0, 4, 118, 101, 99, 52, 2, 3, 0, 0, 5, 117, 118, 101, 99, 50, 2, 3, 0, 0, 5, 117, 118, 101, 99, 51, 2, 3, 0, 0, 5, 117, 118, 101, 99, 52, 1, 66, 66, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 2, 4, 4, 109, 97, 116, 52, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 4, 2, 3, 2, 1, 4, 4, 4, 118, 101, 99, 50, 0, 3, 0, 6, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 8, 2, 3, 2, 1, 6, 4, 4, 118, 101, 99, 52, 0, 3, 0, 10, 2, 3, 2, 1, 7, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 12, 2, 3, 2, 1, 8, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 14, 2, 3, 2, 1, 9, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 16, 1, 109, 3, 5, 102, 114, 97, 109, 101, 5, 115, 112, 97, 119, 110, 7, 100, 101, 115, 112, 97, 119, 110, 4, 11, 113, 117, 101, 114, 121, 45, 101, 118, 101, 110, 116, 0, 3, 0, 18, 1, 112, 121, 1, 114, 4, 10, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 20, 7, 105, 110, 99, 108, 117, 100, 101, 20, 7, 101, 120, 99, 108, 117, 100, 101, 20, 7, 99, 104, 97, 110, 103, 101, 100, 20, 4, 11, 113, 117, 101, 114, 121, 45, 98, 117, 105, 108, 100, 0, 3, 0, 21, 1, 111, 0, 1, 112, 23, 1, 112, 127, 1, 112, 1, 1, 112, 118, 1, 112, 117, 1, 112, 3, 1, 112, 122, 1, 112, 5, 1, 112, 115, 1, 112, 119, 1, 112, 7, 1, 112, 9, 1, 112, 11, 1, 112, 13, 1, 112, 15, 1, 112, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 24, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 25, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 26, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 27, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 28, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 29, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 30, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 31, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 32, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 20, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 33, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 34, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 35, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 36, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 37, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 38, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 39, 0, 4, 9, 118, 101, 99, 45, 118, 97, 108, 117, 101, 0, 3, 0, 40, 1, 107, 23, 1, 107, 127, 1, 107, 1, 1, 107, 118, 1, 107, 117, 1, 107, 3, 1, 107, 122, 1, 107, 5, 1, 107, 115, 1, 107, 121, 1, 107, 119, 1, 107, 7, 1, 107, 9, 1, 107, 11, 1, 107, 13, 1, 107, 15, 1, 107, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 42, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 43, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 44, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 45, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 46, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 47, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 48, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 49, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 50, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 51, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 52, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 53, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 54, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 55, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 56, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 57, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 58, 0, 4, 12, 111, 112, 116, 105, 111, 110, 45, 118, 97, 108, 117, 101, 0, 3, 0, 59, 1, 113, 19, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 23, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 127, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 1, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 118, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 117, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 3, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 122, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 5, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 115, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 121, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 119, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 7, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 9, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 11, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 13, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 15, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 17, 0, 8, 116, 121, 112, 101, 45, 118, 101, 99, 1, 41, 0, 11, 116, 121, 112, 101, 45, 111, 112, 116, 105, 111, 110, 1, 60, 0, 4, 5, 118, 97, 108, 117, 101, 0, 3, 0, 61, 1, 111, 2, 121, 62, 1, 112, 63, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 64, 3, 9, 99, 111, 109, 112, 111, 110, 101, 110, 116, 24, 112, 107, 103, 58, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 5, 10, 2, 3, 0, 1, 6, 101, 110, 116, 105, 116, 121, 1, 66, 6, 2, 3, 2, 1, 11, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 0, 1, 64, 0, 1, 0, 4, 4, 105, 110, 105, 116, 0, 1, 2, 1, 64, 3, 4, 116, 105, 109, 101, 118, 10, 101, 118, 101, 110, 116, 45, 110, 97, 109, 101, 115, 10, 101, 118, 101, 110, 116, 45, 100, 97, 116, 97, 1, 1, 0, 4, 4, 101, 120, 101, 99, 0, 1, 3, 4, 5, 103, 117, 101, 115, 116, 16, 112, 107, 103, 58, 47, 103, 117, 101, 115, 116, 47, 103, 117, 101, 115, 116, 5, 12, 11, 21, 1, 5, 103, 117, 101, 115, 116, 10, 112, 107, 103, 58, 47, 103, 117, 101, 115, 116, 3, 10, 0, 7, 203, 12, 1, 65, 16, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 3, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 2, 3, 0, 0, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 2, 3, 0, 0, 4, 109, 97, 116, 52, 2, 3, 0, 0, 4, 113, 117, 97, 116, 2, 3, 0, 0, 4, 118, 101, 99, 50, 2, 3, 0, 0, 4, 118, 101, 99, 51, 2, 3, 0, 0, 4, 118, 101, 99, 52, 2, 3, 0, 0, 5, 117, 118, 101, 99, 50, 2, 3, 0, 0, 5, 117, 118, 101, 99, 51, 2, 3, 0, 0, 5, 117, 118, 101, 99, 52, 1, 66, 66, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 2, 4, 4, 109, 97, 116, 52, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 4, 2, 3, 2, 1, 4, 4, 4, 118, 101, 99, 50, 0, 3, 0, 6, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 8, 2, 3, 2, 1, 6, 4, 4, 118, 101, 99, 52, 0, 3, 0, 10, 2, 3, 2, 1, 7, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 12, 2, 3, 2, 1, 8, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 14, 2, 3, 2, 1, 9, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 16, 1, 109, 3, 5, 102, 114, 97, 109, 101, 5, 115, 112, 97, 119, 110, 7, 100, 101, 115, 112, 97, 119, 110, 4, 11, 113, 117, 101, 114, 121, 45, 101, 118, 101, 110, 116, 0, 3, 0, 18, 1, 112, 121, 1, 114, 4, 10, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 20, 7, 105, 110, 99, 108, 117, 100, 101, 20, 7, 101, 120, 99, 108, 117, 100, 101, 20, 7, 99, 104, 97, 110, 103, 101, 100, 20, 4, 11, 113, 117, 101, 114, 121, 45, 98, 117, 105, 108, 100, 0, 3, 0, 21, 1, 111, 0, 1, 112, 23, 1, 112, 127, 1, 112, 1, 1, 112, 118, 1, 112, 117, 1, 112, 3, 1, 112, 122, 1, 112, 5, 1, 112, 115, 1, 112, 119, 1, 112, 7, 1, 112, 9, 1, 112, 11, 1, 112, 13, 1, 112, 15, 1, 112, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 24, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 25, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 26, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 27, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 28, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 29, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 30, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 31, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 32, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 20, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 33, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 34, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 35, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 36, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 37, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 38, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 39, 0, 4, 9, 118, 101, 99, 45, 118, 97, 108, 117, 101, 0, 3, 0, 40, 1, 107, 23, 1, 107, 127, 1, 107, 1, 1, 107, 118, 1, 107, 117, 1, 107, 3, 1, 107, 122, 1, 107, 5, 1, 107, 115, 1, 107, 121, 1, 107, 119, 1, 107, 7, 1, 107, 9, 1, 107, 11, 1, 107, 13, 1, 107, 15, 1, 107, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 42, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 43, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 44, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 45, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 46, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 47, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 48, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 49, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 50, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 51, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 52, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 53, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 54, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 55, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 56, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 57, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 58, 0, 4, 12, 111, 112, 116, 105, 111, 110, 45, 118, 97, 108, 117, 101, 0, 3, 0, 59, 1, 113, 19, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 23, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 127, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 1, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 118, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 117, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 3, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 122, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 5, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 115, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 121, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 119, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 7, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 9, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 11, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 13, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 15, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 17, 0, 8, 116, 121, 112, 101, 45, 118, 101, 99, 1, 41, 0, 11, 116, 121, 112, 101, 45, 111, 112, 116, 105, 111, 110, 1, 60, 0, 4, 5, 118, 97, 108, 117, 101, 0, 3, 0, 61, 1, 111, 2, 121, 62, 1, 112, 63, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 64, 3, 9, 99, 111, 109, 112, 111, 110, 101, 110, 116, 24, 112, 107, 103, 58, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 5, 10, 2, 3, 0, 1, 6, 101, 110, 116, 105, 116, 121, 1, 66, 6, 2, 3, 2, 1, 11, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 0, 1, 64, 1, 4, 110, 97, 109, 101, 115, 1, 0, 4, 9, 115, 117, 98, 115, 99, 114, 105, 98, 101, 0, 1, 2, 1, 64, 2, 4, 110, 97, 109, 101, 115, 4, 100, 97, 116, 97, 1, 1, 0, 4, 4, 115, 101, 110, 100, 0, 1, 3, 4, 5, 101, 118, 101, 110, 116, 16, 112, 107, 103, 58, 47, 101, 118, 101, 110, 116, 47, 101, 118, 101, 110, 116, 5, 12, 11, 21, 1, 5, 101, 118, 101, 110, 116, 10, 112, 107, 103, 58, 47, 101, 118, 101, 110, 116, 3, 12, 0, 7, 189, 15, 1, 65, 16, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 3, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 2, 3, 0, 0, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 2, 3, 0, 0, 4, 109, 97, 116, 52, 2, 3, 0, 0, 4, 113, 117, 97, 116, 2, 3, 0, 0, 4, 118, 101, 99, 50, 2, 3, 0, 0, 4, 118, 101, 99, 51, 2, 3, 0, 0, 4, 118, 101, 99, 52, 2, 3, 0, 0, 5, 117, 118, 101, 99, 50, 2, 3, 0, 0, 5, 117, 118, 101, 99, 51, 2, 3, 0, 0, 5, 117, 118, 101, 99, 52, 1, 66, 66, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 2, 4, 4, 109, 97, 116, 52, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 4, 2, 3, 2, 1, 4, 4, 4, 118, 101, 99, 50, 0, 3, 0, 6, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 8, 2, 3, 2, 1, 6, 4, 4, 118, 101, 99, 52, 0, 3, 0, 10, 2, 3, 2, 1, 7, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 12, 2, 3, 2, 1, 8, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 14, 2, 3, 2, 1, 9, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 16, 1, 109, 3, 5, 102, 114, 97, 109, 101, 5, 115, 112, 97, 119, 110, 7, 100, 101, 115, 112, 97, 119, 110, 4, 11, 113, 117, 101, 114, 121, 45, 101, 118, 101, 110, 116, 0, 3, 0, 18, 1, 112, 121, 1, 114, 4, 10, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 20, 7, 105, 110, 99, 108, 117, 100, 101, 20, 7, 101, 120, 99, 108, 117, 100, 101, 20, 7, 99, 104, 97, 110, 103, 101, 100, 20, 4, 11, 113, 117, 101, 114, 121, 45, 98, 117, 105, 108, 100, 0, 3, 0, 21, 1, 111, 0, 1, 112, 23, 1, 112, 127, 1, 112, 1, 1, 112, 118, 1, 112, 117, 1, 112, 3, 1, 112, 122, 1, 112, 5, 1, 112, 115, 1, 112, 119, 1, 112, 7, 1, 112, 9, 1, 112, 11, 1, 112, 13, 1, 112, 15, 1, 112, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 24, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 25, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 26, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 27, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 28, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 29, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 30, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 31, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 32, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 20, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 33, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 34, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 35, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 36, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 37, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 38, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 39, 0, 4, 9, 118, 101, 99, 45, 118, 97, 108, 117, 101, 0, 3, 0, 40, 1, 107, 23, 1, 107, 127, 1, 107, 1, 1, 107, 118, 1, 107, 117, 1, 107, 3, 1, 107, 122, 1, 107, 5, 1, 107, 115, 1, 107, 121, 1, 107, 119, 1, 107, 7, 1, 107, 9, 1, 107, 11, 1, 107, 13, 1, 107, 15, 1, 107, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 42, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 43, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 44, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 45, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 46, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 47, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 48, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 49, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 50, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 51, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 52, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 53, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 54, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 55, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 56, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 57, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 58, 0, 4, 12, 111, 112, 116, 105, 111, 110, 45, 118, 97, 108, 117, 101, 0, 3, 0, 59, 1, 113, 19, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 23, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 127, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 1, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 118, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 117, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 3, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 122, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 5, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 115, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 121, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 119, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 7, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 9, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 11, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 13, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 15, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 17, 0, 8, 116, 121, 112, 101, 45, 118, 101, 99, 1, 41, 0, 11, 116, 121, 112, 101, 45, 111, 112, 116, 105, 111, 110, 1, 60, 0, 4, 5, 118, 97, 108, 117, 101, 0, 3, 0, 61, 1, 111, 2, 121, 62, 1, 112, 63, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 64, 3, 9, 99, 111, 109, 112, 111, 110, 101, 110, 116, 24, 112, 107, 103, 58, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 5, 10, 2, 3, 0, 1, 6, 101, 110, 116, 105, 116, 121, 1, 66, 29, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 4, 2, 3, 2, 1, 11, 4, 11, 101, 110, 116, 105, 116, 121, 45, 100, 97, 116, 97, 0, 3, 0, 6, 1, 114, 3, 8, 99, 108, 105, 112, 45, 117, 114, 108, 115, 7, 108, 111, 111, 112, 105, 110, 103, 127, 6, 119, 101, 105, 103, 104, 116, 118, 4, 16, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 97, 99, 116, 105, 111, 110, 0, 3, 0, 8, 1, 112, 9, 1, 114, 2, 7, 97, 99, 116, 105, 111, 110, 115, 10, 15, 97, 112, 112, 108, 121, 45, 98, 97, 115, 101, 45, 112, 111, 115, 101, 127, 4, 20, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 99, 111, 110, 116, 114, 111, 108, 108, 101, 114, 0, 3, 0, 11, 1, 64, 1, 4, 100, 97, 116, 97, 7, 0, 1, 4, 5, 115, 112, 97, 119, 110, 0, 1, 13, 1, 64, 1, 6, 101, 110, 116, 105, 116, 121, 1, 0, 127, 4, 7, 100, 101, 115, 112, 97, 119, 110, 0, 1, 14, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 20, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 99, 111, 110, 116, 114, 111, 108, 108, 101, 114, 12, 1, 0, 4, 24, 115, 101, 116, 45, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 99, 111, 110, 116, 114, 111, 108, 108, 101, 114, 0, 1, 15, 1, 112, 1, 1, 64, 2, 8, 112, 111, 115, 105, 116, 105, 111, 110, 3, 6, 114, 97, 100, 105, 117, 115, 118, 0, 16, 4, 7, 105, 110, 45, 97, 114, 101, 97, 0, 1, 17, 4, 6, 101, 120, 105, 115, 116, 115, 0, 1, 14, 1, 64, 1, 5, 105, 110, 100, 101, 120, 121, 0, 16, 4, 7, 103, 101, 116, 45, 97, 108, 108, 0, 1, 18, 1, 64, 0, 0, 1, 4, 9, 114, 101, 115, 111, 117, 114, 99, 101, 115, 0, 1, 19, 4, 22, 115, 121, 110, 99, 104, 114, 111, 110, 105, 122, 101, 100, 45, 114, 101, 115, 111, 117, 114, 99, 101, 115, 0, 1, 19, 4, 19, 112, 101, 114, 115, 105, 115, 116, 101, 100, 45, 114, 101, 115, 111, 117, 114, 99, 101, 115, 0, 1, 19, 4, 6, 101, 110, 116, 105, 116, 121, 18, 112, 107, 103, 58, 47, 101, 110, 116, 105, 116, 121, 47, 101, 110, 116, 105, 116, 121, 5, 12, 11, 23, 1, 6, 101, 110, 116, 105, 116, 121, 11, 112, 107, 103, 58, 47, 101, 110, 116, 105, 116, 121, 3, 14, 0, 7, 90, 1, 65, 2, 1, 66, 4, 1, 64, 1, 4, 110, 97, 109, 101, 115, 1, 0, 4, 11, 98, 101, 103, 105, 110, 45, 115, 99, 111, 112, 101, 0, 1, 0, 1, 64, 0, 1, 0, 4, 9, 101, 110, 100, 45, 115, 99, 111, 112, 101, 0, 1, 1, 4, 9, 112, 114, 111, 102, 105, 108, 105, 110, 103, 24, 112, 107, 103, 58, 47, 112, 114, 111, 102, 105, 108, 105, 110, 103, 47, 112, 114, 111, 102, 105, 108, 105, 110, 103, 5, 0, 11, 29, 1, 9, 112, 114, 111, 102, 105, 108, 105, 110, 103, 14, 112, 107, 103, 58, 47, 112, 114, 111, 102, 105, 108, 105, 110, 103, 3, 16, 0, 7, 210, 1, 1, 65, 2, 1, 66, 15, 1, 106, 1, 119, 1, 115, 1, 64, 1, 4, 112, 97, 116, 104, 115, 0, 0, 4, 10, 114, 101, 97, 100, 45, 97, 115, 115, 101, 116, 0, 1, 1, 4, 9, 114, 101, 97, 100, 45, 100, 97, 116, 97, 0, 1, 1, 1, 112, 125, 1, 64, 2, 4, 112, 97, 116, 104, 115, 4, 100, 97, 116, 97, 2, 0, 0, 4, 10, 119, 114, 105, 116, 101, 45, 100, 97, 116, 97, 0, 1, 3, 1, 64, 1, 3, 117, 114, 108, 115, 0, 0, 4, 8, 104, 116, 116, 112, 45, 103, 101, 116, 0, 1, 4, 1, 64, 2, 3, 117, 114, 108, 115, 4, 98, 111, 100, 121, 2, 0, 0, 4, 9, 104, 116, 116, 112, 45, 112, 111, 115, 116, 0, 1, 5, 1, 106, 1, 2, 1, 115, 1, 107, 6, 1, 64, 1, 7, 114, 101, 113, 117, 101, 115, 116, 119, 0, 7, 4, 4, 112, 111, 108, 108, 0, 1, 8, 4, 9, 115, 101, 114, 118, 101, 114, 45, 105, 111, 24, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 105, 111, 47, 115, 101, 114, 118, 101, 114, 45, 105, 111, 5, 0, 11, 29, 1, 9, 115, 101, 114, 118, 101, 114, 45, 105, 111, 14, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 105, 111, 3, 18, 0, 7, 230, 1, 1, 65, 2, 1, 66, 17, 1, 112, 125, 1, 107, 0, 1, 114, 2, 3, 107, 101, 121, 115, 5, 118, 97, 108, 117, 101, 1, 4, 11, 98, 97, 116, 99, 104, 45, 119, 114, 105, 116, 101, 0, 3, 0, 2, 1, 64, 1, 3, 107, 101, 121, 115, 0, 1, 4, 3, 103, 101, 116, 0, 1, 4, 1, 106, 0, 1, 115, 1, 64, 2, 3, 107, 101, 121, 115, 5, 118, 97, 108, 117, 101, 0, 0, 5, 4, 3, 115, 101, 116, 0, 1, 6, 1, 64, 1, 3, 107, 101, 121, 115, 0, 5, 4, 6, 100, 101, 108, 101, 116, 101, 0, 1, 7, 1, 112, 115, 1, 64, 1, 6, 112, 114, 101, 102, 105, 120, 115, 0, 8, 4, 11, 108, 105, 115, 116, 45, 112, 114, 101, 102, 105, 120, 0, 1, 9, 1, 112, 3, 1, 64, 1, 6, 119, 114, 105, 116, 101, 115, 10, 0, 5, 4, 11, 119, 114, 105, 116, 101, 45, 98, 97, 116, 99, 104, 0, 1, 11, 4, 14, 115, 101, 114, 118, 101, 114, 45, 115, 116, 111, 114, 97, 103, 101, 34, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 115, 116, 111, 114, 97, 103, 101, 47, 115, 101, 114, 118, 101, 114, 45, 115, 116, 111, 114, 97, 103, 101, 5, 0, 11, 39, 1, 14, 115, 101, 114, 118, 101, 114, 45, 115, 116, 111, 114, 97, 103, 101, 19, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 115, 116, 111, 114, 97, 103, 101, 3, 20, 0, 7, 221, 4, 1, 65, 6, 1, 66, 13, 1, 112, 115, 1, 64, 0, 0, 0, 4, 5, 102, 105, 108, 101, 115, 0, 1, 1, 1, 112, 125, 1, 106, 1, 2, 1, 115, 1, 64, 1, 4, 112, 97, 116, 104, 115, 0, 3, 4, 9, 114, 101, 97, 100, 45, 102, 105, 108, 101, 0, 1, 4, 1, 106, 0, 1, 115, 1, 64, 2, 4, 112, 97, 116, 104, 115, 8, 99, 111, 110, 116, 101, 110, 116, 115, 2, 0, 5, 4, 10, 119, 114, 105, 116, 101, 45, 102, 105, 108, 101, 0, 1, 6, 1, 107, 115, 1, 64, 5, 4, 112, 97, 116, 104, 115, 10, 97, 115, 115, 101, 116, 45, 116, 121, 112, 101, 115, 4, 110, 97, 109, 101, 115, 4, 116, 97, 103, 115, 0, 6, 115, 111, 117, 114, 99, 101, 7, 0, 5, 4, 9, 97, 100, 100, 45, 97, 115, 115, 101, 116, 0, 1, 8, 4, 13, 112, 105, 112, 101, 108, 105, 110, 101, 45, 104, 111, 115, 116, 27, 112, 107, 103, 58, 47, 112, 105, 112, 101, 108, 105, 110, 101, 47, 112, 105, 112, 101, 108, 105, 110, 101, 45, 104, 111, 115, 116, 5, 0, 1, 66, 3, 1, 106, 0, 1, 115, 1, 64, 1, 6, 99, 111, 110, 102, 105, 103, 115, 0, 0, 4, 7, 112, 114, 111, 99, 101, 115, 115, 0, 1, 1, 4, 14, 112, 105, 112, 101, 108, 105, 110, 101, 45, 103, 117, 101, 115, 116, 28, 112, 107, 103, 58, 47, 112, 105, 112, 101, 108, 105, 110, 101, 47, 112, 105, 112, 101, 108, 105, 110, 101, 45, 103, 117, 101, 115, 116, 5, 1, 1, 65, 4, 1, 66, 13, 1, 112, 115, 1, 64, 0, 0, 0, 4, 5, 102, 105, 108, 101, 115, 0, 1, 1, 1, 112, 125, 1, 106, 1, 2, 1, 115, 1, 64, 1, 4, 112, 97, 116, 104, 115, 0, 3, 4, 9, 114, 101, 97, 100, 45, 102, 105, 108, 101, 0, 1, 4, 1, 106, 0, 1, 115, 1, 64, 2, 4, 112, 97, 116, 104, 115, 8, 99, 111, 110, 116, 101, 110, 116, 115, 2, 0, 5, 4, 10, 119, 114, 105, 116, 101, 45, 102, 105, 108, 101, 0, 1, 6, 1, 107, 115, 1, 64, 5, 4, 112, 97, 116, 104, 115, 10, 97, 115, 115, 101, 116, 45, 116, 121, 112, 101, 115, 4, 110, 97, 109, 101, 115, 4, 116, 97, 103, 115, 0, 6, 115, 111, 117, 114, 99, 101, 7, 0, 5, 4, 9, 97, 100, 100, 45, 97, 115, 115, 101, 116, 0, 1, 8, 3, 13, 112, 105, 112, 101, 108, 105, 110, 101, 45, 104, 111, 115, 116, 27, 112, 107, 103, 58, 47, 112, 105, 112, 101, 108, 105, 110, 101, 47, 112, 105, 112, 101, 108, 105, 110, 101, 45, 104, 111, 115, 116, 5, 0, 1, 66, 3, 1, 106, 0, 1, 115, 1, 64, 1, 6, 99, 111, 110, 102, 105, 103, 115, 0, 0, 4, 7, 112, 114, 111, 99, 101, 115, 115, 0, 1, 1, 4, 14, 112, 105, 112, 101, 108, 105, 110, 101, 45, 103, 117, 101, 115, 116, 28, 112, 107, 103, 58, 47, 112, 105, 112, 101, 108, 105, 110, 101, 47, 112, 105, 112, 101, 108, 105, 110, 101, 45, 103, 117, 101, 115, 116, 5, 1, 4, 9, 112, 114, 111, 99, 101, 115, 115, 111, 114, 23, 112, 107, 103, 58, 47, 112, 105, 112, 101, 108, 105, 110, 101, 47, 112, 114, 111, 99, 101, 115, 115, 111, 114, 4, 2, 11, 27, 1, 8, 112, 105, 112, 101, 108, 105, 110, 101, 13, 112, 107, 103, 58, 47, 112, 105, 112, 101, 108, 105, 110, 101, 3, 22, 0, 7, 196, 47, 1, 65, 2, 1, 65, 32, 1, 66, 18, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 118, 101, 99, 52, 0, 3, 0, 0, 1, 114, 3, 1, 120, 118, 1, 121, 118, 1, 122, 118, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 1, 114, 2, 1, 120, 118, 1, 121, 118, 4, 4, 118, 101, 99, 50, 0, 3, 0, 4, 1, 114, 4, 1, 120, 121, 1, 121, 121, 1, 122, 121, 1, 119, 121, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 6, 1, 114, 3, 1, 120, 121, 1, 121, 121, 1, 122, 121, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 8, 1, 114, 2, 1, 120, 121, 1, 121, 121, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 10, 1, 114, 4, 1, 120, 118, 1, 121, 118, 1, 122, 118, 1, 119, 118, 4, 4, 113, 117, 97, 116, 0, 3, 0, 12, 1, 114, 4, 1, 120, 1, 1, 121, 1, 1, 122, 1, 1, 119, 1, 4, 4, 109, 97, 116, 52, 0, 3, 0, 14, 1, 114, 2, 3, 105, 100, 48, 119, 3, 105, 100, 49, 119, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 16, 3, 5, 116, 121, 112, 101, 115, 16, 112, 107, 103, 58, 47, 116, 121, 112, 101, 115, 47, 116, 121, 112, 101, 115, 5, 0, 2, 3, 0, 0, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 2, 3, 0, 0, 4, 109, 97, 116, 52, 2, 3, 0, 0, 4, 113, 117, 97, 116, 2, 3, 0, 0, 4, 118, 101, 99, 50, 2, 3, 0, 0, 4, 118, 101, 99, 51, 2, 3, 0, 0, 4, 118, 101, 99, 52, 2, 3, 0, 0, 5, 117, 118, 101, 99, 50, 2, 3, 0, 0, 5, 117, 118, 101, 99, 51, 2, 3, 0, 0, 5, 117, 118, 101, 99, 52, 1, 66, 92, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 2, 4, 4, 109, 97, 116, 52, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 4, 2, 3, 2, 1, 4, 4, 4, 118, 101, 99, 50, 0, 3, 0, 6, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 8, 2, 3, 2, 1, 6, 4, 4, 118, 101, 99, 52, 0, 3, 0, 10, 2, 3, 2, 1, 7, 4, 5, 117, 118, 101, 99, 50, 0, 3, 0, 12, 2, 3, 2, 1, 8, 4, 5, 117, 118, 101, 99, 51, 0, 3, 0, 14, 2, 3, 2, 1, 9, 4, 5, 117, 118, 101, 99, 52, 0, 3, 0, 16, 1, 109, 3, 5, 102, 114, 97, 109, 101, 5, 115, 112, 97, 119, 110, 7, 100, 101, 115, 112, 97, 119, 110, 4, 11, 113, 117, 101, 114, 121, 45, 101, 118, 101, 110, 116, 0, 3, 0, 18, 1, 112, 121, 1, 114, 4, 10, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 20, 7, 105, 110, 99, 108, 117, 100, 101, 20, 7, 101, 120, 99, 108, 117, 100, 101, 20, 7, 99, 104, 97, 110, 103, 101, 100, 20, 4, 11, 113, 117, 101, 114, 121, 45, 98, 117, 105, 108, 100, 0, 3, 0, 21, 1, 111, 0, 1, 112, 23, 1, 112, 127, 1, 112, 1, 1, 112, 118, 1, 112, 117, 1, 112, 3, 1, 112, 122, 1, 112, 5, 1, 112, 115, 1, 112, 119, 1, 112, 7, 1, 112, 9, 1, 112, 11, 1, 112, 13, 1, 112, 15, 1, 112, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 24, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 25, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 26, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 27, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 28, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 29, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 30, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 31, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 32, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 20, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 33, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 34, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 35, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 36, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 37, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 38, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 39, 0, 4, 9, 118, 101, 99, 45, 118, 97, 108, 117, 101, 0, 3, 0, 40, 1, 107, 23, 1, 107, 127, 1, 107, 1, 1, 107, 118, 1, 107, 117, 1, 107, 3, 1, 107, 122, 1, 107, 5, 1, 107, 115, 1, 107, 121, 1, 107, 119, 1, 107, 7, 1, 107, 9, 1, 107, 11, 1, 107, 13, 1, 107, 15, 1, 107, 17, 1, 113, 17, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 42, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 43, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 44, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 45, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 46, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 47, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 48, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 49, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 50, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 51, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 52, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 53, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 54, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 55, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 56, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 57, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 58, 0, 4, 12, 111, 112, 116, 105, 111, 110, 45, 118, 97, 108, 117, 101, 0, 3, 0, 59, 1, 113, 19, 10, 116, 121, 112, 101, 45, 101, 109, 112, 116, 121, 1, 23, 0, 9, 116, 121, 112, 101, 45, 98, 111, 111, 108, 1, 127, 0, 14, 116, 121, 112, 101, 45, 101, 110, 116, 105, 116, 121, 45, 105, 100, 1, 1, 0, 8, 116, 121, 112, 101, 45, 102, 51, 50, 1, 118, 0, 8, 116, 121, 112, 101, 45, 102, 54, 52, 1, 117, 0, 9, 116, 121, 112, 101, 45, 109, 97, 116, 52, 1, 3, 0, 8, 116, 121, 112, 101, 45, 105, 51, 50, 1, 122, 0, 9, 116, 121, 112, 101, 45, 113, 117, 97, 116, 1, 5, 0, 11, 116, 121, 112, 101, 45, 115, 116, 114, 105, 110, 103, 1, 115, 0, 8, 116, 121, 112, 101, 45, 117, 51, 50, 1, 121, 0, 8, 116, 121, 112, 101, 45, 117, 54, 52, 1, 119, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 50, 1, 7, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 51, 1, 9, 0, 9, 116, 121, 112, 101, 45, 118, 101, 99, 52, 1, 11, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 50, 1, 13, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 51, 1, 15, 0, 10, 116, 121, 112, 101, 45, 117, 118, 101, 99, 52, 1, 17, 0, 8, 116, 121, 112, 101, 45, 118, 101, 99, 1, 41, 0, 11, 116, 121, 112, 101, 45, 111, 112, 116, 105, 111, 110, 1, 60, 0, 4, 5, 118, 97, 108, 117, 101, 0, 3, 0, 61, 1, 111, 2, 121, 62, 1, 112, 63, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 64, 1, 64, 1, 2, 105, 100, 115, 0, 51, 4, 9, 103, 101, 116, 45, 105, 110, 100, 101, 120, 0, 1, 66, 1, 107, 62, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 0, 195, 0, 4, 13, 103, 101, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 68, 1, 64, 3, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 5, 118, 97, 108, 117, 101, 62, 1, 0, 4, 13, 97, 100, 100, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 69, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 4, 100, 97, 116, 97, 193, 0, 1, 0, 4, 14, 97, 100, 100, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 70, 4, 13, 115, 101, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 69, 4, 14, 115, 101, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 70, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 0, 127, 4, 13, 104, 97, 115, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 71, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 7, 105, 110, 100, 105, 99, 101, 115, 20, 0, 127, 4, 14, 104, 97, 115, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 72, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 5, 105, 110, 100, 101, 120, 121, 1, 0, 4, 16, 114, 101, 109, 111, 118, 101, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 0, 1, 73, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 7, 105, 110, 100, 105, 99, 101, 115, 20, 1, 0, 4, 17, 114, 101, 109, 111, 118, 101, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 115, 0, 1, 74, 1, 64, 2, 1, 113, 22, 1, 116, 19, 0, 119, 4, 5, 113, 117, 101, 114, 121, 0, 1, 75, 1, 112, 62, 1, 111, 2, 1, 204, 0, 1, 112, 205, 0, 1, 64, 1, 1, 113, 119, 0, 206, 0, 4, 10, 113, 117, 101, 114, 121, 45, 101, 118, 97, 108, 0, 1, 79, 3, 9, 99, 111, 109, 112, 111, 110, 101, 110, 116, 24, 112, 107, 103, 58, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 47, 99, 111, 109, 112, 111, 110, 101, 110, 116, 5, 10, 2, 3, 0, 1, 6, 101, 110, 116, 105, 116, 121, 1, 66, 29, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 2, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 4, 2, 3, 2, 1, 11, 4, 11, 101, 110, 116, 105, 116, 121, 45, 100, 97, 116, 97, 0, 3, 0, 6, 1, 114, 3, 8, 99, 108, 105, 112, 45, 117, 114, 108, 115, 7, 108, 111, 111, 112, 105, 110, 103, 127, 6, 119, 101, 105, 103, 104, 116, 118, 4, 16, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 97, 99, 116, 105, 111, 110, 0, 3, 0, 8, 1, 112, 9, 1, 114, 2, 7, 97, 99, 116, 105, 111, 110, 115, 10, 15, 97, 112, 112, 108, 121, 45, 98, 97, 115, 101, 45, 112, 111, 115, 101, 127, 4, 20, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 99, 111, 110, 116, 114, 111, 108, 108, 101, 114, 0, 3, 0, 11, 1, 64, 1, 4, 100, 97, 116, 97, 7, 0, 1, 4, 5, 115, 112, 97, 119, 110, 0, 1, 13, 1, 64, 1, 6, 101, 110, 116, 105, 116, 121, 1, 0, 127, 4, 7, 100, 101, 115, 112, 97, 119, 110, 0, 1, 14, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 20, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 99, 111, 110, 116, 114, 111, 108, 108, 101, 114, 12, 1, 0, 4, 24, 115, 101, 116, 45, 97, 110, 105, 109, 97, 116, 105, 111, 110, 45, 99, 111, 110, 116, 114, 111, 108, 108, 101, 114, 0, 1, 15, 1, 112, 1, 1, 64, 2, 8, 112, 111, 115, 105, 116, 105, 111, 110, 3, 6, 114, 97, 100, 105, 117, 115, 118, 0, 16, 4, 7, 105, 110, 45, 97, 114, 101, 97, 0, 1, 17, 4, 6, 101, 120, 105, 115, 116, 115, 0, 1, 14, 1, 64, 1, 5, 105, 110, 100, 101, 120, 121, 0, 16, 4, 7, 103, 101, 116, 45, 97, 108, 108, 0, 1, 18, 1, 64, 0, 0, 1, 4, 9, 114, 101, 115, 111, 117, 114, 99, 101, 115, 0, 1, 19, 4, 22, 115, 121, 110, 99, 104, 114, 111, 110, 105, 122, 101, 100, 45, 114, 101, 115, 111, 117, 114, 99, 101, 115, 0, 1, 19, 4, 19, 112, 101, 114, 115, 105, 115, 116, 101, 100, 45, 114, 101, 115, 111, 117, 114, 99, 101, 115, 0, 1, 19, 3, 6, 101, 110, 116, 105, 116, 121, 18, 112, 107, 103, 58, 47, 101, 110, 116, 105, 116, 121, 47, 101, 110, 116, 105, 116, 121, 5, 12, 1, 66, 6, 2, 3, 2, 1, 11, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 0, 1, 64, 1, 4, 110, 97, 109, 101, 115, 1, 0, 4, 9, 115, 117, 98, 115, 99, 114, 105, 98, 101, 0, 1, 2, 1, 64, 2, 4, 110, 97, 109, 101, 115, 4, 100, 97, 116, 97, 1, 1, 0, 4, 4, 115, 101, 110, 100, 0, 1, 3, 3, 5, 101, 118, 101, 110, 116, 16, 112, 107, 103, 58, 47, 101, 118, 101, 110, 116, 47, 101, 118, 101, 110, 116, 5, 13, 1, 66, 4, 1, 64, 1, 4, 110, 97, 109, 101, 115, 1, 0, 4, 11, 98, 101, 103, 105, 110, 45, 115, 99, 111, 112, 101, 0, 1, 0, 1, 64, 0, 1, 0, 4, 9, 101, 110, 100, 45, 115, 99, 111, 112, 101, 0, 1, 1, 3, 9, 112, 114, 111, 102, 105, 108, 105, 110, 103, 24, 112, 107, 103, 58, 47, 112, 114, 111, 102, 105, 108, 105, 110, 103, 47, 112, 114, 111, 102, 105, 108, 105, 110, 103, 5, 14, 1, 66, 16, 2, 3, 2, 1, 4, 4, 4, 118, 101, 99, 50, 0, 3, 0, 0, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 2, 1, 109, 163, 1, 4, 107, 101, 121, 49, 4, 107, 101, 121, 50, 4, 107, 101, 121, 51, 4, 107, 101, 121, 52, 4, 107, 101, 121, 53, 4, 107, 101, 121, 54, 4, 107, 101, 121, 55, 4, 107, 101, 121, 56, 4, 107, 101, 121, 57, 4, 107, 101, 121, 48, 1, 97, 1, 98, 1, 99, 1, 100, 1, 101, 1, 102, 1, 103, 1, 104, 1, 105, 1, 106, 1, 107, 1, 108, 1, 109, 1, 110, 1, 111, 1, 112, 1, 113, 1, 114, 1, 115, 1, 116, 1, 117, 1, 118, 1, 119, 1, 120, 1, 121, 1, 122, 6, 101, 115, 99, 97, 112, 101, 2, 102, 49, 2, 102, 50, 2, 102, 51, 2, 102, 52, 2, 102, 53, 2, 102, 54, 2, 102, 55, 2, 102, 56, 2, 102, 57, 3, 102, 49, 48, 3, 102, 49, 49, 3, 102, 49, 50, 3, 102, 49, 51, 3, 102, 49, 52, 3, 102, 49, 53, 3, 102, 49, 54, 3, 102, 49, 55, 3, 102, 49, 56, 3, 102, 49, 57, 3, 102, 50, 48, 3, 102, 50, 49, 3, 102, 50, 50, 3, 102, 50, 51, 3, 102, 50, 52, 8, 115, 110, 97, 112, 115, 104, 111, 116, 6, 115, 99, 114, 111, 108, 108, 5, 112, 97, 117, 115, 101, 6, 105, 110, 115, 101, 114, 116, 4, 104, 111, 109, 101, 6, 100, 101, 108, 101, 116, 101, 3, 101, 110, 100, 9, 112, 97, 103, 101, 45, 100, 111, 119, 110, 7, 112, 97, 103, 101, 45, 117, 112, 4, 108, 101, 102, 116, 2, 117, 112, 5, 114, 105, 103, 104, 116, 4, 100, 111, 119, 110, 4, 98, 97, 99, 107, 6, 114, 101, 116, 117, 114, 110, 5, 115, 112, 97, 99, 101, 7, 99, 111, 109, 112, 111, 115, 101, 5, 99, 97, 114, 101, 116, 7, 110, 117, 109, 108, 111, 99, 107, 7, 110, 117, 109, 112, 97, 100, 48, 7, 110, 117, 109, 112, 97, 100, 49, 7, 110, 117, 109, 112, 97, 100, 50, 7, 110, 117, 109, 112, 97, 100, 51, 7, 110, 117, 109, 112, 97, 100, 52, 7, 110, 117, 109, 112, 97, 100, 53, 7, 110, 117, 109, 112, 97, 100, 54, 7, 110, 117, 109, 112, 97, 100, 55, 7, 110, 117, 109, 112, 97, 100, 56, 7, 110, 117, 109, 112, 97, 100, 57, 10, 110, 117, 109, 112, 97, 100, 45, 97, 100, 100, 13, 110, 117, 109, 112, 97, 100, 45, 100, 105, 118, 105, 100, 101, 14, 110, 117, 109, 112, 97, 100, 45, 100, 101, 99, 105, 109, 97, 108, 12, 110, 117, 109, 112, 97, 100, 45, 99, 111, 109, 109, 97, 12, 110, 117, 109, 112, 97, 100, 45, 101, 110, 116, 101, 114, 13, 110, 117, 109, 112, 97, 100, 45, 101, 113, 117, 97, 108, 115, 15, 110, 117, 109, 112, 97, 100, 45, 109, 117, 108, 116, 105, 112, 108, 121, 15, 110, 117, 109, 112, 97, 100, 45, 115, 117, 98, 116, 114, 97, 99, 116, 7, 97, 98, 110, 116, 45, 99, 49, 7, 97, 98, 110, 116, 45, 99, 50, 10, 97, 112, 111, 115, 116, 114, 111, 112, 104, 101, 4, 97, 112, 112, 115, 8, 97, 115, 116, 101, 114, 105, 115, 107, 2, 97, 116, 2, 97, 120, 9, 98, 97, 99, 107, 115, 108, 97, 115, 104, 10, 99, 97, 108, 99, 117, 108, 97, 116, 111, 114, 7, 99, 97, 112, 105, 116, 97, 108, 5, 99, 111, 108, 111, 110, 5, 99, 111, 109, 109, 97, 7, 99, 111, 110, 118, 101, 114, 116, 6, 101, 113, 117, 97, 108, 115, 5, 103, 114, 97, 118, 101, 4, 107, 97, 110, 97, 5, 107, 97, 110, 106, 105, 5, 108, 45, 97, 108, 116, 9, 108, 45, 98, 114, 97, 99, 107, 101, 116, 9, 108, 45, 99, 111, 110, 116, 114, 111, 108, 7, 108, 45, 115, 104, 105, 102, 116, 5, 108, 45, 119, 105, 110, 4, 109, 97, 105, 108, 12, 109, 101, 100, 105, 97, 45, 115, 101, 108, 101, 99, 116, 10, 109, 101, 100, 105, 97, 45, 115, 116, 111, 112, 5, 109, 105, 110, 117, 115, 4, 109, 117, 116, 101, 11, 109, 121, 45, 99, 111, 109, 112, 117, 116, 101, 114, 16, 110, 97, 118, 105, 103, 97, 116, 101, 45, 102, 111, 114, 119, 97, 114, 100, 17, 110, 97, 118, 105, 103, 97, 116, 101, 45, 98, 97, 99, 107, 119, 97, 114, 100, 10, 110, 101, 120, 116, 45, 116, 114, 97, 99, 107, 10, 110, 111, 45, 99, 111, 110, 118, 101, 114, 116, 6, 111, 101, 109, 49, 48, 50, 6, 112, 101, 114, 105, 111, 100, 10, 112, 108, 97, 121, 45, 112, 97, 117, 115, 101, 4, 112, 108, 117, 115, 5, 112, 111, 119, 101, 114, 10, 112, 114, 101, 118, 45, 116, 114, 97, 99, 107, 5, 114, 45, 97, 108, 116, 9, 114, 45, 98, 114, 97, 99, 107, 101, 116, 9, 114, 45, 99, 111, 110, 116, 114, 111, 108, 7, 114, 45, 115, 104, 105, 102, 116, 5, 114, 45, 119, 105, 110, 9, 115, 101, 109, 105, 99, 111, 108, 111, 110, 5, 115, 108, 97, 115, 104, 5, 115, 108, 101, 101, 112, 4, 115, 116, 111, 112, 5, 115, 121, 115, 114, 113, 3, 116, 97, 98, 9, 117, 110, 100, 101, 114, 108, 105, 110, 101, 9, 117, 110, 108, 97, 98, 101, 108, 101, 100, 11, 118, 111, 108, 117, 109, 101, 45, 100, 111, 119, 110, 9, 118, 111, 108, 117, 109, 101, 45, 117, 112, 4, 119, 97, 107, 101, 8, 119, 101, 98, 45, 98, 97, 99, 107, 13, 119, 101, 98, 45, 102, 97, 118, 111, 114, 105, 116, 101, 115, 11, 119, 101, 98, 45, 102, 111, 114, 119, 97, 114, 100, 8, 119, 101, 98, 45, 104, 111, 109, 101, 11, 119, 101, 98, 45, 114, 101, 102, 114, 101, 115, 104, 10, 119, 101, 98, 45, 115, 101, 97, 114, 99, 104, 8, 119, 101, 98, 45, 115, 116, 111, 112, 3, 121, 101, 110, 4, 99, 111, 112, 121, 5, 112, 97, 115, 116, 101, 3, 99, 117, 116, 4, 16, 118, 105, 114, 116, 117, 97, 108, 45, 107, 101, 121, 45, 99, 111, 100, 101, 0, 3, 0, 4, 1, 113, 4, 4, 108, 101, 102, 116, 0, 0, 5, 114, 105, 103, 104, 116, 0, 0, 6, 109, 105, 100, 100, 108, 101, 0, 0, 5, 111, 116, 104, 101, 114, 1, 123, 0, 4, 12, 109, 111, 117, 115, 101, 45, 98, 117, 116, 116, 111, 110, 0, 3, 0, 6, 1, 112, 5, 1, 112, 7, 1, 114, 5, 4, 107, 101, 121, 115, 8, 14, 109, 111, 117, 115, 101, 45, 112, 111, 115, 105, 116, 105, 111, 110, 1, 15, 99, 117, 114, 115, 111, 114, 45, 112, 111, 115, 105, 116, 105, 111, 110, 1, 11, 109, 111, 117, 115, 101, 45, 119, 104, 101, 101, 108, 118, 13, 109, 111, 117, 115, 101, 45, 98, 117, 116, 116, 111, 110, 115, 9, 4, 9, 114, 97, 119, 45, 105, 110, 112, 117, 116, 0, 3, 0, 10, 1, 107, 11, 1, 64, 1, 6, 112, 108, 97, 121, 101, 114, 3, 0, 12, 4, 13, 103, 101, 116, 45, 114, 97, 119, 45, 105, 110, 112, 117, 116, 0, 1, 13, 4, 18, 103, 101, 116, 45, 112, 114, 101, 118, 45, 114, 97, 119, 45, 105, 110, 112, 117, 116, 0, 1, 13, 3, 13, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 32, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 47, 115, 101, 114, 118, 101, 114, 45, 112, 108, 97, 121, 101, 114, 5, 15, 1, 66, 60, 2, 3, 2, 1, 1, 4, 9, 101, 110, 116, 105, 116, 121, 45, 105, 100, 0, 3, 0, 0, 2, 3, 2, 1, 3, 4, 4, 113, 117, 97, 116, 0, 3, 0, 2, 2, 3, 2, 1, 5, 4, 4, 118, 101, 99, 51, 0, 3, 0, 4, 1, 114, 3, 4, 115, 105, 100, 101, 127, 2, 117, 112, 127, 4, 100, 111, 119, 110, 127, 4, 19, 99, 104, 97, 114, 97, 99, 116, 101, 114, 45, 99, 111, 108, 108, 105, 115, 105, 111, 110, 0, 3, 0, 6, 1, 109, 3, 7, 112, 104, 121, 115, 105, 99, 115, 12, 116, 114, 105, 103, 103, 101, 114, 45, 97, 114, 101, 97, 7, 112, 105, 99, 107, 105, 110, 103, 4, 14, 99, 111, 108, 108, 105, 100, 101, 114, 45, 115, 99, 101, 110, 101, 0, 3, 0, 8, 1, 114, 2, 6, 114, 97, 100, 105, 117, 115, 118, 11, 104, 97, 108, 102, 45, 104, 101, 105, 103, 104, 116, 118, 4, 13, 99, 97, 112, 115, 117, 108, 101, 45, 115, 104, 97, 112, 101, 0, 3, 0, 10, 1, 113, 3, 6, 115, 112, 104, 101, 114, 101, 1, 118, 0, 6, 99, 117, 98, 111, 105, 100, 1, 5, 0, 7, 99, 97, 112, 115, 117, 108, 101, 1, 11, 0, 4, 11, 113, 117, 101, 114, 121, 45, 115, 104, 97, 112, 101, 0, 3, 0, 12, 1, 114, 4, 6, 101, 110, 116, 105, 116, 121, 1, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 6, 110, 111, 114, 109, 97, 108, 5, 8, 100, 105, 115, 116, 97, 110, 99, 101, 118, 4, 9, 115, 119, 101, 101, 112, 45, 104, 105, 116, 0, 3, 0, 14, 1, 109, 5, 5, 102, 105, 120, 101, 100, 8, 114, 101, 118, 111, 108, 117, 116, 101, 9, 112, 114, 105, 115, 109, 97, 116, 105, 99, 9, 115, 112, 104, 101, 114, 105, 99, 97, 108, 8, 100, 105, 115, 116, 97, 110, 99, 101, 4, 10, 106, 111, 105, 110, 116, 45, 116, 121, 112, 101, 0, 3, 0, 16, 1, 114, 5, 5, 111, 116, 104, 101, 114, 1, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 6, 110, 111, 114, 109, 97, 108, 5, 10, 115, 101, 112, 97, 114, 97, 116, 105, 111, 110, 118, 7, 105, 109, 112, 117, 108, 115, 101, 5, 4, 7, 99, 111, 110, 116, 97, 99, 116, 0, 3, 0, 18, 1, 112, 1, 1, 64, 2, 8, 101, 110, 116, 105, 116, 105, 101, 115, 20, 5, 102, 111, 114, 99, 101, 5, 1, 0, 4, 11, 97, 112, 112, 108, 121, 45, 102, 111, 114, 99, 101, 0, 1, 21, 1, 107, 118, 1, 64, 4, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 5, 102, 111, 114, 99, 101, 118, 6, 114, 97, 100, 105, 117, 115, 118, 14, 102, 97, 108, 108, 111, 102, 102, 45, 114, 97, 100, 105, 117, 115, 22, 1, 0, 4, 12, 101, 120, 112, 108, 111, 100, 101, 45, 98, 111, 109, 98, 0, 1, 23, 1, 64, 1, 7, 103, 114, 97, 118, 105, 116, 121, 5, 1, 0, 4, 11, 115, 101, 116, 45, 103, 114, 97, 118, 105, 116, 121, 0, 1, 24, 1, 64, 1, 6, 101, 110, 116, 105, 116, 121, 1, 1, 0, 4, 8, 117, 110, 102, 114, 101, 101, 122, 101, 0, 1, 25, 4, 6, 102, 114, 101, 101, 122, 101, 0, 1, 25, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 8, 118, 101, 108, 111, 99, 105, 116, 121, 118, 1, 0, 4, 11, 115, 116, 97, 114, 116, 45, 109, 111, 116, 111, 114, 0, 1, 26, 4, 10, 115, 116, 111, 112, 45, 109, 111, 116, 111, 114, 0, 1, 25, 1, 111, 2, 1, 118, 1, 107, 27, 1, 64, 2, 6, 111, 114, 105, 103, 105, 110, 5, 9, 100, 105, 114, 101, 99, 116, 105, 111, 110, 5, 0, 28, 4, 13, 114, 97, 121, 99, 97, 115, 116, 45, 102, 105, 114, 115, 116, 0, 1, 29, 1, 112, 27, 1, 64, 2, 6, 111, 114, 105, 103, 105, 110, 5, 9, 100, 105, 114, 101, 99, 116, 105, 111, 110, 5, 0, 30, 4, 7, 114, 97, 121, 99, 97, 115, 116, 0, 1, 31, 1, 64, 4, 6, 101, 110, 116, 105, 116, 121, 1, 12, 100, 105, 115, 112, 108, 97, 99, 101, 109, 101, 110, 116, 5, 8, 109, 105, 110, 45, 100, 105, 115, 116, 118, 12, 101, 108, 97, 112, 115, 101, 100, 45, 116, 105, 109, 101, 118, 0, 7, 4, 14, 109, 111, 118, 101, 45, 99, 104, 97, 114, 97, 99, 116, 101, 114, 0, 1, 32, 1, 107, 9, 1, 112, 15, 1, 64, 6, 5, 115, 104, 97, 112, 101, 13, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 8, 114, 111, 116, 97, 116, 105, 111, 110, 3, 9, 100, 105, 114, 101, 99, 116, 105, 111, 110, 5, 12, 109, 97, 120, 45, 100, 105, 115, 116, 97, 110, 99, 101, 118, 5, 115, 99, 101, 110, 101, 33, 0, 34, 4, 5, 115, 119, 101, 101, 112, 0, 1, 35, 1, 64, 4, 5, 115, 104, 97, 112, 101, 13, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 8, 114, 111, 116, 97, 116, 105, 111, 110, 3, 5, 115, 99, 101, 110, 101, 33, 0, 20, 4, 7, 111, 118, 101, 114, 108, 97, 112, 0, 1, 36, 1, 107, 1, 1, 64, 5, 10, 106, 111, 105, 110, 116, 45, 116, 121, 112, 101, 17, 7, 101, 110, 116, 105, 116, 121, 48, 1, 7, 101, 110, 116, 105, 116, 121, 49, 37, 8, 112, 111, 115, 105, 116, 105, 111, 110, 5, 8, 114, 111, 116, 97, 116, 105, 111, 110, 3, 0, 1, 4, 12, 99, 114, 101, 97, 116, 101, 45, 106, 111, 105, 110, 116, 0, 1, 38, 1, 64, 1, 5, 106, 111, 105, 110, 116, 1, 1, 0, 4, 11, 98, 114, 101, 97, 107, 45, 106, 111, 105, 110, 116, 0, 1, 39, 1, 64, 2, 6, 101, 110, 116, 105, 116, 121, 1, 8, 118, 101, 108, 111, 99, 105, 116, 121, 5, 1, 0, 4, 19, 115, 101, 116, 45, 108, 105, 110, 101, 97, 114, 45, 118, 101, 108, 111, 99, 105, 116, 121, 0, 1, 40, 4, 20, 115, 101, 116, 45, 97, 110, 103, 117, 108, 97, 114, 45, 118, 101, 108, 111, 99, 105, 116, 121, 0, 1, 40, 1, 112, 19, 1, 64, 1, 6, 101, 110, 116, 105, 116, 121, 1, 0, 41, 4, 12, 103, 101, 116, 45, 99, 111, 110, 116, 97, 99, 116, 115, 0, 1, 42, 3, 14, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 34, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 47, 115, 101, 114, 118, 101, 114, 45, 112, 104, 121, 115, 105, 99, 115, 5, 16, 1, 66, 12, 1, 109, 13, 11, 97, 115, 115, 101, 116, 45, 99, 114, 97, 116, 101, 6, 112, 114, 101, 102, 97, 98, 13, 115, 99, 114, 105, 112, 116, 45, 98, 117, 110, 100, 108, 101, 5, 109, 111, 100, 101, 108, 5, 105, 109, 97, 103, 101, 9, 97, 110, 105, 109, 97, 116, 105, 111, 110, 8, 109, 97, 116, 101, 114, 105, 97, 108, 8, 99, 111, 108, 108, 105, 100, 101, 114, 16, 116, 101, 114, 114, 97, 105, 110, 45, 109, 97, 116, 101, 114, 105, 97, 108, 10, 97, 116, 109, 111, 115, 112, 104, 101, 114, 101, 6, 98, 105, 111, 109, 101, 115, 12, 118, 111, 114, 98, 105, 115, 45, 116, 114, 97, 99, 107, 11, 115, 111, 117, 110, 100, 45, 103, 114, 97, 112, 104, 4, 10, 97, 115, 115, 101, 116, 45, 116, 121, 112, 101, 0, 3, 0, 0, 1, 112, 115, 1, 107, 115, 1, 114, 9, 2, 105, 100, 115, 10, 97, 115, 115, 101, 116, 45, 116, 121, 112, 101, 1, 4, 110, 97, 109, 101, 115, 4, 116, 97, 103, 115, 2, 10, 99, 97, 116, 101, 103, 111, 114, 105, 101, 115, 2, 3, 117, 114, 108, 3, 10, 99, 111, 108, 108, 101, 99, 116, 105, 111, 110, 2, 6, 115, 111, 117, 114, 99, 101, 3, 11, 112, 114, 101, 118, 105, 101, 119, 45, 117, 114, 108, 3, 4, 13, 99, 97, 116, 97, 108, 111, 103, 45, 97, 115, 115, 101, 116, 0, 3, 0, 4, 1, 64, 1, 4, 112, 97, 116, 104, 115, 0, 3, 4, 3, 117, 114, 108, 0, 1, 6, 1, 107, 1, 1, 112, 5, 1, 64, 3, 10, 97, 115, 115, 101, 116, 45, 116, 121, 112, 101, 7, 3, 116, 97, 103, 3, 8, 99, 97, 116, 101, 103, 111, 114, 121, 3, 0, 8, 4, 7, 99, 97, 116, 97, 108, 111, 103, 0, 1, 9, 3, 12, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 30, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 47, 115, 101, 114, 118, 101, 114, 45, 97, 115, 115, 101, 116, 5, 17, 1, 66, 15, 1, 106, 1, 119, 1, 115, 1, 64, 1, 4, 112, 97, 116, 104, 115, 0, 0, 4, 10, 114, 101, 97, 100, 45, 97, 115, 115, 101, 116, 0, 1, 1, 4, 9, 114, 101, 97, 100, 45, 100, 97, 116, 97, 0, 1, 1, 1, 112, 125, 1, 64, 2, 4, 112, 97, 116, 104, 115, 4, 100, 97, 116, 97, 2, 0, 0, 4, 10, 119, 114, 105, 116, 101, 45, 100, 97, 116, 97, 0, 1, 3, 1, 64, 1, 3, 117, 114, 108, 115, 0, 0, 4, 8, 104, 116, 116, 112, 45, 103, 101, 116, 0, 1, 4, 1, 64, 2, 3, 117, 114, 108, 115, 4, 98, 111, 100, 121, 2, 0, 0, 4, 9, 104, 116, 116, 112, 45, 112, 111, 115, 116, 0, 1, 5, 1, 106, 1, 2, 1, 115, 1, 107, 6, 1, 64, 1, 7, 114, 101, 113, 117, 101, 115, 116, 119, 0, 7, 4, 4, 112, 111, 108, 108, 0, 1, 8, 3, 9, 115, 101, 114, 118, 101, 114, 45, 105, 111, 24, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 105, 111, 47, 115, 101, 114, 118, 101, 114, 45, 105, 111, 5, 18, 1, 66, 17, 1, 112, 125, 1, 107, 0, 1, 114, 2, 3, 107, 101, 121, 115, 5, 118, 97, 108, 117, 101, 1, 4, 11, 98, 97, 116, 99, 104, 45, 119, 114, 105, 116, 101, 0, 3, 0, 2, 1, 64, 1, 3, 107, 101, 121, 115, 0, 1, 4, 3, 103, 101, 116, 0, 1, 4, 1, 106, 0, 1, 115, 1, 64, 2, 3, 107, 101, 121, 115, 5, 118, 97, 108, 117, 101, 0, 0, 5, 4, 3, 115, 101, 116, 0, 1, 6, 1, 64, 1, 3, 107, 101, 121, 115, 0, 5, 4, 6, 100, 101, 108, 101, 116, 101, 0, 1, 7, 1, 112, 115, 1, 64, 1, 6, 112, 114, 101, 102, 105, 120, 115, 0, 8, 4, 11, 108, 105, 115, 116, 45, 112, 114, 101, 102, 105, 120, 0, 1, 9, 1, 112, 3, 1, 64, 1, 6, 119, 114, 105, 116, 101, 115, 10, 0, 5, 4, 11, 119, 114, 105, 116, 101, 45, 98, 97, 116, 99, 104, 0, 1, 11, 3, 14, 115, 101, 114, 118, 101, 114, 45, 115, 116, 111, 114, 97, 103, 101, 34, 112, 107, 103, 58, 47, 115, 101, 114, 118, 101, 114, 45, 115, 116, 111, 114, 97, 103, 101, 47, 115, 101, 114, 118, 101, 114, 45, 115, 116, 111, 114, 97, 103, 101, 5, 19, 1, 66, 6, 2, 3, 2, 1, 11, 4, 6, 101, 110, 116, 105, 116, 121, 0, 3, 0, 0, 1, 64, 0, 1, 0, 4, 4, 105, 110, 105, 116, 0, 1, 2, 1, 64, 3, 4, 116, 105, 109, 101, 118, 10, 101, 118, 101, 110, 116, 45, 110, 97, 109, 101, 115, 10, 101, 118, 101, 110, 116, 45, 100, 97, 116, 97, 1, 1, 0, 4, 4, 101, 120, 101, 99, 0, 1, 3, 4, 5, 103, 117, 101, 115, 116, 16, 112, 107, 103, 58, 47, 103, 117, 101, 115, 116, 47, 103, 117, 101, 115, 116, 5, 20, 4, 8, 98, 105, 110, 100, 105, 110, 103, 115, 18, 112, 107, 103, 58, 47, 109, 97, 105, 110, 47, 98, 105, 110, 100, 105, 110, 103, 115, 4, 0, 0, 68, 9, 112, 114, 111, 100, 117, 99, 101, 114, 115, 1, 12, 112, 114, 111, 99, 101, 115, 115, 101, 100, 45, 98, 121, 2, 13, 119, 105, 116, 45, 99, 111, 109, 112, 111, 110, 101, 110, 116, 5, 48, 46, 55, 46, 51, 16, 119, 105, 116, 45, 98, 105, 110, 100, 103, 101, 110, 45, 114, 117, 115, 116, 5, 48, 46, 52, 46, 48, 11, 19, 1, 4, 109, 97, 105, 110, 9, 112, 107, 103, 58, 47, 109, 97, 105, 110, 3, 24, 0];
+  
+  #[inline(never)]
+  #[doc(hidden)]
+  #[cfg(target_arch = "wasm32")]
+  pub fn __link_section() {}
+  
\ No newline at end of file
@@ -372,6 +372,15 @@ impl PxRigidDynamicRef {
     pub fn set_kinematic_target(&self, destination: &PxTransform) {
         unsafe { physx_sys::PxRigidDynamic_setKinematicTarget_mut(self.0, &destination.0); }
     }
+    /// The target set with [Self::set_kinematic_target] that the body hasn't reached yet
+    pub fn get_kinematic_target(&self) -> Option<PxTransform> {
+        let mut target = PxTransform::identity();
+        if unsafe { physx_sys::PxRigidDynamic_getKinematicTarget(self.0, &mut target.0) } {
+            Some(target)
+        } else {
+            None
+        }
+    }
 }
 impl AsPxBase for PxRigidDynamicRef {
     fn as_base(&self) -> PxBaseRef {
@@ -40,6 +40,15 @@ unsafe impl Send for PxArticulationRef {}
 #[derive(Clone, Copy)]
 pub struct PxArticulationJointRef(*mut physx_sys::PxArticulationJointReducedCoordinate);
 impl PxArticulationJointRef {
+    /// The joint connecting `link` to its parent, or None for the root link
+    pub fn from_inbound_link(link: &PxArticulationLinkRef) -> Option<Self> {
+        let joint = unsafe { physx_sys::PxArticulationLink_getInboundJoint(link.0) };
+        if joint.is_null() {
+            None
+        } else {
+            Some(Self(joint as *mut physx_sys::PxArticulationJointReducedCoordinate))
+        }
+    }
     pub fn set_joint_type(&mut self, joint_type: PxArticulationJointType) {
         unsafe { physx_sys::PxArticulationJointReducedCoordinate_setJointType_mut(self.0, joint_type as u32) }
     }
@@ -1,5 +1,5 @@
 use crate::{
-    AsPxJoint, AsPxRigidActor, AsPxRigidBody, PxAggregateRef, PxArticulationLinkRef, PxConstraintRef, PxConvexMesh, PxFixedJointRef, PxHeightField, PxJointRef, PxMaterial, PxRevoluteJointRef, PxRigidActorRef, PxRigidBodyRef, PxRigidDynamicRef, PxRigidStaticRef, PxShape
+    AsPxJoint, AsPxRigidActor, AsPxRigidBody, PxAggregateRef, PxArticulationLinkRef, PxConstraintRef, PxConvexMesh, PxDistanceJointRef, PxFixedJointRef, PxHeightField, PxJointRef, PxMaterial, PxPrismaticJointRef, PxRevoluteJointRef, PxRigidActorRef, PxRigidBodyRef, PxRigidDynamicRef, PxRigidStaticRef, PxShape, PxSphericalJointRef
 };
 
 pub trait AsPxBase: Sync + Send {
@@ -53,6 +53,9 @@ impl<T: AsPxBase + 'static> PxBase for T {
         match self.as_px_any() {
             PxAny::PxFixedJoint(o) => Some(o.as_joint()),
             PxAny::PxRevoluteJoint(o) => Some(o.as_joint()),
+            PxAny::PxPrismaticJoint(o) => Some(o.as_joint()),
+            PxAny::PxSphericalJoint(o) => Some(o.as_joint()),
+            PxAny::PxDistanceJoint(o) => Some(o.as_joint()),
             _ => None,
         }
     }
@@ -77,6 +80,12 @@ impl AsPxBase for PxBaseRef {
         PxBaseRef(self.0)
     }
 }
+impl PxBaseRef {
+    /// Releases the object, or for reference counted objects, one reference to it
+    pub fn release(self) {
+        unsafe { physx_sys::PxBase_release_mut(self.0) }
+    }
+}
 unsafe impl Sync for PxBaseRef {}
 unsafe impl Send for PxBaseRef {}
 
@@ -91,6 +100,9 @@ pub enum PxAny {
     PxShape(PxShape),
     PxFixedJoint(PxFixedJointRef),
     PxRevoluteJoint(PxRevoluteJointRef),
+    PxPrismaticJoint(PxPrismaticJointRef),
+    PxSphericalJoint(PxSphericalJointRef),
+    PxDistanceJoint(PxDistanceJointRef),
     PxConstraint(PxConstraintRef),
     PxArticulationLink(PxArticulationLinkRef),
 }
@@ -121,12 +133,12 @@ impl PxAny {
 
                 physx_sys::PxJointConcreteType::eCONTACT => panic!("PhysX object type is not supported"),
                 physx_sys::PxJointConcreteType::eD6 => panic!("PhysX object type is not supported"),
-                physx_sys::PxJointConcreteType::eDISTANCE => panic!("PhysX object type is not supported"),
+                physx_sys::PxJointConcreteType::eDISTANCE => PxAny::PxDistanceJoint(PxDistanceJointRef(obj as _)),
                 physx_sys::PxJointConcreteType::eFIXED => PxAny::PxFixedJoint(PxFixedJointRef(obj as _)),
                 physx_sys::PxJointConcreteType::eLast => panic!("PhysX object type is not supported"),
-                physx_sys::PxJointConcreteType::ePRISMATIC => panic!("PhysX object type is not supported"),
+                physx_sys::PxJointConcreteType::ePRISMATIC => PxAny::PxPrismaticJoint(PxPrismaticJointRef(obj as _)),
                 physx_sys::PxJointConcreteType::eREVOLUTE => PxAny::PxRevoluteJoint(PxRevoluteJointRef(obj as _)),
-                physx_sys::PxJointConcreteType::eSPHERICAL => panic!("PhysX object type is not supported"),
+                physx_sys::PxJointConcreteType::eSPHERICAL => PxAny::PxSphericalJoint(PxSphericalJointRef(obj as _)),
 
                 _ => panic!("Unknown type"),
             }
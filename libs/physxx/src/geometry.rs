@@ -78,6 +78,12 @@ impl PxCapsuleGeometry {
     pub fn new(radius: f32, half_height: f32) -> Self {
         Self(unsafe { physx_sys::PxCapsuleGeometry_new_1(radius, half_height) })
     }
+    pub fn radius(&self) -> f32 {
+        self.0.radius
+    }
+    pub fn half_height(&self) -> f32 {
+        self.0.halfHeight
+    }
 }
 impl PxGeometry for PxCapsuleGeometry {
     fn as_geometry_ptr(&self) -> *const physx_sys::PxGeometry {
@@ -191,6 +197,9 @@ impl PxHeightFieldGeometry {
             )
         })
     }
+    pub fn height_field(&self) -> PxHeightField {
+        PxHeightField::from_ptr(self.0.heightField)
+    }
 }
 impl PxGeometry for PxHeightFieldGeometry {
     fn as_geometry_ptr(&self) -> *const physx_sys::PxGeometry {
@@ -212,6 +221,12 @@ impl PxGeometryHolder {
         }
         unsafe { Some(PxSphereGeometry(*physx_sys::PxGeometryHolder_sphere(&self.0))) }
     }
+    pub fn as_capsule(&self) -> Option<PxCapsuleGeometry> {
+        if self.get_type() != PxGeometryType::Capsule {
+            return None;
+        }
+        unsafe { Some(PxCapsuleGeometry(*physx_sys::PxGeometryHolder_capsule(&self.0))) }
+    }
     pub fn as_convex_mesh(&self) -> Option<PxConvexMeshGeometry> {
         if self.get_type() != PxGeometryType::ConvexMesh {
             return None;
@@ -224,6 +239,12 @@ impl PxGeometryHolder {
         }
         unsafe { Some(PxTriangleMeshGeometry(*physx_sys::PxGeometryHolder_triangleMesh(&self.0))) }
     }
+    pub fn as_height_field(&self) -> Option<PxHeightFieldGeometry> {
+        if self.get_type() != PxGeometryType::HEIGHTFIELD {
+            return None;
+        }
+        unsafe { Some(PxHeightFieldGeometry(*physx_sys::PxGeometryHolder_heightField(&self.0))) }
+    }
 }
 impl PxGeometry for PxGeometryHolder {
     fn as_geometry_ptr(&self) -> *const physx_sys::PxGeometry {
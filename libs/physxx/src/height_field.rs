@@ -2,6 +2,8 @@ use std::{ffi::c_void, mem::size_of};
 
 use enumflags2::BitFlags;
 
+use crate::{AsPxBase, PxBaseRef};
+
 #[derive(Debug, Copy, Clone)]
 #[repr(u32)]
 pub enum PxHeightFieldFormat {
@@ -36,6 +38,13 @@ impl Drop for PxHeightField {
         unsafe { physx_sys::PxHeightField_release_mut(self.0) }
     }
 }
+impl AsPxBase for PxHeightField {
+    fn as_base(&self) -> PxBaseRef {
+        PxBaseRef(self.0 as _)
+    }
+}
+unsafe impl Sync for PxHeightField {}
+unsafe impl Send for PxHeightField {}
 
 const PX_MIN_HEIGHTFIELD_Y_SCALE: f32 = 0.0001 / (0xFFFF as f32);
 
@@ -245,6 +245,144 @@ impl AsPxJoint for PxRevoluteJointRef {
 unsafe impl Sync for PxRevoluteJointRef {}
 unsafe impl Send for PxRevoluteJointRef {}
 
+#[derive(Debug, Clone, Copy)]
+pub struct PxPrismaticJointRef(pub(crate) *mut physx_sys::PxPrismaticJoint);
+impl PxPrismaticJointRef {
+    pub fn new(
+        physics: PxPhysicsRef,
+        actor0: Option<PxRigidActorRef>,
+        local_frame_0: &PxTransform,
+        actor1: Option<PxRigidActorRef>,
+        local_frame_1: &PxTransform,
+    ) -> Self {
+        Self(unsafe {
+            physx_sys::phys_PxPrismaticJointCreate(
+                physics.0,
+                actor0.map_or(null_mut(), |v| v.0),
+                &local_frame_0.0,
+                actor1.map_or(null_mut(), |v| v.0),
+                &local_frame_1.0,
+            )
+        })
+    }
+    pub fn get_position(&self) -> f32 {
+        unsafe { physx_sys::PxPrismaticJoint_getPosition(self.0) }
+    }
+    pub fn get_velocity(&self) -> f32 {
+        unsafe { physx_sys::PxPrismaticJoint_getVelocity(self.0) }
+    }
+}
+impl AsPxBase for PxPrismaticJointRef {
+    fn as_base(&self) -> PxBaseRef {
+        PxBaseRef(self.0 as _)
+    }
+}
+impl AsPxJoint for PxPrismaticJointRef {
+    fn as_joint(&self) -> PxJointRef {
+        PxJointRef(self.0 as _)
+    }
+}
+unsafe impl Sync for PxPrismaticJointRef {}
+unsafe impl Send for PxPrismaticJointRef {}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PxSphericalJointRef(pub(crate) *mut physx_sys::PxSphericalJoint);
+impl PxSphericalJointRef {
+    pub fn new(
+        physics: PxPhysicsRef,
+        actor0: Option<PxRigidActorRef>,
+        local_frame_0: &PxTransform,
+        actor1: Option<PxRigidActorRef>,
+        local_frame_1: &PxTransform,
+    ) -> Self {
+        Self(unsafe {
+            physx_sys::phys_PxSphericalJointCreate(
+                physics.0,
+                actor0.map_or(null_mut(), |v| v.0),
+                &local_frame_0.0,
+                actor1.map_or(null_mut(), |v| v.0),
+                &local_frame_1.0,
+            )
+        })
+    }
+}
+impl AsPxBase for PxSphericalJointRef {
+    fn as_base(&self) -> PxBaseRef {
+        PxBaseRef(self.0 as _)
+    }
+}
+impl AsPxJoint for PxSphericalJointRef {
+    fn as_joint(&self) -> PxJointRef {
+        PxJointRef(self.0 as _)
+    }
+}
+unsafe impl Sync for PxSphericalJointRef {}
+unsafe impl Send for PxSphericalJointRef {}
+
+bitflags! {
+    #[derive(Serialize, Deserialize)]
+    pub struct PxDistanceJointFlag: u32 {
+        const MAX_DISTANCE_ENABLED = physx_sys::PxDistanceJointFlag::eMAX_DISTANCE_ENABLED;
+        const MIN_DISTANCE_ENABLED = physx_sys::PxDistanceJointFlag::eMIN_DISTANCE_ENABLED;
+        const SPRING_ENABLED = physx_sys::PxDistanceJointFlag::eSPRING_ENABLED;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PxDistanceJointRef(pub(crate) *mut physx_sys::PxDistanceJoint);
+impl PxDistanceJointRef {
+    pub fn new(
+        physics: PxPhysicsRef,
+        actor0: Option<PxRigidActorRef>,
+        local_frame_0: &PxTransform,
+        actor1: Option<PxRigidActorRef>,
+        local_frame_1: &PxTransform,
+    ) -> Self {
+        Self(unsafe {
+            physx_sys::phys_PxDistanceJointCreate(
+                physics.0,
+                actor0.map_or(null_mut(), |v| v.0),
+                &local_frame_0.0,
+                actor1.map_or(null_mut(), |v| v.0),
+                &local_frame_1.0,
+            )
+        })
+    }
+    pub fn get_distance(&self) -> f32 {
+        unsafe { physx_sys::PxDistanceJoint_getDistance(self.0) }
+    }
+    pub fn get_min_distance(&self) -> f32 {
+        unsafe { physx_sys::PxDistanceJoint_getMinDistance(self.0) }
+    }
+    pub fn set_min_distance(&self, distance: f32) {
+        unsafe { physx_sys::PxDistanceJoint_setMinDistance_mut(self.0, distance) }
+    }
+    pub fn get_max_distance(&self) -> f32 {
+        unsafe { physx_sys::PxDistanceJoint_getMaxDistance(self.0) }
+    }
+    pub fn set_max_distance(&self, distance: f32) {
+        unsafe { physx_sys::PxDistanceJoint_setMaxDistance_mut(self.0, distance) }
+    }
+    pub fn get_distance_joint_flags(&self) -> PxDistanceJointFlag {
+        PxDistanceJointFlag::from_bits(unsafe { physx_sys::PxDistanceJoint_getDistanceJointFlags(self.0) }.mBits as u32).unwrap()
+    }
+    pub fn set_distance_joint_flag(&self, flag: PxDistanceJointFlag, value: bool) {
+        unsafe { physx_sys::PxDistanceJoint_setDistanceJointFlag_mut(self.0, flag.bits() as _, value) }
+    }
+}
+impl AsPxBase for PxDistanceJointRef {
+    fn as_base(&self) -> PxBaseRef {
+        PxBaseRef(self.0 as _)
+    }
+}
+impl AsPxJoint for PxDistanceJointRef {
+    fn as_joint(&self) -> PxJointRef {
+        PxJointRef(self.0 as _)
+    }
+}
+unsafe impl Sync for PxDistanceJointRef {}
+unsafe impl Send for PxDistanceJointRef {}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct PxJointAngularLimitPair {
     pub restitution: f32,
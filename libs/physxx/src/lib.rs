@@ -111,6 +111,30 @@ impl PxMaterial {
     pub fn new(physics: PxPhysicsRef, static_friction: f32, dynamic_friction: f32, restitution: f32) -> Self {
         Self(unsafe { physx_sys::PxPhysics_createMaterial_mut(physics.0, static_friction, dynamic_friction, restitution) })
     }
+    pub fn get_static_friction(&self) -> f32 {
+        unsafe { physx_sys::PxMaterial_getStaticFriction(self.0) }
+    }
+    pub fn set_static_friction(&self, friction: f32) {
+        unsafe { physx_sys::PxMaterial_setStaticFriction_mut(self.0, friction) }
+    }
+    pub fn get_dynamic_friction(&self) -> f32 {
+        unsafe { physx_sys::PxMaterial_getDynamicFriction(self.0) }
+    }
+    pub fn set_dynamic_friction(&self, friction: f32) {
+        unsafe { physx_sys::PxMaterial_setDynamicFriction_mut(self.0, friction) }
+    }
+    pub fn get_restitution(&self) -> f32 {
+        unsafe { physx_sys::PxMaterial_getRestitution(self.0) }
+    }
+    pub fn set_restitution(&self, restitution: f32) {
+        unsafe { physx_sys::PxMaterial_setRestitution_mut(self.0, restitution) }
+    }
+    pub fn set_friction_combine_mode(&self, mode: PxCombineMode) {
+        unsafe { physx_sys::PxMaterial_setFrictionCombineMode_mut(self.0, mode as u32) }
+    }
+    pub fn set_restitution_combine_mode(&self, mode: PxCombineMode) {
+        unsafe { physx_sys::PxMaterial_setRestitutionCombineMode_mut(self.0, mode as u32) }
+    }
     pub(crate) fn from_ptr(ptr: *mut physx_sys::PxMaterial) -> Self {
         let mut s = Self(ptr);
         s.acquire_reference();
@@ -120,6 +144,11 @@ impl PxMaterial {
         unsafe { physx_sys::PxMaterial_acquireReference_mut(self.0) }
     }
 }
+impl PxReferenceCounted for PxMaterial {
+    fn get_reference_count(&self) -> u32 {
+        unsafe { physx_sys::PxMaterial_getReferenceCount(self.0) }
+    }
+}
 impl AsPxBase for PxMaterial {
     fn as_base(&self) -> PxBaseRef {
         PxBaseRef(self.0 as _)
@@ -138,6 +167,16 @@ impl Clone for PxMaterial {
 unsafe impl Sync for PxMaterial {}
 unsafe impl Send for PxMaterial {}
 
+/// How the friction or restitution of the two materials in a contact are combined.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PxCombineMode {
+    Average = physx_sys::PxCombineMode::eAVERAGE,
+    Min = physx_sys::PxCombineMode::eMIN,
+    Multiply = physx_sys::PxCombineMode::eMULTIPLY,
+    Max = physx_sys::PxCombineMode::eMAX,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct PxAggregateRef(*mut physx_sys::PxAggregate);
 impl PxAggregateRef {
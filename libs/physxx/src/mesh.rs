@@ -1,7 +1,7 @@
 use glam::Vec3;
 
 use crate::{
-    AsPxPtr, PxConvexMeshCookingResult, PxCookingRef, PxDefaultMemoryInputData, PxDefaultMemoryOutputStream, PxPhysicsRef, PxReferenceCounted
+    AsPxBase, AsPxPtr, PxBaseRef, PxConvexMeshCookingResult, PxCookingRef, PxDefaultMemoryInputData, PxDefaultMemoryOutputStream, PxPhysicsRef, PxReferenceCounted
 };
 
 bitflags! {
@@ -68,6 +68,11 @@ impl Drop for PxConvexMesh {
         unsafe { physx_sys::PxConvexMesh_release_mut(self.0) }
     }
 }
+impl AsPxBase for PxConvexMesh {
+    fn as_base(&self) -> PxBaseRef {
+        PxBaseRef(self.0 as _)
+    }
+}
 unsafe impl Sync for PxConvexMesh {}
 unsafe impl Send for PxConvexMesh {}
 
@@ -123,5 +128,10 @@ impl Drop for PxTriangleMesh {
         unsafe { physx_sys::PxTriangleMesh_release_mut(self.0) }
     }
 }
+impl AsPxBase for PxTriangleMesh {
+    fn as_base(&self) -> PxBaseRef {
+        PxBaseRef(self.0 as _)
+    }
+}
 unsafe impl Sync for PxTriangleMesh {}
 unsafe impl Send for PxTriangleMesh {}
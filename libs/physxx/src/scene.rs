@@ -6,8 +6,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     sweep::PxSweepHit, to_glam_vec3, to_physx_vec3, AsArticulationBase, AsPxActor, PxActorRef, PxAggregateRef, PxCollectionRef,
-    PxConstraintRef, PxDefaultCpuDispatcherRef, PxGeometry, PxHitFlags, PxPhysicsRef, PxPvdSceneClientRef, PxRaycastHit, PxRigidActorRef,
-    PxShape, PxTransform,
+    PxConstraintRef, PxDefaultCpuDispatcherRef, PxFilterData, PxGeometry, PxHitFlags, PxPhysicsRef, PxPvdSceneClientRef, PxRaycastHit,
+    PxRigidActorRef, PxShape, PxTransform,
 };
 
 pub struct PxSceneDesc(physx_sys::PxSceneDesc);
@@ -41,8 +41,8 @@ impl PxSceneDesc {
             unsafe extern "C" fn collision_callback_trampoline<C: FnMut(&PxContactPairHeader)>(
                 user_data: *mut std::ffi::c_void,
                 pair_header: *const physx_sys::PxContactPairHeader,
-                _pairs: *const physx_sys::PxContactPair,
-                _nb_pairs: u32,
+                pairs: *const physx_sys::PxContactPair,
+                nb_pairs: u32,
             ) {
                 let mut cb: Box<C> = Box::from_raw(user_data as _);
                 let pair_header_flags = PxContactPairHeaderFlag::from_bits((*pair_header).flags.mBits).unwrap();
@@ -59,6 +59,7 @@ impl PxSceneDesc {
                             PxRigidActorRef::from_ptr((*pair_header).actors[1])
                         },
                     ],
+                    pairs: (0..nb_pairs as usize).map(|i| PxContactPair::from_physx(&*pairs.add(i))).collect(),
                 });
                 Box::into_raw(cb);
             }
@@ -85,6 +86,51 @@ impl PxSceneDesc {
 
 pub struct PxContactPairHeader {
     pub actors: [Option<PxRigidActorRef>; 2],
+    pub pairs: Vec<PxContactPair>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PxContactPair {
+    pub shapes: [Option<PxShape>; 2],
+    pub points: Vec<PxContactPairPoint>,
+    /// The touch events that caused this pair to be reported
+    pub events: PxPairFlag,
+}
+impl PxContactPair {
+    unsafe fn from_physx(pair: &physx_sys::PxContactPair) -> Self {
+        let flags = PxContactPairFlag::from_bits_truncate(pair.flags.mBits);
+        let shape = |ptr: *mut physx_sys::PxShape, removed: PxContactPairFlag| {
+            if ptr.is_null() || flags.contains(removed) {
+                None
+            } else {
+                Some(PxShape::from_ptr(ptr))
+            }
+        };
+        let mut points = Vec::<physx_sys::PxContactPairPoint>::with_capacity(pair.contactCount as usize);
+        let count = physx_sys::PxContactPair_extractContacts(pair, points.as_mut_ptr(), pair.contactCount as u32);
+        points.set_len(count as usize);
+        Self {
+            shapes: [shape(pair.shapes[0], PxContactPairFlag::REMOVED_SHAPE_0), shape(pair.shapes[1], PxContactPairFlag::REMOVED_SHAPE_1)],
+            events: PxPairFlag::from_bits_truncate(pair.events.mBits),
+            points: points
+                .iter()
+                .map(|point| PxContactPairPoint {
+                    position: to_glam_vec3(&point.position),
+                    normal: to_glam_vec3(&point.normal),
+                    separation: point.separation,
+                    impulse: to_glam_vec3(&point.impulse),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PxContactPairPoint {
+    pub position: Vec3,
+    pub normal: Vec3,
+    pub separation: f32,
+    pub impulse: Vec3,
 }
 
 pub struct PxSimulationEventCallback<C: FnMut(&PxContactPairHeader)> {
@@ -98,6 +144,21 @@ bitflags! {
     }
 }
 
+bitflags! {
+    pub struct PxContactPairFlag: u16 {
+        const REMOVED_SHAPE_0 = physx_sys::PxContactPairFlag::eREMOVED_SHAPE_0 as u16;
+        const REMOVED_SHAPE_1 = physx_sys::PxContactPairFlag::eREMOVED_SHAPE_1 as u16;
+    }
+}
+
+bitflags! {
+    pub struct PxPairFlag: u16 {
+        const NOTIFY_TOUCH_FOUND = physx_sys::PxPairFlag::eNOTIFY_TOUCH_FOUND as u16;
+        const NOTIFY_TOUCH_PERSISTS = physx_sys::PxPairFlag::eNOTIFY_TOUCH_PERSISTS as u16;
+        const NOTIFY_TOUCH_LOST = physx_sys::PxPairFlag::eNOTIFY_TOUCH_LOST as u16;
+    }
+}
+
 bitflags! {
     pub struct PxSceneFlags: u32 {
         const ADAPTIVE_FORCE = physx_sys::PxSceneFlag::eADAPTIVE_FORCE;
@@ -357,6 +418,9 @@ impl PxQueryFilterData {
     pub fn set_flags(&mut self, flags: PxQueryFlag) {
         self.0.flags.mBits = flags.bits as u16;
     }
+    pub fn set_data(&mut self, data: PxFilterData) {
+        self.0.data = data.to_physx();
+    }
 }
 impl Default for PxQueryFilterData {
     fn default() -> Self {
@@ -17,14 +17,20 @@ impl PxSerializationRegistryRef {
     pub fn is_serializable(&self, collection: PxCollectionRef, external_reference: Option<PxCollectionRef>) -> bool {
         unsafe { physx_sys::PxSerialization_isSerializable_mut(collection.0, self.0, external_reference.map_or(null_mut(), |x| x.0)) }
     }
-    pub fn serialize_collection_to_xml(&self, out_stream: &PxDefaultMemoryOutputStream, collection: PxCollectionRef) -> bool {
+    /// Objects in `external_refs` aren't serialized; the objects in `collection` refer to them by their serial ids instead
+    pub fn serialize_collection_to_xml(
+        &self,
+        out_stream: &PxDefaultMemoryOutputStream,
+        collection: PxCollectionRef,
+        external_refs: Option<PxCollectionRef>,
+    ) -> bool {
         unsafe {
             physx_sys::PxSerialization_serializeCollectionToXml_mut(
                 out_stream.0 as *mut physx_sys::PxOutputStream,
                 collection.0,
                 self.0,
                 null_mut(),
-                null_mut(),
+                external_refs.map(|x| x.0).unwrap_or(null_mut()),
                 null_mut(),
             )
         }
@@ -46,13 +52,19 @@ impl PxSerializationRegistryRef {
             )
         }
     }
-    pub fn create_collection_from_xml(&self, input_data: &PxDefaultMemoryInputData, cooking: &PxCookingRef) -> Option<PxCollectionRef> {
+    /// `external_refs` must hold the objects that were external references when the collection was serialized, with the same serial ids
+    pub fn create_collection_from_xml(
+        &self,
+        input_data: &PxDefaultMemoryInputData,
+        cooking: &PxCookingRef,
+        external_refs: Option<PxCollectionRef>,
+    ) -> Option<PxCollectionRef> {
         unsafe {
             let res = physx_sys::PxSerialization_createCollectionFromXml_mut(
                 input_data.0 as *mut physx_sys::PxInputData,
                 cooking.0,
                 self.0,
-                null_mut(),
+                external_refs.map(|x| x.0).unwrap_or(null_mut()),
                 null_mut(),
                 null_mut(),
             );
@@ -84,6 +96,9 @@ impl PxCollectionRef {
     pub fn add_with_serial_id(&mut self, object: PxBaseRef, id: PxSerialObjectId) {
         unsafe { physx_sys::PxCollection_add_mut(self.0, object.0, id) }
     }
+    pub fn get_nb_objects(&self) -> usize {
+        unsafe { physx_sys::PxCollection_getNbObjects(self.0) as usize }
+    }
     pub fn get_objects(&self) -> Vec<(PxSerialObjectId, PxAny)> {
         let mut res = Vec::new();
         unsafe {
@@ -96,6 +111,23 @@ impl PxCollectionRef {
         }
         res
     }
+    /// The object with the serial `id`, if it's in this collection
+    pub fn find(&self, id: PxSerialObjectId) -> Option<PxAny> {
+        self.find_base(id).map(|obj| PxAny::from_obj(obj.0))
+    }
+    /// Like [Self::find], for objects that [PxAny] doesn't support, such as triangle meshes
+    pub fn find_base(&self, id: PxSerialObjectId) -> Option<PxBaseRef> {
+        let obj = unsafe { physx_sys::PxCollection_find(self.0, id) };
+        if obj.is_null() {
+            None
+        } else {
+            Some(PxBaseRef(obj))
+        }
+    }
+    /// Gives every object in this collection that has no serial id a unique one, starting from `base`
+    pub fn create_serial_object_ids(&mut self, base: PxSerialObjectId) {
+        unsafe { physx_sys::PxSerialization_createSerialObjectIds_mut(self.0, base) }
+    }
     pub fn release(&mut self) {
         unsafe { physx_sys::PxCollection_release_mut(self.0) }
     }
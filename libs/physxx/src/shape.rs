@@ -20,6 +20,27 @@ impl Default for PxShapeFlag {
     }
 }
 
+/// Data used to filter the collisions and scene queries of shapes. What the words mean is up to the filter shader of
+/// the scene; scene queries by default only report shapes whose query filter data shares a bit with the query's.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PxFilterData {
+    pub word0: u32,
+    pub word1: u32,
+    pub word2: u32,
+    pub word3: u32,
+}
+impl PxFilterData {
+    pub fn new(word0: u32, word1: u32, word2: u32, word3: u32) -> Self {
+        Self { word0, word1, word2, word3 }
+    }
+    pub fn from_physx(data: &physx_sys::PxFilterData) -> Self {
+        Self { word0: data.word0, word1: data.word1, word2: data.word2, word3: data.word3 }
+    }
+    pub(crate) fn to_physx(self) -> physx_sys::PxFilterData {
+        physx_sys::PxFilterData { word0: self.word0, word1: self.word1, word2: self.word2, word3: self.word3 }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub struct PxShape(pub *mut physx_sys::PxShape);
 impl PxShape {
@@ -81,6 +102,22 @@ impl PxShape {
     pub fn get_flags(&self) -> PxShapeFlag {
         PxShapeFlag::from_bits(unsafe { physx_sys::PxShape_getFlags(self.0) }.mBits).unwrap()
     }
+    pub fn set_materials(&self, materials: &[&PxMaterial]) {
+        let mats = materials.iter().map(|x| x.0).collect::<Vec<*mut physx_sys::PxMaterial>>();
+        unsafe { physx_sys::PxShape_setMaterials_mut(self.0, mats.as_ptr() as *const *mut physx_sys::PxMaterial, materials.len() as u16) }
+    }
+    pub fn get_simulation_filter_data(&self) -> PxFilterData {
+        PxFilterData::from_physx(&unsafe { physx_sys::PxShape_getSimulationFilterData(self.0) })
+    }
+    pub fn set_simulation_filter_data(&self, data: PxFilterData) {
+        unsafe { physx_sys::PxShape_setSimulationFilterData_mut(self.0, &data.to_physx()) }
+    }
+    pub fn get_query_filter_data(&self) -> PxFilterData {
+        PxFilterData::from_physx(&unsafe { physx_sys::PxShape_getQueryFilterData(self.0) })
+    }
+    pub fn set_query_filter_data(&self, data: PxFilterData) {
+        unsafe { physx_sys::PxShape_setQueryFilterData_mut(self.0, &data.to_physx()) }
+    }
     pub fn set_flag(&self, flag: PxShapeFlag, value: bool) {
         unsafe { physx_sys::PxShape_setFlag_mut(self.0, flag.bits as u32, value) }
     }